@@ -0,0 +1,286 @@
+//! [`Agency`]-related definitions.
+
+use std::{future, time};
+
+use common::{DateTime, Handler as _, Percent};
+use derive_more::{AsRef, Display, From, Into};
+use futures::TryFutureExt as _;
+use juniper::{graphql_object, GraphQLEnum, GraphQLObject, GraphQLScalar};
+use service::{domain, query};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
+
+/// A real estate agency.
+#[derive(Clone, Debug, From)]
+pub struct Agency {
+    /// ID of this [`Agency`].
+    id: Id,
+
+    /// Underlying [`domain::Agency`].
+    agency: OnceCell<domain::Agency>,
+}
+
+impl From<domain::Agency> for Agency {
+    fn from(agency: domain::Agency) -> Self {
+        Self {
+            id: agency.id.into(),
+            agency: OnceCell::new_with(Some(agency)),
+        }
+    }
+}
+
+impl Agency {
+    /// Creates a new [`Agency`] with the provided ID.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that [`Agency`] with the provided ID exists,
+    /// otherwise accessing this [`Agency`] will result with an error.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            agency: OnceCell::new(),
+        }
+    }
+
+    /// Returns the underlying [`domain::Agency`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`domain::Agency`] doesn't exist.
+    async fn agency(&self, ctx: &Context) -> Result<&domain::Agency, Error> {
+        let id = self.id.into();
+        self.agency
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::agency::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|a| {
+                        future::ready(
+                            a.ok_or_else(|| AgencyError::NotExists.into()),
+                        )
+                    })
+            })
+            .await
+    }
+}
+
+/// A real estate agency.
+#[graphql_object(context = Context)]
+impl Agency {
+    /// Unique identifier of this `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Name of this `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn name(&self, ctx: &Context) -> Result<Name, Error> {
+        Ok(self.agency(ctx).await?.name.clone().into())
+    }
+
+    /// `User` administrating this `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.admin",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn admin(&self, ctx: &Context) -> Result<api::User, Error> {
+        let admin_id = self.agency(ctx).await?.admin_id;
+        // SAFETY: `User` is referenced by an existing `Agency`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        Ok(unsafe { api::User::new_unchecked(admin_id) })
+    }
+
+    /// `Branch`es of this `Agency`, ordered by their names.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.branches",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn branches(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Branch>, Error> {
+        ctx.service()
+            .execute(query::branch::ByAgency::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|branches| branches.into_iter().map(Into::into).collect())
+    }
+
+    /// Default settings of this `Agency`, applied to its `Contract`s when the
+    /// corresponding details are omitted.
+    ///
+    /// `null` if this `Agency` has never configured them.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.settings",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn settings(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<Settings>, Error> {
+        ctx.service()
+            .execute(query::agency::SettingsById::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|settings| settings.map(Into::into))
+    }
+
+    /// `DateTime` when this `Agency` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Agency.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn created_at(&self, ctx: &Context) -> Result<DateTime, Error> {
+        Ok(self.agency(ctx).await?.created_at.coerce())
+    }
+}
+
+/// Unique identifier of an `Agency`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::agency::Id)]
+#[into(domain::agency::Id)]
+#[graphql(name = "AgencyId", transparent)]
+pub struct Id(Uuid);
+
+/// Name of an `Agency`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "AgencyName",
+    with = scalar::Via::<domain::agency::Name>,
+)]
+pub struct Name(domain::agency::Name);
+
+/// Default settings of an `Agency`.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "AgencySettings")]
+pub struct Settings {
+    /// Default `Currency` of the `Agency` prices.
+    pub currency: Option<api::finance::Currency>,
+
+    /// Default duration of a `Contract`, in days, after which it expires.
+    ///
+    /// `null` means `Contract`s don't expire by default.
+    pub contract_duration_days: Option<i32>,
+
+    /// Default commission of a management `Contract`, used when no fee is
+    /// specified.
+    pub commission: Option<Percent>,
+
+    /// Default locale of the `Agency`.
+    pub locale: Option<Locale>,
+
+    /// `DateTime` when these settings were updated.
+    pub updated_at: DateTime,
+}
+
+impl From<domain::agency::Settings> for Settings {
+    fn from(settings: domain::agency::Settings) -> Self {
+        let domain::agency::Settings {
+            agency_id: _,
+            currency,
+            contract_duration,
+            commission,
+            locale,
+            updated_at,
+        } = settings;
+        let days = |d: time::Duration| {
+            i32::try_from(d.as_secs() / DAY.as_secs()).unwrap_or(i32::MAX)
+        };
+        Self {
+            currency: currency.map(Into::into),
+            contract_duration_days: contract_duration.map(days),
+            commission,
+            locale: locale.map(Into::into),
+            updated_at: updated_at.coerce(),
+        }
+    }
+}
+
+/// Duration of a day, in which `Settings.contract_duration_days` is
+/// measured.
+pub const DAY: time::Duration = time::Duration::from_hours(24);
+
+/// Locale of an `Agency`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "AgencyLocale")]
+pub enum Locale {
+    /// English.
+    En,
+
+    /// Russian.
+    Ru,
+}
+
+impl From<domain::agency::Locale> for Locale {
+    fn from(locale: domain::agency::Locale) -> Self {
+        match locale {
+            domain::agency::Locale::En => Self::En,
+            domain::agency::Locale::Ru => Self::Ru,
+        }
+    }
+}
+
+impl From<Locale> for domain::agency::Locale {
+    fn from(locale: Locale) -> Self {
+        match locale {
+            Locale::En => Self::En,
+            Locale::Ru => Self::Ru,
+        }
+    }
+}
+
+define_error! {
+    enum AgencyError {
+        #[code = "AGENCY_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Agency` does not exist"]
+        NotExists,
+
+        #[code = "INVALID_AGENCY_ID"]
+        #[status = BAD_REQUEST]
+        #[message = "`X-Agency-Id` header must be a valid `AgencyId`"]
+        InvalidId,
+
+        #[code = "NOT_AGENCY_ADMIN"]
+        #[status = FORBIDDEN]
+        #[message = "Authenticated `User` must administrate the `Agency`"]
+        NotAdmin,
+    }
+}
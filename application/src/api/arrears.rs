@@ -0,0 +1,136 @@
+//! [`Arrears`]-related definitions.
+
+use common::{DateTime, Money};
+use derive_more::From;
+use juniper::graphql_object;
+use service::domain;
+
+use crate::{api, Context};
+
+/// Overdue debt of a purchaser under a rent `Contract`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Arrears(domain::Arrears);
+
+/// Overdue debt of a purchaser under a rent `Contract`.
+#[graphql_object(name = "RentArrears", context = Context)]
+impl Arrears {
+    /// `RentContract` in arrears.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn contract(&self) -> api::contract::Rent {
+        // SAFETY: `Contract` is referenced by existing `RentArrears`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::contract::Rent::new_unchecked(self.0.contract_id)
+        }
+    }
+
+    /// `User` renting the `Realty`, who owes the `amount`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.purchaser",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn purchaser(&self) -> api::User {
+        // SAFETY: `User` is referenced by existing `RentArrears`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.purchaser_id)
+        }
+    }
+
+    /// Employer `User` responsible for the `RentContract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.employer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn employer(&self) -> api::User {
+        // SAFETY: `User` is referenced by existing `RentArrears`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.employer_id)
+        }
+    }
+
+    /// Overdue amount, in the currency of the `RentContract.price`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.amount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn amount(&self) -> Money {
+        self.0.amount
+    }
+
+    /// `amount` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.formattedAmount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_amount(&self, ctx: &Context) -> String {
+        self.0.amount.format(ctx.locale().into())
+    }
+
+    /// Part of the `amount` consisting of the accrued `RentLateFee`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.lateFees",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn late_fees(&self) -> Money {
+        self.0.late_fees
+    }
+
+    /// `DateTime` when the earliest unpaid installment was due.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.overdueSince",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn overdue_since(&self) -> DateTime {
+        self.0.overdue_since.coerce()
+    }
+
+    /// `DateTime` when these `RentArrears` were detected.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentArrears.detectedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn detected_at(&self) -> DateTime {
+        self.0.detected_at.coerce()
+    }
+}
@@ -0,0 +1,311 @@
+//! Audit log [`Entry`]-related definitions.
+
+use common::DateTime;
+use derive_more::{Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, Context};
+
+/// Entry of the audit log, recording an action performed by a `User` on an
+/// entity.
+#[derive(Clone, Debug, From)]
+pub struct Entry(domain::audit::Entry);
+
+/// Entry of the audit log, recording an action performed by a `User` on an
+/// entity.
+#[graphql_object(name = "AuditEntry", context = Context)]
+impl Entry {
+    /// Unique identifier of this `AuditEntry`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `User` who performed the action.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.actor",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn actor(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `AuditEntry`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.actor_id)
+        }
+    }
+
+    /// Performed action.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.action",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn action(&self) -> Action {
+        self.0.action.into()
+    }
+
+    /// Kind of the entity the action was performed on.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.entity",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn entity(&self) -> Entity {
+        self.0.entity.into()
+    }
+
+    /// Unique identifier of the entity the action was performed on.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.entityId",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn entity_id(&self) -> Uuid {
+        self.0.entity_id
+    }
+
+    /// JSON snapshot of the entity before the action.
+    ///
+    /// `null` if the entity didn't exist or isn't snapshotted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.before",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn before(&self) -> Option<&str> {
+        self.0.before.as_ref().map(AsRef::as_ref)
+    }
+
+    /// JSON snapshot of the entity after the action.
+    ///
+    /// `null` if the entity doesn't exist anymore or isn't snapshotted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.after",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn after(&self) -> Option<&str> {
+        self.0.after.as_ref().map(AsRef::as_ref)
+    }
+
+    /// `DateTime` when the action was performed.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "AuditEntry.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of an `AuditEntry`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::audit::Id)]
+#[into(domain::audit::Id)]
+#[graphql(name = "AuditEntryId", transparent)]
+pub struct Id(Uuid);
+
+/// Action recorded by an `AuditEntry`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "AuditAction")]
+pub enum Action {
+    /// `User` was impersonated by an admin.
+    ImpersonateUser,
+
+    /// `Contract` was archived.
+    ArchiveContract,
+
+    /// Archived `Contract` was restored.
+    RestoreContract,
+
+    /// Deleted `Realty` was restored.
+    RestoreRealty,
+
+    /// Deleted `User` was restored.
+    RestoreUser,
+}
+
+impl From<domain::audit::Action> for Action {
+    fn from(action: domain::audit::Action) -> Self {
+        use domain::audit::Action as A;
+        match action {
+            A::ImpersonateUser => Self::ImpersonateUser,
+            A::ArchiveContract => Self::ArchiveContract,
+            A::RestoreContract => Self::RestoreContract,
+            A::RestoreRealty => Self::RestoreRealty,
+            A::RestoreUser => Self::RestoreUser,
+        }
+    }
+}
+
+/// Kind of the entity an `AuditEntry` is recorded for.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "AuditEntity")]
+pub enum Entity {
+    /// `User`.
+    User,
+
+    /// `Realty`.
+    Realty,
+
+    /// `Contract`.
+    Contract,
+}
+
+impl From<domain::audit::Entity> for Entity {
+    fn from(entity: domain::audit::Entity) -> Self {
+        use domain::audit::Entity as E;
+        match entity {
+            E::User => Self::User,
+            E::Realty => Self::Realty,
+            E::Contract => Self::Contract,
+        }
+    }
+}
+
+impl From<Entity> for domain::audit::Entity {
+    fn from(entity: Entity) -> Self {
+        match entity {
+            Entity::User => Self::User,
+            Entity::Realty => Self::Realty,
+            Entity::Contract => Self::Contract,
+        }
+    }
+}
+
+pub mod list {
+    //! Definitions related to an [`Entry`] list.
+
+    use derive_more::{AsRef, From, Into};
+    use juniper::{graphql_object, GraphQLScalar};
+    use service::read;
+
+    use crate::{
+        api::{self, scalar},
+        Context,
+    };
+
+    use super::Entry;
+
+    /// Cursor for the `AuditEntry` list.
+    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
+    #[from(api::audit::Id, read::audit::list::Cursor)]
+    #[graphql(
+        name = "AuditEntryListCursor",
+        with = scalar::Via::<read::audit::list::Cursor>,
+    )]
+    pub struct Cursor(pub read::audit::list::Cursor);
+
+    /// Edge in the [`Entry`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Edge(read::audit::list::Edge);
+
+    /// Edge in the `AuditEntry` list.
+    #[graphql_object(name = "AuditEntryListEdge", context = Context)]
+    impl Edge {
+        /// Cursor of this `AuditEntryListEdge`.
+        #[must_use]
+        pub fn cursor(&self) -> Cursor {
+            self.0.cursor.into()
+        }
+
+        /// Node of this `AuditEntryListEdge`.
+        #[must_use]
+        pub fn node(&self) -> Entry {
+            self.0.node.clone().into()
+        }
+    }
+
+    /// Connection of the [`Entry`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Connection(read::audit::list::Connection);
+
+    /// Connection of the `AuditEntry` list.
+    #[graphql_object(name = "AuditEntryListConnection", context = Context)]
+    impl Connection {
+        /// Edges in this `AuditEntryListConnection`.
+        #[must_use]
+        pub fn edges(&self) -> Vec<Edge> {
+            self.0.edges.iter().cloned().map(Into::into).collect()
+        }
+
+        /// Information about the page.
+        #[must_use]
+        pub fn page_info(&self) -> PageInfo {
+            PageInfo {
+                info: self.0.page_info(),
+                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
+                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+            }
+        }
+    }
+
+    /// Information about a [`Connection`] page.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PageInfo {
+        /// Underlying [`read::audit::list::PageInfo`].
+        info: read::audit::list::PageInfo,
+
+        /// Start cursor of the page.
+        start_cursor: Option<Cursor>,
+
+        /// End cursor of the page.
+        end_cursor: Option<Cursor>,
+    }
+
+    /// Information about an `AuditEntryListConnection` page.
+    #[graphql_object(name = "AuditEntryListPageInfo", context = Context)]
+    impl PageInfo {
+        /// Indicator whether there is a next page.
+        #[must_use]
+        pub fn has_next_page(&self) -> bool {
+            self.info.has_next_page
+        }
+
+        /// Indicator whether there is a previous page.
+        #[must_use]
+        pub fn has_previous_page(&self) -> bool {
+            self.info.has_previous_page
+        }
+
+        /// Start cursor of the page.
+        #[must_use]
+        pub fn start_cursor(&self) -> &Option<Cursor> {
+            &self.start_cursor
+        }
+
+        /// End cursor of the page.
+        #[must_use]
+        pub fn end_cursor(&self) -> &Option<Cursor> {
+            &self.end_cursor
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! [`Branch`]-related definitions.
+
+use std::future;
+
+use common::{DateTime, Handler as _};
+use derive_more::{AsRef, Display, From, Into};
+use futures::TryFutureExt as _;
+use juniper::{graphql_object, GraphQLScalar};
+use service::{domain, query};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
+
+/// A branch office of an agency.
+#[derive(Clone, Debug, From)]
+pub struct Branch {
+    /// ID of this [`Branch`].
+    id: Id,
+
+    /// Underlying [`domain::Branch`].
+    branch: OnceCell<domain::Branch>,
+}
+
+impl From<domain::Branch> for Branch {
+    fn from(branch: domain::Branch) -> Self {
+        Self {
+            id: branch.id.into(),
+            branch: OnceCell::new_with(Some(branch)),
+        }
+    }
+}
+
+impl Branch {
+    /// Creates a new [`Branch`] with the provided ID.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that [`Branch`] with the provided ID exists,
+    /// otherwise accessing this [`Branch`] will result with an error.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            branch: OnceCell::new(),
+        }
+    }
+
+    /// Returns the underlying [`domain::Branch`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`domain::Branch`] doesn't exist.
+    async fn branch(&self, ctx: &Context) -> Result<&domain::Branch, Error> {
+        let id = self.id.into();
+        self.branch
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::branch::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|b| {
+                        future::ready(
+                            b.ok_or_else(|| BranchError::NotExists.into()),
+                        )
+                    })
+            })
+            .await
+    }
+}
+
+/// A branch office of an agency.
+#[graphql_object(context = Context)]
+impl Branch {
+    /// Unique identifier of this `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Branch.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Name of this `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Branch.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn name(&self, ctx: &Context) -> Result<Name, Error> {
+        Ok(self.branch(ctx).await?.name.clone().into())
+    }
+
+    /// Address of this `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Branch.address",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn address(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::realty::Address, Error> {
+        Ok(self.branch(ctx).await?.address.clone().into())
+    }
+
+    /// `Agency` this `Branch` belongs to.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Branch.agency",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn agency(&self, ctx: &Context) -> Result<api::Agency, Error> {
+        let agency_id = self.branch(ctx).await?.agency_id;
+        // SAFETY: `Agency` is referenced by an existing `Branch`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        Ok(unsafe { api::Agency::new_unchecked(agency_id) })
+    }
+
+    /// `DateTime` when this `Branch` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Branch.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn created_at(&self, ctx: &Context) -> Result<DateTime, Error> {
+        Ok(self.branch(ctx).await?.created_at.coerce())
+    }
+}
+
+/// Unique identifier of a `Branch`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::branch::Id)]
+#[into(domain::branch::Id)]
+#[graphql(name = "BranchId", transparent)]
+pub struct Id(Uuid);
+
+/// Name of a `Branch`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "BranchName",
+    with = scalar::Via::<domain::branch::Name>,
+)]
+pub struct Name(domain::branch::Name);
+
+define_error! {
+    enum BranchError {
+        #[code = "BRANCH_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Branch` does not exist"]
+        NotExists,
+    }
+}
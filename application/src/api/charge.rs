@@ -0,0 +1,191 @@
+//! [`Charge`]-related definitions.
+
+use common::{DateTime, Money};
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, Context};
+
+/// Recurring utility or service charge billed under a rent `Contract`.
+#[derive(Clone, Debug, From)]
+pub struct Charge(domain::Charge);
+
+/// Recurring utility or service charge billed under a rent `Contract`.
+#[graphql_object(name = "RentCharge", context = Context)]
+impl Charge {
+    /// Unique identifier of this `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `RentContract` this `RentCharge` is billed under.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn contract(&self) -> api::contract::Rent {
+        // SAFETY: `Contract` is referenced by an existing `RentCharge`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::contract::Rent::new_unchecked(self.0.contract_id)
+        }
+    }
+
+    /// Name of this `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn name(&self) -> Name {
+        self.0.name.clone().into()
+    }
+
+    /// Amount billed monthly along with each rent installment.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.amount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn amount(&self) -> Money {
+        self.0.amount
+    }
+
+    /// `amount` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.formattedAmount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_amount(&self, ctx: &Context) -> String {
+        self.0.amount.format(ctx.locale().into())
+    }
+
+    /// Party bearing this `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.bearer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn bearer(&self) -> Bearer {
+        self.0.bearer.into()
+    }
+
+    /// Employer `User` who added this `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.creator",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn creator(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `RentCharge`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.creator_id)
+        }
+    }
+
+    /// `DateTime` when this `RentCharge` was added.
+    ///
+    /// Only the rent installments due after it are billed with this
+    /// `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentCharge.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of a `RentCharge`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::charge::Id)]
+#[into(domain::charge::Id)]
+#[graphql(name = "RentChargeId", transparent)]
+pub struct Id(Uuid);
+
+/// Name of a `RentCharge`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RentChargeName",
+    with = scalar::Via::<domain::charge::Name>,
+)]
+pub struct Name(domain::charge::Name);
+
+/// Party bearing a `RentCharge`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RentChargeBearer")]
+pub enum Bearer {
+    /// Purchaser `User` renting the `Realty`.
+    Tenant,
+
+    /// Landlord `User` owning the `Realty`.
+    Landlord,
+
+    /// `Agency` managing the `Realty`.
+    Agency,
+}
+
+impl From<domain::charge::Bearer> for Bearer {
+    fn from(bearer: domain::charge::Bearer) -> Self {
+        use domain::charge::Bearer as B;
+        match bearer {
+            B::Tenant => Self::Tenant,
+            B::Landlord => Self::Landlord,
+            B::Agency => Self::Agency,
+        }
+    }
+}
+
+impl From<Bearer> for domain::charge::Bearer {
+    fn from(bearer: Bearer) -> Self {
+        match bearer {
+            Bearer::Tenant => Self::Tenant,
+            Bearer::Landlord => Self::Landlord,
+            Bearer::Agency => Self::Agency,
+        }
+    }
+}
+
+define_error! {
+    enum ChargeError {
+        #[code = "RENT_CHARGE_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`RentCharge` does not exist"]
+        NotExists,
+    }
+}
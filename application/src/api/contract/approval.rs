@@ -0,0 +1,136 @@
+//! [`Approval`]-related definitions.
+
+use common::{DateTime, DateTimeOf, Handler as _};
+use derive_more::From;
+use juniper::graphql_object;
+use service::{domain, query};
+
+use crate::{api, define_error, AsError, Context, Error};
+
+/// Approval of a high-value `Contract` by a second employer `User`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Approval(domain::contract::Approval);
+
+/// Approval of a high-value `Contract` by a second employer `User`.
+///
+/// `Contract` is not active until it's approved.
+#[graphql_object(name = "ContractApproval", context = Context)]
+impl Approval {
+    /// `Contract` requiring this `ContractApproval`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        ctx.service()
+            .execute(query::contract::ById::by(self.0.contract_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::query::ContractError::NotExists.into())
+    }
+
+    /// Indicates whether this `ContractApproval` is not granted yet.
+    #[graphql(deprecated = "Use `approvedAt`, which is `null` while pending")]
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.isPending",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn is_pending(&self) -> bool {
+        self.0.is_pending()
+    }
+
+    /// Employer `User` who created the `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.requester",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn requester(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `ContractApproval`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.requester_id)
+        }
+    }
+
+    /// `DateTime` when this `ContractApproval` was requested.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.requestedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn requested_at(&self) -> DateTime {
+        self.0.requested_at.coerce()
+    }
+
+    /// Employer `User` who approved the `Contract`.
+    ///
+    /// `null` if not approved yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.approver",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn approver(&self) -> Option<api::User> {
+        self.0.approver_id.map(|id| {
+            // SAFETY: `User` is referenced by an existing `ContractApproval`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(id)
+            }
+        })
+    }
+
+    /// `DateTime` when the `Contract` was approved.
+    ///
+    /// `null` if not approved yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractApproval.approvedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn approved_at(&self) -> Option<DateTime> {
+        self.0.approved_at.map(DateTimeOf::coerce)
+    }
+}
+
+define_error! {
+    enum ApprovalError {
+        #[code = "CONTRACT_NOT_PENDING_APPROVAL"]
+        #[status = CONFLICT]
+        #[message = "`Contract` with the provided ID is not pending approval"]
+        NotPending,
+
+        #[code = "CONTRACT_SELF_APPROVAL"]
+        #[status = FORBIDDEN]
+        #[message = "`Contract` must be approved by another employer"]
+        SelfApproval,
+    }
+}
@@ -0,0 +1,69 @@
+//! [`Split`]-related definitions.
+
+use common::Percent;
+use derive_more::From;
+use juniper::{graphql_object, GraphQLInputObject};
+use service::domain;
+
+use crate::{api, define_error, Context};
+
+/// Share of a `Contract` commission attributed to an employer `User`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Split(domain::contract::commission::Split);
+
+/// Share of a `Contract` commission attributed to an employer `User`.
+#[graphql_object(name = "ContractCommissionSplit", context = Context)]
+impl Split {
+    /// Employer `User` the share is attributed to.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractCommissionSplit.employer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn employer(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing
+        //         `ContractCommissionSplit`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.employer_id)
+        }
+    }
+
+    /// Share of the commission attributed to the employer `User`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractCommissionSplit.share",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn share(&self) -> Percent {
+        self.0.share
+    }
+}
+
+/// Share of a `Contract` commission to be attributed to an employer `User`.
+#[derive(Clone, Copy, Debug, GraphQLInputObject)]
+#[graphql(name = "ContractCommissionSplitInput")]
+pub struct SplitInput {
+    /// ID of the employer `User` the share is attributed to.
+    pub employer_id: api::user::Id,
+
+    /// Share of the commission attributed to the employer `User`.
+    pub share: Percent,
+}
+
+define_error! {
+    enum SplitError {
+        #[code = "INVALID_COMMISSION_SHARES"]
+        #[status = BAD_REQUEST]
+        #[message = "Shares of `ContractCommissionSplit`s must sum to 100%"]
+        InvalidShares,
+    }
+}
@@ -10,7 +10,7 @@ use tokio::sync::OnceCell;
 use crate::api::Contract;
 use crate::{api, AsError, Context, Error};
 
-use super::{ContractValue, Description, Id, Name};
+use super::{ContractValue, Description, Id, Name, Number};
 
 /// Employment [`Contract`].
 #[derive(Clone, Debug)]
@@ -110,6 +110,18 @@ impl Employment {
         self.id
     }
 
+    /// Human-readable number of this `Contract`, unique within its `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.number",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn number(&self, ctx: &Context) -> Result<Number, Error> {
+        Ok(self.contract(ctx).await?.number.clone().into())
+    }
+
     /// Name of this `Contract`.
     #[tracing::instrument(
         skip_all,
@@ -163,6 +175,55 @@ impl Employment {
             .await
     }
 
+    /// Manager the employer of this `Contract` reports to, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.manager",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn manager(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::User>, Error> {
+        let Some(id) = self.contract(ctx).await?.manager_id else {
+            return Ok(None);
+        };
+        ctx.service()
+            .execute(query::user::ById::by(id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .ok_or_else(|| api::query::UserError::NotExists.into())
+            .map_err(ctx.error())
+            .map(|u| Some(u.into()))
+    }
+
+    /// `Branch` the employer of this `Contract` is assigned to, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.branch",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn branch(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::Branch>, Error> {
+        let Some(id) = self.contract(ctx).await?.branch_id else {
+            return Ok(None);
+        };
+        // SAFETY: `Branch` is referenced by an existing `Contract`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        Ok(Some(unsafe { api::Branch::new_unchecked(id) }))
+    }
+
     /// Base salary of employer this `Contract` is about.
     #[tracing::instrument(
         skip_all,
@@ -175,6 +236,25 @@ impl Employment {
         Ok(self.contract(ctx).await?.base_salary)
     }
 
+    /// `baseSalary` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.formattedBaseSalary",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_base_salary(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .base_salary
+            .format(ctx.locale().into()))
+    }
+
     /// `DateTime` when this `Contract` was created.
     #[tracing::instrument(
         skip_all,
@@ -202,6 +282,25 @@ impl Employment {
         Ok(self.contract(ctx).await?.expires_at.map(DateTimeOf::coerce))
     }
 
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.completedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn completed_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .completed_at
+            .map(DateTimeOf::coerce))
+    }
+
     /// `DateTime` when this `Contract` was terminated.
     #[tracing::instrument(
         skip_all,
@@ -220,4 +319,43 @@ impl Employment {
             .terminated_at
             .map(DateTimeOf::coerce))
     }
+
+    /// `DateTime` when this `Contract` was archived.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.archivedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn archived_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .archived_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// Signing parties of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "EmploymentContract.parties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn parties(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        ctx.service()
+            .execute(query::contract::Parties::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
 }
@@ -10,7 +10,7 @@ use tokio::sync::OnceCell;
 use crate::api::{Contract, Realty, User};
 use crate::{api, AsError, Context, Error};
 
-use super::{ContractValue, Description, Id, Name};
+use super::{ContractValue, Description, Id, Name, Number};
 
 /// [`Contract`] about managing a [`Realty`] for sale.
 #[derive(Clone, Debug)]
@@ -126,6 +126,18 @@ impl ManagementForSale {
         self.id
     }
 
+    /// Human-readable number of this `Contract`, unique within its `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.number",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn number(&self, ctx: &Context) -> Result<Number, Error> {
+        Ok(self.contract(ctx).await?.number.clone().into())
+    }
+
     /// Name of this `Contract`.
     #[tracing::instrument(
         skip_all,
@@ -243,6 +255,25 @@ impl ManagementForSale {
         Ok(self.contract(ctx).await?.expected_price)
     }
 
+    /// `expectedPrice` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.formattedExpectedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_expected_price(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .expected_price
+            .format(ctx.locale().into()))
+    }
+
     /// Expected deposit of the `Realty` this `Contract` is about.
     #[tracing::instrument(
         skip_all,
@@ -258,6 +289,26 @@ impl ManagementForSale {
         Ok(self.contract(ctx).await?.expected_deposit)
     }
 
+    /// `expectedDeposit` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.formattedExpectedDeposit",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_expected_deposit(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self
+            .contract(ctx)
+            .await?
+            .expected_deposit
+            .map(|m| m.format(locale)))
+    }
+
     /// One-time fee the landlord charges for managing the `Realty`.
     #[tracing::instrument(
         skip_all,
@@ -273,6 +324,26 @@ impl ManagementForSale {
         Ok(self.contract(ctx).await?.one_time_fee)
     }
 
+    /// `oneTimeFee` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.formattedOneTimeFee",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_one_time_fee(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self
+            .contract(ctx)
+            .await?
+            .one_time_fee
+            .map(|m| m.format(locale)))
+    }
+
     /// Monthly fee the landlord charges for managing the `Realty`.
     #[tracing::instrument(
         skip_all,
@@ -288,6 +359,26 @@ impl ManagementForSale {
         Ok(self.contract(ctx).await?.monthly_fee)
     }
 
+    /// `monthlyFee` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.formattedMonthlyFee",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_monthly_fee(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self
+            .contract(ctx)
+            .await?
+            .monthly_fee
+            .map(|m| m.format(locale)))
+    }
+
     /// Percentage fee from sale price the landlord charges for managing the
     /// `Realty`.
     #[tracing::instrument(
@@ -345,6 +436,25 @@ impl ManagementForSale {
         Ok(self.contract(ctx).await?.expires_at.map(DateTimeOf::coerce))
     }
 
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.completedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn completed_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .completed_at
+            .map(DateTimeOf::coerce))
+    }
+
     /// `DateTime` when this `Contract` was terminated.
     #[tracing::instrument(
         skip_all,
@@ -363,4 +473,96 @@ impl ManagementForSale {
             .terminated_at
             .map(DateTimeOf::coerce))
     }
+
+    /// `DateTime` when this `Contract` was archived.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.archivedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn archived_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .archived_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// `InsurancePolicy` attached to this `Contract`.
+    ///
+    /// `null` if none is attached, or the authenticated `User` is not the
+    /// employer responsible for this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.insurance",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn insurance(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::Insurance>, Error> {
+        let Some(session) = ctx.try_current_session().await? else {
+            return Ok(None);
+        };
+        let my_id: domain::user::Id = session.user_id.into();
+        if self.contract(ctx).await?.employer_id != my_id {
+            return Ok(None);
+        }
+
+        ctx.service()
+            .execute(query::insurance::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|i| i.map(Into::into))
+    }
+
+    /// Signing parties of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.parties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn parties(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        ctx.service()
+            .execute(query::contract::Parties::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Splits of the commission of this `Contract` between employer `User`s.
+    ///
+    /// Empty if the commission is attributed solely to the signing employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ManagementForSaleContract.commissionSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn commission_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::commission::Split>, Error> {
+        ctx.service()
+            .execute(query::contract::CommissionSplits::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.into_iter().map(Into::into).collect())
+    }
 }
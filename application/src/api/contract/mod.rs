@@ -1,14 +1,19 @@
 //! [`Contract`]-related definitions.
 
+pub mod approval;
+pub mod commission;
 mod employment;
 mod management_for_rent;
 mod management_for_sale;
+pub mod party;
 mod rent;
 mod sale;
+mod short_term_rent;
+pub mod template;
 
 use common::DateTime;
 use derive_more::{AsRef, Display, From, Into};
-use juniper::{GraphQLInterface, GraphQLScalar};
+use juniper::{GraphQLInterface, GraphQLObject, GraphQLScalar};
 use service::{domain, read};
 use uuid::Uuid;
 
@@ -17,6 +22,7 @@ use crate::{api::scalar, Context};
 pub use self::{
     employment::Employment, management_for_rent::ManagementForRent,
     management_for_sale::ManagementForSale, rent::Rent, sale::Sale,
+    short_term_rent::ShortTermRent,
 };
 
 /// Contract representing a legal agreement between two or more parties.
@@ -29,12 +35,16 @@ pub use self::{
         ManagementForSale,
         Rent,
         Sale,
+        ShortTermRent,
     ]
 )]
 pub struct Contract {
     /// Unique identifier of the `Contract`.
     id: Id,
 
+    /// Human-readable number of the `Contract`, unique within its `Agency`.
+    number: Number,
+
     /// Name of the `Contract`.
     name: Name,
 
@@ -47,8 +57,17 @@ pub struct Contract {
     /// `DateTime` when this `Contract` expires.
     expires_at: Option<DateTime>,
 
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    completed_at: Option<DateTime>,
+
     /// `DateTime` when this `Contract` was terminated.
     terminated_at: Option<DateTime>,
+
+    /// `DateTime` when this `Contract` was archived.
+    archived_at: Option<DateTime>,
+
+    /// Signing parties of this `Contract`.
+    parties: Vec<party::Party>,
 }
 
 impl From<domain::Contract> for ContractValue {
@@ -60,6 +79,7 @@ impl From<domain::Contract> for ContractValue {
             Contract::ManagementForSale(c) => Self::ManagementForSale(c.into()),
             Contract::Rent(c) => Self::Rent(c.into()),
             Contract::Sale(c) => Self::Sale(c.into()),
+            Contract::ShortTermRent(c) => Self::ShortTermRent(c.into()),
         }
     }
 }
@@ -98,6 +118,9 @@ impl ContractValue {
             }
             Kind::Rent => Self::Rent(Rent::new_unchecked(id)),
             Kind::Sale => Self::Sale(Sale::new_unchecked(id)),
+            Kind::ShortTermRent => {
+                Self::ShortTermRent(ShortTermRent::new_unchecked(id))
+            }
         }
     }
 }
@@ -109,6 +132,16 @@ impl ContractValue {
 #[graphql(name = "ContractId", transparent)]
 pub struct Id(Uuid);
 
+/// Human-readable number of a `Contract`, unique within its `Agency`.
+///
+/// Formatted as `{KIND}-{YEAR}-{SEQUENCE}` (`RENT-2025-00042`, for example).
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "ContractNumber",
+    with = scalar::Via::<domain::contract::Number>,
+)]
+pub struct Number(domain::contract::Number);
+
 /// Name of a `Contract`.
 #[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
 #[graphql(
@@ -125,12 +158,98 @@ pub struct Name(domain::contract::Name);
 )]
 pub struct Description(domain::contract::Description);
 
+/// Numbers of `Contract`s grouped by their status and kind.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "ContractStats")]
+pub struct Stats {
+    /// Numbers of the active `Contract`s.
+    pub active: KindCounts,
+
+    /// Numbers of the `Contract`s completed (or expired).
+    pub completed: KindCounts,
+
+    /// Numbers of the terminated `Contract`s.
+    pub terminated: KindCounts,
+}
+
+impl From<read::contract::Counts> for Stats {
+    fn from(counts: read::contract::Counts) -> Self {
+        use domain::contract::Status;
+
+        Self {
+            active: KindCounts::new(&counts, Status::Active),
+            completed: KindCounts::new(&counts, Status::Completed),
+            terminated: KindCounts::new(&counts, Status::Terminated),
+        }
+    }
+}
+
+/// Numbers of `Contract`s of the same status, grouped by their kind.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "ContractKindCounts")]
+pub struct KindCounts {
+    /// Number of the `RentContract`s.
+    pub rent: i32,
+
+    /// Number of the `SaleContract`s.
+    pub sale: i32,
+
+    /// Number of the `ManagementForRentContract`s.
+    pub management_for_rent: i32,
+
+    /// Number of the `ManagementForSaleContract`s.
+    pub management_for_sale: i32,
+
+    /// Number of the `EmploymentContract`s.
+    pub employment: i32,
+
+    /// Number of the `ShortTermRentContract`s.
+    pub short_term_rent: i32,
+
+    /// Number of the `Contract`s of all kinds.
+    pub total: i32,
+}
+
+impl KindCounts {
+    /// Creates new [`KindCounts`] out of the provided
+    /// [`read::contract::Counts`] of the provided [`domain::contract::Status`].
+    #[must_use]
+    fn new(
+        counts: &read::contract::Counts,
+        status: domain::contract::Status,
+    ) -> Self {
+        use domain::contract::Kind;
+
+        let count = |kind| i32::from(counts.get(status, kind));
+        let rent = count(Kind::Rent);
+        let sale = count(Kind::Sale);
+        let management_for_rent = count(Kind::ManagementForRent);
+        let management_for_sale = count(Kind::ManagementForSale);
+        let employment = count(Kind::Employment);
+        let short_term_rent = count(Kind::ShortTermRent);
+        Self {
+            rent,
+            sale,
+            management_for_rent,
+            management_for_sale,
+            employment,
+            short_term_rent,
+            total: rent
+                + sale
+                + management_for_rent
+                + management_for_sale
+                + employment
+                + short_term_rent,
+        }
+    }
+}
+
 pub mod list {
     //! Definitions related to the [`Contract`] list.
 
     use derive_more::{AsRef, From, Into};
-    use juniper::{graphql_object, GraphQLScalar};
-    use service::{query, read, Query as _};
+    use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+    use service::{domain, query, read, Query as _};
 
     #[cfg(doc)]
     use crate::api::Contract;
@@ -139,16 +258,48 @@ pub mod list {
     use super::{ContractValue, Id};
 
     /// Cursor for the `Contract` list.
-    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
-    #[from(Id, read::contract::list::Cursor)]
+    #[derive(AsRef, Clone, Debug, From, GraphQLScalar, Into)]
     #[graphql(
         name = "ContractListCursor",
         with = scalar::Via::<read::contract::list::Cursor>,
     )]
     pub struct Cursor(pub read::contract::list::Cursor);
 
+    impl From<Id> for Cursor {
+        fn from(id: Id) -> Self {
+            Self(domain::contract::Id::from(id).into())
+        }
+    }
+
+    /// Order of the `Contract` list.
+    #[derive(Clone, Copy, Debug, Default, GraphQLEnum)]
+    #[graphql(name = "ContractListSortBy")]
+    pub enum SortBy {
+        /// Relevance to the searched `name`.
+        ///
+        /// Order is stable, but unspecified, if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// `Contract` name in lexicographical order.
+        Name,
+
+        /// `Contract` creation date in chronological order.
+        CreatedAt,
+    }
+
+    impl From<SortBy> for read::contract::list::SortBy {
+        fn from(sort_by: SortBy) -> Self {
+            match sort_by {
+                SortBy::Relevance => Self::Relevance,
+                SortBy::Name => Self::Name,
+                SortBy::CreatedAt => Self::CreatedAt,
+            }
+        }
+    }
+
     /// Edge in the [`Contract`] list.
-    #[derive(Clone, Copy, Debug, From, Into)]
+    #[derive(Clone, Debug, From, Into)]
     pub struct Edge(read::contract::list::Edge);
 
     /// Edge in the `Contract` list.
@@ -157,28 +308,38 @@ pub mod list {
         /// Cursor of this `ContractListEdge`.
         #[must_use]
         pub fn cursor(&self) -> Cursor {
-            self.0.cursor.into()
+            self.0.cursor.clone().into()
         }
 
         /// Node of this `ContractListEdge`.
         #[must_use]
         pub fn node(&self) -> ContractValue {
-            let (id, kind) = self.0.node;
-
-            #[expect(
-                unsafe_code,
-                reason = "`Edge` loaded from repository guarantees `Contract`\
-                          existence"
-            )]
-            unsafe {
-                ContractValue::new_unchecked(id, kind)
-            }
+            self.0.node.clone().into()
         }
     }
 
     /// Connection of the [`Contract`] list.
-    #[derive(Clone, Debug, From, Into)]
-    pub struct Connection(read::contract::list::Connection);
+    #[derive(Clone, Debug)]
+    pub struct Connection {
+        /// Underlying [`read::contract::list::Connection`].
+        connection: read::contract::list::Connection,
+
+        /// [`read::contract::list::Filter`] the list is selected by.
+        filter: read::contract::list::Filter,
+    }
+
+    impl Connection {
+        /// Creates a new [`Connection`] of the provided
+        /// [`read::contract::list::Connection`] selected by the provided
+        /// [`read::contract::list::Filter`].
+        #[must_use]
+        pub fn new(
+            connection: read::contract::list::Connection,
+            filter: read::contract::list::Filter,
+        ) -> Self {
+            Self { connection, filter }
+        }
+    }
 
     /// Connection of the `Contract` list.
     #[graphql_object(name = "ContractListConnection", context = Context)]
@@ -186,22 +347,24 @@ pub mod list {
         /// Edges in this `ContractListConnection`.
         #[must_use]
         pub fn edges(&self) -> Vec<Edge> {
-            self.0.edges.iter().copied().map(Into::into).collect()
+            self.connection.edges.iter().cloned().map(Into::into).collect()
         }
 
         /// Information about the page.
         #[must_use]
         pub fn page_info(&self) -> PageInfo {
+            let edges = &self.connection.edges;
             PageInfo {
-                info: self.0.page_info(),
-                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
-                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+                info: self.connection.page_info(),
+                start_cursor: edges.first().map(|e| e.cursor.clone().into()),
+                end_cursor: edges.last().map(|e| e.cursor.clone().into()),
+                filter: self.filter.clone(),
             }
         }
     }
 
     /// Information about a [`Connection`] page.
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Debug)]
     pub struct PageInfo {
         /// Underlying [`read::contract::list::PageInfo`].
         info: read::contract::list::PageInfo,
@@ -211,6 +374,9 @@ pub mod list {
 
         /// End cursor of the page.
         end_cursor: Option<Cursor>,
+
+        /// [`read::contract::list::Filter`] the list is selected by.
+        filter: read::contract::list::Filter,
     }
 
     /// Information about a `ContractListConnection` page.
@@ -243,7 +409,7 @@ pub mod list {
         /// Total `Contract`s count.
         pub async fn total_count(&self, ctx: &Context) -> Result<i32, Error> {
             ctx.service()
-                .execute(query::contracts::TotalCount::by(()))
+                .execute(query::contracts::TotalCount::by(self.filter.clone()))
                 .await
                 .map_err(AsError::into_error)
                 .map_err(ctx.error())
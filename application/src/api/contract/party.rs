@@ -0,0 +1,128 @@
+//! [`Party`]-related definitions.
+
+use common::{DateTime, DateTimeOf};
+use derive_more::From;
+use juniper::{graphql_object, GraphQLEnum};
+use service::domain;
+
+use crate::{api, define_error, Context};
+
+/// `User` being a signing party of a `Contract`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Party(domain::contract::Party);
+
+/// `User` being a signing party of a `Contract`.
+#[graphql_object(name = "ContractParty", context = Context)]
+impl Party {
+    /// `User` being this `ContractParty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractParty.user",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn user(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `ContractParty`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.user_id)
+        }
+    }
+
+    /// Role of this `ContractParty` in the `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractParty.role",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn role(&self) -> Role {
+        self.0.role.into()
+    }
+
+    /// Indicates whether this `ContractParty` has signed the `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractParty.isSigned",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn is_signed(&self) -> bool {
+        self.0.is_signed()
+    }
+
+    /// `DateTime` when this `ContractParty` signed the `Contract`.
+    ///
+    /// `null` if not signed yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractParty.signedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn signed_at(&self) -> Option<DateTime> {
+        self.0.signed_at.map(DateTimeOf::coerce)
+    }
+}
+
+/// Role of a `ContractParty` in a `Contract`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "ContractPartyRole")]
+pub enum Role {
+    /// `User` owning the `Realty`.
+    Landlord,
+
+    /// `User` renting or buying the `Realty`.
+    Purchaser,
+
+    /// Employer `User` managing the `Contract`.
+    Employer,
+}
+
+impl From<domain::contract::party::Role> for Role {
+    fn from(role: domain::contract::party::Role) -> Self {
+        use domain::contract::party::Role as R;
+        match role {
+            R::Landlord => Self::Landlord,
+            R::Purchaser => Self::Purchaser,
+            R::Employer => Self::Employer,
+        }
+    }
+}
+
+impl From<Role> for domain::contract::party::Role {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Landlord => Self::Landlord,
+            Role::Purchaser => Self::Purchaser,
+            Role::Employer => Self::Employer,
+        }
+    }
+}
+
+define_error! {
+    enum PartyError {
+        #[code = "CONTRACT_PARTY_ALREADY_EXISTS"]
+        #[status = CONFLICT]
+        #[message = "`User` is already a party of the `Contract` in this role"]
+        AlreadyExists,
+
+        #[code = "CONTRACT_ALREADY_SIGNED"]
+        #[status = CONFLICT]
+        #[message = "`Contract` is already signed by the `User`"]
+        AlreadySigned,
+
+        #[code = "CONTRACT_PARTY_NOT_EXISTS"]
+        #[status = FORBIDDEN]
+        #[message = "`User` is not a party of the `Contract`"]
+        NotParty,
+    }
+}
@@ -10,7 +10,7 @@ use tokio::sync::OnceCell;
 use crate::api::{Contract, Realty, User};
 use crate::{api, AsError, Context, Error};
 
-use super::{ContractValue, Description, Id, Name};
+use super::{ContractValue, Description, Id, Name, Number};
 
 /// [`Contract`] about some [`Realty`] being rented.
 #[derive(Clone, Debug)]
@@ -126,6 +126,18 @@ impl Rent {
         self.id
     }
 
+    /// Human-readable number of this `Contract`, unique within its `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.number",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn number(&self, ctx: &Context) -> Result<Number, Error> {
+        Ok(self.contract(ctx).await?.number.clone().into())
+    }
+
     /// Name of this `Contract`.
     #[tracing::instrument(
         skip_all,
@@ -269,6 +281,21 @@ impl Rent {
         Ok(self.contract(ctx).await?.price)
     }
 
+    /// `price` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.formattedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_price(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self.contract(ctx).await?.price.format(ctx.locale().into()))
+    }
+
     /// Deposit the purchaser was paid.
     #[tracing::instrument(
         skip_all,
@@ -281,6 +308,22 @@ impl Rent {
         Ok(self.contract(ctx).await?.deposit)
     }
 
+    /// `deposit` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.formattedDeposit",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_deposit(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self.contract(ctx).await?.deposit.map(|m| m.format(locale)))
+    }
+
     /// `DateTime` when this `Contract` was created.
     #[tracing::instrument(
         skip_all,
@@ -308,6 +351,25 @@ impl Rent {
         Ok(self.contract(ctx).await?.expires_at.map(DateTimeOf::coerce))
     }
 
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.completedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn completed_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .completed_at
+            .map(DateTimeOf::coerce))
+    }
+
     /// `DateTime` when this `Contract` was terminated.
     #[tracing::instrument(
         skip_all,
@@ -326,4 +388,327 @@ impl Rent {
             .terminated_at
             .map(DateTimeOf::coerce))
     }
+
+    /// `DateTime` when this `Contract` was archived.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.archivedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn archived_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .archived_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// `Offer`s negotiating the terms of this `Contract` in the order of their
+    /// submission, ending with the accepted one.
+    ///
+    /// Empty if this `Contract` was not concluded on an `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.negotiationHistory",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn negotiation_history(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Offer>, Error> {
+        ctx.service()
+            .execute(query::offer::Negotiation::by(read::offer::Negotiation(
+                self.id.into(),
+            )))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|offers| offers.into_iter().map(Into::into).collect())
+    }
+
+    /// `RentPayment`s recorded under this `Contract` in the order of their
+    /// recording.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.payments",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn payments(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Payment>, Error> {
+        ctx.service()
+            .execute(query::payment::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|payments| payments.into_iter().map(Into::into).collect())
+    }
+
+    /// `RentLateFee` charged under this `Contract` for its overdue
+    /// installments.
+    ///
+    /// `null` if no late fees are charged.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.lateFee",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn late_fee(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::late_fee::LateFee>, Error> {
+        ctx.service()
+            .execute(query::late_fee::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|f| f.map(Into::into))
+    }
+
+    /// `RentCharge`s billed under this `Contract`, in the order they were
+    /// added.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.charges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn charges(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Charge>, Error> {
+        ctx.service()
+            .execute(query::charge::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|charges| charges.into_iter().map(Into::into).collect())
+    }
+
+    /// `RentStatement` of the amounts billed to and paid by the purchaser
+    /// `User` under this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.statement",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn statement(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::report::Statement, Error> {
+        ctx.service()
+            .execute(query::report::Statement {
+                contract_id: self.id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::query::ContractError::NotExists.into())
+    }
+
+    /// `RentInventory` checklists recorded under this `Contract`, the
+    /// move-in one first.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.inventories",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn inventories(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Inventory>, Error> {
+        ctx.service()
+            .execute(query::inventory::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|inventories| {
+                inventories.into_iter().map(Into::into).collect()
+            })
+    }
+
+    /// `InventoryDiffReport` comparing the move-in and the move-out
+    /// `RentInventory`s of this `Contract`.
+    ///
+    /// `null` unless both the `RentInventory`s are recorded.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.inventoryDiff",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn inventory_diff(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::report::InventoryDiff>, Error> {
+        ctx.service()
+            .execute(query::report::InventoryDiff {
+                contract_id: self.id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|d| d.map(Into::into))
+    }
+
+    /// Current `RentArrears` of this `Contract`.
+    ///
+    /// `null` if no rent installment is overdue.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.arrears",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn arrears(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::Arrears>, Error> {
+        ctx.service()
+            .execute(query::arrears::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|a| a.map(Into::into))
+    }
+
+    /// `InsurancePolicy` attached to this `Contract`.
+    ///
+    /// `null` if none is attached, or the authenticated `User` is not the
+    /// employer responsible for this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.insurance",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn insurance(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::Insurance>, Error> {
+        let Some(session) = ctx.try_current_session().await? else {
+            return Ok(None);
+        };
+        let my_id: domain::user::Id = session.user_id.into();
+        if self.contract(ctx).await?.employer_id != my_id {
+            return Ok(None);
+        }
+
+        ctx.service()
+            .execute(query::insurance::ByContract::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|i| i.map(Into::into))
+    }
+
+    /// `ContractApproval` of this `Contract`.
+    ///
+    /// `null` if this `Contract` doesn't require approval.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.approval",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn approval(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::contract::approval::Approval>, Error> {
+        ctx.service()
+            .execute(query::contract::Approval::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|a| a.map(Into::into))
+    }
+
+    /// Signing parties of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.parties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn parties(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        ctx.service()
+            .execute(query::contract::Parties::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Parts of the `price` due to the co-owners of the `Realty`.
+    ///
+    /// The whole `price` is due to the `landlord` if no co-owners are
+    /// recorded for the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.ownerSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn owner_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::OwnerSplit>, Error> {
+        let contract = self.contract(ctx).await?;
+        api::realty::OwnerSplit::of_price(
+            contract.realty_id,
+            contract.landlord_id,
+            contract.price,
+            ctx,
+        )
+        .await
+    }
+
+    /// Splits of the commission of this `Contract` between employer `User`s.
+    ///
+    /// Empty if the commission is attributed solely to the signing employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentContract.commissionSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn commission_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::commission::Split>, Error> {
+        ctx.service()
+            .execute(query::contract::CommissionSplits::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.into_iter().map(Into::into).collect())
+    }
 }
@@ -10,7 +10,7 @@ use tokio::sync::OnceCell;
 use crate::api::{Contract, Realty, User};
 use crate::{api, AsError, Context, Error};
 
-use super::{ContractValue, Description, Id, Name};
+use super::{ContractValue, Description, Id, Name, Number};
 
 /// [`Contract`] about some [`Realty`] being sold.
 #[derive(Clone, Debug)]
@@ -125,6 +125,18 @@ impl Sale {
         self.id
     }
 
+    /// Human-readable number of this `Contract`, unique within its `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.number",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn number(&self, ctx: &Context) -> Result<Number, Error> {
+        Ok(self.contract(ctx).await?.number.clone().into())
+    }
+
     /// Name of this `Contract`.
     #[tracing::instrument(
         skip_all,
@@ -268,6 +280,21 @@ impl Sale {
         Ok(self.contract(ctx).await?.price)
     }
 
+    /// `price` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.formattedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_price(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self.contract(ctx).await?.price.format(ctx.locale().into()))
+    }
+
     /// Deposit the purchaser was paid before this `Contract` was signed.
     #[tracing::instrument(
         skip_all,
@@ -280,6 +307,22 @@ impl Sale {
         Ok(self.contract(ctx).await?.deposit)
     }
 
+    /// `deposit` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.formattedDeposit",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_deposit(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self.contract(ctx).await?.deposit.map(|m| m.format(locale)))
+    }
+
     /// `DateTime` when this `Contract` was created.
     #[tracing::instrument(
         skip_all,
@@ -307,6 +350,25 @@ impl Sale {
         Ok(self.contract(ctx).await?.expires_at.map(DateTimeOf::coerce))
     }
 
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.completedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn completed_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .completed_at
+            .map(DateTimeOf::coerce))
+    }
+
     /// `DateTime` when this `Contract` was terminated.
     #[tracing::instrument(
         skip_all,
@@ -325,4 +387,136 @@ impl Sale {
             .terminated_at
             .map(DateTimeOf::coerce))
     }
+
+    /// `DateTime` when this `Contract` was archived.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.archivedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn archived_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .archived_at
+            .map(DateTimeOf::coerce))
+    }
+    /// `Offer`s negotiating the terms of this `Contract` in the order of their
+    /// submission, ending with the accepted one.
+    ///
+    /// Empty if this `Contract` was not concluded on an `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.negotiationHistory",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn negotiation_history(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Offer>, Error> {
+        ctx.service()
+            .execute(query::offer::Negotiation::by(read::offer::Negotiation(
+                self.id.into(),
+            )))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|offers| offers.into_iter().map(Into::into).collect())
+    }
+
+    /// `ContractApproval` of this `Contract`.
+    ///
+    /// `null` if this `Contract` doesn't require approval.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.approval",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn approval(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::contract::approval::Approval>, Error> {
+        ctx.service()
+            .execute(query::contract::Approval::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|a| a.map(Into::into))
+    }
+
+    /// Signing parties of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.parties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn parties(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        ctx.service()
+            .execute(query::contract::Parties::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Parts of the `price` due to the co-owners of the `Realty`.
+    ///
+    /// The whole `price` is due to the `landlord` if no co-owners are
+    /// recorded for the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.ownerSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn owner_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::OwnerSplit>, Error> {
+        let contract = self.contract(ctx).await?;
+        api::realty::OwnerSplit::of_price(
+            contract.realty_id,
+            contract.landlord_id,
+            contract.price,
+            ctx,
+        )
+        .await
+    }
+
+    /// Splits of the commission of this `Contract` between employer `User`s.
+    ///
+    /// Empty if the commission is attributed solely to the signing employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SaleContract.commissionSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn commission_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::commission::Split>, Error> {
+        ctx.service()
+            .execute(query::contract::CommissionSplits::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.into_iter().map(Into::into).collect())
+    }
 }
@@ -0,0 +1,541 @@
+use std::future;
+
+use common::{DateTime, DateTimeOf, Money};
+use futures::TryFutureExt as _;
+use juniper::graphql_object;
+use service::{domain, query, read, Command as _};
+use tokio::sync::OnceCell;
+
+#[cfg(doc)]
+use crate::api::{Contract, Realty, User};
+use crate::{api, AsError, Context, Error};
+
+use super::{ContractValue, Description, Id, Name, Number};
+
+/// [`Contract`] about some [`Realty`] being booked for a few nights.
+#[derive(Clone, Debug)]
+pub struct ShortTermRent {
+    /// ID of this [`Contract`].
+    id: Id,
+
+    /// Underlying [`domain::contract::ShortTermRent`].
+    contract: OnceCell<domain::contract::ShortTermRent>,
+
+    /// Realty this [`Contract`] is about.
+    realty: OnceCell<api::Realty>,
+
+    /// [`User`] who is booking the [`Realty`].
+    purchaser: OnceCell<api::User>,
+
+    /// [`User`] who is renting out the [`Realty`].
+    landlord: OnceCell<api::User>,
+
+    /// [`User`] who is the manager of the [`Realty`] this [`Contract`] is
+    /// about.
+    employer: OnceCell<api::User>,
+}
+
+impl From<domain::contract::ShortTermRent> for ShortTermRent {
+    fn from(contract: domain::contract::ShortTermRent) -> Self {
+        Self {
+            id: contract.id.into(),
+            contract: OnceCell::new_with(Some(contract)),
+            realty: OnceCell::new(),
+            purchaser: OnceCell::new(),
+            landlord: OnceCell::new(),
+            employer: OnceCell::new(),
+        }
+    }
+}
+
+impl From<read::contract::Active<domain::contract::ShortTermRent>>
+    for ShortTermRent
+{
+    fn from(
+        read::contract::Active(c): read::contract::Active<
+            domain::contract::ShortTermRent,
+        >,
+    ) -> Self {
+        c.into()
+    }
+}
+
+impl ShortTermRent {
+    /// Creates a new [`ShortTermRent`] [`Contract`] with the provided ID.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure that provided ID related to existing
+    /// [`domain::contract::ShortTermRent`].
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            contract: OnceCell::new(),
+            realty: OnceCell::new(),
+            purchaser: OnceCell::new(),
+            landlord: OnceCell::new(),
+            employer: OnceCell::new(),
+        }
+    }
+
+    /// Returns [`domain::contract::ShortTermRent`] representing this
+    /// [`ShortTermRent`] [`Contract`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the [`domain::contract::ShortTermRent`] does not
+    /// exist.
+    async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<&domain::contract::ShortTermRent, Error> {
+        self.contract
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::contract::ById::by(self.id.into()))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|c| {
+                        future::ready(match c {
+                            Some(domain::Contract::ShortTermRent(c)) => Ok(c),
+                            _ => {
+                                Err(api::query::ContractError::NotExists.into())
+                            }
+                        })
+                    })
+            })
+            .await
+    }
+}
+
+/// `Contract` about some `Realty` being booked for a few nights.
+#[graphql_object(
+    name = "ShortTermRentContract",
+    context = Context,
+    impl = ContractValue,
+)]
+impl ShortTermRent {
+    /// Unique identifier of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Human-readable number of this `Contract`, unique within its `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.number",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn number(&self, ctx: &Context) -> Result<Number, Error> {
+        Ok(self.contract(ctx).await?.number.clone().into())
+    }
+
+    /// Name of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn name(&self, ctx: &Context) -> Result<Name, Error> {
+        Ok(self.contract(ctx).await?.name.clone().into())
+    }
+
+    /// Description of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.description",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn description(
+        &self,
+        ctx: &Context,
+    ) -> Result<Description, Error> {
+        Ok(self.contract(ctx).await?.description.clone().into())
+    }
+
+    /// `Realty` being booked.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.realty",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn realty(&self, ctx: &Context) -> Result<&api::Realty, Error> {
+        let id = self.contract(ctx).await?.realty_id;
+        self.realty
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::realty::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|u| {
+                        future::ready(u.map_or_else(
+                            || Err(api::query::RealtyError::NotExists.into()),
+                            |u| Ok(u.into()),
+                        ))
+                    })
+            })
+            .await
+    }
+
+    /// `User` who is booking the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.purchaser",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn purchaser(&self, ctx: &Context) -> Result<&api::User, Error> {
+        let id = self.contract(ctx).await?.purchaser_id;
+        self.purchaser
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::user::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|u| {
+                        future::ready(u.map_or_else(
+                            || Err(api::query::UserError::NotExists.into()),
+                            |u| Ok(u.into()),
+                        ))
+                    })
+            })
+            .await
+    }
+
+    /// `User` who is renting out the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.landlord",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn landlord(&self, ctx: &Context) -> Result<&api::User, Error> {
+        let id = self.contract(ctx).await?.landlord_id;
+        self.landlord
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::user::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|u| {
+                        future::ready(u.map_or_else(
+                            || Err(api::query::UserError::NotExists.into()),
+                            |u| Ok(u.into()),
+                        ))
+                    })
+            })
+            .await
+    }
+
+    /// `User` who is the manager of the `Realty` this `Contract` is about.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.employer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn employer(&self, ctx: &Context) -> Result<&api::User, Error> {
+        let id = self.contract(ctx).await?.employer_id;
+        self.employer
+            .get_or_try_init(|| {
+                ctx.service()
+                    .execute(query::user::ById::by(id))
+                    .map_err(AsError::into_error)
+                    .map_err(ctx.error())
+                    .and_then(|u| {
+                        future::ready(u.map_or_else(
+                            || Err(api::query::UserError::NotExists.into()),
+                            |u| Ok(u.into()),
+                        ))
+                    })
+            })
+            .await
+    }
+
+    /// Base nightly rate, applied to the nights not covered by any
+    /// `RealtySeasonalRate`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.nightlyRate",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn nightly_rate(&self, ctx: &Context) -> Result<Money, Error> {
+        Ok(self.contract(ctx).await?.nightly_rate)
+    }
+
+    /// `nightlyRate` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.formattedNightlyRate",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_nightly_rate(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .nightly_rate
+            .format(ctx.locale().into()))
+    }
+
+    /// Total price of the booked nights.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.price",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn price(&self, ctx: &Context) -> Result<Money, Error> {
+        Ok(self.contract(ctx).await?.price)
+    }
+
+    /// `price` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.formattedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_price(
+        &self,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        Ok(self.contract(ctx).await?.price.format(ctx.locale().into()))
+    }
+
+    /// Deposit the purchaser was paid.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.deposit",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn deposit(&self, ctx: &Context) -> Result<Option<Money>, Error> {
+        Ok(self.contract(ctx).await?.deposit)
+    }
+
+    /// `deposit` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.formattedDeposit",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn formatted_deposit(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<String>, Error> {
+        let locale = ctx.locale().into();
+        Ok(self.contract(ctx).await?.deposit.map(|m| m.format(locale)))
+    }
+
+    /// `DateTime` when the purchaser checks in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.checkInAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn check_in_at(&self, ctx: &Context) -> Result<DateTime, Error> {
+        Ok(self.contract(ctx).await?.check_in_at.coerce())
+    }
+
+    /// Number of the booked nights.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.nights",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn nights(&self, ctx: &Context) -> Result<i32, Error> {
+        let c = self.contract(ctx).await?;
+        let nights = domain::contract::ShortTermRent::nights(
+            c.check_in_at.coerce(),
+            c.expires_at.coerce(),
+        );
+        Ok(i32::try_from(nights).unwrap_or(i32::MAX))
+    }
+
+    /// `DateTime` when this `Contract` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn created_at(&self, ctx: &Context) -> Result<DateTime, Error> {
+        Ok(self.contract(ctx).await?.created_at.coerce())
+    }
+
+    /// `DateTime` when the purchaser checks out, so this `Contract` expires.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.expiresAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn expires_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(Some(self.contract(ctx).await?.expires_at.coerce()))
+    }
+
+    /// `DateTime` when this `Contract` was completed due to its expiration.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.completedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn completed_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .completed_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// `DateTime` when this `Contract` was terminated.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.terminatedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn terminated_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .terminated_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// `DateTime` when this `Contract` was archived.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.archivedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn archived_at(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<DateTime>, Error> {
+        Ok(self
+            .contract(ctx)
+            .await?
+            .archived_at
+            .map(DateTimeOf::coerce))
+    }
+
+    /// Signing parties of this `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.parties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn parties(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        ctx.service()
+            .execute(query::contract::Parties::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Parts of the `price` due to the co-owners of the `Realty`.
+    ///
+    /// The whole `price` is due to the `landlord` if no co-owners are
+    /// recorded for the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.ownerSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn owner_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::OwnerSplit>, Error> {
+        let contract = self.contract(ctx).await?;
+        api::realty::OwnerSplit::of_price(
+            contract.realty_id,
+            contract.landlord_id,
+            contract.price,
+            ctx,
+        )
+        .await
+    }
+
+    /// Splits of the commission of this `Contract` between employer `User`s.
+    ///
+    /// Empty if the commission is attributed solely to the signing employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ShortTermRentContract.commissionSplits",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn commission_splits(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::commission::Split>, Error> {
+        ctx.service()
+            .execute(query::contract::CommissionSplits::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,132 @@
+//! [`Template`]-related definitions.
+
+use common::DateTime;
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, Context};
+
+/// Template of a `Contract` name and description.
+#[derive(Clone, Debug, From)]
+pub struct Template(domain::contract::Template);
+
+/// Template of a `Contract` name and description.
+///
+/// Supports the following placeholders, substituted with the `Contract`
+/// details on rendering:
+/// - `{realty.address}` - address of the `Realty`;
+/// - `{realty.city}` - city of the `Realty`;
+/// - `{landlord.name}` - name of the landlord `User`;
+/// - `{purchaser.name}` - name of the purchaser `User`;
+/// - `{employer.name}` - name of the employer `User`;
+/// - `{price}` - price of the `Contract`.
+///
+/// Literal braces are escaped by doubling them: `{{` and `}}`.
+#[graphql_object(name = "ContractTemplate", context = Context)]
+impl Template {
+    /// Unique identifier of this `ContractTemplate`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractTemplate.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// Template of the `Contract` name.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractTemplate.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn name(&self) -> Text {
+        self.0.name.clone().into()
+    }
+
+    /// Template of the `Contract` description.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractTemplate.description",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn description(&self) -> Text {
+        self.0.description.clone().into()
+    }
+
+    /// Employer `User` who saved this `ContractTemplate` last.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractTemplate.creator",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn creator(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `ContractTemplate`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.creator_id)
+        }
+    }
+
+    /// `DateTime` when this `ContractTemplate` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ContractTemplate.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of a `ContractTemplate`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::contract::template::Id)]
+#[into(domain::contract::template::Id)]
+#[graphql(name = "ContractTemplateId", transparent)]
+pub struct Id(Uuid);
+
+/// Text of a `ContractTemplate` with `{placeholder}`s.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "ContractTemplateText",
+    with = scalar::Via::<domain::contract::template::Text>,
+)]
+pub struct Text(domain::contract::template::Text);
+
+define_error! {
+    enum TemplateError {
+        #[code = "CONTRACT_TEMPLATE_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`ContractTemplate` with the provided ID does not exist"]
+        NotExists,
+
+        #[code = "CONTRACT_TEMPLATE_NOT_RENDERABLE"]
+        #[status = BAD_REQUEST]
+        #[message = "`ContractTemplate` renders invalid `Contract` name or \
+                     description"]
+        NotRenderable,
+
+        #[code = "CONTRACT_TEMPLATE_REQUIRED"]
+        #[status = BAD_REQUEST]
+        #[message = "Either `Contract` name and description or \
+                     `ContractTemplate` must be provided"]
+        Required,
+    }
+}
@@ -0,0 +1,288 @@
+//! Deprecations of the GraphQL schema and tracking of their usage.
+//!
+//! To deprecate a field, mark it with a `#[graphql(deprecated = "...")]`
+//! attribute having the same reason as its [`Deprecation`] listed in the
+//! [`DEPRECATIONS`] (tests check them to match). Usages of the listed fields
+//! are recorded by the [`UsageLayer`] per client (see [`CLIENT_NAME_HEADER`]),
+//! so the field may be removed once no clients use it, or its
+//! [`Deprecation::sunset`] passes.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Mutex, PoisonError},
+};
+
+use common::DateTime;
+use tracing::{
+    field::{Field, Visit},
+    span, Subscriber,
+};
+use tracing_subscriber::{layer, registry::LookupSpan, Layer};
+
+/// Name of the HTTP header identifying the client application making the
+/// request.
+///
+/// `User-Agent` header is used to identify clients not providing it.
+pub const CLIENT_NAME_HEADER: &str = "x-client-name";
+
+/// Deprecation of a GraphQL field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Deprecation {
+    /// Coordinate of the deprecated field in the `Type.field` form (e.g.
+    /// `Query.users` or `User.name`).
+    pub field: &'static str,
+
+    /// Reason of the deprecation, suggesting a replacement, if any.
+    pub reason: &'static str,
+
+    /// [RFC 3339] date (`YYYY-MM-DD`) after which the deprecated field may be
+    /// removed from the schema.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub sunset: &'static str,
+}
+
+impl Deprecation {
+    /// Returns the [`DateTime`] of this [`Deprecation::sunset`], or [`None`]
+    /// if it's malformed.
+    #[must_use]
+    pub fn sunset_at(&self) -> Option<DateTime> {
+        DateTime::from_rfc3339(&format!("{}T00:00:00Z", self.sunset)).ok()
+    }
+}
+
+/// All the [`Deprecation`]s of the GraphQL schema.
+pub const DEPRECATIONS: &[Deprecation] = &[Deprecation {
+    field: "ContractApproval.isPending",
+    reason: "Use `approvedAt`, which is `null` while pending",
+    sunset: "2027-04-16",
+}];
+
+/// Returns the [`Deprecation`] of the GraphQL field with the provided
+/// coordinate, if it's deprecated.
+#[must_use]
+pub fn find(field: &str) -> Option<&'static Deprecation> {
+    DEPRECATIONS.iter().find(|d| d.field == field)
+}
+
+/// Maximum number of the distinct clients recorded for every deprecated
+/// field, so the arbitrary client names cannot exhaust memory.
+///
+/// Clients beyond this limit are recorded as [`OTHER_CLIENT`].
+const MAX_CLIENTS: usize = 100;
+
+/// Maximum length of a recorded client name.
+const MAX_CLIENT_NAME_LEN: usize = 64;
+
+/// Client name of the requests not identifying their client.
+const UNKNOWN_CLIENT: &str = "unknown";
+
+/// Client name of the clients not fitting into the [`MAX_CLIENTS`].
+const OTHER_CLIENT: &str = "other";
+
+/// Numbers of the deprecated fields resolutions, keyed by the fields
+/// coordinates and the client names.
+static USAGES: Mutex<BTreeMap<&'static str, BTreeMap<String, u64>>> =
+    Mutex::new(BTreeMap::new());
+
+/// Usage of a deprecated GraphQL field by a client.
+#[derive(Clone, Debug)]
+pub struct Usage {
+    /// [`Deprecation`] of the used field.
+    pub deprecation: &'static Deprecation,
+
+    /// Name of the client using the field.
+    pub client: String,
+
+    /// Number of the field resolutions by the client.
+    pub count: u64,
+}
+
+impl Usage {
+    /// Returns all the [`Usage`]s recorded by the [`UsageLayer`] since the
+    /// process start.
+    #[must_use]
+    pub fn collected() -> Vec<Self> {
+        let usages = USAGES.lock().unwrap_or_else(PoisonError::into_inner);
+        usages
+            .iter()
+            .filter_map(|(field, clients)| Some((find(field)?, clients)))
+            .flat_map(|(deprecation, clients)| {
+                clients.iter().map(|(client, &count)| Self {
+                    deprecation,
+                    client: client.clone(),
+                    count,
+                })
+            })
+            .collect()
+    }
+
+    /// Records a resolution of the deprecated field by the provided `client`.
+    fn record(deprecation: &'static Deprecation, client: &str) {
+        let client = client
+            .chars()
+            .filter(|c| c.is_ascii_graphic() && !matches!(c, '"' | '\\'))
+            .take(MAX_CLIENT_NAME_LEN)
+            .collect::<String>();
+
+        let mut usages = USAGES.lock().unwrap_or_else(PoisonError::into_inner);
+        let clients = usages.entry(deprecation.field).or_default();
+        let client =
+            if clients.contains_key(&client) || clients.len() < MAX_CLIENTS {
+                client
+            } else {
+                OTHER_CLIENT.to_owned()
+            };
+        *clients.entry(client).or_default() += 1;
+    }
+}
+
+/// [`Layer`] recording [`Usage`]s of the deprecated GraphQL fields by the
+/// [`tracing::Span`]s of their resolvers.
+///
+/// Resolvers are recognized by their `gql.name` field, while clients are
+/// recognized by the `http.client_name` field of the HTTP request
+/// [`tracing::Span`] they're resolved within.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UsageLayer;
+
+impl<S> Layer<S> for UsageLayer
+where
+    S: Subscriber + for<'l> LookupSpan<'l>,
+{
+    fn on_new_span(
+        &self,
+        attrs: &span::Attributes<'_>,
+        id: &span::Id,
+        ctx: layer::Context<'_, S>,
+    ) {
+        let fields = attrs.metadata().fields();
+        if fields.field("gql.name").is_none()
+            && fields.field("http.client_name").is_none()
+        {
+            return;
+        }
+
+        let mut visitor = SpanFields::default();
+        attrs.record(&mut visitor);
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+
+        if let Some(client) = visitor.client {
+            span.extensions_mut().insert(Client(client));
+            return;
+        }
+        let Some(deprecation) = visitor.coordinate().as_deref().and_then(find)
+        else {
+            return;
+        };
+
+        let client = span.scope().skip(1).find_map(|s| {
+            s.extensions().get::<Client>().map(|Client(c)| c.clone())
+        });
+        Usage::record(deprecation, client.as_deref().unwrap_or(UNKNOWN_CLIENT));
+    }
+}
+
+/// Name of the client stored in the extensions of an HTTP request
+/// [`tracing::Span`].
+#[derive(Clone, Debug)]
+struct Client(String);
+
+/// [`Visit`]or of the [`tracing::Span`] fields relevant to the [`UsageLayer`].
+#[derive(Debug, Default)]
+struct SpanFields {
+    /// `gql.name` field of a resolver [`tracing::Span`].
+    gql: Option<String>,
+
+    /// `otel.name` field of a resolver [`tracing::Span`].
+    otel: Option<String>,
+
+    /// `http.client_name` field of an HTTP request [`tracing::Span`].
+    client: Option<String>,
+}
+
+impl SpanFields {
+    /// Returns the coordinate of the resolved GraphQL field, if any.
+    ///
+    /// Fields of the root types are named without their type.
+    fn coordinate(&self) -> Option<String> {
+        let name = self.gql.as_deref()?;
+        Some(if name.contains('.') {
+            name.to_owned()
+        } else if self.otel.as_deref() == Some(super::Mutation::SPAN_NAME) {
+            format!("Mutation.{name}")
+        } else {
+            format!("Query.{name}")
+        })
+    }
+}
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "gql.name" => self.gql = Some(value.to_owned()),
+            "otel.name" => self.otel = Some(value.to_owned()),
+            "http.client_name" => self.client = Some(value.to_owned()),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, _: &Field, _: &dyn fmt::Debug) {}
+}
+
+#[cfg(test)]
+mod spec {
+    use juniper::meta::{InterfaceMeta, MetaType, ObjectMeta};
+
+    use crate::api;
+
+    use super::DEPRECATIONS;
+
+    #[test]
+    fn registry_matches_schema() {
+        let schema =
+            api::Schema::new(api::Query, api::Mutation, api::Subscription);
+        let mut deprecated = schema
+            .schema
+            .concrete_type_list()
+            .into_iter()
+            .filter_map(|t| match t {
+                MetaType::Object(ObjectMeta { name, fields, .. })
+                | MetaType::Interface(InterfaceMeta { name, fields, .. }) => {
+                    Some((name, fields))
+                }
+                _ => None,
+            })
+            .filter(|(name, _)| !name.starts_with("__"))
+            .flat_map(|(name, fields)| {
+                fields
+                    .iter()
+                    .filter(|f| f.deprecation_status.is_deprecated())
+                    .map(move |f| {
+                        let reason = f.deprecation_status.reason();
+                        let reason = reason.map(ToString::to_string);
+                        (format!("{name}.{}", f.name), reason)
+                    })
+            })
+            .collect::<Vec<(String, Option<String>)>>();
+        deprecated.sort();
+
+        let mut registered = DEPRECATIONS
+            .iter()
+            .map(|d| (d.field.to_owned(), Some(d.reason.to_owned())))
+            .collect::<Vec<_>>();
+        registered.sort();
+
+        assert_eq!(deprecated, registered);
+    }
+
+    #[test]
+    fn sunsets_are_well_formed() {
+        for d in DEPRECATIONS {
+            assert!(d.sunset_at().is_some(), "malformed sunset: {d:?}");
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Financial calculations definitions.
+
+use common::{
+    money::{self, Locale},
+    Money,
+};
+use juniper::{GraphQLEnum, GraphQLObject};
+use service::query;
+
+use crate::{define_error, AsError, Context, Error};
+
+/// Calculated annuity mortgage.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(context = Context)]
+pub struct Mortgage {
+    /// Regular monthly payment.
+    ///
+    /// The last `MortgageInstallment` may slightly differ due to rounding.
+    pub monthly_payment: Money,
+
+    /// `monthlyPayment` formatted according to the `Accept-Language` header.
+    pub formatted_monthly_payment: String,
+
+    /// Total amount paid over the whole term.
+    pub total_payment: Money,
+
+    /// Total interest paid over the whole term.
+    pub total_interest: Money,
+
+    /// Amortization schedule, one `MortgageInstallment` per month.
+    pub schedule: Vec<Installment>,
+}
+
+impl Mortgage {
+    /// Creates a new [`Mortgage`] out of the provided
+    /// [`query::finance::mortgage::Output`], formatting it according to the
+    /// provided [`Locale`].
+    #[must_use]
+    pub fn new(
+        output: query::finance::mortgage::Output,
+        locale: Locale,
+    ) -> Self {
+        let query::finance::mortgage::Output {
+            monthly_payment,
+            total_payment,
+            total_interest,
+            schedule,
+        } = output;
+        Self {
+            monthly_payment,
+            formatted_monthly_payment: monthly_payment.format(locale),
+            total_payment,
+            total_interest,
+            schedule: schedule.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Monthly installment of a `Mortgage`.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "MortgageInstallment", context = Context)]
+pub struct Installment {
+    /// Number of the month, starting from `1`.
+    pub month: i32,
+
+    /// Amount paid in this month.
+    pub payment: Money,
+
+    /// Part of the `payment` repaying the principal.
+    pub principal: Money,
+
+    /// Part of the `payment` paying the interest.
+    pub interest: Money,
+
+    /// Principal remaining after this `MortgageInstallment`.
+    pub balance: Money,
+}
+
+impl From<query::finance::mortgage::Installment> for Installment {
+    fn from(i: query::finance::mortgage::Installment) -> Self {
+        Self {
+            month: i.month.into(),
+            payment: i.payment,
+            principal: i.principal,
+            interest: i.interest,
+            balance: i.balance,
+        }
+    }
+}
+
+/// Currency of a `Money` amount.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+pub enum Currency {
+    /// US Dollar.
+    Usd,
+
+    /// Euro.
+    Eur,
+
+    /// Russian Ruble.
+    Rub,
+}
+
+impl From<money::Currency> for Currency {
+    fn from(currency: money::Currency) -> Self {
+        match currency {
+            money::Currency::Usd => Self::Usd,
+            money::Currency::Eur => Self::Eur,
+            money::Currency::Rub => Self::Rub,
+        }
+    }
+}
+
+impl From<Currency> for money::Currency {
+    fn from(currency: Currency) -> Self {
+        match currency {
+            Currency::Usd => Self::Usd,
+            Currency::Eur => Self::Eur,
+            Currency::Rub => Self::Rub,
+        }
+    }
+}
+
+define_error! {
+    enum MortgageError {
+        #[code = "INVALID_MORTGAGE_TERM"]
+        #[status = BAD_REQUEST]
+        #[message = "`Mortgage` term must be from 1 to 600 months"]
+        InvalidTerm,
+
+        #[code = "INVALID_DOWN_PAYMENT"]
+        #[status = BAD_REQUEST]
+        #[message = "Down payment must be non-negative, in the currency of \
+                     the price and not exceed it"]
+        InvalidDownPayment,
+
+        #[code = "MORTGAGE_OUT_OF_RANGE"]
+        #[status = BAD_REQUEST]
+        #[message = "`Mortgage` amounts are too large to be calculated"]
+        OutOfRange,
+    }
+}
+
+impl AsError for query::finance::mortgage::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::NegativePrincipal => MortgageError::InvalidDownPayment.into(),
+            Self::TermOutOfRange(_) => MortgageError::InvalidTerm.into(),
+            Self::Overflow => MortgageError::OutOfRange.into(),
+        })
+    }
+}
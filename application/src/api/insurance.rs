@@ -0,0 +1,201 @@
+//! [`Insurance`]-related definitions.
+
+use common::{DateTime, DateTimeOf, Handler as _, Money};
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLScalar};
+use service::{domain, query};
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
+
+/// Insurance policy covering a `Realty` under a `Contract`.
+#[derive(Clone, Debug, From)]
+pub struct Insurance(domain::Insurance);
+
+/// Insurance policy covering a `Realty` under a `Contract`.
+#[graphql_object(name = "InsurancePolicy", context = Context)]
+impl Insurance {
+    /// Unique identifier of this `InsurancePolicy`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `Contract` this `InsurancePolicy` is attached to.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        ctx.service()
+            .execute(query::contract::ById::by(self.0.contract_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::query::ContractError::NotExists.into())
+    }
+
+    /// Company provided this `InsurancePolicy`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.provider",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn provider(&self) -> Provider {
+        self.0.provider.clone().into()
+    }
+
+    /// Number of this `InsurancePolicy`, assigned by its `provider`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.policyNumber",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn policy_number(&self) -> PolicyNumber {
+        self.0.policy_number.clone().into()
+    }
+
+    /// Maximum amount covered by this `InsurancePolicy`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.coverage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn coverage(&self) -> Money {
+        self.0.coverage
+    }
+
+    /// `coverage` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.formattedCoverage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_coverage(&self, ctx: &Context) -> String {
+        self.0.coverage.format(ctx.locale().into())
+    }
+
+    /// `DateTime` when this `InsurancePolicy` expires.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.expiresAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn expires_at(&self) -> DateTime {
+        self.0.expires_at.coerce()
+    }
+
+    /// Indicates whether this `InsurancePolicy` is already expired.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.isExpired",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn is_expired(&self) -> bool {
+        self.0.is_expired(DateTime::now())
+    }
+
+    /// Employer `User` who attached this `InsurancePolicy`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.creator",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn creator(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `InsurancePolicy`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.creator_id)
+        }
+    }
+
+    /// `DateTime` when this `InsurancePolicy` was attached.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+
+    /// `DateTime` when the responsible employer `User` was notified about
+    /// this `InsurancePolicy` expiring.
+    ///
+    /// `null` if not notified yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InsurancePolicy.notifiedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn notified_at(&self) -> Option<DateTime> {
+        self.0.notified_at.map(DateTimeOf::coerce)
+    }
+}
+
+/// Unique identifier of an `InsurancePolicy`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::insurance::Id)]
+#[into(domain::insurance::Id)]
+#[graphql(name = "InsurancePolicyId", transparent)]
+pub struct Id(Uuid);
+
+/// Name of a company providing an `InsurancePolicy`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "InsuranceProvider",
+    with = scalar::Via::<domain::insurance::Provider>,
+)]
+pub struct Provider(domain::insurance::Provider);
+
+/// Number of an `InsurancePolicy`, assigned by its provider.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "InsurancePolicyNumber",
+    with = scalar::Via::<domain::insurance::PolicyNumber>,
+)]
+pub struct PolicyNumber(domain::insurance::PolicyNumber);
+
+define_error! {
+    enum InsuranceError {
+        #[code = "INSURANCE_POLICY_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Contract` has no `InsurancePolicy` attached"]
+        NotExists,
+    }
+}
@@ -0,0 +1,307 @@
+//! [`Inventory`]-related definitions.
+
+use common::DateTime;
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLInputObject, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, Context};
+
+/// Checklist of the items of a `Realty` rented under a rent `Contract`,
+/// recorded at a move-in or a move-out.
+#[derive(Clone, Debug, From)]
+pub struct Inventory(domain::Inventory);
+
+/// Checklist of the items of a `Realty` rented under a rent `Contract`,
+/// recorded at a move-in or a move-out.
+#[graphql_object(name = "RentInventory", context = Context)]
+impl Inventory {
+    /// Unique identifier of this `RentInventory`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `RentContract` this `RentInventory` is recorded under.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn contract(&self) -> api::contract::Rent {
+        // SAFETY: `Contract` is referenced by an existing `RentInventory`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::contract::Rent::new_unchecked(self.0.contract_id)
+        }
+    }
+
+    /// Stage this `RentInventory` is recorded at.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.stage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn stage(&self) -> Stage {
+        self.0.stage.into()
+    }
+
+    /// Items of this `RentInventory`, in the order they were listed.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.items",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn items(&self) -> Vec<Item> {
+        self.0.items.iter().cloned().map(Item).collect()
+    }
+
+    /// Employer `User` who recorded this `RentInventory`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.recorder",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn recorder(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `RentInventory`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.recorder_id)
+        }
+    }
+
+    /// `DateTime` when this `RentInventory` was recorded.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventory.recordedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn recorded_at(&self) -> DateTime {
+        self.0.recorded_at.coerce()
+    }
+}
+
+/// Item of an [`Inventory`].
+#[derive(Clone, Debug)]
+pub struct Item(domain::inventory::Item);
+
+/// Item of a `RentInventory`.
+#[graphql_object(name = "RentInventoryItem", context = Context)]
+impl Item {
+    /// Room this item is located in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventoryItem.room",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn room(&self) -> Room {
+        self.0.room.clone().into()
+    }
+
+    /// Name of this item.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventoryItem.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn name(&self) -> ItemName {
+        self.0.name.clone().into()
+    }
+
+    /// Condition of this item.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventoryItem.condition",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn condition(&self) -> Condition {
+        self.0.condition.into()
+    }
+
+    /// Photos of this item.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentInventoryItem.photos",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn photos(&self) -> Vec<Photo> {
+        self.0.photos.iter().cloned().map(Into::into).collect()
+    }
+}
+
+/// Item of a `RentInventory` to be recorded.
+#[derive(Clone, Debug, GraphQLInputObject)]
+#[graphql(name = "RentInventoryItemInput")]
+pub struct ItemInput {
+    /// Room the item is located in.
+    pub room: Room,
+
+    /// Name of the item.
+    pub name: ItemName,
+
+    /// Condition of the item.
+    pub condition: Condition,
+
+    /// Photos of the item, if any.
+    pub photos: Option<Vec<Photo>>,
+}
+
+impl From<ItemInput> for domain::inventory::Item {
+    fn from(item: ItemInput) -> Self {
+        Self {
+            room: item.room.into(),
+            name: item.name.into(),
+            condition: item.condition.into(),
+            photos: item
+                .photos
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// Unique identifier of a `RentInventory`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::inventory::Id)]
+#[into(domain::inventory::Id)]
+#[graphql(name = "RentInventoryId", transparent)]
+pub struct Id(Uuid);
+
+/// Room of a `Realty` an item of a `RentInventory` is located in.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RentInventoryRoom",
+    with = scalar::Via::<domain::inventory::Room>,
+)]
+pub struct Room(domain::inventory::Room);
+
+/// Name of an item of a `RentInventory`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RentInventoryItemName",
+    with = scalar::Via::<domain::inventory::ItemName>,
+)]
+pub struct ItemName(domain::inventory::ItemName);
+
+/// HTTP(S) URL of a photo of an item of a `RentInventory`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RentInventoryPhoto",
+    with = scalar::Via::<domain::inventory::Photo>,
+)]
+pub struct Photo(domain::inventory::Photo);
+
+/// Stage of a rent `Contract` a `RentInventory` is recorded at.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RentInventoryStage")]
+pub enum Stage {
+    /// Purchaser `User` moves into the `Realty`.
+    MoveIn,
+
+    /// Purchaser `User` moves out of the `Realty`.
+    MoveOut,
+}
+
+impl From<domain::inventory::Stage> for Stage {
+    fn from(stage: domain::inventory::Stage) -> Self {
+        use domain::inventory::Stage as S;
+        match stage {
+            S::MoveIn => Self::MoveIn,
+            S::MoveOut => Self::MoveOut,
+        }
+    }
+}
+
+impl From<Stage> for domain::inventory::Stage {
+    fn from(stage: Stage) -> Self {
+        match stage {
+            Stage::MoveIn => Self::MoveIn,
+            Stage::MoveOut => Self::MoveOut,
+        }
+    }
+}
+
+/// Condition of an item of a `RentInventory`, from the best to the worst.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RentInventoryCondition")]
+pub enum Condition {
+    /// Item is new or as new.
+    Excellent,
+
+    /// Item has minor signs of use.
+    Good,
+
+    /// Item has noticeable wear.
+    Fair,
+
+    /// Item is heavily worn.
+    Poor,
+
+    /// Item is damaged.
+    Damaged,
+
+    /// Item is missing.
+    Missing,
+}
+
+impl From<domain::inventory::Condition> for Condition {
+    fn from(condition: domain::inventory::Condition) -> Self {
+        use domain::inventory::Condition as C;
+        match condition {
+            C::Excellent => Self::Excellent,
+            C::Good => Self::Good,
+            C::Fair => Self::Fair,
+            C::Poor => Self::Poor,
+            C::Damaged => Self::Damaged,
+            C::Missing => Self::Missing,
+        }
+    }
+}
+
+impl From<Condition> for domain::inventory::Condition {
+    fn from(condition: Condition) -> Self {
+        match condition {
+            Condition::Excellent => Self::Excellent,
+            Condition::Good => Self::Good,
+            Condition::Fair => Self::Fair,
+            Condition::Poor => Self::Poor,
+            Condition::Damaged => Self::Damaged,
+            Condition::Missing => Self::Missing,
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! [`Key`]-related definitions.
+
+use common::DateTime;
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, Context};
+
+/// Physical key of a `Realty` managed by an `Agency`.
+#[derive(Clone, Debug, From)]
+pub struct Key(domain::Key);
+
+/// Physical key of a `Realty` managed by an `Agency`.
+#[graphql_object(name = "RealtyKey", context = Context)]
+impl Key {
+    /// Unique identifier of this `RealtyKey`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `Realty` this `RealtyKey` opens.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.realty",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn realty(&self) -> api::Realty {
+        // SAFETY: `Realty` is referenced by an existing `RealtyKey`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::Realty::new_unchecked(self.0.realty_id)
+        }
+    }
+
+    /// Label of this `RealtyKey`, unique among the `RealtyKey`s of the same
+    /// `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.label",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn label(&self) -> Label {
+        self.0.label.clone().into()
+    }
+
+    /// `User` currently holding this `RealtyKey`.
+    ///
+    /// `null` if this `RealtyKey` is checked in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.holder",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn holder(&self) -> Option<api::User> {
+        self.0.checkout.map(|c| {
+            // SAFETY: `User` is referenced by an existing `RealtyKey`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(c.holder_id)
+            }
+        })
+    }
+
+    /// Employer `User` who checked out this `RealtyKey`.
+    ///
+    /// `null` if this `RealtyKey` is checked in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.issuer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn issuer(&self) -> Option<api::User> {
+        self.0.checkout.map(|c| {
+            // SAFETY: `User` is referenced by an existing `RealtyKey`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(c.issuer_id)
+            }
+        })
+    }
+
+    /// `DateTime` when this `RealtyKey` was checked out.
+    ///
+    /// `null` if this `RealtyKey` is checked in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.checkedOutAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn checked_out_at(&self) -> Option<DateTime> {
+        self.0.checkout.map(|c| c.checked_out_at.coerce())
+    }
+
+    /// `DateTime` when this `RealtyKey` is due to be returned.
+    ///
+    /// `null` if this `RealtyKey` is checked in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.dueAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn due_at(&self) -> Option<DateTime> {
+        self.0.checkout.map(|c| c.due_at.coerce())
+    }
+
+    /// Indicates whether this `RealtyKey` is checked out and not returned by
+    /// its `dueAt`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.isOverdue",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn is_overdue(&self) -> bool {
+        self.0.is_overdue(DateTime::now())
+    }
+
+    /// `DateTime` when this `RealtyKey` was registered.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyKey.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of a `RealtyKey`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::key::Id)]
+#[into(domain::key::Id)]
+#[graphql(name = "RealtyKeyId", transparent)]
+pub struct Id(Uuid);
+
+/// Label of a `RealtyKey`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RealtyKeyLabel",
+    with = scalar::Via::<domain::key::Label>,
+)]
+pub struct Label(domain::key::Label);
+
+define_error! {
+    enum KeyError {
+        #[code = "REALTY_KEY_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`RealtyKey` with the specified ID does not exist"]
+        NotExists,
+    }
+}
@@ -0,0 +1,134 @@
+//! [`LateFee`]-related definitions.
+
+use common::{DateTime, DateTimeOf, Money, Percent};
+use derive_more::From;
+use juniper::graphql_object;
+use service::domain::{self, late_fee::Charge};
+
+use crate::{api, define_error, Context};
+
+/// Late fee charged under a rent `Contract` for its overdue installments.
+#[derive(Clone, Copy, Debug, From)]
+pub struct LateFee(domain::LateFee);
+
+/// Late fee charged under a rent `Contract` for its overdue installments.
+#[graphql_object(name = "RentLateFee", context = Context)]
+impl LateFee {
+    /// Fixed amount charged per each day an installment is overdue.
+    ///
+    /// `null` if the `percentPerDay` is charged instead.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.perDay",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn per_day(&self) -> Option<Money> {
+        match self.0.policy.charge {
+            Charge::Fixed(fee) => Some(fee),
+            Charge::Percent(_) => None,
+        }
+    }
+
+    /// Percent of the overdue rent amount charged per each day an
+    /// installment is overdue.
+    ///
+    /// `null` if the fixed `perDay` amount is charged instead.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.percentPerDay",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn percent_per_day(&self) -> Option<Percent> {
+        match self.0.policy.charge {
+            Charge::Fixed(_) => None,
+            Charge::Percent(p) => Some(p),
+        }
+    }
+
+    /// Maximum total amount of this `RentLateFee`, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.cap",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn cap(&self) -> Option<Money> {
+        self.0.policy.cap
+    }
+
+    /// Total amount accrued so far.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.accrued",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn accrued(&self) -> Money {
+        self.0.accrued
+    }
+
+    /// `accrued` amount formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.formattedAccrued",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_accrued(&self, ctx: &Context) -> String {
+        self.0.accrued.format(ctx.locale().into())
+    }
+
+    /// `DateTime` until which this `RentLateFee` is accrued.
+    ///
+    /// `null` if nothing was accrued yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentLateFee.accruedUntil",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn accrued_until(&self) -> Option<DateTime> {
+        self.0.accrued_until.map(DateTimeOf::coerce)
+    }
+}
+
+/// Builds a [`domain::late_fee::Policy`] out of the provided optional
+/// arguments.
+///
+/// # Errors
+///
+/// If both of the `per_day` and the `percent_per_day` are provided, or the
+/// `cap` is provided without any of them.
+pub fn policy(
+    per_day: Option<Money>,
+    percent_per_day: Option<Percent>,
+    cap: Option<Money>,
+) -> Result<Option<domain::late_fee::Policy>, LateFeeError> {
+    let charge = match (per_day, percent_per_day) {
+        (Some(fee), None) => Charge::Fixed(fee),
+        (None, Some(p)) => Charge::Percent(p),
+        (None, None) if cap.is_none() => return Ok(None),
+        (Some(_), Some(_)) | (None, None) => {
+            return Err(LateFeeError::Ambiguous);
+        }
+    };
+    Ok(Some(domain::late_fee::Policy { charge, cap }))
+}
+
+define_error! {
+    enum LateFeeError {
+        #[code = "LATE_FEE_AMBIGUOUS"]
+        #[status = BAD_REQUEST]
+        #[message = "Exactly one of `lateFeePerDay` or \
+                     `lateFeePercentPerDay` must be provided"]
+        Ambiguous,
+    }
+}
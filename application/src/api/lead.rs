@@ -0,0 +1,349 @@
+//! [`Lead`]-related definitions.
+
+use common::{DateTime, Handler as _};
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+use service::{domain, query};
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
+
+/// A prospect in the sales pipeline of an agency.
+#[derive(Clone, Debug, From)]
+pub struct Lead(domain::Lead);
+
+/// A prospect in the sales pipeline of an agency.
+#[graphql_object(context = Context)]
+impl Lead {
+    /// Unique identifier of this `Lead`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// Source this `Lead` came from.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.source",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn source(&self) -> Source {
+        self.0.source.clone().into()
+    }
+
+    /// Current stage of this `Lead`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.stage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn stage(&self) -> Stage {
+        self.0.stage.into()
+    }
+
+    /// Prospect `User` of this `Lead`, if known.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.user",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn user(&self) -> Option<api::User> {
+        self.0.user_id.map(|id| {
+            // SAFETY: `User` is referenced by an existing `Lead`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(id)
+            }
+        })
+    }
+
+    /// Placed `Realty` this `Lead` is interested in, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.realty",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn realty(&self) -> Option<api::Realty> {
+        self.0.realty_id.map(|id| {
+            // SAFETY: `Realty` is referenced by an existing `Lead`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::Realty::new_unchecked(id)
+            }
+        })
+    }
+
+    /// Stage transitions of this `Lead` in their chronological order.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.history",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn history(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<Transition>, Error> {
+        ctx.service()
+            .execute(query::lead::History::by(self.0.id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|history| history.into_iter().map(Transition).collect())
+    }
+
+    /// `DateTime` when this `Lead` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Lead.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Move of a `Lead` between stages.
+#[derive(Clone, Copy, Debug)]
+pub struct Transition(domain::lead::Transition);
+
+/// Move of a `Lead` between stages.
+#[graphql_object(name = "LeadTransition", context = Context)]
+impl Transition {
+    /// Stage the `Lead` was moved from.
+    ///
+    /// `null` if the `Lead` was just created.
+    #[must_use]
+    pub fn from(&self) -> Option<Stage> {
+        self.0.from.map(Into::into)
+    }
+
+    /// Stage the `Lead` was moved to.
+    #[must_use]
+    pub fn to(&self) -> Stage {
+        self.0.to.into()
+    }
+
+    /// `User` who moved the `Lead`.
+    #[must_use]
+    pub fn initiator(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `LeadTransition`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.initiator_id)
+        }
+    }
+
+    /// `DateTime` when the `Lead` was moved.
+    #[must_use]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of a `Lead`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::lead::Id)]
+#[into(domain::lead::Id)]
+#[graphql(name = "LeadId", transparent)]
+pub struct Id(Uuid);
+
+/// Source of a `Lead` (a website form, a phone call, a referral, etc.).
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "LeadSource",
+    with = scalar::Via::<domain::lead::Source>,
+)]
+pub struct Source(domain::lead::Source);
+
+/// Stage of a `Lead` in the sales pipeline.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "LeadStage")]
+pub enum Stage {
+    /// Newly received inquiry.
+    New,
+
+    /// Prospect has been contacted.
+    Contacted,
+
+    /// Prospect is viewing the `Realty`.
+    Viewing,
+
+    /// Prospect has made an offer.
+    Offer,
+
+    /// `Lead` is closed (either won or lost).
+    Closed,
+}
+
+impl From<domain::lead::Stage> for Stage {
+    fn from(stage: domain::lead::Stage) -> Self {
+        use domain::lead::Stage as S;
+        match stage {
+            S::New => Self::New,
+            S::Contacted => Self::Contacted,
+            S::Viewing => Self::Viewing,
+            S::Offer => Self::Offer,
+            S::Closed => Self::Closed,
+        }
+    }
+}
+
+impl From<Stage> for domain::lead::Stage {
+    fn from(stage: Stage) -> Self {
+        match stage {
+            Stage::New => Self::New,
+            Stage::Contacted => Self::Contacted,
+            Stage::Viewing => Self::Viewing,
+            Stage::Offer => Self::Offer,
+            Stage::Closed => Self::Closed,
+        }
+    }
+}
+
+define_error! {
+    enum LeadError {
+        #[code = "LEAD_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Lead` does not exist"]
+        NotExists,
+    }
+}
+
+pub mod list {
+    //! Definitions related to a [`Lead`] list.
+
+    use derive_more::{AsRef, From, Into};
+    use juniper::{graphql_object, GraphQLScalar};
+    use service::read;
+
+    use crate::{
+        api::{self, scalar},
+        Context,
+    };
+
+    use super::Lead;
+
+    /// Cursor for the `Lead` list.
+    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
+    #[from(api::lead::Id, read::lead::list::Cursor)]
+    #[graphql(
+        name = "LeadListCursor",
+        with = scalar::Via::<read::lead::list::Cursor>,
+    )]
+    pub struct Cursor(pub read::lead::list::Cursor);
+
+    /// Edge in the [`Lead`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Edge(read::lead::list::Edge);
+
+    /// Edge in the `Lead` list.
+    #[graphql_object(name = "LeadListEdge", context = Context)]
+    impl Edge {
+        /// Cursor of this `LeadListEdge`.
+        #[must_use]
+        pub fn cursor(&self) -> Cursor {
+            self.0.cursor.into()
+        }
+
+        /// Node of this `LeadListEdge`.
+        #[must_use]
+        pub fn node(&self) -> Lead {
+            self.0.node.clone().into()
+        }
+    }
+
+    /// Connection of the [`Lead`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Connection(read::lead::list::Connection);
+
+    /// Connection of the `Lead` list.
+    #[graphql_object(name = "LeadListConnection", context = Context)]
+    impl Connection {
+        /// Edges in this `LeadListConnection`.
+        #[must_use]
+        pub fn edges(&self) -> Vec<Edge> {
+            self.0.edges.iter().cloned().map(Into::into).collect()
+        }
+
+        /// Information about the page.
+        #[must_use]
+        pub fn page_info(&self) -> PageInfo {
+            PageInfo {
+                info: self.0.page_info(),
+                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
+                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+            }
+        }
+    }
+
+    /// Information about a [`Connection`] page.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PageInfo {
+        /// Underlying [`read::lead::list::PageInfo`].
+        info: read::lead::list::PageInfo,
+
+        /// Start cursor of the page.
+        start_cursor: Option<Cursor>,
+
+        /// End cursor of the page.
+        end_cursor: Option<Cursor>,
+    }
+
+    /// Information about a `LeadListConnection` page.
+    #[graphql_object(name = "LeadListPageInfo", context = Context)]
+    impl PageInfo {
+        /// Indicator whether there is a next page.
+        #[must_use]
+        pub fn has_next_page(&self) -> bool {
+            self.info.has_next_page
+        }
+
+        /// Indicator whether there is a previous page.
+        #[must_use]
+        pub fn has_previous_page(&self) -> bool {
+            self.info.has_previous_page
+        }
+
+        /// Start cursor of the page.
+        #[must_use]
+        pub fn start_cursor(&self) -> &Option<Cursor> {
+            &self.start_cursor
+        }
+
+        /// End cursor of the page.
+        #[must_use]
+        pub fn end_cursor(&self) -> &Option<Cursor> {
+            &self.end_cursor
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! External listing definitions.
+
+use common::DateTime;
+use juniper::GraphQLObject;
+use service::domain;
+
+/// Synchronization of an external portal or MLS listing feed.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(name = "ListingSync")]
+pub struct Sync {
+    /// Name of the feed.
+    pub source: String,
+
+    /// `DateTime` when the synchronization has started.
+    pub started_at: DateTime,
+
+    /// `DateTime` when the synchronization has finished.
+    pub finished_at: DateTime,
+
+    /// Number of the listings imported as new `Realty`s with management
+    /// `Contract`s.
+    pub imported: i32,
+
+    /// Number of the listings matched the already existing `Realty`s.
+    pub deduplicated: i32,
+
+    /// Number of the listings imported by previous synchronizations.
+    pub unchanged: i32,
+
+    /// Number of the listings being malformed or failed to be imported.
+    pub rejected: i32,
+
+    /// Error of pulling the feed, if it has failed.
+    pub error: Option<String>,
+}
+
+impl From<domain::listing::Sync> for Sync {
+    fn from(sync: domain::listing::Sync) -> Self {
+        let domain::listing::Sync {
+            source,
+            started_at,
+            finished_at,
+            imported,
+            deduplicated,
+            unchanged,
+            rejected,
+            error,
+        } = sync;
+        let [imported, deduplicated, unchanged, rejected] =
+            [imported, deduplicated, unchanged, rejected]
+                .map(|n| i32::try_from(n).unwrap_or(i32::MAX));
+        Self {
+            source: source.to_string(),
+            started_at,
+            finished_at,
+            imported,
+            deduplicated,
+            unchanged,
+            rejected,
+            error,
+        }
+    }
+}
@@ -1,22 +1,56 @@
 //! GraphQL API definitions.
 
+pub mod agency;
+pub mod arrears;
+pub mod audit;
+pub mod branch;
+pub mod charge;
 pub mod contract;
+pub mod deprecation;
+pub mod finance;
+pub mod insurance;
+pub mod inventory;
+pub mod key;
+pub mod late_fee;
+pub mod lead;
+pub mod listing;
 mod mutation;
+pub mod offer;
+pub mod payment;
 pub mod placement;
 mod query;
 pub mod realty;
 pub mod report;
+pub mod review;
+pub mod saved_search;
 pub mod scalar;
+pub mod search;
 mod subscription;
+pub mod task;
+pub mod template;
+pub mod trash;
 pub mod user;
 
 use crate::define_error;
 
 pub use self::{
+    agency::Agency,
+    arrears::Arrears,
+    branch::Branch,
+    charge::Charge,
     contract::{Contract, ContractValue},
+    insurance::Insurance,
+    inventory::Inventory,
+    key::Key,
+    lead::Lead,
     mutation::Mutation,
+    offer::Offer,
+    payment::Payment,
     query::Query,
     realty::Realty,
+    review::Review,
+    saved_search::SavedSearch,
+    search::SearchResult,
     subscription::Subscription,
     user::User,
 };
@@ -33,11 +67,30 @@ define_error! {
     }
 }
 
+define_error! {
+    enum CredentialsError {
+        #[code = "AMBIGUOUS_CREDENTIALS"]
+        #[status = BAD_REQUEST]
+        #[message = "Exactly one of `login` or `email` must be provided"]
+        Ambiguous,
+    }
+}
+
 define_error! {
     enum PaginationError {
         #[code = "AMBIGUOUS_PAGINATION_ARGUMENTS"]
         #[status = BAD_REQUEST]
         #[message = "Ambiguous pagination arguments"]
         Ambiguous,
+
+        #[code = "INVALID_PAGINATION_ARGUMENTS"]
+        #[status = BAD_REQUEST]
+        #[message = "Invalid pagination arguments"]
+        Invalid,
+
+        #[code = "PAGINATION_CURSOR_MISMATCH"]
+        #[status = BAD_REQUEST]
+        #[message = "Pagination cursor doesn't match the requested order"]
+        CursorMismatch,
     }
 }
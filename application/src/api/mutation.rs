@@ -1,8 +1,14 @@
 //! GraphQL [`Mutation`]s definitions.
 
+use std::{num::TryFromIntError, time};
+
 use common::{DateTime, Money, Percent};
 use juniper::graphql_object;
-use service::{command, query, Command as _};
+use service::{
+    command,
+    domain::{self, user},
+    query, Command as _,
+};
 
 use crate::{api, define_error, AsError, Context, Error, Session};
 
@@ -12,7 +18,7 @@ pub struct Mutation;
 
 impl Mutation {
     /// Name of the [`tracing::Span`] for the mutations.
-    const SPAN_NAME: &'static str = "GraphQL mutation";
+    pub(crate) const SPAN_NAME: &'static str = "GraphQL mutation";
 }
 
 #[graphql_object(context = Context)]
@@ -24,7 +30,12 @@ impl Mutation {
     /// Possible error codes:
     /// - `LOGIN_OCCUPIED` - provided `UserLogin` is occupied by another `User`;
     /// - `NO_CONTACT_INFO` - either `UserEmail` or `UserPhone` must be
-    ///                       provided.
+    ///                       provided;
+    /// - `UNDELIVERABLE_EMAIL` - domain of the provided `UserEmail` does not
+    ///                           accept mail;
+    /// - `WEAK_PASSWORD` - provided `UserPassword` violates the password
+    ///                     policy, with the violated rules listed in the
+    ///                     `details` extension.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -67,6 +78,7 @@ impl Mutation {
 
         ctx.set_current_session(Session {
             user_id: output.user.id.into(),
+            impersonated_by: None,
             token: output.token.clone(),
             expires_at: output.expires_at.coerce(),
         })
@@ -77,27 +89,213 @@ impl Mutation {
 
     /// Creates a new `UserSession` with the provided credentials.
     ///
+    /// `User` is identified either by its `login` or by its `email`. The latter
+    /// identifies a `User` only if no other `User` has the same `email`.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
+    /// - `AMBIGUOUS_CREDENTIALS` - none or both of `login` and `email` are
+    ///                             provided;
     /// - `WRONG_CREDENTIALS` - provided credentials does not match any `User`.
     #[tracing::instrument(
         skip_all,
         fields(
+            email = ?email,
             gql.name = "createUserSession",
-            login = %login,
+            login = ?login,
             otel.name = Self::SPAN_NAME,
         ),
     )]
     pub async fn create_user_session(
-        login: api::user::Login,
+        login: Option<api::user::Login>,
+        email: Option<api::user::Email>,
         password: api::user::Password,
         ctx: &Context,
     ) -> Result<api::user::session::CreateResult, Error> {
+        let identifier = match (login, email) {
+            (Some(login), None) => user::Identifier::Login(login.into()),
+            (None, Some(email)) => user::Identifier::Email(email.into()),
+            (None, None) | (Some(_), Some(_)) => {
+                return Err(api::CredentialsError::Ambiguous.into());
+            }
+        };
+
         let output = ctx
             .service()
             .execute(command::CreateUserSession::ByCredentials {
-                login: login.into(),
+                identifier,
+                password: secrecy::SecretBox::init_with(move || {
+                    password.into()
+                }),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?;
+
+        ctx.set_current_session(Session {
+            user_id: output.user.id.into(),
+            impersonated_by: None,
+            token: output.token.clone(),
+            expires_at: output.expires_at.coerce(),
+        })
+        .await;
+
+        Ok(output.into())
+    }
+
+    /// Creates a short-lived `Session` of the `User` with the provided ID on
+    /// behalf of the current platform operator `User` (administrator of the
+    /// default `Agency`), for support and debugging of the `User`-specific
+    /// issues.
+    ///
+    /// The created `Session` expires in 10 minutes and is marked with the
+    /// operator `User` (see `Session.impersonatedBy`), while the
+    /// impersonation is recorded into the audit log. Impersonating from an
+    /// impersonating `Session` is performed on behalf of its operator `User`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `ADMIN_IMPERSONATION` - the `User` with the provided ID administrates
+    ///                           an `Agency`;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator;
+    /// - `SELF_IMPERSONATION` - the `User` with the provided ID is the
+    ///                          operator `User` itself;
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "impersonateUser",
+            otel.name = Self::SPAN_NAME,
+            user_id = %id,
+        ),
+    )]
+    pub async fn impersonate_user(
+        id: api::user::Id,
+        ctx: &Context,
+    ) -> Result<api::user::session::CreateResult, Error> {
+        let session = ctx.current_session().await?;
+        let admin_id = session.impersonated_by.unwrap_or(session.user_id);
+
+        ctx.service()
+            .execute(command::ImpersonateUser {
+                user_id: id.into(),
+                initiator_id: admin_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Restores the deleted `User` with the provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `USER_NOT_DELETED` - the deleted `User` with the provided ID does not
+    ///                        exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator
+    ///                        (administrator of the default `Agency`).
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "restoreUser",
+            otel.name = Self::SPAN_NAME,
+            user_id = %id,
+        ),
+    )]
+    pub async fn restore_user(
+        id: api::user::Id,
+        ctx: &Context,
+    ) -> Result<api::User, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RestoreUser {
+                user_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Imports `User`s in bulk from the provided CSV, for agencies migrating
+    /// from spreadsheets or another CRM.
+    ///
+    /// CSV must have a header with `name`, `login`, `email` and `phone`
+    /// columns in any order, while every `User` must have either `email` or
+    /// `phone`. Imported `User`s have no password, and set one by accepting
+    /// their invites into the current `Agency` (see `acceptUserInvite`),
+    /// which links are returned.
+    ///
+    /// Either all the `User`s are imported, or none of them.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_CSV` - provided CSV is malformed, with the violating lines
+    ///                   listed in the `details` extension;
+    /// - `LOGIN_OCCUPIED` - some of the provided `UserLogin`s are occupied by
+    ///                      other `User`s, listed in the `details` extension;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer of the current
+    ///                  `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "importUsers",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn import_users(
+        csv: String,
+        ctx: &Context,
+    ) -> Result<Vec<api::user::invite::Imported>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::ImportUsers {
+                csv,
+                agency_id: ctx.agency_id().into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|users| users.into_iter().map(Into::into).collect())
+    }
+
+    /// Accepts the `User` invite with the provided token, by setting the
+    /// password of the invited `User`, and creates a new `Session` of it.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVITE_EXPIRED` - the invite with the provided token has expired;
+    /// - `INVITE_NOT_EXISTS` - the invite with the provided token does not
+    ///                         exist, or is accepted already;
+    /// - `WEAK_PASSWORD` - provided `UserPassword` violates the password
+    ///                     policy, with the violated rules listed in the
+    ///                     `details` extension.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "acceptUserInvite",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn accept_user_invite(
+        token: api::user::invite::Token,
+        password: api::user::Password,
+        ctx: &Context,
+    ) -> Result<api::user::session::CreateResult, Error> {
+        let user = ctx
+            .service()
+            .execute(command::AcceptUserInvite {
+                token: token.into(),
                 password: secrecy::SecretBox::init_with(move || {
                     password.into()
                 }),
@@ -105,9 +303,16 @@ impl Mutation {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?;
+        let output = ctx
+            .service()
+            .execute(command::CreateUserSession::ByUserId(user.id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?;
 
         ctx.set_current_session(Session {
             user_id: output.user.id.into(),
+            impersonated_by: None,
             token: output.token.clone(),
             expires_at: output.expires_at.coerce(),
         })
@@ -148,7 +353,10 @@ impl Mutation {
     ///
     /// Possible error codes:
     /// - `WRONG_PASSWORD` - provided `old_password` does not match the current
-    ///                      `User` password.
+    ///                      `User` password;
+    /// - `WEAK_PASSWORD` - provided `new_password` violates the password
+    ///                     policy, with the violated rules listed in the
+    ///                     `details` extension.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -178,6 +386,12 @@ impl Mutation {
     }
 
     /// Updates the `User`'s email to the provided one.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `UNDELIVERABLE_EMAIL` - domain of the provided `UserEmail` does not
+    ///                           accept mail.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -229,6 +443,138 @@ impl Mutation {
             .map(Into::into)
     }
 
+    /// Issues a personal iCalendar feed of the `User`, containing the
+    /// expiration dates of the active `Contract`s the `User` takes part in.
+    ///
+    /// Returns the secret token of the feed, to be subscribed to from
+    /// calendar apps as `/calendar.ics?token={token}`. The token is shown only
+    /// once, and issuing a new one revokes the previous one.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "issueCalendarFeed",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn issue_calendar_feed(ctx: &Context) -> Result<String, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::IssueCalendarFeed {
+                user_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|token| token.to_string())
+    }
+
+    /// Revokes the personal iCalendar feed of the `User`, if any.
+    ///
+    /// Always returns `true`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "revokeCalendarFeed",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn revoke_calendar_feed(ctx: &Context) -> Result<bool, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RevokeCalendarFeed {
+                user_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|()| true)
+    }
+
+    /// Saves a new `SavedSearch` of the `User`, so the `User` is alerted
+    /// about the `Placement`s matching it, placed since now.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `INVALID_PRICE_RANGE` - `minPrice` exceeds `maxPrice`;
+    /// - `NEGATIVE_PRICE` - `minPrice` or `maxPrice` is negative;
+    /// - `PRICE_CURRENCY_MISMATCH` - `minPrice` and `maxPrice` have different
+    ///                               currencies.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            city = ?city.as_ref().map(ToString::to_string),
+            gql.name = "saveSearch",
+            kind = ?kind,
+            max_price = ?max_price,
+            min_price = ?min_price,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn save_search(
+        city: Option<api::realty::City>,
+        min_price: Option<Money>,
+        max_price: Option<Money>,
+        kind: Option<api::saved_search::Kind>,
+        ctx: &Context,
+    ) -> Result<api::SavedSearch, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::SaveSearch {
+                user_id: my_id.into(),
+                agency_id: ctx.agency_id().into(),
+                city: city.map(Into::into),
+                min_price,
+                max_price,
+                kind: kind.map(Into::into),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Deletes the `SavedSearch` of the `User` with the provided ID, so the
+    /// `User` is not alerted about it anymore.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `SAVED_SEARCH_NOT_EXISTS` - the `SavedSearch` with the provided ID
+    ///                               does not exist or belongs to another
+    ///                               `User`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "deleteSavedSearch",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn delete_saved_search(
+        id: api::saved_search::Id,
+        ctx: &Context,
+    ) -> Result<api::SavedSearch, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::DeleteSavedSearch {
+                id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
     /// Creates a new `Realty` with the provided details.
     ///
     /// # Errors
@@ -239,6 +585,7 @@ impl Mutation {
         skip_all,
         fields(
             apartment_num = ?apartment_num,
+            area = ?area,
             building_name = %building_name,
             city = %city,
             country = %country,
@@ -264,6 +611,7 @@ impl Mutation {
         floor: Option<i32>,
         apartment_num: Option<api::realty::ApartmentNum>,
         room_num: Option<api::realty::RoomNum>,
+        area: Option<api::realty::Area>,
         ctx: &Context,
     ) -> Result<api::Realty, Error> {
         let num_floors = num_floors.try_into().map_err(AsError::into_error)?;
@@ -279,13 +627,14 @@ impl Mutation {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Err(api::PrivilegeError::Employer.into());
         }
 
         ctx.service()
             .execute(command::CreateRealty {
+                agency_id: ctx.agency_id().into(),
                 country: country.into(),
                 state: state.map(Into::into),
                 city: city.into(),
@@ -296,6 +645,41 @@ impl Mutation {
                 floor,
                 apartment_num: apartment_num.map(Into::into),
                 room_num: room_num.map(Into::into),
+                area: area.map(Into::into),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Restores the deleted `Realty` with the provided ID, unless it's purged
+    /// already.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `REALTY_NOT_DELETED` - the deleted `Realty` with the provided ID does
+    ///                          not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "restoreRealty",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn restore_realty(
+        id: api::realty::Id,
+        ctx: &Context,
+    ) -> Result<api::Realty, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RestoreRealty {
+                realty_id: id.into(),
+                initiator_id: my_id.into(),
             })
             .await
             .map_err(AsError::into_error)
@@ -308,6 +692,11 @@ impl Mutation {
     /// # Errors
     ///
     /// Possible error codes:
+    /// - `BRANCH_NOT_EXISTS` - the `Branch` with the provided ID does not
+    ///                         exist in the current `Agency`;
+    /// - `MANAGER_NOT_EMPLOYED` - the manager `User` with the provided ID is
+    ///                            not employed (or is the employed `User`
+    ///                            itself);
     /// - `USER_EMPLOYED` - the `User` with the provided ID is already employed;
     /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist;
     /// - `NOT_EMPLOYER` - the current `User` is not an employer.
@@ -315,32 +704,40 @@ impl Mutation {
         skip_all,
         fields(
             base_salary = %base_salary,
+            branch_id = ?branch_id,
             description = %description,
             expires_at = ?expires_at.as_ref().map(DateTime::to_rfc3339),
             gql.name = "createEmploymentContract",
+            manager_id = ?manager_id,
             name = %name,
             otel.name = Self::SPAN_NAME,
             user_id = %user_id,
         ),
     )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn create_employment_contract(
         user_id: api::user::Id,
         name: api::contract::Name,
         description: api::contract::Description,
         expires_at: Option<DateTime>,
         base_salary: Money,
+        manager_id: Option<api::user::Id>,
+        branch_id: Option<api::branch::Id>,
         ctx: &Context,
     ) -> Result<api::ContractValue, Error> {
         let my_id = ctx.current_session().await?.user_id;
 
         ctx.service()
             .execute(command::CreateEmploymentContract {
+                agency_id: ctx.agency_id().into(),
                 user_id: user_id.into(),
                 initiator_id: my_id.into(),
                 name: name.into(),
                 description: description.into(),
                 expires_at: expires_at.map(DateTime::coerce),
                 base_salary,
+                manager_id: manager_id.map(Into::into),
+                branch_id: branch_id.map(Into::into),
             })
             .await
             .map_err(AsError::into_error)
@@ -350,6 +747,11 @@ impl Mutation {
 
     /// Creates a new `ManagementForRentContract` with the provided details.
     ///
+    /// `Realty.suggestedPrice` may be used to choose the `expectedPrice`.
+    ///
+    /// Omitted `expiresAt` defaults to `AgencySettings.contractDurationDays`,
+    /// and omitted fees default to `AgencySettings.commission` percent fee.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
@@ -420,6 +822,11 @@ impl Mutation {
 
     /// Creates a new `ManagementForSaleContract` with the provided details.
     ///
+    /// `Realty.suggestedPrice` may be used to choose the `expectedPrice`.
+    ///
+    /// Omitted `expiresAt` defaults to `AgencySettings.contractDurationDays`,
+    /// and omitted fees default to `AgencySettings.commission` percent fee.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
@@ -490,11 +897,44 @@ impl Mutation {
 
     /// Creates a new `RentContract` with the provided details.
     ///
+    /// `Realty` must not be rented by another active `RentContract`, and the
+    /// new `RentContract` must not overlap with active `ShortTermRentContract`s
+    /// of the `Realty`.
+    ///
+    /// `RentLateFee` is charged for the overdue installments, if either the
+    /// fixed `lateFeePerDay` or the `lateFeePercentPerDay` of the overdue rent
+    /// is specified (optionally limited by the `lateFeeCap`).
+    ///
+    /// `name` and `description`, if not specified, are rendered with the
+    /// `ContractTemplate` of the provided `templateId`.
+    ///
+    /// Omitted `expiresAt` defaults to `AgencySettings.contractDurationDays`.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
+    /// - `CONTRACT_TEMPLATE_NOT_EXISTS` - the `ContractTemplate` with the
+    ///                                    provided ID does not exist;
+    /// - `CONTRACT_TEMPLATE_NOT_RENDERABLE` - the `ContractTemplate` renders
+    ///                                        invalid `name` or
+    ///                                        `description`;
+    /// - `CONTRACT_TEMPLATE_REQUIRED` - neither `name` and `description`, nor
+    ///                                  `templateId` are specified;
+    /// - `LATE_FEE_AMBIGUOUS` - both or none of the `lateFeePerDay` and the
+    ///                          `lateFeePercentPerDay` are specified along
+    ///                          with the `lateFeeCap`;
+    /// - `LATE_FEE_NOT_APPLICABLE` - the late fee amounts are not positive, or
+    ///                               not in the currency of the `price`;
+    /// - `OFFER_NOT_ACCEPTED` - the `Offer` with the provided ID is not
+    ///                          accepted by the purchaser, or is concluded
+    ///                          already;
     /// - `REALTY_NOT_MANAGED` - the `Realty` with the provided ID is not
     ///                          managed for rent;
+    /// - `REALTY_OCCUPIED` - the `Realty` is already booked by a
+    ///                       `ShortTermRentContract` within the period of the
+    ///                       new `Contract`;
+    /// - `REALTY_RENTED` - the `Realty` with the provided ID is rented by
+    ///                     another active `RentContract`;
     /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not
     ///                       exist;
     /// - `USER_NOT_MANAGER` - the current `User` is not a manager of the
@@ -504,39 +944,58 @@ impl Mutation {
         skip_all,
         fields(
             deposit = ?deposit.as_ref().map(ToString::to_string),
-            description = %description,
+            description = ?description.as_ref().map(ToString::to_string),
             expires_at = ?expires_at.as_ref().map(DateTime::to_rfc3339),
             gql.name = "createRentContract",
-            name = %name,
+            late_fee_cap = ?late_fee_cap.as_ref().map(ToString::to_string),
+            late_fee_per_day =
+                ?late_fee_per_day.as_ref().map(ToString::to_string),
+            late_fee_percent_per_day = ?late_fee_percent_per_day,
+            name = ?name.as_ref().map(ToString::to_string),
+            offer_id = ?offer_id,
             otel.name = Self::SPAN_NAME,
             price = price.to_string(),
             purchaser_id = %purchaser_id,
             realty_id = %realty_id,
+            template_id = ?template_id,
         ),
     )]
     #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn create_rent_contract(
         realty_id: api::realty::Id,
         purchaser_id: api::user::Id,
-        name: api::contract::Name,
-        description: api::contract::Description,
+        name: Option<api::contract::Name>,
+        description: Option<api::contract::Description>,
+        template_id: Option<api::contract::template::Id>,
         expires_at: Option<DateTime>,
         price: Money,
         deposit: Option<Money>,
+        late_fee_per_day: Option<Money>,
+        late_fee_percent_per_day: Option<Percent>,
+        late_fee_cap: Option<Money>,
+        offer_id: Option<api::offer::Id>,
         ctx: &Context,
     ) -> Result<api::ContractValue, Error> {
         let my_id = ctx.current_session().await?.user_id;
+        let late_fee = api::late_fee::policy(
+            late_fee_per_day,
+            late_fee_percent_per_day,
+            late_fee_cap,
+        )?;
 
         ctx.service()
             .execute(command::CreateRentContract {
                 realty_id: realty_id.into(),
                 employer_id: my_id.into(),
                 purchaser_id: purchaser_id.into(),
-                name: name.into(),
-                description: description.into(),
+                name: name.map(Into::into),
+                description: description.map(Into::into),
+                template_id: template_id.map(Into::into),
                 expires_at: expires_at.map(DateTime::coerce),
                 price,
                 deposit,
+                late_fee,
+                offer_id: offer_id.map(Into::into),
             })
             .await
             .map_err(AsError::into_error)
@@ -546,9 +1005,24 @@ impl Mutation {
 
     /// Creates a new `SaleContract` with the provided details.
     ///
+    /// `name` and `description`, if not specified, are rendered with the
+    /// `ContractTemplate` of the provided `templateId`.
+    ///
+    /// Omitted `expiresAt` defaults to `AgencySettings.contractDurationDays`.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
+    /// - `CONTRACT_TEMPLATE_NOT_EXISTS` - the `ContractTemplate` with the
+    ///                                    provided ID does not exist;
+    /// - `CONTRACT_TEMPLATE_NOT_RENDERABLE` - the `ContractTemplate` renders
+    ///                                        invalid `name` or
+    ///                                        `description`;
+    /// - `CONTRACT_TEMPLATE_REQUIRED` - neither `name` and `description`, nor
+    ///                                  `templateId` are specified;
+    /// - `OFFER_NOT_ACCEPTED` - the `Offer` with the provided ID is not
+    ///                          accepted by the purchaser, or is concluded
+    ///                          already;
     /// - `REALTY_MANAGED_FOR_RENTED` - the `Realty` with the provided ID is
     ///                                 managed for rent;
     /// - `REALTY_RENTED` - the `Realty` with the provided ID is rented;
@@ -562,25 +1036,29 @@ impl Mutation {
         skip_all,
         fields(
             deposit = ?deposit.as_ref().map(ToString::to_string),
-            description = %description,
+            description = ?description.as_ref().map(ToString::to_string),
             expires_at = ?expires_at.as_ref().map(DateTime::to_rfc3339),
             gql.name = "createSaleContract",
-            name = %name,
+            name = ?name.as_ref().map(ToString::to_string),
+            offer_id = ?offer_id,
             otel.name = Self::SPAN_NAME,
             price = price.to_string(),
             purchaser_id = %purchaser_id,
             realty_id = %realty_id,
+            template_id = ?template_id,
         ),
     )]
     #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn create_sale_contract(
         realty_id: api::realty::Id,
         purchaser_id: api::user::Id,
-        name: api::contract::Name,
-        description: api::contract::Description,
+        name: Option<api::contract::Name>,
+        description: Option<api::contract::Description>,
+        template_id: Option<api::contract::template::Id>,
         expires_at: Option<DateTime>,
         price: Money,
         deposit: Option<Money>,
+        offer_id: Option<api::offer::Id>,
         ctx: &Context,
     ) -> Result<api::ContractValue, Error> {
         let my_id = ctx.current_session().await?.user_id;
@@ -590,11 +1068,13 @@ impl Mutation {
                 realty_id: realty_id.into(),
                 employer_id: my_id.into(),
                 purchaser_id: purchaser_id.into(),
-                name: name.into(),
-                description: description.into(),
+                name: name.map(Into::into),
+                description: description.map(Into::into),
+                template_id: template_id.map(Into::into),
                 expires_at: expires_at.map(DateTime::coerce),
                 price,
                 deposit,
+                offer_id: offer_id.map(Into::into),
             })
             .await
             .map_err(AsError::into_error)
@@ -602,30 +1082,40 @@ impl Mutation {
             .map(Into::into)
     }
 
-    /// Terminates the `Contract` with the provided ID.
+    /// Updates the name and the description of the `Contract` with the
+    /// provided ID.
+    ///
+    /// Omitted `name` or `description` remains unchanged.
     ///
     /// # Errors
     ///
     /// Possible error codes:
     /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
-    ///                           exist or terminated already.
+    ///                           exist or terminated already;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
         fields(
-            gql.name = "terminateContract",
+            description = ?description.as_ref().map(ToString::to_string),
+            gql.name = "updateContract",
             id = %id,
+            name = ?name.as_ref().map(ToString::to_string),
             otel.name = Self::SPAN_NAME,
         ),
     )]
-    pub async fn terminate_contract(
+    pub async fn update_contract(
         id: api::contract::Id,
+        name: Option<api::contract::Name>,
+        description: Option<api::contract::Description>,
         ctx: &Context,
     ) -> Result<api::ContractValue, Error> {
         let my_id = ctx.current_session().await?.user_id;
 
         ctx.service()
-            .execute(command::TerminateContract {
+            .execute(command::UpdateContractMetadata {
                 contract_id: id.into(),
+                name: name.map(Into::into),
+                description: description.map(Into::into),
                 initiator_id: my_id.into(),
             })
             .await
@@ -634,7 +1124,111 @@ impl Mutation {
             .map(Into::into)
     }
 
-    /// Toggles the placement of the `Contract` with the provided ID.
+    /// Terminates the `Contract` with the provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
+    ///                           exist or terminated already.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "terminateContract",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn terminate_contract(
+        id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::TerminateContract {
+                contract_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Archives the `Contract` with the provided ID.
+    ///
+    /// Archived `Contract`s are not listed by default, but remain accessible.
+    /// Archiving an already archived `Contract` is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
+    ///                           exist;
+    /// - `CONTRACT_ACTIVE` - the `Contract` with the provided ID is still
+    ///                       active;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "archiveContract",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn archive_contract(
+        id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::ArchiveContract {
+                contract_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Restores the archived `Contract` with the provided ID, so it's listed
+    /// by default again.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `CONTRACT_NOT_ARCHIVED` - the archived `Contract` with the provided ID
+    ///                             does not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "restoreContract",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn restore_contract(
+        id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RestoreContract {
+                contract_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Toggles the placement of the `Contract` with the provided ID.
     ///
     /// # Errors
     ///
@@ -686,244 +1280,3099 @@ impl Mutation {
                 .map(Into::into)
         }
     }
-}
 
-impl AsError for command::create_user::ExecutionError {
-    fn try_as_error(&self) -> Option<Error> {
-        define_error! {
-            enum Error {
-                #[code = "LOGIN_OCCUPIED"]
-                #[status = CONFLICT]
-                #[message = "`UserLogin` is occupied by another \
-                             `User`"]
-                LoginOccupied,
+    /// Updates the runtime settings of the specified background task,
+    /// overriding its configuration without a redeploy.
+    ///
+    /// `null` `intervalSeconds` or `timeoutSeconds` resets it to the
+    /// configured one.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_TASK_SETTINGS` - the interval or timeout is not positive;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "updateBackgroundTaskSettings",
+            interval_seconds = ?interval_seconds,
+            otel.name = Self::SPAN_NAME,
+            task = ?task,
+            timeout_seconds = ?timeout_seconds,
+        ),
+    )]
+    pub async fn update_background_task_settings(
+        task: api::task::Kind,
+        interval_seconds: Option<i32>,
+        timeout_seconds: Option<i32>,
+        ctx: &Context,
+    ) -> Result<api::task::Settings, Error> {
+        let my_id = ctx.current_session().await?.user_id;
 
-                #[code = "NO_CONTACT_INFO"]
-                #[status = BAD_REQUEST]
-                #[message = "Either `UserEmail` or `UserPhone` must be \
-                             provided"]
-                NoContactInfo,
-            }
-        }
+        // Negative values are rejected as zero ones.
+        let seconds = |s: i32| {
+            time::Duration::from_secs(u64::try_from(s).unwrap_or_default())
+        };
+        ctx.service()
+            .execute(command::UpdateTaskSettings {
+                task: task.into(),
+                interval: interval_seconds.map(seconds),
+                timeout: timeout_seconds.map(seconds),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
 
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::LoginOccupied(_) => Some(Error::LoginOccupied.into()),
-            Self::NoContactInfo => Some(Error::NoContactInfo.into()),
-        }
+    /// Requeues the work items the specified background task has failed to
+    /// process, so they're processed again on its next run.
+    ///
+    /// Only the item with the provided `item` ID is requeued, if it's
+    /// specified. Returns the number of the requeued items.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "requeueBackgroundTaskDeadLetters",
+            item = ?item,
+            otel.name = Self::SPAN_NAME,
+            task = %task,
+        ),
+    )]
+    pub async fn requeue_background_task_dead_letters(
+        task: String,
+        item: Option<String>,
+        ctx: &Context,
+    ) -> Result<i32, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RequeueTaskDeadLetters {
+                task,
+                item,
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|n| i32::try_from(n).unwrap_or(i32::MAX))
+    }
+
+    /// Creates a new `Agency` with the provided name, administrated by the
+    /// `User` with the provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the default `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            admin_id = %admin_id,
+            gql.name = "createAgency",
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn create_agency(
+        name: api::agency::Name,
+        admin_id: api::user::Id,
+        ctx: &Context,
+    ) -> Result<api::Agency, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CreateAgency {
+                name: name.into(),
+                admin_id: admin_id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Updates the name of the current `Agency` to the provided one.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "updateAgencyName",
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn update_agency_name(
+        name: api::agency::Name,
+        ctx: &Context,
+    ) -> Result<api::Agency, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::UpdateAgencyName {
+                agency_id: ctx.agency_id().into(),
+                name: name.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Transfers the administration of the current `Agency` to the `User`
+    /// with the provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            admin_id = %admin_id,
+            gql.name = "updateAgencyAdmin",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn update_agency_admin(
+        admin_id: api::user::Id,
+        ctx: &Context,
+    ) -> Result<api::Agency, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::UpdateAgencyAdmin {
+                agency_id: ctx.agency_id().into(),
+                admin_id: admin_id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Updates the default settings of the current `Agency`, applied to its
+    /// `Contract`s when the corresponding details are omitted.
+    ///
+    /// `null` argument resets the corresponding default.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `INVALID_AGENCY_SETTINGS` - the contract duration is not positive;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            commission = ?commission,
+            contract_duration_days = ?contract_duration_days,
+            currency = ?currency,
+            gql.name = "updateAgencySettings",
+            locale = ?locale,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn update_agency_settings(
+        currency: Option<api::finance::Currency>,
+        contract_duration_days: Option<i32>,
+        commission: Option<Percent>,
+        locale: Option<api::agency::Locale>,
+        ctx: &Context,
+    ) -> Result<api::agency::Settings, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        // Negative values are rejected as zero ones.
+        let days =
+            |d: i32| api::agency::DAY * u32::try_from(d).unwrap_or_default();
+        ctx.service()
+            .execute(command::UpdateAgencySettings {
+                agency_id: ctx.agency_id().into(),
+                currency: currency.map(Into::into),
+                contract_duration: contract_duration_days.map(days),
+                commission,
+                locale: locale.map(Into::into),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Creates a new `Branch` of the current `Agency` with the provided
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            address = %address,
+            gql.name = "createBranch",
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn create_branch(
+        name: api::branch::Name,
+        address: api::realty::Address,
+        ctx: &Context,
+    ) -> Result<api::Branch, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CreateBranch {
+                agency_id: ctx.agency_id().into(),
+                name: name.into(),
+                address: address.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Assigns the employed `User` with the provided ID to the `Branch` with
+    /// the provided ID.
+    ///
+    /// `null` `branchId` unassigns the `User` from any `Branch`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `BRANCH_NOT_EXISTS` - the `Branch` with the provided ID does not
+    ///                         exist in the `Agency` of the `User`;
+    /// - `USER_NOT_EMPLOYED` - the `User` with the provided ID is not
+    ///                         employed;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the `Agency` the `User` is employed by.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            branch_id = ?branch_id,
+            gql.name = "assignBranch",
+            otel.name = Self::SPAN_NAME,
+            user_id = %user_id,
+        ),
+    )]
+    pub async fn assign_branch(
+        user_id: api::user::Id,
+        branch_id: Option<api::branch::Id>,
+        ctx: &Context,
+    ) -> Result<api::contract::Employment, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::AssignBranch {
+                user_id: user_id.into(),
+                branch_id: branch_id.map(Into::into),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Assigns the `Realty` with the provided ID to the employed `User` with
+    /// the provided ID, making them responsible for it.
+    ///
+    /// `null` `assigneeId` unassigns the `Realty`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `USER_NOT_EMPLOYED` - the `User` with the provided ID is not
+    ///                         employed by the current `Agency`;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            assignee_id = ?assignee_id,
+            gql.name = "assignRealty",
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn assign_realty(
+        realty_id: api::realty::Id,
+        assignee_id: Option<api::user::Id>,
+        ctx: &Context,
+    ) -> Result<api::Realty, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::AssignRealty {
+                realty_id: realty_id.into(),
+                agency_id: ctx.agency_id().into(),
+                assignee_id: assignee_id.map(Into::into),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Creates a new `Lead` at the `NEW` stage.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "createLead",
+            otel.name = Self::SPAN_NAME,
+            realty_id = ?realty_id,
+            source = %source,
+            user_id = ?user_id,
+        ),
+    )]
+    pub async fn create_lead(
+        source: api::lead::Source,
+        user_id: Option<api::user::Id>,
+        realty_id: Option<api::realty::Id>,
+        ctx: &Context,
+    ) -> Result<api::Lead, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::CreateLead {
+                agency_id: ctx.agency_id().into(),
+                source: source.into(),
+                user_id: user_id.map(Into::into),
+                realty_id: realty_id.map(Into::into),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Moves the `Lead` with the provided ID to the provided `stage`.
+    ///
+    /// `Lead`s only move forward through the pipeline, though any stage may
+    /// be skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `LEAD_NOT_EXISTS` - the `Lead` with the provided ID does not exist;
+    /// - `LEAD_STAGE_NOT_ALLOWED` - the `Lead` cannot be moved to the
+    ///                              provided `stage`;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "moveLead",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+            stage = ?stage,
+        ),
+    )]
+    pub async fn move_lead(
+        id: api::lead::Id,
+        stage: api::lead::Stage,
+        ctx: &Context,
+    ) -> Result<api::Lead, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::MoveLead {
+                lead_id: id.into(),
+                agency_id: ctx.agency_id().into(),
+                stage: stage.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Submits an `Offer` with the provided `price` on the `Realty` placed by
+    /// the `Contract` with the provided ID.
+    ///
+    /// Both the current `User` and the employer managing the `Realty` are
+    /// notified via the `offerUpdates` subscription.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_PLACED` - the `Contract` with the provided ID is not
+    ///                           placed;
+    /// - `OFFER_CURRENCY_MISMATCH` - the `price` is not in the currency of the
+    ///                               expected one;
+    /// - `OFFER_BY_EMPLOYER` - the current `User` manages the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "submitOffer",
+            otel.name = Self::SPAN_NAME,
+            price = %price,
+        ),
+    )]
+    pub async fn submit_offer(
+        contract_id: api::contract::Id,
+        price: Money,
+        ctx: &Context,
+    ) -> Result<api::Offer, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::SubmitOffer {
+                contract_id: contract_id.into(),
+                bidder_id: my_id.into(),
+                price,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Accepts the pending `Offer` with the provided ID.
+    ///
+    /// Only the counterparty of the `User` proposed the `Offer` may accept it.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `OFFER_NOT_EXISTS` - the `Offer` with the provided ID does not
+    ///                        exist;
+    /// - `OFFER_ALREADY_DECIDED` - the `Offer` is accepted, declined or
+    ///                             countered already.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "acceptOffer",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn accept_offer(
+        id: api::offer::Id,
+        ctx: &Context,
+    ) -> Result<api::Offer, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::AcceptOffer {
+                offer_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Declines the pending `Offer` with the provided ID.
+    ///
+    /// Only the counterparty of the `User` proposed the `Offer` may decline it.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `OFFER_NOT_EXISTS` - the `Offer` with the provided ID does not
+    ///                        exist;
+    /// - `OFFER_ALREADY_DECIDED` - the `Offer` is accepted, declined or
+    ///                             countered already.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "declineOffer",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn decline_offer(
+        id: api::offer::Id,
+        ctx: &Context,
+    ) -> Result<api::Offer, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::DeclineOffer {
+                offer_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Counters the pending `Offer` with the provided ID by a new `Offer` with
+    /// the provided `price`, which awaits a decision of the `User` proposed
+    /// the countered one.
+    ///
+    /// Only the counterparty of the `User` proposed the `Offer` may counter
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `OFFER_NOT_EXISTS` - the `Offer` with the provided ID does not
+    ///                        exist;
+    /// - `OFFER_ALREADY_DECIDED` - the `Offer` is accepted, declined or
+    ///                             countered already;
+    /// - `OFFER_CURRENCY_MISMATCH` - the `price` is not in the currency of the
+    ///                               countered one.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "counterOffer",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+            price = %price,
+        ),
+    )]
+    pub async fn counter_offer(
+        id: api::offer::Id,
+        price: Money,
+        ctx: &Context,
+    ) -> Result<api::Offer, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CounterOffer {
+                offer_id: id.into(),
+                initiator_id: my_id.into(),
+                price,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Leaves a `Review` of the employer on the completed `Contract` with the
+    /// provided ID.
+    ///
+    /// Only a purchaser or a landlord of a completed `RentContract` or
+    /// `SaleContract` may leave a `Review`, and only a single one per
+    /// `Contract`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
+    ///                           exist among the ones of the current `User`;
+    /// - `CONTRACT_NOT_COMPLETED` - the `Contract` is not completed yet;
+    /// - `INVALID_REVIEW_RATING` - the `rating` is not from 1 to 5;
+    /// - `REVIEW_ALREADY_EXISTS` - the `Contract` has a `Review` already.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "createReview",
+            otel.name = Self::SPAN_NAME,
+            rating = rating,
+        ),
+    )]
+    pub async fn create_review(
+        contract_id: api::contract::Id,
+        rating: i32,
+        comment: Option<api::review::Comment>,
+        ctx: &Context,
+    ) -> Result<api::Review, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        let rating = i16::try_from(rating)
+            .ok()
+            .and_then(domain::review::Rating::new)
+            .ok_or(api::review::ReviewError::InvalidRating)?;
+
+        ctx.service()
+            .execute(command::CreateReview {
+                contract_id: contract_id.into(),
+                author_id: my_id.into(),
+                rating,
+                comment: comment.map(Into::into),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Hides the `Review` with the provided ID, excluding it from the employer
+    /// rating.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `REVIEW_NOT_EXISTS` - the `Review` with the provided ID does not
+    ///                         exist in the current `Agency`;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "hideReview",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn hide_review(
+        id: api::review::Id,
+        ctx: &Context,
+    ) -> Result<api::Review, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::HideReview {
+                review_id: id.into(),
+                agency_id: ctx.agency_id().into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Publishes the previously hidden `Review` with the provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist;
+    /// - `REVIEW_NOT_EXISTS` - the `Review` with the provided ID does not
+    ///                         exist in the current `Agency`;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not an administrator of
+    ///                        the current `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "publishReview",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn publish_review(
+        id: api::review::Id,
+        ctx: &Context,
+    ) -> Result<api::Review, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::PublishReview {
+                review_id: id.into(),
+                agency_id: ctx.agency_id().into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Records a `RentPayment` of the provided `amount` under the
+    /// `RentContract` with the provided ID.
+    ///
+    /// Settled `RentArrears` are removed on the next arrears detection.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the `RentContract` with the provided ID does
+    ///                           not exist or is not managed by the current
+    ///                           `User`;
+    /// - `PAYMENT_CURRENCY_MISMATCH` - the `amount` is not in the currency of
+    ///                                 the `RentContract.price`;
+    /// - `PAYMENT_NOT_POSITIVE` - the `amount` is zero or negative.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            amount = %amount,
+            contract_id = %contract_id,
+            gql.name = "recordRentPayment",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn record_rent_payment(
+        contract_id: api::contract::Id,
+        amount: Money,
+        ctx: &Context,
+    ) -> Result<api::Payment, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RecordRentPayment {
+                contract_id: contract_id.into(),
+                amount,
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Records a `RentInventory` checklist of the provided `items` under the
+    /// `RentContract` with the provided ID at the provided `stage`.
+    ///
+    /// `MOVE_OUT` `RentInventory` may be recorded only after the `MOVE_IN`
+    /// one, and may be recorded even after the `RentContract` is ended.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the `RentContract` with the provided ID does
+    ///                           not exist or is not managed by the current
+    ///                           `User`;
+    /// - `INVENTORY_ALREADY_RECORDED` - the `RentInventory` is already
+    ///                                  recorded at the `stage`;
+    /// - `INVENTORY_DUPLICATE_ITEM` - the same `room` and `name` are listed
+    ///                                more than once;
+    /// - `INVENTORY_MOVE_IN_NOT_RECORDED` - the `MOVE_OUT` `RentInventory`
+    ///                                      is recorded before the `MOVE_IN`
+    ///                                      one;
+    /// - `INVENTORY_NO_ITEMS` - the `items` are empty.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "recordRentInventory",
+            items = items.len(),
+            otel.name = Self::SPAN_NAME,
+            stage = ?stage,
+        ),
+    )]
+    pub async fn record_rent_inventory(
+        contract_id: api::contract::Id,
+        stage: api::inventory::Stage,
+        items: Vec<api::inventory::ItemInput>,
+        ctx: &Context,
+    ) -> Result<api::Inventory, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RecordRentInventory {
+                contract_id: contract_id.into(),
+                stage: stage.into(),
+                items: items.into_iter().map(Into::into).collect(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Adds a `RentCharge` billed monthly under the `RentContract` with the
+    /// provided ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the active `RentContract` with the provided
+    ///                           ID does not exist or is not managed by the
+    ///                           current `User`;
+    /// - `RENT_CHARGE_CURRENCY_MISMATCH` - the `amount` is not in the
+    ///                                     currency of the
+    ///                                     `RentContract.price`;
+    /// - `RENT_CHARGE_NOT_POSITIVE` - the `amount` is zero or negative.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            amount = %amount,
+            bearer = ?bearer,
+            contract_id = %contract_id,
+            gql.name = "addRentCharge",
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn add_rent_charge(
+        contract_id: api::contract::Id,
+        name: api::charge::Name,
+        amount: Money,
+        bearer: api::charge::Bearer,
+        ctx: &Context,
+    ) -> Result<api::Charge, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::AddRentCharge {
+                contract_id: contract_id.into(),
+                name: name.into(),
+                amount,
+                bearer: bearer.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Removes the `RentCharge` with the provided ID.
+    ///
+    /// Removed `RentCharge` is no longer billed, including its already billed
+    /// installments.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `RENT_CHARGE_NOT_EXISTS` - the `RentCharge` with the provided ID
+    ///                              does not exist or is not managed by the
+    ///                              current `User`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "removeRentCharge",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn remove_rent_charge(
+        id: api::charge::Id,
+        ctx: &Context,
+    ) -> Result<api::Charge, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::RemoveRentCharge {
+                charge_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Registers a new physical `RealtyKey` of the `Realty` with the provided
+    /// ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `REALTY_KEY_LABEL_OCCUPIED` - another `RealtyKey` of the `Realty`
+    ///                                 has the same `label`;
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "createRealtyKey",
+            label = %label,
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn create_realty_key(
+        realty_id: api::realty::Id,
+        label: api::key::Label,
+        ctx: &Context,
+    ) -> Result<api::Key, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CreateRealtyKey {
+                realty_id: realty_id.into(),
+                label: label.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Checks out the `RealtyKey` with the provided ID to the `User` holding
+    /// it until the provided `dueAt`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `REALTY_KEY_ALREADY_CHECKED_OUT` - the `RealtyKey` is already
+    ///                                      checked out;
+    /// - `REALTY_KEY_DUE_IN_PAST` - the `dueAt` is not in the future;
+    /// - `REALTY_KEY_NOT_EXISTS` - the `RealtyKey` with the provided ID does
+    ///                             not exist;
+    /// - `USER_NOT_EXISTS` - the holder `User` with the provided ID does not
+    ///                       exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            due_at = %due_at.to_rfc3339(),
+            gql.name = "checkOutRealtyKey",
+            holder_id = %holder_id,
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn check_out_realty_key(
+        id: api::key::Id,
+        holder_id: api::user::Id,
+        due_at: DateTime,
+        ctx: &Context,
+    ) -> Result<api::Key, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CheckOutRealtyKey {
+                key_id: id.into(),
+                holder_id: holder_id.into(),
+                due_at,
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Checks in the `RealtyKey` with the provided ID returned by its holder.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `REALTY_KEY_NOT_CHECKED_OUT` - the `RealtyKey` is not checked out;
+    /// - `REALTY_KEY_NOT_EXISTS` - the `RealtyKey` with the provided ID does
+    ///                             not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "checkInRealtyKey",
+            id = %id,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn check_in_realty_key(
+        id: api::key::Id,
+        ctx: &Context,
+    ) -> Result<api::Key, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CheckInRealtyKey {
+                key_id: id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Attaches an `InsurancePolicy` to the rent or management `Contract`
+    /// with the provided ID, replacing the already attached one, if any.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the active rent or management `Contract`
+    ///                           with the provided ID does not exist or is
+    ///                           not managed by the current `User`;
+    /// - `INSURANCE_POLICY_COVERAGE_NOT_POSITIVE` - the `coverage` is zero or
+    ///                                              negative;
+    /// - `INSURANCE_POLICY_EXPIRES_IN_PAST` - the `expiresAt` is not in the
+    ///                                        future.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            coverage = %coverage,
+            expires_at = %expires_at.to_rfc3339(),
+            gql.name = "attachContractInsurance",
+            otel.name = Self::SPAN_NAME,
+            policy_number = %policy_number,
+            provider = %provider,
+        ),
+    )]
+    pub async fn attach_contract_insurance(
+        contract_id: api::contract::Id,
+        provider: api::insurance::Provider,
+        policy_number: api::insurance::PolicyNumber,
+        coverage: Money,
+        expires_at: DateTime,
+        ctx: &Context,
+    ) -> Result<api::Insurance, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::AttachContractInsurance {
+                contract_id: contract_id.into(),
+                provider: provider.into(),
+                policy_number: policy_number.into(),
+                coverage,
+                expires_at,
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Detaches the `InsurancePolicy` from the `Contract` with the provided
+    /// ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the rent or management `Contract` with the
+    ///                           provided ID does not exist or is not
+    ///                           managed by the current `User`;
+    /// - `INSURANCE_POLICY_NOT_EXISTS` - the `Contract` has no
+    ///                                   `InsurancePolicy` attached.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "detachContractInsurance",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn detach_contract_insurance(
+        contract_id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<api::Insurance, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::DetachContractInsurance {
+                contract_id: contract_id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Saves a `ContractTemplate` of the current `Agency`.
+    ///
+    /// Creates a new `ContractTemplate` if no `id` is provided, or replaces
+    /// the texts of the existing one otherwise.
+    ///
+    /// Unknown placeholders in the `name` or the `description` are rejected
+    /// as invalid `ContractTemplateText`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_TEMPLATE_NOT_EXISTS` - the `ContractTemplate` with the
+    ///                                    provided ID does not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            description = %description,
+            gql.name = "saveContractTemplate",
+            id = ?id,
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn save_contract_template(
+        id: Option<api::contract::template::Id>,
+        name: api::contract::template::Text,
+        description: api::contract::template::Text,
+        ctx: &Context,
+    ) -> Result<api::contract::template::Template, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::SaveContractTemplate {
+                template_id: id.map(Into::into),
+                name: name.into(),
+                description: description.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Approves the `Contract` with the provided ID, pending a
+    /// `ContractApproval`.
+    ///
+    /// `Contract` must be approved by an employer `User` other than the one
+    /// who created it.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_PENDING_APPROVAL` - the `Contract` with the provided
+    ///                                     ID does not exist, is not pending
+    ///                                     approval, or is terminated or
+    ///                                     expired already;
+    /// - `CONTRACT_SELF_APPROVAL` - the `Contract` was created by the current
+    ///                              `User`;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer;
+    /// - `REALTY_OCCUPIED` - the `Realty` of the rent `Contract` is rented by
+    ///                       another `Contract` within its period;
+    /// - `REALTY_RENTED` - the `Realty` of the `Contract` is rented already.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "approveContract",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn approve_contract(
+        contract_id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<api::contract::approval::Approval, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::ApproveContract {
+                contract_id: contract_id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Adds the `User` with the provided ID as a `ContractParty` of the
+    /// `Contract` with the provided ID in the provided role.
+    ///
+    /// Only the employer `User` managing the `Contract` may add its parties.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
+    ///                           exist, is an `EmploymentContract` or is not
+    ///                           managed by the current `User`;
+    /// - `CONTRACT_PARTY_ALREADY_EXISTS` - the `User` is already a party of
+    ///                                     the `Contract` in the provided
+    ///                                     role;
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not exist.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "addContractParty",
+            otel.name = Self::SPAN_NAME,
+            user_id = %user_id,
+        ),
+    )]
+    pub async fn add_contract_party(
+        contract_id: api::contract::Id,
+        user_id: api::user::Id,
+        role: api::contract::party::Role,
+        ctx: &Context,
+    ) -> Result<api::contract::party::Party, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::AddContractParty {
+                contract_id: contract_id.into(),
+                user_id: user_id.into(),
+                role: role.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Signs the `Contract` with the provided ID on behalf of the current
+    /// `User` in all their `ContractParty` roles.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_ALREADY_SIGNED` - the current `User` has already signed
+    ///                               the `Contract`;
+    /// - `CONTRACT_PARTY_NOT_EXISTS` - the current `User` is not a party of
+    ///                                 the `Contract` with the provided ID.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "signContract",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn sign_contract(
+        contract_id: api::contract::Id,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::party::Party>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::SignContract {
+                contract_id: contract_id.into(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Sets the co-owners of the `Realty` with the provided ID along with
+    /// their shares, replacing the existing ones.
+    ///
+    /// Shares must sum to 100%.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `INVALID_OWNERSHIP_SHARES` - the shares do not sum to 100%, or some
+    ///                                `User` is listed twice or with a zero
+    ///                                share;
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `USER_NOT_EXISTS` - some `User` with the provided ID does not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "setRealtyOwners",
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn set_realty_owners(
+        realty_id: api::realty::Id,
+        owners: Vec<api::realty::OwnerInput>,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::Owner>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::SetRealtyOwners {
+                realty_id: realty_id.into(),
+                agency_id: ctx.agency_id().into(),
+                owners: owners
+                    .into_iter()
+                    .map(|o| (o.user_id.into(), o.share))
+                    .collect(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|o| o.into_iter().map(Into::into).collect())
+    }
+
+    /// Splits the commission of the `Contract` with the provided ID between
+    /// the provided employer `User`s, replacing the existing splits.
+    ///
+    /// Shares must sum to 100%. Only the signing employer of the `Contract`
+    /// may split its commission.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the provided ID does not
+    ///                           exist, is an `EmploymentContract` or is not
+    ///                           signed by the current `User`;
+    /// - `INVALID_COMMISSION_SHARES` - the shares do not sum to 100%, or some
+    ///                                 `User` is listed twice or with a zero
+    ///                                 share;
+    /// - `USER_NOT_EMPLOYED` - some `User` with the provided ID is not
+    ///                         employed by the `Agency` of the `Contract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            contract_id = %contract_id,
+            gql.name = "splitContractCommission",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn split_contract_commission(
+        contract_id: api::contract::Id,
+        splits: Vec<api::contract::commission::SplitInput>,
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::commission::Split>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::SplitContractCommission {
+                contract_id: contract_id.into(),
+                splits: splits
+                    .into_iter()
+                    .map(|s| (s.employer_id.into(), s.share))
+                    .collect(),
+                initiator_id: my_id.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates a new `ShortTermRentContract` booking the `Realty` with the
+    /// provided ID for the nights between `checkInAt` and `checkOutAt`.
+    ///
+    /// Every night is priced by the `RealtySeasonalRate` covering it, or by
+    /// the provided `nightlyRate` otherwise. The booking must not overlap with
+    /// other active `RentContract`s and `ShortTermRentContract`s of the
+    /// `Realty`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `INVALID_BOOKING_PERIOD` - the booking does not span at least one
+    ///                              whole night in the future;
+    /// - `NIGHTLY_RATE_NOT_POSITIVE` - the `nightlyRate` is not positive;
+    /// - `REALTY_NOT_MANAGED` - the `Realty` with the provided ID is not
+    ///                          managed for rent;
+    /// - `REALTY_OCCUPIED` - the `Realty` is already rented or booked within
+    ///                       the booked period;
+    /// - `SEASONAL_RATE_CURRENCY_MISMATCH` - some `RealtySeasonalRate` of the
+    ///                                       booked nights is not in the
+    ///                                       currency of the `nightlyRate`;
+    /// - `USER_NOT_EXISTS` - the `User` with the provided ID does not
+    ///                       exist;
+    /// - `USER_NOT_MANAGER` - the current `User` is not a manager of the
+    ///                        `Realty`;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            check_in_at = check_in_at.to_rfc3339(),
+            check_out_at = check_out_at.to_rfc3339(),
+            deposit = ?deposit.as_ref().map(ToString::to_string),
+            description = %description,
+            gql.name = "createShortTermRentContract",
+            name = %name,
+            nightly_rate = nightly_rate.to_string(),
+            otel.name = Self::SPAN_NAME,
+            purchaser_id = %purchaser_id,
+            realty_id = %realty_id,
+        ),
+    )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
+    pub async fn create_short_term_rent_contract(
+        realty_id: api::realty::Id,
+        purchaser_id: api::user::Id,
+        name: api::contract::Name,
+        description: api::contract::Description,
+        check_in_at: DateTime,
+        check_out_at: DateTime,
+        nightly_rate: Money,
+        deposit: Option<Money>,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+
+        ctx.service()
+            .execute(command::CreateShortTermRentContract {
+                realty_id: realty_id.into(),
+                employer_id: my_id.into(),
+                purchaser_id: purchaser_id.into(),
+                name: name.into(),
+                description: description.into(),
+                check_in_at: check_in_at.coerce(),
+                check_out_at: check_out_at.coerce(),
+                nightly_rate,
+                deposit,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Sets the floor plans of the `Realty` with the provided ID, replacing
+    /// the existing ones.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `DUPLICATE_FLOOR_PLAN_LABELS` - some label is used twice on the same
+    ///                                   floor;
+    /// - `FLOOR_NOT_EXISTS` - some floor exceeds the number of floors of the
+    ///                        `Realty`;
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "setRealtyFloorPlans",
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn set_realty_floor_plans(
+        realty_id: api::realty::Id,
+        floor_plans: Vec<api::realty::FloorPlanInput>,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::FloorPlan>, Error> {
+        let plans = floor_plans
+            .into_iter()
+            .map(|p| {
+                Ok((
+                    p.floor.try_into()?,
+                    p.label.into(),
+                    p.format.into(),
+                    p.url.into(),
+                ))
+            })
+            .collect::<Result<Vec<_>, TryFromIntError>>()
+            .map_err(AsError::into_error)?;
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::SetRealtyFloorPlans {
+                realty_id: realty_id.into(),
+                agency_id: ctx.agency_id().into(),
+                plans,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// Sets the links to external virtual tours and videos of the `Realty`
+    /// with the provided ID, replacing the existing ones.
+    ///
+    /// Links are shown in the provided order.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `INVALID_MEDIA_LINKS` - some link is used twice, or there are too
+    ///                           many of them;
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "setRealtyMediaLinks",
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn set_realty_media_links(
+        realty_id: api::realty::Id,
+        urls: Vec<api::realty::MediaLinkUrl>,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::MediaLink>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::SetRealtyMediaLinks {
+                realty_id: realty_id.into(),
+                agency_id: ctx.agency_id().into(),
+                urls: urls.into_iter().map(Into::into).collect(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|l| l.into_iter().map(Into::into).collect())
+    }
+
+    /// Sets the seasonal nightly rates of `ShortTermRentContract`s on the
+    /// `Realty` with the provided ID, replacing the existing ones.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `INVALID_SEASONAL_RATES` - some season is empty, has a non-positive
+    ///                              rate, or overlaps with another one;
+    /// - `REALTY_NOT_EXISTS` - the `Realty` with the provided ID does not
+    ///                         exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "setSeasonalRates",
+            otel.name = Self::SPAN_NAME,
+            realty_id = %realty_id,
+        ),
+    )]
+    pub async fn set_seasonal_rates(
+        realty_id: api::realty::Id,
+        rates: Vec<api::realty::SeasonalRateInput>,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::SeasonalRate>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(command::SetSeasonalRates {
+                realty_id: realty_id.into(),
+                agency_id: ctx.agency_id().into(),
+                rates: rates
+                    .into_iter()
+                    .map(|r| (r.from..r.until, r.nightly_rate))
+                    .collect(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|r| r.into_iter().map(Into::into).collect())
+    }
+}
+
+impl AsError for command::create_user::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "LOGIN_OCCUPIED"]
+                #[status = CONFLICT]
+                #[message = "`UserLogin` is occupied by another \
+                             `User`"]
+                LoginOccupied,
+
+                #[code = "NO_CONTACT_INFO"]
+                #[status = BAD_REQUEST]
+                #[message = "Either `UserEmail` or `UserPhone` must be \
+                             provided"]
+                NoContactInfo,
+
+                #[code = "UNDELIVERABLE_EMAIL"]
+                #[status = BAD_REQUEST]
+                #[message = "`UserEmail` domain does not accept mail"]
+                UndeliverableEmail,
+
+                #[code = "WEAK_PASSWORD"]
+                #[status = BAD_REQUEST]
+                #[message = "`UserPassword` violates the password policy"]
+                WeakPassword,
+            }
+        }
+
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::LoginOccupied(_) => Some(Error::LoginOccupied.into()),
+            Self::NoContactInfo => Some(Error::NoContactInfo.into()),
+            Self::UndeliverableEmail(_) => {
+                Some(Error::UndeliverableEmail.into())
+            }
+            Self::WeakPassword(rules) => Some(crate::Error {
+                details: rules.iter().map(ToString::to_string).collect(),
+                ..Error::WeakPassword.into()
+            }),
+        }
+    }
+}
+
+impl AsError for command::create_user_session::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "WRONG_CREDENTIALS"]
+                #[status = FORBIDDEN]
+                #[message = "Provided credentials does not match any `User`"]
+                WrongCredentials,
+            }
+        }
+
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::JsonWebTokenEncodeError(_) => None,
+            Self::UserNotExists(_) | Self::WrongCredentials => {
+                Some(Error::WrongCredentials.into())
+            }
+        }
+    }
+}
+
+impl AsError for command::impersonate_user::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "SELF_IMPERSONATION"]
+                #[status = CONFLICT]
+                #[message = "`User` cannot impersonate themselves"]
+                SelfImpersonation,
+
+                #[code = "ADMIN_IMPERSONATION"]
+                #[status = FORBIDDEN]
+                #[message = "`Agency` administrator cannot be impersonated"]
+                AdminImpersonation,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the specified ID does not exist"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::JsonWebTokenEncodeError(_) => return None,
+            Self::SelfImpersonation => Error::SelfImpersonation.into(),
+            Self::UserIsAdmin(_) => Error::AdminImpersonation.into(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::restore_user::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "USER_NOT_DELETED"]
+                #[status = NOT_FOUND]
+                #[message = "Deleted `User` with the provided ID does not \
+                             exist"]
+                UserNotDeleted,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotDeleted(_) => Error::UserNotDeleted.into(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+        })
+    }
+}
+
+impl AsError for command::import_users::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "INVALID_CSV"]
+                #[status = BAD_REQUEST]
+                #[message = "CSV of `User`s is malformed"]
+                InvalidCsv,
+
+                #[code = "LOGIN_OCCUPIED"]
+                #[status = CONFLICT]
+                #[message = "`UserLogin` is occupied by another \
+                             `User`"]
+                LoginOccupied,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::InvalidCsv(violations) => crate::Error {
+                details: violations.iter().map(ToString::to_string).collect(),
+                ..Error::InvalidCsv.into()
+            },
+            Self::LoginsOccupied(logins) => crate::Error {
+                details: logins.iter().map(ToString::to_string).collect(),
+                ..Error::LoginOccupied.into()
+            },
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+        })
+    }
+}
+
+impl AsError for command::accept_user_invite::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "INVITE_EXPIRED"]
+                #[status = CONFLICT]
+                #[message = "`User` invite has expired"]
+                InviteExpired,
+
+                #[code = "INVITE_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` invite with the provided token does not \
+                             exist"]
+                InviteNotExists,
+
+                #[code = "WEAK_PASSWORD"]
+                #[status = BAD_REQUEST]
+                #[message = "`UserPassword` violates the password policy"]
+                WeakPassword,
+            }
+        }
+
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::InviteExpired => Some(Error::InviteExpired.into()),
+            Self::InviteNotExists => Some(Error::InviteNotExists.into()),
+            Self::WeakPassword(rules) => Some(crate::Error {
+                details: rules.iter().map(ToString::to_string).collect(),
+                ..Error::WeakPassword.into()
+            }),
+        }
+    }
+}
+
+impl AsError for command::update_user_name::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::UserNotExists(_) => None,
+        }
+    }
+}
+
+impl AsError for command::update_user_password::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "WRONG_PASSWORD"]
+                #[status = CONFLICT]
+                #[message = "Provided `old_password` does not match the \
+                             current `User` password"]
+                WrongPassword,
+
+                #[code = "WEAK_PASSWORD"]
+                #[status = BAD_REQUEST]
+                #[message = "`UserPassword` violates the password policy"]
+                WeakPassword,
+            }
+        }
+
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::UserNotExists(_) => None,
+            Self::WrongPassword => Some(Error::WrongPassword.into()),
+            Self::WeakPassword(rules) => Some(crate::Error {
+                details: rules.iter().map(ToString::to_string).collect(),
+                ..Error::WeakPassword.into()
+            }),
+        }
+    }
+}
+
+impl AsError for command::update_user_email::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "UNDELIVERABLE_EMAIL"]
+                #[status = BAD_REQUEST]
+                #[message = "`UserEmail` domain does not accept mail"]
+                UndeliverableEmail,
+            }
+        }
+
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::UserNotExists(_) => None,
+            Self::UndeliverableEmail(_) => {
+                Some(Error::UndeliverableEmail.into())
+            }
+        }
+    }
+}
+
+impl AsError for command::update_user_phone::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        match self {
+            Self::Db(e) => e.try_as_error(),
+            Self::UserNotExists(_) => None,
+        }
+    }
+}
+
+impl AsError for command::issue_calendar_feed::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        match self {
+            Self::Db(e) => e.try_as_error(),
+        }
+    }
+}
+
+impl AsError for command::revoke_calendar_feed::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        match self {
+            Self::Db(e) => e.try_as_error(),
+        }
+    }
+}
+
+impl AsError for command::save_search::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "INVALID_PRICE_RANGE"]
+                #[status = BAD_REQUEST]
+                #[message = "`minPrice` must not exceed `maxPrice`"]
+                InvalidPriceRange,
+
+                #[code = "NEGATIVE_PRICE"]
+                #[status = BAD_REQUEST]
+                #[message = "Price must not be negative"]
+                NegativePrice,
+
+                #[code = "PRICE_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "`minPrice` and `maxPrice` must have the same \
+                             currency"]
+                PriceCurrencyMismatch,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::InvalidPriceRange => Error::InvalidPriceRange.into(),
+            Self::NegativePrice => Error::NegativePrice.into(),
+            Self::PriceCurrencyMismatch => Error::PriceCurrencyMismatch.into(),
+        })
+    }
+}
+
+impl AsError for command::delete_saved_search::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::SavedSearchNotExists(_) => {
+                api::saved_search::SavedSearchError::NotExists.into()
+            }
+        })
+    }
+}
+
+impl AsError for command::restore_realty::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "REALTY_NOT_DELETED"]
+                #[status = NOT_FOUND]
+                #[message = "Deleted `Realty` with the provided ID does not \
+                             exist"]
+                RealtyNotDeleted,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::RealtyNotDeleted(_) => Error::RealtyNotDeleted.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+        })
+    }
+}
+
+impl AsError for command::create_employment_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "MANAGER_NOT_EMPLOYED"]
+                #[status = CONFLICT]
+                #[message = "Manager `User` with the provided ID is not \
+                             employed"]
+                ManagerNotEmployed,
+
+                #[code = "USER_EMPLOYED"]
+                #[status = CONFLICT]
+                #[message = "`User` with the provided ID is already employed"]
+                UserAlreadyEmployed,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::BranchNotExists(_) => {
+                api::branch::BranchError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::ManagerNotEmployed(_) => Error::ManagerNotEmployed.into(),
+            Self::UserAlreadyEmployed(_) => Error::UserAlreadyEmployed.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::create_management_for_rent_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "REALTY_MANAGED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is already managed \
+                             for rent"]
+                RealtyAlreadyManaged,
+
+                #[code = "REALTY_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Realty` with the provided ID is not exists"]
+                RealtyNotExists,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::RealtyAlreadyManaged(_) => Error::RealtyAlreadyManaged.into(),
+            Self::RealtyNotExists(_) => Error::RealtyNotExists.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::create_management_for_sale_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "REALTY_MANAGED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is already managed \
+                             for sale"]
+                RealtyAlreadyManaged,
+
+                #[code = "REALTY_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Realty` with the provided ID is not exists"]
+                RealtyNotExists,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::RealtyAlreadyManaged(_) => Error::RealtyAlreadyManaged.into(),
+            Self::RealtyNotExists(_) => Error::RealtyNotExists.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::create_rent_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "LATE_FEE_NOT_APPLICABLE"]
+                #[status = BAD_REQUEST]
+                #[message = "Late fee amounts must be positive and in the \
+                             currency of the price"]
+                LateFeeNotApplicable,
+
+                #[code = "REALTY_NOT_MANAGED"]
+                #[status = FORBIDDEN]
+                #[message = "`Realty` with the provided ID is not managed \
+                             for rent"]
+                RealtyNotManaged,
+
+                #[code = "REALTY_OCCUPIED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` is already rented for the requested \
+                             period"]
+                RealtyOccupied,
+
+                #[code = "REALTY_RENTED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is rented"]
+                RealtyRented,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+
+                #[code = "USER_NOT_MANAGER"]
+                #[status = FORBIDDEN]
+                #[message = "Authenticated `User` is not manager of the \
+                             `Realty`"]
+                UserNotManager,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::LateFeeNotApplicable => Error::LateFeeNotApplicable.into(),
+            Self::OfferNotAccepted(_) => {
+                api::offer::OfferError::NotAccepted.into()
+            }
+            Self::RealtyNotManaged(_) => Error::RealtyNotManaged.into(),
+            Self::RealtyOccupied(_) => Error::RealtyOccupied.into(),
+            Self::RealtyRented(_) => Error::RealtyRented.into(),
+            Self::TemplateNotExists(_) => {
+                api::contract::template::TemplateError::NotExists.into()
+            }
+            Self::TemplateNotRenderable(_) => {
+                api::contract::template::TemplateError::NotRenderable.into()
+            }
+            Self::TemplateRequired => {
+                api::contract::template::TemplateError::Required.into()
+            }
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+            Self::UserNotManager(_) => Error::UserNotManager.into(),
+        })
+    }
+}
+
+impl AsError for command::create_sale_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "REALTY_MANAGED_FOR_RENTED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is managed rent"]
+                RealtyManagedForRent,
+
+                #[code = "REALTY_RENTED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is rented"]
+                RealtyRented,
+
+                #[code = "REALTY_NOT_MANAGED"]
+                #[status = FORBIDDEN]
+                #[message = "`Realty` with the provided ID is not managed \
+                             for sale"]
+                RealtyNotManaged,
+
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+
+                #[code = "USER_NOT_MANAGER"]
+                #[status = FORBIDDEN]
+                #[message = "Authenticated `User` is not manager of the \
+                             `Realty`"]
+                UserNotManager,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::OfferNotAccepted(_) => {
+                api::offer::OfferError::NotAccepted.into()
+            }
+            Self::RealtyManagedForRent(_) => Error::RealtyManagedForRent.into(),
+            Self::RealtyNotManaged(_) => Error::RealtyNotManaged.into(),
+            Self::RealtyRented(_) => Error::RealtyRented.into(),
+            Self::TemplateNotExists(_) => {
+                api::contract::template::TemplateError::NotExists.into()
+            }
+            Self::TemplateNotRenderable(_) => {
+                api::contract::template::TemplateError::NotRenderable.into()
+            }
+            Self::TemplateRequired => {
+                api::contract::template::TemplateError::Required.into()
+            }
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+            Self::UserNotManager(_) => Error::UserNotManager.into(),
+        })
+    }
+}
+
+impl AsError for command::terminate_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Contract` with the provided ID is not exists or \
+                             terminated already"]
+                ContractNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::ContractAlreadyTerminated(_) | Self::ContractNotExists(_) => {
+                Error::ContractNotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+        })
+    }
+}
+
+impl AsError for command::archive_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_ACTIVE"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is still active"]
+                ContractActive,
+
+                #[code = "CONTRACT_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Contract` with the provided ID is not exists"]
+                ContractNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::ContractIsActive(_) => Error::ContractActive.into(),
+            Self::ContractNotExists(_) => Error::ContractNotExists.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+        })
+    }
+}
+
+impl AsError for command::restore_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_NOT_ARCHIVED"]
+                #[status = NOT_FOUND]
+                #[message = "Archived `Contract` with the provided ID does \
+                             not exist"]
+                ContractNotArchived,
+            }
+        }
+
+        Some(match self {
+            Self::ContractNotArchived(_) => Error::ContractNotArchived.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+        })
+    }
+}
+
+impl AsError for command::place_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_ALREADY_PLACED"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is already placed"]
+                ContractAlreadyPlaced,
+
+                #[code = "CONTRACT_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Contract` with the provided ID is not exists"]
+                ContractNotExists,
+
+                #[code = "UNSUPPORTED_CONTRACT"]
+                #[status = BAD_REQUEST]
+                #[message = "Placement of `Contract` with the provided ID is \
+                             not supported"]
+                UnsupportedContract,
+            }
+        }
+
+        Some(match self {
+            Self::ContractAlreadyPlaced(_) => {
+                Error::ContractAlreadyPlaced.into()
+            }
+            Self::ContractNotExists(_) => Error::ContractNotExists.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+            Self::UnsupportedContract(_) => Error::UnsupportedContract.into(),
+        })
+    }
+}
+
+impl AsError for command::deplace_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_NOT_PLACED"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is not placed"]
+                ContractNotPlaced,
+
+                #[code = "CONTRACT_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Contract` with the provided ID is not exists"]
+                ContractNotExists,
+
+                #[code = "UNSUPPORTED_CONTRACT"]
+                #[status = BAD_REQUEST]
+                #[message = "Placement of `Contract` with the provided ID is \
+                             not supported"]
+                UnsupportedContract,
+            }
+        }
+
+        Some(match self {
+            Self::ContractNotPlaced(_) => Error::ContractNotPlaced.into(),
+            Self::ContractNotExists(_) => Error::ContractNotExists.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => return None,
+            Self::UnsupportedContract(_) => Error::UnsupportedContract.into(),
+        })
+    }
+}
+
+impl AsError for command::requeue_task_dead_letters::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotExists(_) => return None,
+        })
+    }
+}
+
+impl AsError for command::update_task_settings::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "INVALID_TASK_SETTINGS"]
+                #[status = BAD_REQUEST]
+                #[message = "Interval and timeout must be positive"]
+                InvalidTaskSettings,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotExists(_) => return None,
+            Self::ZeroDuration => Error::InvalidTaskSettings.into(),
+        })
+    }
+}
+
+impl AsError for command::create_agency::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::update_agency_name::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+        })
+    }
+}
+
+impl AsError for command::update_agency_admin::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "USER_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not exists"]
+                UserNotExists,
+            }
+        }
+
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotExists(_) => Error::UserNotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::create_branch::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+        })
+    }
+}
+
+impl AsError for command::assign_branch::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "USER_NOT_EMPLOYED"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not employed"]
+                UserNotEmployed,
+            }
+        }
+
+        Some(match self {
+            Self::BranchNotExists(_) => {
+                api::branch::BranchError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::UserNotEmployed(_) => Error::UserNotEmployed.into(),
+        })
+    }
+}
+
+impl AsError for command::assign_realty::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "USER_NOT_EMPLOYED"]
+                #[status = NOT_FOUND]
+                #[message = "`User` with the provided ID is not employed"]
+                UserNotEmployed,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+            Self::UserNotEmployed(_) => Error::UserNotEmployed.into(),
+        })
+    }
+}
+
+impl AsError for command::create_lead::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+            Self::UserNotExists(_) => api::query::UserError::NotExists.into(),
+        })
+    }
+}
+
+impl AsError for command::move_lead::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "LEAD_STAGE_NOT_ALLOWED"]
+                #[status = BAD_REQUEST]
+                #[message = "`Lead` cannot be moved back to a previous stage"]
+                StageNotAllowed,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::LeadNotExists(_) => api::lead::LeadError::NotExists.into(),
+            Self::TransitionNotAllowed { .. } => Error::StageNotAllowed.into(),
+        })
+    }
+}
+
+impl AsError for command::submit_offer::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_NOT_PLACED"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is not placed"]
+                ContractNotPlaced,
+
+                #[code = "OFFER_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "Offered price must be in the currency of the \
+                             expected one"]
+                CurrencyMismatch,
+
+                #[code = "OFFER_BY_EMPLOYER"]
+                #[status = FORBIDDEN]
+                #[message = "Employer cannot submit `Offer`s on the `Realty` \
+                             managed by themselves"]
+                BidderIsEmployer,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::ContractNotPlaced(_) => Error::ContractNotPlaced.into(),
+            Self::CurrencyMismatch(_) => Error::CurrencyMismatch.into(),
+            Self::BidderIsEmployer(_) => Error::BidderIsEmployer.into(),
+        })
+    }
+}
+
+impl AsError for command::accept_offer::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::OfferNotExists(_) => api::offer::OfferError::NotExists.into(),
+            Self::OfferAlreadyDecided(_) => {
+                api::offer::OfferError::AlreadyDecided.into()
+            }
+        })
+    }
+}
+
+impl AsError for command::decline_offer::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::OfferNotExists(_) => api::offer::OfferError::NotExists.into(),
+            Self::OfferAlreadyDecided(_) => {
+                api::offer::OfferError::AlreadyDecided.into()
+            }
+        })
+    }
+}
+
+impl AsError for command::counter_offer::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "OFFER_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "Counter-offered price must be in the currency of \
+                             the countered one"]
+                CurrencyMismatch,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::OfferNotExists(_) => api::offer::OfferError::NotExists.into(),
+            Self::OfferAlreadyDecided(_) => {
+                api::offer::OfferError::AlreadyDecided.into()
+            }
+            Self::CurrencyMismatch(_) => Error::CurrencyMismatch.into(),
+        })
+    }
+}
+
+impl AsError for command::create_review::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "CONTRACT_NOT_EXISTS"]
+                #[status = NOT_FOUND]
+                #[message = "`Contract` with the provided ID is not exists"]
+                ContractNotExists,
+
+                #[code = "CONTRACT_NOT_COMPLETED"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is not completed"]
+                ContractNotCompleted,
+
+                #[code = "REVIEW_ALREADY_EXISTS"]
+                #[status = CONFLICT]
+                #[message = "`Contract` with the provided ID is reviewed \
+                             already"]
+                ReviewAlreadyExists,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::ContractNotExists(_) => Error::ContractNotExists.into(),
+            Self::ContractNotCompleted(_) => Error::ContractNotCompleted.into(),
+            Self::ReviewAlreadyExists(_) => Error::ReviewAlreadyExists.into(),
+        })
+    }
+}
+
+impl AsError for command::hide_review::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::ReviewNotExists(_) => {
+                api::review::ReviewError::NotExists.into()
+            }
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+        })
+    }
+}
+
+impl AsError for command::publish_review::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::ReviewNotExists(_) => {
+                api::review::ReviewError::NotExists.into()
+            }
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+        })
+    }
+}
+
+impl AsError for command::record_rent_payment::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "PAYMENT_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "Paid amount must be in the currency of the rent \
+                             price"]
+                CurrencyMismatch,
+
+                #[code = "PAYMENT_NOT_POSITIVE"]
+                #[status = BAD_REQUEST]
+                #[message = "Paid amount must be positive"]
+                NonPositiveAmount,
+            }
+        }
+
+        Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
+            Self::CurrencyMismatch(_) => Error::CurrencyMismatch.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::NonPositiveAmount => Error::NonPositiveAmount.into(),
+        })
+    }
+}
+
+impl AsError for command::add_rent_charge::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "RENT_CHARGE_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "Charge amount must be in the currency of the \
+                             rent price"]
+                CurrencyMismatch,
+
+                #[code = "RENT_CHARGE_NOT_POSITIVE"]
+                #[status = BAD_REQUEST]
+                #[message = "Charge amount must be positive"]
+                NonPositiveAmount,
+            }
+        }
+
+        Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
+            Self::CurrencyMismatch(_) => Error::CurrencyMismatch.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::NonPositiveAmount => Error::NonPositiveAmount.into(),
+        })
+    }
+}
+
+impl AsError for command::remove_rent_charge::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::ChargeNotExists(_) => {
+                api::charge::ChargeError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+        })
+    }
+}
+
+impl AsError for command::record_rent_inventory::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "INVENTORY_ALREADY_RECORDED"]
+                #[status = CONFLICT]
+                #[message = "`RentInventory` is already recorded at the \
+                             provided stage"]
+                AlreadyRecorded,
+
+                #[code = "INVENTORY_DUPLICATE_ITEM"]
+                #[status = BAD_REQUEST]
+                #[message = "Item with the same room and name is listed \
+                             more than once"]
+                DuplicateItem,
+
+                #[code = "INVENTORY_MOVE_IN_NOT_RECORDED"]
+                #[status = CONFLICT]
+                #[message = "Move-in `RentInventory` must be recorded \
+                             before the move-out one"]
+                MoveInNotRecorded,
+
+                #[code = "INVENTORY_NO_ITEMS"]
+                #[status = BAD_REQUEST]
+                #[message = "At least one item must be listed"]
+                NoItems,
+            }
+        }
+
+        Some(match self {
+            Self::AlreadyRecorded(_) => Error::AlreadyRecorded.into(),
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::DuplicateItem => Error::DuplicateItem.into(),
+            Self::MoveInNotRecorded(_) => Error::MoveInNotRecorded.into(),
+            Self::NoItems => Error::NoItems.into(),
+        })
+    }
+}
+
+impl AsError for command::create_realty_key::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        define_error! {
+            enum Error {
+                #[code = "REALTY_KEY_LABEL_OCCUPIED"]
+                #[status = CONFLICT]
+                #[message = "`RealtyKey` with the provided label already \
+                             exists"]
+                LabelOccupied,
+            }
+        }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::LabelOccupied(_) => Error::LabelOccupied.into(),
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+        })
     }
 }
 
-impl AsError for command::create_user_session::ExecutionError {
+impl AsError for command::check_out_realty_key::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "WRONG_CREDENTIALS"]
-                #[status = FORBIDDEN]
-                #[message = "Provided credentials does not match any `User`"]
-                WrongCredentials,
-            }
-        }
+                #[code = "REALTY_KEY_ALREADY_CHECKED_OUT"]
+                #[status = CONFLICT]
+                #[message = "`RealtyKey` is already checked out"]
+                AlreadyCheckedOut,
 
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::JsonWebTokenEncodeError(_) => None,
-            Self::UserNotExists(_) | Self::WrongCredentials => {
-                Some(Error::WrongCredentials.into())
+                #[code = "REALTY_KEY_DUE_IN_PAST"]
+                #[status = BAD_REQUEST]
+                #[message = "`RealtyKey` return must be due in the future"]
+                DueInPast,
             }
         }
+
+        Some(match self {
+            Self::AlreadyCheckedOut(_) => Error::AlreadyCheckedOut.into(),
+            Self::Db(e) => return e.try_as_error(),
+            Self::DueInPast => Error::DueInPast.into(),
+            Self::KeyNotExists(_) => api::key::KeyError::NotExists.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+            Self::UserNotExists(_) => api::query::UserError::NotExists.into(),
+        })
     }
 }
 
-impl AsError for command::update_user_name::ExecutionError {
+impl AsError for command::check_in_realty_key::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::UserNotExists(_) => None,
+        define_error! {
+            enum Error {
+                #[code = "REALTY_KEY_NOT_CHECKED_OUT"]
+                #[status = CONFLICT]
+                #[message = "`RealtyKey` is not checked out"]
+                NotCheckedOut,
+            }
         }
+
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::KeyNotExists(_) => api::key::KeyError::NotExists.into(),
+            Self::NotCheckedOut(_) => Error::NotCheckedOut.into(),
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+        })
     }
 }
 
-impl AsError for command::update_user_password::ExecutionError {
+impl AsError for command::attach_contract_insurance::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "WRONG_PASSWORD"]
-                #[status = CONFLICT]
-                #[message = "Provided `old_password` does not match the \
-                             current `User` password"]
-                WrongPassword,
+                #[code = "INSURANCE_POLICY_EXPIRES_IN_PAST"]
+                #[status = BAD_REQUEST]
+                #[message = "`InsurancePolicy` must expire in the future"]
+                ExpiresInPast,
+
+                #[code = "INSURANCE_POLICY_COVERAGE_NOT_POSITIVE"]
+                #[status = BAD_REQUEST]
+                #[message = "`InsurancePolicy` coverage must be positive"]
+                NonPositiveCoverage,
             }
         }
 
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::UserNotExists(_) => None,
-            Self::WrongPassword => Some(Error::WrongPassword.into()),
-        }
+        Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::ExpiresInPast => Error::ExpiresInPast.into(),
+            Self::NonPositiveCoverage => Error::NonPositiveCoverage.into(),
+        })
     }
 }
 
-impl AsError for command::update_user_email::ExecutionError {
+impl AsError for command::detach_contract_insurance::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::UserNotExists(_) => None,
-        }
+        Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
+            Self::Db(e) => return e.try_as_error(),
+            Self::InsuranceNotExists(_) => {
+                api::insurance::InsuranceError::NotExists.into()
+            }
+        })
     }
 }
 
-impl AsError for command::update_user_phone::ExecutionError {
+impl AsError for command::save_contract_template::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        match self {
-            Self::Db(e) => e.try_as_error(),
-            Self::UserNotExists(_) => None,
-        }
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::TemplateNotExists(_) => {
+                api::contract::template::TemplateError::NotExists.into()
+            }
+            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
+        })
     }
 }
 
-impl AsError for command::create_employment_contract::ExecutionError {
+impl AsError for command::approve_contract::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "USER_EMPLOYED"]
+                #[code = "REALTY_OCCUPIED"]
                 #[status = CONFLICT]
-                #[message = "`User` with the provided ID is already employed"]
-                UserAlreadyEmployed,
+                #[message = "`Realty` is already rented for the requested \
+                             period"]
+                RealtyOccupied,
 
-                #[code = "USER_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`User` with the provided ID is not exists"]
-                UserNotExists,
+                #[code = "REALTY_RENTED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` with the provided ID is rented"]
+                RealtyRented,
             }
         }
 
         Some(match self {
+            Self::ContractNotPending(_) => {
+                api::contract::approval::ApprovalError::NotPending.into()
+            }
             Self::Db(e) => return e.try_as_error(),
-            Self::UserAlreadyEmployed(_) => Error::UserAlreadyEmployed.into(),
+            Self::RealtyOccupied(_) => Error::RealtyOccupied.into(),
+            Self::RealtyRented(_) => Error::RealtyRented.into(),
+            Self::SelfApproval(_) => {
+                api::contract::approval::ApprovalError::SelfApproval.into()
+            }
             Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => Error::UserNotExists.into(),
         })
     }
 }
 
-impl AsError for command::create_management_for_rent_contract::ExecutionError {
+impl AsError for command::add_contract_party::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        define_error! {
-            enum Error {
-                #[code = "REALTY_MANAGED"]
-                #[status = CONFLICT]
-                #[message = "`Realty` with the provided ID is already managed \
-                             for rent"]
-                RealtyAlreadyManaged,
-
-                #[code = "REALTY_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`Realty` with the provided ID is not exists"]
-                RealtyNotExists,
-
-                #[code = "USER_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`User` with the provided ID is not exists"]
-                UserNotExists,
+        Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
             }
-        }
+            Self::Db(e) => return e.try_as_error(),
+            Self::PartyAlreadyExists(_) => {
+                api::contract::party::PartyError::AlreadyExists.into()
+            }
+            Self::UserNotExists(_) => api::query::UserError::NotExists.into(),
+        })
+    }
+}
 
+impl AsError for command::sign_contract::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
         Some(match self {
+            Self::AlreadySigned(_) => {
+                api::contract::party::PartyError::AlreadySigned.into()
+            }
             Self::Db(e) => return e.try_as_error(),
-            Self::RealtyAlreadyManaged(_) => Error::RealtyAlreadyManaged.into(),
-            Self::RealtyNotExists(_) => Error::RealtyNotExists.into(),
-            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => Error::UserNotExists.into(),
+            Self::NotParty(_) => {
+                api::contract::party::PartyError::NotParty.into()
+            }
         })
     }
 }
 
-impl AsError for command::create_management_for_sale_contract::ExecutionError {
+impl AsError for command::set_realty_floor_plans::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        define_error! {
-            enum Error {
-                #[code = "REALTY_MANAGED"]
-                #[status = CONFLICT]
-                #[message = "`Realty` with the provided ID is already managed \
-                             for sale"]
-                RealtyAlreadyManaged,
-
-                #[code = "REALTY_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`Realty` with the provided ID is not exists"]
-                RealtyNotExists,
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::DuplicateLabels => {
+                api::realty::FloorPlanError::DuplicateLabels.into()
+            }
+            Self::FloorNotExists(_) => {
+                api::realty::FloorPlanError::FloorNotExists.into()
+            }
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+        })
+    }
+}
 
-                #[code = "USER_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`User` with the provided ID is not exists"]
-                UserNotExists,
+impl AsError for command::set_realty_media_links::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
+        Some(match self {
+            Self::Db(e) => return e.try_as_error(),
+            Self::InvalidLinks => {
+                api::realty::MediaLinkError::InvalidLinks.into()
             }
-        }
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+        })
+    }
+}
 
+impl AsError for command::set_realty_owners::ExecutionError {
+    fn try_as_error(&self) -> Option<Error> {
         Some(match self {
             Self::Db(e) => return e.try_as_error(),
-            Self::RealtyAlreadyManaged(_) => Error::RealtyAlreadyManaged.into(),
-            Self::RealtyNotExists(_) => Error::RealtyNotExists.into(),
-            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => Error::UserNotExists.into(),
+            Self::InvalidShares => {
+                api::realty::OwnerError::InvalidShares.into()
+            }
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
+            Self::UserNotExists(_) => api::query::UserError::NotExists.into(),
         })
     }
 }
 
-impl AsError for command::create_rent_contract::ExecutionError {
+impl AsError for command::split_contract_commission::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "REALTY_NOT_MANAGED"]
-                #[status = FORBIDDEN]
-                #[message = "`Realty` with the provided ID is not managed \
-                             for rent"]
-                RealtyNotManaged,
-
-                #[code = "USER_NOT_EXISTS"]
+                #[code = "USER_NOT_EMPLOYED"]
                 #[status = NOT_FOUND]
-                #[message = "`User` with the provided ID is not exists"]
-                UserNotExists,
-
-                #[code = "USER_NOT_MANAGER"]
-                #[status = FORBIDDEN]
-                #[message = "Authenticated `User` is not manager of the \
-                             `Realty`"]
-                UserNotManager,
+                #[message = "`User` with the provided ID is not employed"]
+                UserNotEmployed,
             }
         }
 
         Some(match self {
+            Self::ContractNotExists(_) => {
+                api::query::ContractError::NotExists.into()
+            }
             Self::Db(e) => return e.try_as_error(),
-            Self::RealtyNotManaged(_) => Error::RealtyNotManaged.into(),
-            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => Error::UserNotExists.into(),
-            Self::UserNotManager(_) => Error::UserNotManager.into(),
+            Self::InvalidShares => {
+                api::contract::commission::SplitError::InvalidShares.into()
+            }
+            Self::UserNotEmployed(_) => Error::UserNotEmployed.into(),
         })
     }
 }
 
-impl AsError for command::create_sale_contract::ExecutionError {
+impl AsError for command::create_short_term_rent_contract::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "REALTY_MANAGED_FOR_RENTED"]
-                #[status = CONFLICT]
-                #[message = "`Realty` with the provided ID is managed rent"]
-                RealtyManagedForRent,
+                #[code = "SEASONAL_RATE_CURRENCY_MISMATCH"]
+                #[status = BAD_REQUEST]
+                #[message = "Seasonal rates must be in the currency of the \
+                             nightly rate"]
+                CurrencyMismatch,
 
-                #[code = "REALTY_RENTED"]
-                #[status = CONFLICT]
-                #[message = "`Realty` with the provided ID is rented"]
-                RealtyRented,
+                #[code = "INVALID_BOOKING_PERIOD"]
+                #[status = BAD_REQUEST]
+                #[message = "Booked period must span at least one night in \
+                             the future"]
+                InvalidPeriod,
+
+                #[code = "NIGHTLY_RATE_NOT_POSITIVE"]
+                #[status = BAD_REQUEST]
+                #[message = "Nightly rate must be positive"]
+                NonPositiveRate,
 
                 #[code = "REALTY_NOT_MANAGED"]
                 #[status = FORBIDDEN]
                 #[message = "`Realty` with the provided ID is not managed \
-                             for sale"]
+                             for rent"]
                 RealtyNotManaged,
 
+                #[code = "REALTY_OCCUPIED"]
+                #[status = CONFLICT]
+                #[message = "`Realty` is already rented for the requested \
+                             period"]
+                RealtyOccupied,
+
                 #[code = "USER_NOT_EXISTS"]
                 #[status = NOT_FOUND]
                 #[message = "`User` with the provided ID is not exists"]
@@ -938,10 +4387,12 @@ impl AsError for command::create_sale_contract::ExecutionError {
         }
 
         Some(match self {
+            Self::CurrencyMismatch => Error::CurrencyMismatch.into(),
             Self::Db(e) => return e.try_as_error(),
-            Self::RealtyManagedForRent(_) => Error::RealtyManagedForRent.into(),
+            Self::InvalidPeriod => Error::InvalidPeriod.into(),
+            Self::NonPositiveRate => Error::NonPositiveRate.into(),
             Self::RealtyNotManaged(_) => Error::RealtyNotManaged.into(),
-            Self::RealtyRented(_) => Error::RealtyRented.into(),
+            Self::RealtyOccupied(_) => Error::RealtyOccupied.into(),
             Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
             Self::UserNotExists(_) => Error::UserNotExists.into(),
             Self::UserNotManager(_) => Error::UserNotManager.into(),
@@ -949,93 +4400,59 @@ impl AsError for command::create_sale_contract::ExecutionError {
     }
 }
 
-impl AsError for command::terminate_contract::ExecutionError {
+impl AsError for command::set_seasonal_rates::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
-        define_error! {
-            enum Error {
-                #[code = "CONTRACT_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`Contract` with the provided ID is not exists or \
-                             terminated already"]
-                ContractNotExists,
-            }
-        }
-
         Some(match self {
-            Self::ContractAlreadyTerminated(_) | Self::ContractNotExists(_) => {
-                Error::ContractNotExists.into()
-            }
             Self::Db(e) => return e.try_as_error(),
-            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => return None,
+            Self::InvalidRates => {
+                api::realty::SeasonalRateError::InvalidRates.into()
+            }
+            Self::RealtyNotExists(_) => {
+                api::query::RealtyError::NotExists.into()
+            }
         })
     }
 }
 
-impl AsError for command::place_contract::ExecutionError {
+impl AsError for command::update_agency_settings::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "CONTRACT_ALREADY_PLACED"]
-                #[status = CONFLICT]
-                #[message = "`Contract` with the provided ID is already placed"]
-                ContractAlreadyPlaced,
-
-                #[code = "CONTRACT_NOT_EXISTS"]
-                #[status = NOT_FOUND]
-                #[message = "`Contract` with the provided ID is not exists"]
-                ContractNotExists,
-
-                #[code = "UNSUPPORTED_CONTRACT"]
+                #[code = "INVALID_AGENCY_SETTINGS"]
                 #[status = BAD_REQUEST]
-                #[message = "Placement of `Contract` with the provided ID is \
-                             not supported"]
-                UnsupportedContract,
+                #[message = "Contract duration must be positive"]
+                InvalidAgencySettings,
             }
         }
 
         Some(match self {
-            Self::ContractAlreadyPlaced(_) => {
-                Error::ContractAlreadyPlaced.into()
+            Self::AgencyNotExists(_) => {
+                api::agency::AgencyError::NotExists.into()
             }
-            Self::ContractNotExists(_) => Error::ContractNotExists.into(),
             Self::Db(e) => return e.try_as_error(),
-            Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
-            Self::UserNotExists(_) => return None,
-            Self::UnsupportedContract(_) => Error::UnsupportedContract.into(),
+            Self::UserNotAdmin(_) => api::agency::AgencyError::NotAdmin.into(),
+            Self::ZeroDuration => Error::InvalidAgencySettings.into(),
         })
     }
 }
 
-impl AsError for command::deplace_contract::ExecutionError {
+impl AsError for command::update_contract_metadata::ExecutionError {
     fn try_as_error(&self) -> Option<Error> {
         define_error! {
             enum Error {
-                #[code = "CONTRACT_NOT_PLACED"]
-                #[status = CONFLICT]
-                #[message = "`Contract` with the provided ID is not placed"]
-                ContractNotPlaced,
-
                 #[code = "CONTRACT_NOT_EXISTS"]
                 #[status = NOT_FOUND]
-                #[message = "`Contract` with the provided ID is not exists"]
+                #[message = "`Contract` with the provided ID is not exists or \
+                             terminated already"]
                 ContractNotExists,
-
-                #[code = "UNSUPPORTED_CONTRACT"]
-                #[status = BAD_REQUEST]
-                #[message = "Placement of `Contract` with the provided ID is \
-                             not supported"]
-                UnsupportedContract,
             }
         }
 
         Some(match self {
-            Self::ContractNotPlaced(_) => Error::ContractNotPlaced.into(),
             Self::ContractNotExists(_) => Error::ContractNotExists.into(),
             Self::Db(e) => return e.try_as_error(),
             Self::UserNotEmployer(_) => api::PrivilegeError::Employer.into(),
             Self::UserNotExists(_) => return None,
-            Self::UnsupportedContract(_) => Error::UnsupportedContract.into(),
         })
     }
 }
@@ -0,0 +1,276 @@
+//! [`Offer`]-related definitions.
+
+use common::{DateTime, DateTimeOf, Handler as _, Money};
+use derive_more::{Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+use service::{domain, query};
+use uuid::Uuid;
+
+use crate::{api, define_error, AsError, Context, Error};
+
+/// Price offer of a `User` on a placed `Realty`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Offer(domain::Offer);
+
+/// Price offer of a `User` on a placed `Realty`.
+#[graphql_object(context = Context)]
+impl Offer {
+    /// Unique identifier of this `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// Placed `Realty` this `Offer` is submitted on.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.realty",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn realty(&self) -> api::Realty {
+        // SAFETY: `Realty` is referenced by an existing `Offer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::Realty::new_unchecked(self.0.realty_id)
+        }
+    }
+
+    /// `User` who submitted this `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.bidder",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn bidder(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `Offer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.bidder_id)
+        }
+    }
+
+    /// Employer managing the `Realty`, who decides upon this `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.employer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn employer(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `Offer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.employer_id)
+        }
+    }
+
+    /// `User` who proposed this `Offer`.
+    ///
+    /// Either the bidder, or the employer countering a previous `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.proposer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn proposer(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `Offer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.proposer_id)
+        }
+    }
+
+    /// Previous `Offer` countered by this one, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.previous",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn previous(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<Offer>, Error> {
+        let Some(id) = self.0.previous_id else {
+            return Ok(None);
+        };
+
+        ctx.service()
+            .execute(query::offer::ById::by(id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|o| o.map(Into::into))
+    }
+
+    /// `Contract` concluded on the terms of this `Offer`, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.concludedContract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn concluded_contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::ContractValue>, Error> {
+        let Some(id) = self.0.concluded_contract_id else {
+            return Ok(None);
+        };
+
+        ctx.service()
+            .execute(query::contract::ById::by(id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|c| c.map(Into::into))
+    }
+
+    /// Offered price.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.price",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn price(&self) -> Money {
+        self.0.price
+    }
+
+    /// `price` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.formattedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_price(&self, ctx: &Context) -> String {
+        self.0.price.format(ctx.locale().into())
+    }
+
+    /// Current status of this `Offer`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.status",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn status(&self) -> Status {
+        self.0.status.into()
+    }
+
+    /// `DateTime` when this `Offer` was submitted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+
+    /// `DateTime` when this `Offer` was accepted, declined or countered.
+    ///
+    /// `null` if this `Offer` is still pending.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Offer.decidedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn decided_at(&self) -> Option<DateTime> {
+        self.0.decided_at.map(DateTimeOf::coerce)
+    }
+}
+
+/// Unique identifier of an `Offer`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::offer::Id)]
+#[into(domain::offer::Id)]
+#[graphql(name = "OfferId", transparent)]
+pub struct Id(Uuid);
+
+/// Status of an `Offer`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "OfferStatus")]
+pub enum Status {
+    /// `Offer` awaits a decision of the counterparty.
+    Pending,
+
+    /// `Offer` is accepted by the counterparty.
+    Accepted,
+
+    /// `Offer` is declined by the counterparty.
+    Declined,
+
+    /// `Offer` is countered by the counterparty with a new `Offer`.
+    Countered,
+}
+
+impl From<domain::offer::Status> for Status {
+    fn from(status: domain::offer::Status) -> Self {
+        use domain::offer::Status as S;
+        match status {
+            S::Pending => Self::Pending,
+            S::Accepted => Self::Accepted,
+            S::Declined => Self::Declined,
+            S::Countered => Self::Countered,
+        }
+    }
+}
+
+define_error! {
+    enum OfferError {
+        #[code = "OFFER_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Offer` does not exist"]
+        NotExists,
+
+        #[code = "OFFER_ALREADY_DECIDED"]
+        #[status = CONFLICT]
+        #[message = "`Offer` is accepted, declined or countered already"]
+        AlreadyDecided,
+
+        #[code = "OFFER_NOT_ACCEPTED"]
+        #[status = CONFLICT]
+        #[message = "`Offer` is not accepted or concluded already"]
+        NotAccepted,
+    }
+}
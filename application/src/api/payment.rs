@@ -0,0 +1,112 @@
+//! [`Payment`]-related definitions.
+
+use common::{DateTime, Money};
+use derive_more::{Display, From, Into};
+use juniper::{graphql_object, GraphQLScalar};
+use service::domain;
+use uuid::Uuid;
+
+use crate::{api, Context};
+
+/// Payment recorded under a rent `Contract`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Payment(domain::Payment);
+
+/// Payment recorded under a rent `Contract`.
+#[graphql_object(name = "RentPayment", context = Context)]
+impl Payment {
+    /// Unique identifier of this `RentPayment`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// `RentContract` this `RentPayment` is made under.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn contract(&self) -> api::contract::Rent {
+        // SAFETY: `Contract` is referenced by an existing `RentPayment`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::contract::Rent::new_unchecked(self.0.contract_id)
+        }
+    }
+
+    /// Paid amount.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.amount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn amount(&self) -> Money {
+        self.0.amount
+    }
+
+    /// `amount` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.formattedAmount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_amount(&self, ctx: &Context) -> String {
+        self.0.amount.format(ctx.locale().into())
+    }
+
+    /// Employer `User` who recorded this `RentPayment`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.recorder",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn recorder(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `RentPayment`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.recorder_id)
+        }
+    }
+
+    /// `DateTime` when this `RentPayment` was recorded.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentPayment.paidAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn paid_at(&self) -> DateTime {
+        self.0.paid_at.coerce()
+    }
+}
+
+/// Unique identifier of a `RentPayment`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::payment::Id)]
+#[into(domain::payment::Id)]
+#[graphql(name = "RentPaymentId", transparent)]
+pub struct Id(Uuid);
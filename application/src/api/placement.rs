@@ -1,12 +1,12 @@
 //! [`Placement`]-related definitions.
 
-use common::{DateTime, Handler as _, Money};
+use common::{DateTime, Handler as _, Money, Percent};
 use futures::{
     future, stream::FuturesUnordered, TryFutureExt as _, TryStreamExt as _,
 };
 use itertools::Either;
 use juniper::{graphql_object, GraphQLObject};
-use service::{query, read};
+use service::{domain, query, read};
 use tokio::sync::OnceCell;
 
 #[cfg(doc)]
@@ -147,7 +147,7 @@ impl Placement {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Ok(None);
         }
@@ -180,7 +180,7 @@ impl Placement {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Ok(None);
         }
@@ -206,9 +206,14 @@ impl Placement {
             return Ok(None);
         };
 
+        let locale = ctx.locale().into();
+        let price = c.expected_price(ctx).await?;
+        let deposit = c.expected_deposit(ctx).await?;
         Ok(Some(RentInfo {
-            price: c.expected_price(ctx).await?,
-            deposit: c.expected_deposit(ctx).await?,
+            price,
+            formatted_price: price.format(locale),
+            deposit,
+            formatted_deposit: deposit.map(|d| d.format(locale)),
             employer: c.employer(ctx).await?.clone(),
         }))
     }
@@ -231,13 +236,69 @@ impl Placement {
             return Ok(None);
         };
 
+        let locale = ctx.locale().into();
+        let price = c.expected_price(ctx).await?;
+        let deposit = c.expected_deposit(ctx).await?;
         Ok(Some(SaleInfo {
-            price: c.expected_price(ctx).await?,
-            deposit: c.expected_deposit(ctx).await?,
+            price,
+            formatted_price: price.format(locale),
+            deposit,
+            formatted_deposit: deposit.map(|d| d.format(locale)),
             employer: c.employer(ctx).await?.clone(),
         }))
     }
 
+    /// Calculates a `Mortgage` for buying the `Realty` this `Placement` is
+    /// about, with the provided annual interest `rate` and term in months.
+    ///
+    /// The borrowed amount is the sale price reduced by the `downPayment`, if
+    /// any. No `Mortgage` is returned if the `Realty` is not for sale.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Placement.mortgage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn mortgage(
+        &self,
+        rate: Percent,
+        term_months: i32,
+        down_payment: Option<Money>,
+        ctx: &Context,
+    ) -> Result<Option<api::finance::Mortgage>, Error> {
+        use api::finance::MortgageError as E;
+
+        let Some(c) = self.management_for_sale_contract(ctx).await? else {
+            return Ok(None);
+        };
+
+        let term_months =
+            u16::try_from(term_months).map_err(|_| E::InvalidTerm)?;
+
+        let mut principal = c.expected_price(ctx).await?;
+        if let Some(down) = down_payment {
+            if down.currency != principal.currency
+                || down.amount.is_sign_negative()
+                || down.amount > principal.amount
+            {
+                return Err(E::InvalidDownPayment.into());
+            }
+            principal.amount -= down.amount;
+        }
+
+        ctx.service()
+            .execute(query::finance::Mortgage {
+                principal,
+                rate,
+                term_months,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|o| Some(api::finance::Mortgage::new(o, ctx.locale().into())))
+    }
+
     /// `DateTime` when the `Realty` was placed.
     #[tracing::instrument(
         skip_all,
@@ -274,6 +335,130 @@ impl Placement {
         .min()
         .ok_or_else(|| api::query::ContractError::NotExists.into())
     }
+
+    /// External virtual tours and videos of the `Realty` this `Placement` is
+    /// about, in the order they should be shown.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Placement.mediaLinks",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn media_links(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::realty::MediaLink>, Error> {
+        ctx.service()
+            .execute(query::realty::MediaLinks::by(self.placement.realty_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|l| l.into_iter().map(Into::into).collect())
+    }
+
+    /// `Offer`s submitted on the `Realty` this `Placement` is about, in the
+    /// order of their submission.
+    ///
+    /// Employers see all the `Offer`s, while other `User`s see only their own
+    /// ones. No `Offer`s are returned if the current session is not
+    /// authenticated.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Placement.offers",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn offers(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Offer>, Error> {
+        let Some(session) = ctx.try_current_session().await? else {
+            return Ok(vec![]);
+        };
+
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(session.user_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+
+        let my_id = session.user_id.into();
+        Ok(ctx
+            .service()
+            .execute(query::offer::ByRealty::by(self.placement.realty_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .into_iter()
+            .filter(|o| is_employed || o.bidder_id == my_id)
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Windows within the provided period the `Realty` this `Placement` is
+    /// about is not occupied by active rent or short-term rent `Contract`s, in
+    /// their chronological order.
+    ///
+    /// Period starts at the current moment, unless `from` is specified.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Placement.availability",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn availability(
+        &self,
+        from: Option<DateTime>,
+        until: DateTime,
+        ctx: &Context,
+    ) -> Result<Vec<AvailabilityWindow>, Error> {
+        let rents = ctx
+            .service()
+            .execute(query::contract::ActiveRents::by(self.placement.realty_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?;
+        let bookings = ctx
+            .service()
+            .execute(query::contract::ActiveShortTermRents::by(
+                self.placement.realty_id,
+            ))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?;
+        let calendar = domain::Calendar::new(
+            rents
+                .iter()
+                .map(|c| domain::calendar::Occupancy::of_rent(&c.0))
+                .chain(bookings.iter().map(|c| {
+                    domain::calendar::Occupancy::of_short_term_rent(&c.0)
+                })),
+        );
+        Ok(calendar
+            .availability(from.unwrap_or_else(DateTime::now), until)
+            .into_iter()
+            .map(|w| AvailabilityWindow {
+                from: w.from,
+                until: w.until,
+            })
+            .collect())
+    }
+}
+
+/// Period the `Realty` is available for rent within.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "PlacementAvailabilityWindow", context = Context)]
+pub struct AvailabilityWindow {
+    /// `DateTime` the `Realty` becomes available at.
+    pub from: DateTime,
+
+    /// `DateTime` the `Realty` stops being available at.
+    pub until: DateTime,
 }
 
 /// Information about `Realty` rent.
@@ -283,9 +468,15 @@ pub struct RentInfo {
     /// Price of the rent.
     pub price: Money,
 
+    /// `price` formatted according to the `Accept-Language` header.
+    pub formatted_price: String,
+
     /// Deposit the purchaser should pay.
     pub deposit: Option<Money>,
 
+    /// `deposit` formatted according to the `Accept-Language` header.
+    pub formatted_deposit: Option<String>,
+
     /// Employer managing the `Realty`.
     pub employer: api::User,
 }
@@ -297,9 +488,15 @@ pub struct SaleInfo {
     /// Price of the `Realty`.
     pub price: Money,
 
+    /// `price` formatted according to the `Accept-Language` header.
+    pub formatted_price: String,
+
     /// Deposit the purchaser should pay.
     pub deposit: Option<Money>,
 
+    /// `deposit` formatted according to the `Accept-Language` header.
+    pub formatted_deposit: Option<String>,
+
     /// Employer managing the `Realty`.
     pub employer: api::User,
 }
@@ -307,8 +504,9 @@ pub struct SaleInfo {
 pub mod list {
     //! Definitions related to a [`Placement`] list.
 
+    use common::Money;
     use derive_more::{AsRef, From, Into};
-    use juniper::{graphql_object, GraphQLScalar};
+    use juniper::{graphql_object, GraphQLObject, GraphQLScalar};
     use service::{query, read, Query as _};
 
     use crate::{
@@ -348,8 +546,27 @@ pub mod list {
     }
 
     /// Connection of the [`Placement`] list.
-    #[derive(Clone, Debug, From, Into)]
-    pub struct Connection(read::placement::list::Connection);
+    #[derive(Clone, Debug)]
+    pub struct Connection {
+        /// Underlying [`read::placement::list::Connection`].
+        connection: read::placement::list::Connection,
+
+        /// [`read::placement::list::Filter`] the list is selected by.
+        filter: read::placement::list::Filter,
+    }
+
+    impl Connection {
+        /// Creates a new [`Connection`] of the provided
+        /// [`read::placement::list::Connection`] selected by the provided
+        /// [`read::placement::list::Filter`].
+        #[must_use]
+        pub fn new(
+            connection: read::placement::list::Connection,
+            filter: read::placement::list::Filter,
+        ) -> Self {
+            Self { connection, filter }
+        }
+    }
 
     /// Connection of the `Contract` list.
     #[graphql_object(name = "PlacementListConnection", context = Context)]
@@ -357,22 +574,127 @@ pub mod list {
         /// Edges in this `PlacementListConnection`.
         #[must_use]
         pub fn edges(&self) -> Vec<Edge> {
-            self.0.edges.iter().copied().map(Into::into).collect()
+            self.connection.edges.iter().copied().map(Into::into).collect()
         }
 
         /// Information about the page.
         #[must_use]
         pub fn page_info(&self) -> PageInfo {
+            let edges = &self.connection.edges;
             PageInfo {
-                info: self.0.page_info(),
-                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
-                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+                info: self.connection.page_info(),
+                start_cursor: edges.first().map(|e| e.cursor.into()),
+                end_cursor: edges.last().map(|e| e.cursor.into()),
+                filter: self.filter.clone(),
+            }
+        }
+
+        /// Counts of all the `Placement`s matching the filters of this
+        /// `PlacementListConnection` (regardless of its pages), grouped by
+        /// their cities, kinds and price ranges.
+        #[tracing::instrument(
+            skip_all,
+            fields(
+                gql.name = "PlacementListConnection.facets",
+                otel.name = api::Query::SPAN_NAME,
+            ),
+        )]
+        pub async fn facets(&self, ctx: &Context) -> Result<Facets, Error> {
+            ctx.service()
+                .execute(query::placements::Facets::by(self.filter.clone()))
+                .await
+                .map_err(AsError::into_error)
+                .map_err(ctx.error())
+                .map(Into::into)
+        }
+    }
+
+    /// Counts of the `Placement`s, grouped by their different properties.
+    #[derive(Clone, Debug, GraphQLObject)]
+    #[graphql(name = "PlacementListFacets", context = Context)]
+    pub struct Facets {
+        /// Counts of the `Placement`s per city, in the descending order.
+        pub cities: Vec<CityFacet>,
+
+        /// Number of the `Placement`s for rent.
+        pub rent_count: i32,
+
+        /// Number of the `Placement`s for sale.
+        pub sale_count: i32,
+
+        /// Counts of the `Placement`s for rent per price range, in the order
+        /// of their currencies and prices.
+        pub rent_prices: Vec<PriceFacet>,
+
+        /// Counts of the `Placement`s for sale per price range, in the order
+        /// of their currencies and prices.
+        pub sale_prices: Vec<PriceFacet>,
+    }
+
+    impl From<read::placement::list::Facets> for Facets {
+        fn from(facets: read::placement::list::Facets) -> Self {
+            let read::placement::list::Facets {
+                cities,
+                rent,
+                sale,
+                rent_prices,
+                sale_prices,
+            } = facets;
+            Self {
+                cities: cities
+                    .into_iter()
+                    .map(|f| CityFacet {
+                        city: f.city.into(),
+                        count: f.count,
+                    })
+                    .collect(),
+                rent_count: rent,
+                sale_count: sale,
+                rent_prices: rent_prices.into_iter().map(Into::into).collect(),
+                sale_prices: sale_prices.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    /// Number of the `Placement`s in a city.
+    #[derive(Clone, Debug, GraphQLObject)]
+    #[graphql(name = "PlacementCityFacet", context = Context)]
+    pub struct CityFacet {
+        /// City of the placed `Realty`s.
+        pub city: api::realty::City,
+
+        /// Number of the `Placement`s in the `city`.
+        pub count: i32,
+    }
+
+    /// Number of the `Placement`s within a price range.
+    #[derive(Clone, Copy, Debug, GraphQLObject)]
+    #[graphql(name = "PlacementPriceFacet", context = Context)]
+    pub struct PriceFacet {
+        /// Minimal price (inclusive) of the range.
+        pub min: Money,
+
+        /// Maximal price (exclusive) of the range.
+        ///
+        /// No price means the range is unbounded.
+        pub max: Option<Money>,
+
+        /// Number of the `Placement`s within the range.
+        pub count: i32,
+    }
+
+    impl From<read::placement::list::PriceFacet> for PriceFacet {
+        fn from(facet: read::placement::list::PriceFacet) -> Self {
+            Self {
+                min: facet.min,
+                max: facet.max,
+                count: facet.count,
             }
         }
     }
 
     /// Information about a [`Connection`] page.
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Debug)]
     pub struct PageInfo {
         /// Underlying [`read::placement::list::PageInfo`].
         info: read::placement::list::PageInfo,
@@ -382,6 +704,9 @@ pub mod list {
 
         /// End cursor of the page.
         end_cursor: Option<Cursor>,
+
+        /// [`read::placement::list::Filter`] the list is selected by.
+        filter: read::placement::list::Filter,
     }
 
     /// Information about a `PlacementListConnection` page.
@@ -414,7 +739,7 @@ pub mod list {
         /// Total `Placement`s count.
         pub async fn total_count(&self, ctx: &Context) -> Result<i32, Error> {
             ctx.service()
-                .execute(query::placements::TotalCount::by(()))
+                .execute(query::placements::TotalCount::by(self.filter.clone()))
                 .await
                 .map_err(AsError::into_error)
                 .map_err(ctx.error())
@@ -1,9 +1,10 @@
 //! GraphQL [`Query`]s definitions.
 
-use common::DateTime;
+use common::{DateTime, Money, TimeZone};
 use itertools::Itertools as _;
 use juniper::graphql_object;
-use service::{query, read, Query as _};
+use service::{domain, query, read, Query as _};
+use uuid::Uuid;
 
 use crate::{api, define_error, AsError, Context, Error};
 
@@ -38,8 +39,175 @@ impl Query {
             .map(Into::into)
     }
 
+    /// Returns the current `Session`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "mySession",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn my_session(
+        ctx: &Context,
+    ) -> Result<api::user::session::Session, Error> {
+        ctx.current_session().await.map(Into::into)
+    }
+
+    /// Returns `User`s reporting directly to the currently authenticated one,
+    /// in the order of their employment.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "myTeam",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn my_team(ctx: &Context) -> Result<Vec<api::User>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        Ok(ctx
+            .service()
+            .execute(query::contract::Team::by(read::contract::DirectReports(
+                my_id.into(),
+            )))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .into_iter()
+            .map(|read::contract::Active(c)| {
+                // SAFETY: `User` is referenced by an existing `Contract`.
+                #[expect(
+                    clippy::allow_attributes,
+                    reason = "TODO: Remove once clippy is fixed"
+                )]
+                #[allow(unsafe_code, reason = "invariants are preserved")]
+                unsafe {
+                    api::User::new_unchecked(c.employer_id)
+                }
+            })
+            .collect())
+    }
+
+    /// Returns the `SavedSearch`es of the currently authenticated `User`, in
+    /// the order of their creation.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "mySavedSearches",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn my_saved_searches(
+        ctx: &Context,
+    ) -> Result<Vec<api::SavedSearch>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        Ok(ctx
+            .service()
+            .execute(query::saved_search::ByUser::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Fetches the page of `Realty`s assigned to the currently authenticated
+    /// `User`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `PAGINATION_CURSOR_MISMATCH` - the pagination cursor belongs to
+    ///                                  another `sortBy` order;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            after = ?after,
+            before = ?before,
+            first = ?first,
+            gql.name = "myAssignedRealties",
+            last = ?last,
+            otel.name = Self::SPAN_NAME,
+            sort_by = ?sort_by,
+        ),
+    )]
+    pub async fn my_assigned_realties(
+        first: Option<i32>,
+        after: Option<api::realty::list::Cursor>,
+        last: Option<i32>,
+        before: Option<api::realty::list::Cursor>,
+        sort_by: Option<api::realty::list::SortBy>,
+        ctx: &Context,
+    ) -> Result<api::realty::list::Connection, Error> {
+        const DEFAULT_PAGE_SIZE: i32 = 10;
+
+        let sort_by =
+            read::realty::list::SortBy::from(sort_by.unwrap_or_default());
+        let arguments = read::realty::list::Arguments::new(
+            first,
+            after.map(Into::into),
+            last,
+            before.map(Into::into),
+            DEFAULT_PAGE_SIZE,
+        )
+        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        .map_err(ctx.error())?;
+        if arguments.cursor().is_some_and(|c| c.key.sort_by() != sort_by) {
+            return Err(api::PaginationError::CursorMismatch.into());
+        }
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        let filter = read::realty::list::Filter {
+            agency_id: Some(ctx.agency_id().into()),
+            assignee_id: Some(my_id.into()),
+            address: None,
+            ids: None,
+            sort_by,
+        };
+        ctx.service()
+            .execute(query::realties::List::by(read::realty::list::Selector {
+                arguments,
+                filter: filter.clone(),
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|c| api::realty::list::Connection::new(c, filter))
+    }
+
     /// Returns the `User` with the specified ID.
     ///
+    /// `User`s of other `Agency`s are not visible, except the current `User`.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
@@ -58,7 +226,18 @@ impl Query {
         id: api::user::Id,
         ctx: &Context,
     ) -> Result<api::user::list::Edge, Error> {
-        Self::users(None, Some(id.into()), None, Some(id.into()), None, ctx)
+        Self::users(
+            None,
+            Some(id.into()),
+            None,
+            Some(id.into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            ctx,
+        )
             .await?
             .edges()
             .into_iter()
@@ -69,42 +248,88 @@ impl Query {
 
     /// Fetches the page of `User`s.
     ///
+    /// Either cursor (`first`/`after`/`last`/`before`) or offset
+    /// (`page`/`perPage`) pagination may be used, but not both at once.
+    ///
+    /// Only the `User`s of the current `Agency` are returned, being the ones
+    /// actively employed by it or having a pending invite into it.
+    ///
+    /// Only the `User`s employed in the `Branch` with the provided `branchId`
+    /// are returned, if it's specified.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
     /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `page` or `perPage` is not
+    ///                                    positive;
+    /// - `PAGINATION_CURSOR_MISMATCH` - the pagination cursor belongs to
+    ///                                  another `sortBy` order;
+    /// - `BRANCH_NOT_EXISTS` - the `Branch` with the provided `branchId` does
+    ///                         not exist;
     /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
         fields(
             after = ?after,
             before = ?before,
+            branch_id = ?branch_id,
             first = ?first,
             gql.name = "users",
             last = ?last,
             name = ?name.as_ref().map(ToString::to_string),
             otel.name = Self::SPAN_NAME,
+            page = ?page,
+            per_page = ?per_page,
+            sort_by = ?sort_by,
         ),
     )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn users(
         first: Option<i32>,
         after: Option<api::user::list::Cursor>,
         last: Option<i32>,
         before: Option<api::user::list::Cursor>,
+        page: Option<i32>,
+        per_page: Option<i32>,
         name: Option<api::user::Name>,
+        branch_id: Option<api::branch::Id>,
+        sort_by: Option<api::user::list::SortBy>,
         ctx: &Context,
     ) -> Result<api::user::list::Connection, Error> {
         const DEFAULT_PAGE_SIZE: i32 = 10;
+        const MAX_PAGE_SIZE: i32 = 100;
 
-        let arguments = read::user::list::Arguments::new(
-            first,
-            after.map(Into::into),
-            last,
-            before.map(Into::into),
-            DEFAULT_PAGE_SIZE,
-        )
-        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        let sort_by =
+            read::user::list::SortBy::from(sort_by.unwrap_or_default());
+        let arguments = match (page, per_page) {
+            (None, None) => read::user::list::Arguments::new(
+                first,
+                after.map(Into::into),
+                last,
+                before.map(Into::into),
+                DEFAULT_PAGE_SIZE,
+            )
+            .ok_or_else(|| api::PaginationError::Ambiguous.into()),
+            _ if first.is_some()
+                || after.is_some()
+                || last.is_some()
+                || before.is_some() =>
+            {
+                Err(api::PaginationError::Ambiguous.into())
+            }
+            (page, per_page) => read::user::list::Arguments::new_offset(
+                page,
+                per_page,
+                DEFAULT_PAGE_SIZE,
+                MAX_PAGE_SIZE,
+            )
+            .ok_or_else(|| api::PaginationError::Invalid.into()),
+        }
         .map_err(ctx.error())?;
+        if arguments.cursor().is_some_and(|c| c.key.sort_by() != sort_by) {
+            return Err(api::PaginationError::CursorMismatch.into());
+        }
 
         let my_id = ctx.current_session().await?.user_id;
         let is_employed = ctx
@@ -113,9 +338,9 @@ impl Query {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         let (is_myself, is_employer) =
-            if let Some(id) = arguments.exact_cursor().copied() {
+            if let Some(id) = arguments.exact_cursor().map(|c| c.id) {
                 let is_myself = api::user::Id::from(id) == my_id;
                 let is_employer = ctx
                     .service()
@@ -123,7 +348,7 @@ impl Query {
                     .await
                     .map_err(AsError::into_error)
                     .map_err(ctx.error())?
-                    .is_some();
+                    .is_some_and(|e| ctx.is_agency_employment(&e.0));
                 (is_myself, is_employer)
             } else {
                 (false, false)
@@ -131,18 +356,35 @@ impl Query {
         if !is_employed && !is_myself && !is_employer {
             return Err(api::PrivilegeError::Employer.into());
         }
+        if let Some(branch_id) = branch_id {
+            // `Branch`es of other `Agency`s are not visible.
+            ctx.service()
+                .execute(query::branch::ById::by(branch_id.into()))
+                .await
+                .map_err(AsError::into_error)
+                .map_err(ctx.error())?
+                .filter(|b| b.agency_id == ctx.agency_id().into())
+                .ok_or_else(|| api::branch::BranchError::NotExists.into())
+                .map_err(ctx.error())
+                .map(drop)?;
+        }
 
+        let filter = read::user::list::Filter {
+            // The current `User` is always visible to themselves.
+            agency_id: (!is_myself).then(|| ctx.agency_id().into()),
+            branch_id: branch_id.map(Into::into),
+            name: name.map(Into::into),
+            sort_by,
+        };
         ctx.service()
             .execute(query::users::List::by(read::user::list::Selector {
                 arguments,
-                filter: read::user::list::Filter {
-                    name: name.map(Into::into),
-                },
+                filter: filter.clone(),
             }))
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map(Into::into)
+            .map(|c| api::user::list::Connection::new(c, filter))
     }
 
     /// Returns the `Placement` with the specified ID.
@@ -171,6 +413,7 @@ impl Query {
             Some(id.into()),
             None,
             None,
+            None,
             ctx,
         )
         .await?
@@ -183,6 +426,8 @@ impl Query {
 
     /// Fetches the page of `Placement`s.
     ///
+    /// `address` is fuzzy searched for among the placed `Realty`s.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
@@ -190,6 +435,7 @@ impl Query {
     #[tracing::instrument(
         skip_all,
         fields(
+            address = ?address.as_ref().map(ToString::to_string),
             after = ?after,
             before = ?before,
             first = ?first,
@@ -200,6 +446,7 @@ impl Query {
             otel.name = Self::SPAN_NAME,
         ),
     )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn placements(
         first: Option<i32>,
         after: Option<api::placement::list::Cursor>,
@@ -207,10 +454,18 @@ impl Query {
         before: Option<api::placement::list::Cursor>,
         include_sale: Option<bool>,
         include_rent: Option<bool>,
+        address: Option<api::realty::Address>,
         ctx: &Context,
     ) -> Result<api::placement::list::Connection, Error> {
         const DEFAULT_PAGE_SIZE: i32 = 10;
 
+        let filter = read::placement::list::Filter {
+            agency_id: Some(ctx.agency_id().into()),
+            rent: include_rent.unwrap_or(true),
+            sale: include_sale.unwrap_or(true),
+            address: address.map(Into::into),
+            realty_ids: None,
+        };
         ctx.service()
             .execute(query::placements::List::by(
                 read::placement::list::Selector {
@@ -223,51 +478,84 @@ impl Query {
                     )
                     .ok_or_else(|| api::PaginationError::Ambiguous.into())
                     .map_err(ctx.error())?,
-                    filter: read::placement::list::Filter {
-                        rent: include_rent.unwrap_or(true),
-                        sale: include_sale.unwrap_or(true),
-                    },
+                    filter: filter.clone(),
                 },
             ))
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map(Into::into)
+            .map(|c| api::placement::list::Connection::new(c, filter))
     }
 
-    /// Returns the `Contract` with the specified ID.
+    /// Returns the `Contract` with the specified ID or `ContractNumber`.
     ///
     /// # Errors
     ///
     /// Possible error codes:
-    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the specified ID does not
-    ///                           exist;
+    /// - `CONTRACT_LOOKUP_AMBIGUOUS` - none or both of `id` and `number` are
+    ///                                 provided;
+    /// - `CONTRACT_NOT_EXISTS` - the `Contract` with the specified ID or
+    ///                           `ContractNumber` does not exist;
     /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
         fields(
-            id = %id,
             gql.name = "contract",
+            id = ?id,
+            number = ?number,
             otel.name = Self::SPAN_NAME,
         ),
     )]
     pub async fn contract(
-        id: api::contract::Id,
+        id: Option<api::contract::Id>,
+        number: Option<api::contract::Number>,
         ctx: &Context,
     ) -> Result<api::contract::list::Edge, Error> {
         let my_id = ctx.current_session().await?.user_id;
-        let is_employed = ctx
+        let read::contract::Active(employment) = ctx
             .service()
             .execute(query::contract::Employment::by(my_id.into()))
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
-        if !is_employed {
-            return Err(api::PrivilegeError::Employer.into());
-        }
+            .filter(|e| ctx.is_agency_employment(&e.0))
+            .ok_or_else(|| api::PrivilegeError::Employer.into())
+            .map_err(ctx.error())?;
+
+        let id: api::contract::Id = match (id, number) {
+            (Some(id), None) => id,
+            (None, Some(number)) => ctx
+                .service()
+                .execute(query::contract::ByNumber::by(
+                    read::contract::ByNumber {
+                        agency_id: employment.agency_id,
+                        number: number.into(),
+                    },
+                ))
+                .await
+                .map_err(AsError::into_error)
+                .map_err(ctx.error())?
+                .ok_or_else(|| ContractError::NotExists.into())
+                .map_err(ctx.error())?
+                .id()
+                .into(),
+            (None, None) | (Some(_), Some(_)) => {
+                return Err(ContractError::Ambiguous.into());
+            }
+        };
 
-        Self::contracts(None, Some(id.into()), None, Some(id.into()), None, ctx)
+        Self::contracts(
+            None,
+            Some(id.into()),
+            None,
+            Some(id.into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            ctx,
+        )
             .await?
             .edges()
             .into_iter()
@@ -278,32 +566,87 @@ impl Query {
 
     /// Fetches the page of `Contract`s.
     ///
+    /// Either cursor (`first`/`after`/`last`/`before`) or offset
+    /// (`page`/`perPage`) pagination may be used, but not both at once.
+    ///
+    /// Only the `EmploymentContract`s of the `Branch` with the provided
+    /// `branchId` are returned, if it's specified.
+    ///
+    /// Archived `Contract`s are returned only if `includeArchived` is `true`.
+    ///
     /// # Errors
     ///
     /// Possible error codes:
     /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `page` or `perPage` is not
+    ///                                    positive;
+    /// - `PAGINATION_CURSOR_MISMATCH` - the pagination cursor belongs to
+    ///                                  another `sortBy` order;
     /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
         fields(
             after = ?after,
             before = ?before,
+            branch_id = ?branch_id,
             first = ?first,
             gql.name = "contracts",
+            include_archived = ?include_archived,
             last = ?last,
             name = ?name.as_ref().map(ToString::to_string),
             otel.name = Self::SPAN_NAME,
+            page = ?page,
+            per_page = ?per_page,
+            sort_by = ?sort_by,
         ),
     )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
     pub async fn contracts(
         first: Option<i32>,
         after: Option<api::contract::list::Cursor>,
         last: Option<i32>,
         before: Option<api::contract::list::Cursor>,
+        page: Option<i32>,
+        per_page: Option<i32>,
         name: Option<api::contract::Name>,
+        branch_id: Option<api::branch::Id>,
+        include_archived: Option<bool>,
+        sort_by: Option<api::contract::list::SortBy>,
         ctx: &Context,
     ) -> Result<api::contract::list::Connection, Error> {
         const DEFAULT_PAGE_SIZE: i32 = 10;
+        const MAX_PAGE_SIZE: i32 = 100;
+
+        let sort_by =
+            read::contract::list::SortBy::from(sort_by.unwrap_or_default());
+        let arguments = match (page, per_page) {
+            (None, None) => read::contract::list::Arguments::new(
+                first,
+                after.map(Into::into),
+                last,
+                before.map(Into::into),
+                DEFAULT_PAGE_SIZE,
+            )
+            .ok_or_else(|| api::PaginationError::Ambiguous.into()),
+            _ if first.is_some()
+                || after.is_some()
+                || last.is_some()
+                || before.is_some() =>
+            {
+                Err(api::PaginationError::Ambiguous.into())
+            }
+            (page, per_page) => read::contract::list::Arguments::new_offset(
+                page,
+                per_page,
+                DEFAULT_PAGE_SIZE,
+                MAX_PAGE_SIZE,
+            )
+            .ok_or_else(|| api::PaginationError::Invalid.into()),
+        }
+        .map_err(ctx.error())?;
+        if arguments.cursor().is_some_and(|c| c.key.sort_by() != sort_by) {
+            return Err(api::PaginationError::CursorMismatch.into());
+        }
 
         let my_id = ctx.current_session().await?.user_id;
         let is_employed = ctx
@@ -312,31 +655,67 @@ impl Query {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Err(api::PrivilegeError::Employer.into());
         }
 
+        let filter = read::contract::list::Filter {
+            agency_id: Some(ctx.agency_id().into()),
+            branch_id: branch_id.map(Into::into),
+            name: name.map(Into::into),
+            include_archived: include_archived.unwrap_or_default(),
+            sort_by,
+        };
         ctx.service()
             .execute(query::contracts::List::by(
                 read::contract::list::Selector {
-                    arguments: read::contract::list::Arguments::new(
-                        first,
-                        after.map(Into::into),
-                        last,
-                        before.map(Into::into),
-                        DEFAULT_PAGE_SIZE,
-                    )
-                    .ok_or_else(|| api::PaginationError::Ambiguous.into())
-                    .map_err(ctx.error())?,
-                    filter: read::contract::list::Filter {
-                        name: name.map(Into::into),
-                    },
+                    arguments,
+                    filter: filter.clone(),
                 },
             ))
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())
+            .map(|c| api::contract::list::Connection::new(c, filter))
+    }
+
+    /// Returns the numbers of the current `Agency` `Contract`s, grouped by
+    /// their status and kind.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "contractStats",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract_stats(
+        ctx: &Context,
+    ) -> Result<api::contract::Stats, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::contracts::CountsByStatusAndKind::by(
+                ctx.agency_id().into(),
+            ))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
             .map(Into::into)
     }
 
@@ -367,12 +746,20 @@ impl Query {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Err(api::PrivilegeError::Employer.into());
         }
 
-        Self::realties(None, Some(id.into()), None, Some(id.into()), None, ctx)
+        Self::realties(
+            None,
+            Some(id.into()),
+            None,
+            Some(id.into()),
+            None,
+            None,
+            ctx,
+        )
             .await?
             .edges()
             .into_iter()
@@ -387,6 +774,8 @@ impl Query {
     ///
     /// Possible error codes:
     /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `PAGINATION_CURSOR_MISMATCH` - the pagination cursor belongs to
+    ///                                  another `sortBy` order;
     /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
@@ -398,6 +787,7 @@ impl Query {
             gql.name = "realties",
             last = ?last,
             otel.name = Self::SPAN_NAME,
+            sort_by = ?sort_by,
         ),
     )]
     pub async fn realties(
@@ -406,10 +796,26 @@ impl Query {
         last: Option<i32>,
         before: Option<api::realty::list::Cursor>,
         address: Option<api::realty::Address>,
+        sort_by: Option<api::realty::list::SortBy>,
         ctx: &Context,
     ) -> Result<api::realty::list::Connection, Error> {
         const DEFAULT_PAGE_SIZE: i32 = 10;
 
+        let sort_by =
+            read::realty::list::SortBy::from(sort_by.unwrap_or_default());
+        let arguments = read::realty::list::Arguments::new(
+            first,
+            after.map(Into::into),
+            last,
+            before.map(Into::into),
+            DEFAULT_PAGE_SIZE,
+        )
+        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        .map_err(ctx.error())?;
+        if arguments.cursor().is_some_and(|c| c.key.sort_by() != sort_by) {
+            return Err(api::PaginationError::CursorMismatch.into());
+        }
+
         let my_id = ctx.current_session().await?.user_id;
         let is_employed = ctx
             .service()
@@ -417,47 +823,48 @@ impl Query {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Err(api::PrivilegeError::Employer.into());
         }
 
+        let filter = read::realty::list::Filter {
+            agency_id: Some(ctx.agency_id().into()),
+            assignee_id: None,
+            address: address.map(Into::into),
+            ids: None,
+            sort_by,
+        };
         ctx.service()
             .execute(query::realties::List::by(read::realty::list::Selector {
-                arguments: read::realty::list::Arguments::new(
-                    first,
-                    after.map(Into::into),
-                    last,
-                    before.map(Into::into),
-                    DEFAULT_PAGE_SIZE,
-                )
-                .ok_or_else(|| api::PaginationError::Ambiguous.into())
-                .map_err(ctx.error())?,
-                filter: read::realty::list::Filter {
-                    address: address.map(Into::into),
-                },
+                arguments,
+                filter: filter.clone(),
             }))
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map(Into::into)
+            .map(|c| api::realty::list::Connection::new(c, filter))
     }
 
-    /// Calculates the `SalaryReport` for the specified period.
+    /// Returns the `Lead` with the specified ID.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `LEAD_NOT_EXISTS` - the `Lead` with the specified ID does not exist;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
     #[tracing::instrument(
         skip_all,
         fields(
-            end_at = ?end_at,
-            gql.name = "salaryReport",
+            id = %id,
+            gql.name = "lead",
             otel.name = Self::SPAN_NAME,
-            start_at = ?start_at,
         ),
     )]
-    pub async fn salary_report(
-        start_at: DateTime,
-        end_at: DateTime,
+    pub async fn lead(
+        id: api::lead::Id,
         ctx: &Context,
-    ) -> Result<api::report::Salary, Error> {
+    ) -> Result<api::Lead, Error> {
         let my_id = ctx.current_session().await?.user_id;
         let is_employed = ctx
             .service()
@@ -465,25 +872,940 @@ impl Query {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())?
-            .is_some();
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
         if !is_employed {
             return Err(api::PrivilegeError::Employer.into());
         }
 
+        let agency_id = ctx.agency_id().into();
         ctx.service()
-            .execute(query::report::Salary {
-                start: start_at,
-                end: end_at,
-            })
+            .execute(query::lead::ById::by(id.into()))
             .await
             .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .filter(|l| l.agency_id == agency_id)
+            .ok_or_else(|| api::lead::LeadError::NotExists.into())
             .map_err(ctx.error())
             .map(Into::into)
     }
-}
 
-define_error! {
-    enum ContractError {
+    /// Fetches the page of `Lead`s, optionally restricted to the provided
+    /// `stages`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            after = ?after,
+            before = ?before,
+            first = ?first,
+            gql.name = "leads",
+            last = ?last,
+            otel.name = Self::SPAN_NAME,
+            stages = ?stages,
+        ),
+    )]
+    pub async fn leads(
+        first: Option<i32>,
+        after: Option<api::lead::list::Cursor>,
+        last: Option<i32>,
+        before: Option<api::lead::list::Cursor>,
+        stages: Option<Vec<api::lead::Stage>>,
+        ctx: &Context,
+    ) -> Result<api::lead::list::Connection, Error> {
+        const DEFAULT_PAGE_SIZE: i32 = 10;
+
+        let arguments = read::lead::list::Arguments::new(
+            first,
+            after.map(Into::into),
+            last,
+            before.map(Into::into),
+            DEFAULT_PAGE_SIZE,
+        )
+        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        .map_err(ctx.error())?;
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::leads::List::by(read::lead::list::Selector {
+                arguments,
+                filter: read::lead::list::Filter {
+                    agency_id: ctx.agency_id().into(),
+                    stages: stages
+                        .map(|s| s.into_iter().map(Into::into).collect()),
+                },
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Fetches the page of the audit log `AuditEntry`s of the current
+    /// `Agency`, in their chronological order, optionally restricted by the
+    /// provided filters.
+    ///
+    /// `startAt` is inclusive, while `endAt` is exclusive.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            actor_id = ?actor_id,
+            after = ?after,
+            before = ?before,
+            end_at = ?end_at,
+            entity = ?entity,
+            entity_id = ?entity_id,
+            first = ?first,
+            gql.name = "auditEntries",
+            last = ?last,
+            otel.name = Self::SPAN_NAME,
+            start_at = ?start_at,
+        ),
+    )]
+    #[expect(clippy::too_many_arguments, reason = "still readable")]
+    pub async fn audit_entries(
+        first: Option<i32>,
+        after: Option<api::audit::list::Cursor>,
+        last: Option<i32>,
+        before: Option<api::audit::list::Cursor>,
+        actor_id: Option<api::user::Id>,
+        entity: Option<api::audit::Entity>,
+        entity_id: Option<Uuid>,
+        start_at: Option<DateTime>,
+        end_at: Option<DateTime>,
+        ctx: &Context,
+    ) -> Result<api::audit::list::Connection, Error> {
+        const DEFAULT_PAGE_SIZE: i32 = 20;
+
+        let arguments = read::audit::list::Arguments::new(
+            first,
+            after.map(Into::into),
+            last,
+            before.map(Into::into),
+            DEFAULT_PAGE_SIZE,
+        )
+        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        .map_err(ctx.error())?;
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::audit::List::by(read::audit::list::Selector {
+                arguments,
+                filter: read::audit::list::Filter {
+                    agency_id: ctx.agency_id().into(),
+                    actor_id: actor_id.map(Into::into),
+                    entity: entity.map(Into::into),
+                    entity_id,
+                    start: start_at,
+                    end: end_at,
+                },
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Fetches the page of the deleted entities of the current `Agency`,
+    /// which may be restored yet, in the chronological order of their
+    /// deletion, optionally restricted to the provided `entity` kind.
+    ///
+    /// Archived `Contract`s are listed as deleted ones. Deleted `User`s are
+    /// listed for the default `Agency` only.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `PAGINATION_AMBIGUOUS` - the pagination arguments are ambiguous;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            after = ?after,
+            before = ?before,
+            entity = ?entity,
+            first = ?first,
+            gql.name = "trash",
+            last = ?last,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn trash(
+        first: Option<i32>,
+        after: Option<api::trash::list::Cursor>,
+        last: Option<i32>,
+        before: Option<api::trash::list::Cursor>,
+        entity: Option<api::audit::Entity>,
+        ctx: &Context,
+    ) -> Result<api::trash::list::Connection, Error> {
+        const DEFAULT_PAGE_SIZE: i32 = 20;
+
+        let arguments = read::trash::list::Arguments::new(
+            first,
+            after.map(Into::into),
+            last,
+            before.map(Into::into),
+            DEFAULT_PAGE_SIZE,
+        )
+        .ok_or_else(|| api::PaginationError::Ambiguous.into())
+        .map_err(ctx.error())?;
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        let agency_id: domain::agency::Id = ctx.agency_id().into();
+        ctx.service()
+            .execute(query::trash::List::by(read::trash::list::Selector {
+                arguments,
+                filter: read::trash::list::Filter {
+                    agency_id,
+                    include_users: agency_id.is_default(),
+                    entity: entity.map(Into::into),
+                },
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Searches for `User`s, `Realty`s, `Contract`s and `Placement`s matching
+    /// the provided `query` at once, ordered by their relevance.
+    ///
+    /// `Placement`s are additionally ranked by their freshness and the
+    /// proximity of their price to the provided `price`, if any, as
+    /// configured on the server.
+    ///
+    /// At most `first` (`10` by default, `50` at max) results are returned.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `first` argument is not
+    ///                                    positive;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            first = ?first,
+            gql.name = "search",
+            otel.name = Self::SPAN_NAME,
+            price = ?price,
+            query = %query,
+        ),
+    )]
+    pub async fn search(
+        query: String,
+        price: Option<Money>,
+        first: Option<i32>,
+        ctx: &Context,
+    ) -> Result<Vec<api::SearchResult>, Error> {
+        const DEFAULT_LIMIT: i32 = 10;
+        const MAX_LIMIT: i32 = 50;
+
+        let limit = first.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let limit = usize::try_from(limit)
+            .ok()
+            .filter(|l| *l > 0)
+            .ok_or_else(|| api::PaginationError::Invalid.into())
+            .map_err(ctx.error())?;
+
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::search::Global {
+                query,
+                limit,
+                agency_id: Some(ctx.agency_id().into()),
+                price,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|hits| hits.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the current `Agency`, selected by the `X-Agency-Id` HTTP
+    /// header (the default one, if omitted).
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AGENCY_NOT_EXISTS` - the current `Agency` does not exist.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "agency",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn agency(ctx: &Context) -> Result<api::Agency, Error> {
+        ctx.service()
+            .execute(query::agency::ById::by(ctx.agency_id().into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::agency::AgencyError::NotExists.into())
+            .map_err(ctx.error())
+    }
+
+    /// Returns the statuses of the periodic background tasks.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "backgroundTasks",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn background_tasks(
+        ctx: &Context,
+    ) -> Result<Vec<api::task::Status>, Error> {
+        ctx.ensure_operator().await?;
+
+        let statuses = ctx
+            .service()
+            .execute(query::task::Status)
+            .await
+            .unwrap_or_else(|e| match e {});
+        Ok(statuses
+            .into_iter()
+            .map(|(name, status)| api::task::Status::new(name, status))
+            .collect())
+    }
+
+    /// Returns the history of the periodic background task runs, starting
+    /// from the latest one.
+    ///
+    /// Only the runs of the task with the provided `name` are returned, if
+    /// it's specified. At most `first` (`20` by default, `100` at max) runs
+    /// are returned.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `first` argument is not
+    ///                                    positive;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            first = ?first,
+            gql.name = "taskRuns",
+            name = ?name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn task_runs(
+        name: Option<String>,
+        first: Option<i32>,
+        ctx: &Context,
+    ) -> Result<Vec<api::task::Run>, Error> {
+        const DEFAULT_LIMIT: i32 = 20;
+        const MAX_LIMIT: i32 = 100;
+
+        let limit = first.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let limit = usize::try_from(limit)
+            .ok()
+            .filter(|l| *l > 0)
+            .ok_or_else(|| api::PaginationError::Invalid.into())
+            .map_err(ctx.error())?;
+
+        ctx.ensure_operator().await?;
+
+        ctx.service()
+            .execute(query::task::Runs::by(read::task::History {
+                task: name,
+                limit,
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|runs| runs.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the work items the periodic background tasks have failed to
+    /// process, starting from the latest failed one.
+    ///
+    /// Only the items of the task with the provided `name` are returned, if
+    /// it's specified. At most `first` (`20` by default, `100` at max) items
+    /// are returned.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `first` argument is not
+    ///                                    positive;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            first = ?first,
+            gql.name = "taskDeadLetters",
+            name = ?name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn task_dead_letters(
+        name: Option<String>,
+        first: Option<i32>,
+        ctx: &Context,
+    ) -> Result<Vec<api::task::DeadLetter>, Error> {
+        const DEFAULT_LIMIT: i32 = 20;
+        const MAX_LIMIT: i32 = 100;
+
+        let limit = first.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let limit = usize::try_from(limit)
+            .ok()
+            .filter(|l| *l > 0)
+            .ok_or_else(|| api::PaginationError::Invalid.into())
+            .map_err(ctx.error())?;
+
+        ctx.ensure_operator().await?;
+
+        ctx.service()
+            .execute(query::task::DeadLetters::by(read::task::DeadLetters {
+                task: name,
+                limit,
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|letters| letters.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the latest synchronizations of the external listing feeds,
+    /// from the most recent one.
+    ///
+    /// Only the synchronizations of the feed with the provided `source` are
+    /// returned, if it's specified. At most `first` (`20` by default, `100` at
+    /// max) synchronizations are returned.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `INVALID_PAGINATION_ARGUMENTS` - the `first` argument is not
+    ///                                    positive;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            first = ?first,
+            gql.name = "listingSyncs",
+            otel.name = Self::SPAN_NAME,
+            source = ?source,
+        ),
+    )]
+    pub async fn listing_syncs(
+        source: Option<String>,
+        first: Option<i32>,
+        ctx: &Context,
+    ) -> Result<Vec<api::listing::Sync>, Error> {
+        const DEFAULT_LIMIT: i32 = 20;
+        const MAX_LIMIT: i32 = 100;
+
+        let limit = first.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let limit = usize::try_from(limit)
+            .ok()
+            .filter(|l| *l > 0)
+            .ok_or_else(|| api::PaginationError::Invalid.into())
+            .map_err(ctx.error())?;
+
+        ctx.ensure_operator().await?;
+
+        let source = match source.map(domain::listing::Source::new) {
+            Some(Some(s)) => Some(s),
+            // No feed may have an invalid `source`.
+            Some(None) => return Ok(Vec::new()),
+            None => None,
+        };
+        ctx.service()
+            .execute(query::listing::Syncs::by(read::listing::History {
+                source,
+                limit,
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|syncs| syncs.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the templates of the outgoing email and SMS messages.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "messageTemplates",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn message_templates(
+        ctx: &Context,
+    ) -> Result<Vec<api::template::Definition>, Error> {
+        ctx.ensure_operator().await?;
+
+        Ok(service::infra::template::DEFINITIONS
+            .iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Renders the outgoing message template with the provided `name` in the
+    /// provided `locale` (English by default), using sample values of its
+    /// placeholders.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `MESSAGE_TEMPLATE_NOT_EXISTS` - template with the provided `name`
+    ///                                   does not exist;
+    /// - `NOT_AGENCY_ADMIN` - the current `User` is not the platform operator.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "messageTemplatePreview",
+            locale = ?locale,
+            name = %name,
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn message_template_preview(
+        name: String,
+        locale: Option<api::agency::Locale>,
+        ctx: &Context,
+    ) -> Result<String, Error> {
+        ctx.ensure_operator().await?;
+
+        ctx.service()
+            .execute(query::template::Preview {
+                name,
+                locale: locale.map(Into::into),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+    }
+
+    /// Calculates the `SalaryReport` for the specified period.
+    ///
+    /// If the `timeZone` is specified, the period is extended to the whole
+    /// days of its local calendar.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            end_at = ?end_at,
+            gql.name = "salaryReport",
+            otel.name = Self::SPAN_NAME,
+            start_at = ?start_at,
+            time_zone = ?time_zone,
+        ),
+    )]
+    pub async fn salary_report(
+        start_at: DateTime,
+        end_at: DateTime,
+        time_zone: Option<TimeZone>,
+        ctx: &Context,
+    ) -> Result<api::report::Salary, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::report::Salary {
+                agency_id: ctx.agency_id().into(),
+                start: start_at,
+                end: end_at,
+                time_zone,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Calculates the `ArrearsReport` of the current `Agency`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "arrearsReport",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn arrears_report(
+        ctx: &Context,
+    ) -> Result<api::report::Arrears, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::report::Arrears {
+                agency_id: ctx.agency_id().into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Summarizes the current `Agency` activity for its back-office home
+    /// screen.
+    ///
+    /// If the `timeZone` is specified, the month-to-date revenue is aligned to
+    /// its local calendar.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "dashboard",
+            otel.name = Self::SPAN_NAME,
+            time_zone = ?time_zone,
+        ),
+    )]
+    pub async fn dashboard(
+        time_zone: Option<TimeZone>,
+        ctx: &Context,
+    ) -> Result<api::report::Dashboard, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::report::Dashboard {
+                agency_id: ctx.agency_id().into(),
+                time_zone,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Calculates the `TimeSeriesReport` of the current `Agency` for the
+    /// specified period.
+    ///
+    /// The period is extended to the whole days of the `timeZone` local
+    /// calendar, or of `UTC` if none is specified.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            end_at = ?end_at,
+            gql.name = "timeSeriesReport",
+            granularity = ?granularity,
+            otel.name = Self::SPAN_NAME,
+            start_at = ?start_at,
+            time_zone = ?time_zone,
+        ),
+    )]
+    pub async fn time_series_report(
+        granularity: api::report::time_series::Granularity,
+        start_at: DateTime,
+        end_at: DateTime,
+        time_zone: Option<TimeZone>,
+        ctx: &Context,
+    ) -> Result<api::report::TimeSeries, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::report::TimeSeries {
+                agency_id: ctx.agency_id().into(),
+                granularity: granularity.into(),
+                start: start_at,
+                end: end_at,
+                time_zone,
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Calculates the `RevenueReport` of the current `Agency`.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "revenueReport",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn revenue_report(
+        ctx: &Context,
+    ) -> Result<api::report::Revenue, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::report::Revenue {
+                agency_id: ctx.agency_id().into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(Into::into)
+    }
+
+    /// Returns the checked out `RealtyKey`s of the current `Agency`, not
+    /// returned by their `dueAt`, the longest overdue first.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "overdueRealtyKeys",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn overdue_realty_keys(
+        ctx: &Context,
+    ) -> Result<Vec<api::Key>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::key::Overdue::by(read::key::Overdue {
+                agency_id: ctx.agency_id().into(),
+                at: DateTime::now(),
+            }))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|keys| keys.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns all the `ContractTemplate`s of the current `Agency`, the
+    /// oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "contractTemplates",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract_templates(
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::template::Template>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::contract::Templates::by(ctx.agency_id().into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|ts| ts.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns the `ContractApproval`s of the current `Agency` not granted
+    /// yet, the oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "pendingContractApprovals",
+            otel.name = Self::SPAN_NAME,
+        ),
+    )]
+    pub async fn pending_contract_approvals(
+        ctx: &Context,
+    ) -> Result<Vec<api::contract::approval::Approval>, Error> {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        ctx.service()
+            .execute(query::contract::PendingApprovals::by(
+                read::contract::PendingApprovals {
+                    agency_id: ctx.agency_id().into(),
+                },
+            ))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|a| a.into_iter().map(Into::into).collect())
+    }
+}
+
+define_error! {
+    enum ContractError {
+        #[code = "CONTRACT_LOOKUP_AMBIGUOUS"]
+        #[status = BAD_REQUEST]
+        #[message = "Exactly one of `id` or `number` must be provided"]
+        Ambiguous,
+
         #[code = "CONTRACT_NOT_EXISTS"]
         #[status = NOT_FOUND]
         #[message = "`Contract` with the specified ID does not exist"]
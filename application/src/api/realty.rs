@@ -2,15 +2,15 @@
 
 use std::future;
 
-use common::{DateTime, Handler as _};
+use common::{DateTime, Handler as _, Money, Percent};
 use derive_more::{AsRef, Display, From, Into};
 use futures::TryFutureExt as _;
-use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
-use service::{domain, query};
+use juniper::{graphql_object, GraphQLEnum, GraphQLInputObject, GraphQLScalar};
+use service::{domain, query, read};
 use tokio::sync::OnceCell;
 use uuid::Uuid;
 
-use crate::{api, api::scalar, AsError, Context, Error};
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
 
 /// A realty.
 #[derive(Clone, Debug, From)]
@@ -109,6 +109,219 @@ impl Realty {
         Ok(self.realty(ctx).await?.address.clone().into())
     }
 
+    /// Floor area of this `Realty` in square meters, if known.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.area",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn area(&self, ctx: &Context) -> Result<Option<Area>, Error> {
+        Ok(self.realty(ctx).await?.area.map(Into::into))
+    }
+
+    /// Employed `User` responsible for this `Realty`, if assigned.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.assignee",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn assignee(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::User>, Error> {
+        Ok(self.realty(ctx).await?.assignee_id.map(|id| {
+            // SAFETY: `User` is referenced by an existing `Realty`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(id)
+            }
+        }))
+    }
+
+    /// Co-owners of this `Realty` along with their shares, from the largest
+    /// share to the smallest one.
+    ///
+    /// Empty if no owners are recorded.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.owners",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn owners(&self, ctx: &Context) -> Result<Vec<Owner>, Error> {
+        ctx.service()
+            .execute(query::realty::Owners::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|o| o.into_iter().map(Into::into).collect())
+    }
+
+    /// Floor plans of this `Realty`, in the order of their floors and
+    /// labels.
+    ///
+    /// Kept apart from the ordinary photos.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.floorPlans",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn floor_plans(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<FloorPlan>, Error> {
+        ctx.service()
+            .execute(query::realty::FloorPlans::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|p| p.into_iter().map(Into::into).collect())
+    }
+
+    /// External portal or MLS listing this `Realty` was imported from.
+    ///
+    /// `null` if this `Realty` is not externally sourced.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.externalListing",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn external_listing(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<ExternalListing>, Error> {
+        ctx.service()
+            .execute(query::realty::ExternalListing::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|l| l.map(Into::into))
+    }
+
+    /// External virtual tours and videos of this `Realty`, in the order they
+    /// should be shown.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.mediaLinks",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn media_links(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<MediaLink>, Error> {
+        ctx.service()
+            .execute(query::realty::MediaLinks::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|l| l.into_iter().map(Into::into).collect())
+    }
+
+    /// Transfers of the ownership of this `Realty` by completed sale
+    /// `Contract`s, from the most recent one.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.ownershipHistory",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn ownership_history(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<OwnershipTransfer>, Error> {
+        ctx.service()
+            .execute(query::realty::OwnershipHistory::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|t| t.into_iter().map(Into::into).collect())
+    }
+
+    /// Seasonal nightly rates of short-term rent `Contract`s on this
+    /// `Realty`, in the order of their seasons.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.seasonalRates",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn seasonal_rates(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<SeasonalRate>, Error> {
+        ctx.service()
+            .execute(query::realty::SeasonalRates::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|r| r.into_iter().map(Into::into).collect())
+    }
+
+    /// Price suggested for this `Realty` for the provided `purpose` from the
+    /// recent `Contract`s on the comparable `Realty`s: located in the same
+    /// city, of the same `RealtyKind` and a similar `RealtyArea`.
+    ///
+    /// Intended to prefill the expected price of a new management
+    /// `Contract`.
+    ///
+    /// Nothing is suggested if there are no comparable `Contract`s, or the
+    /// current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.suggestedPrice",
+            otel.name = api::Query::SPAN_NAME,
+            purpose = ?purpose,
+        ),
+    )]
+    pub async fn suggested_price(
+        &self,
+        purpose: Purpose,
+        ctx: &Context,
+    ) -> Result<Option<SuggestedPrice>, Error> {
+        let Some(session) = ctx.try_current_session().await? else {
+            return Ok(None);
+        };
+
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(session.user_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Ok(None);
+        }
+
+        ctx.service()
+            .execute(query::realty::SuggestedPrice {
+                realty_id: self.id.into(),
+                purpose: purpose.into(),
+            })
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|s| s.map(Into::into))
+    }
+
     /// `DateTime` when this `Realty` was created.
     #[tracing::instrument(
         skip_all,
@@ -120,6 +333,602 @@ impl Realty {
     pub async fn created_at(&self, ctx: &Context) -> Result<DateTime, Error> {
         Ok(self.realty(ctx).await?.created_at.coerce())
     }
+
+    /// Physical `RealtyKey`s of this `Realty`, in the order of their labels.
+    ///
+    /// No `RealtyKey`s are returned if the current `User` is not an employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Realty.keys",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn keys(&self, ctx: &Context) -> Result<Vec<api::Key>, Error> {
+        let Some(session) = ctx.try_current_session().await? else {
+            return Ok(vec![]);
+        };
+
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(session.user_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Ok(vec![]);
+        }
+
+        ctx.service()
+            .execute(query::key::ByRealty::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|keys| keys.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Co-owner of a `Realty`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Owner(domain::Ownership);
+
+/// Co-owner of a `Realty`.
+#[graphql_object(name = "RealtyOwner", context = Context)]
+impl Owner {
+    /// `User` co-owning the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwner.user",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn user(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `RealtyOwner`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.owner_id)
+        }
+    }
+
+    /// Share of the `Realty` owned by the `User`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwner.share",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn share(&self) -> Percent {
+        self.0.share
+    }
+}
+
+/// Transfer of a `Realty` ownership by a completed sale `Contract`.
+#[derive(Clone, Copy, Debug, From)]
+pub struct OwnershipTransfer(domain::ownership::Transfer);
+
+/// Transfer of a `Realty` ownership by a completed sale `Contract`.
+#[graphql_object(name = "RealtyOwnershipTransfer", context = Context)]
+impl OwnershipTransfer {
+    /// Sale `Contract` transferred the ownership.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnershipTransfer.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        ctx.service()
+            .execute(query::contract::ById::by(self.0.contract_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::query::ContractError::NotExists.into())
+    }
+
+    /// Landlord `User` sold the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnershipTransfer.seller",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn seller(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing
+        //         `RealtyOwnershipTransfer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.seller_id)
+        }
+    }
+
+    /// Purchaser `User` became the sole owner of the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnershipTransfer.buyer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn buyer(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing
+        //         `RealtyOwnershipTransfer`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.buyer_id)
+        }
+    }
+
+    /// `DateTime` when the ownership was transferred.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnershipTransfer.transferredAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn transferred_at(&self) -> DateTime {
+        self.0.transferred_at.coerce()
+    }
+}
+
+/// Co-owner of a `Realty` to be set.
+#[derive(Clone, Copy, Debug, GraphQLInputObject)]
+#[graphql(name = "RealtyOwnerInput")]
+pub struct OwnerInput {
+    /// ID of the `User` co-owning the `Realty`.
+    pub user_id: api::user::Id,
+
+    /// Share of the `Realty` owned by the `User`.
+    pub share: Percent,
+}
+
+/// Floor plan of a `Realty`.
+#[derive(Clone, Debug, From)]
+pub struct FloorPlan(domain::FloorPlan);
+
+/// Floor plan of a `Realty`.
+#[graphql_object(name = "RealtyFloorPlan", context = Context)]
+impl FloorPlan {
+    /// Floor depicted by this `RealtyFloorPlan`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyFloorPlan.floor",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn floor(&self) -> i32 {
+        self.0.floor.into()
+    }
+
+    /// Label of this `RealtyFloorPlan`, unique per floor.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyFloorPlan.label",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn label(&self) -> FloorPlanLabel {
+        self.0.label.clone().into()
+    }
+
+    /// Format of this `RealtyFloorPlan` file.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyFloorPlan.format",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn format(&self) -> FloorPlanFormat {
+        self.0.format.into()
+    }
+
+    /// URL of this `RealtyFloorPlan` file.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyFloorPlan.url",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn url(&self) -> FloorPlanUrl {
+        self.0.url.clone().into()
+    }
+}
+
+/// Floor plan of a `Realty` to be set.
+#[derive(Clone, Debug, GraphQLInputObject)]
+#[graphql(name = "RealtyFloorPlanInput")]
+pub struct FloorPlanInput {
+    /// Floor depicted by the `RealtyFloorPlan`.
+    pub floor: i32,
+
+    /// Label of the `RealtyFloorPlan`, unique per floor.
+    pub label: FloorPlanLabel,
+
+    /// Format of the `RealtyFloorPlan` file.
+    pub format: FloorPlanFormat,
+
+    /// URL of the `RealtyFloorPlan` file.
+    pub url: FloorPlanUrl,
+}
+
+/// External portal or MLS listing a `Realty` was imported from.
+#[derive(Clone, Debug, From)]
+pub struct ExternalListing(domain::listing::Import);
+
+/// External portal or MLS listing a `Realty` was imported from.
+#[graphql_object(name = "RealtyExternalListing", context = Context)]
+impl ExternalListing {
+    /// Name of the feed the listing was imported from.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyExternalListing.source",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn source(&self) -> &str {
+        self.0.source.as_ref()
+    }
+
+    /// ID of the listing in its feed.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyExternalListing.externalId",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn external_id(&self) -> &str {
+        self.0.external_id.as_ref()
+    }
+
+    /// Management `Contract` created for the listing.
+    ///
+    /// `null` if the listing matched an already existing `Realty`, or the
+    /// `Contract` was deleted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyExternalListing.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::ContractValue>, Error> {
+        let Some(id) = self.0.contract_id else {
+            return Ok(None);
+        };
+        ctx.service()
+            .execute(query::contract::ById::by(id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|c| c.map(Into::into))
+    }
+
+    /// `DateTime` when the listing was imported.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyExternalListing.importedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn imported_at(&self) -> DateTime {
+        self.0.imported_at.coerce()
+    }
+}
+
+/// Link to an external virtual tour or video of a `Realty`.
+#[derive(Clone, Debug, From)]
+pub struct MediaLink(domain::MediaLink);
+
+/// Link to an external virtual tour or video of a `Realty`.
+#[graphql_object(name = "RealtyMediaLink", context = Context)]
+impl MediaLink {
+    /// Provider serving this `RealtyMediaLink`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyMediaLink.provider",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn provider(&self) -> MediaLinkProvider {
+        self.0.url.provider().into()
+    }
+
+    /// URL of this `RealtyMediaLink`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyMediaLink.url",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn url(&self) -> MediaLinkUrl {
+        self.0.url.clone().into()
+    }
+}
+
+/// Nightly rate of short-term rent `Contract`s on a `Realty` within a season.
+#[derive(Clone, Copy, Debug, From)]
+pub struct SeasonalRate(domain::contract::short_term_rent::SeasonalRate);
+
+/// Nightly rate of short-term rent `Contract`s on a `Realty` within a season.
+#[graphql_object(name = "RealtySeasonalRate", context = Context)]
+impl SeasonalRate {
+    /// `DateTime` when the season starts (inclusive).
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySeasonalRate.from",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn from(&self) -> DateTime {
+        self.0.from
+    }
+
+    /// `DateTime` when the season ends (exclusive).
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySeasonalRate.until",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn until(&self) -> DateTime {
+        self.0.until
+    }
+
+    /// Nightly rate within the season.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySeasonalRate.nightlyRate",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn nightly_rate(&self) -> Money {
+        self.0.nightly_rate
+    }
+}
+
+/// Seasonal nightly rate of a `Realty` to be set.
+#[derive(Clone, Copy, Debug, GraphQLInputObject)]
+#[graphql(name = "RealtySeasonalRateInput")]
+pub struct SeasonalRateInput {
+    /// `DateTime` when the season starts (inclusive).
+    pub from: DateTime,
+
+    /// `DateTime` when the season ends (exclusive).
+    pub until: DateTime,
+
+    /// Nightly rate within the season.
+    pub nightly_rate: Money,
+}
+
+/// Purpose a `Realty` is managed for.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RealtyPurpose")]
+pub enum Purpose {
+    /// `Realty` is rented out.
+    Rent,
+
+    /// `Realty` is sold.
+    Sale,
+}
+
+impl From<Purpose> for read::realty::Purpose {
+    fn from(purpose: Purpose) -> Self {
+        match purpose {
+            Purpose::Rent => Self::Rent,
+            Purpose::Sale => Self::Sale,
+        }
+    }
+}
+
+/// Price suggested for a `Realty` from the comparable `Contract`s.
+#[derive(Clone, Copy, Debug, From)]
+pub struct SuggestedPrice(query::realty::Suggestion);
+
+/// Price suggested for a `Realty` from the comparable `Contract`s.
+#[graphql_object(name = "RealtySuggestedPrice", context = Context)]
+impl SuggestedPrice {
+    /// Median price of the comparable `Contract`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySuggestedPrice.price",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn price(&self) -> Money {
+        self.0.price
+    }
+
+    /// `price` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySuggestedPrice.formattedPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_price(&self, ctx: &Context) -> String {
+        self.0.price.format(ctx.locale().into())
+    }
+
+    /// Number of the comparable `Contract`s the `price` is calculated from.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySuggestedPrice.comparables",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn comparables(&self) -> i32 {
+        self.0.comparables.into()
+    }
+
+    /// Confidence in the `price`, growing with the number of the comparable
+    /// `Contract`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtySuggestedPrice.confidence",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn confidence(&self) -> Percent {
+        self.0.confidence
+    }
+}
+
+/// Part of a `Contract` price due to a co-owner of its `Realty`.
+#[derive(Clone, Copy, Debug)]
+pub struct OwnerSplit {
+    /// Ownership of the co-owner.
+    pub ownership: domain::Ownership,
+
+    /// Amount due to the co-owner.
+    pub amount: Money,
+}
+
+/// Part of a `Contract` price due to a co-owner of its `Realty`.
+#[graphql_object(name = "RealtyOwnerSplit", context = Context)]
+impl OwnerSplit {
+    /// Co-owner of the `Realty`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnerSplit.owner",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn owner(&self) -> Owner {
+        self.ownership.into()
+    }
+
+    /// Amount of the `Contract` price due to the co-owner.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RealtyOwnerSplit.amount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+}
+
+impl OwnerSplit {
+    /// Splits the provided `price` of a `Contract` on the `Realty` with the
+    /// provided ID between its co-owners.
+    ///
+    /// The whole `price` is due to the `landlord_id` if no co-owners are
+    /// recorded for the `Realty`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the co-owners cannot be queried.
+    pub async fn of_price(
+        realty_id: domain::realty::Id,
+        landlord_id: domain::user::Id,
+        price: Money,
+        ctx: &Context,
+    ) -> Result<Vec<Self>, Error> {
+        let mut ownerships = ctx
+            .service()
+            .execute(query::realty::Owners::by(realty_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?;
+        if ownerships.is_empty() {
+            ownerships.push(domain::Ownership {
+                realty_id,
+                owner_id: landlord_id,
+                share: Percent::HUNDRED,
+            });
+        }
+        Ok(domain::Ownership::split(&ownerships, price)
+            .into_iter()
+            .zip(ownerships)
+            .map(|((_, amount), ownership)| Self { ownership, amount })
+            .collect())
+    }
+}
+
+define_error! {
+    enum OwnerError {
+        #[code = "INVALID_OWNERSHIP_SHARES"]
+        #[status = BAD_REQUEST]
+        #[message = "Shares of `RealtyOwner`s must sum to 100%"]
+        InvalidShares,
+    }
+}
+
+define_error! {
+    enum FloorPlanError {
+        #[code = "DUPLICATE_FLOOR_PLAN_LABELS"]
+        #[status = BAD_REQUEST]
+        #[message = "`RealtyFloorPlan` labels must be unique per floor"]
+        DuplicateLabels,
+
+        #[code = "FLOOR_NOT_EXISTS"]
+        #[status = BAD_REQUEST]
+        #[message = "`RealtyFloorPlan` floor exceeds the number of floors \
+                     of the `Realty`"]
+        FloorNotExists,
+    }
+}
+
+define_error! {
+    enum MediaLinkError {
+        #[code = "INVALID_MEDIA_LINKS"]
+        #[status = BAD_REQUEST]
+        #[message = "`RealtyMediaLink`s must be unique and not exceed the \
+                     limit"]
+        InvalidLinks,
+    }
+}
+
+define_error! {
+    enum SeasonalRateError {
+        #[code = "INVALID_SEASONAL_RATES"]
+        #[status = BAD_REQUEST]
+        #[message = "`RealtySeasonalRate`s must be positive and not overlap"]
+        InvalidRates,
+    }
 }
 
 /// Unique identifier of a `Realty`.
@@ -201,6 +1010,87 @@ pub struct ApartmentNum(domain::realty::ApartmentNum);
 )]
 pub struct RoomNum(domain::realty::RoomNum);
 
+/// Floor area of a `Realty` in square meters.
+#[derive(AsRef, Clone, Copy, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(name = "RealtyArea", with = scalar::Via::<domain::realty::Area>)]
+pub struct Area(domain::realty::Area);
+
+/// Label of a `RealtyFloorPlan`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RealtyFloorPlanLabel",
+    with = scalar::Via::<domain::floor_plan::Label>,
+)]
+pub struct FloorPlanLabel(domain::floor_plan::Label);
+
+/// URL of a `RealtyFloorPlan` file.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RealtyFloorPlanUrl",
+    with = scalar::Via::<domain::floor_plan::Url>,
+)]
+pub struct FloorPlanUrl(domain::floor_plan::Url);
+
+/// Format of a `RealtyFloorPlan` file.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RealtyFloorPlanFormat")]
+pub enum FloorPlanFormat {
+    /// Raster or vector image.
+    Image,
+
+    /// PDF document.
+    Pdf,
+}
+
+impl From<domain::floor_plan::Format> for FloorPlanFormat {
+    fn from(format: domain::floor_plan::Format) -> Self {
+        use domain::floor_plan::Format as F;
+        match format {
+            F::Image => Self::Image,
+            F::Pdf => Self::Pdf,
+        }
+    }
+}
+
+impl From<FloorPlanFormat> for domain::floor_plan::Format {
+    fn from(format: FloorPlanFormat) -> Self {
+        match format {
+            FloorPlanFormat::Image => Self::Image,
+            FloorPlanFormat::Pdf => Self::Pdf,
+        }
+    }
+}
+
+/// HTTPS URL of a `RealtyMediaLink`, served by some allowed
+/// `RealtyMediaLinkProvider`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "RealtyMediaLinkUrl",
+    with = scalar::Via::<domain::media_link::Url>,
+)]
+pub struct MediaLinkUrl(domain::media_link::Url);
+
+/// Allowed provider of `RealtyMediaLink`s.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "RealtyMediaLinkProvider")]
+pub enum MediaLinkProvider {
+    /// Matterport virtual tour.
+    Matterport,
+
+    /// YouTube video.
+    Youtube,
+}
+
+impl From<domain::media_link::Provider> for MediaLinkProvider {
+    fn from(provider: domain::media_link::Provider) -> Self {
+        use domain::media_link::Provider as P;
+        match provider {
+            P::Matterport => Self::Matterport,
+            P::Youtube => Self::Youtube,
+        }
+    }
+}
+
 /// Kind of a `Realty`.
 #[derive(Clone, Copy, Debug, GraphQLEnum)]
 #[graphql(name = "RealtyKind")]
@@ -230,23 +1120,55 @@ pub mod list {
     //! Definitions related to the [`Realty`] list.
 
     use derive_more::{AsRef, From, Into};
-    use juniper::{graphql_object, GraphQLScalar};
-    use service::{query, read, Query as _};
+    use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+    use service::{domain, query, read, Query as _};
 
     use super::{Id, Realty};
     use crate::{api::scalar, AsError, Context, Error};
 
     /// Cursor for the `Realty` list.
-    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
-    #[from(Id, read::realty::list::Cursor)]
+    #[derive(AsRef, Clone, Debug, From, GraphQLScalar, Into)]
     #[graphql(
         name = "RealtyListCursor",
         with = scalar::Via::<read::realty::list::Cursor>,
     )]
     pub struct Cursor(pub read::realty::list::Cursor);
 
+    impl From<Id> for Cursor {
+        fn from(id: Id) -> Self {
+            Self(domain::realty::Id::from(id).into())
+        }
+    }
+
+    /// Order of the `Realty` list.
+    #[derive(Clone, Copy, Debug, Default, GraphQLEnum)]
+    #[graphql(name = "RealtyListSortBy")]
+    pub enum SortBy {
+        /// Relevance to the searched `address`.
+        ///
+        /// Order is stable, but unspecified, if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// `Realty` address in lexicographical order.
+        Address,
+
+        /// `Realty` creation date in chronological order.
+        CreatedAt,
+    }
+
+    impl From<SortBy> for read::realty::list::SortBy {
+        fn from(sort_by: SortBy) -> Self {
+            match sort_by {
+                SortBy::Relevance => Self::Relevance,
+                SortBy::Address => Self::Address,
+                SortBy::CreatedAt => Self::CreatedAt,
+            }
+        }
+    }
+
     /// Edge in the [`Realty`] list.
-    #[derive(Clone, Copy, Debug, From, Into)]
+    #[derive(Clone, Debug, From, Into)]
     pub struct Edge(read::realty::list::Edge);
 
     /// Edge in the `Realty` list.
@@ -255,26 +1177,38 @@ pub mod list {
         /// Cursor of this `RealtyListEdge`.
         #[must_use]
         pub fn cursor(&self) -> Cursor {
-            self.0.cursor.into()
+            self.0.cursor.clone().into()
         }
 
         /// Node of this `RealtyListEdge`.
         #[must_use]
         pub fn node(&self) -> Realty {
-            #[expect(
-                unsafe_code,
-                reason = "`Edge` loaded from repository guarantees `User` \
-                          existence"
-            )]
-            unsafe {
-                Realty::new_unchecked(self.0.node)
-            }
+            self.0.node.clone().into()
         }
     }
 
     /// Connection of the [`Realty`] list.
-    #[derive(Clone, Debug, From, Into)]
-    pub struct Connection(read::realty::list::Connection);
+    #[derive(Clone, Debug)]
+    pub struct Connection {
+        /// Underlying [`read::realty::list::Connection`].
+        connection: read::realty::list::Connection,
+
+        /// [`read::realty::list::Filter`] the list is selected by.
+        filter: read::realty::list::Filter,
+    }
+
+    impl Connection {
+        /// Creates a new [`Connection`] of the provided
+        /// [`read::realty::list::Connection`] selected by the provided
+        /// [`read::realty::list::Filter`].
+        #[must_use]
+        pub fn new(
+            connection: read::realty::list::Connection,
+            filter: read::realty::list::Filter,
+        ) -> Self {
+            Self { connection, filter }
+        }
+    }
 
     /// Connection of the `Realty` list.
     #[graphql_object(name = "RealtyListConnection", context = Context)]
@@ -282,22 +1216,24 @@ pub mod list {
         /// Edges of this `RealtyListConnection`.
         #[must_use]
         pub fn edges(&self) -> Vec<Edge> {
-            self.0.edges.iter().copied().map(Into::into).collect()
+            self.connection.edges.iter().cloned().map(Into::into).collect()
         }
 
         /// Information about the page.
         #[must_use]
         pub fn page_info(&self) -> PageInfo {
+            let edges = &self.connection.edges;
             PageInfo {
-                info: self.0.page_info(),
-                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
-                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+                info: self.connection.page_info(),
+                start_cursor: edges.first().map(|e| e.cursor.clone().into()),
+                end_cursor: edges.last().map(|e| e.cursor.clone().into()),
+                filter: self.filter.clone(),
             }
         }
     }
 
     /// Information about a [`Connection`] page.
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Debug)]
     pub struct PageInfo {
         /// Underlying [`read::realty::list::PageInfo`].
         info: read::realty::list::PageInfo,
@@ -307,6 +1243,9 @@ pub mod list {
 
         /// End cursor of the page.
         end_cursor: Option<Cursor>,
+
+        /// [`read::realty::list::Filter`] the list is selected by.
+        filter: read::realty::list::Filter,
     }
 
     /// Information about a `RealtyListConnection` page.
@@ -339,7 +1278,7 @@ pub mod list {
         /// Total `Realty` count.
         pub async fn total_count(&self, ctx: &Context) -> Result<i32, Error> {
             ctx.service()
-                .execute(query::realties::TotalCount::by(()))
+                .execute(query::realties::TotalCount::by(self.filter.clone()))
                 .await
                 .map_err(AsError::into_error)
                 .map_err(ctx.error())
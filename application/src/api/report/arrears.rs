@@ -0,0 +1,154 @@
+//! [`Arrears`] report definition.
+
+use common::{DateTime, Money};
+use derive_more::From;
+use juniper::graphql_object;
+use service::query;
+
+#[cfg(doc)]
+use crate::api::User;
+use crate::{api, Context};
+
+/// Report aggregating overdue amounts of rent `Contract`s.
+#[derive(Clone, Debug, From)]
+pub struct Arrears(query::report::arrears::Output);
+
+/// Report aggregating overdue amounts of rent `Contract`s.
+#[graphql_object(name = "ArrearsReport", context = Context)]
+impl Arrears {
+    /// `RentArrears` per `RentContract`, the longest overdue first.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReport.rows",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn rows(&self) -> Vec<api::Arrears> {
+        self.0.rows.iter().copied().map(Into::into).collect()
+    }
+
+    /// `ArrearsReportTenant`s of this report, aggregating its `rows` per
+    /// purchaser `User`, the longest overdue first.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReport.tenants",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn tenants(&self) -> Vec<TenantRow> {
+        self.0.tenants.iter().cloned().map(TenantRow).collect()
+    }
+
+    /// Total overdue amounts, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReport.totals",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn totals(&self) -> &[Money] {
+        &self.0.totals
+    }
+
+    /// Total unpaid `RentLateFee`s included into the `totals`, one per a
+    /// currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReport.lateFees",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn late_fees(&self) -> &[Money] {
+        &self.0.late_fees
+    }
+}
+
+/// Aggregation of the rows of an [`Arrears`] report per purchaser [`User`].
+#[derive(Clone, Debug)]
+pub struct TenantRow(query::report::arrears::TenantRow);
+
+/// Aggregation of the `RentArrears` per purchaser `User`.
+#[graphql_object(name = "ArrearsReportTenant", context = Context)]
+impl TenantRow {
+    /// Purchaser `User` the `RentArrears` are aggregated for.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReportTenant.purchaser",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn purchaser(&self) -> api::User {
+        // SAFETY: `User` is referenced by existing `RentArrears`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.purchaser_id)
+        }
+    }
+
+    /// Number of the `RentContract`s of the `User` in arrears.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReportTenant.contractsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn contracts_count(&self) -> i32 {
+        i32::try_from(self.0.contracts).unwrap_or(i32::MAX)
+    }
+
+    /// Total overdue amounts of the `User`, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReportTenant.amounts",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn amounts(&self) -> &[Money] {
+        &self.0.amounts
+    }
+
+    /// Total unpaid `RentLateFee`s of the `User` included into the
+    /// `amounts`, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReportTenant.lateFees",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn late_fees(&self) -> &[Money] {
+        &self.0.late_fees
+    }
+
+    /// `DateTime` when the earliest unpaid installment of the `User` was due.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "ArrearsReportTenant.overdueSince",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn overdue_since(&self) -> DateTime {
+        self.0.overdue_since.coerce()
+    }
+}
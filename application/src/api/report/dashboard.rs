@@ -0,0 +1,82 @@
+//! [`Dashboard`] report definition.
+
+use common::Money;
+use derive_more::From;
+use juniper::graphql_object;
+use service::read;
+
+use crate::{api, Context};
+
+/// Summary of the current `Agency` activity for its back-office home screen.
+#[derive(Clone, Debug, From)]
+pub struct Dashboard(read::Dashboard);
+
+/// Summary of the current `Agency` activity for its back-office home screen.
+#[graphql_object(context = Context)]
+impl Dashboard {
+    /// Number of the placed `Realty`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Dashboard.activePlacements",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn active_placements(&self) -> i32 {
+        self.0.active_placements
+    }
+
+    /// Number of the active `Contract`s expiring within 30 days.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Dashboard.expiringContracts",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn expiring_contracts(&self) -> i32 {
+        self.0.expiring_contracts
+    }
+
+    /// Number of the `Lead`s which are not closed yet.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Dashboard.openLeads",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn open_leads(&self) -> i32 {
+        self.0.open_leads
+    }
+
+    /// Number of the `RentContract`s with overdue payments.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Dashboard.overduePayments",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn overdue_payments(&self) -> i32 {
+        self.0.overdue_payments
+    }
+
+    /// Total amounts of the `RentPayment`s made since the start of the
+    /// current month, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Dashboard.monthToDateRevenue",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn month_to_date_revenue(&self) -> &[Money] {
+        &self.0.revenue
+    }
+}
@@ -0,0 +1,154 @@
+//! [`InventoryDiff`] report definition.
+
+use derive_more::From;
+use juniper::graphql_object;
+use service::{domain, query};
+
+use crate::{
+    api::{self, inventory},
+    Context,
+};
+
+/// Report comparing the move-in and the move-out `RentInventory`s of a rent
+/// `Contract`.
+#[derive(Clone, Debug, From)]
+pub struct InventoryDiff(query::report::inventory_diff::Output);
+
+/// Report comparing the move-in and the move-out `RentInventory`s of a rent
+/// `Contract`, to be used in deposit disputes.
+#[graphql_object(name = "InventoryDiffReport", context = Context)]
+impl InventoryDiff {
+    /// Move-in `RentInventory`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReport.moveIn",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn move_in(&self) -> api::Inventory {
+        self.0.move_in.clone().into()
+    }
+
+    /// Move-out `RentInventory`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReport.moveOut",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn move_out(&self) -> api::Inventory {
+        self.0.move_out.clone().into()
+    }
+
+    /// Differences of the changed items.
+    ///
+    /// Items are matched by their rooms and names. Unchanged items are
+    /// omitted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReport.differences",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn differences(&self) -> Vec<Difference> {
+        self.0.differences.iter().cloned().map(Difference).collect()
+    }
+}
+
+/// Difference of an item between the `RentInventory`s of an [`InventoryDiff`]
+/// report.
+#[derive(Clone, Debug)]
+pub struct Difference(domain::inventory::Difference);
+
+/// Difference of an item between the move-in and the move-out
+/// `RentInventory`s.
+#[graphql_object(name = "InventoryDiffReportItem", context = Context)]
+impl Difference {
+    /// Room the item is located in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.room",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn room(&self) -> inventory::Room {
+        self.0.room.clone().into()
+    }
+
+    /// Name of the item.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.name",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn name(&self) -> inventory::ItemName {
+        self.0.name.clone().into()
+    }
+
+    /// Condition of the item at the move-in.
+    ///
+    /// `null` if the item was not listed at the move-in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.moveIn",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn move_in(&self) -> Option<inventory::Condition> {
+        self.0.move_in.map(Into::into)
+    }
+
+    /// Condition of the item at the move-out.
+    ///
+    /// `null` if the item was not listed at the move-out.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.moveOut",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn move_out(&self) -> Option<inventory::Condition> {
+        self.0.move_out.map(Into::into)
+    }
+
+    /// Photos of the item taken at the move-out.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.photos",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn photos(&self) -> Vec<inventory::Photo> {
+        self.0.photos.iter().cloned().map(Into::into).collect()
+    }
+
+    /// Indicator whether the item was damaged or lost since the move-in.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "InventoryDiffReportItem.isDamage",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn is_damage(&self) -> bool {
+        self.0.is_damage()
+    }
+}
@@ -1,5 +1,15 @@
 //! Module containing the report API.
 
+pub mod arrears;
+pub mod dashboard;
+pub mod inventory_diff;
+pub mod revenue;
 pub mod salary;
+pub mod statement;
+pub mod time_series;
 
-pub use self::salary::Salary;
+pub use self::{
+    arrears::Arrears, dashboard::Dashboard, inventory_diff::InventoryDiff,
+    revenue::Revenue, salary::Salary, statement::Statement,
+    time_series::TimeSeries,
+};
@@ -0,0 +1,166 @@
+//! [`Revenue`] report definition.
+
+use common::Money;
+use derive_more::From;
+use juniper::graphql_object;
+use service::query;
+
+use crate::{api, Context};
+
+/// Report calculating monthly revenue of the active rent `Contract`s.
+#[derive(Clone, Debug, From)]
+pub struct Revenue(query::report::revenue::Output);
+
+/// Report calculating monthly revenue of the active rent `Contract`s, along
+/// with their `RentCharge`s.
+#[graphql_object(name = "RevenueReport", context = Context)]
+impl Revenue {
+    /// Rows of this report, one per an active `RentContract`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReport.rows",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn rows(&self) -> Vec<Row> {
+        self.0.rows.iter().copied().map(Row).collect()
+    }
+
+    /// Total monthly rents, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReport.rents",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn rents(&self) -> &[Money] {
+        &self.0.rents
+    }
+
+    /// Total monthly `RentCharge`s borne by tenants, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReport.tenantCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn tenant_charges(&self) -> &[Money] {
+        &self.0.tenant_charges
+    }
+
+    /// Total monthly `RentCharge`s borne by landlords, one per a currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReport.landlordCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn landlord_charges(&self) -> &[Money] {
+        &self.0.landlord_charges
+    }
+
+    /// Total monthly `RentCharge`s borne by the `Agency`, one per a
+    /// currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReport.agencyCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn agency_charges(&self) -> &[Money] {
+        &self.0.agency_charges
+    }
+}
+
+/// Row of a [`Revenue`] report.
+#[derive(Clone, Copy, Debug)]
+pub struct Row(query::report::revenue::Row);
+
+/// Monthly revenue of an active `RentContract`.
+///
+/// All the amounts are in the currency of the `RentContract.price`.
+#[graphql_object(name = "RevenueReportRow", context = Context)]
+impl Row {
+    /// `RentContract` this row is of.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReportRow.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn contract(&self) -> api::contract::Rent {
+        // SAFETY: `Contract` is selected from the existing ones.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::contract::Rent::new_unchecked(self.0.contract_id)
+        }
+    }
+
+    /// Monthly rent.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReportRow.rent",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn rent(&self) -> Money {
+        self.0.rent
+    }
+
+    /// Monthly `RentCharge`s borne by the tenant.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReportRow.tenantCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn tenant_charges(&self) -> Money {
+        self.0.tenant_charges
+    }
+
+    /// Monthly `RentCharge`s borne by the landlord.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReportRow.landlordCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn landlord_charges(&self) -> Money {
+        self.0.landlord_charges
+    }
+
+    /// Monthly `RentCharge`s borne by the `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RevenueReportRow.agencyCharges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn agency_charges(&self) -> Money {
+        self.0.agency_charges
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::sync::OnceLock;
 
-use common::Money;
+use common::{LocalDateTime, Money, TimeZone};
 use derive_more::From;
 use juniper::graphql_object;
 use service::query;
@@ -19,6 +19,9 @@ pub struct Salary {
 
     /// [`Row`]s of this report.
     rows: OnceLock<Vec<Row>>,
+
+    /// [`BranchRow`]s of this report.
+    branches: OnceLock<Vec<BranchRow>>,
 }
 
 impl From<query::report::salary::Output> for Salary {
@@ -26,6 +29,7 @@ impl From<query::report::salary::Output> for Salary {
         Self {
             output,
             rows: OnceLock::new(),
+            branches: OnceLock::new(),
         }
     }
 }
@@ -33,6 +37,47 @@ impl From<query::report::salary::Output> for Salary {
 /// Report calculating salaries of `User`-employees.
 #[graphql_object(name = "SalaryReport", context = Context)]
 impl Salary {
+    /// Start of the report period, in its `timeZone`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReport.startAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn start_at(&self) -> LocalDateTime {
+        self.output.start.in_tz(self.output.time_zone)
+    }
+
+    /// End of the report period, in its `timeZone`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReport.endAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn end_at(&self) -> LocalDateTime {
+        self.output.end.in_tz(self.output.time_zone)
+    }
+
+    /// Time zone the report period is aligned to.
+    ///
+    /// `UTC` if none was specified.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReport.timeZone",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn time_zone(&self) -> TimeZone {
+        self.output.time_zone
+    }
+
     /// Total number of `Contract`s created within the report period.
     #[tracing::instrument(
         skip_all,
@@ -62,6 +107,29 @@ impl Salary {
             })
             .as_slice()
     }
+
+    /// `SalaryReportBranch`es of this report, aggregating its `rows` per
+    /// `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReport.branches",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn branches(&self) -> &[BranchRow] {
+        self.branches
+            .get_or_init(|| {
+                self.output
+                    .branches
+                    .iter()
+                    .cloned()
+                    .map(BranchRow::from)
+                    .collect()
+            })
+            .as_slice()
+    }
 }
 
 /// Row of a [`Salary`] report.
@@ -105,7 +173,8 @@ impl Row {
         &self.user
     }
 
-    /// Number of `Contract`s signed by the `User` within the report period.
+    /// Number of `Contract`s signed by the `User`, or sharing their commission
+    /// with the `User`, within the report period.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -119,6 +188,8 @@ impl Row {
     }
 
     /// Total salary of the `User` within the report period.
+    ///
+    /// Bonus part is attributed according to `ContractCommissionSplit`s.
     #[tracing::instrument(
         skip_all,
         fields(
@@ -130,4 +201,103 @@ impl Row {
     pub fn salary(&self) -> Money {
         self.row.salary
     }
+
+    /// `salary` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReportRow.formattedSalary",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn formatted_salary(&self, ctx: &Context) -> String {
+        self.row.salary.format(ctx.locale().into())
+    }
+}
+
+/// Aggregation of the [`Row`]s of a [`Salary`] report per [`api::Branch`].
+#[derive(Clone, Debug)]
+pub struct BranchRow {
+    /// Underlying [`query::report::salary::BranchRow`].
+    row: query::report::salary::BranchRow,
+
+    /// [`api::Branch`] this [`BranchRow`] is about, if any.
+    branch: Option<api::Branch>,
+}
+
+impl From<query::report::salary::BranchRow> for BranchRow {
+    fn from(row: query::report::salary::BranchRow) -> Self {
+        Self {
+            // SAFETY: `BranchRow` is constructed from a valid `branch_id`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            branch: row
+                .branch_id
+                .map(|id| unsafe { api::Branch::new_unchecked(id) }),
+            row,
+        }
+    }
+}
+
+/// Aggregation of the `SalaryReportRow`s per `Branch`.
+#[graphql_object(name = "SalaryReportBranch", context = Context)]
+impl BranchRow {
+    /// `Branch` the `SalaryReportRow`s are aggregated for.
+    ///
+    /// `null` aggregates the `User`s not assigned to any `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReportBranch.branch",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn branch(&self) -> Option<&api::Branch> {
+        self.branch.as_ref()
+    }
+
+    /// Number of the `User`s assigned to the `Branch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReportBranch.employeesCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn employees_count(&self) -> i32 {
+        i32::try_from(self.row.employees).unwrap_or(i32::MAX)
+    }
+
+    /// Number of `Contract`s signed by the `User`s within the report period.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReportBranch.contractsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn contracts_count(&self) -> i32 {
+        self.row.contracts.into()
+    }
+
+    /// Total salaries of the `User`s within the report period, one per a
+    /// salary currency.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SalaryReportBranch.salaries",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn salaries(&self) -> &[Money] {
+        &self.row.salaries
+    }
 }
@@ -0,0 +1,162 @@
+//! [`Statement`] report definition.
+
+use common::Money;
+use derive_more::From;
+use juniper::graphql_object;
+use service::query;
+
+#[cfg(doc)]
+use crate::api::User;
+use crate::{api, Context};
+
+/// Statement of the amounts billed to and paid by the purchaser [`User`]
+/// under a rent `Contract`.
+#[derive(Clone, Debug, From)]
+pub struct Statement(query::report::statement::Output);
+
+/// Statement of the amounts billed to and paid by the purchaser `User` under
+/// a rent `Contract`.
+///
+/// All the amounts are in the currency of the `RentContract.price`.
+#[graphql_object(name = "RentStatement", context = Context)]
+impl Statement {
+    /// Number of the rent installments due so far.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.installmentsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn installments_count(&self) -> i32 {
+        i32::try_from(self.0.installments).unwrap_or(i32::MAX)
+    }
+
+    /// Total rent billed for the due installments.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.rent",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn rent(&self) -> Money {
+        self.0.rent
+    }
+
+    /// `RentStatementCharge`s borne by the purchaser `User`, in the order
+    /// they were added.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.charges",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn charges(&self) -> Vec<ChargeRow> {
+        self.0.charges.iter().cloned().map(ChargeRow).collect()
+    }
+
+    /// Total accrued `RentLateFee`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.lateFees",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn late_fees(&self) -> Money {
+        self.0.late_fees
+    }
+
+    /// Total amount of the `RentPayment`s made.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.paid",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn paid(&self) -> Money {
+        self.0.paid
+    }
+
+    /// Remaining balance to be paid.
+    ///
+    /// Negative if overpaid.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.balance",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn balance(&self) -> Money {
+        self.0.balance
+    }
+
+    /// `balance` formatted according to the `Accept-Language` header.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatement.formattedBalance",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn formatted_balance(&self, ctx: &Context) -> String {
+        self.0.balance.format(ctx.locale().into())
+    }
+}
+
+/// `RentCharge` billed in a [`Statement`].
+#[derive(Clone, Debug)]
+pub struct ChargeRow(query::report::statement::ChargeRow);
+
+/// `RentCharge` billed in a `RentStatement`.
+#[graphql_object(name = "RentStatementCharge", context = Context)]
+impl ChargeRow {
+    /// Billed `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatementCharge.charge",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn charge(&self) -> api::Charge {
+        self.0.charge.clone().into()
+    }
+
+    /// Number of the rent installments the `RentCharge` is billed with.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatementCharge.installmentsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn installments_count(&self) -> i32 {
+        i32::try_from(self.0.installments).unwrap_or(i32::MAX)
+    }
+
+    /// Total billed amount of the `RentCharge`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "RentStatementCharge.amount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn amount(&self) -> Money {
+        self.0.amount
+    }
+}
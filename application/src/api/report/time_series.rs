@@ -0,0 +1,142 @@
+//! [`TimeSeries`] report definition.
+
+use common::{LocalDateTime, TimeZone};
+use derive_more::From;
+use juniper::{graphql_object, GraphQLEnum};
+use service::{query, read};
+
+use crate::{api, Context};
+
+/// Report counting the placed, signed and terminated `Contract`s of the
+/// current `Agency`, bucketed by time for charting.
+#[derive(Clone, Debug, From)]
+pub struct TimeSeries(query::report::time_series::Output);
+
+/// Report counting the placed, signed and terminated `Contract`s of the
+/// current `Agency`, bucketed by time for charting.
+#[graphql_object(name = "TimeSeriesReport", context = Context)]
+impl TimeSeries {
+    /// Time zone the buckets are aligned to.
+    ///
+    /// `UTC` if none was specified.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReport.timeZone",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn time_zone(&self) -> TimeZone {
+        self.0.time_zone
+    }
+
+    /// `TimeSeriesReportPoint`s of this report, in the order of their buckets.
+    ///
+    /// Buckets without any events are omitted.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReport.points",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn points(&self) -> Vec<Point> {
+        self.0
+            .points
+            .iter()
+            .map(|&point| Point {
+                point,
+                time_zone: self.0.time_zone,
+            })
+            .collect()
+    }
+}
+
+/// Point of a [`TimeSeries`] report.
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    /// Underlying [`read::time_series::Point`].
+    point: read::time_series::Point,
+
+    /// [`TimeZone`] the bucket of this [`Point`] is aligned to.
+    time_zone: TimeZone,
+}
+
+/// Point of a `TimeSeriesReport`, counting the events of a single bucket.
+#[graphql_object(name = "TimeSeriesReportPoint", context = Context)]
+impl Point {
+    /// Start of the bucket, in the report `timeZone`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReportPoint.at",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn at(&self) -> LocalDateTime {
+        self.point.at.in_tz(self.time_zone)
+    }
+
+    /// Number of the `Contract`s placed within the bucket.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReportPoint.placementsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn placements_count(&self) -> i32 {
+        self.point.placements
+    }
+
+    /// Number of the `Contract`s signed by all their `ContractParty`s within
+    /// the bucket.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReportPoint.signedContractsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn signed_contracts_count(&self) -> i32 {
+        self.point.signed_contracts
+    }
+
+    /// Number of the `Contract`s terminated within the bucket.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TimeSeriesReportPoint.terminationsCount",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    #[must_use]
+    pub fn terminations_count(&self) -> i32 {
+        self.point.terminations
+    }
+}
+
+/// Granularity of the `TimeSeriesReport` buckets.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "TimeSeriesGranularity")]
+pub enum Granularity {
+    /// Daily buckets.
+    Day,
+
+    /// Weekly buckets, starting on Mondays.
+    Week,
+}
+
+impl From<Granularity> for read::time_series::Granularity {
+    fn from(granularity: Granularity) -> Self {
+        match granularity {
+            Granularity::Day => Self::Day,
+            Granularity::Week => Self::Week,
+        }
+    }
+}
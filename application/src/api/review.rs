@@ -0,0 +1,210 @@
+//! [`Review`]-related definitions.
+
+use common::{DateTime, Handler as _};
+use derive_more::{AsRef, Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLObject, GraphQLScalar};
+use service::{domain, query, read};
+use uuid::Uuid;
+
+use crate::{api, api::scalar, define_error, AsError, Context, Error};
+
+/// Review of an employer left by a purchaser or a landlord of a completed
+/// `Contract`.
+#[derive(Clone, Debug, From)]
+pub struct Review(domain::Review);
+
+/// Review of an employer left by a purchaser or a landlord of a completed
+/// `Contract`.
+#[graphql_object(context = Context)]
+impl Review {
+    /// Unique identifier of this `Review`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// Completed `Contract` this `Review` is left on.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.contract",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn contract(
+        &self,
+        ctx: &Context,
+    ) -> Result<api::ContractValue, Error> {
+        ctx.service()
+            .execute(query::contract::ById::by(self.0.contract_id))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .map(Into::into)
+            .ok_or_else(|| api::query::ContractError::NotExists.into())
+    }
+
+    /// Reviewed employer.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.employer",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn employer(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `Review`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.employer_id)
+        }
+    }
+
+    /// `User` who left this `Review`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.author",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn author(&self) -> api::User {
+        // SAFETY: `User` is referenced by an existing `Review`.
+        #[expect(
+            clippy::allow_attributes,
+            reason = "TODO: Remove once clippy is fixed"
+        )]
+        #[allow(unsafe_code, reason = "invariants are preserved")]
+        unsafe {
+            api::User::new_unchecked(self.0.author_id)
+        }
+    }
+
+    /// Rating of the employer, from `1` to `5`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.rating",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn rating(&self) -> i32 {
+        self.0.rating.get().into()
+    }
+
+    /// Comment of the author, if any.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.comment",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn comment(&self) -> Option<Comment> {
+        self.0.comment.clone().map(Into::into)
+    }
+
+    /// Moderation status of this `Review`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.status",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn status(&self) -> Status {
+        self.0.status.into()
+    }
+
+    /// `DateTime` when this `Review` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "Review.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+}
+
+/// Unique identifier of a `Review`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::review::Id)]
+#[into(domain::review::Id)]
+#[graphql(name = "ReviewId", transparent)]
+pub struct Id(Uuid);
+
+/// Comment of a `Review`.
+#[derive(AsRef, Clone, Debug, Display, From, GraphQLScalar, Into)]
+#[graphql(
+    name = "ReviewComment",
+    with = scalar::Via::<domain::review::Comment>,
+)]
+pub struct Comment(domain::review::Comment);
+
+/// Moderation status of a `Review`.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "ReviewStatus")]
+pub enum Status {
+    /// `Review` is visible and counted in the employer rating.
+    Published,
+
+    /// `Review` is hidden by a moderator.
+    Hidden,
+}
+
+impl From<domain::review::Status> for Status {
+    fn from(status: domain::review::Status) -> Self {
+        use domain::review::Status as S;
+        match status {
+            S::Published => Self::Published,
+            S::Hidden => Self::Hidden,
+        }
+    }
+}
+
+/// Rating of an employer aggregated over all its published `Review`s.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "UserRating")]
+pub struct Rating {
+    /// Average rating of the employer, from `1` to `5`.
+    pub average: f64,
+
+    /// Number of the published `Review`s of the employer.
+    pub count: i32,
+}
+
+impl From<read::review::Rating> for Rating {
+    fn from(rating: read::review::Rating) -> Self {
+        Self {
+            average: rating.average,
+            count: i32::try_from(rating.count).unwrap_or(i32::MAX),
+        }
+    }
+}
+
+define_error! {
+    enum ReviewError {
+        #[code = "REVIEW_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`Review` does not exist"]
+        NotExists,
+
+        #[code = "INVALID_REVIEW_RATING"]
+        #[status = BAD_REQUEST]
+        #[message = "`Review` rating must be from 1 to 5"]
+        InvalidRating,
+    }
+}
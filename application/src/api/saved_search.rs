@@ -0,0 +1,216 @@
+//! [`SavedSearch`]-related definitions.
+
+use common::{DateTime, Money};
+use derive_more::{Display, From, Into};
+use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+use service::{domain, read};
+use uuid::Uuid;
+
+use crate::{api, define_error, Context};
+
+/// Filter of the `Placement`s saved by a `User` to be alerted about the newly
+/// placed ones matching it.
+#[derive(Clone, Debug, From)]
+pub struct SavedSearch(domain::SavedSearch);
+
+/// Filter of the `Placement`s saved by a `User` to be alerted about the newly
+/// placed ones matching it.
+#[graphql_object(context = Context)]
+impl SavedSearch {
+    /// Unique identifier of this `SavedSearch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.id",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn id(&self) -> Id {
+        self.0.id.into()
+    }
+
+    /// City of the placed `Realty`s to match.
+    ///
+    /// `null` means any city.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.city",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn city(&self) -> Option<api::realty::City> {
+        self.0.city.clone().map(Into::into)
+    }
+
+    /// Minimal price (inclusive) of the `Placement`s to match.
+    ///
+    /// `null` means no lower bound.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.minPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn min_price(&self) -> Option<Money> {
+        self.0.min_price
+    }
+
+    /// Maximal price (inclusive) of the `Placement`s to match.
+    ///
+    /// `null` means no upper bound.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.maxPrice",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn max_price(&self) -> Option<Money> {
+        self.0.max_price
+    }
+
+    /// Kind of the `Placement`s to match.
+    ///
+    /// `null` means both.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.kind",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn kind(&self) -> Option<Kind> {
+        self.0.kind.map(Into::into)
+    }
+
+    /// `DateTime` when this `SavedSearch` was created.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.createdAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn created_at(&self) -> DateTime {
+        self.0.created_at.coerce()
+    }
+
+    /// `DateTime` the `User` was last alerted about the `Placement`s matching
+    /// this `SavedSearch` by.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearch.alertedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn alerted_at(&self) -> DateTime {
+        self.0.alerted_at.coerce()
+    }
+}
+
+/// Newly placed `Realty`s matching a `SavedSearch`.
+#[derive(Clone, Debug, From)]
+pub struct Match(read::saved_search::Matched);
+
+/// Newly placed `Realty`s matching a `SavedSearch`.
+#[graphql_object(name = "SavedSearchMatch", context = Context)]
+impl Match {
+    /// `SavedSearch` being matched.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearchMatch.savedSearch",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn saved_search(&self) -> SavedSearch {
+        self.0.search.clone().into()
+    }
+
+    /// Total number of the newly placed `Realty`s matching the `SavedSearch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearchMatch.count",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn count(&self) -> i32 {
+        i32::try_from(self.0.count).unwrap_or(i32::MAX)
+    }
+
+    /// First of the newly placed `Realty`s matching the `SavedSearch`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "SavedSearchMatch.realties",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn realties(&self) -> Vec<api::Realty> {
+        self.0
+            .realty_ids
+            .iter()
+            .map(|id| {
+                // SAFETY: `Realty` is matched as an existing one.
+                #[expect(
+                    clippy::allow_attributes,
+                    reason = "TODO: Remove once clippy is fixed"
+                )]
+                #[allow(unsafe_code, reason = "invariants are preserved")]
+                unsafe {
+                    api::Realty::new_unchecked(*id)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Unique identifier of a `SavedSearch`.
+#[derive(Clone, Copy, Debug, Display, Into, From, GraphQLScalar)]
+#[from(domain::saved_search::Id)]
+#[into(domain::saved_search::Id)]
+#[graphql(name = "SavedSearchId", transparent)]
+pub struct Id(Uuid);
+
+/// Kind of the `Placement`s a `SavedSearch` matches.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "SavedSearchKind")]
+pub enum Kind {
+    /// `Realty` placed for rent.
+    Rent,
+
+    /// `Realty` placed for sale.
+    Sale,
+}
+
+impl From<domain::saved_search::Kind> for Kind {
+    fn from(kind: domain::saved_search::Kind) -> Self {
+        use domain::saved_search::Kind as K;
+        match kind {
+            K::Rent => Self::Rent,
+            K::Sale => Self::Sale,
+        }
+    }
+}
+
+impl From<Kind> for domain::saved_search::Kind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::Rent => Self::Rent,
+            Kind::Sale => Self::Sale,
+        }
+    }
+}
+
+define_error! {
+    enum SavedSearchError {
+        #[code = "SAVED_SEARCH_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`SavedSearch` does not exist"]
+        NotExists,
+    }
+}
@@ -0,0 +1,69 @@
+//! Global search definitions.
+
+use derive_more::From;
+use juniper::GraphQLUnion;
+use service::query;
+
+use crate::{
+    api::{self, contract, ContractValue},
+    Context,
+};
+
+/// Entity found by the global search.
+#[expect(
+    clippy::large_enum_variant,
+    reason = "short-lived GraphQL output value"
+)]
+#[derive(Debug, From, GraphQLUnion)]
+#[graphql(context = Context)]
+pub enum SearchResult {
+    /// Found `User`.
+    User(api::User),
+
+    /// Found `Realty`.
+    Realty(api::Realty),
+
+    /// Found `Placement`.
+    Placement(api::placement::Placement),
+
+    /// Found `Employment` contract.
+    Employment(contract::Employment),
+
+    /// Found `ManagementForRent` contract.
+    ManagementForRent(contract::ManagementForRent),
+
+    /// Found `ManagementForSale` contract.
+    ManagementForSale(contract::ManagementForSale),
+
+    /// Found `Rent` contract.
+    Rent(contract::Rent),
+
+    /// Found `Sale` contract.
+    Sale(contract::Sale),
+
+    /// Found `ShortTermRent` contract.
+    ShortTermRent(contract::ShortTermRent),
+}
+
+impl From<query::search::Hit> for SearchResult {
+    fn from(hit: query::search::Hit) -> Self {
+        use query::search::Hit;
+        match hit {
+            Hit::User(u) => Self::User(u.into()),
+            Hit::Realty(r) => Self::Realty(r.into()),
+            Hit::Placement(p) => Self::Placement(p.into()),
+            Hit::Contract(c) => match ContractValue::from(c) {
+                ContractValue::Employment(c) => Self::Employment(c),
+                ContractValue::ManagementForRent(c) => {
+                    Self::ManagementForRent(c)
+                }
+                ContractValue::ManagementForSale(c) => {
+                    Self::ManagementForSale(c)
+                }
+                ContractValue::Rent(c) => Self::Rent(c),
+                ContractValue::Sale(c) => Self::Sale(c),
+                ContractValue::ShortTermRent(c) => Self::ShortTermRent(c),
+            },
+        }
+    }
+}
@@ -6,8 +6,10 @@ use futures::{
     FutureExt as _, StreamExt as _,
 };
 use juniper::graphql_subscription;
+use service::{query, Query as _};
+use tokio::sync::broadcast::error::RecvError;
 
-use crate::{context, Context, Error};
+use crate::{api, context, AsError, Context, Error};
 
 /// Root of all GraphQL subscription.
 #[derive(Clone, Copy, Debug)]
@@ -35,4 +37,176 @@ impl Subscription {
         )
         .boxed())
     }
+
+    /// Subscription to the `Contract`s being completed due to their
+    /// expiration.
+    ///
+    /// `Contract`s completed while the subscriber lags behind are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired;
+    /// - `NOT_EMPLOYER` - the current `User` is not an employer.
+    pub async fn completed_contracts(
+        &self,
+        ctx: &Context,
+    ) -> Result<BoxStream<'static, Result<api::ContractValue, Error>>, Error>
+    {
+        let my_id = ctx.current_session().await?.user_id;
+        let is_employed = ctx
+            .service()
+            .execute(query::contract::Employment::by(my_id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())?
+            .is_some_and(|e| ctx.is_agency_employment(&e.0));
+        if !is_employed {
+            return Err(api::PrivilegeError::Employer.into());
+        }
+
+        let completed = ctx.service().completed_contracts();
+        Ok(stream::unfold(completed, |mut completed| async move {
+            loop {
+                match completed.recv().await {
+                    Ok(c) => return Some((Ok(c.into()), completed)),
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    /// Subscription to the `Offer`s being submitted, accepted or declined.
+    ///
+    /// Only the `Offer`s submitted by the current `User`, or the ones on the
+    /// `Realty` managed by the current `User`, are emitted. `Offer`s updated
+    /// while the subscriber lags behind are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired.
+    pub async fn offer_updates(
+        &self,
+        ctx: &Context,
+    ) -> Result<BoxStream<'static, Result<api::Offer, Error>>, Error> {
+        let my_id = ctx.current_session().await?.user_id.into();
+
+        let offers = ctx.service().offers();
+        Ok(stream::unfold(offers, move |mut offers| async move {
+            loop {
+                match offers.recv().await {
+                    Ok(o) if o.bidder_id == my_id || o.employer_id == my_id => {
+                        return Some((Ok(o.into()), offers));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    /// Subscription to the `RentArrears` being detected or grown.
+    ///
+    /// Only the `RentArrears` of the `RentContract`s managed by the current
+    /// `User` are emitted. `RentArrears` detected while the subscriber lags
+    /// behind are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired.
+    pub async fn rent_arrears(
+        &self,
+        ctx: &Context,
+    ) -> Result<BoxStream<'static, Result<api::Arrears, Error>>, Error> {
+        let my_id = ctx.current_session().await?.user_id.into();
+
+        let arrears = ctx.service().arrears();
+        Ok(stream::unfold(arrears, move |mut arrears| async move {
+            loop {
+                match arrears.recv().await {
+                    Ok(a) if a.employer_id == my_id => {
+                        return Some((Ok(a.into()), arrears));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    /// Subscription to the `InsurancePolicy`s expiring soon.
+    ///
+    /// Only the `InsurancePolicy`s of the `Contract`s managed by the current
+    /// `User` are emitted, each one once. `InsurancePolicy`s expiring while
+    /// the subscriber lags behind are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired.
+    pub async fn expiring_insurance_policies(
+        &self,
+        ctx: &Context,
+    ) -> Result<BoxStream<'static, Result<api::Insurance, Error>>, Error> {
+        let my_id = ctx.current_session().await?.user_id.into();
+
+        let expiring = ctx.service().expiring_insurances();
+        Ok(stream::unfold(expiring, move |mut expiring| async move {
+            loop {
+                match expiring.recv().await {
+                    Ok(e) if e.employer_id == my_id => {
+                        return Some((Ok(e.insurance.into()), expiring));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    /// Subscription to the newly placed `Realty`s matching the `SavedSearch`es
+    /// of the current `User`.
+    ///
+    /// Every `Realty` is emitted once per `SavedSearch`. Matches happening
+    /// while the subscriber lags behind are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Possible error codes:
+    /// - `AUTHORIZATION_REQUIRED` - if the current session is not
+    ///                              authenticated or session expired.
+    pub async fn saved_search_matches(
+        &self,
+        ctx: &Context,
+    ) -> Result<
+        BoxStream<'static, Result<api::saved_search::Match, Error>>,
+        Error,
+    > {
+        let my_id = ctx.current_session().await?.user_id.into();
+
+        let matches = ctx.service().saved_search_matches();
+        Ok(stream::unfold(matches, move |mut matches| async move {
+            loop {
+                match matches.recv().await {
+                    Ok(m) if m.search.user_id == my_id => {
+                        return Some((Ok(m.into()), matches));
+                    }
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
 }
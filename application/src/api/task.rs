@@ -0,0 +1,234 @@
+//! Background task definitions.
+
+use std::time;
+
+use common::DateTime;
+use juniper::{GraphQLEnum, GraphQLObject};
+use service::{domain, task};
+
+/// Status of a periodic background task.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(name = "BackgroundTask")]
+pub struct Status {
+    /// Name of the task.
+    pub name: String,
+
+    /// `DateTime` when the last run of the task has started.
+    ///
+    /// `null` if the task hasn't run yet.
+    pub last_run_at: Option<DateTime>,
+
+    /// Result of the last run of the task.
+    ///
+    /// `null` if the task hasn't run yet.
+    pub last_result: Option<RunResult>,
+
+    /// Error of the last run of the task, if it has failed.
+    pub last_error: Option<String>,
+
+    /// `DateTime` when the next run of the task is scheduled at.
+    pub next_run_at: Option<DateTime>,
+
+    /// Number of the consecutive failed runs of the task.
+    pub consecutive_failures: i32,
+}
+
+impl Status {
+    /// Creates a new [`Status`] of the task with the provided `name`.
+    #[must_use]
+    pub fn new(name: &str, status: task::Status) -> Self {
+        let task::Status {
+            last_run_at,
+            last_error,
+            next_run_at,
+            consecutive_failures,
+            last_duration: _,
+            runs: _,
+            failures: _,
+            processed: _,
+        } = status;
+        Self {
+            name: name.to_owned(),
+            last_run_at,
+            last_result: last_run_at.map(|_| {
+                if last_error.is_some() {
+                    RunResult::Failed
+                } else {
+                    RunResult::Succeeded
+                }
+            }),
+            last_error,
+            next_run_at,
+            consecutive_failures: i32::try_from(consecutive_failures)
+                .unwrap_or(i32::MAX),
+        }
+    }
+}
+
+/// Result of a background task run.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "BackgroundTaskRunResult")]
+pub enum RunResult {
+    /// The run has succeeded.
+    Succeeded,
+
+    /// The run has failed.
+    Failed,
+}
+
+/// Finished run of a periodic background task.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(name = "BackgroundTaskRun")]
+pub struct Run {
+    /// Name of the task.
+    pub task: String,
+
+    /// `DateTime` when the run has started.
+    pub started_at: DateTime,
+
+    /// Duration of the run, in milliseconds.
+    pub duration_milliseconds: i32,
+
+    /// Number of the items (rows, documents, etc.) processed by the run.
+    pub processed: i32,
+
+    /// Result of the run.
+    pub result: RunResult,
+
+    /// Error of the run, if it has failed.
+    pub error: Option<String>,
+}
+
+impl From<domain::task::Run> for Run {
+    fn from(run: domain::task::Run) -> Self {
+        let domain::task::Run {
+            task,
+            started_at,
+            duration,
+            processed,
+            error,
+        } = run;
+        Self {
+            task,
+            started_at,
+            duration_milliseconds: i32::try_from(duration.as_millis())
+                .unwrap_or(i32::MAX),
+            processed: i32::try_from(processed).unwrap_or(i32::MAX),
+            result: if error.is_some() {
+                RunResult::Failed
+            } else {
+                RunResult::Succeeded
+            },
+            error,
+        }
+    }
+}
+
+/// Work item a periodic background task has failed to process, kept until
+/// requeued.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(name = "BackgroundTaskDeadLetter")]
+pub struct DeadLetter {
+    /// Name of the task.
+    pub task: String,
+
+    /// ID of the failed work item (a `Contract`, for example).
+    pub item: String,
+
+    /// Error of the last processing attempt.
+    pub error: String,
+
+    /// Number of the failed processing attempts.
+    pub attempts: i32,
+
+    /// `DateTime` of the last processing attempt.
+    pub failed_at: DateTime,
+}
+
+impl From<domain::task::DeadLetter> for DeadLetter {
+    fn from(letter: domain::task::DeadLetter) -> Self {
+        let domain::task::DeadLetter {
+            task,
+            item,
+            error,
+            attempts,
+            failed_at,
+        } = letter;
+        Self {
+            task,
+            item,
+            error,
+            attempts: i32::try_from(attempts).unwrap_or(i32::MAX),
+            failed_at,
+        }
+    }
+}
+
+/// Runtime settings of a periodic background task, overriding its
+/// configuration.
+#[derive(Clone, Copy, Debug, GraphQLObject)]
+#[graphql(name = "BackgroundTaskSettings")]
+pub struct Settings {
+    /// Kind of the task these settings belong to.
+    pub task: Kind,
+
+    /// Interval between the task runs, in seconds.
+    ///
+    /// `null` means the configured one.
+    pub interval_seconds: Option<i32>,
+
+    /// Task-specific timeout, in seconds.
+    ///
+    /// `null` means the configured one.
+    pub timeout_seconds: Option<i32>,
+
+    /// `DateTime` when these settings were updated.
+    pub updated_at: DateTime,
+}
+
+impl From<domain::task::Settings> for Settings {
+    fn from(settings: domain::task::Settings) -> Self {
+        let domain::task::Settings {
+            task,
+            interval,
+            timeout,
+            updated_at,
+        } = settings;
+        let seconds =
+            |d: time::Duration| i32::try_from(d.as_secs()).unwrap_or(i32::MAX);
+        Self {
+            task: task.into(),
+            interval_seconds: interval.map(seconds),
+            timeout_seconds: timeout.map(seconds),
+            updated_at: updated_at.coerce(),
+        }
+    }
+}
+
+/// Kind of a periodic background task with adjustable settings.
+#[derive(Clone, Copy, Debug, GraphQLEnum)]
+#[graphql(name = "BackgroundTaskKind")]
+pub enum Kind {
+    /// Task deleting `Realty`s which are not used by any `Contract`.
+    ///
+    /// Its timeout is the age after which an unused `Realty` is deleted.
+    CleanUnusedRealties,
+}
+
+impl From<domain::task::Kind> for Kind {
+    fn from(kind: domain::task::Kind) -> Self {
+        match kind {
+            domain::task::Kind::CleanUnusedRealties => {
+                Self::CleanUnusedRealties
+            }
+        }
+    }
+}
+
+impl From<Kind> for domain::task::Kind {
+    fn from(kind: Kind) -> Self {
+        match kind {
+            Kind::CleanUnusedRealties => Self::CleanUnusedRealties,
+        }
+    }
+}
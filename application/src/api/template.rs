@@ -0,0 +1,50 @@
+//! Outgoing message template definitions.
+
+use juniper::GraphQLObject;
+use service::infra::template;
+
+use crate::{define_error, AsError, Error};
+
+/// Template of an outgoing email or SMS message.
+#[derive(Clone, Debug, GraphQLObject)]
+#[graphql(name = "MessageTemplate")]
+pub struct Definition {
+    /// Name of the template (like `email/password_changed/subject`).
+    pub name: String,
+
+    /// Placeholders the template may refer to.
+    pub placeholders: Vec<String>,
+}
+
+impl From<&template::Definition> for Definition {
+    fn from(def: &template::Definition) -> Self {
+        Self {
+            name: def.name.to_owned(),
+            placeholders: def
+                .placeholders
+                .iter()
+                .map(|(p, _)| (*p).to_owned())
+                .collect(),
+        }
+    }
+}
+
+define_error! {
+    enum TemplateError {
+        #[code = "MESSAGE_TEMPLATE_NOT_EXISTS"]
+        #[status = NOT_FOUND]
+        #[message = "`MessageTemplate` with the provided name does not exist"]
+        NotExists,
+    }
+}
+
+impl AsError for template::Error {
+    fn try_as_error(&self) -> Option<Error> {
+        match self {
+            Self::UnknownTemplate(_) => Some(TemplateError::NotExists.into()),
+            Self::Io(_)
+            | Self::Template(_)
+            | Self::UnknownPlaceholder { .. } => None,
+        }
+    }
+}
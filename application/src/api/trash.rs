@@ -0,0 +1,185 @@
+//! Trash-related definitions.
+
+use common::DateTime;
+use derive_more::From;
+use juniper::graphql_object;
+use service::read;
+use uuid::Uuid;
+
+use crate::{api, Context};
+
+/// Deleted entity in the trash, which may be restored yet.
+#[derive(Clone, Copy, Debug, From)]
+pub struct Item(read::trash::list::Item);
+
+/// Deleted entity in the trash, which may be restored yet.
+#[graphql_object(name = "TrashItem", context = Context)]
+impl Item {
+    /// Kind of the deleted entity.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TrashItem.entity",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn entity(&self) -> api::audit::Entity {
+        self.0.entity.into()
+    }
+
+    /// Unique identifier of the deleted entity.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TrashItem.entityId",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn entity_id(&self) -> Uuid {
+        self.0.id
+    }
+
+    /// `DateTime` when the entity was deleted.
+    ///
+    /// For `Contract`s, this is the `DateTime` they were archived at.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TrashItem.deletedAt",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn deleted_at(&self) -> DateTime {
+        self.0.deleted_at
+    }
+
+    /// `User` who deleted the entity.
+    ///
+    /// `null` if the entity was deleted by the system (like unused
+    /// `Realty`s), or the deletion wasn't recorded into the audit log.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "TrashItem.deletedBy",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub fn deleted_by(&self) -> Option<api::User> {
+        self.0.deleted_by.map(|id| {
+            // SAFETY: `User` is referenced by an existing `AuditEntry`.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(id)
+            }
+        })
+    }
+}
+
+pub mod list {
+    //! Definitions related to an [`Item`] list.
+
+    use derive_more::{AsRef, From, Into};
+    use juniper::{graphql_object, GraphQLScalar};
+    use service::read;
+
+    use crate::{api::scalar, Context};
+
+    use super::Item;
+
+    /// Cursor for the `TrashItem` list.
+    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
+    #[graphql(
+        name = "TrashItemListCursor",
+        with = scalar::Via::<read::trash::list::Cursor>,
+    )]
+    pub struct Cursor(pub read::trash::list::Cursor);
+
+    /// Edge in the [`Item`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Edge(read::trash::list::Edge);
+
+    /// Edge in the `TrashItem` list.
+    #[graphql_object(name = "TrashItemListEdge", context = Context)]
+    impl Edge {
+        /// Cursor of this `TrashItemListEdge`.
+        #[must_use]
+        pub fn cursor(&self) -> Cursor {
+            self.0.cursor.into()
+        }
+
+        /// Node of this `TrashItemListEdge`.
+        #[must_use]
+        pub fn node(&self) -> Item {
+            self.0.node.into()
+        }
+    }
+
+    /// Connection of the [`Item`] list.
+    #[derive(Clone, Debug, From, Into)]
+    pub struct Connection(read::trash::list::Connection);
+
+    /// Connection of the `TrashItem` list.
+    #[graphql_object(name = "TrashItemListConnection", context = Context)]
+    impl Connection {
+        /// Edges in this `TrashItemListConnection`.
+        #[must_use]
+        pub fn edges(&self) -> Vec<Edge> {
+            self.0.edges.iter().cloned().map(Into::into).collect()
+        }
+
+        /// Information about the page.
+        #[must_use]
+        pub fn page_info(&self) -> PageInfo {
+            PageInfo {
+                info: self.0.page_info(),
+                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
+                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+            }
+        }
+    }
+
+    /// Information about a [`Connection`] page.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PageInfo {
+        /// Underlying [`read::trash::list::PageInfo`].
+        info: read::trash::list::PageInfo,
+
+        /// Start cursor of the page.
+        start_cursor: Option<Cursor>,
+
+        /// End cursor of the page.
+        end_cursor: Option<Cursor>,
+    }
+
+    /// Information about a `TrashItemListConnection` page.
+    #[graphql_object(name = "TrashItemListPageInfo", context = Context)]
+    impl PageInfo {
+        /// Indicator whether there is a next page.
+        #[must_use]
+        pub fn has_next_page(&self) -> bool {
+            self.info.has_next_page
+        }
+
+        /// Indicator whether there is a previous page.
+        #[must_use]
+        pub fn has_previous_page(&self) -> bool {
+            self.info.has_previous_page
+        }
+
+        /// Start cursor of the page.
+        #[must_use]
+        pub fn start_cursor(&self) -> &Option<Cursor> {
+            &self.start_cursor
+        }
+
+        /// End cursor of the page.
+        #[must_use]
+        pub fn end_cursor(&self) -> &Option<Cursor> {
+            &self.end_cursor
+        }
+    }
+}
@@ -125,7 +125,9 @@ impl User {
                     .execute(query::contract::Employment::by(my_id.into()))
                     .map_err(AsError::into_error)
                     .map_err(ctx.error())
-                    .map_ok(|c| c.is_some()),
+                    .map_ok(|c| {
+                        c.is_some_and(|e| ctx.is_agency_employment(&e.0))
+                    }),
             )
         } else {
             Either::Right(future::ok(false))
@@ -161,14 +163,16 @@ impl User {
             .execute(query::contract::Employment::by(self.id.into()))
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map_ok(|c| c.is_some());
+            .map_ok(|c| c.is_some_and(|e| ctx.is_agency_employment(&e.0)));
         let is_employed = if let Some(my_id) = my_id {
             Either::Left(
                 ctx.service()
                     .execute(query::contract::Employment::by(my_id.into()))
                     .map_err(AsError::into_error)
                     .map_err(ctx.error())
-                    .map_ok(|c| c.is_some()),
+                    .map_ok(|c| {
+                        c.is_some_and(|e| ctx.is_agency_employment(&e.0))
+                    }),
             )
         } else {
             Either::Right(future::ok(false))
@@ -204,14 +208,16 @@ impl User {
             .execute(query::contract::Employment::by(self.id.into()))
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map_ok(|c| c.is_some());
+            .map_ok(|c| c.is_some_and(|e| ctx.is_agency_employment(&e.0)));
         let is_employed = if let Some(my_id) = my_id {
             Either::Left(
                 ctx.service()
                     .execute(query::contract::Employment::by(my_id.into()))
                     .map_err(AsError::into_error)
                     .map_err(ctx.error())
-                    .map_ok(|c| c.is_some()),
+                    .map_ok(|c| {
+                        c.is_some_and(|e| ctx.is_agency_employment(&e.0))
+                    }),
             )
         } else {
             Either::Right(future::ok(false))
@@ -238,7 +244,74 @@ impl User {
             .await
             .map_err(AsError::into_error)
             .map_err(ctx.error())
-            .map(|c| c.is_some())
+            .map(|c| c.is_some_and(|e| ctx.is_agency_employment(&e.0)))
+    }
+
+    /// Rating of this `User` as an employer, aggregated over all the
+    /// published `Review`s.
+    ///
+    /// `null` if this `User` has no published `Review`s.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "User.rating",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn rating(
+        &self,
+        ctx: &Context,
+    ) -> Result<Option<api::review::Rating>, Error> {
+        ctx.service()
+            .execute(query::review::Rating::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|r| r.map(Into::into))
+    }
+
+    /// `Review`s of this `User` as an employer, the most recent first.
+    ///
+    /// Hidden `Review`s are visible only to the administrator of the current
+    /// `Agency`.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            gql.name = "User.reviews",
+            otel.name = api::Query::SPAN_NAME,
+        ),
+    )]
+    pub async fn reviews(
+        &self,
+        ctx: &Context,
+    ) -> Result<Vec<api::Review>, Error> {
+        let is_admin = match ctx.try_current_session().await? {
+            Some(session) => ctx
+                .service()
+                .execute(query::agency::ById::by(ctx.agency_id().into()))
+                .await
+                .map_err(AsError::into_error)
+                .map_err(ctx.error())?
+                .is_some_and(|a| a.admin_id == session.user_id.into()),
+            None => false,
+        };
+
+        ctx.service()
+            .execute(query::review::ByEmployer::by(self.id.into()))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(ctx.error())
+            .map(|reviews| {
+                reviews
+                    .into_iter()
+                    .filter(|r| {
+                        r.status == domain::review::Status::Published
+                            || (is_admin
+                                && r.agency_id == ctx.agency_id().into())
+                    })
+                    .map(Into::into)
+                    .collect()
+            })
     }
 
     /// `DateTime` when this `User` was created.
@@ -310,7 +383,7 @@ pub mod session {
 
     use common::DateTime;
     use derive_more::{AsRef, From, Into};
-    use juniper::{GraphQLObject, GraphQLScalar};
+    use juniper::{graphql_object, GraphQLObject, GraphQLScalar};
     use service::{command, domain};
 
     use crate::{
@@ -318,6 +391,71 @@ pub mod session {
         Context,
     };
 
+    /// Authenticated `Session` of a `User`.
+    #[derive(Clone, Debug, From)]
+    pub struct Session(crate::Session);
+
+    /// Authenticated `Session` of a `User`.
+    #[graphql_object(context = Context)]
+    impl Session {
+        /// `User` this `Session` belongs to.
+        #[tracing::instrument(
+            skip_all,
+            fields(
+                gql.name = "Session.user",
+                otel.name = api::Query::SPAN_NAME,
+            ),
+        )]
+        pub fn user(&self) -> api::User {
+            // SAFETY: `User` is checked to exist on `Session` authorization.
+            #[expect(
+                clippy::allow_attributes,
+                reason = "TODO: Remove once clippy is fixed"
+            )]
+            #[allow(unsafe_code, reason = "invariants are preserved")]
+            unsafe {
+                api::User::new_unchecked(self.0.user_id)
+            }
+        }
+
+        /// Admin `User` impersonating the `Session.user` via this `Session`.
+        ///
+        /// `null` if this `Session` is not an impersonating one. Clients are
+        /// expected to show a banner while it's not `null`.
+        #[tracing::instrument(
+            skip_all,
+            fields(
+                gql.name = "Session.impersonatedBy",
+                otel.name = api::Query::SPAN_NAME,
+            ),
+        )]
+        pub fn impersonated_by(&self) -> Option<api::User> {
+            self.0.impersonated_by.map(|id| {
+                // SAFETY: Admin `User` is checked to exist on impersonation.
+                #[expect(
+                    clippy::allow_attributes,
+                    reason = "TODO: Remove once clippy is fixed"
+                )]
+                #[allow(unsafe_code, reason = "invariants are preserved")]
+                unsafe {
+                    api::User::new_unchecked(id)
+                }
+            })
+        }
+
+        /// `DateTime` when this `Session` expires.
+        #[tracing::instrument(
+            skip_all,
+            fields(
+                gql.name = "Session.expiresAt",
+                otel.name = api::Query::SPAN_NAME,
+            ),
+        )]
+        pub fn expires_at(&self) -> DateTime {
+            self.0.expires_at
+        }
+    }
+
     /// `Session` access token.
     #[derive(AsRef, Clone, Debug, From, GraphQLScalar, Into)]
     #[graphql(
@@ -356,28 +494,105 @@ pub mod session {
     }
 }
 
+pub mod invite {
+    //! `User` invite-related definitions.
+
+    use derive_more::{AsRef, From, Into};
+    use juniper::{GraphQLObject, GraphQLScalar};
+    use service::{command, domain};
+
+    use crate::{
+        api::{self, scalar},
+        Context,
+    };
+
+    /// Secret token of a `User` invite.
+    #[derive(AsRef, Clone, Debug, From, GraphQLScalar, Into)]
+    #[graphql(
+        name = "UserInviteToken",
+        with = scalar::Via::<domain::user::invite::Token>,
+    )]
+    pub struct Token(domain::user::invite::Token);
+
+    /// `User` imported with an invite to set its password by.
+    #[derive(Clone, Debug, GraphQLObject)]
+    #[graphql(context = Context, name = "ImportedUser")]
+    pub struct Imported {
+        /// Imported `User`.
+        pub user: api::User,
+
+        /// Link to accept the invite of the imported `User` by.
+        ///
+        /// Expires in 14 days.
+        pub invite_link: String,
+    }
+
+    impl From<command::import_users::Imported> for Imported {
+        fn from(imported: command::import_users::Imported) -> Self {
+            let command::import_users::Imported { user, invite_link } =
+                imported;
+            Self {
+                user: user.into(),
+                invite_link,
+            }
+        }
+    }
+}
+
 pub mod list {
     //! Definitions related to [`User`] list.
 
     use derive_more::{AsRef, From, Into};
-    use juniper::{graphql_object, GraphQLScalar};
-    use service::{query, read, Query as _};
+    use juniper::{graphql_object, GraphQLEnum, GraphQLScalar};
+    use service::{domain, query, read, Query as _};
 
     use crate::{api::scalar, AsError, Context, Error};
 
     use super::{Id, User};
 
     /// Cursor for the `User` list.
-    #[derive(AsRef, Clone, Copy, Debug, From, GraphQLScalar, Into)]
-    #[from(Id, read::user::list::Cursor)]
+    #[derive(AsRef, Clone, Debug, From, GraphQLScalar, Into)]
     #[graphql(
         name = "UserListCursor",
         with = scalar::Via::<read::user::list::Cursor>,
     )]
     pub struct Cursor(pub read::user::list::Cursor);
 
+    impl From<Id> for Cursor {
+        fn from(id: Id) -> Self {
+            Self(domain::user::Id::from(id).into())
+        }
+    }
+
+    /// Order of the `User` list.
+    #[derive(Clone, Copy, Debug, Default, GraphQLEnum)]
+    #[graphql(name = "UserListSortBy")]
+    pub enum SortBy {
+        /// Relevance to the searched `name`.
+        ///
+        /// Order is stable, but unspecified, if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// `User` name in lexicographical order.
+        Name,
+
+        /// `User` creation date in chronological order.
+        CreatedAt,
+    }
+
+    impl From<SortBy> for read::user::list::SortBy {
+        fn from(sort_by: SortBy) -> Self {
+            match sort_by {
+                SortBy::Relevance => Self::Relevance,
+                SortBy::Name => Self::Name,
+                SortBy::CreatedAt => Self::CreatedAt,
+            }
+        }
+    }
+
     /// Edge in the [`User`] list.
-    #[derive(Clone, Copy, Debug, From, Into)]
+    #[derive(Clone, Debug, From, Into)]
     pub struct Edge(read::user::list::Edge);
 
     /// Edge in the `User` list.
@@ -386,26 +601,38 @@ pub mod list {
         /// Cursor of this `UserListEdge`.
         #[must_use]
         pub fn cursor(&self) -> Cursor {
-            self.0.cursor.into()
+            self.0.cursor.clone().into()
         }
 
         /// Node of this `UserListEdge`.
         #[must_use]
         pub fn node(&self) -> User {
-            #[expect(
-                unsafe_code,
-                reason = "`Edge` loaded from repository guarantees `User` \
-                          existence"
-            )]
-            unsafe {
-                User::new_unchecked(self.0.node)
-            }
+            self.0.node.clone().into()
         }
     }
 
     /// Connection of the [`User`] list.
-    #[derive(Clone, Debug, From, Into)]
-    pub struct Connection(read::user::list::Connection);
+    #[derive(Clone, Debug)]
+    pub struct Connection {
+        /// Underlying [`read::user::list::Connection`].
+        connection: read::user::list::Connection,
+
+        /// [`read::user::list::Filter`] the list is selected by.
+        filter: read::user::list::Filter,
+    }
+
+    impl Connection {
+        /// Creates a new [`Connection`] of the provided
+        /// [`read::user::list::Connection`] selected by the provided
+        /// [`read::user::list::Filter`].
+        #[must_use]
+        pub fn new(
+            connection: read::user::list::Connection,
+            filter: read::user::list::Filter,
+        ) -> Self {
+            Self { connection, filter }
+        }
+    }
 
     /// Connection of the `User` list.
     #[graphql_object(name = "UserListConnection", context = Context)]
@@ -413,22 +640,24 @@ pub mod list {
         /// Edges in this `ContractListConnection`.
         #[must_use]
         pub fn edges(&self) -> Vec<Edge> {
-            self.0.edges.iter().copied().map(Into::into).collect()
+            self.connection.edges.iter().cloned().map(Into::into).collect()
         }
 
         /// Information about the page.
         #[must_use]
         pub fn page_info(&self) -> PageInfo {
+            let edges = &self.connection.edges;
             PageInfo {
-                info: self.0.page_info(),
-                start_cursor: self.0.edges.first().map(|e| e.cursor.into()),
-                end_cursor: self.0.edges.last().map(|e| e.cursor.into()),
+                info: self.connection.page_info(),
+                start_cursor: edges.first().map(|e| e.cursor.clone().into()),
+                end_cursor: edges.last().map(|e| e.cursor.clone().into()),
+                filter: self.filter.clone(),
             }
         }
     }
 
     /// Information about a [`Connection`] page.
-    #[derive(Clone, Copy, Debug)]
+    #[derive(Clone, Debug)]
     pub struct PageInfo {
         /// Underlying [`read::user::list::PageInfo`].
         info: read::user::list::PageInfo,
@@ -438,6 +667,9 @@ pub mod list {
 
         /// End cursor of the page.
         end_cursor: Option<Cursor>,
+
+        /// [`read::user::list::Filter`] the list is selected by.
+        filter: read::user::list::Filter,
     }
 
     /// Information about a `UserListConnection` page.
@@ -470,7 +702,7 @@ pub mod list {
         /// Total `User`s count.
         pub async fn total_count(&self, ctx: &Context) -> Result<i32, Error> {
             ctx.service()
-                .execute(query::users::TotalCount::by(()))
+                .execute(query::users::TotalCount::by(self.filter.clone()))
                 .await
                 .map_err(AsError::into_error)
                 .map_err(ctx.error())
@@ -9,6 +9,34 @@ pub struct Args {
     /// Path to the configuration file.
     #[arg(short, long, default_value = "config.toml")]
     pub config: String,
+
+    /// Watch the configuration file for changes (and `SIGHUP`), applying
+    /// reloadable settings without restarting the server.
+    #[arg(long)]
+    pub watch_config: bool,
+
+    /// Print applied and pending database migrations, and exit.
+    #[arg(long, conflicts_with = "migrate_rollback")]
+    pub migrate_status: bool,
+
+    /// Revert the specified number of the latest applied database
+    /// migrations, and exit.
+    #[arg(long, value_name = "N")]
+    pub migrate_rollback: Option<usize>,
+
+    /// Populate the database with demo data for local development, and exit.
+    #[arg(long, conflicts_with_all = ["migrate_status", "migrate_rollback"])]
+    pub seed: bool,
+
+    /// Export a consistent logical snapshot of all the entities as
+    /// newline-delimited JSON into the specified file (or the standard output,
+    /// if `-`), and exit.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["migrate_status", "migrate_rollback", "seed"],
+    )]
+    pub export: Option<String>,
 }
 
 impl Args {
@@ -1,6 +1,6 @@
 /// [`Context`]-related definitions.
 use std::{
-    future,
+    future::{self, Future},
     sync::atomic::{self, AtomicU16},
 };
 
@@ -16,13 +16,23 @@ use juniper::{
 };
 use service::{
     command::{self, Command as _},
-    domain::user::session,
+    domain::{self, user::session},
+    query, Query as _,
 };
 use tokio::sync::OnceCell;
 
 #[cfg(doc)]
 use crate::api::User;
-use crate::{api, define_error, AsError, Error, JuniperResponse, Service};
+use crate::{
+    api, define_error, error::REQUEST_ID, i18n::Locale, AsError, Error,
+    JuniperResponse, Service,
+};
+
+/// Name of the HTTP header carrying the request ID.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Name of the HTTP header selecting the [`api::Agency`] to operate on.
+pub const AGENCY_ID_HEADER: &str = "x-agency-id";
 
 /// Application context.
 #[derive(Debug)]
@@ -36,6 +46,14 @@ pub struct Context {
     /// Parts of the HTTP request.
     parts: http::request::Parts,
 
+    /// [`Locale`] of the [`Error`] messages, selected by the
+    /// `Accept-Language` header.
+    locale: Locale,
+
+    /// ID of the [`api::Agency`] to operate on, selected by the `X-Agency-Id`
+    /// header.
+    agency_id: api::agency::Id,
+
     /// Current [`Session`].
     current_session: OnceCell<Session>,
 
@@ -50,6 +68,52 @@ impl Context {
         &self.service
     }
 
+    /// Returns the [`Locale`] of the [`Error`] messages and formatted values
+    /// of this [`Context`].
+    #[must_use]
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Returns the ID of the [`api::Agency`] to operate on in this
+    /// [`Context`] (the `X-Agency-Id` header), defaulting to the
+    /// [`domain::agency::Id::DEFAULT`] one.
+    #[must_use]
+    pub fn agency_id(&self) -> api::agency::Id {
+        self.agency_id
+    }
+
+    /// Indicates whether the provided [`domain::contract::Employment`] is
+    /// made in the [`api::Agency`] of this [`Context`].
+    #[must_use]
+    pub fn is_agency_employment(
+        &self,
+        employment: &domain::contract::Employment,
+    ) -> bool {
+        employment.agency_id == self.agency_id.into()
+    }
+
+    /// Returns the ID of the HTTP request of this [`Context`] (the
+    /// `X-Request-Id` header), if any.
+    #[must_use]
+    pub fn request_id(&self) -> Option<&str> {
+        self.parts
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|h| h.to_str().ok())
+    }
+
+    /// Executes the provided [`Future`] in the scope of this [`Context`], so
+    /// the [`Error`]s produced by it are localized and carry the request ID.
+    pub(crate) async fn scope<F: Future>(&self, fut: F) -> F::Output {
+        REQUEST_ID
+            .scope(
+                self.request_id().map(ToOwned::to_owned),
+                self.locale.scope(fut),
+            )
+            .await
+    }
+
     /// Returns the error status code of this [`Context`].
     #[expect(clippy::missing_panics_doc, reason = "infallible")]
     #[must_use]
@@ -129,6 +193,29 @@ impl Context {
             .map_err(Clone::clone)
     }
 
+    /// Ensures that the current [`Session`] belongs to the platform operator
+    /// (administrator of the default [`api::Agency`]).
+    ///
+    /// # Errors
+    ///
+    /// Errors if:
+    /// - the current HTTP request is not authorized;
+    /// - the current [`Session`] doesn't belong to the platform operator.
+    pub async fn ensure_operator(&self) -> Result<(), Error> {
+        let my_id = self.current_session().await?.user_id;
+        let is_operator = self
+            .service()
+            .execute(query::agency::ById::by(domain::agency::Id::DEFAULT))
+            .await
+            .map_err(AsError::into_error)
+            .map_err(self.error())?
+            .is_some_and(|a| api::user::Id::from(a.admin_id) == my_id);
+        if !is_operator {
+            return Err(api::agency::AgencyError::NotAdmin.into());
+        }
+        Ok(())
+    }
+
     /// Applies the [`juniper::Variables`] provided by the client on GraphQL
     /// subscription initialization.
     ///
@@ -180,6 +267,7 @@ impl Context {
                     .await
                     .map(|s| Session {
                         user_id: s.user_id.into(),
+                        impersonated_by: s.impersonated_by.map(Into::into),
                         token,
                         expires_at: s.expires_at.coerce(),
                     })
@@ -223,12 +311,36 @@ where
                 }
             })?;
 
+        let agency_id = match parts.headers.get(AGENCY_ID_HEADER) {
+            Some(h) => h
+                .to_str()
+                .ok()
+                .and_then(|h| h.parse::<domain::agency::Id>().ok())
+                .ok_or_else(|| {
+                    let err = Error::from(api::agency::AgencyError::InvalidId);
+                    JuniperResponse {
+                        status_code: err.status_code,
+                        response: GraphQLBatchResponse::Single(
+                            GraphQLResponse::error(err.into_field_error()),
+                        ),
+                    }
+                })?,
+            None => domain::agency::Id::DEFAULT,
+        };
+
         Ok(Self {
             service,
             error_status_code: AtomicU16::new(
                 http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
             ),
             parts: parts.clone(),
+            locale: parts
+                .headers
+                .get(http::header::ACCEPT_LANGUAGE)
+                .and_then(|h| h.to_str().ok())
+                .and_then(Locale::from_accept_language)
+                .unwrap_or_default(),
+            agency_id: agency_id.into(),
             current_session: OnceCell::new(),
             auth_error: OnceCell::new(),
         })
@@ -241,6 +353,10 @@ pub struct Session {
     /// ID of the [`User`] associated with this [`Session`].
     pub user_id: api::user::Id,
 
+    /// ID of the admin [`User`] impersonating the [`Session::user_id`] one
+    /// via this [`Session`], if any.
+    pub impersonated_by: Option<api::user::Id>,
+
     /// Authentication token.
     pub token: session::Token,
 
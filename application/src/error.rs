@@ -9,6 +9,8 @@ use juniper::IntoFieldError;
 use service::infra::database;
 use tracerr::{Trace, Traced};
 
+use crate::i18n::Locale;
+
 /// Defines a new error type.
 #[expect(clippy::module_name_repetitions, reason = "more readable")]
 #[macro_export]
@@ -48,6 +50,7 @@ macro_rules! define_error {
                             code: $code,
                             status_code: ::http::StatusCode::$status_code,
                             message: $message.to_string(),
+                            details: Vec::new(),
                             backtrace: None,
                         },
                     )*
@@ -57,6 +60,12 @@ macro_rules! define_error {
     };
 }
 
+tokio::task_local! {
+    /// ID of the currently executed HTTP request, reported in the [`Error`]
+    /// extensions.
+    pub(crate) static REQUEST_ID: Option<String>;
+}
+
 /// GraphQL API [`Error`].
 #[derive(Clone, Debug, StdError)]
 pub struct Error {
@@ -72,6 +81,10 @@ pub struct Error {
 
     /// [`Error`] message.
     pub message: String,
+
+    /// Machine-readable details of this [`Error`] (e.g. codes of the violated
+    /// rules), reported in the `details` extension, if not empty.
+    pub details: Vec<String>,
 }
 
 impl Error {
@@ -82,6 +95,7 @@ impl Error {
             code: "INTERNAL_SERVER_ERROR",
             status_code: http::StatusCode::INTERNAL_SERVER_ERROR,
             message: msg.to_string(),
+            details: Vec::new(),
             backtrace: None,
         }
     }
@@ -94,6 +108,7 @@ impl fmt::Display for Error {
             status_code: _,
             backtrace,
             message,
+            details: _,
         } = self;
 
         write!(
@@ -111,7 +126,7 @@ where
     S: From<String>,
 {
     fn into_field_error(self) -> juniper::FieldError<S> {
-        let mut ext = juniper::Object::with_capacity(1);
+        let mut ext = juniper::Object::with_capacity(4);
         drop(
             ext.add_field("code", juniper::Value::scalar(self.code.to_owned())),
         );
@@ -127,7 +142,26 @@ where
                 ),
             ),
         );
-        juniper::FieldError::new(self.message, juniper::Value::object(ext))
+        if !self.details.is_empty() {
+            drop(
+                ext.add_field(
+                    "details",
+                    juniper::Value::list(
+                        self.details
+                            .iter()
+                            .map(|d| juniper::Value::scalar(d.clone()))
+                            .collect(),
+                    ),
+                ),
+            );
+        }
+        if let Some(id) = REQUEST_ID.try_with(Clone::clone).ok().flatten() {
+            drop(ext.add_field("requestId", juniper::Value::scalar(id)));
+        }
+        let message = Locale::current()
+            .translate(&self.message)
+            .map_or(self.message, ToOwned::to_owned);
+        juniper::FieldError::new(message, juniper::Value::object(ext))
     }
 }
 
@@ -173,6 +207,7 @@ impl AsError for TypedHeaderRejection {
             code: "BAD_REQUEST",
             status_code: http::StatusCode::BAD_REQUEST,
             message: self.to_string(),
+            details: Vec::new(),
             backtrace: None,
         })
     }
@@ -180,7 +215,11 @@ impl AsError for TypedHeaderRejection {
 
 impl AsError for database::Error {
     fn try_as_error(&self) -> Option<Error> {
-        None
+        if self.is_timeout() {
+            return Some(crate::RequestError::Timeout.into());
+        }
+        self.is_conflict()
+            .then(|| crate::RequestError::Conflict.into())
     }
 }
 
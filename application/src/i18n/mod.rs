@@ -0,0 +1,99 @@
+//! Internationalization of [`Error`] messages.
+//!
+//! [`Error`] codes are never translated, only their messages. Catalogs are
+//! keyed by the original English message, so a missing translation falls back
+//! to it.
+
+mod ru;
+
+use std::future::Future;
+
+#[cfg(doc)]
+use crate::Error;
+
+tokio::task_local! {
+    /// [`Locale`] of the currently executed request.
+    static LOCALE: Locale;
+}
+
+/// Locale of [`Error`] messages and formatted values.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Locale {
+    /// English.
+    #[default]
+    En,
+
+    /// Russian.
+    Ru,
+}
+
+impl Locale {
+    /// Selects the most preferred supported [`Locale`] from the provided
+    /// [`Accept-Language`] header value.
+    ///
+    /// [`Accept-Language`]: https://www.rfc-editor.org/rfc/rfc9110#name-accept-language
+    #[must_use]
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        header
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let locale = Self::from_tag(parts.next()?.trim())?;
+                let weight = match parts
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                {
+                    Some(q) => q.parse::<f32>().ok()?,
+                    None => 1.0,
+                };
+                (weight > 0.0).then_some((locale, weight))
+            })
+            .reduce(|best, l| if l.1 > best.1 { l } else { best })
+            .map(|(locale, _)| locale)
+    }
+
+    /// Returns the [`Locale`] of the currently executed request.
+    ///
+    /// Defaults to [`Locale::En`] outside of [`Locale::scope()`].
+    #[must_use]
+    pub fn current() -> Self {
+        LOCALE.try_with(|l| *l).unwrap_or_default()
+    }
+
+    /// Executes the provided [`Future`] with this [`Locale`] being the
+    /// [`Locale::current()`] one.
+    pub async fn scope<F: Future>(self, fut: F) -> F::Output {
+        LOCALE.scope(self, fut).await
+    }
+
+    /// Translates the provided English `message` into this [`Locale`].
+    ///
+    /// Returns [`None`] if there is no translation.
+    #[must_use]
+    pub fn translate(self, message: &str) -> Option<&'static str> {
+        match self {
+            Self::En => None,
+            Self::Ru => ru::translate(message),
+        }
+    }
+
+    /// Parses a [`Locale`] from the provided language tag (e.g. `en-US`).
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split('-').next()?;
+        if primary.eq_ignore_ascii_case("en") {
+            Some(Self::En)
+        } else if primary.eq_ignore_ascii_case("ru") {
+            Some(Self::Ru)
+        } else {
+            None
+        }
+    }
+}
+
+impl From<Locale> for common::money::Locale {
+    fn from(locale: Locale) -> Self {
+        match locale {
+            Locale::En => Self::En,
+            Locale::Ru => Self::Ru,
+        }
+    }
+}
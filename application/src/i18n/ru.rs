@@ -0,0 +1,325 @@
+//! Russian [`Error`] messages catalog.
+//!
+//! [`Error`]: crate::Error
+
+/// Translates the provided English `message` into Russian.
+#[expect(
+    clippy::match_same_arms,
+    reason = "different messages may have the same translation"
+)]
+#[expect(clippy::too_many_lines, reason = "catalog of all the messages")]
+pub(super) fn translate(message: &str) -> Option<&'static str> {
+    Some(match message {
+        "Ambiguous pagination arguments" => {
+            "Неоднозначные аргументы пагинации"
+        }
+        "Archived `Contract` with the provided ID does not exist" => {
+            "Архивированный `Contract` с указанным ID не существует"
+        }
+        "At least one item must be listed" => {
+            "Необходимо указать хотя бы один предмет"
+        }
+        "Authenticated `User` is not manager of the `Realty`" => {
+            "Аутентифицированный `User` не является менеджером `Realty`"
+        }
+        "Authenticated `User` must administrate the `Agency`" => {
+            "Аутентифицированный `User` должен администрировать `Agency`"
+        }
+        "Authenticated `User` must be an employer" => {
+            "Аутентифицированный `User` должен быть сотрудником"
+        }
+        "Authorization required" => "Требуется авторизация",
+        "Booked period must span at least one night in the future" => {
+            "Бронируемый период должен охватывать хотя бы одну ночь в будущем"
+        }
+        "CSV of `User`s is malformed" => "CSV `User`ов имеет неверный формат",
+        "Charge amount must be in the currency of the rent price" => {
+            "Сумма начисления должна быть в валюте арендной платы"
+        }
+        "Charge amount must be positive" => {
+            "Сумма начисления должна быть положительной"
+        }
+        "Contract duration must be positive" => {
+            "Срок действия договора должен быть положительным"
+        }
+        "Counter-offered price must be in the currency of the countered \
+         one" => "Встречная цена должна быть в валюте исходного `Offer`",
+        "Deleted `Realty` with the provided ID does not exist" => {
+            "Удалённый `Realty` с указанным ID не существует"
+        }
+        "Deleted `User` with the provided ID does not exist" => {
+            "Удалённый `User` с указанным ID не существует"
+        }
+        "Down payment must be non-negative, in the currency of the price \
+         and not exceed it" => "Первоначальный взнос должен быть \
+                                неотрицательным, в валюте цены и не \
+                                превышать её",
+        "Either `Contract` name and description or `ContractTemplate` must \
+         be provided" => {
+            "Необходимо указать либо название и описание `Contract`, либо \
+             `ContractTemplate`"
+        }
+        "Either `UserEmail` or `UserPhone` must be provided" => {
+            "Необходимо указать `UserEmail` или `UserPhone`"
+        }
+        "Employer cannot submit `Offer`s on the `Realty` managed by \
+         themselves" => "Сотрудник не может делать `Offer` на `Realty` \
+                         под своим управлением",
+        "Entity was concurrently modified, retry the operation" => {
+            "Сущность была одновременно изменена, повторите операцию"
+        }
+        "Exactly one of `id` or `number` must be provided" => {
+            "Необходимо указать ровно одно из `id` или `number`"
+        }
+        "Exactly one of `lateFeePerDay` or `lateFeePercentPerDay` must be \
+         provided" => "Необходимо указать ровно одно из `lateFeePerDay` \
+                       или `lateFeePercentPerDay`",
+        "Exactly one of `login` or `email` must be provided" => {
+            "Необходимо указать ровно одно из `login` или `email`"
+        }
+        "Interval and timeout must be positive" => {
+            "Интервал и таймаут должны быть положительными"
+        }
+        "Invalid pagination arguments" => {
+            "Некорректные аргументы пагинации"
+        }
+        "Invalid subscription authorization variables" => {
+            "Некорректные переменные авторизации подписки"
+        }
+        "Item with the same room and name is listed more than once" => {
+            "Предмет с той же комнатой и названием указан более одного раза"
+        }
+        "Journal period must be a valid RFC 3339 range" => {
+            "Период журнала должен быть корректным диапазоном RFC 3339"
+        }
+        "Late fee amounts must be positive and in the currency of the \
+         price" => "Суммы пени должны быть положительными и в валюте цены",
+        "Manager `User` with the provided ID is not employed" => {
+            "`User`-менеджер с указанным ID не трудоустроен"
+        }
+        "Move-in `RentInventory` must be recorded before the move-out \
+         one" => "`RentInventory` при заселении должен быть записан до \
+                  `RentInventory` при выселении",
+        "Nightly rate must be positive" => {
+            "Ночная ставка должна быть положительной"
+        }
+        "Offered price must be in the currency of the expected one" => {
+            "Предлагаемая цена должна быть в валюте ожидаемой цены"
+        }
+        "Operation execution timed out" => {
+            "Превышено время выполнения операции"
+        }
+        "Pagination cursor doesn't match the requested order" => {
+            "Курсор пагинации не соответствует запрошенному порядку"
+        }
+        "Paid amount must be in the currency of the rent price" => {
+            "Оплаченная сумма должна быть в валюте арендной платы"
+        }
+        "Paid amount must be positive" => {
+            "Оплаченная сумма должна быть положительной"
+        }
+        "Placement of `Contract` with the provided ID is not supported" => {
+            "Размещение `Contract` с указанным ID не поддерживается"
+        }
+        "Price must not be negative" => "Цена не должна быть отрицательной",
+        "Provided `old_password` does not match the current `User` \
+         password" => "Указанный `old_password` не совпадает с текущим \
+                       паролем `User`",
+        "Provided credentials does not match any `User`" => {
+            "Указанные учётные данные не соответствуют ни одному `User`"
+        }
+        "Seasonal rates must be in the currency of the nightly rate" => {
+            "Сезонные ставки должны быть в валюте ночной ставки"
+        }
+        "Shares of `ContractCommissionSplit`s must sum to 100%" => {
+            "Доли `ContractCommissionSplit` должны в сумме составлять 100%"
+        }
+        "Shares of `RealtyOwner`s must sum to 100%" => {
+            "Доли `RealtyOwner` должны в сумме составлять 100%"
+        }
+        "Too many operations in the batch request" => {
+            "Слишком много операций в пакетном запросе"
+        }
+        "`Agency` administrator cannot be impersonated" => {
+            "Нельзя действовать от имени администратора `Agency`"
+        }
+        "`Agency` does not exist" => "`Agency` не существует",
+        "`Branch` does not exist" => "`Branch` не существует",
+        "`ContractTemplate` renders invalid `Contract` name or \
+         description" => {
+            "`ContractTemplate` формирует некорректное название или описание \
+             `Contract`"
+        }
+        "`ContractTemplate` with the provided ID does not exist" => {
+            "`ContractTemplate` с указанным ID не существует"
+        }
+        "`Contract` has no `InsurancePolicy` attached" => {
+            "К `Contract` не прикреплён `InsurancePolicy`"
+        }
+        "`Contract` is already signed by the `User`" => {
+            "`Contract` уже подписан `User`"
+        }
+        "`Contract` must be approved by another employer" => {
+            "`Contract` должен быть одобрен другим сотрудником"
+        }
+        "`Contract` with the provided ID is already placed" => {
+            "`Contract` с указанным ID уже размещён"
+        }
+        "`Contract` with the provided ID is not completed" => {
+            "`Contract` с указанным ID не завершён"
+        }
+        "`Contract` with the provided ID is not exists" => {
+            "`Contract` с указанным ID не существует"
+        }
+        "`Contract` with the provided ID is not exists or terminated \
+         already" => "`Contract` с указанным ID не существует или уже \
+                      расторгнут",
+        "`Contract` with the provided ID is not pending approval" => {
+            "`Contract` с указанным ID не ожидает одобрения"
+        }
+        "`Contract` with the provided ID is not placed" => {
+            "`Contract` с указанным ID не размещён"
+        }
+        "`Contract` with the provided ID is reviewed already" => {
+            "На `Contract` с указанным ID уже оставлен `Review`"
+        }
+        "`Contract` with the provided ID is still active" => {
+            "`Contract` с указанным ID всё ещё действует"
+        }
+        "`Contract` with the specified ID does not exist" => {
+            "`Contract` с указанным ID не существует"
+        }
+        "`InsurancePolicy` coverage must be positive" => {
+            "Страховое покрытие `InsurancePolicy` должно быть положительным"
+        }
+        "`InsurancePolicy` must expire in the future" => {
+            "Срок действия `InsurancePolicy` должен истекать в будущем"
+        }
+        "`Lead` cannot be moved back to a previous stage" => {
+            "`Lead` не может быть возвращён на предыдущий этап"
+        }
+        "`Lead` does not exist" => "`Lead` не существует",
+        "`MessageTemplate` with the provided name does not exist" => {
+            "`MessageTemplate` с указанным названием не существует"
+        }
+        "`Mortgage` amounts are too large to be calculated" => {
+            "Суммы `Mortgage` слишком велики для расчёта"
+        }
+        "`Mortgage` term must be from 1 to 600 months" => {
+            "Срок `Mortgage` должен быть от 1 до 600 месяцев"
+        }
+        "`Offer` does not exist" => "`Offer` не существует",
+        "`Offer` is accepted, declined or countered already" => {
+            "`Offer` уже принят, отклонён или встречно предложен"
+        }
+        "`Offer` is not accepted or concluded already" => {
+            "`Offer` не принят или уже заключён"
+        }
+        "`Placement` with the specified ID does not exist" => {
+            "`Placement` с указанным ID не существует"
+        }
+        "`RealtyFloorPlan` floor exceeds the number of floors of the \
+         `Realty`" => "Этаж `RealtyFloorPlan` превышает количество этажей \
+                       `Realty`",
+        "`RealtyFloorPlan` labels must be unique per floor" => {
+            "Метки `RealtyFloorPlan` должны быть уникальными в пределах этажа"
+        }
+        "`RealtyKey` is already checked out" => "`RealtyKey` уже выдан",
+        "`RealtyKey` is not checked out" => "`RealtyKey` не выдан",
+        "`RealtyKey` return must be due in the future" => {
+            "Срок возврата `RealtyKey` должен быть в будущем"
+        }
+        "`RealtyKey` with the provided label already exists" => {
+            "`RealtyKey` с указанной меткой уже существует"
+        }
+        "`RealtyKey` with the specified ID does not exist" => {
+            "`RealtyKey` с указанным ID не существует"
+        }
+        "`RealtyMediaLink`s must be unique and not exceed the limit" => {
+            "`RealtyMediaLink` должны быть уникальными и не превышать лимит"
+        }
+        "`RealtySeasonalRate`s must be positive and not overlap" => {
+            "`RealtySeasonalRate` должны быть положительными и не пересекаться"
+        }
+        "`Realty` is already rented for the requested period" => {
+            "`Realty` уже арендована на запрошенный период"
+        }
+        "`Realty` with the provided ID is already managed for rent" => {
+            "`Realty` с указанным ID уже находится в управлении для аренды"
+        }
+        "`Realty` with the provided ID is already managed for sale" => {
+            "`Realty` с указанным ID уже находится в управлении для продажи"
+        }
+        "`Realty` with the provided ID is managed rent" => {
+            "`Realty` с указанным ID находится в управлении для аренды"
+        }
+        "`Realty` with the provided ID is not exists" => {
+            "`Realty` с указанным ID не существует"
+        }
+        "`Realty` with the provided ID is not managed for rent" => {
+            "`Realty` с указанным ID не находится в управлении для аренды"
+        }
+        "`Realty` with the provided ID is not managed for sale" => {
+            "`Realty` с указанным ID не находится в управлении для продажи"
+        }
+        "`Realty` with the provided ID is rented" => {
+            "`Realty` с указанным ID уже арендована"
+        }
+        "`Realty` with the specified ID does not exist" => {
+            "`Realty` с указанным ID не существует"
+        }
+        "`RentCharge` does not exist" => "`RentCharge` не существует",
+        "`RentInventory` is already recorded at the provided stage" => {
+            "`RentInventory` уже записан на указанном этапе"
+        }
+        "`Review` does not exist" => "`Review` не существует",
+        "`Review` rating must be from 1 to 5" => {
+            "Оценка `Review` должна быть от 1 до 5"
+        }
+        "`SavedSearch` does not exist" => "`SavedSearch` не существует",
+        "`User` cannot impersonate themselves" => {
+            "`User` не может действовать от своего же имени"
+        }
+        "`User` invite has expired" => "Срок действия приглашения `User` истёк",
+        "`User` invite with the provided token does not exist" => {
+            "Приглашение `User` с указанным токеном не существует"
+        }
+        "`User` is already a party of the `Contract` in this role" => {
+            "`User` уже является стороной `Contract` в этой роли"
+        }
+        "`User` is not a party of the `Contract`" => {
+            "`User` не является стороной `Contract`"
+        }
+        "`User` with the provided ID is already employed" => {
+            "`User` с указанным ID уже трудоустроен"
+        }
+        "`User` with the provided ID is not employed" => {
+            "`User` с указанным ID не трудоустроен"
+        }
+        "`User` with the provided ID is not exists" => {
+            "`User` с указанным ID не существует"
+        }
+        "`User` with the specified ID does not exist" => {
+            "`User` с указанным ID не существует"
+        }
+        "`UserEmail` domain does not accept mail" => {
+            "Домен `UserEmail` не принимает почту"
+        }
+        "`UserLogin` is occupied by another `User`" => {
+            "`UserLogin` занят другим `User`"
+        }
+        "`UserPassword` violates the password policy" => {
+            "`UserPassword` нарушает политику паролей"
+        }
+        "`X-Agency-Id` header must be a valid `AgencyId`" => {
+            "Заголовок `X-Agency-Id` должен содержать корректный `AgencyId`"
+        }
+        "`minPrice` and `maxPrice` must have the same currency" => {
+            "`minPrice` и `maxPrice` должны быть в одной валюте"
+        }
+        "`minPrice` must not exceed `maxPrice`" => {
+            "`minPrice` не должна превышать `maxPrice`"
+        }
+        _ => return None,
+    })
+}
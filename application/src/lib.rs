@@ -32,28 +32,44 @@ pub mod args;
 pub mod config;
 mod context;
 pub mod error;
+pub mod i18n;
 
-use std::sync::Arc;
+use std::{fmt::Write as _, sync::Arc};
 
 use axum::{
-    extract::WebSocketUpgrade,
+    extract::{Path, Query, WebSocketUpgrade},
     response::{IntoResponse, Response},
-    Extension, Json,
+    Extension, Form, Json,
 };
+use common::DateTime;
 use derive_more::Debug;
-use juniper::{http::GraphQLBatchResponse, DefaultScalarValue, ScalarValue};
+use futures::{future, FutureExt as _};
+use juniper::{
+    http::{GraphQLBatchRequest, GraphQLBatchResponse, GraphQLResponse},
+    DefaultScalarValue, IntoFieldError as _, ScalarValue,
+};
 use juniper_axum::{extract::JuniperRequest, subscriptions};
 use juniper_graphql_ws::ConnectionConfig;
+use serde::{Deserialize, Serialize};
+use service::{
+    command,
+    domain::{branch, ical, journal, user},
+    infra::{postgres::StatementCacheStats, sms, storage},
+    query, read, task, Command as _, Query as _,
+};
+use tokio::time;
+use tracing as log;
 // Used in binary.
 use axum_client_ip as _;
+use axum_server as _;
 use refinery as _;
+use regex as _;
 use tower_http as _;
-use tracing_subscriber as _;
 
 pub use self::{
     args::Args,
     config::Config,
-    context::{Context, Session},
+    context::{Context, Session, AGENCY_ID_HEADER, REQUEST_ID_HEADER},
     error::{AsError, Error},
 };
 
@@ -62,6 +78,11 @@ pub use self::{
 /// [`Service`]: service::Service
 pub type Service = service::Service<service::infra::Postgres>;
 
+/// [`Future`] resolving once the application shutdown is initiated.
+///
+/// [`Future`]: std::future::Future
+pub type Shutdown = future::Shared<future::BoxFuture<'static, ()>>;
+
 /// [`juniper`] GraphQL response.
 #[derive(Debug)]
 pub struct JuniperResponse<S = DefaultScalarValue>
@@ -97,13 +118,44 @@ where
 /// GraphQL API handler.
 pub async fn graphql(
     Extension(schema): Extension<Arc<api::Schema>>,
+    Extension(limits): Extension<config::Limits>,
     context: Context,
     JuniperRequest(gql_request): JuniperRequest,
 ) -> JuniperResponse {
-    JuniperResponse {
-        status_code: context.error_status_code(),
-        response: gql_request.execute(&*schema, &context).await,
-    }
+    context
+        .scope(async {
+            if let GraphQLBatchRequest::Batch(ops) = &gql_request {
+                if ops.len() > limits.max_batch_size {
+                    let err = Error::from(RequestError::BatchTooLarge);
+                    return JuniperResponse {
+                        status_code: err.status_code,
+                        response: GraphQLBatchResponse::Single(
+                            GraphQLResponse::error(err.into_field_error()),
+                        ),
+                    };
+                }
+            }
+
+            let Ok(response) = time::timeout(
+                limits.execution_timeout,
+                gql_request.execute(&*schema, &context),
+            )
+            .await
+            else {
+                let err = Error::from(RequestError::Timeout);
+                return JuniperResponse {
+                    status_code: err.status_code,
+                    response: GraphQLBatchResponse::Single(
+                        GraphQLResponse::error(err.into_field_error()),
+                    ),
+                };
+            };
+            JuniperResponse {
+                status_code: context.error_status_code(),
+                response,
+            }
+        })
+        .await
 }
 
 /// GraphQL subscriptions handler.
@@ -113,20 +165,792 @@ pub async fn graphql(
 )]
 pub async fn subscriptions(
     Extension(schema): Extension<Arc<api::Schema>>,
+    Extension(config): Extension<config::Subscriptions>,
+    Extension(shutdown): Extension<Shutdown>,
     mut context: Context,
     ws: WebSocketUpgrade,
 ) -> Response {
     ws.protocols(["graphql-transport-ws", "graphql-ws"])
-        .max_frame_size(1024)
-        .max_message_size(1024)
-        .write_buffer_size(512)
-        .max_write_buffer_size(1024)
+        .max_frame_size(config.max_frame_size)
+        .max_message_size(config.max_message_size)
+        .write_buffer_size(config.write_buffer_size)
+        .max_write_buffer_size(config.max_write_buffer_size)
         .on_upgrade(move |socket| {
-            subscriptions::serve_ws(socket, schema, move |vars| async move {
-                context.apply_subscription_variables(&vars).map(|()| {
-                    ConnectionConfig::new(context)
-                        .with_max_in_flight_operations(10)
+            let (locale, request_id) =
+                (context.locale(), context.request_id().map(ToOwned::to_owned));
+            let serve = subscriptions::serve_ws(
+                socket,
+                schema,
+                move |vars| async move {
+                    let max_in_flight = config.max_in_flight_operations;
+                    context.apply_subscription_variables(&vars).map(|()| {
+                        ConnectionConfig::new(context)
+                            .with_max_in_flight_operations(max_in_flight)
+                    })
+                },
+            );
+            // Subscriptions don't complete on their own, so are closed once
+            // the shutdown is initiated, letting clients reconnect to another
+            // instance instead of being dropped on the process exit.
+            let serve = future::select(serve.boxed(), shutdown).map(|r| {
+                if let future::Either::Right(_) = r {
+                    log::debug!("closing WebSocket session on shutdown");
+                }
+            });
+            error::REQUEST_ID.scope(request_id, locale.scope(serve))
+        })
+}
+
+/// Metrics handler, exposing them in the [Prometheus text format][1].
+///
+/// # Errors
+///
+/// With `500 Internal Server Error` if failed to collect the metrics.
+///
+/// [1]: https://prometheus.io/docs/instrumenting/exposition_formats
+pub async fn metrics(
+    Extension(service): Extension<Service>,
+) -> Result<impl IntoResponse, http::StatusCode> {
+    let pool = service
+        .execute(query::infra::PoolStats::by(()))
+        .await
+        .map_err(|e| {
+            log::error!("failed to query `PoolStats`: {e}");
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    let statements = StatementCacheStats::collected();
+    let status = *service.database_status().borrow();
+    let tasks = service
+        .execute(query::task::Status)
+        .await
+        .unwrap_or_else(|e| match e {});
+    let retention = service.retention_stats().borrow().clone();
+
+    let mut body = String::new();
+    for (name, kind, help, value) in [
+        (
+            "db_pool_max_size",
+            "gauge",
+            "Maximum number of connections in the database pool.",
+            pool.max_size as u64,
+        ),
+        (
+            "db_pool_size",
+            "gauge",
+            "Current number of connections in the database pool.",
+            pool.size as u64,
+        ),
+        (
+            "db_pool_available",
+            "gauge",
+            "Number of idle connections in the database pool.",
+            pool.available as u64,
+        ),
+        (
+            "db_pool_waiting",
+            "gauge",
+            "Number of callers waiting for a database connection.",
+            pool.waiting as u64,
+        ),
+        (
+            "db_pool_timeouts_total",
+            "counter",
+            "Number of timed out waits for a database connection.",
+            pool.timeouts,
+        ),
+        (
+            "db_statement_cache_hits_total",
+            "counter",
+            "Number of prepared statements reused from the cache.",
+            statements.hits,
+        ),
+        (
+            "db_statement_cache_misses_total",
+            "counter",
+            "Number of statements prepared due to a cache miss.",
+            statements.misses,
+        ),
+        (
+            "db_reachable",
+            "gauge",
+            "Whether the database responded to the last health check.",
+            u64::from(status.reachable),
+        ),
+        (
+            "db_ping_latency_microseconds",
+            "gauge",
+            "Round-trip latency of the last successful database health check.",
+            status.latency.map_or(0, |l| {
+                u64::try_from(l.as_micros()).unwrap_or(u64::MAX)
+            }),
+        ),
+    ] {
+        _ = writeln!(
+            body,
+            "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}",
+        );
+    }
+    let task_metrics: [(&str, &str, &str, fn(&task::Status) -> u64); 4] = [
+        (
+            "task_runs_total",
+            "counter",
+            "Number of the background task runs.",
+            |s| s.runs,
+        ),
+        (
+            "task_failures_total",
+            "counter",
+            "Number of the failed background task runs.",
+            |s| s.failures,
+        ),
+        (
+            "task_processed_total",
+            "counter",
+            "Number of the items processed by the background task runs.",
+            |s| s.processed,
+        ),
+        (
+            "task_last_run_duration_milliseconds",
+            "gauge",
+            "Duration of the last background task run.",
+            |s| {
+                s.last_duration.map_or(0, |d| {
+                    u64::try_from(d.as_millis()).unwrap_or(u64::MAX)
                 })
-            })
+            },
+        ),
+    ];
+    for (name, kind, help, value) in task_metrics {
+        _ = writeln!(body, "# HELP {name} {help}\n# TYPE {name} {kind}");
+        for (task, status) in &tasks {
+            _ = writeln!(body, "{name}{{task=\"{task}\"}} {}", value(status));
+        }
+    }
+    for (name, kind, help, purged) in [
+        (
+            "retention_purged_total",
+            "counter",
+            "Number of the entities purged by the retention policies.",
+            &retention.total,
+        ),
+        (
+            "retention_last_purged",
+            "gauge",
+            "Number of the entities purged by the last retention run.",
+            &retention.last,
+        ),
+    ] {
+        _ = writeln!(body, "# HELP {name} {help}\n# TYPE {name} {kind}");
+        for (policy, count) in purged {
+            _ = writeln!(body, "{name}{{policy=\"{policy}\"}} {count}");
+        }
+    }
+
+    let name = "graphql_deprecated_field_usages_total";
+    _ = writeln!(
+        body,
+        "# HELP {name} Number of the deprecated GraphQL fields resolutions.\n\
+         # TYPE {name} counter",
+    );
+    for api::deprecation::Usage {
+        deprecation,
+        client,
+        count,
+    } in api::deprecation::Usage::collected()
+    {
+        _ = writeln!(
+            body,
+            "{name}{{field=\"{}\",client=\"{client}\",sunset=\"{}\"}} \
+             {count}",
+            deprecation.field, deprecation.sunset,
+        );
+    }
+
+    Ok((
+        [(http::header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        body,
+    ))
+}
+
+/// Query parameters of the [`export_journal()`] handler.
+#[derive(Clone, Debug, Deserialize)]
+pub struct JournalPeriod {
+    /// [RFC 3339] date and time (inclusive) the journal starts at.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub since: String,
+
+    /// [RFC 3339] date and time (exclusive) the journal ends at.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub until: String,
+}
+
+/// Accounting journal export handler, rendering the rent payments received by
+/// the current `Agency` within the requested [`JournalPeriod`] as CSV (see
+/// [`journal::to_csv()`]).
+///
+/// # Errors
+///
+/// If the current session is not authenticated, its `User` is not an employer
+/// of the current `Agency`, or the requested [`JournalPeriod`] is invalid.
+pub async fn export_journal(
+    context: Context,
+    Query(period): Query<JournalPeriod>,
+) -> Result<impl IntoResponse, JuniperResponse> {
+    context
+        .scope(async {
+            let entries = async {
+                let my_id = context.current_session().await?.user_id;
+                let is_employed = context
+                    .service()
+                    .execute(query::contract::Employment::by(my_id.into()))
+                    .await
+                    .map_err(AsError::into_error)?
+                    .is_some_and(|e| context.is_agency_employment(&e.0));
+                if !is_employed {
+                    return Err(api::PrivilegeError::Employer.into());
+                }
+
+                let (Ok(since), Ok(until)) = (
+                    DateTime::from_rfc3339(&period.since),
+                    DateTime::from_rfc3339(&period.until),
+                ) else {
+                    return Err(RequestError::InvalidPeriod.into());
+                };
+                if since >= until {
+                    return Err(RequestError::InvalidPeriod.into());
+                }
+
+                context
+                    .service()
+                    .execute(query::report::Journal {
+                        agency_id: context.agency_id().into(),
+                        since,
+                        until,
+                    })
+                    .await
+                    .map_err(AsError::into_error)
+            }
+            .await
+            .map_err(|e: Error| JuniperResponse {
+                status_code: e.status_code,
+                response: GraphQLBatchResponse::Single(GraphQLResponse::error(
+                    e.into_field_error(),
+                )),
+            })?;
+
+            Ok((
+                [
+                    (http::header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                    (
+                        http::header::CONTENT_DISPOSITION,
+                        "attachment; filename=\"journal.csv\"",
+                    ),
+                ],
+                journal::to_csv(&entries),
+            ))
         })
+        .await
+}
+
+/// Query parameters of the [`export_users()`] handler.
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub struct UsersFilter {
+    /// ID of the `Branch` to export the employees of only.
+    #[serde(rename = "branchId")]
+    pub branch_id: Option<branch::Id>,
+}
+
+/// `User`s export handler, rendering the non-deleted `User`s employed by (or
+/// invited into) the current `Agency`, optionally filtered by the
+/// [`UsersFilter`], as CSV (see [`user::csv::render()`]), which is importable
+/// back by the `importUsers` GraphQL mutation.
+///
+/// # Errors
+///
+/// If the current session is not authenticated, its `User` is not an employer
+/// of the current `Agency`, or the requested `Branch` doesn't belong to the
+/// current `Agency`.
+pub async fn export_users(
+    context: Context,
+    Query(filter): Query<UsersFilter>,
+) -> Result<impl IntoResponse, JuniperResponse> {
+    context
+        .scope(async {
+            let users = async {
+                let my_id = context.current_session().await?.user_id;
+                let is_employed = context
+                    .service()
+                    .execute(query::contract::Employment::by(my_id.into()))
+                    .await
+                    .map_err(AsError::into_error)?
+                    .is_some_and(|e| context.is_agency_employment(&e.0));
+                if !is_employed {
+                    return Err(api::PrivilegeError::Employer.into());
+                }
+
+                if let Some(branch_id) = filter.branch_id {
+                    // `Branch`es of other `Agency`s are not visible.
+                    context
+                        .service()
+                        .execute(query::branch::ById::by(branch_id))
+                        .await
+                        .map_err(AsError::into_error)?
+                        .filter(|b| b.agency_id == context.agency_id().into())
+                        .ok_or_else(|| {
+                            api::branch::BranchError::NotExists.into()
+                        })
+                        .map(drop)?;
+                }
+
+                context
+                    .service()
+                    .execute(query::users::Export::by(read::user::Export {
+                        agency_id: context.agency_id().into(),
+                        branch_id: filter.branch_id,
+                    }))
+                    .await
+                    .map_err(AsError::into_error)
+            }
+            .await
+            .map_err(|e: Error| JuniperResponse {
+                status_code: e.status_code,
+                response: GraphQLBatchResponse::Single(GraphQLResponse::error(
+                    e.into_field_error(),
+                )),
+            })?;
+
+            Ok((
+                [
+                    (http::header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                    (
+                        http::header::CONTENT_DISPOSITION,
+                        "attachment; filename=\"users.csv\"",
+                    ),
+                ],
+                user::csv::render(&users),
+            ))
+        })
+        .await
+}
+
+/// Form of the [`sms_status()`] handler, reported by an SMS provider in the
+/// [Twilio status callback][1] format.
+///
+/// [1]: https://www.twilio.com/docs/messaging/guides/track-outbound-message-status
+#[derive(Clone, Debug, Deserialize)]
+pub struct SmsStatus {
+    /// ID of the message, assigned by the SMS provider.
+    #[serde(rename = "MessageSid")]
+    pub message_sid: String,
+
+    /// Delivery status of the message.
+    #[serde(rename = "MessageStatus")]
+    pub message_status: String,
+}
+
+/// SMS delivery status callback handler, recording the [`SmsStatus`]
+/// reported by an SMS provider.
+///
+/// Unknown statuses are acknowledged without recording, so the provider
+/// doesn't retry them.
+pub async fn sms_status(
+    Extension(service): Extension<Service>,
+    Form(report): Form<SmsStatus>,
+) -> http::StatusCode {
+    let Some(status) = sms::parse_status(&report.message_status) else {
+        log::warn!(
+            "unknown SMS delivery status `{}` of `{}` message",
+            report.message_status,
+            report.message_sid,
+        );
+        return http::StatusCode::NO_CONTENT;
+    };
+
+    let res = service
+        .execute(command::RecordSmsStatus {
+            provider_id: report.message_sid.into(),
+            status,
+        })
+        .await;
+    match res {
+        Ok(_) => http::StatusCode::NO_CONTENT,
+        Err(e) => match e.as_ref() {
+            command::record_sms_status::ExecutionError::DeliveryNotExists(
+                _,
+            ) => http::StatusCode::NOT_FOUND,
+            command::record_sms_status::ExecutionError::Db(_) => {
+                log::error!("failed to record SMS delivery status: {e}");
+                http::StatusCode::INTERNAL_SERVER_ERROR
+            }
+        },
+    }
+}
+
+/// Query of the [`file()`] handler, produced by the [`storage::Signer`].
+#[derive(Clone, Debug, Deserialize)]
+pub struct SignedFile {
+    /// Unix timestamp the signed URL expires at.
+    pub expires: i64,
+
+    /// Hex-encoded signature of the URL.
+    pub signature: String,
+}
+
+/// Signed file download handler, serving the file from the configured
+/// [`Storage`] by the URL signed with the configured [`storage::Signer`].
+///
+/// Intended for the local [`Storage`] mostly, as the files in the remote ones
+/// are better served by an edge proxy verifying the [`SignedFile`] itself.
+///
+/// [`Storage`]: service::infra::Storage
+pub async fn file(
+    Extension(service): Extension<Service>,
+    Path(path): Path<String>,
+    Query(signed): Query<SignedFile>,
+) -> Response {
+    let config = service.config();
+    let (Some(storage), Some(signer)) =
+        (config.storage.as_deref(), config.url_signer.as_deref())
+    else {
+        return http::StatusCode::NOT_FOUND.into_response();
+    };
+    let key = match signer.verify(&path, signed.expires, &signed.signature) {
+        Ok(key) => key,
+        Err(e) => {
+            log::debug!("rejected signed URL of `{path}` file: {e}");
+            return http::StatusCode::FORBIDDEN.into_response();
+        }
+    };
+
+    match storage.get(&key).await {
+        Ok(Some(data)) => (
+            [
+                (http::header::CONTENT_TYPE, "application/octet-stream"),
+                (http::header::CONTENT_DISPOSITION, "attachment"),
+            ],
+            data,
+        )
+            .into_response(),
+        Ok(None) => http::StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            log::error!("failed to read `{key}` file: {e}");
+            http::StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Query of the [`calendar()`] handler.
+#[derive(Clone, Debug, Deserialize)]
+pub struct CalendarFeed {
+    /// Secret token of the feed, issued by the `issueCalendarFeed` mutation.
+    pub token: String,
+}
+
+impl CalendarFeed {
+    /// Period the already expired contracts are kept in a feed for.
+    pub const HISTORY: time::Duration = time::Duration::from_secs(30 * 86_400);
+
+    /// Maximum number of the events in a feed.
+    pub const MAX_EVENTS: usize = 500;
+}
+
+/// Personal iCalendar feed handler, rendering the expiration dates of the
+/// active contracts the `User` of the [`CalendarFeed`] takes part in (see
+/// [`ical::to_ics()`]), so they may be subscribed to from calendar apps.
+///
+/// Contracts expired more than [`CalendarFeed::HISTORY`] ago are omitted,
+/// and at most [`CalendarFeed::MAX_EVENTS`] ones are rendered.
+pub async fn calendar(
+    Extension(service): Extension<Service>,
+    Query(feed): Query<CalendarFeed>,
+) -> Response {
+    let Some(token) = ical::Token::new(feed.token) else {
+        return http::StatusCode::NOT_FOUND.into_response();
+    };
+    let feed = match service
+        .execute(query::user::CalendarFeed::by(token.hash()))
+        .await
+    {
+        Ok(Some(feed)) => feed,
+        Ok(None) => return http::StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            log::error!("failed to select calendar feed: {e}");
+            return http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let now = DateTime::now();
+    let contracts = match service
+        .execute(query::contracts::Expiring::by(
+            read::contract::Expiring {
+                user_id: feed.user_id,
+                since: now - CalendarFeed::HISTORY,
+                limit: CalendarFeed::MAX_EVENTS,
+            },
+        ))
+        .await
+    {
+        Ok(contracts) => contracts,
+        Err(e) => {
+            log::error!(
+                "failed to select expiring contracts of `User(id: {})`: {e}",
+                feed.user_id,
+            );
+            return http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let events = contracts
+        .iter()
+        .filter_map(ical::Event::contract_expiry)
+        .collect::<Vec<_>>();
+
+    (
+        [
+            (http::header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                http::header::CONTENT_DISPOSITION,
+                "inline; filename=\"calendar.ics\"",
+            ),
+        ],
+        ical::to_ics(&events, now),
+    )
+        .into_response()
+}
+
+/// Sitemap handler, rendering the sitemap of the realties placed by the
+/// current `Agency`, as generated in background by the `GenerateSitemaps`
+/// task.
+///
+/// Responds with `503 Service Unavailable` until the sitemaps are generated
+/// for the first time.
+pub async fn sitemap(context: Context) -> Response {
+    match context.service().sitemap(context.agency_id().into()) {
+        Some(xml) => (
+            [(http::header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+            xml.to_string(),
+        )
+            .into_response(),
+        None => http::StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
+
+/// Query of the [`placements_feed()`] handler.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PlacementsFeed {
+    /// Maximum number of the items in the page.
+    ///
+    /// Defaults to [`PlacementsFeed::DEFAULT_FIRST`].
+    pub first: Option<usize>,
+
+    /// `nextCursor` of the previous page to continue after.
+    pub after: Option<String>,
+}
+
+impl PlacementsFeed {
+    /// Default number of the items in a page.
+    pub const DEFAULT_FIRST: usize = 50;
+
+    /// Maximum number of the items in a page.
+    pub const MAX_FIRST: usize = 100;
+}
+
+/// Page of the [`placements_feed()`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedPage {
+    /// Placed realties of this page.
+    items: Vec<FeedItem>,
+
+    /// Cursor to request the next page with, if there is one.
+    next_cursor: Option<String>,
+}
+
+/// Placed realty of a [`FeedPage`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedItem {
+    /// ID of the realty.
+    id: String,
+
+    /// Full address of the realty.
+    address: String,
+
+    /// Area of the realty in square meters, as a decimal string.
+    area: Option<String>,
+
+    /// Room number of the realty.
+    room_num: Option<String>,
+
+    /// Floor of the realty.
+    floor: Option<u16>,
+
+    /// Number of floors of the realty building.
+    num_floors: u16,
+
+    /// Offer of the realty for rent, if any.
+    rent: Option<FeedOffer>,
+
+    /// Offer of the realty for sale, if any.
+    sale: Option<FeedOffer>,
+
+    /// Floor plans of the realty.
+    floor_plans: Vec<FeedFloorPlan>,
+
+    /// URLs of the virtual tours and videos of the realty.
+    media_links: Vec<String>,
+}
+
+/// Offer of a [`FeedItem`].
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FeedOffer {
+    /// Asked price.
+    price: FeedPrice,
+
+    /// [RFC 3339] date and time the realty was placed at, if known.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    placed_at: Option<String>,
+}
+
+/// Price of a [`FeedOffer`].
+#[derive(Clone, Debug, Serialize)]
+struct FeedPrice {
+    /// Amount, as a decimal string.
+    amount: String,
+
+    /// ISO 4217 code of the currency.
+    currency: String,
+}
+
+/// Floor plan of a [`FeedItem`].
+#[derive(Clone, Debug, Serialize)]
+struct FeedFloorPlan {
+    /// Floor the plan depicts.
+    floor: u16,
+
+    /// Label of the plan.
+    label: String,
+
+    /// URL of the plan file.
+    url: String,
+}
+
+impl From<read::placement::feed::Item> for FeedItem {
+    fn from(item: read::placement::feed::Item) -> Self {
+        let offer = |o: read::placement::feed::Offer| FeedOffer {
+            price: FeedPrice {
+                amount: o.price.amount.to_string(),
+                currency: o.price.currency.to_string(),
+            },
+            placed_at: o.placed_at.map(|at| at.to_rfc3339()),
+        };
+        Self {
+            id: item.realty_id.to_string(),
+            address: item.address.to_string(),
+            area: item.area.map(|a| a.to_string()),
+            room_num: item.room_num,
+            floor: item.floor,
+            num_floors: item.num_floors,
+            rent: item.rent.map(offer),
+            sale: item.sale.map(offer),
+            floor_plans: item
+                .floor_plans
+                .into_iter()
+                .map(|p| FeedFloorPlan {
+                    floor: p.floor,
+                    label: p.label,
+                    url: p.url,
+                })
+                .collect(),
+            media_links: item.media_links,
+        }
+    }
+}
+
+/// Public JSON feed handler, rendering a page of the realties placed by the
+/// current `Agency` for syndication partners and static site generators.
+///
+/// Pages are ordered by the realty IDs, so the `nextCursor` of a page stays
+/// valid while the feed changes. Responses are cacheable for the configured
+/// cache TTL.
+pub async fn placements_feed(
+    context: Context,
+    Query(feed): Query<PlacementsFeed>,
+) -> Response {
+    let first = feed.first.unwrap_or(PlacementsFeed::DEFAULT_FIRST);
+    if first == 0 || first > PlacementsFeed::MAX_FIRST {
+        return http::StatusCode::BAD_REQUEST.into_response();
+    }
+    let after = match feed.after.as_deref().map(uuid::Uuid::parse_str) {
+        None => None,
+        Some(Ok(id)) => Some(id.into()),
+        Some(Err(_)) => return http::StatusCode::BAD_REQUEST.into_response(),
+    };
+
+    let service = context.service();
+    let page = match service
+        .execute(query::placements::Feed::by(
+            read::placement::feed::Selector {
+                agency_id: context.agency_id().into(),
+                first,
+                after,
+            },
+        ))
+        .await
+    {
+        Ok(page) => page,
+        Err(e) => {
+            log::error!("failed to select placements feed: {e}");
+            return http::StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let next_cursor = page
+        .items
+        .last()
+        .filter(|_| page.has_next_page)
+        .map(|i| i.realty_id.to_string());
+    let page = FeedPage {
+        items: page.items.into_iter().map(Into::into).collect(),
+        next_cursor,
+    };
+
+    (
+        [(
+            http::header::CACHE_CONTROL,
+            format!(
+                "public, max-age={}",
+                service.config().cache_ttl.as_secs(),
+            ),
+        )],
+        Json(page),
+    )
+        .into_response()
+}
+
+define_error! {
+    enum RequestError {
+        #[code = "BATCH_TOO_LARGE"]
+        #[status = BAD_REQUEST]
+        #[message = "Too many operations in the batch request"]
+        BatchTooLarge,
+
+        #[code = "TIMEOUT"]
+        #[status = GATEWAY_TIMEOUT]
+        #[message = "Operation execution timed out"]
+        Timeout,
+
+        #[code = "CONFLICT"]
+        #[status = CONFLICT]
+        #[message = "Entity was concurrently modified, retry the operation"]
+        Conflict,
+
+        #[code = "INVALID_JOURNAL_PERIOD"]
+        #[status = BAD_REQUEST]
+        #[message = "Journal period must be a valid RFC 3339 range"]
+        InvalidPeriod,
+    }
 }
@@ -1,24 +1,56 @@
 use std::{
-    future::IntoFuture as _,
-    io,
-    sync::{Arc, OnceLock},
+    future::{Future, IntoFuture as _},
+    io::{self, Write as _},
+    pin::Pin,
+    sync::{Arc, PoisonError, RwLock},
     time,
 };
 
-use application::{api, graphql, subscriptions, Args, Config};
+use application::{
+    api,
+    config::{self, Compression},
+    calendar, export_journal, export_users, file, graphql, metrics,
+    placements_feed, sitemap, sms_status, subscriptions, Args, Config,
+    Shutdown, REQUEST_ID_HEADER,
+};
 use axum::{
     extract::MatchedPath,
-    routing::{get, on, MethodFilter},
+    response::Redirect,
+    routing::{get, on, post, MethodFilter},
     Extension, Router,
 };
 use axum_client_ip::InsecureClientIp;
-use futures::{future, TryFutureExt as _};
+use axum_server::tls_rustls::RustlsConfig;
+use common::{DateTime, DateTimeOf, Money};
+use futures::{
+    future,
+    io::{AsyncWrite, BufWriter},
+    FutureExt as _, TryFutureExt as _,
+};
+use regex::Regex;
+use secrecy::{SecretBox, SecretString};
 use service::{
-    infra::{postgres, Postgres},
-    Service,
+    command,
+    domain::{agency, contract, listing, realty, user},
+    infra::{
+        listing_feed, postgres, sms, storage, template, Gcs, Local,
+        Meilisearch, Postgres, Redis, Ses, Smtp, Twilio, S3,
+    },
+    Command as _, Service,
+};
+use tokio::{net::TcpListener, signal};
+use tokio_util::compat::TokioAsyncWriteCompatExt as _;
+use tower_http::{
+    compression::{
+        predicate::{Predicate as _, SizeAbove},
+        CompressionLayer,
+    },
+    cors::{AllowOrigin, CorsLayer},
+    decompression::RequestDecompressionLayer,
+    limit::RequestBodyLimitLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::TraceLayer,
 };
-use tokio::net::TcpListener;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
 use tracing as log;
 use tracing_subscriber::{
     filter::filter_fn,
@@ -28,10 +60,84 @@ use tracing_subscriber::{
 
 const STDERR_LEVELS: &[log::Level] = &[log::Level::WARN, log::Level::ERROR];
 
-static LOG_LEVEL: OnceLock<log::Level> = OnceLock::new();
+/// Interval of checking the configuration file for changes, when running with
+/// `--watch-config`.
+const CONFIG_POLL_INTERVAL: time::Duration = time::Duration::from_secs(5);
+
+/// Origins no intended [CORS] origin should match, so an origin matching them
+/// allows any origin.
+///
+/// `.invalid` top-level domain is reserved by [RFC 2606].
+///
+/// [CORS]: https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS
+/// [RFC 2606]: https://www.rfc-editor.org/rfc/rfc2606
+const CORS_PROBE_ORIGINS: &[&str] =
+    &["https://cors-probe.invalid", "http://cors-probe.invalid"];
+
+static LOG_LEVEL: RwLock<log::Level> = RwLock::new(log::Level::INFO);
+
+/// [CORS] origins allowed to make requests, reloadable at runtime.
+///
+/// [CORS]: https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS
+type CorsOrigins = Arc<RwLock<Vec<Regex>>>;
 
 postgres::embed_migrations!("../migrations");
 
+/// Rollback SQL scripts of the embedded [`migrations`], keyed by their
+/// versions.
+const MIGRATION_ROLLBACKS: &[(u32, &str)] = &[
+    (1, include_str!("../../rollbacks/V1__basic.sql")),
+    (2, include_str!("../../rollbacks/V2__users.sql")),
+    (3, include_str!("../../rollbacks/V3__realties.sql")),
+    (4, include_str!("../../rollbacks/V4__contracts.sql")),
+    (5, include_str!("../../rollbacks/V5__versions.sql")),
+    (6, include_str!("../../rollbacks/V6__advisory_locks.sql")),
+    (7, include_str!("../../rollbacks/V7__trigram_search.sql")),
+    (8, include_str!("../../rollbacks/V8__contracts_completion.sql")),
+    (9, include_str!("../../rollbacks/V9__task_settings.sql")),
+    (10, include_str!("../../rollbacks/V10__task_runs.sql")),
+    (11, include_str!("../../rollbacks/V11__task_dead_letters.sql")),
+    (12, include_str!("../../rollbacks/V12__employment_managers.sql")),
+    (13, include_str!("../../rollbacks/V13__agencies.sql")),
+    (14, include_str!("../../rollbacks/V14__branches.sql")),
+    (15, include_str!("../../rollbacks/V15__realty_assignees.sql")),
+    (16, include_str!("../../rollbacks/V16__leads.sql")),
+    (17, include_str!("../../rollbacks/V17__offers.sql")),
+    (18, include_str!("../../rollbacks/V18__offer_negotiations.sql")),
+    (19, include_str!("../../rollbacks/V19__reviews.sql")),
+    (20, include_str!("../../rollbacks/V20__rent_arrears.sql")),
+    (21, include_str!("../../rollbacks/V21__rent_late_fees.sql")),
+    (22, include_str!("../../rollbacks/V22__rent_charges.sql")),
+    (23, include_str!("../../rollbacks/V23__rent_inventories.sql")),
+    (24, include_str!("../../rollbacks/V24__realty_keys.sql")),
+    (25, include_str!("../../rollbacks/V25__contract_insurances.sql")),
+    (26, include_str!("../../rollbacks/V26__journal_exports.sql")),
+    (27, include_str!("../../rollbacks/V27__contract_templates.sql")),
+    (28, include_str!("../../rollbacks/V28__contract_numbers.sql")),
+    (29, include_str!("../../rollbacks/V29__contract_approvals.sql")),
+    (30, include_str!("../../rollbacks/V30__contract_parties.sql")),
+    (31, include_str!("../../rollbacks/V31__realty_ownerships.sql")),
+    (32, include_str!("../../rollbacks/V32__contract_commission_splits.sql")),
+    (33, include_str!("../../rollbacks/V33__realty_ownership_transfers.sql")),
+    (34, include_str!("../../rollbacks/V34__short_term_rents.sql")),
+    (35, include_str!("../../rollbacks/V35__rent_double_booking.sql")),
+    (36, include_str!("../../rollbacks/V36__realty_areas.sql")),
+    (37, include_str!("../../rollbacks/V37__agency_settings.sql")),
+    (38, include_str!("../../rollbacks/V38__contracts_placed_at.sql")),
+    (39, include_str!("../../rollbacks/V39__sms_deliveries.sql")),
+    (40, include_str!("../../rollbacks/V40__realty_floor_plans.sql")),
+    (41, include_str!("../../rollbacks/V41__realty_media_links.sql")),
+    (42, include_str!("../../rollbacks/V42__external_listings.sql")),
+    (43, include_str!("../../rollbacks/V43__user_calendar_feeds.sql")),
+    (44, include_str!("../../rollbacks/V44__saved_searches.sql")),
+    (45, include_str!("../../rollbacks/V45__audit_entries.sql")),
+    (46, include_str!("../../rollbacks/V46__contracts_archivation.sql")),
+    (47, include_str!("../../rollbacks/V47__realties_soft_deletion.sql")),
+    (48, include_str!("../../rollbacks/V48__audit_entries_entity_index.sql")),
+    (49, include_str!("../../rollbacks/V49__retention_indexes.sql")),
+    (50, include_str!("../../rollbacks/V50__user_invites.sql")),
+];
+
 #[tokio::main]
 async fn main() {
     tracing_subscriber::registry()
@@ -44,11 +150,7 @@ async fn main() {
                 .with_filter(filter_fn(|meta| {
                     meta.is_span()
                         || (!STDERR_LEVELS.contains(meta.level()))
-                            && LOG_LEVEL
-                                .get()
-                                .copied()
-                                .unwrap_or(log::Level::INFO)
-                                >= *meta.level()
+                            && log_level() >= *meta.level()
                 })),
         )
         .with(
@@ -60,20 +162,24 @@ async fn main() {
                 .with_filter(filter_fn(|meta| {
                     meta.is_span()
                         || (STDERR_LEVELS.contains(meta.level()))
-                            && LOG_LEVEL
-                                .get()
-                                .copied()
-                                .unwrap_or(log::Level::INFO)
-                                >= *meta.level()
+                            && log_level() >= *meta.level()
                 })),
         )
+        .with(api::deprecation::UsageLayer)
         .init();
 
     _ = start().await;
 }
 
 async fn start() -> Result<(), ()> {
-    let Args { config } = Args::parse().map_err(|e| {
+    let Args {
+        config: config_path,
+        watch_config,
+        migrate_status,
+        migrate_rollback,
+        seed,
+        export,
+    } = Args::parse().map_err(|e| {
         log::error!("failed to parse command line arguments: {e}");
     })?;
 
@@ -82,18 +188,36 @@ async fn start() -> Result<(), ()> {
         service,
         server,
         log,
-    } = Config::new(config).map_err(|e| {
+    } = Config::new(&config_path).map_err(|e| {
         log::error!("failed to load `Config`: {e}");
     })?;
 
-    LOG_LEVEL
-        .set(log.level.into())
-        .unwrap_or_else(|_| unreachable!("first initialization"));
+    set_log_level(log.level.into());
+    if export.as_deref() == Some("-") {
+        // Standard output is occupied by the exported snapshot.
+        set_log_level(log_level().min(log::Level::WARN));
+    }
 
+    let postgres_tls = postgres.tls.clone().into();
+    let postgres_retry = postgres::retry::Config {
+        startup: postgres.startup_retry.into(),
+        checkout: postgres.checkout_retry.into(),
+    };
+    let statement_timeout = postgres.statement_timeout;
     let postgres_config = postgres.into();
-    let mut postgres = Postgres::new(&postgres_config).map_err(|e| {
-        log::error!("failed to initialize `Postgres` client: {e}");
-    })?;
+    let mut postgres =
+        Postgres::new(&postgres_config, &postgres_tls, postgres_retry)
+            .await
+            .map_err(|e| {
+                log::error!("failed to initialize `Postgres` client: {e}");
+            })?;
+
+    if migrate_status {
+        return print_migrations_status(&mut postgres).await;
+    }
+    if let Some(count) = migrate_rollback {
+        return rollback_migrations(&mut postgres, count).await;
+    }
 
     migrations::runner()
         .run_async(&mut postgres)
@@ -102,27 +226,101 @@ async fn start() -> Result<(), ()> {
             log::error!("failed to run database migrations: {e}");
         })?;
 
-    let (service, background) = Service::new(service.into(), postgres);
+    let redis_url = service.cache.redis_url.clone();
+    let search = service.search.clone();
+    let email = service.email.clone();
+    let sms = service.sms.clone();
+    let templates = service.templates.clone();
+    let storage = service.storage.clone();
+    let feeds = service.listing_feeds.clone();
+    let mut service_config = service::Config::from(service);
+    if let Some(url) = redis_url {
+        let redis = Redis::new(&url).map_err(|e| {
+            log::error!("failed to initialize `Redis` client: {e}");
+        })?;
+        service_config.cache = Some(Arc::new(redis));
+    }
+    if let Some(url) = search.meilisearch_url {
+        let api_key = search.meilisearch_api_key.map(SecretString::from);
+        service_config.search = Some(Arc::new(Meilisearch::new(&url, api_key)));
+    }
+    if let Some(url) = email.smtp_url {
+        let smtp = Smtp::new(&url, &email.from).map_err(|e| {
+            log::error!("failed to initialize `Smtp` client: {e}");
+        })?;
+        service_config.mailer = Some(Arc::new(smtp));
+    } else if email.ses {
+        service_config.mailer = Some(Arc::new(Ses::new(email.from).await));
+    }
+    service_config.sms_gateway = sms_gateway(sms)?;
+    if let Some(dir) = templates.dir {
+        let engine = template::Engine::load(&dir).map_err(|e| {
+            log::error!("failed to load message templates: {e}");
+        })?;
+        service_config.templates = Arc::new(engine);
+    }
+    if let (Some(base_url), Some(secret)) =
+        (storage.url_base.clone(), storage.url_secret.clone())
+    {
+        service_config.url_signer = Some(Arc::new(storage::Signer::new(
+            SecretString::from(secret),
+            base_url,
+            storage.url_ttl,
+        )));
+    }
+    service_config.storage = file_storage(storage).await?;
+    service_config.listing_feeds = listing_feeds(feeds)?;
 
-    let schema = api::Schema::new(api::Query, api::Mutation, api::Subscription);
+    let (service, background) = Service::new(service_config, postgres);
 
-    let mut cors = CorsLayer::new()
-        .allow_methods([
-            http::Method::GET,
-            http::Method::OPTIONS,
-            http::Method::POST,
-        ])
-        .allow_headers([
-            http::header::AUTHORIZATION,
-            http::header::CONTENT_TYPE,
-        ]);
-    for origin in server.cors.origins {
-        cors = cors.allow_origin(
-            origin.parse::<http::header::HeaderValue>().map_err(|e| {
-                log::error!("`{origin}` is not current CORS origin: {e}");
-            })?,
-        );
+    if seed {
+        return seed_demo_data(&service).await;
     }
+    if let Some(path) = export {
+        return export_snapshot(&service, &path).await;
+    }
+
+    // Only the statements serving requests are limited by the timeout.
+    let service = service.with_database(
+        service.database().with_statement_timeout(statement_timeout),
+    );
+
+    let schema = api::Schema::new(api::Query, api::Mutation, api::Subscription);
+    check_deprecations();
+
+    let cors_origins = Arc::new(RwLock::new(parse_cors_origins(&server.cors)?));
+    let cors = cors_layer(&server.cors, Arc::clone(&cors_origins))?;
+
+    let compression = {
+        let Compression {
+            enabled,
+            min_size,
+            content_types,
+        } = server.compression;
+        CompressionLayer::new().compress_when(SizeAbove::new(min_size).and(
+            move |_: http::StatusCode,
+                  _: http::Version,
+                  headers: &http::HeaderMap,
+                  _: &http::Extensions| {
+                enabled
+                    && headers
+                        .get(http::header::CONTENT_TYPE)
+                        .and_then(|h| h.to_str().ok())
+                        .is_some_and(|ct| {
+                            content_types.iter().any(|t| ct.starts_with(t))
+                        })
+            },
+        ))
+    };
+
+    let config_watcher = if watch_config {
+        watch_config_file(config_path, cors_origins, service.clone())
+            .boxed()
+    } else {
+        future::pending().boxed()
+    };
+
+    let shutdown: Shutdown = shutdown_signal().boxed().shared();
 
     let app = Router::new()
         .route(
@@ -130,8 +328,24 @@ async fn start() -> Result<(), ()> {
             on(MethodFilter::GET.or(MethodFilter::POST), graphql),
         )
         .route("/subscriptions", get(subscriptions))
+        .route("/export/journal.csv", get(export_journal))
+        .route("/export/users.csv", get(export_users))
+        .route("/files/*path", get(file))
+        .route("/calendar.ics", get(calendar))
+        .route("/feed/placements.json", get(placements_feed))
+        .route("/sitemap.xml", get(sitemap))
+        .route("/webhooks/sms/status", post(sms_status))
+        .route("/metrics", get(metrics))
+        .route("/readyz", get(readyz))
         .layer(Extension(Arc::new(schema)))
         .layer(Extension(service))
+        .layer(Extension(server.limits))
+        .layer(Extension(server.subscriptions))
+        .layer(Extension(shutdown.clone()))
+        .layer(compression)
+        // Limit is applied to the decompressed body.
+        .layer(RequestBodyLimitLayer::new(server.limits.max_body_size))
+        .layer(RequestDecompressionLayer::new())
         .layer(cors)
         .layer(
             TraceLayer::new_for_http()
@@ -147,6 +361,10 @@ async fn start() -> Result<(), ()> {
                         http.flavor = ?r.version(),
                         http.host = r.uri().host(),
                         http.method = r.method().as_str(),
+                        http.request_id = r
+                            .headers()
+                            .get(REQUEST_ID_HEADER)
+                            .and_then(|h| h.to_str().ok()),
                         http.route = r
                             .extensions()
                             .get::<MatchedPath>()
@@ -163,6 +381,11 @@ async fn start() -> Result<(), ()> {
                             .headers()
                             .get("User-Agent")
                             .and_then(|h| h.to_str().ok()),
+                        http.client_name = r
+                            .headers()
+                            .get(api::deprecation::CLIENT_NAME_HEADER)
+                            .or_else(|| r.headers().get("User-Agent"))
+                            .and_then(|h| h.to_str().ok()),
                         http.status_code = tracing::field::Empty,
                     )
                 })
@@ -188,7 +411,14 @@ async fn start() -> Result<(), ()> {
                         }
                     },
                 ),
-        );
+        )
+        .layer(PropagateRequestIdLayer::new(http::HeaderName::from_static(
+            REQUEST_ID_HEADER,
+        )))
+        .layer(SetRequestIdLayer::new(
+            http::HeaderName::from_static(REQUEST_ID_HEADER),
+            MakeRequestUuid,
+        ));
 
     let listener = TcpListener::bind((server.host.clone(), server.port))
         .await
@@ -200,18 +430,880 @@ async fn start() -> Result<(), ()> {
             );
         })?;
 
-    log::info!("listening on `{}:{}`", server.host, server.port);
+    let serve = if let Some(tls) = server.tls {
+        let tls_config = RustlsConfig::from_pem_file(&tls.cert, &tls.key)
+            .await
+            .map_err(|e| {
+                log::error!("failed to load TLS certificate and key: {e}");
+            })?;
+        let listener = listener.into_std().map_err(|e| {
+            log::error!("failed to convert `TcpListener`: {e}");
+        })?;
+
+        let handle = axum_server::Handle::new();
+        drop(tokio::spawn({
+            let handle = handle.clone();
+            shutdown.clone().map(move |()| handle.graceful_shutdown(None))
+        }));
 
-    let serve = axum::serve(listener, app);
+        log::info!("listening on `{}:{}` (HTTPS)", server.host, server.port);
 
-    future::try_join(
-        serve
+        let https = axum_server::from_tcp_rustls(listener, tls_config)
+            .handle(handle)
+            .serve(app.into_make_service())
+            .map_err(|e| log::error!("webserver failed: {e}"));
+        let redirect = match tls.redirect_port {
+            Some(port) => serve_https_redirect(
+                server.host.clone(),
+                port,
+                server.port,
+                shutdown.clone(),
+            )
+            .boxed(),
+            None => future::ok(()).boxed(),
+        };
+        future::try_join(https, redirect).map_ok(drop).boxed()
+    } else {
+        log::info!("listening on `{}:{}`", server.host, server.port);
+
+        axum::serve(listener, app)
+            .with_graceful_shutdown(shutdown.clone())
             .into_future()
-            .map_err(|e| log::error!("webserver failed: {e}")),
-        background.into_future().map_err(|e| {
-            log::error!("background task failed: {e}");
+            .map_err(|e| log::error!("webserver failed: {e}"))
+            .boxed()
+    };
+    let timeout = server.shutdown_timeout;
+    let drain_deadline = shutdown.clone().then(move |()| {
+        tokio::time::sleep(timeout).map(move |()| {
+            log::warn!(
+                "in-flight requests haven't completed in {timeout:?}, \
+                 dropping them",
+            );
+        })
+    });
+
+    // Uncommitted database transactions of the dropped requests are rolled
+    // back once their connections are returned to the pool.
+    let run = future::try_join(
+        future::select(serve, drain_deadline.boxed()).map(|r| match r {
+            future::Either::Left((r, _)) => r,
+            future::Either::Right(((), _)) => Ok(()),
         }),
+        background
+            .with_graceful_shutdown(shutdown.clone(), timeout)
+            .map_err(|e| log::error!("background task failed: {e}")),
     )
-    .await
-    .map(drop)
+    .map_ok(drop)
+    .boxed_local();
+
+    // Config watching stops once shutdown is initiated.
+    future::select(run, future::select(config_watcher, shutdown))
+        .then(|r| match r {
+            future::Either::Left((r, _)) => future::ready(r).boxed_local(),
+            future::Either::Right((_, run)) => run,
+        })
+        .await
+}
+
+/// Returns the current maximum logging level.
+fn log_level() -> log::Level {
+    *LOG_LEVEL.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Sets the maximum logging level.
+fn set_log_level(level: log::Level) {
+    *LOG_LEVEL.write().unwrap_or_else(PoisonError::into_inner) = level;
+}
+
+/// Reports the [`api::deprecation::DEPRECATIONS`] past their sunset dates,
+/// so the deprecated fields may be removed from the GraphQL schema.
+fn check_deprecations() {
+    let now = DateTime::now();
+    for d in api::deprecation::DEPRECATIONS {
+        match d.sunset_at() {
+            Some(at) if at <= now => {
+                log::warn!(
+                    "deprecated GraphQL field `{}` is past its {} sunset, and \
+                     may be removed",
+                    d.field,
+                    d.sunset,
+                );
+            }
+            Some(_) => {}
+            None => {
+                log::error!(
+                    "deprecated GraphQL field `{}` has malformed `{}` sunset",
+                    d.field,
+                    d.sunset,
+                );
+            }
+        }
+    }
+}
+
+/// Builds a [`CorsLayer`] from the provided [`config::Cors`], allowing the
+/// provided (reloadable) [`CorsOrigins`].
+fn cors_layer(
+    config: &config::Cors,
+    origins: CorsOrigins,
+) -> Result<CorsLayer, ()> {
+    let parse_headers = |headers: &[String]| {
+        headers
+            .iter()
+            .map(|h| {
+                h.parse::<http::HeaderName>().map_err(|e| {
+                    log::error!("`{h}` is not correct CORS header: {e}");
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    let mut cors = CorsLayer::new()
+        .allow_methods([
+            http::Method::GET,
+            http::Method::OPTIONS,
+            http::Method::POST,
+        ])
+        .allow_headers(parse_headers(&config.headers)?)
+        .expose_headers(parse_headers(&config.expose_headers)?)
+        .allow_credentials(config.credentials)
+        .allow_origin(AllowOrigin::predicate(move |origin, _| {
+            origin.to_str().is_ok_and(|origin| {
+                origins
+                    .read()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .iter()
+                    .any(|o| o.is_match(origin))
+            })
+        }));
+    if let Some(max_age) = config.max_age {
+        cors = cors.max_age(max_age);
+    }
+    Ok(cors)
+}
+
+/// Parses the allowed [CORS] origins of the provided [`config::Cors`] as
+/// [`Regex`]es.
+///
+/// Origins matching any origin (like `*` or `.*` regex) are rejected if
+/// credentials are allowed, since they would allow any site to make
+/// credentialed requests.
+///
+/// [CORS]: https://developer.mozilla.org/en-US/docs/Web/HTTP/CORS
+fn parse_cors_origins(config: &config::Cors) -> Result<Vec<Regex>, ()> {
+    config
+        .origins
+        .iter()
+        .map(|origin| {
+            let re = if origin == "*" {
+                ".*".to_owned()
+            } else {
+                regex::escape(origin).replace(r"\*", "[^/]+")
+            };
+            (origin, re)
+        })
+        .chain(config.origin_regexes.iter().map(|re| (re, re.clone())))
+        .map(|(origin, re)| {
+            let re = Regex::new(&format!("^(?:{re})$")).map_err(|e| {
+                log::error!("`{origin}` is not correct CORS origin: {e}");
+            })?;
+            if config.credentials
+                && CORS_PROBE_ORIGINS.iter().any(|o| re.is_match(o))
+            {
+                log::error!(
+                    "`{origin}` CORS origin matches any origin, so cannot be \
+                     used with allowed credentials",
+                );
+                return Err(());
+            }
+            Ok(re)
+        })
+        .collect()
+}
+
+/// Watches the configuration file at the provided `path` for changes (and the
+/// application for `SIGHUP`), applying the reloadable settings on each one.
+///
+/// Reloadable settings are:
+/// - log level;
+/// - CORS origins;
+/// - [`Service`] tasks configuration.
+async fn watch_config_file(
+    path: String,
+    cors_origins: CorsOrigins,
+    service: Service<Postgres>,
+) {
+    let modified_at = |path: &str| {
+        let path = path.to_owned();
+        async move {
+            tokio::fs::metadata(path)
+                .await
+                .and_then(|m| m.modified())
+                .ok()
+        }
+    };
+
+    #[cfg(unix)]
+    let mut sighup =
+        signal::unix::signal(signal::unix::SignalKind::hangup())
+            .map_err(|e| log::error!("failed to listen for `SIGHUP`: {e}"))
+            .ok();
+
+    let mut last_modified_at = modified_at(&path).await;
+    let mut poll = tokio::time::interval(CONFIG_POLL_INTERVAL);
+    loop {
+        #[cfg(unix)]
+        let hangup = async {
+            match &mut sighup {
+                Some(s) => {
+                    _ = s.recv().await;
+                }
+                None => future::pending().await,
+            }
+        };
+        #[cfg(not(unix))]
+        let hangup = future::pending::<()>();
+
+        tokio::select! {
+            _ = poll.tick() => {
+                let modified = modified_at(&path).await;
+                if modified == last_modified_at {
+                    continue;
+                }
+                last_modified_at = modified;
+            }
+            () = hangup => {}
+        }
+
+        let Ok(Config {
+            server,
+            service: svc,
+            log,
+            ..
+        }) = Config::new(&path).map_err(|e| {
+            log::error!("failed to reload `Config`, keeping the old one: {e}");
+        })
+        else {
+            continue;
+        };
+
+        set_log_level(log.level.into());
+        if let Ok(origins) = parse_cors_origins(&server.cors) {
+            *cors_origins.write().unwrap_or_else(PoisonError::into_inner) =
+                origins;
+        }
+        service.reload(&svc.into());
+
+        log::info!("`Config` reloaded from `{path}`");
+    }
+}
+
+/// Serves plain HTTP on the provided `host` and `port`, permanently redirecting
+/// all the requests to HTTPS on the provided `https_port`.
+async fn serve_https_redirect(
+    host: String,
+    port: u16,
+    https_port: u16,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), ()> {
+    let listener = TcpListener::bind((host.clone(), port)).await.map_err(|e| {
+        log::error!("failed to listen on `{host}:{port}`: {e}");
+    })?;
+
+    log::info!("redirecting `{host}:{port}` to HTTPS");
+
+    let app = Router::new().fallback(
+        move |headers: http::HeaderMap, uri: http::Uri| async move {
+            https_redirect(&headers, uri, https_port)
+        },
+    );
+    axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| log::error!("HTTPS redirect server failed: {e}"))
+}
+
+/// Builds a [`Redirect`] of the provided [`http::Uri`] to HTTPS on the
+/// provided `https_port`.
+fn https_redirect(
+    headers: &http::HeaderMap,
+    uri: http::Uri,
+    https_port: u16,
+) -> Result<Redirect, http::StatusCode> {
+    let host = headers
+        .get(http::header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.parse::<http::uri::Authority>().ok())
+        .ok_or(http::StatusCode::BAD_REQUEST)?;
+    let authority = if https_port == 443 {
+        host.host().to_owned()
+    } else {
+        format!("{}:{https_port}", host.host())
+    };
+
+    let mut parts = uri.into_parts();
+    parts.scheme = Some(http::uri::Scheme::HTTPS);
+    parts.authority = Some(
+        authority
+            .parse()
+            .map_err(|_| http::StatusCode::BAD_REQUEST)?,
+    );
+    if parts.path_and_query.is_none() {
+        parts.path_and_query = Some(http::uri::PathAndQuery::from_static("/"));
+    }
+    let uri = http::Uri::from_parts(parts)
+        .map_err(|_| http::StatusCode::BAD_REQUEST)?;
+
+    Ok(Redirect::permanent(&uri.to_string()))
+}
+
+/// Prints the applied and pending database [`migrations`].
+async fn print_migrations_status(postgres: &mut Postgres) -> Result<(), ()> {
+    let runner = migrations::runner();
+    let applied = runner
+        .get_applied_migrations_async(postgres)
+        .await
+        .map_err(|e| {
+            log::error!("failed to query applied database migrations: {e}");
+        })?;
+
+    let mut stdout = io::stdout().lock();
+    for migration in runner.get_migrations() {
+        let status = applied
+            .iter()
+            .find(|m| m.version() == migration.version())
+            .and_then(|m| m.applied_on())
+            .map_or_else(
+                || "pending".to_owned(),
+                |at| format!("applied at {at}"),
+            );
+        writeln!(stdout, "{migration}: {status}").map_err(|e| {
+            log::error!("failed to print database migrations status: {e}");
+        })?;
+    }
+    stdout.flush().map_err(|e| {
+        log::error!("failed to print database migrations status: {e}");
+    })
+}
+
+/// Reverts the provided `count` of the latest applied database [`migrations`]
+/// using the [`MIGRATION_ROLLBACKS`] scripts.
+async fn rollback_migrations(
+    postgres: &mut Postgres,
+    count: usize,
+) -> Result<(), ()> {
+    let mut applied = migrations::runner()
+        .get_applied_migrations_async(postgres)
+        .await
+        .map_err(|e| {
+            log::error!("failed to query applied database migrations: {e}");
+        })?;
+    applied.sort_by_key(|m| std::cmp::Reverse(m.version()));
+
+    for migration in applied.into_iter().take(count) {
+        let Some((_, sql)) =
+            MIGRATION_ROLLBACKS.iter().find(|(v, _)| *v == migration.version())
+        else {
+            log::error!("no rollback script for `{migration}` migration");
+            return Err(());
+        };
+        postgres
+            .revert_migration(migration.version(), sql)
+            .await
+            .map_err(|e| {
+                log::error!("failed to revert `{migration}` migration: {e}");
+            })?;
+        log::info!("reverted `{migration}` migration");
+    }
+    Ok(())
+}
+
+/// ID of the administrator [`user`] created by the [`migrations`], hiring the
+/// demo employees.
+const ADMIN_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+/// First and last names of the demo [`user`]s.
+const DEMO_USERS: &[(&str, &str)] = &[
+    ("Olivia", "Bennett"),
+    ("Liam", "Carter"),
+    ("Emma", "Donovan"),
+    ("Noah", "Fletcher"),
+    ("Ava", "Hughes"),
+    ("Lucas", "Morgan"),
+    ("Mia", "Reynolds"),
+    ("Ethan", "Sullivan"),
+];
+
+/// Number of the demo [`user`]s (from the beginning of [`DEMO_USERS`]) to be
+/// employed.
+const DEMO_EMPLOYEES: usize = 3;
+
+/// Cities, streets and building names of the demo [`realty`]s.
+const DEMO_REALTIES: &[(&str, &str, &str)] = &[
+    ("Austin", "Congress Avenue", "Riverside Lofts"),
+    ("Boston", "Beacon Street", "Harbor View"),
+    ("Chicago", "Michigan Avenue", "Lakeshore Tower"),
+    ("Denver", "Colfax Avenue", "Mile High Residences"),
+    ("Seattle", "Pine Street", "Emerald Court"),
+    ("Portland", "Burnside Street", "Rose Garden Apartments"),
+];
+
+/// Exports a logical snapshot of all the entities into the file by the
+/// provided `path`, or into the standard output, if it's `-`.
+async fn export_snapshot(
+    service: &Service<Postgres>,
+    path: &str,
+) -> Result<(), ()> {
+    let output: Pin<Box<dyn AsyncWrite + Send>> = if path == "-" {
+        Box::pin(tokio::io::stdout().compat_write())
+    } else {
+        let file = tokio::fs::File::create(path).await.map_err(|e| {
+            log::error!("failed to create `{path}` export file: {e}");
+        })?;
+        Box::pin(file.compat_write())
+    };
+
+    let count = service
+        .execute(command::ExportSnapshot {
+            output: BufWriter::new(output),
+        })
+        .await
+        .map_err(|e| {
+            log::error!("failed to export snapshot: {e}");
+        })?;
+
+    log::info!("exported {count} records into `{path}`");
+    Ok(())
+}
+
+/// Populates the database with demo [`user`]s, employments, [`realty`]s,
+/// contracts and placements through the regular [`command`]s.
+///
+/// Generated logins are suffixed randomly, so the demo data may be seeded
+/// multiple times.
+async fn seed_demo_data(service: &Service<Postgres>) -> Result<(), ()> {
+    let admin_id = ADMIN_ID.parse::<user::Id>().expect("valid `user::Id`");
+    let suffix = &uuid::Uuid::new_v4().simple().to_string()[..6];
+    let year = time::Duration::from_secs(365 * 24 * 60 * 60);
+
+    let mut users = Vec::with_capacity(DEMO_USERS.len());
+    for (i, (first, last)) in DEMO_USERS.iter().enumerate() {
+        let login = format!("{first}{last}{suffix}").to_lowercase();
+        let user = service
+            .execute(command::CreateUser {
+                name: user::Name::new(format!("{first} {last}"))
+                    .expect("valid `user::Name`"),
+                login: user::Login::new(login.clone())
+                    .expect("valid `user::Login`"),
+                password: SecretBox::new(Box::new(
+                    user::Password::new("demo-password")
+                        .expect("valid `user::Password`"),
+                )),
+                email: user::Email::new(format!("{login}@example.com")),
+                phone: user::Phone::new(format!("+1 555 010 {i:04}")),
+            })
+            .await
+            .map_err(|e| log::error!("failed to create demo `User`: {e}"))?;
+        users.push(user);
+    }
+
+    let (employees, clients) = users.split_at(DEMO_EMPLOYEES);
+    for (i, employee) in employees.iter().enumerate() {
+        _ = service
+            .execute(command::CreateEmploymentContract {
+                agency_id: agency::Id::DEFAULT,
+                user_id: employee.id,
+                initiator_id: admin_id,
+                name: contract::Name::new(format!(
+                    "Employment of {}",
+                    employee.name,
+                ))
+                .expect("valid `contract::Name`"),
+                description: contract::Description::new(
+                    "Full-time realty manager position",
+                )
+                .expect("valid `contract::Description`"),
+                expires_at: None,
+                base_salary: format!("{}USD", 3000 + 500 * i)
+                    .parse::<Money>()
+                    .expect("valid `Money`"),
+                manager_id: Some(admin_id),
+                branch_id: None,
+            })
+            .await
+            .map_err(|e| {
+                log::error!("failed to create demo employment `Contract`: {e}");
+            })?;
+    }
+
+    for (i, (city, street, building)) in DEMO_REALTIES.iter().enumerate() {
+        let realty = service
+            .execute(command::CreateRealty {
+                agency_id: agency::Id::DEFAULT,
+                country: realty::Country::new("United States")
+                    .expect("valid `realty::Country`"),
+                state: None,
+                city: realty::City::new(*city).expect("valid `realty::City`"),
+                street: realty::Street::new(format!("{} {street}", 100 + i))
+                    .expect("valid `realty::Street`"),
+                zip_code: realty::ZipCode::new(format!("{:05}", 10_001 + i)),
+                building_name: realty::BuildingName::new(*building)
+                    .expect("valid `realty::BuildingName`"),
+                num_floors: 12,
+                floor: Some(u16::try_from(i % 12 + 1).expect("fits `u16`")),
+                apartment_num: realty::ApartmentNum::new((i + 1).to_string()),
+                room_num: None,
+                area: None,
+            })
+            .await
+            .map_err(|e| log::error!("failed to create demo `Realty`: {e}"))?;
+
+        let employer = &employees[i % employees.len()];
+        let landlord = &clients[i % clients.len()];
+        let name = contract::Name::new(format!("Management of {building}"))
+            .expect("valid `contract::Name`");
+        let description =
+            contract::Description::new(format!("Apartment in {city}"))
+                .expect("valid `contract::Description`");
+        let expires_at = Some(DateTimeOf::now() + year);
+        let price = |amount: usize| {
+            format!("{amount}USD").parse::<Money>().expect("valid `Money`")
+        };
+
+        // The last `Realty` is rented out right away, while the others stay
+        // placed for sale or rent.
+        let rented = i + 1 == DEMO_REALTIES.len();
+        let res = if i % 2 == 0 || rented {
+            service
+                .execute(command::CreateManagementForRentContract {
+                    realty_id: realty.id,
+                    landlord_id: landlord.id,
+                    employer_id: employer.id,
+                    name,
+                    description,
+                    expires_at,
+                    expected_price: price(1500 + 250 * i),
+                    expected_deposit: Some(price(1500)),
+                    one_time_fee: None,
+                    monthly_fee: Some(price(100)),
+                    percent_fee: None,
+                    make_placement: !rented,
+                })
+                .await
+                .map_err(|e| e.to_string())
+        } else {
+            service
+                .execute(command::CreateManagementForSaleContract {
+                    realty_id: realty.id,
+                    landlord_id: landlord.id,
+                    employer_id: employer.id,
+                    name,
+                    description,
+                    expires_at,
+                    expected_price: price(250_000 + 50_000 * i),
+                    expected_deposit: Some(price(10_000)),
+                    one_time_fee: Some(price(5_000)),
+                    monthly_fee: None,
+                    percent_fee: None,
+                    make_placement: true,
+                })
+                .await
+                .map_err(|e| e.to_string())
+        };
+        _ = res.map_err(|e| {
+            log::error!("failed to create demo management `Contract`: {e}");
+        })?;
+
+        if rented {
+            let purchaser = &clients[(i + 1) % clients.len()];
+            _ = service
+                .execute(command::CreateRentContract {
+                    realty_id: realty.id,
+                    employer_id: employer.id,
+                    purchaser_id: purchaser.id,
+                    name: Some(
+                        contract::Name::new(format!("Rent of {building}"))
+                            .expect("valid `contract::Name`"),
+                    ),
+                    description: Some(
+                        contract::Description::new(format!(
+                            "Long-term rent of an apartment in {city}",
+                        ))
+                        .expect("valid `contract::Description`"),
+                    ),
+                    template_id: None,
+                    expires_at,
+                    price: price(1500 + 250 * i),
+                    deposit: Some(price(1500)),
+                    late_fee: None,
+                    offer_id: None,
+                })
+                .await
+                .map_err(|e| {
+                    log::error!("failed to create demo rent `Contract`: {e}");
+                })?;
+        }
+    }
+
+    log::info!(
+        "seeded {} demo users and {} demo realties (logins suffixed with \
+         `{suffix}`, password `demo-password`)",
+        DEMO_USERS.len(),
+        DEMO_REALTIES.len(),
+    );
+    Ok(())
+}
+
+/// Readiness probe handler.
+///
+/// Responds with `503 Service Unavailable` until the database is reported
+/// reachable by the `DatabaseHealthcheck` task.
+async fn readyz(
+    Extension(service): Extension<Service<Postgres>>,
+) -> http::StatusCode {
+    if service.database_status().borrow().reachable {
+        http::StatusCode::OK
+    } else {
+        http::StatusCode::SERVICE_UNAVAILABLE
+    }
+}
+
+/// Creates the [`service::infra::Storage`] according to the provided
+/// [`config::Storage`].
+///
+/// # Errors
+///
+/// If the settings required by the [`config::StorageProvider`] are missing,
+/// or its client fails to be initialized.
+async fn file_storage(
+    config: config::Storage,
+) -> Result<Option<Arc<dyn service::infra::Storage>>, ()> {
+    let config::Storage {
+        provider,
+        dir,
+        bucket,
+        prefix,
+        url_base: _,
+        url_secret: _,
+        url_ttl: _,
+    } = config;
+
+    Ok(match provider {
+        None => None,
+        Some(config::StorageProvider::Local) => {
+            let Some(dir) = dir else {
+                log::error!("`dir` is required by `local` storage provider");
+                return Err(());
+            };
+            Some(Arc::new(Local::new(dir, prefix)))
+        }
+        Some(config::StorageProvider::S3) => {
+            let Some(bucket) = bucket else {
+                log::error!("`bucket` is required by `s3` storage provider");
+                return Err(());
+            };
+            Some(Arc::new(S3::new(bucket, prefix).await))
+        }
+        Some(config::StorageProvider::Gcs) => {
+            let Some(bucket) = bucket else {
+                log::error!("`bucket` is required by `gcs` storage provider");
+                return Err(());
+            };
+            let gcs = Gcs::new(bucket, prefix).await.map_err(|e| {
+                log::error!("failed to initialize `Gcs` client: {e}");
+            })?;
+            Some(Arc::new(gcs))
+        }
+    })
+}
+
+/// Creates the [`service::task::sync_listings::Feed`]s according to the
+/// provided [`config::ListingFeed`]s.
+///
+/// # Errors
+///
+/// If any [`config::ListingFeed::source`] is invalid or used more than once.
+fn listing_feeds(
+    config: Vec<config::ListingFeed>,
+) -> Result<Vec<service::task::sync_listings::Feed>, ()> {
+    let mut feeds = Vec::<service::task::sync_listings::Feed>::new();
+    for feed in config {
+        let config::ListingFeed {
+            source,
+            url,
+            api_key,
+            agency_id,
+            employer_id,
+            landlord_id,
+        } = feed;
+
+        let Some(source) = listing::Source::new(&source) else {
+            log::error!("invalid listing feed `source`: `{source}`");
+            return Err(());
+        };
+        if feeds.iter().any(|f| f.source == source) {
+            log::error!("duplicate listing feed `source`: `{source}`");
+            return Err(());
+        }
+
+        let api_key = api_key.map(SecretString::from);
+        feeds.push(service::task::sync_listings::Feed {
+            source,
+            agency_id,
+            employer_id,
+            landlord_id,
+            client: Arc::new(listing_feed::Http::new(url, api_key)),
+        });
+    }
+    Ok(feeds)
+}
+
+/// Creates the [`service::infra::SmsGateway`] according to the provided
+/// [`config::Sms`].
+///
+/// # Errors
+///
+/// If the credentials required by the [`config::SmsProvider`] are missing.
+fn sms_gateway(
+    config: config::Sms,
+) -> Result<Option<Arc<dyn service::infra::SmsGateway>>, ()> {
+    let config::Sms {
+        provider,
+        sandbox,
+        from,
+        status_callback_url,
+        twilio_account_sid,
+        twilio_auth_token,
+        http_url,
+        http_api_key,
+    } = config;
+
+    if sandbox {
+        return Ok(Some(Arc::new(sms::Sandbox)));
+    }
+    Ok(match provider {
+        None => None,
+        Some(config::SmsProvider::Twilio) => {
+            let (Some(sid), Some(token)) =
+                (twilio_account_sid, twilio_auth_token)
+            else {
+                log::error!(
+                    "`twilio_account_sid` and `twilio_auth_token` are \
+                     required by `twilio` SMS provider",
+                );
+                return Err(());
+            };
+            Some(Arc::new(Twilio::new(
+                sid,
+                SecretString::from(token),
+                from,
+                status_callback_url,
+            )))
+        }
+        Some(config::SmsProvider::Http) => {
+            let Some(url) = http_url else {
+                log::error!("`http_url` is required by `http` SMS provider");
+                return Err(());
+            };
+            Some(Arc::new(sms::Http::new(
+                url,
+                http_api_key.map(SecretString::from),
+                from,
+                status_callback_url,
+            )))
+        }
+    })
+}
+
+/// Resolves once the application receives `SIGINT` (Ctrl+C) or `SIGTERM`.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = signal::ctrl_c().await {
+            log::error!("failed to listen for `SIGINT`: {e}");
+            future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match signal::unix::signal(signal::unix::SignalKind::terminate()) {
+            Ok(mut s) => {
+                _ = s.recv().await;
+            }
+            Err(e) => {
+                log::error!("failed to listen for `SIGTERM`: {e}");
+                future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {},
+        () = terminate => {},
+    }
+
+    log::info!("shutdown signal received, draining connections");
+}
+
+#[cfg(test)]
+mod spec {
+    use application::config;
+
+    use super::parse_cors_origins;
+
+    fn cors(
+        origins: &[&str],
+        regexes: &[&str],
+        credentials: bool,
+    ) -> config::Cors {
+        config::Cors {
+            origins: origins.iter().map(ToString::to_string).collect(),
+            origin_regexes: regexes.iter().map(ToString::to_string).collect(),
+            credentials,
+            ..config::Cors::default()
+        }
+    }
+
+    #[test]
+    fn rejects_match_all_origins_with_credentials() {
+        let cases: [(&[&str], &[&str]); 5] = [
+            (&["*"], &[]),
+            (&["https://*"], &[]),
+            (&[], &[".*"]),
+            (&[], &["^.*$"]),
+            (&[], &["https?://.+"]),
+        ];
+        for (origins, regexes) in cases {
+            assert!(
+                parse_cors_origins(&cors(origins, regexes, true)).is_err(),
+                "{origins:?} {regexes:?} allowed with credentials",
+            );
+            assert!(
+                parse_cors_origins(&cors(origins, regexes, false)).is_ok(),
+                "{origins:?} {regexes:?} rejected without credentials",
+            );
+        }
+    }
+
+    #[test]
+    fn allows_specific_origins_with_credentials() {
+        let origins = parse_cors_origins(&cors(
+            &["https://example.com", "https://*.example.com"],
+            &[r"https://[a-z]+\.example\.org"],
+            true,
+        ))
+        .unwrap();
+
+        assert_eq!(origins.len(), 3);
+        for origin in [
+            "https://example.com",
+            "https://app.example.com",
+            "https://app.example.org",
+        ] {
+            assert!(origins.iter().any(|re| re.is_match(origin)), "{origin}");
+        }
+        assert!(!origins.iter().any(|re| re.is_match("https://example.net")));
+    }
 }
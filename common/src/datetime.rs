@@ -2,14 +2,20 @@
 
 #[cfg(feature = "postgres")]
 use std::error::Error as StdError;
-use std::{cmp::Ordering, marker::PhantomData, ops, time::Duration};
+use std::{
+    cmp::Ordering, marker::PhantomData, ops, str::FromStr, time::Duration,
+};
 
 use derive_more::{Debug, Display, Error};
 #[cfg(feature = "postgres")]
 use postgres_types::{
     accepts, private::BytesMut, to_sql_checked, FromSql, IsNull, ToSql, Type,
 };
-use time::{format_description::well_known::Rfc3339, UtcOffset};
+use time::{format_description::well_known::Rfc3339, Date, UtcOffset};
+use time_tz::{
+    timezones, OffsetDateTimeExt as _, OffsetResult, PrimitiveDateTimeExt as _,
+    TimeZone as _, Tz,
+};
 
 /// Untyped date and time.
 pub type DateTime = DateTimeOf;
@@ -66,6 +72,12 @@ impl<Of: ?Sized> DateTimeOf<Of> {
         self.inner.unix_timestamp()
     }
 
+    /// Returns the calendar year of this [`DateTime`] in UTC.
+    #[must_use]
+    pub fn year(&self) -> i32 {
+        self.inner.year()
+    }
+
     /// Creates a new [`DateTime`] from the provided [RFC 3339] string.
     ///
     /// # Errors
@@ -101,6 +113,164 @@ impl<Of: ?Sized> DateTimeOf<Of> {
             _of: PhantomData,
         }
     }
+
+    /// Converts this [`DateTime`] into the [`LocalDateTime`] of the provided
+    /// [`TimeZone`].
+    #[must_use]
+    pub fn in_tz(&self, tz: TimeZone) -> LocalDateTime {
+        LocalDateTime(self.inner.to_timezone(tz.0))
+    }
+
+    /// Returns the start of the day this [`DateTime`] belongs to in the
+    /// provided [`TimeZone`].
+    #[must_use]
+    pub fn start_of_day_in(&self, tz: TimeZone) -> Self {
+        Self::local_midnight(self.in_tz(tz).0.date(), tz)
+    }
+
+    /// Returns the start of the month this [`DateTime`] belongs to in the
+    /// provided [`TimeZone`].
+    #[must_use]
+    pub fn start_of_month_in(&self, tz: TimeZone) -> Self {
+        let date = self.in_tz(tz).0.date();
+        Self::local_midnight(date.replace_day(1).unwrap_or(date), tz)
+    }
+
+    /// Returns the last microsecond of the day this [`DateTime`] belongs to in
+    /// the provided [`TimeZone`].
+    #[must_use]
+    pub fn end_of_day_in(&self, tz: TimeZone) -> Self {
+        self.in_tz(tz).0.date().next_day().map_or(*self, |next| {
+            Self::local_midnight(next, tz) - Duration::from_micros(1)
+        })
+    }
+
+    /// Adds the provided number of calendar `months` to this [`DateTime`] in
+    /// UTC, clamping its day to the last day of the resulting month (so
+    /// `Jan 31` plus one month is `Feb 28` or `Feb 29`).
+    ///
+    /// [`None`] is returned if the result is out of the supported range.
+    #[must_use]
+    pub fn checked_add_months(self, months: u32) -> Option<Self> {
+        let date = self.inner.date();
+        let total = i64::from(date.year()) * 12
+            + i64::from(u8::from(date.month()) - 1)
+            + i64::from(months);
+        let year = i32::try_from(total.div_euclid(12)).ok()?;
+        let month = u8::try_from(total.rem_euclid(12) + 1)
+            .ok()
+            .and_then(|m| time::Month::try_from(m).ok())?;
+        let day = date.day().min(time::util::days_in_year_month(year, month));
+        Some(Self {
+            inner: self
+                .inner
+                .replace_date(Date::from_calendar_date(year, month, day).ok()?),
+            _of: PhantomData,
+        })
+    }
+
+    /// Returns the start of the provided [`Date`] in the provided
+    /// [`TimeZone`].
+    fn local_midnight(date: Date, tz: TimeZone) -> Self {
+        let midnight = date.midnight();
+        let local = match midnight.assume_timezone(tz.0) {
+            OffsetResult::Some(dt) | OffsetResult::Ambiguous(dt, _) => dt,
+            // Midnight is skipped by a DST transition, so the day starts
+            // right after it.
+            OffsetResult::None => midnight.assume_timezone_utc(tz.0),
+        };
+        Self {
+            inner: local.to_offset(UtcOffset::UTC),
+            _of: PhantomData,
+        }
+    }
+}
+
+/// [IANA time zone][1] (`Europe/Berlin`, for example).
+///
+/// [1]: https://www.iana.org/time-zones
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[debug("{}", _0.name())]
+#[display("{}", _0.name())]
+pub struct TimeZone(&'static Tz);
+
+impl TimeZone {
+    /// UTC [`TimeZone`].
+    pub const UTC: Self = Self(timezones::db::UTC);
+
+    /// Returns the [`TimeZone`] with the provided IANA name.
+    ///
+    /// [`None`] is returned if there is no such [`TimeZone`].
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        timezones::get_by_name(name).map(Self)
+    }
+
+    /// Returns the IANA name of this [`TimeZone`].
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.0.name()
+    }
+}
+
+impl Default for TimeZone {
+    fn default() -> Self {
+        Self::UTC
+    }
+}
+
+impl FromStr for TimeZone {
+    type Err = UnknownTimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(UnknownTimeZoneError)
+    }
+}
+
+/// Error of parsing an unknown [`TimeZone`].
+#[derive(Clone, Copy, Debug, Display, Error)]
+#[display("unknown time zone")]
+pub struct UnknownTimeZoneError;
+
+/// Date and time in a [`TimeZone`], as seen on its local calendar.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LocalDateTime(time::OffsetDateTime);
+
+impl LocalDateTime {
+    /// Creates a new [`LocalDateTime`] from the provided [RFC 3339] string,
+    /// keeping its UTC offset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid [RFC 3339] date and time.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    pub fn from_rfc3339(input: &str) -> Result<Self, ParseError> {
+        time::OffsetDateTime::parse(input, &Rfc3339)
+            .map(Self)
+            .map_err(ParseError::Parse)
+    }
+
+    /// Returns the [`LocalDateTime`] as an [RFC 3339] string, including its
+    /// UTC offset.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        self.0.format(&Rfc3339).unwrap_or_else(|e| {
+            panic!("cannot format `LocalDateTime` as RFC 3339: {e}")
+        })
+    }
+}
+
+impl<Of: ?Sized> From<LocalDateTime> for DateTimeOf<Of> {
+    fn from(dt: LocalDateTime) -> Self {
+        Self {
+            inner: dt.0.to_offset(UtcOffset::UTC),
+            _of: PhantomData,
+        }
+    }
 }
 
 /// Error of parsing [`DateTime`] from a string.
@@ -221,8 +391,29 @@ impl<Of: ?Sized> ToSql for DateTimeOf<Of> {
 pub mod serde {
     //! Module providing integration with [`serde`] crate.
 
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
     use super::DateTimeOf;
 
+    impl<Of: ?Sized> Serialize for DateTimeOf<Of> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_rfc3339())
+        }
+    }
+
+    impl<'de, Of: ?Sized> Deserialize<'de> for DateTimeOf<Of> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let s = String::deserialize(deserializer)?;
+            Self::from_rfc3339(&s).map_err(Error::custom)
+        }
+    }
+
     pub mod unix_timestamp {
         //! Module providing serialization and deserialization of [`DateTimeOf`]
         //! as a Unix timestamp.
@@ -300,4 +491,162 @@ mod juniper {
                 })
         }
     }
+
+    /// Date and time in a [RFC 3339] format with a microsecond precision and
+    /// a UTC offset of some time zone.
+    ///
+    /// [RFC 3339]: https://tools.ietf.org/html/rfc3339
+    #[graphql_scalar(with = Self, parse_token(String))]
+    type LocalDateTime = super::LocalDateTime;
+
+    impl LocalDateTime {
+        fn to_output<S: ScalarValue>(dt: &LocalDateTime) -> Value<S> {
+            Value::scalar(dt.to_rfc3339())
+        }
+
+        fn from_input<S: ScalarValue>(
+            input: &InputValue<S>,
+        ) -> Result<Self, String> {
+            input
+                .as_string_value()
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot parse `LocalDateTime` input scalar from \
+                         non-string value: {input}",
+                    )
+                })
+                .and_then(|s| {
+                    Self::from_rfc3339(s).map_err(|e| {
+                        format!(
+                            "Cannot parse `LocalDateTime` input scalar: {e}",
+                        )
+                    })
+                })
+        }
+    }
+
+    /// [IANA time zone] name (`Europe/Berlin`, for example).
+    ///
+    /// [IANA time zone]: https://www.iana.org/time-zones
+    #[graphql_scalar(with = Self, parse_token(String))]
+    type TimeZone = super::TimeZone;
+
+    impl TimeZone {
+        #[expect(
+            clippy::trivially_copy_pass_by_ref,
+            reason = "required by `juniper`"
+        )]
+        fn to_output<S: ScalarValue>(tz: &TimeZone) -> Value<S> {
+            Value::scalar(tz.name().to_owned())
+        }
+
+        fn from_input<S: ScalarValue>(
+            input: &InputValue<S>,
+        ) -> Result<Self, String> {
+            input
+                .as_string_value()
+                .ok_or_else(|| {
+                    format!(
+                        "Cannot parse `TimeZone` input scalar from \
+                         non-string value: {input}",
+                    )
+                })
+                .and_then(|s| {
+                    Self::from_name(s).ok_or_else(|| {
+                        format!("Cannot parse `TimeZone` input scalar: {s}")
+                    })
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::{DateTime, TimeZone};
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn parses_time_zone() {
+        assert_eq!(
+            "Europe/Berlin".parse::<TimeZone>().unwrap().name(),
+            "Europe/Berlin",
+        );
+        assert!("UTC".parse::<TimeZone>().is_ok());
+
+        assert!("".parse::<TimeZone>().is_err());
+        assert!("Mars/Olympus_Mons".parse::<TimeZone>().is_err());
+    }
+
+    #[test]
+    fn converts_into_time_zone() {
+        let tz = TimeZone::from_name("Europe/Moscow").unwrap();
+        let dt = datetime("2024-01-31T22:30:00Z");
+
+        assert_eq!(dt.in_tz(tz).to_rfc3339(), "2024-02-01T01:30:00+03:00");
+        assert_eq!(DateTime::from(dt.in_tz(tz)), dt);
+    }
+
+    #[test]
+    fn aligns_to_local_day() {
+        let tz = TimeZone::from_name("Europe/Moscow").unwrap();
+        let dt = datetime("2024-01-31T22:30:00Z");
+
+        assert_eq!(dt.start_of_day_in(tz), datetime("2024-01-31T21:00:00Z"));
+        assert_eq!(
+            dt.end_of_day_in(tz),
+            datetime("2024-02-01T20:59:59.999999Z"),
+        );
+
+        assert_eq!(
+            dt.start_of_day_in(TimeZone::UTC),
+            datetime("2024-01-31T00:00:00Z"),
+        );
+    }
+
+    #[test]
+    fn aligns_to_local_month() {
+        let tz = TimeZone::from_name("Europe/Moscow").unwrap();
+        let dt = datetime("2024-01-31T22:30:00Z");
+
+        assert_eq!(dt.start_of_month_in(tz), datetime("2024-01-31T21:00:00Z"));
+        assert_eq!(
+            dt.start_of_month_in(TimeZone::UTC),
+            datetime("2024-01-01T00:00:00Z"),
+        );
+    }
+
+    #[test]
+    fn adds_calendar_months() {
+        let dt = datetime("2024-01-31T10:00:00Z");
+
+        assert_eq!(dt.checked_add_months(0), Some(dt));
+        assert_eq!(
+            dt.checked_add_months(1),
+            Some(datetime("2024-02-29T10:00:00Z")),
+        );
+        assert_eq!(
+            dt.checked_add_months(13),
+            Some(datetime("2025-02-28T10:00:00Z")),
+        );
+        assert_eq!(
+            datetime("2024-11-15T00:00:00Z").checked_add_months(2),
+            Some(datetime("2025-01-15T00:00:00Z")),
+        );
+    }
+
+    #[test]
+    fn aligns_to_local_day_across_dst() {
+        let tz = TimeZone::from_name("Europe/Berlin").unwrap();
+
+        // Day of the spring DST transition is 23 hours long.
+        let dt = datetime("2024-03-31T12:00:00Z");
+        assert_eq!(dt.start_of_day_in(tz), datetime("2024-03-30T23:00:00Z"));
+        assert_eq!(
+            dt.end_of_day_in(tz),
+            datetime("2024-03-31T21:59:59.999999Z"),
+        );
+    }
 }
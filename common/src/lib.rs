@@ -39,7 +39,7 @@ mod percent;
 pub mod unit;
 
 pub use self::{
-    datetime::{DateTime, DateTimeOf},
+    datetime::{DateTime, DateTimeOf, LocalDateTime, TimeZone},
     handler::Handler,
     kind::FromParam,
     money::Money,
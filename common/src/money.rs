@@ -2,12 +2,16 @@
 
 use std::{fmt, str::FromStr};
 
-use rust_decimal::{prelude::ToPrimitive as _, Decimal};
+use rust_decimal::{prelude::ToPrimitive as _, Decimal, RoundingStrategy};
 
 use crate::define_kind;
 
 /// Amount of money in some [`Currency`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize)
+)]
 pub struct Money {
     /// Amount of this [`Money`].
     pub amount: Decimal,
@@ -16,6 +20,46 @@ pub struct Money {
     pub currency: Currency,
 }
 
+impl Money {
+    /// Formats this [`Money`] for displaying in the provided [`Locale`]: with
+    /// separated digit groups, two fractional digits and the [`Currency`]
+    /// symbol.
+    #[must_use]
+    pub fn format(&self, locale: Locale) -> String {
+        let (group, decimal) = match locale {
+            Locale::En => (",", "."),
+            Locale::Ru => ("\u{a0}", ","),
+        };
+
+        let amount = self
+            .amount
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        let digits = format!("{:.2}", amount.abs());
+        let (integer, fraction) =
+            digits.split_once('.').unwrap_or((&digits, "00"));
+
+        let mut number = String::with_capacity(integer.len() * 2);
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i).is_multiple_of(3) {
+                number.push_str(group);
+            }
+            number.push(digit);
+        }
+        let sign = if amount.is_sign_negative() && !amount.is_zero() {
+            "-"
+        } else {
+            ""
+        };
+        let symbol = self.currency.symbol();
+
+        let number = format!("{number}{decimal}{fraction}");
+        match locale {
+            Locale::En => format!("{sign}{symbol}{number}"),
+            Locale::Ru => format!("{sign}{number}\u{a0}{symbol}"),
+        }
+    }
+}
+
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let Self { amount, currency } = self;
@@ -58,6 +102,29 @@ define_kind! {
     }
 }
 
+impl Currency {
+    /// Returns the symbol of this [`Currency`].
+    #[must_use]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Usd => "$",
+            Self::Eur => "€",
+            Self::Rub => "₽",
+        }
+    }
+}
+
+/// Locale of [`Money::format()`]ting.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Locale {
+    /// English (`$1,234.56`).
+    #[default]
+    En,
+
+    /// Russian (`1 234,56 $`).
+    Ru,
+}
+
 #[cfg(feature = "juniper")]
 mod juniper {
     //! Module providing integration with [`juniper`] crate.
@@ -104,7 +171,7 @@ mod spec {
 
     use rust_decimal::Decimal;
 
-    use super::{Currency, Money};
+    use super::{Currency, Locale, Money};
 
     fn decimal(s: &str) -> Decimal {
         s.parse().unwrap()
@@ -199,4 +266,36 @@ mod spec {
             "123USD",
         );
     }
+
+    #[test]
+    fn format() {
+        let money = |amount, currency| Money {
+            amount: decimal(amount),
+            currency,
+        };
+
+        assert_eq!(
+            money("1234567.891", Currency::Usd).format(Locale::En),
+            "$1,234,567.89",
+        );
+        assert_eq!(
+            money("1234567.891", Currency::Rub).format(Locale::Ru),
+            "1\u{a0}234\u{a0}567,89\u{a0}₽",
+        );
+        assert_eq!(money("123", Currency::Eur).format(Locale::En), "€123.00");
+        assert_eq!(
+            money("123", Currency::Eur).format(Locale::Ru),
+            "123,00\u{a0}€",
+        );
+        assert_eq!(money("0.005", Currency::Usd).format(Locale::En), "$0.01");
+        assert_eq!(money("0", Currency::Usd).format(Locale::En), "$0.00");
+        assert_eq!(
+            money("-1000.5", Currency::Usd).format(Locale::En),
+            "-$1,000.50",
+        );
+        assert_eq!(
+            money("-0.001", Currency::Usd).format(Locale::En),
+            "$0.00",
+        );
+    }
 }
@@ -4,6 +4,10 @@ use std::fmt;
 
 /// Generic pagination connection.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize)
+)]
 pub struct Connection<C, I> {
     /// [`Edge`]s in this [`Connection`].
     pub edges: Vec<Edge<C, I>>,
@@ -62,6 +66,10 @@ pub struct PageInfo<C> {
 
 /// An edge in a [`Connection`].
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize)
+)]
 pub struct Edge<C, I> {
     /// Cursor of this [`Edge`].
     pub cursor: C,
@@ -104,6 +112,15 @@ pub enum Arguments<C> {
         /// result.
         including: bool,
     },
+
+    /// Offset pagination.
+    Offset {
+        /// Number of the page to return, starting from `1`.
+        page: usize,
+
+        /// Number of items on a page.
+        per_page: usize,
+    },
 }
 
 impl<C> Arguments<C> {
@@ -174,6 +191,29 @@ impl<C> Arguments<C> {
         })
     }
 
+    /// Creates a new offset [`Arguments`].
+    ///
+    /// `per_page` is capped by the provided `max` value.
+    ///
+    /// [`None`] is returned if `page` or `per_page` is not positive.
+    pub fn new_offset<Num>(
+        page: Option<Num>,
+        per_page: Option<Num>,
+        default: Num,
+        max: Num,
+    ) -> Option<Self>
+    where
+        Num: TryInto<usize>,
+    {
+        let page = page.map_or(Some(1), |p| p.try_into().ok())?;
+        let per_page = per_page.unwrap_or(default).try_into().ok()?;
+        let max = max.try_into().ok()?;
+        (page > 0 && per_page > 0).then(|| Self::Offset {
+            page,
+            per_page: per_page.min(max),
+        })
+    }
+
     /// Returns exact cursor requested by this [`Arguments`].
     pub fn exact_cursor(&self) -> Option<&C> {
         match self {
@@ -187,7 +227,9 @@ impl<C> Arguments<C> {
                 before,
                 including: true,
             } => before.as_ref(),
-            Self::Forward { .. } | Self::Backward { .. } => None,
+            Self::Forward { .. }
+            | Self::Backward { .. }
+            | Self::Offset { .. } => None,
         }
     }
 
@@ -197,6 +239,7 @@ impl<C> Arguments<C> {
         match self {
             Self::Forward { after, .. } => after.as_ref(),
             Self::Backward { before, .. } => before.as_ref(),
+            Self::Offset { .. } => None,
         }
     }
 
@@ -217,6 +260,7 @@ impl<C> Arguments<C> {
                     Kind::Backward
                 }
             }
+            Self::Offset { .. } => Kind::Forward,
         }
     }
 
@@ -226,6 +270,18 @@ impl<C> Arguments<C> {
         match *self {
             Self::Forward { first, .. } => first,
             Self::Backward { last, .. } => last,
+            Self::Offset { per_page, .. } => per_page,
+        }
+    }
+
+    /// Returns number of items to skip requested by this [`Arguments`].
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        match *self {
+            Self::Forward { .. } | Self::Backward { .. } => 0,
+            Self::Offset { page, per_page } => {
+                page.saturating_sub(1).saturating_mul(per_page)
+            }
         }
     }
 }
@@ -242,6 +298,10 @@ pub struct Selector<C, F> {
 
 /// Kind of pagination.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize)
+)]
 pub enum Kind {
     /// Forward pagination.
     Forward,
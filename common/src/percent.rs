@@ -7,12 +7,20 @@ use derive_more::Display;
 use postgres_types::{FromSql, ToSql};
 use rust_decimal::Decimal;
 
+use crate::Money;
+
 /// Floating-point percentage.
 #[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Percent(Decimal);
 
 impl Percent {
+    /// `0%`.
+    pub const ZERO: Self = Self(Decimal::ZERO);
+
+    /// `100%`.
+    pub const HUNDRED: Self = Self(Decimal::ONE_HUNDRED);
+
     /// Creates a new [`Percent`] by checking the provided values is
     /// greater than `0` and less than `100`.
     #[must_use]
@@ -39,6 +47,49 @@ impl Percent {
     pub unsafe fn new_unchecked(val: Decimal) -> Self {
         Self(val)
     }
+
+    /// Creates a new [`Percent`] by clamping the provided value into the
+    /// `[0..100]` range.
+    #[must_use]
+    pub fn clamped(val: Decimal) -> Self {
+        Self(val.clamp(Decimal::ZERO, Decimal::ONE_HUNDRED))
+    }
+
+    /// Creates a new [`Percent`] from the provided `ratio` (`0.25` being
+    /// `25%`) by clamping it into the `[0..1]` range.
+    #[must_use]
+    pub fn clamped_ratio(ratio: Decimal) -> Self {
+        Self(ratio.clamp(Decimal::ZERO, Decimal::ONE) * Decimal::ONE_HUNDRED)
+    }
+
+    /// Returns this [`Percent`] as a ratio (`25%` being `0.25`).
+    #[must_use]
+    pub fn ratio(self) -> Decimal {
+        self.0 / Decimal::ONE_HUNDRED
+    }
+
+    /// Calculates this [`Percent`] of the provided [`Money`].
+    #[must_use]
+    pub fn of(self, money: Money) -> Money {
+        Money {
+            amount: money.amount * self.ratio(),
+            currency: money.currency,
+        }
+    }
+
+    /// Adds the provided [`Percent`] to this one.
+    ///
+    /// Returns [`None`] if the sum exceeds `100%`.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(self.0 + rhs.0)
+    }
+
+    /// Adds the provided [`Percent`] to this one, saturating at `100%`.
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self::clamped(self.0 + rhs.0)
+    }
 }
 
 impl FromStr for Percent {
@@ -88,3 +139,67 @@ mod juniper {
         }
     }
 }
+
+#[cfg(test)]
+mod spec {
+    use rust_decimal::Decimal;
+
+    use crate::{money::Currency, Money};
+
+    use super::Percent;
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn percent(s: &str) -> Percent {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn clamped() {
+        assert_eq!(Percent::clamped(decimal("-1")), Percent::ZERO);
+        assert_eq!(Percent::clamped(decimal("42.5")), percent("42.5"));
+        assert_eq!(Percent::clamped(decimal("100.01")), Percent::HUNDRED);
+
+        assert_eq!(Percent::clamped_ratio(decimal("-0.1")), Percent::ZERO);
+        assert_eq!(Percent::clamped_ratio(decimal("0.25")), percent("25"));
+        assert_eq!(Percent::clamped_ratio(decimal("1.5")), Percent::HUNDRED);
+    }
+
+    #[test]
+    fn of() {
+        let money = Money {
+            amount: decimal("1234.50"),
+            currency: Currency::Usd,
+        };
+
+        assert_eq!(
+            percent("10").of(money),
+            Money {
+                amount: decimal("123.45"),
+                currency: Currency::Usd,
+            },
+        );
+        assert_eq!(Percent::ZERO.of(money).amount, Decimal::ZERO);
+        assert_eq!(Percent::HUNDRED.of(money), money);
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            percent("40").checked_add(percent("60")),
+            Some(Percent::HUNDRED),
+        );
+        assert_eq!(percent("40").checked_add(percent("60.1")), None);
+
+        assert_eq!(
+            percent("12.5").saturating_add(percent("30")),
+            percent("42.5"),
+        );
+        assert_eq!(
+            percent("70").saturating_add(percent("70")),
+            Percent::HUNDRED,
+        );
+    }
+}
@@ -0,0 +1,9 @@
+CREATE TABLE task_runs (
+    task         TEXT NOT NULL,
+    started_at   TIMESTAMPTZ NOT NULL,
+    duration_ms  INT8 NOT NULL CHECK (duration_ms >= 0),
+    processed    INT8 NOT NULL CHECK (processed >= 0),
+    error        TEXT,
+    PRIMARY KEY (task, started_at)
+);
+CREATE INDEX idx_task_runs_started_at ON task_runs (started_at DESC);
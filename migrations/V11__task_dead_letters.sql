@@ -0,0 +1,12 @@
+CREATE TABLE task_dead_letters (
+    task       TEXT NOT NULL,
+    item       TEXT NOT NULL,
+    error      TEXT NOT NULL,
+    attempts   INT4 NOT NULL CHECK (attempts > 0),
+    failed_at  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (task, item)
+);
+
+-- For `taskDeadLetters` query.
+CREATE INDEX idx_task_dead_letters_failed_at
+    ON task_dead_letters (failed_at DESC);
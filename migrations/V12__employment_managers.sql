@@ -0,0 +1,8 @@
+ALTER TABLE contracts
+    ADD COLUMN manager_id UUID REFERENCES users ON UPDATE RESTRICT
+                                               ON DELETE RESTRICT;
+
+-- For `myTeam` query.
+CREATE INDEX idx_contracts_manager_id
+    ON contracts (manager_id)
+    WHERE manager_id IS NOT NULL;
@@ -0,0 +1,38 @@
+CREATE TABLE agencies (
+    id          UUID NOT NULL PRIMARY KEY,
+    name        VARCHAR NOT NULL CHECK (length(name) > 0),
+    admin_id    UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                              ON DELETE RESTRICT,
+    created_at  TIMESTAMPTZ NOT NULL,
+    version     INT4 NOT NULL DEFAULT 0
+);
+
+-- Default agency owning everything created before, administrated by the
+-- platform administrator.
+INSERT INTO agencies (id, name, admin_id, created_at)
+VALUES (
+    '00000000-0000-0000-0000-000000000001',
+    'Default agency',
+    '00000000-0000-0000-0000-000000000001',
+    '2024-01-01 00:00:00'
+);
+
+ALTER TABLE realties
+    ADD COLUMN agency_id UUID NOT NULL
+                         DEFAULT '00000000-0000-0000-0000-000000000001'
+                         REFERENCES agencies ON UPDATE RESTRICT
+                                             ON DELETE RESTRICT;
+ALTER TABLE realties ALTER COLUMN agency_id DROP DEFAULT;
+
+-- The same `Realty` may be listed by different agencies independently.
+ALTER TABLE realties DROP CONSTRAINT realties_hash_key;
+ALTER TABLE realties ADD UNIQUE (agency_id, hash);
+
+ALTER TABLE contracts
+    ADD COLUMN agency_id UUID NOT NULL
+                         DEFAULT '00000000-0000-0000-0000-000000000001'
+                         REFERENCES agencies ON UPDATE RESTRICT
+                                             ON DELETE RESTRICT;
+ALTER TABLE contracts ALTER COLUMN agency_id DROP DEFAULT;
+
+CREATE INDEX idx_contracts_agency_id ON contracts (agency_id);
@@ -0,0 +1,17 @@
+CREATE TABLE branches (
+    id          UUID NOT NULL PRIMARY KEY,
+    agency_id   UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                ON DELETE RESTRICT,
+    name        VARCHAR NOT NULL CHECK (length(name) > 0),
+    address     VARCHAR NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL
+);
+
+ALTER TABLE contracts
+    ADD COLUMN branch_id UUID REFERENCES branches ON UPDATE RESTRICT
+                                                  ON DELETE RESTRICT;
+
+-- For `branch` filters of `users` and `contracts` queries.
+CREATE INDEX idx_contracts_branch_id
+    ON contracts (branch_id)
+    WHERE branch_id IS NOT NULL;
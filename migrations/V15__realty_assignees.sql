@@ -0,0 +1,8 @@
+ALTER TABLE realties
+    ADD COLUMN assignee_id UUID REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT;
+
+-- For `myAssignedRealties` query.
+CREATE INDEX idx_realties_assignee_id
+    ON realties (assignee_id)
+    WHERE assignee_id IS NOT NULL;
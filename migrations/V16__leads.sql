@@ -0,0 +1,29 @@
+CREATE TABLE leads (
+    id          UUID NOT NULL PRIMARY KEY,
+    agency_id   UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                ON DELETE RESTRICT,
+    source      VARCHAR NOT NULL CHECK (length(source) > 0),
+    stage       INT2 NOT NULL,
+    user_id     UUID REFERENCES users ON UPDATE RESTRICT
+                                      ON DELETE RESTRICT,
+    realty_id   UUID REFERENCES realties ON UPDATE RESTRICT
+                                         ON DELETE SET NULL,
+    created_at  TIMESTAMPTZ NOT NULL,
+    version     INT4 NOT NULL DEFAULT 0
+);
+
+-- For `leads` query with `stages` filter.
+CREATE INDEX idx_leads_agency_id_stage ON leads (agency_id, stage);
+
+CREATE TABLE lead_transitions (
+    lead_id       UUID NOT NULL REFERENCES leads ON UPDATE RESTRICT
+                                                 ON DELETE CASCADE,
+    from_stage    INT2,
+    to_stage      INT2 NOT NULL,
+    initiator_id  UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    created_at    TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_lead_transitions_lead_id
+    ON lead_transitions (lead_id, created_at);
@@ -0,0 +1,26 @@
+CREATE TABLE offers (
+    id              UUID NOT NULL PRIMARY KEY,
+    agency_id       UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                    ON DELETE RESTRICT,
+    contract_id     UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                     ON DELETE CASCADE,
+    realty_id       UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                    ON DELETE CASCADE,
+    bidder_id       UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    employer_id     UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    price           NUMERIC NOT NULL,
+    price_currency  INT2 NOT NULL CHECK (price_currency BETWEEN 1 AND 3),
+    status          INT2 NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL,
+    decided_at      TIMESTAMPTZ,
+    version         INT4 NOT NULL DEFAULT 0
+);
+COMMENT ON COLUMN offers.price_currency
+        IS '1 - USD, 2 - EUR, 3 - RUB';
+COMMENT ON COLUMN offers.status
+        IS '1 - pending, 2 - accepted, 3 - declined';
+
+-- For `Placement.offers` history.
+CREATE INDEX idx_offers_realty_id ON offers (realty_id, created_at);
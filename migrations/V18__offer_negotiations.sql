@@ -0,0 +1,20 @@
+ALTER TABLE offers
+    ADD COLUMN proposer_id UUID REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    ADD COLUMN previous_id UUID REFERENCES offers ON UPDATE RESTRICT
+                                                  ON DELETE CASCADE,
+    ADD COLUMN concluded_contract_id UUID REFERENCES contracts
+                                          ON UPDATE RESTRICT
+                                          ON DELETE SET NULL;
+
+-- All the `Offer`s submitted before were proposed by their bidders.
+UPDATE offers SET proposer_id = bidder_id;
+ALTER TABLE offers ALTER COLUMN proposer_id SET NOT NULL;
+
+COMMENT ON COLUMN offers.status
+        IS '1 - pending, 2 - accepted, 3 - declined, 4 - countered';
+
+-- For `Contract.negotiationHistory` query.
+CREATE INDEX idx_offers_concluded_contract_id
+    ON offers (concluded_contract_id)
+    WHERE concluded_contract_id IS NOT NULL;
@@ -0,0 +1,22 @@
+CREATE TABLE reviews (
+    id           UUID NOT NULL PRIMARY KEY,
+    agency_id    UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    contract_id  UUID NOT NULL UNIQUE REFERENCES contracts
+                                      ON UPDATE RESTRICT
+                                      ON DELETE CASCADE,
+    employer_id  UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                              ON DELETE RESTRICT,
+    author_id    UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                              ON DELETE RESTRICT,
+    rating       INT2 NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    comment      VARCHAR CHECK (length(comment) > 0),
+    status       INT2 NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL,
+    version      INT4 NOT NULL DEFAULT 0
+);
+COMMENT ON COLUMN reviews.status
+        IS '1 - published, 2 - hidden';
+
+-- For `User.rating` and `User.reviews`.
+CREATE INDEX idx_reviews_employer_id ON reviews (employer_id, created_at);
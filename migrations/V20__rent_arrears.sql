@@ -0,0 +1,36 @@
+CREATE TABLE rent_payments (
+    id               UUID NOT NULL PRIMARY KEY,
+    agency_id        UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                     ON DELETE RESTRICT,
+    contract_id      UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                      ON DELETE CASCADE,
+    amount           NUMERIC NOT NULL CHECK (amount > 0),
+    amount_currency  INT2 NOT NULL,
+    recorder_id      UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                  ON DELETE RESTRICT,
+    paid_at          TIMESTAMPTZ NOT NULL
+);
+
+-- For `RentContract.payments` and arrears detection.
+CREATE INDEX idx_rent_payments_contract_id ON rent_payments (contract_id,
+                                                            paid_at);
+
+CREATE TABLE rent_arrears (
+    contract_id      UUID NOT NULL PRIMARY KEY REFERENCES contracts
+                                               ON UPDATE RESTRICT
+                                               ON DELETE CASCADE,
+    agency_id        UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                     ON DELETE RESTRICT,
+    purchaser_id     UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                  ON DELETE RESTRICT,
+    employer_id      UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                  ON DELETE RESTRICT,
+    amount           NUMERIC NOT NULL CHECK (amount > 0),
+    amount_currency  INT2 NOT NULL,
+    overdue_since    TIMESTAMPTZ NOT NULL,
+    detected_at      TIMESTAMPTZ NOT NULL
+);
+
+-- For `arrearsReport`.
+CREATE INDEX idx_rent_arrears_agency_id ON rent_arrears (agency_id,
+                                                         overdue_since);
@@ -0,0 +1,17 @@
+CREATE TABLE rent_late_fees (
+    contract_id         UUID NOT NULL PRIMARY KEY REFERENCES contracts
+                                                  ON UPDATE RESTRICT
+                                                  ON DELETE CASCADE,
+    fixed_fee           NUMERIC,
+    fixed_fee_currency  INT2,
+    percent_fee         NUMERIC,
+    cap                 NUMERIC,
+    cap_currency        INT2,
+    accrued             NUMERIC NOT NULL DEFAULT 0 CHECK (accrued >= 0),
+    accrued_currency    INT2 NOT NULL,
+    accrued_until       TIMESTAMPTZ,
+    CHECK ((fixed_fee IS NULL) <> (percent_fee IS NULL))
+);
+
+ALTER TABLE rent_arrears
+    ADD COLUMN late_fees NUMERIC NOT NULL DEFAULT 0 CHECK (late_fees >= 0);
@@ -0,0 +1,18 @@
+CREATE TABLE rent_charges (
+    id               UUID NOT NULL PRIMARY KEY,
+    agency_id        UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                     ON DELETE RESTRICT,
+    contract_id      UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                      ON DELETE CASCADE,
+    name             VARCHAR(256) NOT NULL,
+    amount           NUMERIC NOT NULL CHECK (amount > 0),
+    amount_currency  INT2 NOT NULL,
+    bearer           INT2 NOT NULL,
+    creator_id       UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    created_at       TIMESTAMPTZ NOT NULL
+);
+
+-- For `RentContract.charges`, `RentContract.statement` and `revenueReport`.
+CREATE INDEX idx_rent_charges_contract_id ON rent_charges (contract_id,
+                                                          created_at);
@@ -0,0 +1,25 @@
+CREATE TABLE rent_inventories (
+    id           UUID NOT NULL PRIMARY KEY,
+    agency_id    UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    contract_id  UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                  ON DELETE CASCADE,
+    stage        INT2 NOT NULL,
+    recorder_id  UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                             ON DELETE RESTRICT,
+    recorded_at  TIMESTAMPTZ NOT NULL,
+    UNIQUE (contract_id, stage)
+);
+
+CREATE TABLE rent_inventory_items (
+    inventory_id  UUID NOT NULL REFERENCES rent_inventories
+                                ON UPDATE RESTRICT
+                                ON DELETE CASCADE,
+    position      INT4 NOT NULL,
+    room          VARCHAR(256) NOT NULL,
+    name          VARCHAR(256) NOT NULL,
+    condition     INT2 NOT NULL,
+    photos        VARCHAR(2048)[] NOT NULL DEFAULT '{}',
+    PRIMARY KEY (inventory_id, position),
+    UNIQUE (inventory_id, room, name)
+);
@@ -0,0 +1,23 @@
+CREATE TABLE realty_keys (
+    id              UUID NOT NULL PRIMARY KEY,
+    agency_id       UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                    ON DELETE RESTRICT,
+    realty_id       UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                    ON DELETE CASCADE,
+    label           VARCHAR(256) NOT NULL,
+    holder_id       UUID REFERENCES users ON UPDATE RESTRICT
+                                          ON DELETE RESTRICT,
+    issuer_id       UUID REFERENCES users ON UPDATE RESTRICT
+                                          ON DELETE RESTRICT,
+    checked_out_at  TIMESTAMPTZ,
+    due_at          TIMESTAMPTZ,
+    created_at      TIMESTAMPTZ NOT NULL,
+    UNIQUE (realty_id, label),
+    CHECK ((holder_id IS NULL) = (issuer_id IS NULL)
+       AND (holder_id IS NULL) = (checked_out_at IS NULL)
+       AND (holder_id IS NULL) = (due_at IS NULL))
+);
+
+-- For `overdueKeys`.
+CREATE INDEX idx_realty_keys_overdue ON realty_keys (agency_id, due_at)
+                                     WHERE due_at IS NOT NULL;
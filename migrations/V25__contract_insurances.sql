@@ -0,0 +1,22 @@
+CREATE TABLE contract_insurances (
+    id                 UUID NOT NULL PRIMARY KEY,
+    agency_id          UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                       ON DELETE RESTRICT,
+    contract_id        UUID NOT NULL UNIQUE REFERENCES contracts
+                                            ON UPDATE RESTRICT
+                                            ON DELETE CASCADE,
+    provider           VARCHAR(256) NOT NULL,
+    policy_number      VARCHAR(64) NOT NULL,
+    coverage           NUMERIC NOT NULL CHECK (coverage > 0),
+    coverage_currency  INT2 NOT NULL,
+    expires_at         TIMESTAMPTZ NOT NULL,
+    creator_id         UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                   ON DELETE RESTRICT,
+    created_at         TIMESTAMPTZ NOT NULL,
+    notified_at        TIMESTAMPTZ
+);
+
+-- For `NotifyExpiringInsurance` task.
+CREATE INDEX idx_contract_insurances_unnotified
+          ON contract_insurances (expires_at, id)
+          WHERE notified_at IS NULL;
@@ -0,0 +1,10 @@
+CREATE TABLE journal_exports (
+    payment_id   UUID NOT NULL PRIMARY KEY REFERENCES rent_payments
+                                           ON UPDATE RESTRICT
+                                           ON DELETE CASCADE,
+    exported_at  TIMESTAMPTZ NOT NULL
+);
+
+-- For accounting journal export.
+CREATE INDEX idx_rent_payments_agency_id ON rent_payments (agency_id,
+                                                          paid_at);
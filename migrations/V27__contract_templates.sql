@@ -0,0 +1,13 @@
+CREATE TABLE contract_templates (
+    id           UUID NOT NULL PRIMARY KEY,
+    agency_id    UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    name         VARCHAR(512) NOT NULL,
+    description  VARCHAR(512) NOT NULL,
+    creator_id   UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                             ON DELETE RESTRICT,
+    created_at   TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_contract_templates_agency_id
+          ON contract_templates (agency_id, created_at);
@@ -0,0 +1,45 @@
+CREATE TABLE contract_number_sequences (
+    agency_id   UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                ON DELETE CASCADE,
+    kind        INT2 NOT NULL,
+    year        INT4 NOT NULL,
+    last_value  INT4 NOT NULL CHECK (last_value > 0),
+    PRIMARY KEY (agency_id, kind, year)
+);
+
+ALTER TABLE contracts ADD COLUMN number VARCHAR(32);
+
+-- Number the existing `contracts` in the order of their creation.
+WITH numbered AS (
+    SELECT id, kind,
+           EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC')::INT4 AS year,
+           ROW_NUMBER() OVER (
+               PARTITION BY agency_id, kind,
+                            EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC')
+               ORDER BY created_at, id
+           )::TEXT AS seq
+    FROM contracts
+)
+UPDATE contracts c
+   SET number = CASE n.kind WHEN 1 THEN 'RENT'
+                            WHEN 2 THEN 'SALE'
+                            WHEN 3 THEN 'MGMR'
+                            WHEN 4 THEN 'MGMS'
+                            ELSE 'EMPL'
+                END
+                || '-' || LPAD(n.year::TEXT, 4, '0')
+                || '-' || LPAD(n.seq, GREATEST(LENGTH(n.seq), 5), '0')
+  FROM numbered n
+ WHERE c.id = n.id;
+
+INSERT INTO contract_number_sequences (agency_id, kind, year, last_value)
+     SELECT agency_id, kind,
+            EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC')::INT4,
+            COUNT(*)::INT4
+       FROM contracts
+   GROUP BY 1, 2, 3;
+
+ALTER TABLE contracts ALTER COLUMN number SET NOT NULL;
+
+CREATE UNIQUE INDEX idx_contracts_agency_id_number
+                 ON contracts (agency_id, number);
@@ -0,0 +1,19 @@
+CREATE TABLE contract_approvals (
+    contract_id   UUID NOT NULL PRIMARY KEY REFERENCES contracts
+                                            ON UPDATE RESTRICT
+                                            ON DELETE CASCADE,
+    agency_id     UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                  ON DELETE RESTRICT,
+    requester_id  UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                               ON DELETE RESTRICT,
+    requested_at  TIMESTAMPTZ NOT NULL,
+    approver_id   UUID REFERENCES users ON UPDATE RESTRICT
+                                      ON DELETE RESTRICT,
+    approved_at   TIMESTAMPTZ,
+    CHECK ((approver_id IS NULL) = (approved_at IS NULL))
+);
+
+-- For `pendingContractApprovals` query.
+CREATE INDEX idx_contract_approvals_pending
+          ON contract_approvals (agency_id, requested_at)
+          WHERE approved_at IS NULL;
@@ -0,0 +1,27 @@
+CREATE TABLE contract_parties (
+    contract_id  UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                  ON DELETE CASCADE,
+    user_id      UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                              ON DELETE RESTRICT,
+    role         INT2 NOT NULL,
+    signed_at    TIMESTAMPTZ,
+    PRIMARY KEY (contract_id, user_id, role)
+);
+
+-- For `signContract` mutation.
+CREATE INDEX idx_contract_parties_user_id ON contract_parties (user_id);
+
+-- Migrate the single landlord, purchaser and employer of the existing
+-- `contracts` as their primary parties, considering them signed on creation.
+INSERT INTO contract_parties (contract_id, user_id, role, signed_at)
+     SELECT id, landlord_id, 1, created_at
+       FROM contracts
+      WHERE landlord_id IS NOT NULL
+  UNION ALL
+     SELECT id, purchaser_id, 2, created_at
+       FROM contracts
+      WHERE purchaser_id IS NOT NULL
+  UNION ALL
+     SELECT id, employer_id, 3, created_at
+       FROM contracts
+      WHERE employer_id IS NOT NULL;
@@ -0,0 +1,8 @@
+CREATE TABLE realty_ownerships (
+    realty_id  UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                               ON DELETE CASCADE,
+    owner_id   UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                            ON DELETE RESTRICT,
+    share      NUMERIC NOT NULL CHECK (share > 0 AND share <= 100),
+    PRIMARY KEY (realty_id, owner_id)
+);
@@ -0,0 +1,8 @@
+CREATE TABLE contract_commission_splits (
+    contract_id  UUID NOT NULL REFERENCES contracts ON UPDATE RESTRICT
+                                                 ON DELETE CASCADE,
+    employer_id  UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                             ON DELETE RESTRICT,
+    share        NUMERIC NOT NULL CHECK (share > 0 AND share <= 100),
+    PRIMARY KEY (contract_id, employer_id)
+);
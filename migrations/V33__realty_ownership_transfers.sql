@@ -0,0 +1,26 @@
+CREATE TABLE realty_ownership_transfers (
+    contract_id     UUID NOT NULL PRIMARY KEY REFERENCES contracts
+                                              ON UPDATE RESTRICT
+                                              ON DELETE CASCADE,
+    realty_id       UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                    ON DELETE CASCADE,
+    seller_id       UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                 ON DELETE RESTRICT,
+    buyer_id        UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                                ON DELETE RESTRICT,
+    transferred_at  TIMESTAMPTZ NOT NULL
+);
+
+-- For `Realty.ownershipHistory` field.
+CREATE INDEX idx_realty_ownership_transfers_realty_id
+          ON realty_ownership_transfers (realty_id, transferred_at);
+
+-- Record the ownership transfers of the already completed sale contracts.
+INSERT INTO realty_ownership_transfers (
+    contract_id, realty_id, seller_id, buyer_id, transferred_at
+)
+     SELECT id, realty_id, landlord_id, purchaser_id, completed_at
+       FROM contracts
+      WHERE kind = 2
+        AND completed_at IS NOT NULL
+        AND terminated_at IS NULL;
@@ -0,0 +1,27 @@
+ALTER TABLE contracts DROP CONSTRAINT contracts_kind_check;
+ALTER TABLE contracts ADD CONSTRAINT contracts_kind_check
+                      CHECK (kind BETWEEN 1 AND 6);
+COMMENT ON COLUMN contracts.kind
+        IS '1 - rent, 2 - sale, 3 - management for rent, 4 - management for sale, 5 - employment, 6 - short-term rent';
+
+ALTER TABLE contracts ADD COLUMN nightly_rate NUMERIC,
+                      ADD COLUMN check_in_at TIMESTAMPTZ;
+
+-- For availability `Calendar` of a `Realty`.
+CREATE INDEX idx_contracts_realty_id_kind_pending
+    ON contracts (realty_id, kind)
+    WHERE completed_at IS NULL AND terminated_at IS NULL;
+
+CREATE TABLE realty_seasonal_rates (
+    realty_id              UUID NOT NULL REFERENCES realties
+                                         ON UPDATE RESTRICT
+                                         ON DELETE CASCADE,
+    starts_at              TIMESTAMPTZ NOT NULL,
+    ends_at                TIMESTAMPTZ NOT NULL CHECK (ends_at > starts_at),
+    nightly_rate           NUMERIC NOT NULL CHECK (nightly_rate > 0),
+    nightly_rate_currency  INT2 NOT NULL
+                           CHECK (nightly_rate_currency BETWEEN 1 AND 3),
+    PRIMARY KEY (realty_id, starts_at)
+);
+COMMENT ON COLUMN realty_seasonal_rates.nightly_rate_currency
+        IS '1 - USD, 2 - EUR, 3 - RUB';
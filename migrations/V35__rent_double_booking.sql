@@ -0,0 +1,35 @@
+-- Mirrors whether a `Contract` is pending a `ContractApproval`, so the rents
+-- pending approval may be excluded from the index below, the same way they
+-- are not considered renting a `Realty` yet.
+ALTER TABLE contracts
+    ADD COLUMN is_pending_approval BOOLEAN NOT NULL DEFAULT FALSE;
+UPDATE contracts
+   SET is_pending_approval = TRUE
+ WHERE id IN (SELECT contract_id
+                FROM contract_approvals
+               WHERE approved_at IS NULL);
+
+-- Terminate the open-ended rent `Contract`s double booking a `Realty`, keeping
+-- the earliest one only, so the index below may be created.
+UPDATE contracts
+   SET terminated_at = NOW(),
+       version = version + 1
+ WHERE id IN (SELECT id
+                FROM (SELECT id,
+                             ROW_NUMBER() OVER (PARTITION BY realty_id
+                                                ORDER BY created_at, id) AS n
+                        FROM contracts
+                       WHERE kind = 1
+                         AND expires_at IS NULL
+                         AND terminated_at IS NULL
+                         AND NOT is_pending_approval) AS rents
+               WHERE n > 1);
+
+-- At most one open-ended rent `Contract` may occupy a `Realty` at a time.
+-- Rents with an expiration are guarded by the `Realty` advisory lock instead.
+CREATE UNIQUE INDEX idx_contracts_realty_id_open_rent
+                 ON contracts (realty_id)
+              WHERE kind = 1
+                AND expires_at IS NULL
+                AND terminated_at IS NULL
+                AND NOT is_pending_approval;
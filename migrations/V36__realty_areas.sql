@@ -0,0 +1,2 @@
+ALTER TABLE realties ADD COLUMN area NUMERIC CHECK (area > 0);
+COMMENT ON COLUMN realties.area IS 'Floor area in square meters';
@@ -0,0 +1,14 @@
+CREATE TABLE agency_settings (
+    agency_id             UUID NOT NULL PRIMARY KEY
+                               REFERENCES agencies ON UPDATE RESTRICT
+                                                   ON DELETE CASCADE,
+    currency              INT2 CHECK (currency BETWEEN 1 AND 3),
+    contract_duration_ms  INT8 CHECK (contract_duration_ms > 0),
+    commission            NUMERIC CHECK (commission BETWEEN 0 AND 100),
+    locale                INT2 CHECK (locale BETWEEN 1 AND 2),
+    updated_at            TIMESTAMPTZ NOT NULL
+);
+COMMENT ON COLUMN agency_settings.currency
+        IS '1 - USD, 2 - EUR, 3 - RUB';
+COMMENT ON COLUMN agency_settings.locale
+        IS '1 - en, 2 - ru';
@@ -0,0 +1,6 @@
+ALTER TABLE contracts ADD COLUMN placed_at TIMESTAMPTZ;
+COMMENT ON COLUMN contracts.placed_at
+        IS 'When the contract was placed the last time';
+
+-- Placement history isn't known, so consider them placed on creation.
+UPDATE contracts SET placed_at = created_at WHERE is_placed;
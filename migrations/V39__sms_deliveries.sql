@@ -0,0 +1,10 @@
+CREATE TABLE sms_deliveries (
+    id           UUID NOT NULL PRIMARY KEY,
+    phone        VARCHAR NOT NULL CHECK (length(phone) > 0),
+    provider_id  VARCHAR UNIQUE CHECK (length(provider_id) > 0),
+    status       INT2 NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL,
+    updated_at   TIMESTAMPTZ NOT NULL
+);
+COMMENT ON COLUMN sms_deliveries.status
+        IS '1 - sandboxed, 2 - queued, 3 - sent, 4 - delivered, 5 - failed';
@@ -0,0 +1,10 @@
+CREATE TABLE realty_floor_plans (
+    realty_id  UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                 ON DELETE CASCADE,
+    floor      INT4 NOT NULL CHECK (floor >= 0),
+    label      VARCHAR(256) NOT NULL CHECK (length(label) > 0),
+    format     INT2 NOT NULL CHECK (format BETWEEN 1 AND 2),
+    url        VARCHAR(2048) NOT NULL CHECK (length(url) > 0),
+    PRIMARY KEY (realty_id, floor, label)
+);
+COMMENT ON COLUMN realty_floor_plans.format IS '1 - IMAGE, 2 - PDF';
@@ -0,0 +1,8 @@
+CREATE TABLE realty_media_links (
+    realty_id  UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                 ON DELETE CASCADE,
+    position   INT2 NOT NULL CHECK (position >= 0),
+    url        VARCHAR(2048) NOT NULL CHECK (url LIKE 'https://%'),
+    PRIMARY KEY (realty_id, position),
+    UNIQUE (realty_id, url)
+);
@@ -0,0 +1,24 @@
+CREATE TABLE external_listings (
+    source       VARCHAR(64) NOT NULL,
+    external_id  VARCHAR(256) NOT NULL,
+    realty_id    UUID NOT NULL REFERENCES realties ON UPDATE RESTRICT
+                                                   ON DELETE CASCADE,
+    contract_id  UUID REFERENCES contracts ON UPDATE RESTRICT
+                                           ON DELETE SET NULL,
+    imported_at  TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (source, external_id)
+);
+CREATE INDEX idx_external_listings_realty_id ON external_listings (realty_id);
+
+CREATE TABLE listing_syncs (
+    source        VARCHAR(64) NOT NULL,
+    started_at    TIMESTAMPTZ NOT NULL,
+    finished_at   TIMESTAMPTZ NOT NULL,
+    imported      INT4 NOT NULL CHECK (imported >= 0),
+    deduplicated  INT4 NOT NULL CHECK (deduplicated >= 0),
+    unchanged     INT4 NOT NULL CHECK (unchanged >= 0),
+    rejected      INT4 NOT NULL CHECK (rejected >= 0),
+    error         TEXT,
+    PRIMARY KEY (source, started_at)
+);
+CREATE INDEX idx_listing_syncs_started_at ON listing_syncs (started_at DESC);
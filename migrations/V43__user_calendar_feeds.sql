@@ -0,0 +1,7 @@
+CREATE TABLE user_calendar_feeds (
+    user_id     UUID NOT NULL PRIMARY KEY REFERENCES users
+                                          ON UPDATE RESTRICT
+                                          ON DELETE CASCADE,
+    token_hash  VARCHAR(64) NOT NULL UNIQUE,
+    created_at  TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,21 @@
+CREATE TABLE saved_searches (
+    id              UUID NOT NULL PRIMARY KEY,
+    user_id         UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                               ON DELETE CASCADE,
+    agency_id       UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                  ON DELETE CASCADE,
+    city            VARCHAR,
+    min_price       NUMERIC CHECK (min_price >= 0),
+    max_price       NUMERIC CHECK (max_price >= min_price),
+    price_currency  INT2,
+    kind            INT2,
+    created_at      TIMESTAMPTZ NOT NULL,
+    alerted_at      TIMESTAMPTZ NOT NULL,
+    CHECK ((min_price IS NULL AND max_price IS NULL)
+           = (price_currency IS NULL))
+);
+CREATE INDEX idx_saved_searches_user_id ON saved_searches (user_id);
+
+-- For `AlertSavedSearches` task.
+CREATE INDEX idx_contracts_placed_at ON contracts (placed_at)
+WHERE is_placed;
@@ -0,0 +1,15 @@
+CREATE TABLE audit_entries (
+    id          UUID NOT NULL PRIMARY KEY,
+    agency_id   UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                              ON DELETE CASCADE,
+    actor_id    UUID NOT NULL REFERENCES users ON UPDATE RESTRICT
+                                           ON DELETE RESTRICT,
+    action      INT2 NOT NULL,
+    entity      INT2 NOT NULL,
+    entity_id   UUID NOT NULL,
+    before      JSONB,
+    after       JSONB,
+    created_at  TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_audit_entries_agency_id_created_at
+    ON audit_entries (agency_id, created_at);
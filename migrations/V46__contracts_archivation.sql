@@ -0,0 +1,6 @@
+ALTER TABLE contracts ADD COLUMN archived_at TIMESTAMPTZ;
+
+-- For the `Contract`s lists excluding the archived ones by default.
+CREATE INDEX idx_contracts_agency_id_unarchived
+    ON contracts (agency_id)
+    WHERE archived_at IS NULL;
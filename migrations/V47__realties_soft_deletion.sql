@@ -0,0 +1,6 @@
+ALTER TABLE realties ADD COLUMN deleted_at TIMESTAMPTZ;
+
+-- For `CleanUnusedRealties` task purging the deleted realties.
+CREATE INDEX idx_realties_deleted_at
+    ON realties (deleted_at)
+    WHERE deleted_at IS NOT NULL;
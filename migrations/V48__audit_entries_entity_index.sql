@@ -0,0 +1,2 @@
+CREATE INDEX idx_audit_entries_entity_entity_id_created_at
+    ON audit_entries (entity, entity_id, created_at);
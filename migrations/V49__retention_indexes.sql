@@ -0,0 +1,3 @@
+CREATE INDEX idx_audit_entries_created_at ON audit_entries (created_at);
+
+CREATE INDEX idx_sms_deliveries_updated_at ON sms_deliveries (updated_at);
@@ -0,0 +1,11 @@
+CREATE TABLE user_invites (
+    user_id     UUID NOT NULL PRIMARY KEY REFERENCES users
+                                          ON UPDATE RESTRICT
+                                          ON DELETE CASCADE,
+    agency_id   UUID NOT NULL REFERENCES agencies ON UPDATE RESTRICT
+                                                ON DELETE CASCADE,
+    token_hash  VARCHAR(64) NOT NULL UNIQUE,
+    expires_at  TIMESTAMPTZ NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX idx_user_invites_agency_id ON user_invites (agency_id);
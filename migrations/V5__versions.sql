@@ -0,0 +1,5 @@
+ALTER TABLE contracts
+    ADD COLUMN version  INT4 NOT NULL DEFAULT 0;
+
+ALTER TABLE realties
+    ADD COLUMN version  INT4 NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+DROP TABLE contracts_lock;
+DROP TABLE realties_creation_lock;
+DROP TABLE realties_lock;
+DROP TABLE users_lock;
@@ -0,0 +1,13 @@
+-- For `<%` operator and `<<->` distance.
+CREATE EXTENSION pg_trgm SCHEMA public;
+
+CREATE INDEX idx_users_name_trgm ON users USING GIST (name gist_trgm_ops);
+
+CREATE INDEX idx_realties_address_trgm
+    ON realties USING GIST (address gist_trgm_ops);
+
+CREATE INDEX idx_contracts_name_trgm
+    ON contracts USING GIST (name gist_trgm_ops);
+
+-- Superseded by `pg_trgm`.
+DROP EXTENSION fuzzystrmatch;
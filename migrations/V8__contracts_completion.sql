@@ -0,0 +1,6 @@
+ALTER TABLE contracts ADD COLUMN completed_at TIMESTAMPTZ;
+
+-- For `CompleteExpiredContracts` task.
+CREATE INDEX idx_contracts_expires_at_pending
+    ON contracts (expires_at)
+    WHERE completed_at IS NULL AND terminated_at IS NULL;
@@ -0,0 +1,8 @@
+CREATE TABLE task_settings (
+    task         INT2 NOT NULL PRIMARY KEY CHECK (task BETWEEN 1 AND 1),
+    interval_ms  INT8 CHECK (interval_ms > 0),
+    timeout_ms   INT8 CHECK (timeout_ms > 0),
+    updated_at   TIMESTAMPTZ NOT NULL
+);
+COMMENT ON COLUMN task_settings.task
+        IS '1 - clean unused realties';
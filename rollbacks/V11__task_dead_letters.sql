@@ -0,0 +1 @@
+DROP TABLE task_dead_letters;
@@ -0,0 +1,3 @@
+DROP INDEX idx_contracts_manager_id;
+
+ALTER TABLE contracts DROP COLUMN manager_id;
@@ -0,0 +1,13 @@
+DROP INDEX idx_contracts_agency_id;
+
+DELETE FROM contracts
+WHERE agency_id <> '00000000-0000-0000-0000-000000000001';
+ALTER TABLE contracts DROP COLUMN agency_id;
+
+DELETE FROM realties
+WHERE agency_id <> '00000000-0000-0000-0000-000000000001';
+ALTER TABLE realties DROP CONSTRAINT realties_agency_id_hash_key;
+ALTER TABLE realties ADD UNIQUE (hash);
+ALTER TABLE realties DROP COLUMN agency_id;
+
+DROP TABLE agencies;
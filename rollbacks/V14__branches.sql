@@ -0,0 +1,5 @@
+DROP INDEX idx_contracts_branch_id;
+
+ALTER TABLE contracts DROP COLUMN branch_id;
+
+DROP TABLE branches;
@@ -0,0 +1,3 @@
+DROP INDEX idx_realties_assignee_id;
+
+ALTER TABLE realties DROP COLUMN assignee_id;
@@ -0,0 +1,3 @@
+DROP TABLE lead_transitions;
+
+DROP TABLE leads;
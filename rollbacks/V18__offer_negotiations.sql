@@ -0,0 +1,11 @@
+DROP INDEX idx_offers_concluded_contract_id;
+
+-- Countered `Offer`s are considered declined.
+UPDATE offers SET status = 3 WHERE status = 4;
+COMMENT ON COLUMN offers.status
+        IS '1 - pending, 2 - accepted, 3 - declined';
+
+ALTER TABLE offers
+    DROP COLUMN concluded_contract_id,
+    DROP COLUMN previous_id,
+    DROP COLUMN proposer_id;
@@ -0,0 +1,2 @@
+DROP EXTENSION "uuid-ossp";
+DROP EXTENSION fuzzystrmatch;
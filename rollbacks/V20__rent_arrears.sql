@@ -0,0 +1,2 @@
+DROP TABLE rent_arrears;
+DROP TABLE rent_payments;
@@ -0,0 +1,3 @@
+ALTER TABLE rent_arrears DROP COLUMN late_fees;
+
+DROP TABLE rent_late_fees;
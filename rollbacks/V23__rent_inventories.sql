@@ -0,0 +1,2 @@
+DROP TABLE rent_inventory_items;
+DROP TABLE rent_inventories;
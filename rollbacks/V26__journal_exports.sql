@@ -0,0 +1,2 @@
+DROP INDEX idx_rent_payments_agency_id;
+DROP TABLE journal_exports;
@@ -0,0 +1,3 @@
+DROP INDEX idx_contracts_agency_id_number;
+ALTER TABLE contracts DROP COLUMN number;
+DROP TABLE contract_number_sequences;
@@ -0,0 +1,2 @@
+DROP TABLE users_lock;
+DROP TABLE users;
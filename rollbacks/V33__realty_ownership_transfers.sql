@@ -0,0 +1 @@
+DROP TABLE realty_ownership_transfers;
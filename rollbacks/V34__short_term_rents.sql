@@ -0,0 +1,14 @@
+DROP TABLE realty_seasonal_rates;
+
+DROP INDEX idx_contracts_realty_id_kind_pending;
+
+DELETE FROM contracts WHERE kind = 6;
+
+ALTER TABLE contracts DROP COLUMN check_in_at,
+                      DROP COLUMN nightly_rate;
+
+ALTER TABLE contracts DROP CONSTRAINT contracts_kind_check;
+ALTER TABLE contracts ADD CONSTRAINT contracts_kind_check
+                      CHECK (kind BETWEEN 1 AND 5);
+COMMENT ON COLUMN contracts.kind
+        IS '1 - rent, 2 - sale, 3 - management for rent, 4 - management for sale, 5 - employment';
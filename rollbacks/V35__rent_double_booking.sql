@@ -0,0 +1,4 @@
+DROP INDEX idx_contracts_realty_id_open_rent;
+
+-- The double booking `Contract`s terminated by the migration stay terminated.
+ALTER TABLE contracts DROP COLUMN is_pending_approval;
@@ -0,0 +1 @@
+ALTER TABLE realties DROP COLUMN area;
@@ -0,0 +1,3 @@
+DROP TABLE realties_creation_lock;
+DROP TABLE realties_lock;
+DROP TABLE realties;
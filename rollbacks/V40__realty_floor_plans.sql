@@ -0,0 +1 @@
+DROP TABLE realty_floor_plans;
@@ -0,0 +1 @@
+DROP TABLE realty_media_links;
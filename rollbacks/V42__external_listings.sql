@@ -0,0 +1,2 @@
+DROP TABLE listing_syncs;
+DROP TABLE external_listings;
@@ -0,0 +1 @@
+DROP TABLE user_calendar_feeds;
@@ -0,0 +1,2 @@
+DROP INDEX idx_contracts_placed_at;
+DROP TABLE saved_searches;
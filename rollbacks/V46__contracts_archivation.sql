@@ -0,0 +1,3 @@
+DROP INDEX idx_contracts_agency_id_unarchived;
+
+ALTER TABLE contracts DROP COLUMN archived_at;
@@ -0,0 +1,5 @@
+DROP INDEX idx_realties_deleted_at;
+
+DELETE FROM realties WHERE deleted_at IS NOT NULL;
+
+ALTER TABLE realties DROP COLUMN deleted_at;
@@ -0,0 +1 @@
+DROP INDEX idx_audit_entries_entity_entity_id_created_at;
@@ -0,0 +1,3 @@
+DROP INDEX idx_sms_deliveries_updated_at;
+
+DROP INDEX idx_audit_entries_created_at;
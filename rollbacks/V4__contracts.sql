@@ -0,0 +1,2 @@
+DROP TABLE contracts_lock;
+DROP TABLE contracts;
@@ -0,0 +1,5 @@
+ALTER TABLE realties
+    DROP COLUMN version;
+
+ALTER TABLE contracts
+    DROP COLUMN version;
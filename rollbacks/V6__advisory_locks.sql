@@ -0,0 +1,15 @@
+CREATE TABLE users_lock (
+    id  UUID NOT NULL PRIMARY KEY
+);
+
+CREATE TABLE realties_lock (
+    id  UUID NOT NULL PRIMARY KEY
+);
+
+CREATE TABLE realties_creation_lock (
+    hash  UUID NOT NULL PRIMARY KEY
+);
+
+CREATE TABLE contracts_lock (
+    id  UUID NOT NULL PRIMARY KEY
+);
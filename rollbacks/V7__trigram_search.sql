@@ -0,0 +1,7 @@
+CREATE EXTENSION fuzzystrmatch SCHEMA public;
+
+DROP INDEX idx_contracts_name_trgm;
+DROP INDEX idx_realties_address_trgm;
+DROP INDEX idx_users_name_trgm;
+
+DROP EXTENSION pg_trgm;
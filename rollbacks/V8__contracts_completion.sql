@@ -0,0 +1,3 @@
+DROP INDEX idx_contracts_expires_at_pending;
+
+ALTER TABLE contracts DROP COLUMN completed_at;
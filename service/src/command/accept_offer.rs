@@ -0,0 +1,106 @@
+//! [`Command`] for accepting an [`Offer`].
+
+use common::{
+    operations::{By, Select, Update},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{offer, user, Offer},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for accepting an [`Offer`] by its counterparty.
+#[derive(Clone, Copy, Debug)]
+pub struct AcceptOffer {
+    /// ID of the [`Offer`] to be accepted.
+    pub offer_id: offer::Id,
+
+    /// ID of the [`User`] who accepts the [`Offer`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<AcceptOffer> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Offer>, offer::Id>>,
+            Ok = Option<Offer>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Offer>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Offer;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: AcceptOffer) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AcceptOffer {
+            offer_id,
+            initiator_id,
+        } = cmd;
+
+        let mut attempt = 1;
+        let offer = loop {
+            // `Offer`s decided by other `User`s are not visible to the
+            // initiator.
+            let mut offer = self
+                .database()
+                .execute(Select(By::<Option<Offer>, _>::new(offer_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|o| o.decider_id() == initiator_id)
+                .ok_or(E::OfferNotExists(offer_id))
+                .map_err(tracerr::wrap!())?;
+            if offer.status != offer::Status::Pending {
+                return Err(tracerr::new!(E::OfferAlreadyDecided(offer_id)));
+            }
+
+            offer.status = offer::Status::Accepted;
+            offer.decided_at = Some(DateTime::now().coerce());
+
+            match self.database().execute(Update(offer)).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    break res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| offer)?;
+                }
+            }
+        };
+
+        self.notify_offer(offer);
+
+        Ok(offer)
+    }
+}
+
+/// Error of [`AcceptOffer`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Offer`] with the provided ID does not exist among the ones decided by
+    /// the [`User`].
+    #[display("`Offer(id: {_0})` does not exist")]
+    #[from(ignore)]
+    OfferNotExists(#[error(not(source))] offer::Id),
+
+    /// [`Offer`] is already accepted, declined or countered.
+    #[display("`Offer(id: {_0})` is already decided")]
+    #[from(ignore)]
+    OfferAlreadyDecided(#[error(not(source))] offer::Id),
+}
@@ -0,0 +1,143 @@
+//! [`Command`] for accepting a [`user::Invite`].
+
+use common::{
+    operations::{
+        By, Commit, Delete, Lock, Select, Transact, Transacted, Update,
+    },
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use secrecy::{ExposeSecret as _, SecretBox};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::user::Password;
+use crate::{
+    domain::{user, User},
+    infra::{cache, database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for accepting a [`user::Invite`] by setting a [`Password`] of
+/// the invited [`User`].
+///
+/// Accepted [`user::Invite`] is removed, so cannot be accepted twice.
+#[derive(Debug)]
+pub struct AcceptUserInvite {
+    /// [`user::invite::Token`] of the [`user::Invite`] to be accepted.
+    pub token: user::invite::Token,
+
+    /// [`Password`] of the invited [`User`].
+    pub password: SecretBox<user::Password>,
+}
+
+impl<Db> Command<AcceptUserInvite> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<user::Invite>, user::invite::TokenHash>>,
+            Ok = Option<user::Invite>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Lock<By<User, user::Id>>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        > + Database<Update<User>, Ok = (), Err = Traced<database::Error>>
+        + Database<
+            Delete<By<user::Invite, user::Id>>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        > + Database<Commit, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = User;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: AcceptUserInvite,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AcceptUserInvite { token, password } = cmd;
+
+        let violations =
+            self.password_violations(password.expose_secret()).await;
+        if !violations.is_empty() {
+            return Err(tracerr::new!(E::WeakPassword(violations)));
+        }
+
+        let invite = self
+            .database()
+            .execute(Select(By::<Option<user::Invite>, _>::new(token.hash())))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::InviteNotExists)
+            .map_err(tracerr::wrap!())?;
+        if invite.expires_at <= DateTime::now().coerce() {
+            return Err(tracerr::new!(E::InviteExpired));
+        }
+        let user_id = invite.user_id;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent actions upon the same `User`.
+        tx.execute(Lock(By::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let mut user = tx
+            .execute(Select(By::<Option<User>, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::InviteNotExists)
+            .map_err(tracerr::wrap!())?;
+
+        user.password_hash = user::PasswordHash::new(password.expose_secret());
+        tx.execute(Update(user.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        tx.execute(Delete(By::<user::Invite, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        self.invalidate_cache(&[cache::key::user(user_id)]).await;
+
+        Ok(user)
+    }
+}
+
+/// Error of [`AcceptUserInvite`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`user::Invite`] has expired.
+    #[display("`Invite` has expired")]
+    InviteExpired,
+
+    /// [`user::Invite`] with the provided [`user::invite::Token`] does not
+    /// exist.
+    #[display("`Invite` does not exist")]
+    InviteNotExists,
+
+    /// Provided [`Password`] violates the [`user::password::Policy`].
+    #[display("`Password` violates {_0:?} rules")]
+    #[from(ignore)]
+    WeakPassword(#[error(not(source))] Vec<user::password::Rule>),
+}
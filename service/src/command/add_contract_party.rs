@@ -0,0 +1,166 @@
+//! [`Command`] for adding a [`contract::Party`] to a [`Contract`].
+
+use common::operations::{
+    By, Commit, Insert, Lock, Select, Transact, Transacted,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, user, Contract, User},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for adding a [`contract::Party`] to a [`Contract`].
+///
+/// Only the employer [`User`] managing the [`Contract`] may add its
+/// [`contract::Party`]s. [`contract::Employment`]s cannot have additional
+/// [`contract::Party`]s.
+#[derive(Clone, Copy, Debug)]
+pub struct AddContractParty {
+    /// ID of the [`Contract`] to add the [`contract::Party`] to.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] becoming the [`contract::Party`].
+    pub user_id: user::Id,
+
+    /// [`contract::party::Role`] of the [`contract::Party`].
+    pub role: contract::party::Role,
+
+    /// ID of the employer [`User`] adding the [`contract::Party`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<AddContractParty> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<contract::Party>, contract::Id>>,
+            Ok = Vec<contract::Party>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = contract::Party;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: AddContractParty,
+    ) -> Result<Self::Ok, Self::Err> {
+        use contract::party::Role;
+        use ExecutionError as E;
+
+        let AddContractParty {
+            contract_id,
+            user_id,
+            role,
+            initiator_id,
+        } = cmd;
+
+        let user = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(user_id))
+            .map_err(tracerr::wrap!())?;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `contract::Party`s.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let contract = tx
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|c| !matches!(c, Contract::Employment(_)))
+            .ok_or(E::ContractNotExists(contract_id))
+            .map_err(tracerr::wrap!())?;
+
+        let parties = tx
+            .execute(Select(By::<Vec<contract::Party>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        // `Contract`s managed by other employers are not visible to the
+        // initiator.
+        if !parties
+            .iter()
+            .any(|p| p.user_id == initiator_id && p.role == Role::Employer)
+        {
+            return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+        }
+        if parties
+            .iter()
+            .any(|p| p.user_id == user.id && p.role == role)
+        {
+            return Err(tracerr::new!(E::PartyAlreadyExists(user.id)));
+        }
+
+        let party = contract::Party {
+            contract_id: contract.id(),
+            user_id: user.id,
+            role,
+            signed_at: None,
+        };
+        tx.execute(Insert(vec![party]))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(party)
+    }
+}
+
+/// Error of [`AddContractParty`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] with the provided ID does not exist, is a
+    /// [`contract::Employment`], or is not managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is already a [`contract::Party`] of the [`Contract`] in the
+    /// same [`contract::party::Role`].
+    #[display("`User(id: {_0})` is already a party in this role")]
+    #[from(ignore)]
+    PartyAlreadyExists(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,140 @@
+//! [`Command`] for adding a [`Charge`] under a [`contract::Rent`].
+
+use common::{
+    money::Currency,
+    operations::{By, Insert, Select},
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{charge, contract, user, Charge, Contract},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for adding a [`Charge`] under a [`contract::Rent`].
+#[derive(Clone, Debug)]
+pub struct AddRentCharge {
+    /// ID of the [`contract::Rent`] the [`Charge`] is billed under.
+    pub contract_id: contract::Id,
+
+    /// [`charge::Name`] of the [`Charge`].
+    pub name: charge::Name,
+
+    /// Monthly amount of the [`Charge`].
+    pub amount: Money,
+
+    /// [`charge::Bearer`] of the [`Charge`].
+    pub bearer: charge::Bearer,
+
+    /// ID of the employer [`User`] adding the [`Charge`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<AddRentCharge> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Charge>, Err = Traced<database::Error>>,
+{
+    type Ok = Charge;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: AddRentCharge) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AddRentCharge {
+            contract_id,
+            name,
+            amount,
+            bearer,
+            initiator_id,
+        } = cmd;
+
+        if amount.amount <= Decimal::ZERO {
+            return Err(tracerr::new!(E::NonPositiveAmount));
+        }
+
+        // Only the responsible employer adds `Charge`s to an active
+        // `contract::Rent`.
+        let rent = match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c))
+                if c.employer_id == initiator_id && c.is_active() =>
+            {
+                c
+            }
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        };
+        if amount.currency != rent.price.currency {
+            return Err(tracerr::new!(E::CurrencyMismatch(
+                rent.price.currency
+            )));
+        }
+
+        let charge = Charge {
+            id: charge::Id::new(),
+            agency_id: rent.agency_id,
+            contract_id,
+            name,
+            amount,
+            bearer,
+            creator_id: initiator_id,
+            created_at: DateTime::now().coerce(),
+        };
+        self.database()
+            .execute(Insert(charge.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(charge)
+    }
+}
+
+/// Error of [`AddRentCharge`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// Active [`contract::Rent`] with the provided ID does not exist, or is
+    /// not managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Charge`] amount is not in the currency of the rent price.
+    #[display("Charge amount must be in `{_0}` currency")]
+    #[from(ignore)]
+    CurrencyMismatch(#[error(not(source))] Currency),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Charge`] amount is zero or negative.
+    #[display("Charge amount must be positive")]
+    #[from(ignore)]
+    NonPositiveAmount,
+}
@@ -0,0 +1,260 @@
+//! [`Command`] for approving a [`Contract`] pending a [`contract::Approval`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime, DateTimeOf,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{calendar, contract, realty, user, Calendar, Contract, Realty},
+    infra::{database, Database},
+    read::{self, contract::Active},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for approving a [`Contract`] pending a [`contract::Approval`].
+///
+/// [`Contract`] must be approved by an employer [`User`] of the same
+/// [`Agency`] other than the one who created it.
+///
+/// The [`Realty`] of an approved [`contract::Rent`] or [`contract::Sale`] is
+/// re-validated the same way as on their creation, since other [`Contract`]s
+/// could have been concluded on it while the approval was pending.
+#[derive(Clone, Copy, Debug)]
+pub struct ApproveContract {
+    /// ID of the [`Contract`] to be approved.
+    pub contract_id: contract::Id,
+
+    /// ID of the employer [`User`] who approves the [`Contract`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<ApproveContract> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<contract::Approval>, contract::Id>>,
+            Ok = Option<contract::Approval>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<read::realty::IsRented, realty::Id>>,
+            Ok = read::realty::IsRented,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::Rent>>, realty::Id>>,
+            Ok = Vec<Active<contract::Rent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::ShortTermRent>>, realty::Id>>,
+            Ok = Vec<Active<contract::ShortTermRent>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<contract::Approval>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = contract::Approval;
+    type Err = Traced<ExecutionError>;
+
+    #[expect(clippy::too_many_lines, reason = "still readable")]
+    async fn execute(
+        &self,
+        cmd: ApproveContract,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let ApproveContract {
+            contract_id,
+            initiator_id,
+        } = cmd;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        // `Contract`s of other `Agency`s are not visible to the initiator.
+        let realty_id = self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|c| c.agency_id() == employment.agency_id)
+            .ok_or(E::ContractNotPending(contract_id))
+            .map_err(tracerr::wrap!())?
+            .realty_id();
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        if let Some(realty_id) = realty_id {
+            // Avoid concurrent actions upon the same `Realty`.
+            tx.execute(Lock(By::new(realty_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+        }
+
+        // Avoid concurrent approvals.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        // `Contract`s of other `Agency`s are not visible to the initiator.
+        let mut approval = tx
+            .execute(Select(By::<Option<contract::Approval>, _>::new(
+                contract_id,
+            )))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.agency_id == employment.agency_id)
+            .filter(contract::Approval::is_pending)
+            .ok_or(E::ContractNotPending(contract_id))
+            .map_err(tracerr::wrap!())?;
+        if approval.requester_id == initiator_id {
+            return Err(tracerr::new!(E::SelfApproval(initiator_id)));
+        }
+
+        // Terminated or expired `Contract` cannot become active anymore.
+        let contract = tx
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(Contract::is_active)
+            .ok_or(E::ContractNotPending(contract_id))
+            .map_err(tracerr::wrap!())?;
+        if let Contract::Rent(contract::Rent { realty_id, .. })
+        | Contract::Sale(contract::Sale { realty_id, .. }) = &contract
+        {
+            let is_rented = tx
+                .execute(Select(By::<read::realty::IsRented, _>::new(
+                    *realty_id,
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+            if *is_rented {
+                return Err(tracerr::new!(E::RealtyRented(*realty_id)));
+            }
+        }
+        if let Contract::Rent(rent) = &contract {
+            let rents = tx
+                .execute(Select(By::<Vec<Active<contract::Rent>>, _>::new(
+                    rent.realty_id,
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+            let bookings = tx
+                .execute(Select(
+                    By::<Vec<Active<contract::ShortTermRent>>, _>::new(
+                        rent.realty_id,
+                    ),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+            let calendar = Calendar::new(
+                rents
+                    .iter()
+                    .map(|Active(c)| calendar::Occupancy::of_rent(c))
+                    .chain(bookings.iter().map(|Active(c)| {
+                        calendar::Occupancy::of_short_term_rent(c)
+                    })),
+            );
+            if !calendar.is_available(
+                DateTime::now(),
+                rent.expires_at.map(DateTimeOf::coerce),
+            ) {
+                return Err(tracerr::new!(E::RealtyOccupied(rent.realty_id)));
+            }
+        }
+
+        approval.approver_id = Some(initiator_id);
+        approval.approved_at = Some(DateTime::now().coerce());
+        tx.execute(Insert(approval))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map_err(|e| match (e.as_ref(), contract.realty_id()) {
+                (E::Db(db), Some(realty_id)) if db.is_realty_rented() => {
+                    tracerr::new!(E::RealtyRented(realty_id))
+                }
+                _ => e,
+            })
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(approval)
+    }
+}
+
+/// Error of [`ApproveContract`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] with the provided ID does not exist in the [`Agency`], is
+    /// not pending a [`contract::Approval`], or is terminated or expired
+    /// already.
+    #[display("`Contract(id: {_0})` is not pending approval")]
+    #[from(ignore)]
+    ContractNotPending(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Realty`] with the provided ID is occupied by another
+    /// [`contract::Rent`] or [`contract::ShortTermRent`] within the period of
+    /// the approved [`contract::Rent`].
+    #[display("`Realty(id: {_0})` is occupied by another `Contract`")]
+    #[from(ignore)]
+    RealtyOccupied(#[error(not(source))] realty::Id),
+
+    /// [`Realty`] with the provided ID is rented by another active
+    /// [`contract::Rent`].
+    #[display("`Realty(id: {_0})` is rented")]
+    #[from(ignore)]
+    RealtyRented(#[error(not(source))] realty::Id),
+
+    /// [`User`] cannot approve the [`Contract`] created by themselves.
+    #[display("`User(id: {_0})` cannot approve own `Contract`")]
+    #[from(ignore)]
+    SelfApproval(#[error(not(source))] user::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+}
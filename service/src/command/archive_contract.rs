@@ -0,0 +1,214 @@
+//! [`Command`] for archiving a [`Contract`].
+
+use common::{
+    operations::{
+        By, Commit, Insert, Lock, Select, Transact, Transacted, Update,
+    },
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{audit, contract, user, Contract, User},
+    infra::{cache, database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for archiving a [`Contract`].
+///
+/// Archived [`Contract`]s are excluded from the working lists by default, but
+/// are not destroyed. Only inactive (completed, expired or terminated)
+/// [`Contract`]s may be archived.
+///
+/// Every archivation is recorded into the audit log.
+#[derive(Clone, Copy, Debug)]
+pub struct ArchiveContract {
+    /// ID of the [`Contract`] to be archived.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] who archives the [`Contract`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<ArchiveContract> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Contract>, Ok = (), Err = Traced<database::Error>>
+        + Database<Insert<audit::Entry>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Contract;
+    type Err = Traced<ExecutionError>;
+
+    #[expect(clippy::too_many_lines, reason = "still readable")]
+    async fn execute(
+        &self,
+        cmd: ArchiveContract,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let ArchiveContract {
+            contract_id,
+            initiator_id,
+        } = cmd;
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator.id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator.id))
+            .map_err(tracerr::wrap!())?;
+
+        let mut attempt = 1;
+        let contract = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                // Avoid concurrent archivations.
+                tx.execute(Lock(By::new(contract_id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                // `Contract`s of other `Agency`s are not visible to the
+                // initiator.
+                let mut contract = tx
+                    .execute(Select(By::<Option<Contract>, _>::new(
+                        contract_id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(|c| c.agency_id() == employment.agency_id)
+                    .ok_or(E::ContractNotExists(contract_id))
+                    .map_err(tracerr::wrap!())?;
+
+                if contract.archived_at().is_some() {
+                    return Ok(contract);
+                }
+                if contract.is_active() {
+                    return Err(tracerr::new!(E::ContractIsActive(
+                        contract_id
+                    )));
+                }
+
+                _ = contract
+                    .archived_at_mut()
+                    .replace(DateTime::now().coerce());
+                tx.execute(Update(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                tx.execute(Insert(audit::Entry {
+                    id: audit::Id::new(),
+                    agency_id: employment.agency_id,
+                    actor_id: initiator.id,
+                    action: audit::Action::ArchiveContract,
+                    entity: audit::Entity::Contract,
+                    entity_id: contract.id().into(),
+                    before: None,
+                    after: None,
+                    created_at: DateTime::now().coerce(),
+                }))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(contract)
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+        match &contract {
+            Contract::Employment(c) => {
+                self.invalidate_cache(&[cache::key::employment(c.employer_id)])
+                    .await;
+            }
+            Contract::ManagementForRent(_) | Contract::ManagementForSale(_) => {
+                self.placements_changed().await;
+            }
+            Contract::Rent(_)
+            | Contract::Sale(_)
+            | Contract::ShortTermRent(_) => {}
+        }
+
+        Ok(contract)
+    }
+}
+
+/// Error of [`ArchiveContract`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] is still active, so cannot be archived.
+    #[display("`Contract(id: {_0})` is still active")]
+    #[from(ignore)]
+    ContractIsActive(#[error(not(source))] contract::Id),
+
+    /// [`Contract`] with the provided ID does not exist.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
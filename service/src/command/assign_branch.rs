@@ -0,0 +1,143 @@
+//! [`Command`] for assigning an employee to a [`Branch`].
+
+use common::operations::{By, Insert, Select};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{agency, branch, contract, user, Agency, Branch, Contract},
+    infra::{cache, database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for assigning an employee to a [`Branch`].
+#[derive(Clone, Copy, Debug)]
+pub struct AssignBranch {
+    /// ID of the employed [`User`] to be assigned.
+    pub user_id: user::Id,
+
+    /// ID of the [`Branch`] to assign the [`User`] to.
+    ///
+    /// [`None`] unassigns the [`User`] from any [`Branch`].
+    pub branch_id: Option<branch::Id>,
+
+    /// ID of the [`User`] administrating the [`Agency`] the [`User`] is
+    /// employed by.
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<AssignBranch> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Branch>, branch::Id>>,
+            Ok = Option<Branch>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>,
+{
+    type Ok = contract::Employment;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: AssignBranch) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AssignBranch {
+            user_id,
+            branch_id,
+            initiator_id,
+        } = cmd;
+
+        let mut attempt = 1;
+        loop {
+            let Active(mut employment) = self
+                .database()
+                .execute(Select(
+                    By::<Option<Active<contract::Employment>>, _>::new(user_id),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or(E::UserNotEmployed(user_id))
+                .map_err(tracerr::wrap!())?;
+
+            self.database()
+                .execute(Select(By::<Option<Agency>, _>::new(
+                    employment.agency_id,
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|a| a.admin_id == initiator_id)
+                .ok_or(E::UserNotAdmin(initiator_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+
+            if let Some(branch_id) = branch_id {
+                self.database()
+                    .execute(Select(By::<Option<Branch>, _>::new(branch_id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(|b| b.agency_id == employment.agency_id)
+                    .ok_or(E::BranchNotExists(branch_id))
+                    .map_err(tracerr::wrap!())
+                    .map(drop)?;
+            }
+            if employment.branch_id == branch_id {
+                return Ok(employment);
+            }
+
+            employment.branch_id = branch_id;
+            let res = self
+                .database()
+                .execute(Insert(Contract::from(employment.clone())))
+                .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    res.map_err(tracerr::map_from_and_wrap!(=> E)).map(drop)?;
+                    self.invalidate_cache(&[cache::key::employment(user_id)])
+                        .await;
+                    return Ok(employment);
+                }
+            }
+        }
+    }
+}
+
+/// Error of [`AssignBranch`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Branch`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Branch(id: {_0})` does not exist")]
+    #[from(ignore)]
+    BranchNotExists(#[error(not(source))] branch::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] is not employed.
+    #[display("`User(id: {_0})` is not employed")]
+    #[from(ignore)]
+    UserNotEmployed(#[error(not(source))] user::Id),
+}
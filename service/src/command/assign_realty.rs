@@ -0,0 +1,121 @@
+//! [`Command`] for assigning a [`Realty`] to a responsible employee.
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{agency, contract, realty, user, Realty},
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for assigning a [`Realty`] to a responsible employee.
+#[derive(Clone, Copy, Debug)]
+pub struct AssignRealty {
+    /// ID of the [`Realty`] to be assigned.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] employed by the [`Agency`] to assign the [`Realty`]
+    /// to.
+    ///
+    /// [`None`] unassigns the [`Realty`].
+    pub assignee_id: Option<user::Id>,
+}
+
+impl<Db> Command<AssignRealty> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Realty>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Realty;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: AssignRealty) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AssignRealty {
+            realty_id,
+            agency_id,
+            assignee_id,
+        } = cmd;
+
+        if let Some(assignee_id) = assignee_id {
+            self.database()
+                .execute(Select(
+                    By::<Option<Active<contract::Employment>>, _>::new(
+                        assignee_id,
+                    ),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|Active(e)| e.agency_id == agency_id)
+                .ok_or(E::UserNotEmployed(assignee_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        let mut attempt = 1;
+        loop {
+            let mut realty = self
+                .database()
+                .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|r| r.agency_id == agency_id)
+                .ok_or(E::RealtyNotExists(realty_id))
+                .map_err(tracerr::wrap!())?;
+            if realty.assignee_id == assignee_id {
+                return Ok(realty);
+            }
+
+            realty.assignee_id = assignee_id;
+            match self.database().execute(Update(realty.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    return res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| realty);
+                }
+            }
+        }
+    }
+}
+
+/// Error of [`AssignRealty`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+
+    /// [`User`] is not employed by the [`Agency`].
+    #[display("`User(id: {_0})` is not employed")]
+    #[from(ignore)]
+    UserNotEmployed(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,160 @@
+//! [`Command`] for attaching an [`Insurance`] to a [`Contract`].
+
+use common::{
+    operations::{By, Insert, Select},
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, insurance, user, Contract, Insurance},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for attaching an [`Insurance`] to a [`contract::Rent`],
+/// [`contract::ManagementForRent`] or [`contract::ManagementForSale`].
+///
+/// Replaces the [`Insurance`] already attached to the [`Contract`], if any.
+#[derive(Clone, Debug)]
+pub struct AttachContractInsurance {
+    /// ID of the [`Contract`] to attach the [`Insurance`] to.
+    pub contract_id: contract::Id,
+
+    /// [`insurance::Provider`] issued the [`Insurance`].
+    pub provider: insurance::Provider,
+
+    /// [`insurance::PolicyNumber`] of the [`Insurance`].
+    pub policy_number: insurance::PolicyNumber,
+
+    /// Maximum amount covered by the [`Insurance`].
+    pub coverage: Money,
+
+    /// [`DateTime`] when the [`Insurance`] expires.
+    pub expires_at: DateTime,
+
+    /// ID of the employer [`User`] attaching the [`Insurance`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<AttachContractInsurance> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Insurance>, Err = Traced<database::Error>>,
+{
+    type Ok = Insurance;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: AttachContractInsurance,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let AttachContractInsurance {
+            contract_id,
+            provider,
+            policy_number,
+            coverage,
+            expires_at,
+            initiator_id,
+        } = cmd;
+
+        if coverage.amount <= Decimal::ZERO {
+            return Err(tracerr::new!(E::NonPositiveCoverage));
+        }
+        let now = DateTime::now();
+        if expires_at <= now {
+            return Err(tracerr::new!(E::ExpiresInPast));
+        }
+
+        // Only the responsible employer attaches `Insurance`s to an active
+        // `Contract`.
+        let agency_id = match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c))
+                if c.employer_id == initiator_id && c.is_active() =>
+            {
+                c.agency_id
+            }
+            Some(Contract::ManagementForRent(c))
+                if c.employer_id == initiator_id && c.is_active() =>
+            {
+                c.agency_id
+            }
+            Some(Contract::ManagementForSale(c))
+                if c.employer_id == initiator_id && c.is_active() =>
+            {
+                c.agency_id
+            }
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        };
+
+        let insurance = Insurance {
+            id: insurance::Id::new(),
+            agency_id,
+            contract_id,
+            provider,
+            policy_number,
+            coverage,
+            expires_at: expires_at.coerce(),
+            creator_id: initiator_id,
+            created_at: now.coerce(),
+            notified_at: None,
+        };
+        self.database()
+            .execute(Insert(insurance.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(insurance)
+    }
+}
+
+/// Error of [`AttachContractInsurance`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// Active [`Contract`] with the provided ID does not exist, does not
+    /// support [`Insurance`]s, or is not managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Insurance`] expiration [`DateTime`] is not in the future.
+    #[display("Insurance must expire in the future")]
+    #[from(ignore)]
+    ExpiresInPast,
+
+    /// [`Insurance`] coverage is zero or negative.
+    #[display("Insurance coverage must be positive")]
+    #[from(ignore)]
+    NonPositiveCoverage,
+}
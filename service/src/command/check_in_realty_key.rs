@@ -0,0 +1,146 @@
+//! [`Command`] for checking in a [`Key`] of a [`Realty`].
+
+use common::operations::{
+    By, Commit, Insert, Lock, Select, Transact, Transacted,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{contract, key, realty, user, Key, Realty},
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for checking in a [`Key`] of a [`Realty`] returned by its
+/// holder.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckInRealtyKey {
+    /// ID of the [`Key`] to be checked in.
+    pub key_id: key::Id,
+
+    /// ID of the employer [`User`] checking in the [`Key`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CheckInRealtyKey> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Key>, key::Id>>,
+            Ok = Option<Key>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Key>, key::Id>>,
+            Ok = Option<Key>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Key>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Key;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: CheckInRealtyKey,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CheckInRealtyKey {
+            key_id,
+            initiator_id,
+        } = cmd;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        // `Key`s of other `Agency`s are not visible to the initiator.
+        let realty_id = self
+            .database()
+            .execute(Select(By::<Option<Key>, _>::new(key_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|k| k.agency_id == employment.agency_id)
+            .ok_or(E::KeyNotExists(key_id))
+            .map_err(tracerr::wrap!())?
+            .realty_id;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent actions upon the `Key`s of the same `Realty`.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let mut key = tx
+            .execute(Select(By::<Option<Key>, _>::new(key_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::KeyNotExists(key_id))
+            .map_err(tracerr::wrap!())?;
+        if key.checkout.take().is_none() {
+            return Err(tracerr::new!(E::NotCheckedOut(key_id)));
+        }
+
+        tx.execute(Insert(key.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(key)
+    }
+}
+
+/// Error of [`CheckInRealtyKey`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Key`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Key(id: {_0})` does not exist")]
+    #[from(ignore)]
+    KeyNotExists(#[error(not(source))] key::Id),
+
+    /// [`Key`] is not checked out.
+    #[display("`Key(id: {_0})` is not checked out")]
+    #[from(ignore)]
+    NotCheckedOut(#[error(not(source))] key::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,187 @@
+//! [`Command`] for checking out a [`Key`] of a [`Realty`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{contract, key, realty, user, Key, Realty, User},
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for checking out a [`Key`] of a [`Realty`] to its holder.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckOutRealtyKey {
+    /// ID of the [`Key`] to be checked out.
+    pub key_id: key::Id,
+
+    /// ID of the [`User`] to hold the [`Key`].
+    pub holder_id: user::Id,
+
+    /// [`DateTime`] when the [`Key`] is due to be returned.
+    pub due_at: DateTime,
+
+    /// ID of the employer [`User`] checking out the [`Key`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CheckOutRealtyKey> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Key>, key::Id>>,
+            Ok = Option<Key>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Key>, key::Id>>,
+            Ok = Option<Key>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Key>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Key;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: CheckOutRealtyKey,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CheckOutRealtyKey {
+            key_id,
+            holder_id,
+            due_at,
+            initiator_id,
+        } = cmd;
+
+        let now = DateTime::now();
+        if due_at <= now {
+            return Err(tracerr::new!(E::DueInPast));
+        }
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        self.database()
+            .execute(Select(By::<Option<User>, _>::new(holder_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(holder_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        // `Key`s of other `Agency`s are not visible to the initiator.
+        let realty_id = self
+            .database()
+            .execute(Select(By::<Option<Key>, _>::new(key_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|k| k.agency_id == employment.agency_id)
+            .ok_or(E::KeyNotExists(key_id))
+            .map_err(tracerr::wrap!())?
+            .realty_id;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent actions upon the `Key`s of the same `Realty`.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let mut key = tx
+            .execute(Select(By::<Option<Key>, _>::new(key_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::KeyNotExists(key_id))
+            .map_err(tracerr::wrap!())?;
+        if key.checkout.is_some() {
+            return Err(tracerr::new!(E::AlreadyCheckedOut(key_id)));
+        }
+
+        key.checkout = Some(key::Checkout {
+            holder_id,
+            issuer_id: initiator_id,
+            checked_out_at: now.coerce(),
+            due_at: due_at.coerce(),
+        });
+        tx.execute(Insert(key.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(key)
+    }
+}
+
+/// Error of [`CheckOutRealtyKey`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Key`] is already checked out.
+    #[display("`Key(id: {_0})` is already checked out")]
+    #[from(ignore)]
+    AlreadyCheckedOut(#[error(not(source))] key::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Due [`DateTime`] is not in the future.
+    #[display("Due `DateTime` must be in the future")]
+    #[from(ignore)]
+    DueInPast,
+
+    /// [`Key`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Key(id: {_0})` does not exist")]
+    #[from(ignore)]
+    KeyNotExists(#[error(not(source))] key::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
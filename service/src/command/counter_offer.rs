@@ -0,0 +1,159 @@
+//! [`Command`] for countering an [`Offer`] with a new one.
+
+use common::{
+    money::Currency,
+    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{offer, user, Offer, Version},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for countering a pending [`Offer`] by its counterparty with a
+/// new [`Offer`] of another price.
+#[derive(Clone, Copy, Debug)]
+pub struct CounterOffer {
+    /// ID of the [`Offer`] to be countered.
+    pub offer_id: offer::Id,
+
+    /// ID of the [`User`] who counters the [`Offer`].
+    pub initiator_id: user::Id,
+
+    /// Counter-offered price.
+    pub price: Money,
+}
+
+impl<Db> Command<CounterOffer> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Select<By<Option<Offer>, offer::Id>>,
+            Ok = Option<Offer>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Offer>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Offer;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: CounterOffer) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CounterOffer {
+            offer_id,
+            initiator_id,
+            price,
+        } = cmd;
+
+        let mut attempt = 1;
+        let (countered, counter) = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                // `Offer`s decided by other `User`s are not visible to the
+                // initiator.
+                let mut countered = tx
+                    .execute(Select(By::<Option<Offer>, _>::new(offer_id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(|o| o.decider_id() == initiator_id)
+                    .ok_or(E::OfferNotExists(offer_id))
+                    .map_err(tracerr::wrap!())?;
+                if countered.status != offer::Status::Pending {
+                    return Err(tracerr::new!(E::OfferAlreadyDecided(
+                        offer_id
+                    )));
+                }
+                if price.currency != countered.price.currency {
+                    return Err(tracerr::new!(E::CurrencyMismatch(
+                        countered.price.currency
+                    )));
+                }
+
+                let now = DateTime::now();
+                countered.status = offer::Status::Countered;
+                countered.decided_at = Some(now.coerce());
+
+                let counter = Offer {
+                    id: offer::Id::new(),
+                    proposer_id: initiator_id,
+                    previous_id: Some(countered.id),
+                    concluded_contract_id: None,
+                    price,
+                    status: offer::Status::Pending,
+                    created_at: now.coerce(),
+                    decided_at: None,
+                    version: Version::initial(),
+                    ..countered
+                };
+
+                tx.execute(Insert(countered))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                tx.execute(Insert(counter))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>((countered, counter))
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+
+        self.notify_offer(countered);
+        self.notify_offer(counter);
+
+        Ok(counter)
+    }
+}
+
+/// Error of [`CounterOffer`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Offer`] with the provided ID does not exist among the ones decided by
+    /// the [`User`].
+    #[display("`Offer(id: {_0})` does not exist")]
+    #[from(ignore)]
+    OfferNotExists(#[error(not(source))] offer::Id),
+
+    /// [`Offer`] is already accepted, declined or countered.
+    #[display("`Offer(id: {_0})` is already decided")]
+    #[from(ignore)]
+    OfferAlreadyDecided(#[error(not(source))] offer::Id),
+
+    /// Counter-offered price is not in the currency of the countered one.
+    #[display("Counter-offered price must be in `{_0}` currency")]
+    #[from(ignore)]
+    CurrencyMismatch(#[error(not(source))] Currency),
+}
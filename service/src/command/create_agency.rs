@@ -0,0 +1,114 @@
+//! [`Command`] for creating a new [`Agency`].
+
+use std::collections::HashMap;
+
+use common::{
+    operations::{By, Insert, Select},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, user, Agency, User, Version},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for creating a new [`Agency`].
+#[derive(Clone, Debug)]
+pub struct CreateAgency {
+    /// [`agency::Name`] of a new [`Agency`].
+    pub name: agency::Name,
+
+    /// ID of the [`User`] to administrate a new [`Agency`].
+    pub admin_id: user::Id,
+
+    /// ID of the [`User`] who creates a new [`Agency`].
+    ///
+    /// Must administrate the [`agency::Id::DEFAULT`] [`Agency`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CreateAgency> for Service<Db>
+where
+    Db: Database<
+            Select<By<HashMap<user::Id, User>, [user::Id; 2]>>,
+            Ok = HashMap<user::Id, User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Agency>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Agency;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: CreateAgency) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateAgency {
+            name,
+            admin_id,
+            initiator_id,
+        } = cmd;
+
+        let users = self
+            .database()
+            .execute(Select(By::new([admin_id, initiator_id])))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        let admin = users
+            .get(&admin_id)
+            .ok_or(E::UserNotExists(admin_id))
+            .map_err(tracerr::wrap!())?;
+        let initiator = users
+            .get(&initiator_id)
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        self.database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency::Id::DEFAULT)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.admin_id == initiator.id)
+            .ok_or(E::UserNotAdmin(initiator.id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let agency = Agency {
+            id: agency::Id::new(),
+            name,
+            admin_id: admin.id,
+            created_at: DateTime::now().coerce(),
+            version: Version::initial(),
+        };
+        self.database()
+            .execute(Insert(agency.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        Ok(agency)
+    }
+}
+
+/// Error of [`CreateAgency`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the default [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,102 @@
+//! [`Command`] for creating a new [`Branch`].
+
+use common::{
+    operations::{By, Insert, Select},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{agency, branch, realty, user, Agency, Branch},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for creating a new [`Branch`].
+#[derive(Clone, Debug)]
+pub struct CreateBranch {
+    /// ID of the [`Agency`] to create a new [`Branch`] in.
+    pub agency_id: agency::Id,
+
+    /// [`branch::Name`] of a new [`Branch`].
+    pub name: branch::Name,
+
+    /// [`realty::Address`] of a new [`Branch`].
+    pub address: realty::Address,
+
+    /// ID of the [`User`] who creates a new [`Branch`].
+    ///
+    /// Must administrate the [`Agency`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CreateBranch> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Branch>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Branch;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: CreateBranch) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateBranch {
+            agency_id,
+            name,
+            address,
+            initiator_id,
+        } = cmd;
+
+        let agency = self
+            .database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::AgencyNotExists(agency_id))
+            .map_err(tracerr::wrap!())?;
+        if agency.admin_id != initiator_id {
+            return Err(tracerr::new!(E::UserNotAdmin(initiator_id)));
+        }
+
+        let branch = Branch {
+            id: branch::Id::new(),
+            agency_id: agency.id,
+            name,
+            address,
+            created_at: DateTime::now().coerce(),
+        };
+        self.database()
+            .execute(Insert(branch.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        Ok(branch)
+    }
+}
+
+/// Error of [`CreateBranch`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    #[from(ignore)]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+}
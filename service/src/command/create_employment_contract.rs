@@ -3,16 +3,18 @@
 use std::collections::HashMap;
 
 use common::{
-    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    operations::{By, Commit, Insert, Perform, Select, Transact, Transacted},
     DateTime, Money,
 };
 use derive_more::{Display, Error, From};
 use tracerr::Traced;
 
 use crate::{
-    domain::{contract, user, Contract, User},
-    infra::{database, Database},
-    read::contract::Active,
+    domain::{
+        agency, branch, contract, user, Agency, Branch, Contract, User, Version,
+    },
+    infra::{cache, database, Database},
+    read::{self, contract::Active},
     Service,
 };
 
@@ -24,7 +26,12 @@ pub struct CreateEmploymentContract {
     /// ID of the [`User`] to be employed.
     pub user_id: user::Id,
 
+    /// ID of the [`Agency`] the [`User`] is employed by.
+    pub agency_id: agency::Id,
+
     /// ID of the [`User`] who hires.
+    ///
+    /// Must be either employed by the [`Agency`] or administrate it.
     pub initiator_id: user::Id,
 
     /// Name of a new [`Contract`].
@@ -38,12 +45,28 @@ pub struct CreateEmploymentContract {
 
     /// Base salary of a new employer.
     pub base_salary: Money,
+
+    /// ID of the [`User`] employed by the same [`Agency`] the new employer
+    /// reports to, if any.
+    pub manager_id: Option<user::Id>,
+
+    /// ID of the [`Branch`] of the same [`Agency`] to assign the new employer
+    /// to, if any.
+    pub branch_id: Option<branch::Id>,
 }
 
 impl<Db> Command<CreateEmploymentContract> for Service<Db>
 where
     Db: Database<Transact, Err = Traced<database::Error>>
         + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Branch>, branch::Id>>,
+            Ok = Option<Branch>,
+            Err = Traced<database::Error>,
+        > + Database<
             Select<By<HashMap<user::Id, User>, [user::Id; 2]>>,
             Ok = HashMap<user::Id, User>,
             Err = Traced<database::Error>,
@@ -53,11 +76,17 @@ where
             Err = Traced<database::Error>,
         >,
     Transacted<Db>: Database<Insert<Contract>, Err = Traced<database::Error>>
-        + Database<Commit, Err = Traced<database::Error>>,
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
 {
     type Ok = Contract;
     type Err = Traced<ExecutionError>;
 
+    #[expect(clippy::too_many_lines, reason = "still readable")]
     async fn execute(
         &self,
         cmd: CreateEmploymentContract,
@@ -66,11 +95,14 @@ where
 
         let CreateEmploymentContract {
             user_id,
+            agency_id,
             initiator_id,
             name,
             description,
             expires_at,
             base_salary,
+            manager_id,
+            branch_id,
         } = cmd;
 
         let users = self
@@ -87,17 +119,30 @@ where
             .ok_or(E::UserNotExists(initiator_id))
             .map_err(tracerr::wrap!())?;
 
-        self.database()
-            .execute(Select(
-                By::<Option<Active<contract::Employment>>, _>::new(
-                    initiator.id,
-                ),
-            ))
+        let agency = self
+            .database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
-            .ok_or(E::UserNotEmployer(initiator.id))
-            .map_err(tracerr::wrap!())
-            .map(drop)?;
+            .ok_or(E::AgencyNotExists(agency_id))
+            .map_err(tracerr::wrap!())?;
+
+        // `Agency` administrator may hire even not being employed, so the
+        // first employees of a new `Agency` can be hired.
+        if agency.admin_id != initiator.id {
+            self.database()
+                .execute(Select(
+                    By::<Option<Active<contract::Employment>>, _>::new(
+                        initiator.id,
+                    ),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|Active(c)| c.agency_id == agency.id)
+                .ok_or(E::UserNotEmployer(initiator.id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
 
         let existing_contract = self
             .database()
@@ -110,30 +155,81 @@ where
             return Err(tracerr::new!(E::UserAlreadyEmployed(user.id)));
         }
 
+        if let Some(manager_id) = manager_id {
+            if manager_id == user.id {
+                return Err(tracerr::new!(E::ManagerNotEmployed(manager_id)));
+            }
+            self.database()
+                .execute(Select(
+                    By::<Option<Active<contract::Employment>>, _>::new(
+                        manager_id,
+                    ),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|Active(c)| c.agency_id == agency.id)
+                .ok_or(E::ManagerNotEmployed(manager_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        if let Some(branch_id) = branch_id {
+            self.database()
+                .execute(Select(By::<Option<Branch>, _>::new(branch_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|b| b.agency_id == agency.id)
+                .ok_or(E::BranchNotExists(branch_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        let now = DateTime::now();
+        let number = tx
+            .execute(Perform(By::new(read::contract::NumberSequence {
+                agency_id: agency.id,
+                kind: contract::Kind::Employment,
+                year: now.year(),
+            })))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
         let contract = Contract::from(contract::Employment {
             id: contract::Id::new(),
+            agency_id: agency.id,
+            number,
             name,
             description,
             employer_id: user.id,
+            manager_id,
+            branch_id,
             base_salary,
-            created_at: DateTime::now().coerce(),
+            created_at: now.coerce(),
             expires_at,
+            completed_at: None,
             terminated_at: None,
+            archived_at: None,
+            version: Version::initial(),
         });
-
-        let tx = self
-            .database()
-            .execute(Transact)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?;
         tx.execute(Insert(contract.clone()))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))
             .map(drop)?;
+        tx.execute(Insert(contract::Party::primary(&contract)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))
             .map(drop)?;
+        self.invalidate_cache(&[cache::key::employment(user.id)])
+            .await;
 
         Ok(contract)
     }
@@ -142,11 +238,24 @@ where
 /// Error of [`CreateEmploymentContract`] [`Command`] execution.
 #[derive(Debug, Display, Error, From)]
 pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Branch`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Branch(id: {_0})` does not exist")]
+    BranchNotExists(#[error(not(source))] branch::Id),
+
     /// [`Database`] error.
     #[display("`Database` operation failed: {_0}")]
     #[from]
     Db(database::Error),
 
+    /// Manager [`User`] is not employed by the same [`Agency`] (or is the
+    /// employed [`User`] itself).
+    #[display("`User(id: {_0})` cannot be a manager")]
+    ManagerNotEmployed(#[error(not(source))] user::Id),
+
     /// [`User`] is already employed.
     #[display("`User(id: {_0})` is already employed")]
     UserAlreadyEmployed(#[error(not(source))] user::Id),
@@ -0,0 +1,143 @@
+//! [`Command`] for creating a new [`Lead`].
+
+use common::{
+    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, lead, realty, user, Lead, Realty, User, Version},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for creating a new [`Lead`] at the [`lead::Stage::New`].
+#[derive(Clone, Debug)]
+pub struct CreateLead {
+    /// ID of the [`Agency`] a new [`Lead`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`lead::Source`] of a new [`Lead`].
+    pub source: lead::Source,
+
+    /// ID of the prospect [`User`], if known.
+    pub user_id: Option<user::Id>,
+
+    /// ID of the [`Realty`] a new [`Lead`] is interested in, if any.
+    pub realty_id: Option<realty::Id>,
+
+    /// ID of the [`User`] who creates a new [`Lead`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CreateLead> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<Insert<Lead>, Err = Traced<database::Error>>
+        + Database<Insert<lead::Transition>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Lead;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: CreateLead) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateLead {
+            agency_id,
+            source,
+            user_id,
+            realty_id,
+            initiator_id,
+        } = cmd;
+
+        if let Some(user_id) = user_id {
+            self.database()
+                .execute(Select(By::<Option<User>, _>::new(user_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or(E::UserNotExists(user_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+        if let Some(realty_id) = realty_id {
+            self.database()
+                .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|r| r.agency_id == agency_id)
+                .ok_or(E::RealtyNotExists(realty_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        let lead = Lead {
+            id: lead::Id::new(),
+            agency_id,
+            source,
+            stage: lead::Stage::New,
+            user_id,
+            realty_id,
+            created_at: DateTime::now().coerce(),
+            version: Version::initial(),
+        };
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        tx.execute(Insert(lead.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(lead::Transition {
+            lead_id: lead.id,
+            from: None,
+            to: lead.stage,
+            initiator_id,
+            created_at: lead.created_at.coerce(),
+        }))
+        .await
+        .map_err(tracerr::map_from_and_wrap!(=> E))
+        .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(lead)
+    }
+}
+
+/// Error of [`CreateLead`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
@@ -3,18 +3,20 @@
 use std::collections::HashMap;
 
 use common::{
-    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    operations::{
+        By, Commit, Insert, Lock, Perform, Select, Transact, Transacted,
+    },
     DateTime, Money, Percent,
 };
 use derive_more::{Display, Error, From};
 use tracerr::Traced;
 
 #[cfg(doc)]
-use crate::read::Placement;
+use crate::{domain::Agency, read::Placement};
 use crate::{
-    domain::{contract, realty, user, Contract, Realty, User},
+    domain::{agency, contract, realty, user, Contract, Realty, User, Version},
     infra::{database, Database},
-    read::contract::Active,
+    read::{self, contract::Active},
     Service,
 };
 
@@ -30,6 +32,8 @@ pub struct CreateManagementForRentContract {
     pub landlord_id: user::Id,
 
     /// ID of the [`User`] who will manage the [`Realty`].
+    ///
+    /// Must be employed by the [`Agency`] the [`Realty`] belongs to.
     pub employer_id: user::Id,
 
     /// Name of a new [`Contract`].
@@ -39,6 +43,9 @@ pub struct CreateManagementForRentContract {
     pub description: contract::Description,
 
     /// [`DateTime`] when a new [`Contract`] expires.
+    ///
+    /// [`None`] means the [`agency::Settings::contract_duration`] after its
+    /// creation, if any.
     pub expires_at: Option<contract::ExpirationDateTime>,
 
     /// Expected price for rent a [`Realty`].
@@ -54,6 +61,9 @@ pub struct CreateManagementForRentContract {
     pub monthly_fee: Option<Money>,
 
     /// Percent fee for a [`Realty`] management.
+    ///
+    /// [`None`] means the [`agency::Settings::commission`], if no other fee
+    /// is specified.
     pub percent_fee: Option<Percent>,
 
     /// Indicator whether [`Placement`] should be created for the [`Realty`].
@@ -75,18 +85,28 @@ where
             Select<By<Option<Active<contract::Employment>>, user::Id>>,
             Ok = Option<Active<contract::Employment>>,
             Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
         >,
     Transacted<Db>: Database<
             Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
             Ok = Option<Active<contract::ManagementForRent>>,
             Err = Traced<database::Error>,
         > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
         + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
-        + Database<Commit, Err = Traced<database::Error>>,
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
 {
     type Ok = Contract;
     type Err = Traced<ExecutionError>;
 
+    #[expect(clippy::too_many_lines, reason = "still readable")]
     async fn execute(
         &self,
         cmd: CreateManagementForRentContract,
@@ -136,10 +156,20 @@ where
             ))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|Active(c)| c.agency_id == realty.agency_id)
             .ok_or(E::UserNotEmployer(employer.id))
             .map_err(tracerr::wrap!())
             .map(drop)?;
 
+        let settings = self
+            .database()
+            .execute(Select(By::<Option<agency::Settings>, _>::new(
+                realty.agency_id,
+            )))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .unwrap_or_else(|| agency::Settings::new(realty.agency_id));
+
         let tx = self
             .database()
             .execute(Transact)
@@ -163,8 +193,29 @@ where
             return Err(tracerr::new!(E::RealtyAlreadyManaged(realty.id)));
         }
 
+        let now = DateTime::now();
+        let number = tx
+            .execute(Perform(By::new(read::contract::NumberSequence {
+                agency_id: realty.agency_id,
+                kind: contract::Kind::ManagementForRent,
+                year: now.year(),
+            })))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Default commission applies only if no fee is specified at all.
+        let percent_fee = if percent_fee.is_none()
+            && one_time_fee.is_none()
+            && monthly_fee.is_none()
+        {
+            settings.commission
+        } else {
+            percent_fee
+        };
         let contract = Contract::from(contract::ManagementForRent {
             id: contract::Id::new(),
+            agency_id: realty.agency_id,
+            number,
             name,
             description,
             realty_id: realty.id,
@@ -176,19 +227,29 @@ where
             monthly_fee,
             percent_fee,
             is_placed: make_placement,
-            created_at: DateTime::now().coerce(),
-            expires_at,
+            created_at: now.coerce(),
+            expires_at: expires_at.or_else(|| settings.expiration(now)),
+            completed_at: None,
             terminated_at: None,
+            archived_at: None,
+            version: Version::initial(),
         });
         tx.execute(Insert(contract.clone()))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))
             .map(drop)?;
+        tx.execute(Insert(contract::Party::primary(&contract)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
 
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))
             .map(drop)?;
+        if make_placement {
+            self.placements_changed().await;
+        }
 
         Ok(contract)
     }
@@ -7,12 +7,15 @@ use common::{
 use tracerr::Traced;
 
 #[cfg(doc)]
-use crate::domain::realty::{
-    ApartmentNum, BuildingName, City, Country, Floor, NumFloors, RoomNum,
-    State, Street, ZipCode,
+use crate::domain::{
+    realty::{
+        ApartmentNum, Area, BuildingName, City, Country, Floor, NumFloors,
+        RoomNum, State, Street, ZipCode,
+    },
+    Agency,
 };
 use crate::{
-    domain::{realty, Realty},
+    domain::{agency, realty, Realty, Version},
     infra::{database, Database},
     Service,
 };
@@ -22,6 +25,9 @@ use super::Command;
 /// [`Command`] for creating a new [`Realty`].
 #[derive(Clone, Debug)]
 pub struct CreateRealty {
+    /// ID of the [`Agency`] a new [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
     /// [`Country`] of a new [`Realty`].
     pub country: realty::Country,
 
@@ -51,13 +57,16 @@ pub struct CreateRealty {
 
     /// [`RoomNum`] of a new [`Realty`].
     pub room_num: Option<realty::RoomNum>,
+
+    /// [`Area`] of a new [`Realty`].
+    pub area: Option<realty::Area>,
 }
 
 impl<Db> Command<CreateRealty> for Service<Db>
 where
     Db: Database<Transact, Err = Traced<database::Error>>,
     Transacted<Db>: Database<
-            Select<By<Option<Realty>, realty::Hash>>,
+            Select<By<Option<Realty>, (agency::Id, realty::Hash)>>,
             Ok = Option<Realty>,
             Err = Traced<database::Error>,
         > + Database<Insert<Realty>, Err = Traced<database::Error>>
@@ -70,6 +79,7 @@ where
 
     async fn execute(&self, cmd: CreateRealty) -> Result<Self::Ok, Self::Err> {
         let CreateRealty {
+            agency_id,
             country,
             state,
             city,
@@ -80,6 +90,7 @@ where
             floor,
             apartment_num,
             room_num,
+            area,
         } = cmd;
 
         let hash = realty::Hash::new(
@@ -97,6 +108,8 @@ where
 
         let realty = Realty {
             id: realty::Id::new(),
+            agency_id,
+            assignee_id: None,
             hash,
             address: realty::Address::from_parts(
                 &country,
@@ -119,8 +132,10 @@ where
             floor,
             apartment_num,
             room_num,
+            area,
             created_at: DateTime::now().coerce(),
             deleted_at: None,
+            version: Version::initial(),
         };
 
         let tx = self
@@ -136,13 +151,19 @@ where
             .map(drop)?;
 
         let existing_realty = tx
-            .execute(Select(By::new(hash)))
+            .execute(Select(By::new((agency_id, hash))))
             .await
             .map_err(tracerr::wrap!())?;
-        if let Some(realty) = existing_realty {
+        let realty = match existing_realty {
             // `Realty` with the same properties already exists.
-            return Ok(realty);
-        }
+            Some(realty) if realty.deleted_at.is_none() => return Ok(realty),
+            // Deleted `Realty` with the same properties is restored instead.
+            Some(mut deleted) => {
+                deleted.deleted_at = None;
+                deleted
+            }
+            None => realty,
+        };
 
         tx.execute(Insert(realty.clone()))
             .await
@@ -0,0 +1,155 @@
+//! [`Command`] for registering a [`Key`] of a [`Realty`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{contract, key, realty, user, Key, Realty},
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for registering a [`Key`] of a [`Realty`].
+#[derive(Clone, Debug)]
+pub struct CreateRealtyKey {
+    /// ID of the [`Realty`] the [`Key`] opens.
+    pub realty_id: realty::Id,
+
+    /// [`key::Label`] of the [`Key`].
+    pub label: key::Label,
+
+    /// ID of the employer [`User`] registering the [`Key`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<CreateRealtyKey> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Key>, realty::Id>>,
+            Ok = Vec<Key>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Key>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Key;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: CreateRealtyKey,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateRealtyKey {
+            realty_id,
+            label,
+            initiator_id,
+        } = cmd;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        // `Realty`s of other `Agency`s are not visible to the initiator.
+        let realty = self
+            .database()
+            .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|r| r.agency_id == employment.agency_id)
+            .ok_or(E::RealtyNotExists(realty_id))
+            .map_err(tracerr::wrap!())?;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent `Key`s with the same `key::Label`.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let existing = tx
+            .execute(Select(By::<Vec<Key>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if existing.iter().any(|k| k.label == label) {
+            return Err(tracerr::new!(E::LabelOccupied(label)));
+        }
+
+        let key = Key {
+            id: key::Id::new(),
+            agency_id: realty.agency_id,
+            realty_id,
+            label,
+            checkout: None,
+            created_at: DateTime::now().coerce(),
+        };
+        tx.execute(Insert(key.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(key)
+    }
+}
+
+/// Error of [`CreateRealtyKey`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`key::Label`] is already used by another [`Key`] of the [`Realty`].
+    #[display("`Label({_0})` is occupied")]
+    #[from(ignore)]
+    LabelOccupied(#[error(not(source))] key::Label),
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+}
@@ -4,23 +4,35 @@ use std::collections::HashMap;
 
 use common::{
     operations::{
-        By, Commit, Insert, Lock, Select, Transact, Transacted, Update,
+        By, Commit, Insert, Lock, Perform, Select, Transact, Transacted, Update,
     },
-    DateTime, Money,
+    DateTime, DateTimeOf, Money,
 };
 use derive_more::{Display, Error, From};
 use tracerr::Traced;
 
+#[cfg(doc)]
+use crate::Config;
 use crate::{
-    domain::{contract, realty, user, Contract, Realty, User},
+    domain::{
+        agency, calendar, contract, late_fee, offer, realty, user, Calendar,
+        Contract, LateFee, Offer, Realty, User, Version,
+    },
     infra::{database, Database},
-    read::contract::Active,
+    read::{self, contract::Active},
     Service,
 };
 
-use super::Command;
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
 
 /// [`Command`] for creating a new [`contract::Rent`].
+///
+/// [`contract::Rent`] with the price above the [`Config::approval_threshold`]
+/// is created pending a [`contract::Approval`].
+///
+/// [`Realty`] must not be rented by another active [`contract::Rent`], and the
+/// new [`contract::Rent`] must not overlap with active
+/// [`contract::ShortTermRent`]s of the same [`Realty`] in its [`Calendar`].
 #[derive(Clone, Debug)]
 pub struct CreateRentContract {
     /// ID of the [`Realty`] to manage.
@@ -33,12 +45,23 @@ pub struct CreateRentContract {
     pub purchaser_id: user::Id,
 
     /// Name of a new [`Contract`].
-    pub name: contract::Name,
+    ///
+    /// Rendered with the [`contract::Template`], if not provided.
+    pub name: Option<contract::Name>,
 
     /// Description of a new [`Contract`].
-    pub description: contract::Description,
+    ///
+    /// Rendered with the [`contract::Template`], if not provided.
+    pub description: Option<contract::Description>,
+
+    /// ID of the [`contract::Template`] to render the [`contract::Name`] and
+    /// the [`contract::Description`] of a new [`Contract`] with, if any.
+    pub template_id: Option<contract::template::Id>,
 
     /// [`DateTime`] when a new [`Contract`] expires.
+    ///
+    /// [`None`] means the [`agency::Settings::contract_duration`] after its
+    /// creation, if any.
     pub expires_at: Option<contract::ExpirationDateTime>,
 
     /// Monthly price for rent a [`Realty`].
@@ -46,6 +69,14 @@ pub struct CreateRentContract {
 
     /// Deposit to be paid at the beginning of the [`Realty`] rent.
     pub deposit: Option<Money>,
+
+    /// [`late_fee::Policy`] of charging a [`LateFee`] for the overdue rent
+    /// installments, if any.
+    pub late_fee: Option<late_fee::Policy>,
+
+    /// ID of the accepted [`Offer`] on which terms a new [`Contract`] is
+    /// concluded, if any.
+    pub offer_id: Option<offer::Id>,
 }
 
 impl<Db> Command<CreateRentContract> for Service<Db>
@@ -63,19 +94,56 @@ where
             Select<By<Option<Active<contract::Employment>>, user::Id>>,
             Ok = Option<Active<contract::Employment>>,
             Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<contract::Template>, contract::template::Id>>,
+            Ok = Option<contract::Template>,
+            Err = Traced<database::Error>,
         >,
     Transacted<Db>: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
             Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
             Ok = Option<Active<contract::ManagementForRent>>,
             Err = Traced<database::Error>,
+        > + Database<
+            Select<By<read::realty::IsRented, realty::Id>>,
+            Ok = read::realty::IsRented,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::Rent>>, realty::Id>>,
+            Ok = Vec<Active<contract::Rent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::ShortTermRent>>, realty::Id>>,
+            Ok = Vec<Active<contract::ShortTermRent>>,
+            Err = Traced<database::Error>,
         > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Insert<contract::Approval>, Err = Traced<database::Error>>
+        + Database<Insert<LateFee>, Err = Traced<database::Error>>
         + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
         + Database<Update<Contract>, Err = Traced<database::Error>>
-        + Database<Commit, Err = Traced<database::Error>>,
+        + Database<
+            Select<By<Option<Offer>, offer::Id>>,
+            Ok = Option<Offer>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Offer>, Err = Traced<database::Error>>
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
 {
     type Ok = Contract;
     type Err = Traced<ExecutionError>;
 
+    #[expect(clippy::too_many_lines, reason = "still readable")]
     async fn execute(
         &self,
         cmd: CreateRentContract,
@@ -88,11 +156,18 @@ where
             purchaser_id,
             name,
             description,
+            template_id,
             expires_at,
             price,
             deposit,
+            late_fee,
+            offer_id,
         } = cmd;
 
+        if late_fee.is_some_and(|p| !p.is_applicable_to(price)) {
+            return Err(tracerr::new!(E::LateFeeNotApplicable));
+        }
+
         let realty = self
             .database()
             .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
@@ -121,67 +196,263 @@ where
             ))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|Active(c)| c.agency_id == realty.agency_id)
             .ok_or(E::UserNotEmployer(employer_id))
             .map_err(tracerr::wrap!())
             .map(drop)?;
 
-        let tx = self
-            .database()
-            .execute(Transact)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?;
-
-        // Avoid concurrent actions upon the same `Realty`.
-        tx.execute(Lock(By::new(realty.id)))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
+        // `Contract` expires by the `Agency` default, unless specified.
+        let expires_at = if expires_at.is_some() {
+            expires_at
+        } else {
+            self.database()
+                .execute(Select(By::<Option<agency::Settings>, _>::new(
+                    realty.agency_id,
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .and_then(|s| s.expiration(DateTime::now()))
+        };
 
-        let Active(mut realty_contract) =
-            tx.execute(Select(By::<
-                Option<Active<contract::ManagementForRent>>,
-                _,
-            >::new(realty.id)))
+        let template = if let Some(id) = template_id {
+            // `contract::Template`s of other `Agency`s are not visible to the
+            // employer.
+            let template = self
+                .database()
+                .execute(Select(By::<Option<contract::Template>, _>::new(id)))
                 .await
                 .map_err(tracerr::map_from_and_wrap!(=> E))?
-                .ok_or(E::RealtyNotManaged(realty.id))
+                .filter(|t| t.agency_id == realty.agency_id)
+                .ok_or(E::TemplateNotExists(id))
                 .map_err(tracerr::wrap!())?;
-        if realty_contract.employer_id != employer_id {
-            // TODO: Reconsider this.
-            return Err(tracerr::new!(E::UserNotManager(employer_id)));
-        }
+            Some(template)
+        } else {
+            None
+        };
 
-        let contract = Contract::from(contract::Rent {
-            id: contract::Id::new(),
-            name,
-            description,
-            realty_id: realty.id,
-            purchaser_id: purchaser.id,
-            // Landlord existence is guaranteed by the
-            // `contract::ManagementForRent` existence.
-            landlord_id: realty_contract.landlord_id,
-            employer_id: employer.id,
-            price,
-            deposit,
-            created_at: DateTime::now().coerce(),
-            expires_at,
-            terminated_at: None,
-        });
-        tx.execute(Insert(contract.clone()))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
+        let mut attempt = 1;
+        let (contract, offer) = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
 
-        realty_contract.terminated_at = Some(DateTime::now().coerce());
-        tx.execute(Update(realty_contract.into()))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
+                // Avoid concurrent actions upon the same `Realty`.
+                tx.execute(Lock(By::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
 
-        tx.execute(Commit)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
+                let Active(mut realty_contract) =
+                    tx.execute(Select(By::<
+                        Option<Active<contract::ManagementForRent>>,
+                        _,
+                    >::new(realty.id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))?
+                        .ok_or(E::RealtyNotManaged(realty.id))
+                        .map_err(tracerr::wrap!())?;
+                if realty_contract.employer_id != employer_id {
+                    // TODO: Reconsider this.
+                    return Err(tracerr::new!(E::UserNotManager(employer_id)));
+                }
+
+                let is_rented = tx
+                    .execute(Select(By::<read::realty::IsRented, _>::new(
+                        realty.id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                if *is_rented {
+                    return Err(tracerr::new!(E::RealtyRented(realty.id)));
+                }
+
+                let rents = tx
+                    .execute(Select(By::<Vec<Active<contract::Rent>>, _>::new(
+                        realty.id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                let bookings = tx
+                    .execute(Select(By::<
+                        Vec<Active<contract::ShortTermRent>>,
+                        _,
+                    >::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                let calendar = Calendar::new(
+                    rents
+                        .iter()
+                        .map(|Active(c)| calendar::Occupancy::of_rent(c))
+                        .chain(bookings.iter().map(|Active(c)| {
+                            calendar::Occupancy::of_short_term_rent(c)
+                        })),
+                );
+                if !calendar.is_available(
+                    DateTime::now(),
+                    expires_at.map(DateTimeOf::coerce),
+                ) {
+                    return Err(tracerr::new!(E::RealtyOccupied(realty.id)));
+                }
+
+                let (name, description) =
+                    if let (Some(n), Some(d)) = (&name, &description) {
+                        (n.clone(), d.clone())
+                    } else {
+                        let template = template
+                            .as_ref()
+                            .ok_or(E::TemplateRequired)
+                            .map_err(tracerr::wrap!())?;
+                        let landlord_id = realty_contract.landlord_id;
+                        let landlord = tx
+                            .execute(Select(By::<Option<User>, _>::new(
+                                landlord_id,
+                            )))
+                            .await
+                            .map_err(tracerr::map_from_and_wrap!(=> E))?
+                            .ok_or(E::UserNotExists(landlord_id))
+                            .map_err(tracerr::wrap!())?;
+                        let fields = contract::template::Fields {
+                            realty: &realty,
+                            landlord: &landlord,
+                            purchaser,
+                            employer,
+                            price,
+                        };
+                        name.clone()
+                            .or_else(|| template.render_name(&fields))
+                            .zip(description.clone().or_else(|| {
+                                template.render_description(&fields)
+                            }))
+                            .ok_or(E::TemplateNotRenderable(template.id))
+                            .map_err(tracerr::wrap!())?
+                    };
+
+                let now = DateTime::now();
+                let number = tx
+                    .execute(Perform(By::new(read::contract::NumberSequence {
+                        agency_id: realty.agency_id,
+                        kind: contract::Kind::Rent,
+                        year: now.year(),
+                    })))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                let rent = contract::Rent {
+                    id: contract::Id::new(),
+                    agency_id: realty.agency_id,
+                    number,
+                    name,
+                    description,
+                    realty_id: realty.id,
+                    purchaser_id: purchaser.id,
+                    // Landlord existence is guaranteed by the
+                    // `contract::ManagementForRent` existence.
+                    landlord_id: realty_contract.landlord_id,
+                    employer_id: employer.id,
+                    price,
+                    deposit,
+                    created_at: now.coerce(),
+                    expires_at,
+                    completed_at: None,
+                    terminated_at: None,
+                    archived_at: None,
+                    version: Version::initial(),
+                };
+                let late_fee = late_fee.map(|p| LateFee::new(&rent, p));
+                let contract = Contract::from(rent);
+                // `Realty` may still be rented concurrently by an open-ended
+                // `contract::Rent`, which is guarded by the `Database` itself.
+                tx.execute(Insert(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map_err(|e| {
+                        if matches!(e.as_ref(), E::Db(e) if e.is_realty_rented())
+                        {
+                            tracerr::new!(E::RealtyRented(realty.id))
+                        } else {
+                            e
+                        }
+                    })
+                    .map(drop)?;
+                tx.execute(Insert(contract::Party::primary(&contract)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                if contract::Approval::is_required(
+                    price,
+                    self.config().approval_threshold,
+                ) {
+                    tx.execute(Insert(contract::Approval::pending(
+                        &contract,
+                        employer.id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                }
+                if let Some(fee) = late_fee {
+                    tx.execute(Insert(fee))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(drop)?;
+                }
+
+                let offer = if let Some(offer_id) = offer_id {
+                    let mut offer = tx
+                        .execute(Select(By::<Option<Offer>, _>::new(offer_id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))?
+                        .filter(|o| {
+                            o.realty_id == realty.id
+                                && o.bidder_id == purchaser.id
+                                && o.status == offer::Status::Accepted
+                                && o.concluded_contract_id.is_none()
+                        })
+                        .ok_or(E::OfferNotAccepted(offer_id))
+                        .map_err(tracerr::wrap!())?;
+                    offer.concluded_contract_id = Some(contract.id());
+                    tx.execute(Update(offer))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(drop)?;
+                    Some(offer)
+                } else {
+                    None
+                };
+
+                realty_contract.terminated_at = Some(DateTime::now().coerce());
+                tx.execute(Update(Contract::from(realty_contract)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>((contract, offer))
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+        // Terminated management `Contract` is not placed anymore.
+        self.placements_changed().await;
+
+        if let Some(offer) = offer {
+            self.notify_offer(offer);
+        }
 
         Ok(contract)
     }
@@ -195,6 +466,15 @@ pub enum ExecutionError {
     #[from]
     Db(database::Error),
 
+    /// [`late_fee::Policy`] is not applicable to the rent price.
+    #[display("Late fee policy is not applicable to the rent price")]
+    LateFeeNotApplicable,
+
+    /// [`Offer`] with the provided ID is not accepted by the purchaser on the
+    /// [`Realty`], or is concluded already.
+    #[display("`Offer(id: {_0})` is not accepted or concluded already")]
+    OfferNotAccepted(#[error(not(source))] offer::Id),
+
     /// [`Realty`] with the provided ID doesn't have a
     /// [`contract::ManagementForRent`].
     #[display(
@@ -202,6 +482,31 @@ pub enum ExecutionError {
     )]
     RealtyNotManaged(#[error(not(source))] realty::Id),
 
+    /// [`Realty`] with the provided ID is occupied by another
+    /// [`contract::Rent`] or [`contract::ShortTermRent`] within the period of
+    /// a new one.
+    #[display("`Realty(id: {_0})` is occupied by another `Contract`")]
+    RealtyOccupied(#[error(not(source))] realty::Id),
+
+    /// [`Realty`] with the provided ID is rented by another active
+    /// [`contract::Rent`].
+    #[display("`Realty(id: {_0})` is rented")]
+    RealtyRented(#[error(not(source))] realty::Id),
+
+    /// [`contract::Template`] with the provided ID does not exist.
+    #[display("`contract::Template(id: {_0})` does not exist")]
+    TemplateNotExists(#[error(not(source))] contract::template::Id),
+
+    /// [`contract::Template`] renders an invalid [`contract::Name`] or
+    /// [`contract::Description`].
+    #[display("`contract::Template(id: {_0})` renders invalid `Contract`")]
+    TemplateNotRenderable(#[error(not(source))] contract::template::Id),
+
+    /// Neither a [`contract::Name`] and a [`contract::Description`], nor a
+    /// [`contract::Template`] is provided.
+    #[display("`contract::Template` is required")]
+    TemplateRequired,
+
     /// [`User`] with the provided ID does not exist.
     #[display("`User(id: {_0})` does not exist")]
     UserNotExists(#[error(not(source))] user::Id),
@@ -0,0 +1,172 @@
+//! [`Command`] for creating a new [`Review`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, review, user, Contract, Review, Version},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for creating a new [`Review`] of the employer by a purchaser or
+/// a landlord of a completed [`contract::Rent`] or [`contract::Sale`].
+#[derive(Clone, Debug)]
+pub struct CreateReview {
+    /// ID of the completed [`Contract`] to leave a [`Review`] on.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] who leaves a [`Review`].
+    pub author_id: user::Id,
+
+    /// [`review::Rating`] of the employer.
+    pub rating: review::Rating,
+
+    /// [`review::Comment`] of the author, if any.
+    pub comment: Option<review::Comment>,
+}
+
+impl<Db> Command<CreateReview> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Review>, contract::Id>>,
+            Ok = Option<Review>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Review>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Review;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: CreateReview) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateReview {
+            contract_id,
+            author_id,
+            rating,
+            comment,
+        } = cmd;
+
+        // `Contract`s of other `User`s are not visible to the author.
+        let contract = self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::ContractNotExists(contract_id))
+            .map_err(tracerr::wrap!())?;
+        let (parties, employer_id, completed_at) = match &contract {
+            Contract::Rent(c) => (
+                [c.purchaser_id, c.landlord_id],
+                c.employer_id,
+                c.completed_at,
+            ),
+            Contract::Sale(c) => (
+                [c.purchaser_id, c.landlord_id],
+                c.employer_id,
+                c.completed_at,
+            ),
+            Contract::ShortTermRent(c) => (
+                [c.purchaser_id, c.landlord_id],
+                c.employer_id,
+                c.completed_at,
+            ),
+            Contract::Employment(_)
+            | Contract::ManagementForRent(_)
+            | Contract::ManagementForSale(_) => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        };
+        if !parties.contains(&author_id) {
+            return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+        }
+        if completed_at.is_none() {
+            return Err(tracerr::new!(E::ContractNotCompleted(contract_id)));
+        }
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent `Review`s of the same `Contract`.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let existing = tx
+            .execute(Select(By::<Option<Review>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if existing.is_some() {
+            return Err(tracerr::new!(E::ReviewAlreadyExists(contract_id)));
+        }
+
+        let review = Review {
+            id: review::Id::new(),
+            agency_id: contract.agency_id(),
+            contract_id,
+            employer_id,
+            author_id,
+            rating,
+            comment,
+            status: review::Status::Published,
+            created_at: DateTime::now().coerce(),
+            version: Version::initial(),
+        };
+        tx.execute(Insert(review.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(review)
+    }
+}
+
+/// Error of [`CreateReview`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`contract::Rent`] or [`contract::Sale`] with the provided ID does not
+    /// exist among the ones of the [`User`].
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Contract`] with the provided ID is not completed yet.
+    #[display("`Contract(id: {_0})` is not completed")]
+    #[from(ignore)]
+    ContractNotCompleted(#[error(not(source))] contract::Id),
+
+    /// [`Contract`] with the provided ID has a [`Review`] already.
+    #[display("`Contract(id: {_0})` is reviewed already")]
+    #[from(ignore)]
+    ReviewAlreadyExists(#[error(not(source))] contract::Id),
+}
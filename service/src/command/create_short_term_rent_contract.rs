@@ -0,0 +1,353 @@
+//! [`Command`] for creating a new [`contract::ShortTermRent`].
+
+use std::collections::HashMap;
+
+use common::{
+    operations::{
+        By, Commit, Insert, Lock, Perform, Select, Transact, Transacted,
+    },
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+use crate::{
+    domain::{
+        calendar, contract, contract::short_term_rent::SeasonalRate, realty,
+        user, Calendar, Contract, Realty, User, Version,
+    },
+    infra::{database, Database},
+    read::{self, contract::Active},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for creating a new [`contract::ShortTermRent`].
+///
+/// Booked nights are priced by the [`SeasonalRate`]s of the [`Realty`], and
+/// must not overlap with other active [`contract::Rent`]s and
+/// [`contract::ShortTermRent`]s of the same [`Realty`] in its [`Calendar`].
+///
+/// Unlike a [`contract::Rent`], a [`contract::ShortTermRent`] keeps the
+/// [`contract::ManagementForRent`] of the [`Realty`] active.
+#[derive(Clone, Debug)]
+pub struct CreateShortTermRentContract {
+    /// ID of the [`Realty`] to book.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`User`] who manages the [`Realty`].
+    pub employer_id: user::Id,
+
+    /// ID of the [`User`] who books the [`Realty`].
+    pub purchaser_id: user::Id,
+
+    /// Name of a new [`Contract`].
+    pub name: contract::Name,
+
+    /// Description of a new [`Contract`].
+    pub description: contract::Description,
+
+    /// [`DateTime`] when the purchaser checks in.
+    pub check_in_at: contract::short_term_rent::CheckInDateTime,
+
+    /// [`DateTime`] when the purchaser checks out.
+    pub check_out_at: contract::ExpirationDateTime,
+
+    /// Base nightly rate, applied to the nights not covered by any
+    /// [`SeasonalRate`].
+    pub nightly_rate: Money,
+
+    /// Deposit to be paid at the moment of booking.
+    pub deposit: Option<Money>,
+}
+
+impl<Db> Command<CreateShortTermRentContract> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<HashMap<user::Id, User>, [user::Id; 2]>>,
+            Ok = HashMap<user::Id, User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
+            Ok = Option<Active<contract::ManagementForRent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::Rent>>, realty::Id>>,
+            Ok = Vec<Active<contract::Rent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Active<contract::ShortTermRent>>, realty::Id>>,
+            Ok = Vec<Active<contract::ShortTermRent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<SeasonalRate>, realty::Id>>,
+            Ok = Vec<SeasonalRate>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Contract;
+    type Err = Traced<ExecutionError>;
+
+    #[expect(clippy::too_many_lines, reason = "still readable")]
+    async fn execute(
+        &self,
+        cmd: CreateShortTermRentContract,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let CreateShortTermRentContract {
+            realty_id,
+            employer_id,
+            purchaser_id,
+            name,
+            description,
+            check_in_at,
+            check_out_at,
+            nightly_rate,
+            deposit,
+        } = cmd;
+
+        if contract::ShortTermRent::nights(
+            check_in_at.coerce(),
+            check_out_at.coerce(),
+        ) == 0
+            || check_in_at.coerce::<()>() < DateTime::now()
+        {
+            return Err(tracerr::new!(E::InvalidPeriod));
+        }
+        if nightly_rate.amount <= Decimal::ZERO {
+            return Err(tracerr::new!(E::NonPositiveRate));
+        }
+
+        let realty = self
+            .database()
+            .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::RealtyNotManaged(realty_id))
+            .map_err(tracerr::wrap!())?;
+
+        let users = self
+            .database()
+            .execute(Select(By::new([employer_id, purchaser_id])))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        let employer = users
+            .get(&employer_id)
+            .ok_or(E::UserNotExists(employer_id))
+            .map_err(tracerr::wrap!())?;
+        let purchaser = users
+            .get(&purchaser_id)
+            .ok_or(E::UserNotExists(purchaser_id))
+            .map_err(tracerr::wrap!())?;
+
+        self.database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(employer_id),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|Active(c)| c.agency_id == realty.agency_id)
+            .ok_or(E::UserNotEmployer(employer_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let mut attempt = 1;
+        let contract = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                // Avoid concurrent bookings of the same `Realty`.
+                tx.execute(Lock(By::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                let Active(realty_contract) = tx
+                    .execute(Select(By::<
+                        Option<Active<contract::ManagementForRent>>,
+                        _,
+                    >::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .ok_or(E::RealtyNotManaged(realty.id))
+                    .map_err(tracerr::wrap!())?;
+                if realty_contract.employer_id != employer_id {
+                    return Err(tracerr::new!(E::UserNotManager(employer_id)));
+                }
+
+                let rents = tx
+                    .execute(Select(By::<Vec<Active<contract::Rent>>, _>::new(
+                        realty.id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                let bookings = tx
+                    .execute(Select(By::<
+                        Vec<Active<contract::ShortTermRent>>,
+                        _,
+                    >::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                let calendar = Calendar::new(
+                    rents
+                        .iter()
+                        .map(|Active(c)| calendar::Occupancy::of_rent(c))
+                        .chain(bookings.iter().map(|Active(c)| {
+                            calendar::Occupancy::of_short_term_rent(c)
+                        })),
+                );
+                if !calendar.is_available(
+                    check_in_at.coerce(),
+                    Some(check_out_at.coerce()),
+                ) {
+                    return Err(tracerr::new!(E::RealtyOccupied(realty.id)));
+                }
+
+                let seasonal_rates = tx
+                    .execute(Select(By::<Vec<SeasonalRate>, _>::new(realty.id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+                let price = contract::ShortTermRent::quote(
+                    check_in_at.coerce(),
+                    check_out_at.coerce(),
+                    nightly_rate,
+                    &seasonal_rates,
+                )
+                .ok_or(E::CurrencyMismatch)
+                .map_err(tracerr::wrap!())?;
+
+                let now = DateTime::now();
+                let number = tx
+                    .execute(Perform(By::new(read::contract::NumberSequence {
+                        agency_id: realty.agency_id,
+                        kind: contract::Kind::ShortTermRent,
+                        year: now.year(),
+                    })))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                let contract = Contract::from(contract::ShortTermRent {
+                    id: contract::Id::new(),
+                    agency_id: realty.agency_id,
+                    number,
+                    name: name.clone(),
+                    description: description.clone(),
+                    realty_id: realty.id,
+                    purchaser_id: purchaser.id,
+                    // Landlord existence is guaranteed by the
+                    // `contract::ManagementForRent` existence.
+                    landlord_id: realty_contract.landlord_id,
+                    employer_id: employer.id,
+                    nightly_rate,
+                    price,
+                    deposit,
+                    check_in_at,
+                    created_at: now.coerce(),
+                    expires_at: check_out_at,
+                    completed_at: None,
+                    terminated_at: None,
+                    archived_at: None,
+                    version: Version::initial(),
+                });
+                tx.execute(Insert(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                tx.execute(Insert(contract::Party::primary(&contract)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(contract)
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+
+        Ok(contract)
+    }
+}
+
+/// Error of [`CreateShortTermRentContract`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`SeasonalRate`]s of the [`Realty`] are in a currency different from
+    /// the nightly rate.
+    #[display("Seasonal rates are in a different currency")]
+    CurrencyMismatch,
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    #[from]
+    Db(database::Error),
+
+    /// Booked period doesn't span a whole night, or starts in the past.
+    #[display("Booked period must span at least one night in the future")]
+    InvalidPeriod,
+
+    /// Nightly rate is not positive.
+    #[display("Nightly rate must be positive")]
+    NonPositiveRate,
+
+    /// [`Realty`] with the provided ID doesn't have a
+    /// [`contract::ManagementForRent`].
+    #[display(
+        "`Realty(id: {_0})` doesn't have a `contract::ManagementForRent`"
+    )]
+    RealtyNotManaged(#[error(not(source))] realty::Id),
+
+    /// [`Realty`] with the provided ID is occupied by another
+    /// [`contract::Rent`] or [`contract::ShortTermRent`] within the booked
+    /// period.
+    #[display("`Realty(id: {_0})` is occupied within the booked period")]
+    RealtyOccupied(#[error(not(source))] realty::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    UserNotExists(#[error(not(source))] user::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] is not a manager of the [`Realty`].
+    #[display("`User(id: {_0})` is not a manager of the `Realty`")]
+    UserNotManager(#[error(not(source))] user::Id),
+}
@@ -64,6 +64,18 @@ where
         if email.is_none() && phone.is_none() {
             return Err(tracerr::new!(E::NoContactInfo));
         }
+        let violations =
+            self.password_violations(password.expose_secret()).await;
+        if !violations.is_empty() {
+            return Err(tracerr::new!(E::WeakPassword(violations)));
+        }
+        if let Some(email) = &email {
+            if !self.accepts_mail(email).await {
+                return Err(tracerr::new!(E::UndeliverableEmail(
+                    email.clone()
+                )));
+            }
+        }
 
         let u = self
             .database()
@@ -118,4 +130,13 @@ pub enum ExecutionError {
     /// No contact information provided.
     #[display("No contact information provided")]
     NoContactInfo,
+
+    /// Domain of the provided [`user::Email`] doesn't accept mail.
+    #[display("`{_0}` email domain does not accept mail")]
+    UndeliverableEmail(#[error(not(source))] user::Email),
+
+    /// Provided [`user::Password`] violates the
+    /// [`user::password::Policy`].
+    #[display("`Password` violates {_0:?} rules")]
+    WeakPassword(#[error(not(source))] Vec<user::password::Rule>),
 }
@@ -11,7 +11,7 @@ use secrecy::{ExposeSecret, SecretBox};
 use tracerr::Traced;
 
 #[cfg(doc)]
-use crate::domain::user::{session::Token, Login, Password};
+use crate::domain::user::{session::Token, Identifier, Password};
 use crate::{
     domain::{
         user::{self, session, Session},
@@ -28,8 +28,8 @@ use super::Command;
 pub enum CreateUserSession {
     /// Create a new [`Session`] by [`User`] credentials.
     ByCredentials {
-        /// [`Login`] of a [`User`].
-        login: user::Login,
+        /// [`Identifier`] of a [`User`].
+        identifier: user::Identifier,
 
         /// [`Password`] of a [`User`].
         password: SecretBox<user::Password>,
@@ -67,6 +67,10 @@ where
             Select<By<Option<User>, &'l user::Login>>,
             Ok = Option<User>,
             Err = Traced<database::Error>,
+        > + for<'l> Database<
+            Select<By<Option<User>, &'l user::Email>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
         >,
 {
     type Ok = Output;
@@ -80,14 +84,21 @@ where
         use ExecutionError as E;
 
         let user = match cmd {
-            Cmd::ByCredentials { login, password } => {
-                let user = self
-                    .database()
-                    .execute(Select(By::new(&login)))
-                    .await
-                    .map_err(tracerr::map_from_and_wrap!(=> E))?
-                    .ok_or_else(|| E::WrongCredentials)
-                    .map_err(tracerr::wrap!())?;
+            Cmd::ByCredentials {
+                identifier,
+                password,
+            } => {
+                let user = match &identifier {
+                    user::Identifier::Login(login) => {
+                        self.database().execute(Select(By::new(login))).await
+                    }
+                    user::Identifier::Email(email) => {
+                        self.database().execute(Select(By::new(email))).await
+                    }
+                }
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or_else(|| E::WrongCredentials)
+                .map_err(tracerr::wrap!())?;
 
                 let hash = user::PasswordHash::new(password.expose_secret());
                 if user.password_hash != hash {
@@ -110,6 +121,7 @@ where
             &jsonwebtoken::Header::default(),
             &Session {
                 user_id: user.id,
+                impersonated_by: None,
                 expires_at,
             },
             &self.config.jwt_encoding_key,
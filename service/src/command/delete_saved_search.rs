@@ -0,0 +1,81 @@
+//! [`Command`] for deleting a [`SavedSearch`].
+
+use common::operations::{By, Delete, Select};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{saved_search, user, SavedSearch},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for deleting a [`SavedSearch`] of a [`User`], so they're not
+/// alerted about it anymore.
+#[derive(Clone, Copy, Debug)]
+pub struct DeleteSavedSearch {
+    /// ID of the [`SavedSearch`] to delete.
+    pub id: saved_search::Id,
+
+    /// ID of the [`User`] deleting the [`SavedSearch`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<DeleteSavedSearch> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<SavedSearch>, saved_search::Id>>,
+            Ok = Option<SavedSearch>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<SavedSearch, saved_search::Id>>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = SavedSearch;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: DeleteSavedSearch,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let DeleteSavedSearch { id, initiator_id } = cmd;
+
+        // Only the owner deletes the `SavedSearch`.
+        let search = self
+            .database()
+            .execute(Select(By::<Option<SavedSearch>, _>::new(id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|s| s.user_id == initiator_id)
+            .ok_or_else(|| tracerr::new!(E::SavedSearchNotExists(id)))?;
+
+        self.database()
+            .execute(Delete(By::<SavedSearch, _>::new(id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        Ok(search)
+    }
+}
+
+/// Error of [`DeleteSavedSearch`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`SavedSearch`] with the provided ID does not exist or belongs to
+    /// another [`User`].
+    #[display("`SavedSearch(id: {_0})` does not exist")]
+    #[from(ignore)]
+    SavedSearchNotExists(#[error(not(source))] saved_search::Id),
+}
@@ -15,7 +15,7 @@ use crate::{
     Service,
 };
 
-use super::Command;
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
 
 /// [`Command`] for deplacing a [`Contract`] as [`Placement`].
 #[derive(Clone, Copy, Debug)]
@@ -77,7 +77,8 @@ where
             .ok_or(E::UserNotExists(initiator_id))
             .map_err(tracerr::wrap!())?;
 
-        self.database()
+        let Active(employment) = self
+            .database()
             .execute(Select(
                 By::<Option<Active<contract::Employment>>, _>::new(
                     initiator.id,
@@ -86,65 +87,90 @@ where
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
             .ok_or(E::UserNotEmployer(initiator.id))
-            .map_err(tracerr::wrap!())
-            .map(drop)?;
+            .map_err(tracerr::wrap!())?;
 
+        // `Contract`s of other `Agency`s are not visible to the initiator.
         let contract = self
             .database()
             .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
             .filter(Contract::is_active)
+            .filter(|c| c.agency_id() == employment.agency_id)
             .ok_or(E::ContractNotExists(contract_id))
             .map_err(tracerr::wrap!())?;
 
-        let tx = self
-            .database()
-            .execute(Transact)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?;
-
-        if let Some(realty_id) = contract.realty_id() {
-            // Avoid concurrent actions upon the same `Realty`.
-            tx.execute(Lock(By::new(realty_id)))
-                .await
-                .map_err(tracerr::map_from_and_wrap!(=> E))
-                .map(drop)?;
-        }
-
-        // Avoid concurrent deletions.
-        tx.execute(Lock(By::new(contract.id())))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
-
-        let mut contract = tx
-            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?
-            .filter(Contract::is_active)
-            .ok_or(E::ContractNotExists(contract_id))
-            .map_err(tracerr::wrap!())?;
-
-        if let Some(is_placed) = contract.is_placed_mut() {
-            if !*is_placed {
-                return Err(tracerr::new!(E::ContractNotPlaced(contract_id)));
+        let mut attempt = 1;
+        let contract = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                if let Some(realty_id) = contract.realty_id() {
+                    // Avoid concurrent actions upon the same `Realty`.
+                    tx.execute(Lock(By::new(realty_id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(drop)?;
+                }
+
+                // Avoid concurrent deletions.
+                tx.execute(Lock(By::new(contract.id())))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                let mut contract = tx
+                    .execute(Select(By::<Option<Contract>, _>::new(
+                        contract_id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(Contract::is_active)
+                    .ok_or(E::ContractNotExists(contract_id))
+                    .map_err(tracerr::wrap!())?;
+
+                if let Some(is_placed) = contract.is_placed_mut() {
+                    if !*is_placed {
+                        return Err(tracerr::new!(E::ContractNotPlaced(
+                            contract_id
+                        )));
+                    }
+
+                    *is_placed = false;
+                } else {
+                    return Err(tracerr::new!(E::UnsupportedContract(
+                        contract_id
+                    )));
+                }
+
+                tx.execute(Insert(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(contract)
             }
-
-            *is_placed = false;
-        } else {
-            return Err(tracerr::new!(E::UnsupportedContract(contract_id)));
-        }
-
-        tx.execute(Insert(contract.clone()))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
-
-        tx.execute(Commit)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+        self.placements_changed().await;
 
         Ok(contract)
     }
@@ -0,0 +1,116 @@
+//! [`Command`] for detaching an [`Insurance`] from a [`Contract`].
+
+use common::operations::{By, Delete, Select};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, user, Contract, Insurance},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for detaching an [`Insurance`] from a [`Contract`].
+#[derive(Clone, Copy, Debug)]
+pub struct DetachContractInsurance {
+    /// ID of the [`Contract`] to detach the [`Insurance`] from.
+    pub contract_id: contract::Id,
+
+    /// ID of the employer [`User`] detaching the [`Insurance`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<DetachContractInsurance> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Insurance>, contract::Id>>,
+            Ok = Option<Insurance>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<Insurance, contract::Id>>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = Insurance;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: DetachContractInsurance,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let DetachContractInsurance {
+            contract_id,
+            initiator_id,
+        } = cmd;
+
+        // Only the responsible employer detaches `Insurance`s.
+        match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c)) if c.employer_id == initiator_id => {}
+            Some(Contract::ManagementForRent(c))
+                if c.employer_id == initiator_id => {}
+            Some(Contract::ManagementForSale(c))
+                if c.employer_id == initiator_id => {}
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        }
+
+        let insurance = self
+            .database()
+            .execute(Select(By::<Option<Insurance>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::InsuranceNotExists(contract_id))
+            .map_err(tracerr::wrap!())?;
+
+        self.database()
+            .execute(Delete(By::<Insurance, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(insurance)
+    }
+}
+
+/// Error of [`DetachContractInsurance`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] with the provided ID does not exist, does not support
+    /// [`Insurance`]s, or is not managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// No [`Insurance`] is attached to the [`Contract`].
+    #[display("`Contract(id: {_0})` has no insurance attached")]
+    #[from(ignore)]
+    InsuranceNotExists(#[error(not(source))] contract::Id),
+}
@@ -0,0 +1,120 @@
+//! [`Command`] for exporting a logical snapshot of all the entities.
+
+use std::{fmt::Write as _, io};
+
+use common::operations::{By, Perform, Select, Transact, Transacted};
+use derive_more::{Display, Error, From};
+use futures::io::{AsyncWrite, AsyncWriteExt as _};
+use tracerr::Traced;
+
+use crate::{
+    infra::{database, Database},
+    read::snapshot::{Chunk, Record},
+    Service,
+};
+
+use super::Command;
+
+/// Number of the [`Record`]s selected at once.
+const CHUNK_SIZE: usize = 1000;
+
+/// [`Command`] for exporting a consistent logical snapshot of all the
+/// entities, as newline-delimited JSON, for lightweight backups and
+/// environment cloning.
+///
+/// Every line is a JSON object of a single [`Record`]:
+/// `{"entity":"<name>","data":{<fields>}}`.
+///
+/// All the [`Record`]s are selected inside a single read-only transaction, so
+/// concurrent changes don't break the snapshot consistency. Returns the number
+/// of the exported [`Record`]s.
+///
+/// Exported snapshot contains sensitive data (like password hashes), so should
+/// be stored securely.
+#[derive(Debug)]
+pub struct ExportSnapshot<W> {
+    /// [`AsyncWrite`]r to export the snapshot into.
+    pub output: W,
+}
+
+impl<Db, W> Command<ExportSnapshot<W>> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Perform<database::Snapshot>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Record>, Chunk>>,
+            Ok = Vec<Record>,
+            Err = Traced<database::Error>,
+        >,
+    W: AsyncWrite + Send + Unpin,
+{
+    type Ok = u64;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: ExportSnapshot<W>,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let ExportSnapshot { mut output } = cmd;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        tx.execute(Perform(database::Snapshot))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        let mut exported = 0;
+        let mut after = None;
+        loop {
+            let records = tx
+                .execute(Select(By::new(Chunk {
+                    after,
+                    limit: CHUNK_SIZE,
+                })))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+            let Some(last) = records.last() else {
+                break;
+            };
+            after = Some(last.position.clone());
+
+            let mut lines = String::new();
+            for Record { entity, data, .. } in &records {
+                // Entity names are plain identifiers, and the data is a JSON
+                // object already, so need no escaping.
+                _ = writeln!(lines, r#"{{"entity":"{entity}","data":{data}}}"#);
+            }
+            output
+                .write_all(lines.as_bytes())
+                .await
+                .map_err(tracerr::from_and_wrap!(=> E))?;
+            exported += records.len() as u64;
+        }
+        output
+            .flush()
+            .await
+            .map_err(tracerr::from_and_wrap!(=> E))?;
+
+        // Read-only transaction has nothing to commit, so is just dropped.
+        Ok(exported)
+    }
+}
+
+/// Error of [`ExportSnapshot`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Failed to write the snapshot into the output.
+    #[display("Failed to write the snapshot: {_0}")]
+    Io(io::Error),
+}
@@ -0,0 +1,189 @@
+//! [`Command`] for impersonating a [`User`].
+
+use std::time::Duration;
+
+use common::{
+    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{
+        agency, audit,
+        user::{self, session, Session},
+        Agency, User,
+    },
+    infra::{database, Database},
+    Service,
+};
+
+use super::{create_user_session::Output, Command};
+
+/// [`Command`] for creating a short-lived [`Session`] of a [`User`] on behalf
+/// of the platform operator (administrator of the [`agency::Id::DEFAULT`]
+/// [`Agency`]), for support and debugging of the [`User`]-specific issues.
+///
+/// [`Agency`] administrators (including the operator) cannot be impersonated.
+/// Every impersonation is recorded into the audit log.
+#[derive(Clone, Copy, Debug)]
+pub struct ImpersonateUser {
+    /// ID of the [`User`] to impersonate.
+    pub user_id: user::Id,
+
+    /// ID of the operator [`User`] impersonating the [`User`].
+    pub initiator_id: user::Id,
+}
+
+impl ImpersonateUser {
+    /// [`Duration`] of the impersonating [`Session`] expiration.
+    const EXPIRATION_DURATION: Duration = Duration::from_secs(10 * 60);
+}
+
+impl<Db> Command<ImpersonateUser> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Agency>, user::Id>>,
+            Ok = Vec<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<Insert<audit::Entry>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Output;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: ImpersonateUser,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let ImpersonateUser {
+            user_id,
+            initiator_id,
+        } = cmd;
+
+        if user_id == initiator_id {
+            return Err(tracerr::new!(E::SelfImpersonation));
+        }
+
+        self.database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency::Id::DEFAULT)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.admin_id == initiator_id)
+            .ok_or(E::UserNotAdmin(initiator_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let user = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(user_id))
+            .map_err(tracerr::wrap!())?;
+
+        let administrated = self
+            .database()
+            .execute(Select(By::<Vec<Agency>, _>::new(user.id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if !administrated.is_empty() {
+            return Err(tracerr::new!(E::UserIsAdmin(user.id)));
+        }
+
+        // Audit entry is committed only along with the issued `Session`, so
+        // no impersonation happens unrecorded.
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        let now = DateTime::now();
+        tx.execute(Insert(audit::Entry {
+            id: audit::Id::new(),
+            agency_id: agency::Id::DEFAULT,
+            actor_id: initiator_id,
+            action: audit::Action::ImpersonateUser,
+            entity: audit::Entity::User,
+            entity_id: user.id.into(),
+            before: None,
+            after: None,
+            created_at: now.coerce(),
+        }))
+        .await
+        .map_err(tracerr::map_from_and_wrap!(=> E))
+        .map(drop)?;
+
+        let expires_at = (now + ImpersonateUser::EXPIRATION_DURATION).coerce();
+        let token = jsonwebtoken::encode::<Session>(
+            &jsonwebtoken::Header::default(),
+            &Session {
+                user_id: user.id,
+                impersonated_by: Some(initiator_id),
+                expires_at,
+            },
+            &self.config.jwt_encoding_key,
+        )
+        .map_err(tracerr::from_and_wrap!(=> E))?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        // SAFETY: `jsonwebtoken::encode` always returns a valid
+        //         `session::Token`.
+        #[expect(unsafe_code, reason = "invariants are preserved")]
+        let token = unsafe { session::Token::new_unchecked(token) };
+
+        Ok(Output {
+            token,
+            user,
+            expires_at,
+        })
+    }
+}
+
+/// Error of [`ImpersonateUser`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`jsonwebtoken`] encoding error.
+    #[display("Failed to encode a JSON Web Token: {_0}")]
+    JsonWebTokenEncodeError(jsonwebtoken::errors::Error),
+
+    /// [`User`] tries to impersonate themselves.
+    #[display("`User` cannot impersonate themselves")]
+    SelfImpersonation,
+
+    /// [`User`] to impersonate administrates an [`Agency`].
+    #[display("`User(id: {_0})` is an administrator")]
+    #[from(ignore)]
+    UserIsAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] is not an administrator of the default [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
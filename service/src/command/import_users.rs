@@ -0,0 +1,212 @@
+//! [`Command`] for importing [`User`]s in bulk.
+
+use std::time::Duration;
+
+use common::{
+    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{user::Password, Agency};
+use crate::{
+    domain::{agency, contract, user, User},
+    infra::{cache, database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for importing [`User`]s in bulk from their CSV representation,
+/// for [`Agency`]s migrating from spreadsheets or another CRM.
+///
+/// Imported [`User`]s have no known [`Password`], and set one by accepting
+/// their [`user::Invite`]s into the importing [`Agency`]. Either all the
+/// [`User`]s are imported, or none of them.
+#[derive(Clone, Debug)]
+pub struct ImportUsers {
+    /// CSV representation of the [`User`]s to be imported, as described by
+    /// the [`user::csv::parse()`].
+    pub csv: String,
+
+    /// ID of the [`Agency`] the [`User`]s are invited into.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] employed by the [`Agency`] importing the [`User`]s.
+    pub initiator_id: user::Id,
+}
+
+impl ImportUsers {
+    /// [`Duration`] of the [`user::Invite`]s expiration.
+    const INVITE_EXPIRATION: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+}
+
+/// [`User`] imported by the [`ImportUsers`] [`Command`].
+#[derive(Clone, Debug)]
+pub struct Imported {
+    /// Imported [`User`].
+    pub user: User,
+
+    /// Link to accept the [`user::Invite`] of the imported [`User`] by.
+    pub invite_link: String,
+}
+
+impl<Db> Command<ImportUsers> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + for<'l> Database<
+            Select<By<Option<User>, &'l user::Login>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<Insert<User>, Err = Traced<database::Error>>
+        + Database<Insert<user::Invite>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<Imported>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: ImportUsers) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let ImportUsers {
+            csv,
+            agency_id,
+            initiator_id,
+        } = cmd;
+
+        self.database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|Active(c)| c.agency_id == agency_id)
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let rows = user::csv::parse(&csv)
+            .map_err(E::InvalidCsv)
+            .map_err(tracerr::wrap!())?;
+
+        let mut occupied = vec![];
+        for row in &rows {
+            let u = self
+                .database()
+                .execute(Select(By::new(&row.login)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+            if u.is_some() {
+                occupied.push(row.login.clone());
+            }
+        }
+        if !occupied.is_empty() {
+            return Err(tracerr::new!(E::LoginsOccupied(occupied)));
+        }
+
+        let now = DateTime::now();
+        let expires_at = (now + ImportUsers::INVITE_EXPIRATION).coerce();
+        let invited = rows
+            .into_iter()
+            .map(|row| {
+                // Nobody knows the `Password` of an imported `User`, until its
+                // `user::Invite` is accepted.
+                // SAFETY: `user::invite::Token` always matches the `Password`
+                //         format.
+                #[expect(unsafe_code, reason = "invariants are preserved")]
+                let password = unsafe {
+                    user::Password::new_unchecked(
+                        user::invite::Token::generate().to_string(),
+                    )
+                };
+                let user = User {
+                    id: user::Id::new(),
+                    name: row.name,
+                    login: row.login,
+                    password_hash: user::PasswordHash::new(&password),
+                    email: row.email,
+                    phone: row.phone,
+                    created_at: now.coerce(),
+                    deleted_at: None,
+                };
+                let token = user::invite::Token::generate();
+                let invite = user::Invite {
+                    user_id: user.id,
+                    agency_id,
+                    token_hash: token.hash(),
+                    expires_at,
+                    created_at: now.coerce(),
+                };
+                (user, invite, token)
+            })
+            .collect::<Vec<_>>();
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        for (user, invite, _) in &invited {
+            tx.execute(Insert(user.clone()))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+            tx.execute(Insert(invite.clone()))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+        }
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        let keys = invited
+            .iter()
+            .map(|(user, ..)| cache::key::user(user.id))
+            .collect::<Vec<_>>();
+        self.invalidate_cache(&keys).await;
+
+        Ok(invited
+            .into_iter()
+            .map(|(user, _, token)| Imported {
+                user,
+                invite_link: format!(
+                    "{}?token={token}",
+                    self.config().invite_url,
+                ),
+            })
+            .collect())
+    }
+}
+
+/// Error of [`ImportUsers`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Provided CSV contains [`user::csv::Violation`]s.
+    #[display("CSV is invalid: {_0:?}")]
+    #[from(ignore)]
+    InvalidCsv(#[error(not(source))] Vec<user::csv::Violation>),
+
+    /// [`user::Login`]s are already occupied.
+    #[display("{_0:?} logins are occupied")]
+    #[from(ignore)]
+    LoginsOccupied(#[error(not(source))] Vec<user::Login>),
+
+    /// [`User`] is not an employer of the [`Agency`].
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,60 @@
+//! [`Command`] for issuing an [`ical::Feed`].
+
+use common::{operations::Update, DateTime};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{ical, user},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for issuing an [`ical::Feed`] of a [`User`], replacing (and so
+/// revoking) the previously issued one, if any.
+#[derive(Clone, Copy, Debug, From)]
+pub struct IssueCalendarFeed {
+    /// ID of the [`User`] to issue the [`ical::Feed`] for.
+    pub user_id: user::Id,
+}
+
+impl<Db> Command<IssueCalendarFeed> for Service<Db>
+where
+    Db: Database<Update<ical::Feed>, Ok = (), Err = Traced<database::Error>>,
+{
+    /// [`ical::Token`] of the issued [`ical::Feed`], which cannot be obtained
+    /// anymore afterwards.
+    type Ok = ical::Token;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: IssueCalendarFeed,
+    ) -> Result<Self::Ok, Self::Err> {
+        let IssueCalendarFeed { user_id } = cmd;
+
+        let token = ical::Token::generate();
+        self.database()
+            .execute(Update(ical::Feed {
+                user_id,
+                token_hash: token.hash(),
+                created_at: DateTime::now().coerce(),
+            }))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+        Ok(token)
+    }
+}
+
+/// Error of [`IssueCalendarFeed`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+}
@@ -1,17 +1,68 @@
 //! [`Command`] definition.
 
+pub mod accept_offer;
+pub mod accept_user_invite;
+pub mod add_contract_party;
+pub mod add_rent_charge;
+pub mod approve_contract;
+pub mod archive_contract;
+pub mod assign_branch;
+pub mod assign_realty;
+pub mod attach_contract_insurance;
 pub mod authorize_user_session;
+pub mod check_in_realty_key;
+pub mod check_out_realty_key;
+pub mod counter_offer;
+pub mod create_agency;
+pub mod create_branch;
 pub mod create_employment_contract;
+pub mod create_lead;
 pub mod create_management_for_rent_contract;
 pub mod create_management_for_sale_contract;
 pub mod create_realty;
+pub mod create_realty_key;
+pub mod create_review;
 pub mod create_rent_contract;
 pub mod create_sale_contract;
+pub mod create_short_term_rent_contract;
 pub mod create_user;
 pub mod create_user_session;
+pub mod decline_offer;
+pub mod delete_saved_search;
 pub mod deplace_contract;
+pub mod detach_contract_insurance;
+pub mod export_snapshot;
+pub mod hide_review;
+pub mod impersonate_user;
+pub mod import_users;
+pub mod issue_calendar_feed;
+pub mod move_lead;
 pub mod place_contract;
+pub mod publish_review;
+pub mod record_rent_inventory;
+pub mod record_rent_payment;
+pub mod record_sms_status;
+pub mod remove_rent_charge;
+pub mod requeue_task_dead_letters;
+pub mod restore_contract;
+pub mod restore_realty;
+pub mod restore_user;
+pub mod revoke_calendar_feed;
+pub mod save_contract_template;
+pub mod save_search;
+pub mod set_realty_floor_plans;
+pub mod set_realty_media_links;
+pub mod set_realty_owners;
+pub mod set_seasonal_rates;
+pub mod sign_contract;
+pub mod split_contract_commission;
+pub mod submit_offer;
 pub mod terminate_contract;
+pub mod update_agency_admin;
+pub mod update_agency_name;
+pub mod update_agency_settings;
+pub mod update_contract_metadata;
+pub mod update_task_settings;
 pub mod update_user_email;
 pub mod update_user_name;
 pub mod update_user_password;
@@ -22,16 +73,353 @@ pub mod update_user_phone;
 /// [`Service`]: crate::Service
 pub use common::Handler as Command;
 
+/// Maximum number of attempts to execute a [`Command`], whose changes conflict
+/// with concurrent modifications of the same entities.
+const MAX_CONFLICT_ATTEMPTS: usize = 3;
+
 pub use self::{
+    accept_offer::AcceptOffer,
+    accept_user_invite::AcceptUserInvite,
+    add_contract_party::AddContractParty,
+    add_rent_charge::AddRentCharge,
+    approve_contract::ApproveContract,
+    archive_contract::ArchiveContract,
+    assign_branch::AssignBranch, assign_realty::AssignRealty,
+    attach_contract_insurance::AttachContractInsurance,
     authorize_user_session::AuthorizeUserSession,
+    check_in_realty_key::CheckInRealtyKey,
+    check_out_realty_key::CheckOutRealtyKey,
+    counter_offer::CounterOffer,
+    create_agency::CreateAgency,
+    create_branch::CreateBranch,
     create_employment_contract::CreateEmploymentContract,
+    create_lead::CreateLead,
     create_management_for_rent_contract::CreateManagementForRentContract,
     create_management_for_sale_contract::CreateManagementForSaleContract,
-    create_realty::CreateRealty, create_rent_contract::CreateRentContract,
-    create_sale_contract::CreateSaleContract, create_user::CreateUser,
-    create_user_session::CreateUserSession, deplace_contract::DeplaceContract,
-    place_contract::PlaceContract, terminate_contract::TerminateContract,
+    create_realty::CreateRealty, create_realty_key::CreateRealtyKey,
+    create_review::CreateReview,
+    create_rent_contract::CreateRentContract,
+    create_sale_contract::CreateSaleContract,
+    create_short_term_rent_contract::CreateShortTermRentContract,
+    create_user::CreateUser,
+    create_user_session::CreateUserSession, decline_offer::DeclineOffer,
+    delete_saved_search::DeleteSavedSearch,
+    deplace_contract::DeplaceContract,
+    detach_contract_insurance::DetachContractInsurance,
+    export_snapshot::ExportSnapshot,
+    hide_review::HideReview, impersonate_user::ImpersonateUser,
+    import_users::ImportUsers,
+    issue_calendar_feed::IssueCalendarFeed,
+    move_lead::MoveLead, place_contract::PlaceContract,
+    publish_review::PublishReview,
+    record_rent_inventory::RecordRentInventory,
+    record_rent_payment::RecordRentPayment,
+    record_sms_status::RecordSmsStatus,
+    remove_rent_charge::RemoveRentCharge,
+    requeue_task_dead_letters::RequeueTaskDeadLetters,
+    restore_contract::RestoreContract,
+    restore_realty::RestoreRealty,
+    restore_user::RestoreUser,
+    revoke_calendar_feed::RevokeCalendarFeed,
+    save_contract_template::SaveContractTemplate, save_search::SaveSearch,
+    set_realty_floor_plans::SetRealtyFloorPlans,
+    set_realty_media_links::SetRealtyMediaLinks,
+    set_realty_owners::SetRealtyOwners,
+    set_seasonal_rates::SetSeasonalRates,
+    sign_contract::SignContract,
+    split_contract_commission::SplitContractCommission,
+    submit_offer::SubmitOffer,
+    terminate_contract::TerminateContract,
+    update_agency_admin::UpdateAgencyAdmin,
+    update_agency_name::UpdateAgencyName,
+    update_agency_settings::UpdateAgencySettings,
+    update_contract_metadata::UpdateContractMetadata,
+    update_task_settings::UpdateTaskSettings,
     update_user_email::UpdateUserEmail, update_user_name::UpdateUserName,
     update_user_password::UpdateUserPassword,
     update_user_phone::UpdateUserPhone,
 };
+
+#[cfg(test)]
+mod spec {
+    use std::sync::{Arc, Mutex, PoisonError};
+
+    use common::{
+        money::Currency,
+        operations::{By, Commit, Insert, Lock, Select, Transact, Update},
+        DateTime, Money,
+    };
+    use futures::executor::block_on;
+    use rust_decimal::Decimal;
+    use tracerr::Traced;
+
+    use crate::{
+        domain::{
+            agency, contract, realty, user, Contract, Realty, User, Version,
+        },
+        infra::{cache, database, Database},
+        read::contract::Active,
+        spec::{service, RecordingCache},
+    };
+
+    use super::{Command as _, DeplaceContract, UpdateUserName};
+
+    /// In-memory [`Database`] of an employed [`User`] and a placed
+    /// [`Contract`] managed by them.
+    #[derive(Clone, Debug)]
+    struct Fake {
+        /// Employed [`User`].
+        user: Arc<Mutex<User>>,
+
+        /// [`contract::Employment`] of the [`Fake::user`].
+        employment: contract::Employment,
+
+        /// [`contract::ManagementForRent`] managed by the [`Fake::user`].
+        contract: Arc<Mutex<Contract>>,
+    }
+
+    impl Fake {
+        fn new() -> Self {
+            let agency_id = agency::Id::new();
+            let money = Money {
+                amount: Decimal::from(1000),
+                currency: Currency::Usd,
+            };
+            let user = User {
+                id: user::Id::new(),
+                name: user::Name::new("John Doe").unwrap(),
+                login: user::Login::new("johndoe").unwrap(),
+                password_hash: user::PasswordHash::new(
+                    &user::Password::new("secret").unwrap(),
+                ),
+                email: user::Email::new("john@example.com"),
+                phone: None,
+                created_at: DateTime::now().coerce(),
+                deleted_at: None,
+            };
+            let employment = contract::Employment {
+                id: contract::Id::new(),
+                agency_id,
+                number: contract::Number::new(
+                    contract::Kind::Employment,
+                    2024,
+                    1,
+                ),
+                name: contract::Name::new("Employment").unwrap(),
+                description: contract::Description::new("Agent").unwrap(),
+                employer_id: user.id,
+                manager_id: None,
+                branch_id: None,
+                base_salary: money,
+                created_at: DateTime::now().coerce(),
+                expires_at: None,
+                completed_at: None,
+                terminated_at: None,
+                archived_at: None,
+                version: Version::initial(),
+            };
+            let contract = Contract::from(contract::ManagementForRent {
+                id: contract::Id::new(),
+                agency_id,
+                number: contract::Number::new(
+                    contract::Kind::ManagementForRent,
+                    2024,
+                    1,
+                ),
+                name: contract::Name::new("Management").unwrap(),
+                description: contract::Description::new("Flat").unwrap(),
+                realty_id: realty::Id::new(),
+                landlord_id: user::Id::new(),
+                employer_id: user.id,
+                expected_price: money,
+                expected_deposit: None,
+                one_time_fee: None,
+                monthly_fee: None,
+                percent_fee: None,
+                is_placed: true,
+                created_at: DateTime::now().coerce(),
+                expires_at: None,
+                completed_at: None,
+                terminated_at: None,
+                archived_at: None,
+                version: Version::initial(),
+            });
+            Self {
+                user: Arc::new(Mutex::new(user)),
+                employment,
+                contract: Arc::new(Mutex::new(contract)),
+            }
+        }
+
+        fn user(&self) -> User {
+            self.user
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+
+        fn contract(&self) -> Contract {
+            self.contract
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+    }
+
+    impl Database<Transact> for Fake {
+        type Ok = Self;
+        type Err = Traced<database::Error>;
+
+        async fn execute(&self, _: Transact) -> Result<Self::Ok, Self::Err> {
+            Ok(self.clone())
+        }
+    }
+
+    impl Database<Commit> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(&self, _: Commit) -> Result<Self::Ok, Self::Err> {
+            Ok(())
+        }
+    }
+
+    impl Database<Select<By<Option<User>, user::Id>>> for Fake {
+        type Ok = Option<User>;
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            Select(by): Select<By<Option<User>, user::Id>>,
+        ) -> Result<Self::Ok, Self::Err> {
+            let user = self.user();
+            Ok((user.id == by.into_inner()).then_some(user))
+        }
+    }
+
+    impl Database<Select<By<Option<Active<contract::Employment>>, user::Id>>>
+        for Fake
+    {
+        type Ok = Option<Active<contract::Employment>>;
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            Select(by): Select<
+                By<Option<Active<contract::Employment>>, user::Id>,
+            >,
+        ) -> Result<Self::Ok, Self::Err> {
+            Ok((self.employment.employer_id == by.into_inner())
+                .then(|| Active(self.employment.clone())))
+        }
+    }
+
+    impl Database<Select<By<Option<Contract>, contract::Id>>> for Fake {
+        type Ok = Option<Contract>;
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            Select(by): Select<By<Option<Contract>, contract::Id>>,
+        ) -> Result<Self::Ok, Self::Err> {
+            let contract = self.contract();
+            Ok((contract.id() == by.into_inner()).then_some(contract))
+        }
+    }
+
+    impl Database<Lock<By<User, user::Id>>> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            _: Lock<By<User, user::Id>>,
+        ) -> Result<Self::Ok, Self::Err> {
+            Ok(())
+        }
+    }
+
+    impl Database<Lock<By<Contract, contract::Id>>> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            _: Lock<By<Contract, contract::Id>>,
+        ) -> Result<Self::Ok, Self::Err> {
+            Ok(())
+        }
+    }
+
+    impl Database<Lock<By<Realty, realty::Id>>> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            _: Lock<By<Realty, realty::Id>>,
+        ) -> Result<Self::Ok, Self::Err> {
+            Ok(())
+        }
+    }
+
+    impl Database<Update<User>> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            Update(user): Update<User>,
+        ) -> Result<Self::Ok, Self::Err> {
+            *self.user.lock().unwrap_or_else(PoisonError::into_inner) = user;
+            Ok(())
+        }
+    }
+
+    impl Database<Insert<Contract>> for Fake {
+        type Ok = ();
+        type Err = Traced<database::Error>;
+
+        async fn execute(
+            &self,
+            Insert(contract): Insert<Contract>,
+        ) -> Result<Self::Ok, Self::Err> {
+            *self.contract.lock().unwrap_or_else(PoisonError::into_inner) =
+                contract;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn deplacing_invalidates_placements() {
+        let db = Fake::new();
+        let recorder = Arc::new(RecordingCache::default());
+        let service = service(db.clone(), recorder.clone());
+
+        block_on(service.execute(DeplaceContract {
+            contract_id: db.contract().id(),
+            initiator_id: db.user().id,
+        }))
+        .unwrap();
+
+        assert_eq!(db.contract().is_placed(), Some(false));
+        assert_eq!(recorder.deleted_prefixes(), [cache::key::PLACEMENTS]);
+    }
+
+    #[test]
+    fn updating_user_invalidates_user() {
+        let db = Fake::new();
+        let recorder = Arc::new(RecordingCache::default());
+        let service = service(db.clone(), recorder.clone());
+        let user_id = db.user().id;
+
+        block_on(service.execute(UpdateUserName {
+            user_id,
+            name: user::Name::new("Jane Doe").unwrap(),
+        }))
+        .unwrap();
+
+        assert_eq!(db.user().name, user::Name::new("Jane Doe").unwrap());
+        assert_eq!(recorder.deleted(), [cache::key::user(user_id)]);
+    }
+}
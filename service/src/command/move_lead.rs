@@ -0,0 +1,210 @@
+//! [`Command`] for moving a [`Lead`] to another [`lead::Stage`].
+
+use common::{
+    operations::{By, Commit, Insert, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+use tracing as log;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, lead, sms, user, Lead, User},
+    infra::{database, sms as gateway, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for moving a [`Lead`] to another [`lead::Stage`], recording
+/// the [`lead::Transition`] in its history.
+///
+/// The prospect [`User`] is notified via SMS once the [`Lead`] moves to the
+/// [`lead::Stage::Viewing`].
+#[derive(Clone, Copy, Debug)]
+pub struct MoveLead {
+    /// ID of the [`Lead`] to be moved.
+    pub lead_id: lead::Id,
+
+    /// ID of the [`Agency`] the [`Lead`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`lead::Stage`] to move the [`Lead`] to.
+    pub stage: lead::Stage,
+
+    /// ID of the [`User`] who moves the [`Lead`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<MoveLead> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<sms::Delivery>, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Select<By<Option<Lead>, lead::Id>>,
+            Ok = Option<Lead>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Lead>, Err = Traced<database::Error>>
+        + Database<Insert<lead::Transition>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Lead;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: MoveLead) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let MoveLead {
+            lead_id,
+            agency_id,
+            stage,
+            initiator_id,
+        } = cmd;
+
+        let mut attempt = 1;
+        let lead = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                let mut lead = tx
+                    .execute(Select(By::<Option<Lead>, _>::new(lead_id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(|l| l.agency_id == agency_id)
+                    .ok_or(E::LeadNotExists(lead_id))
+                    .map_err(tracerr::wrap!())?;
+                if !lead.stage.can_move_to(stage) {
+                    return Err(tracerr::new!(E::TransitionNotAllowed {
+                        from: lead.stage,
+                        to: stage,
+                    }));
+                }
+
+                let transition = lead::Transition {
+                    lead_id,
+                    from: Some(lead.stage),
+                    to: stage,
+                    initiator_id,
+                    created_at: DateTime::now().coerce(),
+                };
+                lead.stage = stage;
+
+                tx.execute(Insert(lead.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                tx.execute(Insert(transition))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(lead)
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+
+        if lead.stage == lead::Stage::Viewing {
+            if let Some(user_id) = lead.user_id {
+                self.notify_viewing(user_id, agency_id).await;
+            }
+        }
+
+        Ok(lead)
+    }
+}
+
+impl<Db> Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<sms::Delivery>, Err = Traced<database::Error>>,
+{
+    /// Notifies the prospect [`User`] via SMS about the viewing being
+    /// arranged, in the default [`agency::Locale`] of the [`Agency`].
+    ///
+    /// Errors are logged only, since the [`Lead`] is moved already.
+    async fn notify_viewing(&self, user_id: user::Id, agency_id: agency::Id) {
+        let res = async {
+            let phone = self
+                .database()
+                .execute(Select(By::<Option<User>, _>::new(user_id)))
+                .await?
+                .and_then(|u| u.phone);
+            let locale = self
+                .database()
+                .execute(Select(By::<Option<agency::Settings>, _>::new(
+                    agency_id,
+                )))
+                .await?
+                .and_then(|s| s.locale);
+            Ok::<_, Traced<database::Error>>(phone.map(|p| (p, locale)))
+        }
+        .await;
+        match res {
+            Ok(Some((to, locale))) => {
+                self.send_sms(to, locale, gateway::Template::Viewing).await;
+            }
+            Ok(None) => {}
+            Err(e) => log::warn!(
+                "Failed to notify `User(id: {user_id})` about viewing: {e}",
+            ),
+        }
+    }
+}
+
+/// Error of [`MoveLead`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Lead`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Lead(id: {_0})` does not exist")]
+    #[from(ignore)]
+    LeadNotExists(#[error(not(source))] lead::Id),
+
+    /// [`Lead`] cannot be moved backwards in the pipeline.
+    #[display("`Lead` cannot be moved from `{from}` to `{to}` stage")]
+    #[from(ignore)]
+    TransitionNotAllowed {
+        /// Current [`lead::Stage`] of the [`Lead`].
+        from: lead::Stage,
+
+        /// Requested [`lead::Stage`] of the [`Lead`].
+        to: lead::Stage,
+    },
+}
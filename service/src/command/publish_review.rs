@@ -0,0 +1,119 @@
+//! [`Command`] for publishing a [`Review`].
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{agency, review, user, Agency, Review},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for publishing a [`Review`] by the administrator of its
+/// [`Agency`], making it visible again.
+#[derive(Clone, Copy, Debug)]
+pub struct PublishReview {
+    /// ID of the [`Review`] to be published.
+    pub review_id: review::Id,
+
+    /// ID of the [`Agency`] the [`Review`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] administrating the [`Agency`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<PublishReview> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Review>, review::Id>>,
+            Ok = Option<Review>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Review>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Review;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: PublishReview) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let PublishReview {
+            review_id,
+            agency_id,
+            initiator_id,
+        } = cmd;
+
+        let agency = self
+            .database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::AgencyNotExists(agency_id))
+            .map_err(tracerr::wrap!())?;
+        if agency.admin_id != initiator_id {
+            return Err(tracerr::new!(E::UserNotAdmin(initiator_id)));
+        }
+
+        let mut attempt = 1;
+        loop {
+            let mut review = self
+                .database()
+                .execute(Select(By::<Option<Review>, _>::new(review_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|r| r.agency_id == agency_id)
+                .ok_or(E::ReviewNotExists(review_id))
+                .map_err(tracerr::wrap!())?;
+            if review.status == review::Status::Published {
+                return Ok(review);
+            }
+
+            review.status = review::Status::Published;
+            match self.database().execute(Update(review.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    return res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| review);
+                }
+            }
+        }
+    }
+}
+
+/// Error of [`PublishReview`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    #[from(ignore)]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Review`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Review(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ReviewNotExists(#[error(not(source))] review::Id),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+}
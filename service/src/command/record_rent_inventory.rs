@@ -0,0 +1,186 @@
+//! [`Command`] for recording an [`Inventory`] under a [`contract::Rent`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, inventory, user, Contract, Inventory},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for recording an [`Inventory`] under a [`contract::Rent`] at
+/// its move-in or move-out.
+///
+/// Move-out [`Inventory`] may be recorded only after the move-in one, and may
+/// be recorded even after the [`contract::Rent`] is ended.
+#[derive(Clone, Debug)]
+pub struct RecordRentInventory {
+    /// ID of the [`contract::Rent`] to record the [`Inventory`] under.
+    pub contract_id: contract::Id,
+
+    /// [`inventory::Stage`] to record the [`Inventory`] at.
+    pub stage: inventory::Stage,
+
+    /// [`inventory::Item`]s of the [`Inventory`].
+    pub items: Vec<inventory::Item>,
+
+    /// ID of the employer [`User`] recording the [`Inventory`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RecordRentInventory> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Inventory>, contract::Id>>,
+            Ok = Vec<Inventory>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Inventory>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Inventory;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RecordRentInventory,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RecordRentInventory {
+            contract_id,
+            stage,
+            items,
+            initiator_id,
+        } = cmd;
+
+        if items.is_empty() {
+            return Err(tracerr::new!(E::NoItems));
+        }
+        if items
+            .iter()
+            .enumerate()
+            .any(|(n, item)| items[..n].iter().any(|i| i.is_same(item)))
+        {
+            return Err(tracerr::new!(E::DuplicateItem));
+        }
+
+        // Only the responsible employer records `Inventory`s.
+        let rent = match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c)) if c.employer_id == initiator_id => c,
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        };
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent `Inventory`s of the same `contract::Rent`.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let existing = tx
+            .execute(Select(By::<Vec<Inventory>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if existing.iter().any(|i| i.stage == stage) {
+            return Err(tracerr::new!(E::AlreadyRecorded(stage)));
+        }
+        if stage == inventory::Stage::MoveOut
+            && !existing.iter().any(|i| i.stage == inventory::Stage::MoveIn)
+        {
+            return Err(tracerr::new!(E::MoveInNotRecorded(contract_id)));
+        }
+
+        let inventory = Inventory {
+            id: inventory::Id::new(),
+            agency_id: rent.agency_id,
+            contract_id,
+            stage,
+            items,
+            recorder_id: initiator_id,
+            recorded_at: DateTime::now().coerce(),
+        };
+        tx.execute(Insert(inventory.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(inventory)
+    }
+}
+
+/// Error of [`RecordRentInventory`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Inventory`] is already recorded at the provided
+    /// [`inventory::Stage`].
+    #[display("`Inventory` is already recorded at `{_0}` stage")]
+    #[from(ignore)]
+    AlreadyRecorded(#[error(not(source))] inventory::Stage),
+
+    /// [`contract::Rent`] with the provided ID does not exist, or is not
+    /// managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Same [`inventory::Item`] is listed more than once.
+    #[display("`Item` with the same room and name is listed more than once")]
+    #[from(ignore)]
+    DuplicateItem,
+
+    /// Move-out [`Inventory`] is recorded before the move-in one.
+    #[display("Move-in `Inventory` of `Contract(id: {_0})` is not recorded")]
+    #[from(ignore)]
+    MoveInNotRecorded(#[error(not(source))] contract::Id),
+
+    /// No [`inventory::Item`]s are listed.
+    #[display("At least one `Item` must be listed")]
+    #[from(ignore)]
+    NoItems,
+}
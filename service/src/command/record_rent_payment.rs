@@ -0,0 +1,131 @@
+//! [`Command`] for recording a [`Payment`] under a [`contract::Rent`].
+
+use common::{
+    money::Currency,
+    operations::{By, Insert, Select},
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, payment, user, Contract, Payment},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for recording a [`Payment`] under a [`contract::Rent`].
+///
+/// [`Payment`]s may be recorded even after the [`contract::Rent`] is ended,
+/// to settle its remaining arrears.
+#[derive(Clone, Copy, Debug)]
+pub struct RecordRentPayment {
+    /// ID of the [`contract::Rent`] the [`Payment`] is made under.
+    pub contract_id: contract::Id,
+
+    /// Paid amount.
+    pub amount: Money,
+
+    /// ID of the employer [`User`] recording the [`Payment`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RecordRentPayment> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Payment>, Err = Traced<database::Error>>,
+{
+    type Ok = Payment;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RecordRentPayment,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RecordRentPayment {
+            contract_id,
+            amount,
+            initiator_id,
+        } = cmd;
+
+        if amount.amount <= Decimal::ZERO {
+            return Err(tracerr::new!(E::NonPositiveAmount));
+        }
+
+        // Only the responsible employer records `Payment`s.
+        let rent = match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c)) if c.employer_id == initiator_id => c,
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ContractNotExists(contract_id)));
+            }
+        };
+        if amount.currency != rent.price.currency {
+            return Err(tracerr::new!(E::CurrencyMismatch(
+                rent.price.currency
+            )));
+        }
+
+        let payment = Payment {
+            id: payment::Id::new(),
+            agency_id: rent.agency_id,
+            contract_id,
+            amount,
+            recorder_id: initiator_id,
+            paid_at: DateTime::now().coerce(),
+        };
+        self.database()
+            .execute(Insert(payment))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(payment)
+    }
+}
+
+/// Error of [`RecordRentPayment`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`contract::Rent`] with the provided ID does not exist, or is not
+    /// managed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// Paid amount is not in the currency of the rent price.
+    #[display("Paid amount must be in `{_0}` currency")]
+    #[from(ignore)]
+    CurrencyMismatch(#[error(not(source))] Currency),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Paid amount is zero or negative.
+    #[display("Paid amount must be positive")]
+    #[from(ignore)]
+    NonPositiveAmount,
+}
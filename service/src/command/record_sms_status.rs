@@ -0,0 +1,89 @@
+//! [`Command`] for recording a [`sms::Status`] reported by an SMS gateway.
+
+use common::{
+    operations::{By, Insert, Select},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::infra::SmsGateway;
+use crate::{
+    domain::sms,
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for recording a [`sms::Status`] of a [`sms::Delivery`],
+/// reported by the [`SmsGateway`] it was sent via.
+#[derive(Clone, Debug)]
+pub struct RecordSmsStatus {
+    /// [`sms::ProviderId`] of the delivered message.
+    pub provider_id: sms::ProviderId,
+
+    /// Reported [`sms::Status`] of the [`sms::Delivery`].
+    pub status: sms::Status,
+}
+
+impl<Db> Command<RecordSmsStatus> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<sms::Delivery>, sms::ProviderId>>,
+            Ok = Option<sms::Delivery>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<sms::Delivery>, Err = Traced<database::Error>>,
+{
+    type Ok = sms::Delivery;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RecordSmsStatus,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RecordSmsStatus {
+            provider_id,
+            status,
+        } = cmd;
+
+        let mut delivery = self
+            .database()
+            .execute(Select(By::<Option<sms::Delivery>, _>::new(
+                provider_id.clone(),
+            )))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::DeliveryNotExists(provider_id))
+            .map_err(tracerr::wrap!())?;
+        // Reports may arrive out of order, so a final `Status` is kept.
+        if delivery.status == status || delivery.status.is_final() {
+            return Ok(delivery);
+        }
+
+        delivery.status = status;
+        delivery.updated_at = DateTime::now().coerce();
+        self.database()
+            .execute(Insert(delivery.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        Ok(delivery)
+    }
+}
+
+/// Error of [`RecordSmsStatus`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`sms::Delivery`] with the provided [`sms::ProviderId`] doesn't exist.
+    #[display("`sms::Delivery(provider_id: {_0})` does not exist")]
+    #[from(ignore)]
+    DeliveryNotExists(#[error(not(source))] sms::ProviderId),
+}
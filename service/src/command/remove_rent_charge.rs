@@ -0,0 +1,110 @@
+//! [`Command`] for removing a [`Charge`] from a [`contract::Rent`].
+
+use common::operations::{By, Delete, Select};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{charge, contract, user, Charge, Contract},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for removing a [`Charge`] from a [`contract::Rent`].
+///
+/// Removed [`Charge`] is no longer billed, including its already billed
+/// installments.
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveRentCharge {
+    /// ID of the [`Charge`] to be removed.
+    pub charge_id: charge::Id,
+
+    /// ID of the employer [`User`] removing the [`Charge`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RemoveRentCharge> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Charge>, charge::Id>>,
+            Ok = Option<Charge>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<Charge, charge::Id>>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = Charge;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RemoveRentCharge,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RemoveRentCharge {
+            charge_id,
+            initiator_id,
+        } = cmd;
+
+        let charge = self
+            .database()
+            .execute(Select(By::<Option<Charge>, _>::new(charge_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::ChargeNotExists(charge_id))
+            .map_err(tracerr::wrap!())?;
+
+        // Only the responsible employer removes `Charge`s.
+        match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(charge.contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+        {
+            Some(Contract::Rent(c)) if c.employer_id == initiator_id => {}
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => {
+                return Err(tracerr::new!(E::ChargeNotExists(charge_id)));
+            }
+        }
+
+        self.database()
+            .execute(Delete(By::<Charge, _>::new(charge_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(charge)
+    }
+}
+
+/// Error of [`RemoveRentCharge`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Charge`] with the provided ID does not exist, or is not managed by
+    /// the initiator.
+    #[display("`Charge(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ChargeNotExists(#[error(not(source))] charge::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+}
@@ -0,0 +1,104 @@
+//! [`Command`] for requeueing [`task::DeadLetter`]s.
+
+use common::operations::{By, Delete, Select};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, task, user, Agency, User},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for requeueing [`task::DeadLetter`]s, so their work items are
+/// processed again on the next run of their task.
+#[derive(Clone, Debug)]
+pub struct RequeueTaskDeadLetters {
+    /// Name of the task which [`task::DeadLetter`]s should be requeued.
+    pub task: String,
+
+    /// ID of the work item which [`task::DeadLetter`] should be requeued.
+    ///
+    /// [`None`] requeues all the [`task::DeadLetter`]s of the task.
+    pub item: Option<String>,
+
+    /// ID of the [`User`] who requeues the [`task::DeadLetter`]s.
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RequeueTaskDeadLetters> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<task::DeadLetter, (String, Option<String>)>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        >,
+{
+    /// Number of the requeued [`task::DeadLetter`]s.
+    type Ok = u64;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RequeueTaskDeadLetters,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RequeueTaskDeadLetters {
+            task,
+            item,
+            initiator_id,
+        } = cmd;
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        // Tasks are platform-wide, so are managed by the platform operator.
+        self.database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency::Id::DEFAULT)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.admin_id == initiator.id)
+            .ok_or(E::UserNotAdmin(initiator.id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        self.database()
+            .execute(Delete(By::<task::DeadLetter, _>::new((task, item))))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+    }
+}
+
+/// Error of [`RequeueTaskDeadLetters`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the default [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,193 @@
+//! [`Command`] for restoring an archived [`Contract`].
+
+use common::{
+    operations::{
+        By, Commit, Insert, Lock, Select, Transact, Transacted, Update,
+    },
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{audit, contract, user, Contract, User},
+    infra::{cache, database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for restoring an archived [`Contract`] back into the working
+/// lists.
+#[derive(Clone, Copy, Debug)]
+pub struct RestoreContract {
+    /// ID of the [`Contract`] to be restored.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] who restores the [`Contract`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RestoreContract> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Contract>, Ok = (), Err = Traced<database::Error>>
+        + Database<Insert<audit::Entry>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Contract;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RestoreContract,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RestoreContract {
+            contract_id,
+            initiator_id,
+        } = cmd;
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator.id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator.id))
+            .map_err(tracerr::wrap!())?;
+
+        let mut attempt = 1;
+        let contract = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                // Avoid concurrent restorations.
+                tx.execute(Lock(By::new(contract_id)))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                // `Contract`s of other `Agency`s are not visible to the
+                // initiator.
+                let mut contract = tx
+                    .execute(Select(By::<Option<Contract>, _>::new(
+                        contract_id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(|c| c.agency_id() == employment.agency_id)
+                    .filter(|c| c.archived_at().is_some())
+                    .ok_or(E::ContractNotArchived(contract_id))
+                    .map_err(tracerr::wrap!())?;
+
+                _ = contract.archived_at_mut().take();
+                tx.execute(Update(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                tx.execute(Insert(audit::Entry {
+                    id: audit::Id::new(),
+                    agency_id: employment.agency_id,
+                    actor_id: initiator.id,
+                    action: audit::Action::RestoreContract,
+                    entity: audit::Entity::Contract,
+                    entity_id: contract.id().into(),
+                    before: None,
+                    after: None,
+                    created_at: DateTime::now().coerce(),
+                }))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(contract)
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+        match &contract {
+            Contract::Employment(c) => {
+                self.invalidate_cache(&[cache::key::employment(c.employer_id)])
+                    .await;
+            }
+            Contract::ManagementForRent(_) | Contract::ManagementForSale(_) => {
+                self.placements_changed().await;
+            }
+            Contract::Rent(_)
+            | Contract::Sale(_)
+            | Contract::ShortTermRent(_) => {}
+        }
+
+        Ok(contract)
+    }
+}
+
+/// Error of [`RestoreContract`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// Archived [`Contract`] with the provided ID does not exist.
+    #[display("Archived `Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotArchived(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
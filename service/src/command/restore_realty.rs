@@ -0,0 +1,152 @@
+//! [`Command`] for restoring a deleted [`Realty`].
+
+use common::{
+    operations::{By, Insert, Select, Update},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{audit, contract, realty, user, Realty, User},
+    infra::{database, Database},
+    read::{contract::Active, realty::Deleted},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for restoring a deleted [`Realty`], which is not purged yet.
+#[derive(Clone, Copy, Debug)]
+pub struct RestoreRealty {
+    /// ID of the [`Realty`] to be restored.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`User`] who restores the [`Realty`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RestoreRealty> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Deleted<Realty>>, realty::Id>>,
+            Ok = Option<Deleted<Realty>>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Realty>, Ok = (), Err = Traced<database::Error>>
+        + Database<Insert<audit::Entry>, Err = Traced<database::Error>>,
+{
+    type Ok = Realty;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: RestoreRealty) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RestoreRealty {
+            realty_id,
+            initiator_id,
+        } = cmd;
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator.id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator.id))
+            .map_err(tracerr::wrap!())?;
+
+        let mut attempt = 1;
+        let realty = loop {
+            // `Realty`s of other `Agency`s are not visible to the initiator.
+            let Deleted(mut realty) = self
+                .database()
+                .execute(Select(By::<Option<Deleted<Realty>>, _>::new(
+                    realty_id,
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|Deleted(r)| r.agency_id == employment.agency_id)
+                .ok_or(E::RealtyNotDeleted(realty_id))
+                .map_err(tracerr::wrap!())?;
+
+            realty.deleted_at = None;
+
+            match self.database().execute(Update(realty.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    break res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| realty)?;
+                }
+            }
+        };
+
+        self.database()
+            .execute(Insert(audit::Entry {
+                id: audit::Id::new(),
+                agency_id: employment.agency_id,
+                actor_id: initiator.id,
+                action: audit::Action::RestoreRealty,
+                entity: audit::Entity::Realty,
+                entity_id: realty.id.into(),
+                before: None,
+                after: None,
+                created_at: DateTime::now().coerce(),
+            }))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        self.placements_changed().await;
+
+        Ok(realty)
+    }
+}
+
+/// Error of [`RestoreRealty`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Deleted [`Realty`] with the provided ID does not exist, or is purged
+    /// already.
+    #[display("Deleted `Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotDeleted(#[error(not(source))] realty::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,138 @@
+//! [`Command`] for restoring a deleted [`User`].
+
+use common::{
+    operations::{
+        By, Commit, Insert, Lock, Select, Transact, Transacted, Update,
+    },
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, audit, user, Agency, User},
+    infra::{cache, database, Database},
+    read::user::Deleted,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for restoring a deleted [`User`].
+///
+/// As [`User`]s are not bound to any [`Agency`], only the platform operator
+/// (administrator of the [`agency::Id::DEFAULT`] [`Agency`]) may restore them.
+#[derive(Clone, Copy, Debug)]
+pub struct RestoreUser {
+    /// ID of the [`User`] to be restored.
+    pub user_id: user::Id,
+
+    /// ID of the operator [`User`] who restores the [`User`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<RestoreUser> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<User, user::Id>>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Deleted<User>>, user::Id>>,
+            Ok = Option<Deleted<User>>,
+            Err = Traced<database::Error>,
+        > + Database<Update<User>, Ok = (), Err = Traced<database::Error>>
+        + Database<Insert<audit::Entry>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = User;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: RestoreUser) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let RestoreUser {
+            user_id,
+            initiator_id,
+        } = cmd;
+
+        self.database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency::Id::DEFAULT)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.admin_id == initiator_id)
+            .ok_or(E::UserNotAdmin(initiator_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent actions upon the same `User`.
+        tx.execute(Lock(By::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let Deleted(mut user) = tx
+            .execute(Select(By::<Option<Deleted<User>>, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotDeleted(user_id))
+            .map_err(tracerr::wrap!())?;
+
+        user.deleted_at = None;
+        tx.execute(Update(user.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        tx.execute(Insert(audit::Entry {
+            id: audit::Id::new(),
+            agency_id: agency::Id::DEFAULT,
+            actor_id: initiator_id,
+            action: audit::Action::RestoreUser,
+            entity: audit::Entity::User,
+            entity_id: user.id.into(),
+            before: None,
+            after: None,
+            created_at: DateTime::now().coerce(),
+        }))
+        .await
+        .map_err(tracerr::map_from_and_wrap!(=> E))
+        .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        self.invalidate_cache(&[cache::key::user(user.id)]).await;
+
+        Ok(user)
+    }
+}
+
+/// Error of [`RestoreUser`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Deleted [`User`] with the provided ID does not exist.
+    #[display("Deleted `User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotDeleted(#[error(not(source))] user::Id),
+
+    /// [`User`] is not an administrator of the default [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,55 @@
+//! [`Command`] for revoking an [`ical::Feed`].
+
+use common::operations::{By, Delete};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{ical, user},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for revoking an [`ical::Feed`] of a [`User`], so its
+/// [`ical::Token`] cannot be used anymore.
+#[derive(Clone, Copy, Debug, From)]
+pub struct RevokeCalendarFeed {
+    /// ID of the [`User`] to revoke the [`ical::Feed`] of.
+    pub user_id: user::Id,
+}
+
+impl<Db> Command<RevokeCalendarFeed> for Service<Db>
+where
+    Db: Database<
+        Delete<By<ical::Feed, user::Id>>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = ();
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: RevokeCalendarFeed,
+    ) -> Result<Self::Ok, Self::Err> {
+        let RevokeCalendarFeed { user_id } = cmd;
+
+        self.database()
+            .execute(Delete(By::<ical::Feed, _>::new(user_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))
+    }
+}
+
+/// Error of [`RevokeCalendarFeed`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+}
@@ -0,0 +1,134 @@
+//! [`Command`] for saving a [`contract::Template`].
+
+use common::{
+    operations::{By, Insert, Select},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{contract, contract::template, user},
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for saving a [`contract::Template`].
+///
+/// Creates a new [`contract::Template`] if no ID is provided, or replaces the
+/// [`template::Text`]s of the existing one otherwise.
+#[derive(Clone, Debug)]
+pub struct SaveContractTemplate {
+    /// ID of the existing [`contract::Template`] to replace, if any.
+    pub template_id: Option<template::Id>,
+
+    /// [`template::Text`] of the rendered [`contract::Name`].
+    pub name: template::Text,
+
+    /// [`template::Text`] of the rendered [`contract::Description`].
+    pub description: template::Text,
+
+    /// ID of the employer [`User`] saving the [`contract::Template`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<SaveContractTemplate> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<contract::Template>, template::Id>>,
+            Ok = Option<contract::Template>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<contract::Template>, Err = Traced<database::Error>>,
+{
+    type Ok = contract::Template;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SaveContractTemplate,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SaveContractTemplate {
+            template_id,
+            name,
+            description,
+            initiator_id,
+        } = cmd;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        let template = if let Some(id) = template_id {
+            // `contract::Template`s of other `Agency`s are not visible to the
+            // initiator.
+            let existing = self
+                .database()
+                .execute(Select(By::<Option<contract::Template>, _>::new(id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|t| t.agency_id == employment.agency_id)
+                .ok_or(E::TemplateNotExists(id))
+                .map_err(tracerr::wrap!())?;
+            contract::Template {
+                name,
+                description,
+                creator_id: initiator_id,
+                ..existing
+            }
+        } else {
+            contract::Template {
+                id: template::Id::new(),
+                agency_id: employment.agency_id,
+                name,
+                description,
+                creator_id: initiator_id,
+                created_at: DateTime::now().coerce(),
+            }
+        };
+        self.database()
+            .execute(Insert(template.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(template)
+    }
+}
+
+/// Error of [`SaveContractTemplate`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`contract::Template`] with the provided ID does not exist in the
+    /// [`Agency`].
+    #[display("`contract::Template(id: {_0})` does not exist")]
+    #[from(ignore)]
+    TemplateNotExists(#[error(not(source))] template::Id),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+}
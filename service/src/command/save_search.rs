@@ -0,0 +1,122 @@
+//! [`Command`] for saving a [`SavedSearch`].
+
+use common::{operations::Insert, DateTime, Money};
+use derive_more::{Display, Error, From};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::{
+    domain::{Agency, Realty, User},
+    read::Placement,
+};
+use crate::{
+    domain::{agency, realty, saved_search, user, SavedSearch},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for saving a new [`SavedSearch`] of a [`User`], to be alerted
+/// about the [`Placement`]s matching it, placed since now.
+#[derive(Clone, Debug)]
+pub struct SaveSearch {
+    /// ID of the [`User`] saving the [`SavedSearch`].
+    pub user_id: user::Id,
+
+    /// ID of the [`Agency`] the [`Placement`]s are searched among.
+    pub agency_id: agency::Id,
+
+    /// [`realty::City`] of the placed [`Realty`]s to match.
+    pub city: Option<realty::City>,
+
+    /// Minimal price (inclusive) of the [`Placement`]s to match.
+    pub min_price: Option<Money>,
+
+    /// Maximal price (inclusive) of the [`Placement`]s to match.
+    pub max_price: Option<Money>,
+
+    /// [`saved_search::Kind`] of the [`Placement`]s to match.
+    pub kind: Option<saved_search::Kind>,
+}
+
+impl<Db> Command<SaveSearch> for Service<Db>
+where
+    Db: Database<Insert<SavedSearch>, Err = Traced<database::Error>>,
+{
+    type Ok = SavedSearch;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: SaveSearch) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SaveSearch {
+            user_id,
+            agency_id,
+            city,
+            min_price,
+            max_price,
+            kind,
+        } = cmd;
+
+        if [min_price, max_price]
+            .into_iter()
+            .flatten()
+            .any(|p| p.amount < Decimal::ZERO)
+        {
+            return Err(tracerr::new!(E::NegativePrice));
+        }
+        if let Some((min, max)) = min_price.zip(max_price) {
+            if min.currency != max.currency {
+                return Err(tracerr::new!(E::PriceCurrencyMismatch));
+            }
+            if min.amount > max.amount {
+                return Err(tracerr::new!(E::InvalidPriceRange));
+            }
+        }
+
+        let now = DateTime::now();
+        let search = SavedSearch {
+            id: saved_search::Id::new(),
+            user_id,
+            agency_id,
+            city,
+            min_price,
+            max_price,
+            kind,
+            created_at: now.coerce(),
+            alerted_at: now.coerce(),
+        };
+        self.database()
+            .execute(Insert(search.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(search)
+    }
+}
+
+/// Error of [`SaveSearch`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Minimal price is greater than the maximal one.
+    #[display("Minimal price must not exceed the maximal one")]
+    #[from(ignore)]
+    InvalidPriceRange,
+
+    /// Price is negative.
+    #[display("Price must not be negative")]
+    #[from(ignore)]
+    NegativePrice,
+
+    /// Minimal and maximal prices have different currencies.
+    #[display("Minimal and maximal prices must have the same currency")]
+    #[from(ignore)]
+    PriceCurrencyMismatch,
+}
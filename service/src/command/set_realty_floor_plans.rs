@@ -0,0 +1,152 @@
+//! [`Command`] for setting [`FloorPlan`]s of a [`Realty`].
+
+use common::operations::{
+    By, Commit, Delete, Insert, Lock, Select, Transact, Transacted,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, floor_plan, realty, FloorPlan, Realty},
+    infra::{cache, database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for setting [`FloorPlan`]s of a [`Realty`].
+///
+/// Replaces all the existing [`FloorPlan`]s of the [`Realty`].
+#[derive(Clone, Debug)]
+pub struct SetRealtyFloorPlans {
+    /// ID of the [`Realty`] to set the [`FloorPlan`]s of.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`FloorPlan`]s along with the [`realty::Floor`]s they depict.
+    ///
+    /// Every [`floor_plan::Label`] must be used only once per
+    /// [`realty::Floor`].
+    pub plans: Vec<(
+        realty::Floor,
+        floor_plan::Label,
+        floor_plan::Format,
+        floor_plan::Url,
+    )>,
+}
+
+impl<Db> Command<SetRealtyFloorPlans> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<FloorPlan, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<FloorPlan>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<FloorPlan>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SetRealtyFloorPlans,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SetRealtyFloorPlans {
+            realty_id,
+            agency_id,
+            plans,
+        } = cmd;
+
+        let mut plans = plans
+            .into_iter()
+            .map(|(floor, label, format, url)| FloorPlan {
+                realty_id,
+                floor,
+                label,
+                format,
+                url,
+            })
+            .collect::<Vec<_>>();
+        if !FloorPlan::are_valid(&plans) {
+            return Err(tracerr::new!(E::DuplicateLabels));
+        }
+        plans.sort_by(|a, b| (a.floor, &a.label).cmp(&(b.floor, &b.label)));
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `FloorPlan`s.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let realty = tx
+            .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|r| r.agency_id == agency_id)
+            .ok_or(E::RealtyNotExists(realty_id))
+            .map_err(tracerr::wrap!())?;
+        if let Some(p) = plans.iter().find(|p| p.floor > realty.num_floors) {
+            return Err(tracerr::new!(E::FloorNotExists(p.floor)));
+        }
+
+        tx.execute(Delete(By::<FloorPlan, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(plans.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        self.invalidate_cache_prefixed(cache::key::PLACEMENTS).await;
+
+        Ok(plans)
+    }
+}
+
+/// Error of [`SetRealtyFloorPlans`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Some [`floor_plan::Label`] is used twice on the same
+    /// [`realty::Floor`].
+    #[display("Floor plan labels must be unique per floor")]
+    DuplicateLabels,
+
+    /// [`realty::Floor`] is above the [`realty::NumFloors`] of the
+    /// [`Realty`].
+    #[display("`Floor({_0})` does not exist")]
+    #[from(ignore)]
+    FloorNotExists(#[error(not(source))] realty::Floor),
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+}
@@ -0,0 +1,132 @@
+//! [`Command`] for setting [`MediaLink`]s of a [`Realty`].
+
+use common::operations::{
+    By, Commit, Delete, Insert, Lock, Select, Transact, Transacted,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, media_link, realty, MediaLink, Realty},
+    infra::{cache, database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for setting [`MediaLink`]s of a [`Realty`].
+///
+/// Replaces all the existing [`MediaLink`]s of the [`Realty`].
+#[derive(Clone, Debug)]
+pub struct SetRealtyMediaLinks {
+    /// ID of the [`Realty`] to set the [`MediaLink`]s of.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`media_link::Url`]s of the [`MediaLink`]s, in the order they should
+    /// be shown.
+    ///
+    /// Every [`media_link::Url`] must be used only once, and there must be no
+    /// more than [`MediaLink::MAX_PER_REALTY`] of them.
+    pub urls: Vec<media_link::Url>,
+}
+
+impl<Db> Command<SetRealtyMediaLinks> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<MediaLink, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<MediaLink>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<MediaLink>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SetRealtyMediaLinks,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SetRealtyMediaLinks {
+            realty_id,
+            agency_id,
+            urls,
+        } = cmd;
+
+        let links = urls
+            .into_iter()
+            .map(|url| MediaLink { realty_id, url })
+            .collect::<Vec<_>>();
+        if !MediaLink::are_valid(&links) {
+            return Err(tracerr::new!(E::InvalidLinks));
+        }
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `MediaLink`s.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|r| r.agency_id == agency_id)
+            .ok_or(E::RealtyNotExists(realty_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        tx.execute(Delete(By::<MediaLink, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(links.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        self.invalidate_cache_prefixed(cache::key::PLACEMENTS).await;
+
+        Ok(links)
+    }
+}
+
+/// Error of [`SetRealtyMediaLinks`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Some [`media_link::Url`] is used twice, or there are more than
+    /// [`MediaLink::MAX_PER_REALTY`] [`MediaLink`]s.
+    #[display("Media links must be unique and not too many")]
+    InvalidLinks,
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+}
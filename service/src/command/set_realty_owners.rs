@@ -0,0 +1,155 @@
+//! [`Command`] for setting co-owners of a [`Realty`].
+
+use common::{
+    operations::{
+        By, Commit, Delete, Insert, Lock, Select, Transact, Transacted,
+    },
+    Percent,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, realty, user, Ownership, Realty, User},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for setting co-owners of a [`Realty`].
+///
+/// Replaces all the existing [`Ownership`]s of the [`Realty`].
+#[derive(Clone, Debug)]
+pub struct SetRealtyOwners {
+    /// ID of the [`Realty`] to set the owners of.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// IDs of the owner [`User`]s along with their [`Percent`] shares.
+    ///
+    /// Shares must sum to `100%`.
+    pub owners: Vec<(user::Id, Percent)>,
+}
+
+impl<Db> Command<SetRealtyOwners> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<Ownership, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<Ownership>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<Ownership>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SetRealtyOwners,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SetRealtyOwners {
+            realty_id,
+            agency_id,
+            owners,
+        } = cmd;
+
+        let ownerships = owners
+            .into_iter()
+            .map(|(owner_id, share)| Ownership {
+                realty_id,
+                owner_id,
+                share,
+            })
+            .collect::<Vec<_>>();
+        if !Ownership::is_complete(&ownerships) {
+            return Err(tracerr::new!(E::InvalidShares));
+        }
+        for o in &ownerships {
+            self.database()
+                .execute(Select(By::<Option<User>, _>::new(o.owner_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or(E::UserNotExists(o.owner_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `Ownership`s.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|r| r.agency_id == agency_id)
+            .ok_or(E::RealtyNotExists(realty_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        tx.execute(Delete(By::<Ownership, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(ownerships.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(ownerships)
+    }
+}
+
+/// Error of [`SetRealtyOwners`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Shares of the owners do not sum to `100%`, or some owner is listed
+    /// twice or with a zero share.
+    #[display("Ownership shares must sum to 100%")]
+    InvalidShares,
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
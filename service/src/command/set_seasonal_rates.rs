@@ -0,0 +1,139 @@
+//! [`Command`] for setting [`SeasonalRate`]s of a [`Realty`].
+
+use std::ops::Range;
+
+use common::{
+    operations::{
+        By, Commit, Delete, Insert, Lock, Select, Transact, Transacted,
+    },
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{contract, Agency};
+use crate::{
+    domain::{agency, contract::short_term_rent::SeasonalRate, realty, Realty},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for setting [`SeasonalRate`]s of a [`Realty`] applied to its
+/// [`contract::ShortTermRent`]s.
+///
+/// Replaces all the existing [`SeasonalRate`]s of the [`Realty`].
+#[derive(Clone, Debug)]
+pub struct SetSeasonalRates {
+    /// ID of the [`Realty`] to set the [`SeasonalRate`]s of.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// Seasons along with their nightly rates.
+    ///
+    /// Seasons must not overlap each other.
+    pub rates: Vec<(Range<DateTime>, Money)>,
+}
+
+impl<Db> Command<SetSeasonalRates> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<SeasonalRate, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<SeasonalRate>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<SeasonalRate>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SetSeasonalRates,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SetSeasonalRates {
+            realty_id,
+            agency_id,
+            rates,
+        } = cmd;
+
+        let mut rates = rates
+            .into_iter()
+            .map(|(season, nightly_rate)| SeasonalRate {
+                realty_id,
+                from: season.start,
+                until: season.end,
+                nightly_rate,
+            })
+            .collect::<Vec<_>>();
+        if !SeasonalRate::are_valid(&rates) {
+            return Err(tracerr::new!(E::InvalidRates));
+        }
+        rates.sort_by_key(|r| r.from);
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `SeasonalRate`s.
+        tx.execute(Lock(By::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|r| r.agency_id == agency_id)
+            .ok_or(E::RealtyNotExists(realty_id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        tx.execute(Delete(By::<SeasonalRate, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(rates.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(rates)
+    }
+}
+
+/// Error of [`SetSeasonalRates`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Some season is empty, has a non-positive nightly rate, or overlaps
+    /// with another one.
+    #[display("Seasonal rates must have positive rates and not overlap")]
+    InvalidRates,
+
+    /// [`Realty`] with the provided ID does not exist in the [`Agency`].
+    #[display("`Realty(id: {_0})` does not exist")]
+    #[from(ignore)]
+    RealtyNotExists(#[error(not(source))] realty::Id),
+}
@@ -0,0 +1,121 @@
+//! [`Command`] for signing a [`Contract`] by its [`contract::Party`].
+
+use common::{
+    operations::{By, Commit, Insert, Lock, Select, Transact, Transacted},
+    DateTime,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{contract, user, Contract},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for signing a [`Contract`] by its [`contract::Party`].
+///
+/// Signs all the unsigned [`contract::Party`]s of the initiator at once, in
+/// case they participate in the [`Contract`] in several
+/// [`contract::party::Role`]s.
+#[derive(Clone, Copy, Debug)]
+pub struct SignContract {
+    /// ID of the [`Contract`] to be signed.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] signing the [`Contract`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<SignContract> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<contract::Party>, contract::Id>>,
+            Ok = Vec<contract::Party>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<contract::Party>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: SignContract) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SignContract {
+            contract_id,
+            initiator_id,
+        } = cmd;
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent signatures.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let mut parties = tx
+            .execute(Select(By::<Vec<contract::Party>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if !parties.iter().any(|p| p.user_id == initiator_id) {
+            return Err(tracerr::new!(E::NotParty(initiator_id)));
+        }
+
+        let now = DateTime::now();
+        let signed = parties
+            .iter_mut()
+            .filter(|p| p.user_id == initiator_id && !p.is_signed())
+            .map(|p| {
+                p.signed_at = Some(now.coerce());
+                *p
+            })
+            .collect::<Vec<_>>();
+        if signed.is_empty() {
+            return Err(tracerr::new!(E::AlreadySigned(initiator_id)));
+        }
+        tx.execute(Insert(signed))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(parties)
+    }
+}
+
+/// Error of [`SignContract`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`User`] has already signed the [`Contract`].
+    #[display("`User(id: {_0})` has already signed the `Contract`")]
+    #[from(ignore)]
+    AlreadySigned(#[error(not(source))] user::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not a [`contract::Party`] of the [`Contract`] (or the
+    /// [`Contract`] does not exist).
+    #[display("`User(id: {_0})` is not a party of the `Contract`")]
+    #[from(ignore)]
+    NotParty(#[error(not(source))] user::Id),
+}
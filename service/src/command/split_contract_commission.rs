@@ -0,0 +1,178 @@
+//! [`Command`] for splitting a [`Contract`] commission between employers.
+
+use common::{
+    operations::{
+        By, Commit, Delete, Insert, Lock, Select, Transact, Transacted,
+    },
+    Percent,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::{
+    domain::{
+        contract::{self, commission},
+        user, Contract,
+    },
+    infra::{database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for splitting a [`Contract`] commission between employers.
+///
+/// Replaces all the existing [`commission::Split`]s of the [`Contract`]. Only
+/// the signing employer [`User`] of the [`Contract`] may split its
+/// commission, and only with the employer [`User`]s of the same [`Agency`].
+#[derive(Clone, Debug)]
+pub struct SplitContractCommission {
+    /// ID of the [`Contract`] to split the commission of.
+    pub contract_id: contract::Id,
+
+    /// IDs of the employer [`User`]s along with their [`Percent`] shares.
+    ///
+    /// Shares must sum to `100%`.
+    pub splits: Vec<(user::Id, Percent)>,
+
+    /// ID of the employer [`User`] splitting the commission.
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<SplitContractCommission> for Service<Db>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<commission::Split, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Insert<Vec<commission::Split>>,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Vec<commission::Split>;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: SplitContractCommission,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SplitContractCommission {
+            contract_id,
+            splits,
+            initiator_id,
+        } = cmd;
+
+        let splits = splits
+            .into_iter()
+            .map(|(employer_id, share)| commission::Split {
+                contract_id,
+                employer_id,
+                share,
+            })
+            .collect::<Vec<_>>();
+        if !commission::Split::is_complete(&splits) {
+            return Err(tracerr::new!(E::InvalidShares));
+        }
+
+        let tx = self
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent modifications of `commission::Split`s.
+        tx.execute(Lock(By::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        // Only the signing employer splits the commission of a `Contract`.
+        let contract = tx
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|c| match c {
+                Contract::ManagementForRent(c) => c.employer_id == initiator_id,
+                Contract::ManagementForSale(c) => c.employer_id == initiator_id,
+                Contract::Rent(c) => c.employer_id == initiator_id,
+                Contract::Sale(c) => c.employer_id == initiator_id,
+                Contract::ShortTermRent(c) => c.employer_id == initiator_id,
+                Contract::Employment(_) => false,
+            })
+            .ok_or(E::ContractNotExists(contract_id))
+            .map_err(tracerr::wrap!())?;
+
+        for s in &splits {
+            self.database()
+                .execute(Select(
+                    By::<Option<Active<contract::Employment>>, _>::new(
+                        s.employer_id,
+                    ),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(|Active(e)| e.agency_id == contract.agency_id())
+                .ok_or(E::UserNotEmployed(s.employer_id))
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        tx.execute(Delete(By::<commission::Split, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        tx.execute(Insert(splits.clone()))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        Ok(splits)
+    }
+}
+
+/// Error of [`SplitContractCommission`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] with the provided ID does not exist, is a
+    /// [`contract::Employment`], or is not signed by the initiator.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// Shares of the employers do not sum to `100%`, or some employer is
+    /// listed twice or with a zero share.
+    #[display("Commission shares must sum to 100%")]
+    InvalidShares,
+
+    /// [`User`] is not employed by the [`Agency`] of the [`Contract`].
+    #[display("`User(id: {_0})` is not employed")]
+    #[from(ignore)]
+    UserNotEmployed(#[error(not(source))] user::Id),
+}
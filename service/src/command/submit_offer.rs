@@ -0,0 +1,136 @@
+//! [`Command`] for submitting an [`Offer`] on a placed [`Realty`].
+
+use common::{
+    money::Currency,
+    operations::{By, Insert, Select},
+    DateTime, Money,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Realty, User};
+use crate::{
+    domain::{contract, offer, user, Contract, Offer, Version},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for submitting an [`Offer`] on a placed [`Realty`].
+#[derive(Clone, Copy, Debug)]
+pub struct SubmitOffer {
+    /// ID of the [`contract::ManagementForRent`] or the
+    /// [`contract::ManagementForSale`] placing the [`Realty`].
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] submitting the [`Offer`].
+    pub bidder_id: user::Id,
+
+    /// Offered price.
+    pub price: Money,
+}
+
+impl<Db> Command<SubmitOffer> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Offer>, Err = Traced<database::Error>>,
+{
+    type Ok = Offer;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, cmd: SubmitOffer) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let SubmitOffer {
+            contract_id,
+            bidder_id,
+            price,
+        } = cmd;
+
+        let contract = self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(Contract::is_active)
+            .filter(|c| c.is_placed() == Some(true))
+            .ok_or(E::ContractNotPlaced(contract_id))
+            .map_err(tracerr::wrap!())?;
+        let (realty_id, employer_id, expected_price) = match &contract {
+            Contract::ManagementForRent(c) => {
+                (c.realty_id, c.employer_id, &c.expected_price)
+            }
+            Contract::ManagementForSale(c) => {
+                (c.realty_id, c.employer_id, &c.expected_price)
+            }
+            Contract::Employment(_)
+            | Contract::Rent(_)
+            | Contract::Sale(_)
+            | Contract::ShortTermRent(_) => {
+                return Err(tracerr::new!(E::ContractNotPlaced(contract_id)));
+            }
+        };
+        if price.currency != expected_price.currency {
+            return Err(tracerr::new!(E::CurrencyMismatch(
+                expected_price.currency
+            )));
+        }
+        if bidder_id == employer_id {
+            return Err(tracerr::new!(E::BidderIsEmployer(bidder_id)));
+        }
+
+        let offer = Offer {
+            id: offer::Id::new(),
+            agency_id: contract.agency_id(),
+            contract_id,
+            realty_id,
+            bidder_id,
+            employer_id,
+            proposer_id: bidder_id,
+            previous_id: None,
+            concluded_contract_id: None,
+            price,
+            status: offer::Status::Pending,
+            created_at: DateTime::now().coerce(),
+            decided_at: None,
+            version: Version::initial(),
+        };
+        self.database()
+            .execute(Insert(offer))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        self.notify_offer(offer);
+
+        Ok(offer)
+    }
+}
+
+/// Error of [`SubmitOffer`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Contract`] with the provided ID does not place any [`Realty`].
+    #[display("`Contract(id: {_0})` is not placed")]
+    #[from(ignore)]
+    ContractNotPlaced(#[error(not(source))] contract::Id),
+
+    /// Offered price is not in the currency of the expected price.
+    #[display("Offered price must be in `{_0}` currency")]
+    #[from(ignore)]
+    CurrencyMismatch(#[error(not(source))] Currency),
+
+    /// [`User`] cannot bid on a [`Realty`] managed by themselves.
+    #[display("`User(id: {_0})` manages the placed `Realty`")]
+    #[from(ignore)]
+    BidderIsEmployer(#[error(not(source))] user::Id),
+}
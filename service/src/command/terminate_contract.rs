@@ -9,12 +9,12 @@ use tracerr::Traced;
 
 use crate::{
     domain::{contract, realty, user, Contract, Realty, User},
-    infra::{database, Database},
+    infra::{cache, database, Database},
     read::contract::Active,
     Service,
 };
 
-use super::Command;
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
 
 /// [`Command`] for terminating a [`Contract`].
 #[derive(Clone, Copy, Debug)]
@@ -57,6 +57,7 @@ where
     type Ok = Contract;
     type Err = Traced<ExecutionError>;
 
+    #[expect(clippy::too_many_lines, reason = "still readable")]
     async fn execute(
         &self,
         cmd: TerminateContract,
@@ -76,7 +77,8 @@ where
             .ok_or(E::UserNotExists(initiator_id))
             .map_err(tracerr::wrap!())?;
 
-        self.database()
+        let Active(employment) = self
+            .database()
             .execute(Select(
                 By::<Option<Active<contract::Employment>>, _>::new(
                     initiator.id,
@@ -85,66 +87,98 @@ where
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
             .ok_or(E::UserNotEmployer(initiator.id))
-            .map_err(tracerr::wrap!())
-            .map(drop)?;
-
-        let contract = self
-            .database()
-            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?
-            .filter(Contract::is_active)
-            .ok_or(E::ContractNotExists(contract_id))
             .map_err(tracerr::wrap!())?;
 
-        let tx = self
+        // `Contract`s of other `Agency`s are not visible to the initiator.
+        let contract = self
             .database()
-            .execute(Transact)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))?;
-
-        if let Some(realty_id) = contract.realty_id() {
-            // Avoid concurrent actions upon the same `Realty`.
-            tx.execute(Lock(By::new(realty_id)))
-                .await
-                .map_err(tracerr::map_from_and_wrap!(=> E))
-                .map(drop)?;
-        }
-
-        // Avoid concurrent deletions.
-        tx.execute(Lock(By::new(contract.id())))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
-
-        let mut contract = tx
             .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?
             .filter(Contract::is_active)
+            .filter(|c| c.agency_id() == employment.agency_id)
             .ok_or(E::ContractNotExists(contract_id))
             .map_err(tracerr::wrap!())?;
 
-        if contract.terminated_at().is_some() {
-            return Err(tracerr::new!(E::ContractAlreadyTerminated(
-                contract_id
-            )));
+        let mut attempt = 1;
+        let contract = loop {
+            let res = async {
+                let tx = self
+                    .database()
+                    .execute(Transact)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+                if let Some(realty_id) = contract.realty_id() {
+                    // Avoid concurrent actions upon the same `Realty`.
+                    tx.execute(Lock(By::new(realty_id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(drop)?;
+                }
+
+                // Avoid concurrent deletions.
+                tx.execute(Lock(By::new(contract.id())))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                let mut contract = tx
+                    .execute(Select(By::<Option<Contract>, _>::new(
+                        contract_id,
+                    )))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))?
+                    .filter(Contract::is_active)
+                    .ok_or(E::ContractNotExists(contract_id))
+                    .map_err(tracerr::wrap!())?;
+
+                if contract.terminated_at().is_some() {
+                    return Err(tracerr::new!(E::ContractAlreadyTerminated(
+                        contract_id
+                    )));
+                }
+
+                _ = contract
+                    .terminated_at_mut()
+                    .replace(DateTime::now().coerce());
+
+                tx.execute(Insert(contract.clone()))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                tx.execute(Commit)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> E))
+                    .map(drop)?;
+
+                Ok::<_, Traced<E>>(contract)
+            }
+            .await;
+            match res {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && matches!(e.as_ref(), E::Db(e) if e.is_conflict()) =>
+                {
+                    attempt += 1;
+                }
+                res => break res?,
+            }
+        };
+        match &contract {
+            Contract::Employment(c) => {
+                self.invalidate_cache(&[cache::key::employment(c.employer_id)])
+                    .await;
+            }
+            Contract::ManagementForRent(_) | Contract::ManagementForSale(_) => {
+                self.placements_changed().await;
+            }
+            Contract::Rent(_)
+            | Contract::Sale(_)
+            | Contract::ShortTermRent(_) => {}
         }
 
-        _ = contract
-            .terminated_at_mut()
-            .replace(DateTime::now().coerce());
-
-        tx.execute(Insert(contract.clone()))
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
-
-        tx.execute(Commit)
-            .await
-            .map_err(tracerr::map_from_and_wrap!(=> E))
-            .map(drop)?;
-
         Ok(contract)
     }
 }
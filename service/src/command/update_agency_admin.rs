@@ -0,0 +1,120 @@
+//! [`Command`] for transferring an [`Agency`] administration to another
+//! [`User`].
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, user, Agency, User},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for transferring an [`Agency`] administration to another
+/// [`User`].
+#[derive(Clone, Copy, Debug)]
+pub struct UpdateAgencyAdmin {
+    /// ID of the [`Agency`] which administrator should be updated.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] to administrate the [`Agency`].
+    pub admin_id: user::Id,
+
+    /// ID of the [`User`] administrating the [`Agency`] at the moment.
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<UpdateAgencyAdmin> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Agency>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Agency;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: UpdateAgencyAdmin,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let UpdateAgencyAdmin {
+            agency_id,
+            admin_id,
+            initiator_id,
+        } = cmd;
+
+        let admin = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(admin_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(admin_id))
+            .map_err(tracerr::wrap!())?;
+
+        let mut attempt = 1;
+        loop {
+            let mut agency = self
+                .database()
+                .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or(E::AgencyNotExists(agency_id))
+                .map_err(tracerr::wrap!())?;
+            if agency.admin_id != initiator_id {
+                return Err(tracerr::new!(E::UserNotAdmin(initiator_id)));
+            }
+            if agency.admin_id == admin.id {
+                return Ok(agency);
+            }
+
+            agency.admin_id = admin.id;
+            match self.database().execute(Update(agency.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    return res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| agency);
+                }
+            }
+        }
+    }
+}
+
+/// Error of [`UpdateAgencyAdmin`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    #[from(ignore)]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
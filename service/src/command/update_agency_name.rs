@@ -0,0 +1,103 @@
+//! [`Command`] for updating an [`agency::Name`].
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{agency::Name, User};
+use crate::{
+    domain::{agency, user, Agency},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for updating an [`agency::Name`].
+#[derive(Clone, Debug)]
+pub struct UpdateAgencyName {
+    /// ID of the [`Agency`] which [`Name`] should be updated.
+    pub agency_id: agency::Id,
+
+    /// New [`Name`] of the [`Agency`].
+    pub name: agency::Name,
+
+    /// ID of the [`User`] administrating the [`Agency`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<UpdateAgencyName> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Agency>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Agency;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: UpdateAgencyName,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let UpdateAgencyName {
+            agency_id,
+            name,
+            initiator_id,
+        } = cmd;
+
+        let mut attempt = 1;
+        loop {
+            let mut agency = self
+                .database()
+                .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .ok_or(E::AgencyNotExists(agency_id))
+                .map_err(tracerr::wrap!())?;
+            if agency.admin_id != initiator_id {
+                return Err(tracerr::new!(E::UserNotAdmin(initiator_id)));
+            }
+            if agency.name == name {
+                return Ok(agency);
+            }
+
+            agency.name = name.clone();
+            match self.database().execute(Update(agency.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    return res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| agency);
+                }
+            }
+        }
+    }
+}
+
+/// Error of [`UpdateAgencyName`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    #[from(ignore)]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+}
@@ -0,0 +1,127 @@
+//! [`Command`] for updating [`agency::Settings`].
+
+use std::time::Duration;
+
+use common::{
+    money::Currency,
+    operations::{By, Select, Update},
+    Percent,
+};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{agency, user, Agency},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for updating [`agency::Settings`].
+#[derive(Clone, Copy, Debug)]
+pub struct UpdateAgencySettings {
+    /// ID of the [`Agency`] which [`agency::Settings`] should be updated.
+    pub agency_id: agency::Id,
+
+    /// New default [`Currency`] of the [`Agency`].
+    pub currency: Option<Currency>,
+
+    /// New default duration of the [`Agency`] contracts.
+    pub contract_duration: Option<Duration>,
+
+    /// New default commission of the [`Agency`] management contracts.
+    pub commission: Option<Percent>,
+
+    /// New default [`agency::Locale`] of the [`Agency`].
+    pub locale: Option<agency::Locale>,
+
+    /// ID of the [`User`] administrating the [`Agency`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<UpdateAgencySettings> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Update<agency::Settings>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = agency::Settings;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: UpdateAgencySettings,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let UpdateAgencySettings {
+            agency_id,
+            currency,
+            contract_duration,
+            commission,
+            locale,
+            initiator_id,
+        } = cmd;
+
+        if contract_duration.is_some_and(|d| d.is_zero()) {
+            return Err(tracerr::new!(E::ZeroDuration));
+        }
+
+        let agency = self
+            .database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::AgencyNotExists(agency_id))
+            .map_err(tracerr::wrap!())?;
+        if agency.admin_id != initiator_id {
+            return Err(tracerr::new!(E::UserNotAdmin(initiator_id)));
+        }
+
+        let settings = agency::Settings {
+            agency_id: agency.id,
+            currency,
+            contract_duration,
+            commission,
+            locale,
+            updated_at: agency::SettingsUpdateDateTime::now(),
+        };
+        self.database()
+            .execute(Update(settings))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        Ok(settings)
+    }
+}
+
+/// Error of [`UpdateAgencySettings`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Agency`] with the provided ID does not exist.
+    #[display("`Agency(id: {_0})` does not exist")]
+    #[from(ignore)]
+    AgencyNotExists(#[error(not(source))] agency::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// Provided contract duration is zero.
+    #[display("Contract duration must be positive")]
+    ZeroDuration,
+}
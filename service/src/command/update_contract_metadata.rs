@@ -0,0 +1,161 @@
+//! [`Command`] for updating a [`contract::Name`] and a
+//! [`contract::Description`].
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, user, Contract, User},
+    infra::{cache, database, Database},
+    read::contract::Active,
+    Service,
+};
+
+use super::{Command, MAX_CONFLICT_ATTEMPTS};
+
+/// [`Command`] for updating a [`contract::Name`] and a
+/// [`contract::Description`].
+#[derive(Clone, Debug)]
+pub struct UpdateContractMetadata {
+    /// ID of the [`Contract`] to be updated.
+    pub contract_id: contract::Id,
+
+    /// New [`contract::Name`] of the [`Contract`].
+    ///
+    /// [`None`] keeps the current one.
+    pub name: Option<contract::Name>,
+
+    /// New [`contract::Description`] of the [`Contract`].
+    ///
+    /// [`None`] keeps the current one.
+    pub description: Option<contract::Description>,
+
+    /// ID of the [`User`] who updates the [`Contract`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<UpdateContractMetadata> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Update<Contract>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = Contract;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: UpdateContractMetadata,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let UpdateContractMetadata {
+            contract_id,
+            name,
+            description,
+            initiator_id,
+        } = cmd;
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        let Active(employment) = self
+            .database()
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    initiator.id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotEmployer(initiator.id))
+            .map_err(tracerr::wrap!())?;
+
+        let mut attempt = 1;
+        let contract = loop {
+            // `Contract`s of other `Agency`s are not visible to the initiator.
+            let mut contract = self
+                .database()
+                .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?
+                .filter(Contract::is_active)
+                .filter(|c| c.agency_id() == employment.agency_id)
+                .ok_or(E::ContractNotExists(contract_id))
+                .map_err(tracerr::wrap!())?;
+
+            let name = name.as_ref().filter(|n| *n != contract.name());
+            let description = description
+                .as_ref()
+                .filter(|d| *d != contract.description());
+            if name.is_none() && description.is_none() {
+                return Ok(contract);
+            }
+            if let Some(name) = name {
+                *contract.name_mut() = name.clone();
+            }
+            if let Some(description) = description {
+                *contract.description_mut() = description.clone();
+            }
+
+            match self.database().execute(Update(contract.clone())).await {
+                Err(e)
+                    if attempt < MAX_CONFLICT_ATTEMPTS
+                        && e.as_ref().is_conflict() =>
+                {
+                    attempt += 1;
+                }
+                res => {
+                    break res
+                        .map_err(tracerr::map_from_and_wrap!(=> E))
+                        .map(|()| contract)?;
+                }
+            }
+        };
+        if let Contract::Employment(c) = &contract {
+            self.invalidate_cache(&[cache::key::employment(c.employer_id)])
+                .await;
+        }
+
+        Ok(contract)
+    }
+}
+
+/// Error of [`UpdateContractMetadata`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Contract`] with the provided ID does not exist.
+    #[display("`Contract(id: {_0})` does not exist")]
+    #[from(ignore)]
+    ContractNotExists(#[error(not(source))] contract::Id),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an employer.
+    #[display("`User(id: {_0})` is not an employer")]
+    #[from(ignore)]
+    UserNotEmployer(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+}
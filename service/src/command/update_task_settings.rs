@@ -0,0 +1,129 @@
+//! [`Command`] for updating [`task::Settings`].
+
+use std::time::Duration;
+
+use common::operations::{By, Select, Update};
+use derive_more::{Display, Error, From};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, task, user, Agency, User},
+    infra::{database, Database},
+    Service,
+};
+
+use super::Command;
+
+/// [`Command`] for updating [`task::Settings`].
+#[derive(Clone, Copy, Debug)]
+pub struct UpdateTaskSettings {
+    /// [`task::Kind`] of the task which [`task::Settings`] should be updated.
+    pub task: task::Kind,
+
+    /// New interval between the task runs.
+    ///
+    /// [`None`] resets it to the configured one.
+    pub interval: Option<Duration>,
+
+    /// New task-specific timeout.
+    ///
+    /// [`None`] resets it to the configured one.
+    pub timeout: Option<Duration>,
+
+    /// ID of the [`User`] who updates the [`task::Settings`].
+    pub initiator_id: user::Id,
+}
+
+impl<Db> Command<UpdateTaskSettings> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Agency>, agency::Id>>,
+            Ok = Option<Agency>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Update<task::Settings>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = task::Settings;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(
+        &self,
+        cmd: UpdateTaskSettings,
+    ) -> Result<Self::Ok, Self::Err> {
+        use ExecutionError as E;
+
+        let UpdateTaskSettings {
+            task,
+            interval,
+            timeout,
+            initiator_id,
+        } = cmd;
+
+        if [interval, timeout].into_iter().flatten().any(|d| d.is_zero()) {
+            return Err(tracerr::new!(E::ZeroDuration));
+        }
+
+        let initiator = self
+            .database()
+            .execute(Select(By::<Option<User>, _>::new(initiator_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .ok_or(E::UserNotExists(initiator_id))
+            .map_err(tracerr::wrap!())?;
+
+        // Tasks are platform-wide, so are managed by the platform operator.
+        self.database()
+            .execute(Select(By::<Option<Agency>, _>::new(agency::Id::DEFAULT)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .filter(|a| a.admin_id == initiator.id)
+            .ok_or(E::UserNotAdmin(initiator.id))
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let settings = task::Settings {
+            task,
+            interval,
+            timeout,
+            updated_at: task::UpdateDateTime::now(),
+        };
+        self.database()
+            .execute(Update(settings))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Other instances pick the `Settings` up on the task's next run.
+        self.apply_task_settings(settings);
+
+        Ok(settings)
+    }
+}
+
+/// Error of [`UpdateTaskSettings`] [`Command`] execution.
+#[derive(Debug, Display, Error, From)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`User`] is not an administrator of the default [`Agency`].
+    #[display("`User(id: {_0})` is not an administrator")]
+    #[from(ignore)]
+    UserNotAdmin(#[error(not(source))] user::Id),
+
+    /// [`User`] with the provided ID does not exist.
+    #[display("`User(id: {_0})` does not exist")]
+    #[from(ignore)]
+    UserNotExists(#[error(not(source))] user::Id),
+
+    /// Provided interval or timeout is zero.
+    #[display("Interval and timeout must be positive")]
+    ZeroDuration,
+}
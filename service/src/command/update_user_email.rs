@@ -1,5 +1,7 @@
 //! [`Command`] for updating an [`user::Email`].
 
+use std::mem;
+
 use common::operations::{
     By, Commit, Lock, Select, Transact, Transacted, Update,
 };
@@ -10,7 +12,7 @@ use tracerr::Traced;
 use crate::domain::user::Email;
 use crate::{
     domain::{user, User},
-    infra::{database, Database},
+    infra::{cache, database, email, Database},
     Service,
 };
 
@@ -53,6 +55,14 @@ where
 
         let UpdateUserEmail { user_id, address } = cmd;
 
+        if let Some(email) = &address {
+            if !self.accepts_mail(email).await {
+                return Err(tracerr::new!(E::UndeliverableEmail(
+                    email.clone()
+                )));
+            }
+        }
+
         let tx = self
             .database()
             .execute(Transact)
@@ -75,7 +85,7 @@ where
             return Ok(user);
         }
 
-        user.email = address;
+        let previous = mem::replace(&mut user.email, address);
         tx.execute(Update(user.clone()))
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?;
@@ -83,6 +93,18 @@ where
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        self.invalidate_cache(&[cache::key::user(user_id)]).await;
+
+        // Notify the previous address, so its owner may notice a hijacking.
+        if let Some(to) = previous {
+            self.send_email(
+                to,
+                None,
+                &email::Template::EmailChanged {
+                    address: user.email.clone(),
+                },
+            );
+        }
 
         Ok(user)
     }
@@ -99,4 +121,9 @@ pub enum ExecutionError {
     #[display("`User(id: {_0}` does not exist")]
     #[from(ignore)]
     UserNotExists(#[error(not(source))] user::Id),
+
+    /// Domain of the provided [`user::Email`] doesn't accept mail.
+    #[display("`{_0}` email domain does not accept mail")]
+    #[from(ignore)]
+    UndeliverableEmail(#[error(not(source))] user::Email),
 }
@@ -10,7 +10,7 @@ use tracerr::Traced;
 use crate::domain::user::Name;
 use crate::{
     domain::{user, User},
-    infra::{database, Database},
+    infra::{cache, database, Database},
     Service,
 };
 
@@ -81,6 +81,7 @@ where
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        self.invalidate_cache(&[cache::key::user(user_id)]).await;
 
         Ok(user)
     }
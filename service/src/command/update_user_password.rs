@@ -10,7 +10,7 @@ use tracerr::Traced;
 use crate::domain::user::Password;
 use crate::{
     domain::{user, User},
-    infra::{database, Database},
+    infra::{cache, database, email, Database},
     Service,
 };
 
@@ -58,6 +58,11 @@ where
             old_password,
         } = cmd;
 
+        let violations = self.password_violations(&new_password).await;
+        if !violations.is_empty() {
+            return Err(tracerr::new!(E::WeakPassword(violations)));
+        }
+
         let tx = self
             .database()
             .execute(Transact)
@@ -95,6 +100,11 @@ where
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        self.invalidate_cache(&[cache::key::user(user_id)]).await;
+
+        if let Some(to) = user.email.clone() {
+            self.send_email(to, None, &email::Template::PasswordChanged);
+        }
 
         Ok(user)
     }
@@ -115,4 +125,9 @@ pub enum ExecutionError {
     /// Wrong old [`Password`] provided.
     #[display("Wrong old password")]
     WrongPassword,
+
+    /// New [`Password`] violates the [`user::password::Policy`].
+    #[display("`Password` violates {_0:?} rules")]
+    #[from(ignore)]
+    WeakPassword(#[error(not(source))] Vec<user::password::Rule>),
 }
@@ -1,7 +1,7 @@
 //! [`Command`] for updating an [`user::Phone`].
 
 use common::operations::{
-    By, Commit, Lock, Select, Transact, Transacted, Update,
+    By, Commit, Insert, Lock, Select, Transact, Transacted, Update,
 };
 use derive_more::{Display, Error, From};
 use tracerr::Traced;
@@ -9,8 +9,8 @@ use tracerr::Traced;
 #[cfg(doc)]
 use crate::domain::user::Phone;
 use crate::{
-    domain::{user, User},
-    infra::{database, Database},
+    domain::{sms, user, User},
+    infra::{cache, database, sms as gateway, Database},
     Service,
 };
 
@@ -30,7 +30,8 @@ pub struct UpdateUserPhone {
 
 impl<Db> Command<UpdateUserPhone> for Service<Db>
 where
-    Db: Database<Transact, Err = Traced<database::Error>>,
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<Insert<sms::Delivery>, Err = Traced<database::Error>>,
     Transacted<Db>: Database<
             Select<By<Option<User>, user::Id>>,
             Ok = Option<User>,
@@ -83,6 +84,13 @@ where
         tx.execute(Commit)
             .await
             .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        self.invalidate_cache(&[cache::key::user(user_id)]).await;
+
+        // Notify the new number, so its owner may notice a mistyped one.
+        if let Some(to) = user.phone.clone() {
+            self.send_sms(to, None, gateway::Template::PhoneLinked)
+                .await;
+        }
 
         Ok(user)
     }
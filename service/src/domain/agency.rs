@@ -0,0 +1,193 @@
+//! [`Agency`] and its [`Settings`] definitions.
+
+use std::time::Duration;
+
+use common::{
+    define_kind, money::Currency, unit, DateTime, DateTimeOf, Percent,
+};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{contract, user, Version};
+#[cfg(doc)]
+use crate::domain::{Contract, Realty, User};
+
+/// Real estate agency (tenant of the platform), owning its [`Realty`]s and
+/// [`Contract`]s.
+#[derive(Clone, Debug)]
+pub struct Agency {
+    /// ID of this [`Agency`].
+    pub id: Id,
+
+    /// [`Name`] of this [`Agency`].
+    pub name: Name,
+
+    /// ID of the [`User`] administrating this [`Agency`].
+    pub admin_id: user::Id,
+
+    /// [`DateTime`] when this [`Agency`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`Version`] of this [`Agency`].
+    pub version: Version,
+}
+
+/// ID of an [`Agency`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// ID of the default [`Agency`], owning everything created before the
+    /// platform became multi-agency.
+    ///
+    /// Its administrator is considered the platform operator.
+    pub const DEFAULT: Self = Self(Uuid::from_u128(1));
+
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Indicates whether this [`Id`] is the [`Id::DEFAULT`] one.
+    #[must_use]
+    pub fn is_default(self) -> bool {
+        self == Self::DEFAULT
+    }
+}
+
+impl Default for Id {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Name of an [`Agency`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Name(String);
+
+impl Name {
+    /// Creates a new [`Name`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `name` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Creates a new [`Name`] if the given `name` is valid.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Option<Self> {
+        let name = name.into();
+        Self::check(&name).then_some(Self(name))
+    }
+
+    /// Checks whether the given `name` is a valid [`Name`].
+    fn check(name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        name.trim() == name && !name.is_empty() && name.len() <= 256
+    }
+}
+
+impl FromStr for Name {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Name`")
+    }
+}
+
+/// [`DateTime`] when an [`Agency`] was created.
+pub type CreationDateTime = DateTimeOf<(Agency, unit::Creation)>;
+
+/// Default settings of an [`Agency`], applied to its [`Contract`]s when the
+/// corresponding details are omitted.
+#[derive(Clone, Copy, Debug)]
+pub struct Settings {
+    /// ID of the [`Agency`] these [`Settings`] belong to.
+    pub agency_id: Id,
+
+    /// Default [`Currency`] of the [`Agency`] prices.
+    pub currency: Option<Currency>,
+
+    /// Default duration of a [`Contract`], after which it expires.
+    ///
+    /// [`None`] means [`Contract`]s don't expire by default.
+    pub contract_duration: Option<Duration>,
+
+    /// Default commission of a management [`Contract`], taken from its
+    /// price.
+    pub commission: Option<Percent>,
+
+    /// Default [`Locale`] of the [`Agency`].
+    pub locale: Option<Locale>,
+
+    /// [`DateTime`] when these [`Settings`] were updated.
+    pub updated_at: SettingsUpdateDateTime,
+}
+
+impl Settings {
+    /// Creates new empty [`Settings`] of the [`Agency`] with the provided
+    /// ID.
+    #[must_use]
+    pub fn new(agency_id: Id) -> Self {
+        Self {
+            agency_id,
+            currency: None,
+            contract_duration: None,
+            commission: None,
+            locale: None,
+            updated_at: SettingsUpdateDateTime::now(),
+        }
+    }
+
+    /// Returns the default [`contract::ExpirationDateTime`] of a
+    /// [`Contract`] created at the provided [`DateTime`], if any.
+    #[must_use]
+    pub fn expiration(
+        &self,
+        created_at: DateTime,
+    ) -> Option<contract::ExpirationDateTime> {
+        self.contract_duration.map(|d| (created_at + d).coerce())
+    }
+}
+
+define_kind! {
+    #[doc = "Locale of an [`Agency`]."]
+    enum Locale {
+        #[doc = "English."]
+        En = 1,
+
+        #[doc = "Russian."]
+        Ru = 2,
+    }
+}
+
+/// Marker type indicating [`Settings`] update.
+#[derive(Clone, Copy, Debug)]
+pub struct SettingsUpdate;
+
+/// [`DateTime`] when [`Settings`] were updated.
+pub type SettingsUpdateDateTime = DateTimeOf<(Settings, SettingsUpdate)>;
@@ -0,0 +1,247 @@
+//! [`Arrears`] definitions.
+
+use std::time::Duration;
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{DateTimeOf, Money};
+use rust_decimal::Decimal;
+
+use crate::domain::{agency, contract, user};
+#[cfg(doc)]
+use crate::domain::{Agency, LateFee, Payment, User};
+
+/// Overdue debt of a purchaser under a [`contract::Rent`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Arrears {
+    /// ID of the [`contract::Rent`] in arrears.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`Agency`] the [`contract::Rent`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] renting the [`Realty`].
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    pub purchaser_id: user::Id,
+
+    /// ID of the employer [`User`] responsible for the [`contract::Rent`].
+    pub employer_id: user::Id,
+
+    /// Overdue amount, in the currency of the [`contract::Rent::price`].
+    pub amount: Money,
+
+    /// Part of the [`Arrears::amount`] consisting of the accrued
+    /// [`LateFee`]s.
+    pub late_fees: Money,
+
+    /// [`DateTime`] when the earliest unpaid installment was due.
+    pub overdue_since: OverdueDateTime,
+
+    /// [`DateTime`] when these [`Arrears`] were detected.
+    pub detected_at: DetectionDateTime,
+}
+
+impl Arrears {
+    /// Detects [`Arrears`] of the provided [`contract::Rent`] at the provided
+    /// [`DateTime`], given the total `paid` amount of its [`Payment`]s and
+    /// the total amount of its accrued [`LateFee`]s.
+    ///
+    /// Installments become overdue only after the `grace` period since their
+    /// due date. [`None`] is returned if nothing is overdue.
+    #[must_use]
+    pub fn detect(
+        rent: &contract::Rent,
+        paid: Decimal,
+        late_fees: Decimal,
+        grace: Duration,
+        now: DetectionDateTime,
+    ) -> Option<Self> {
+        let due = rent.installments_due_by(now.coerce() - grace);
+        let amount = rent.price.amount * Decimal::from(due) + late_fees - paid;
+        if amount <= Decimal::ZERO || rent.price.amount <= Decimal::ZERO {
+            return None;
+        }
+
+        // Payments cover the installments in the order of their due dates,
+        // and only then the late fees.
+        let unpaid = (paid.max(Decimal::ZERO) / rent.price.amount)
+            .floor()
+            .try_into()
+            .unwrap_or(u32::MAX)
+            .min(due.checked_sub(1)?);
+        let overdue_since = rent.installment_due_at(unpaid)?.coerce();
+
+        Some(Self {
+            contract_id: rent.id,
+            agency_id: rent.agency_id,
+            purchaser_id: rent.purchaser_id,
+            employer_id: rent.employer_id,
+            amount: Money {
+                amount,
+                currency: rent.price.currency,
+            },
+            late_fees: Money {
+                amount: late_fees.clamp(Decimal::ZERO, amount),
+                currency: rent.price.currency,
+            },
+            overdue_since,
+            detected_at: now,
+        })
+    }
+}
+
+/// Marker type indicating an overdue installment.
+#[derive(Clone, Copy, Debug)]
+pub struct Overdue;
+
+/// [`DateTime`] since when [`Arrears`] are overdue.
+pub type OverdueDateTime = DateTimeOf<(Arrears, Overdue)>;
+
+/// Marker type indicating a detection of [`Arrears`].
+#[derive(Clone, Copy, Debug)]
+pub struct Detection;
+
+/// [`DateTime`] when [`Arrears`] were detected.
+pub type DetectionDateTime = DateTimeOf<(Arrears, Detection)>;
+
+#[cfg(test)]
+mod spec {
+    use std::time::Duration;
+
+    use common::{money::Currency, DateTime, Money};
+    use rust_decimal::Decimal;
+
+    use crate::domain::{agency, contract, realty, user, Version};
+
+    use super::Arrears;
+
+    const DAY: Duration = Duration::from_hours(24);
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(s).unwrap()
+    }
+
+    fn rent() -> contract::Rent {
+        contract::Rent {
+            id: contract::Id::new(),
+            agency_id: agency::Id::default(),
+            number: contract::Number::new(contract::Kind::Rent, 2024, 1),
+            name: contract::Name::new("Rent").unwrap(),
+            description: contract::Description::new("Rent").unwrap(),
+            realty_id: realty::Id::new(),
+            purchaser_id: user::Id::new(),
+            landlord_id: user::Id::new(),
+            employer_id: user::Id::new(),
+            price: Money {
+                amount: Decimal::from(1000),
+                currency: Currency::Usd,
+            },
+            deposit: None,
+            created_at: datetime("2024-01-15T12:00:00Z").coerce(),
+            expires_at: None,
+            completed_at: None,
+            terminated_at: None,
+            archived_at: None,
+            version: Version::initial(),
+        }
+    }
+
+    #[test]
+    fn detects_overdue_installments() {
+        let now = datetime("2024-04-01T00:00:00Z").coerce();
+
+        // 3 installments (Jan, Feb, Mar) are due, 1 is paid.
+        let arrears = Arrears::detect(
+            &rent(),
+            Decimal::from(1000),
+            Decimal::ZERO,
+            5 * DAY,
+            now,
+        )
+        .unwrap();
+        assert_eq!(arrears.amount.amount, Decimal::from(2000));
+        assert_eq!(
+            arrears.overdue_since.coerce::<()>(),
+            datetime("2024-02-15T12:00:00Z"),
+        );
+
+        assert!(Arrears::detect(
+            &rent(),
+            Decimal::from(3000),
+            Decimal::ZERO,
+            5 * DAY,
+            now,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn respects_grace_period() {
+        let now = datetime("2024-03-18T00:00:00Z").coerce();
+
+        // March installment is still within its grace period.
+        let arrears = Arrears::detect(
+            &rent(),
+            Decimal::from(2000),
+            Decimal::ZERO,
+            5 * DAY,
+            now,
+        );
+        assert!(arrears.is_none());
+
+        let arrears = Arrears::detect(
+            &rent(),
+            Decimal::from(2000),
+            Decimal::ZERO,
+            2 * DAY,
+            now,
+        )
+        .unwrap();
+        assert_eq!(arrears.amount.amount, Decimal::from(1000));
+    }
+
+    #[test]
+    fn stops_installments_at_contract_end() {
+        let mut rent = rent();
+        rent.terminated_at = Some(datetime("2024-02-01T00:00:00Z").coerce());
+        let now = datetime("2024-06-01T00:00:00Z").coerce();
+
+        let arrears =
+            Arrears::detect(&rent, Decimal::ZERO, Decimal::ZERO, 5 * DAY, now)
+                .unwrap();
+        assert_eq!(arrears.amount.amount, Decimal::from(1000));
+    }
+
+    #[test]
+    fn includes_unpaid_late_fees() {
+        let now = datetime("2024-04-01T00:00:00Z").coerce();
+
+        let arrears = Arrears::detect(
+            &rent(),
+            Decimal::from(2500),
+            Decimal::from(50),
+            5 * DAY,
+            now,
+        )
+        .unwrap();
+        assert_eq!(arrears.amount.amount, Decimal::from(550));
+        assert_eq!(arrears.late_fees.amount, Decimal::from(50));
+        assert_eq!(
+            arrears.overdue_since.coerce::<()>(),
+            datetime("2024-03-15T12:00:00Z"),
+        );
+
+        // Only the late fees are left unpaid.
+        let arrears = Arrears::detect(
+            &rent(),
+            Decimal::from(3020),
+            Decimal::from(50),
+            5 * DAY,
+            now,
+        )
+        .unwrap();
+        assert_eq!(arrears.amount.amount, Decimal::from(30));
+        assert_eq!(arrears.late_fees.amount, Decimal::from(30));
+    }
+}
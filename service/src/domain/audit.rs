@@ -0,0 +1,131 @@
+//! Audit log [`Entry`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, user};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, Realty, User};
+
+/// Entry of an audit log, recording an [`Action`] performed by a [`User`] on
+/// an entity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// ID of this [`Entry`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] the [`Action`] was performed in.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`User`] who performed the [`Action`].
+    pub actor_id: user::Id,
+
+    /// Performed [`Action`].
+    pub action: Action,
+
+    /// [`Entity`] kind the [`Action`] was performed on.
+    pub entity: Entity,
+
+    /// ID of the entity the [`Action`] was performed on.
+    pub entity_id: Uuid,
+
+    /// [`Snapshot`] of the entity before the [`Action`].
+    ///
+    /// [`None`] if the entity didn't exist or isn't snapshotted.
+    pub before: Option<Snapshot>,
+
+    /// [`Snapshot`] of the entity after the [`Action`].
+    ///
+    /// [`None`] if the entity doesn't exist anymore or isn't snapshotted.
+    pub after: Option<Snapshot>,
+
+    /// [`DateTime`] when the [`Action`] was performed.
+    pub created_at: CreationDateTime,
+}
+
+/// ID of an [`Entry`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+define_kind! {
+    #[doc = "Action recorded by an [`Entry`]."]
+    enum Action {
+        #[doc = "[`User`] was impersonated by an admin."]
+        ImpersonateUser = 1,
+
+        #[doc = "[`Contract`] was archived."]
+        ArchiveContract = 2,
+
+        #[doc = "Archived [`Contract`] was restored."]
+        RestoreContract = 3,
+
+        #[doc = "Deleted [`Realty`] was restored."]
+        RestoreRealty = 4,
+
+        #[doc = "Deleted [`User`] was restored."]
+        RestoreUser = 5,
+    }
+}
+
+define_kind! {
+    #[doc = "Kind of the entity an [`Entry`] is recorded for."]
+    enum Entity {
+        #[doc = "[`User`]."]
+        User = 1,
+
+        #[doc = "[`Realty`]."]
+        Realty = 2,
+
+        #[doc = "[`Contract`]."]
+        Contract = 3,
+    }
+}
+
+/// JSON representation of an entity state recorded by an [`Entry`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Snapshot(String);
+
+impl Snapshot {
+    /// Creates a new [`Snapshot`] of the provided entity.
+    ///
+    /// # Panics
+    ///
+    /// If the provided entity fails to be serialized into JSON.
+    #[must_use]
+    pub fn new(entity: &impl Serialize) -> Self {
+        Self(serde_json::to_string(entity).expect("serializable entity"))
+    }
+}
+
+/// [`DateTime`] when an [`Entry`] was created.
+pub type CreationDateTime = DateTimeOf<(Entry, unit::Creation)>;
@@ -0,0 +1,106 @@
+//! [`Branch`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{unit, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, realty};
+#[cfg(doc)]
+use crate::domain::{contract, Agency};
+
+/// Branch office of an [`Agency`], employees are assigned to via their
+/// [`contract::Employment`]s.
+#[derive(Clone, Debug)]
+pub struct Branch {
+    /// ID of this [`Branch`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Branch`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Name`] of this [`Branch`].
+    pub name: Name,
+
+    /// [`realty::Address`] of this [`Branch`].
+    pub address: realty::Address,
+
+    /// [`DateTime`] when this [`Branch`] was created.
+    pub created_at: CreationDateTime,
+}
+
+/// ID of a [`Branch`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Name of a [`Branch`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Name(String);
+
+impl Name {
+    /// Creates a new [`Name`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `name` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Creates a new [`Name`] if the given `name` is valid.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Option<Self> {
+        let name = name.into();
+        Self::check(&name).then_some(Self(name))
+    }
+
+    /// Checks whether the given `name` is a valid [`Name`].
+    fn check(name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        name.trim() == name && !name.is_empty() && name.len() <= 256
+    }
+}
+
+impl FromStr for Name {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Name`")
+    }
+}
+
+/// [`DateTime`] when a [`Branch`] was created.
+pub type CreationDateTime = DateTimeOf<(Branch, unit::Creation)>;
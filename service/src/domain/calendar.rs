@@ -0,0 +1,244 @@
+//! [`Calendar`] definitions.
+
+use common::{DateTime, DateTimeOf};
+
+use crate::domain::contract;
+#[cfg(doc)]
+use crate::domain::{Contract, Realty};
+
+/// Availability calendar of a [`Realty`] for rent, built from the
+/// [`Occupancy`]s by its [`contract::Rent`]s and
+/// [`contract::ShortTermRent`]s.
+#[derive(Clone, Debug, Default)]
+pub struct Calendar {
+    /// [`Occupancy`]s of the [`Realty`], in the order they start.
+    occupancies: Vec<Occupancy>,
+}
+
+impl Calendar {
+    /// Creates a new [`Calendar`] out of the provided [`Occupancy`]s.
+    #[must_use]
+    pub fn new(occupancies: impl IntoIterator<Item = Occupancy>) -> Self {
+        let mut occupancies = occupancies.into_iter().collect::<Vec<_>>();
+        occupancies.sort_by_key(|o| o.from);
+        Self { occupancies }
+    }
+
+    /// Returns the [`Occupancy`]s of this [`Calendar`], in the order they
+    /// start.
+    #[must_use]
+    pub fn occupancies(&self) -> &[Occupancy] {
+        &self.occupancies
+    }
+
+    /// Indicates whether the [`Realty`] is available for the whole provided
+    /// period.
+    ///
+    /// [`None`] `until` means an open-ended period.
+    #[must_use]
+    pub fn is_available(
+        &self,
+        from: DateTime,
+        until: Option<DateTime>,
+    ) -> bool {
+        !self.occupancies.iter().any(|o| o.overlaps(from, until))
+    }
+
+    /// Computes the [`Window`]s the [`Realty`] is available within the
+    /// provided period, in their chronological order.
+    #[must_use]
+    pub fn availability(&self, from: DateTime, until: DateTime) -> Vec<Window> {
+        let mut windows = vec![];
+        let mut cursor = from;
+        for o in &self.occupancies {
+            if cursor >= until {
+                break;
+            }
+            if !o.overlaps(cursor, Some(until)) {
+                continue;
+            }
+            if o.from > cursor {
+                windows.push(Window {
+                    from: cursor,
+                    until: o.from,
+                });
+            }
+            let Some(occupied_until) = o.until else {
+                return windows;
+            };
+            cursor = cursor.max(occupied_until);
+        }
+        if cursor < until {
+            windows.push(Window {
+                from: cursor,
+                until,
+            });
+        }
+        windows
+    }
+}
+
+/// Period a [`Realty`] is occupied by a [`contract::Rent`] or a
+/// [`contract::ShortTermRent`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Occupancy {
+    /// ID of the [`Contract`] occupying the [`Realty`].
+    pub contract_id: contract::Id,
+
+    /// [`DateTime`] the [`Realty`] is occupied from.
+    pub from: DateTime,
+
+    /// [`DateTime`] the [`Realty`] is occupied until.
+    ///
+    /// [`None`] means an open-ended [`Occupancy`].
+    pub until: Option<DateTime>,
+}
+
+impl Occupancy {
+    /// Creates a new [`Occupancy`] by the provided [`contract::Rent`].
+    ///
+    /// [`contract::Rent`] occupies the [`Realty`] since its creation until it
+    /// is terminated, completed or expires, whichever happens first.
+    #[must_use]
+    pub fn of_rent(rent: &contract::Rent) -> Self {
+        let ends = [
+            rent.terminated_at.map(DateTimeOf::coerce),
+            rent.completed_at.map(DateTimeOf::coerce),
+            rent.expires_at.map(DateTimeOf::coerce),
+        ];
+        Self {
+            contract_id: rent.id,
+            from: rent.created_at.coerce(),
+            until: ends.into_iter().flatten().min(),
+        }
+    }
+
+    /// Creates a new [`Occupancy`] by the provided [`contract::ShortTermRent`].
+    ///
+    /// [`contract::ShortTermRent`] occupies the [`Realty`] since the check-in
+    /// until the check-out, unless it's terminated or completed earlier.
+    #[must_use]
+    pub fn of_short_term_rent(rent: &contract::ShortTermRent) -> Self {
+        let ends = [
+            rent.terminated_at.map(DateTimeOf::coerce),
+            rent.completed_at.map(DateTimeOf::coerce),
+            Some(rent.expires_at.coerce()),
+        ];
+        Self {
+            contract_id: rent.id,
+            from: rent.check_in_at.coerce(),
+            until: ends.into_iter().flatten().min(),
+        }
+    }
+
+    /// Indicates whether this [`Occupancy`] overlaps with the provided
+    /// period.
+    ///
+    /// [`None`] `until` means an open-ended period.
+    #[must_use]
+    pub fn overlaps(&self, from: DateTime, until: Option<DateTime>) -> bool {
+        until.is_none_or(|until| self.from < until)
+            && self
+                .until
+                .is_none_or(|occupied_until| from < occupied_until)
+    }
+}
+
+/// Window a [`Realty`] is available for rent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Window {
+    /// [`DateTime`] the [`Realty`] is available from.
+    pub from: DateTime,
+
+    /// [`DateTime`] the [`Realty`] is available until.
+    pub until: DateTime,
+}
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+
+    use crate::domain::contract;
+
+    use super::{Calendar, Occupancy, Window};
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(&format!("{s}T00:00:00Z")).unwrap()
+    }
+
+    fn occupancy(from: &str, until: Option<&str>) -> Occupancy {
+        Occupancy {
+            contract_id: contract::Id::new(),
+            from: datetime(from),
+            until: until.map(datetime),
+        }
+    }
+
+    fn window(from: &str, until: &str) -> Window {
+        Window {
+            from: datetime(from),
+            until: datetime(until),
+        }
+    }
+
+    #[test]
+    fn computes_availability_windows() {
+        let calendar = Calendar::new([
+            occupancy("2024-05-01", Some("2024-06-01")),
+            occupancy("2024-02-01", Some("2024-03-01")),
+        ]);
+
+        assert_eq!(
+            calendar
+                .availability(datetime("2024-01-01"), datetime("2024-12-31")),
+            [
+                window("2024-01-01", "2024-02-01"),
+                window("2024-03-01", "2024-05-01"),
+                window("2024-06-01", "2024-12-31"),
+            ],
+        );
+        assert_eq!(
+            calendar
+                .availability(datetime("2024-02-15"), datetime("2024-05-15")),
+            [window("2024-03-01", "2024-05-01")],
+        );
+        assert_eq!(
+            calendar
+                .availability(datetime("2024-05-02"), datetime("2024-05-03")),
+            [],
+        );
+    }
+
+    #[test]
+    fn open_ended_occupancy_blocks_the_rest() {
+        let calendar = Calendar::new([occupancy("2024-03-01", None)]);
+
+        assert_eq!(
+            calendar
+                .availability(datetime("2024-01-01"), datetime("2024-12-31")),
+            [window("2024-01-01", "2024-03-01")],
+        );
+        assert!(calendar.is_available(
+            datetime("2024-01-01"),
+            Some(datetime("2024-03-01"))
+        ));
+        assert!(!calendar.is_available(datetime("2030-01-01"), None));
+    }
+
+    #[test]
+    fn detects_overlaps() {
+        let calendar =
+            Calendar::new([occupancy("2024-02-01", Some("2024-03-01"))]);
+
+        assert!(calendar.is_available(datetime("2024-03-01"), None));
+        assert!(calendar.is_available(
+            datetime("2024-01-01"),
+            Some(datetime("2024-02-01")),
+        ));
+        assert!(!calendar.is_available(
+            datetime("2024-01-01"),
+            Some(datetime("2024-02-02")),
+        ));
+        assert!(!calendar.is_available(datetime("2024-01-01"), None));
+    }
+}
@@ -0,0 +1,201 @@
+//! [`Charge`] definitions.
+
+use common::{define_kind, unit, DateTime, DateTimeOf, Money};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty, User};
+
+/// Recurring utility or service charge on a [`Realty`] rented under a
+/// [`contract::Rent`], billed along with each of its monthly installments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Charge {
+    /// ID of this [`Charge`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Charge`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`contract::Rent`] this [`Charge`] is billed under.
+    pub contract_id: contract::Id,
+
+    /// [`Name`] of this [`Charge`].
+    pub name: Name,
+
+    /// Monthly amount, in the currency of the [`contract::Rent::price`].
+    pub amount: Money,
+
+    /// [`Bearer`] of this [`Charge`].
+    pub bearer: Bearer,
+
+    /// ID of the employer [`User`] who added this [`Charge`].
+    pub creator_id: user::Id,
+
+    /// [`DateTime`] when this [`Charge`] was added.
+    pub created_at: CreationDateTime,
+}
+
+impl Charge {
+    /// Returns number of the [`contract::Rent`] installments this [`Charge`]
+    /// is billed with by the provided [`DateTime`] (inclusive).
+    ///
+    /// Only the installments due after this [`Charge`] was added are counted.
+    #[must_use]
+    pub fn installments_billed_by(
+        &self,
+        rent: &contract::Rent,
+        at: DateTime,
+    ) -> u32 {
+        rent.installments_due_by(at).saturating_sub(
+            rent.installments_due_by(self.created_at.coerce()),
+        )
+    }
+}
+
+/// ID of a [`Charge`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Name of a [`Charge`] (e.g. "Electricity", "Cleaning").
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Name(String);
+
+impl Name {
+    /// Creates a new [`Name`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `name` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Creates a new [`Name`] if the given `name` is valid.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Option<Self> {
+        let name = name.into();
+        Self::check(&name).then_some(Self(name))
+    }
+
+    /// Checks whether the given `name` is a valid [`Name`].
+    fn check(name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        name.trim() == name && !name.is_empty() && name.len() <= 256
+    }
+}
+
+impl FromStr for Name {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Name`")
+    }
+}
+
+define_kind! {
+    #[doc = "Party bearing a [`Charge`]."]
+    enum Bearer {
+        #[doc = "Purchaser [`User`] renting the [`Realty`]."]
+        Tenant = 1,
+
+        #[doc = "Landlord [`User`] owning the [`Realty`]."]
+        Landlord = 2,
+
+        #[doc = "[`Agency`] managing the [`Realty`]."]
+        Agency = 3,
+    }
+}
+
+/// [`DateTime`] when a [`Charge`] was added.
+pub type CreationDateTime = DateTimeOf<(Charge, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, DateTime, Money};
+    use rust_decimal::Decimal;
+
+    use crate::domain::{agency, contract, realty, user, Version};
+
+    use super::{Bearer, Charge, Id, Name};
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn bills_installments_due_after_creation() {
+        let rent = contract::Rent {
+            id: contract::Id::new(),
+            agency_id: agency::Id::default(),
+            number: contract::Number::new(contract::Kind::Rent, 2024, 1),
+            name: contract::Name::new("Rent").unwrap(),
+            description: contract::Description::new("Rent").unwrap(),
+            realty_id: realty::Id::new(),
+            purchaser_id: user::Id::new(),
+            landlord_id: user::Id::new(),
+            employer_id: user::Id::new(),
+            price: Money {
+                amount: Decimal::from(1000),
+                currency: Currency::Usd,
+            },
+            deposit: None,
+            created_at: datetime("2024-01-15T12:00:00Z").coerce(),
+            expires_at: None,
+            completed_at: None,
+            terminated_at: None,
+            archived_at: None,
+            version: Version::initial(),
+        };
+        let charge = Charge {
+            id: Id::new(),
+            agency_id: rent.agency_id,
+            contract_id: rent.id,
+            name: Name::new("Electricity").unwrap(),
+            amount: Money {
+                amount: Decimal::from(50),
+                currency: Currency::Usd,
+            },
+            bearer: Bearer::Tenant,
+            creator_id: rent.employer_id,
+            created_at: datetime("2024-02-20T00:00:00Z").coerce(),
+        };
+
+        let at = |s| charge.installments_billed_by(&rent, datetime(s));
+        assert_eq!(at("2024-02-01T00:00:00Z"), 0);
+        assert_eq!(at("2024-03-14T00:00:00Z"), 0);
+        assert_eq!(at("2024-03-15T12:00:00Z"), 1);
+        assert_eq!(at("2024-05-20T00:00:00Z"), 3);
+    }
+}
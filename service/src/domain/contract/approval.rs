@@ -0,0 +1,76 @@
+//! [`Approval`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{unit, DateTimeOf, Money};
+use rust_decimal::Decimal;
+
+use crate::domain::{agency, contract, user, Contract};
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+
+/// Approval of a high-value [`Contract`] by a second employer [`User`].
+///
+/// [`Contract`] is pending approval (and so, not active yet) until its
+/// [`Approval`] is granted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Approval {
+    /// ID of the [`Contract`] requiring this [`Approval`].
+    pub contract_id: contract::Id,
+
+    /// ID of the [`Agency`] the [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the employer [`User`] who created the [`Contract`].
+    pub requester_id: user::Id,
+
+    /// [`DateTime`] when this [`Approval`] was requested.
+    pub requested_at: RequestDateTime,
+
+    /// ID of the employer [`User`] who approved the [`Contract`], if any.
+    pub approver_id: Option<user::Id>,
+
+    /// [`DateTime`] when the [`Contract`] was approved, if it was.
+    pub approved_at: Option<ApprovalDateTime>,
+}
+
+impl Approval {
+    /// Creates a new pending [`Approval`] of the provided [`Contract`],
+    /// requested by the employer [`User`] with the provided ID.
+    #[must_use]
+    pub fn pending(contract: &Contract, requester_id: user::Id) -> Self {
+        Self {
+            contract_id: contract.id(),
+            agency_id: contract.agency_id(),
+            requester_id,
+            requested_at: contract.created_at().coerce(),
+            approver_id: None,
+            approved_at: None,
+        }
+    }
+
+    /// Indicates whether a [`Contract`] with the provided `price` requires an
+    /// [`Approval`] under the provided `threshold`.
+    ///
+    /// [`None`] `threshold` means that no [`Contract`] requires it.
+    #[must_use]
+    pub fn is_required(price: Money, threshold: Option<Decimal>) -> bool {
+        threshold.is_some_and(|t| price.amount > t)
+    }
+
+    /// Indicates whether this [`Approval`] is not granted yet.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.approved_at.is_none()
+    }
+}
+
+/// [`DateTime`] when an [`Approval`] was requested.
+pub type RequestDateTime = DateTimeOf<(Approval, unit::Creation)>;
+
+/// Marker type indicating a granted [`Approval`].
+#[derive(Clone, Copy, Debug)]
+pub struct Grant;
+
+/// [`DateTime`] when an [`Approval`] was granted.
+pub type ApprovalDateTime = DateTimeOf<(Approval, Grant)>;
@@ -0,0 +1,75 @@
+//! [`Split`] of a [`Contract`] commission definitions.
+
+use std::collections::HashSet;
+
+use common::Percent;
+
+use crate::domain::{contract, user};
+#[cfg(doc)]
+use crate::domain::{Contract, User};
+
+/// Share of a [`Contract`] commission attributed to an employer [`User`].
+///
+/// [`Contract`] without [`Split`]s is attributed solely to its signing
+/// employer [`User`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Split {
+    /// ID of the [`Contract`] the commission is split of.
+    pub contract_id: contract::Id,
+
+    /// ID of the employer [`User`] the share is attributed to.
+    pub employer_id: user::Id,
+
+    /// [`Percent`] share of the commission.
+    pub share: Percent,
+}
+
+impl Split {
+    /// Indicates whether the provided [`Split`]s cover the whole commission
+    /// of a [`Contract`].
+    ///
+    /// Every employer [`User`] must be listed only once with a non-zero
+    /// share, and the shares must sum to `100%`.
+    #[must_use]
+    pub fn is_complete(splits: &[Self]) -> bool {
+        let mut employers = HashSet::with_capacity(splits.len());
+        let mut total = Percent::ZERO;
+        for s in splits {
+            if s.share == Percent::ZERO || !employers.insert(s.employer_id) {
+                return false;
+            }
+            let Some(sum) = total.checked_add(s.share) else {
+                return false;
+            };
+            total = sum;
+        }
+        total == Percent::HUNDRED
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::domain::{contract, user};
+
+    use super::Split;
+
+    fn split(employer_id: user::Id, share: &str) -> Split {
+        Split {
+            contract_id: contract::Id::default(),
+            employer_id,
+            share: share.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn requires_shares_to_sum_to_hundred() {
+        let (a, b) = (user::Id::new(), user::Id::new());
+
+        assert!(Split::is_complete(&[split(a, "70"), split(b, "30")]));
+
+        assert!(!Split::is_complete(&[]));
+        assert!(!Split::is_complete(&[split(a, "70"), split(b, "20")]));
+        assert!(!Split::is_complete(&[split(a, "50"), split(a, "50")]));
+        assert!(!Split::is_complete(&[split(a, "100"), split(b, "0")]));
+    }
+}
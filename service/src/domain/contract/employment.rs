@@ -1,22 +1,29 @@
 //! Contract of an employment.
 
 use common::{DateTime, Money};
+use serde::{Deserialize, Serialize};
 
-use crate::domain::user;
+use crate::domain::{agency, branch, user, Version};
 #[cfg(doc)]
-use crate::domain::{Contract, User};
+use crate::domain::{Agency, Branch, Contract, User};
 
 use super::{
-    CreationDateTime, Description, ExpirationDateTime, Id, Name,
-    TerminationDateTime,
+    ArchivationDateTime, CompletionDateTime, CreationDateTime, Description,
+    ExpirationDateTime, Id, Name, Number, TerminationDateTime,
 };
 
 /// Employment [`Contract`].
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Employment {
     /// ID of this [`Contract`].
     pub id: Id,
 
+    /// ID of the [`Agency`] this [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Number`] of this [`Contract`].
+    pub number: Number,
+
     /// [`Name`] of this [`Contract`].
     pub name: Name,
 
@@ -26,6 +33,12 @@ pub struct Employment {
     /// ID of the employed [`User`].
     pub employer_id: user::Id,
 
+    /// ID of the [`User`] the employed [`User`] reports to, if any.
+    pub manager_id: Option<user::Id>,
+
+    /// ID of the [`Branch`] the employed [`User`] is assigned to, if any.
+    pub branch_id: Option<branch::Id>,
+
     /// Base salary of the employed [`User`].
     pub base_salary: Money,
 
@@ -37,8 +50,21 @@ pub struct Employment {
     /// [`None`] means that this [`Contract`] is valid indefinitely.
     pub expires_at: Option<ExpirationDateTime>,
 
+    /// [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    pub completed_at: Option<CompletionDateTime>,
+
     /// [`DateTime`] when this [`Contract`] was terminated, if it was.
     pub terminated_at: Option<TerminationDateTime>,
+
+    /// [`DateTime`] when this [`Contract`] was archived, if it was.
+    ///
+    /// Archived [`Contract`]s are hidden from the working lists, but still
+    /// preserved.
+    pub archived_at: Option<ArchivationDateTime>,
+
+    /// [`Version`] of this [`Contract`].
+    pub version: Version,
 }
 
 impl Employment {
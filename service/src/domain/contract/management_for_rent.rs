@@ -2,14 +2,14 @@
 
 use common::{DateTime, Money, Percent};
 
-use crate::domain::{realty, user};
+use crate::domain::{agency, realty, user, Version};
 
 use super::{
-    CreationDateTime, Description, ExpirationDateTime, Id, Name,
-    TerminationDateTime,
+    ArchivationDateTime, CompletionDateTime, CreationDateTime, Description,
+    ExpirationDateTime, Id, Name, Number, TerminationDateTime,
 };
 #[cfg(doc)]
-use crate::domain::{Contract, Realty, User};
+use crate::domain::{Agency, Contract, Realty, User};
 
 /// A [`Contract`] that allows platform to manage a [`Realty`] for a rent.
 #[derive(Clone, Debug)]
@@ -17,6 +17,12 @@ pub struct ManagementForRent {
     /// ID of this [`Contract`].
     pub id: Id,
 
+    /// ID of the [`Agency`] this [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Number`] of this [`Contract`].
+    pub number: Number,
+
     /// [`Name`] of this [`Contract`].
     pub name: Name,
 
@@ -61,8 +67,21 @@ pub struct ManagementForRent {
     /// [`None`] means that this [`Contract`] is valid indefinitely.
     pub expires_at: Option<ExpirationDateTime>,
 
+    /// [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    pub completed_at: Option<CompletionDateTime>,
+
     /// [`DateTime`] when this [`Contract`] was terminated, if it was.
     pub terminated_at: Option<TerminationDateTime>,
+
+    /// [`DateTime`] when this [`Contract`] was archived, if it was.
+    ///
+    /// Archived [`Contract`]s are hidden from the working lists, but still
+    /// preserved.
+    pub archived_at: Option<ArchivationDateTime>,
+
+    /// [`Version`] of this [`Contract`].
+    pub version: Version,
 }
 
 impl ManagementForRent {
@@ -1,10 +1,15 @@
 //! [`Contract`] definitions.
 
+pub mod approval;
+pub mod commission;
 pub mod employment;
 pub mod management_for_rent;
 pub mod management_for_sale;
+pub mod party;
 pub mod rent;
 pub mod sale;
+pub mod short_term_rent;
+pub mod template;
 
 use common::{define_kind, unit, DateTime, DateTimeOf};
 use derive_more::{AsRef, Display, From, FromStr, Into};
@@ -13,13 +18,15 @@ use postgres_types::{FromSql, ToSql};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::realty;
+use crate::domain::{agency, realty, Version};
 #[cfg(doc)]
-use crate::domain::Realty;
+use crate::domain::{Agency, Realty};
 
 pub use self::{
-    employment::Employment, management_for_rent::ManagementForRent,
-    management_for_sale::ManagementForSale, rent::Rent, sale::Sale,
+    approval::Approval, employment::Employment,
+    management_for_rent::ManagementForRent,
+    management_for_sale::ManagementForSale, party::Party, rent::Rent,
+    sale::Sale, short_term_rent::ShortTermRent, template::Template,
 };
 
 /// [`Realty`] contract.
@@ -35,6 +42,8 @@ pub enum Contract {
     ManagementForSale(ManagementForSale),
     #[doc(hidden)]
     Employment(Employment),
+    #[doc(hidden)]
+    ShortTermRent(ShortTermRent),
 }
 
 impl Contract {
@@ -47,6 +56,33 @@ impl Contract {
             Self::ManagementForRent(c) => c.id,
             Self::ManagementForSale(c) => c.id,
             Self::Employment(c) => c.id,
+            Self::ShortTermRent(c) => c.id,
+        }
+    }
+
+    /// Returns ID of the [`Agency`] this [`Contract`] belongs to.
+    #[must_use]
+    pub fn agency_id(&self) -> agency::Id {
+        match self {
+            Self::Rent(c) => c.agency_id,
+            Self::Sale(c) => c.agency_id,
+            Self::ManagementForRent(c) => c.agency_id,
+            Self::ManagementForSale(c) => c.agency_id,
+            Self::Employment(c) => c.agency_id,
+            Self::ShortTermRent(c) => c.agency_id,
+        }
+    }
+
+    /// Returns [`Number`] of this [`Contract`].
+    #[must_use]
+    pub fn number(&self) -> &Number {
+        match self {
+            Self::Rent(c) => &c.number,
+            Self::Sale(c) => &c.number,
+            Self::ManagementForRent(c) => &c.number,
+            Self::ManagementForSale(c) => &c.number,
+            Self::Employment(c) => &c.number,
+            Self::ShortTermRent(c) => &c.number,
         }
     }
 
@@ -59,6 +95,7 @@ impl Contract {
             Self::ManagementForRent(_) => Kind::ManagementForRent,
             Self::ManagementForSale(_) => Kind::ManagementForSale,
             Self::Employment(_) => Kind::Employment,
+            Self::ShortTermRent(_) => Kind::ShortTermRent,
         }
     }
 
@@ -71,6 +108,10 @@ impl Contract {
             return S::Terminated;
         }
 
+        if self.completed_at().is_some() {
+            return S::Completed;
+        }
+
         if let Some(at) = self.expires_at() {
             let now = DateTime::now().coerce();
             if now > at {
@@ -90,6 +131,7 @@ impl Contract {
             Self::ManagementForRent(c) => &c.name,
             Self::ManagementForSale(c) => &c.name,
             Self::Employment(c) => &c.name,
+            Self::ShortTermRent(c) => &c.name,
         }
     }
 
@@ -102,6 +144,33 @@ impl Contract {
             Self::ManagementForRent(c) => &c.description,
             Self::ManagementForSale(c) => &c.description,
             Self::Employment(c) => &c.description,
+            Self::ShortTermRent(c) => &c.description,
+        }
+    }
+
+    /// Returns mutable [`Name`] of this [`Contract`].
+    #[must_use]
+    pub fn name_mut(&mut self) -> &mut Name {
+        match self {
+            Self::Rent(c) => &mut c.name,
+            Self::Sale(c) => &mut c.name,
+            Self::ManagementForRent(c) => &mut c.name,
+            Self::ManagementForSale(c) => &mut c.name,
+            Self::Employment(c) => &mut c.name,
+            Self::ShortTermRent(c) => &mut c.name,
+        }
+    }
+
+    /// Returns mutable [`Description`] of this [`Contract`].
+    #[must_use]
+    pub fn description_mut(&mut self) -> &mut Description {
+        match self {
+            Self::Rent(c) => &mut c.description,
+            Self::Sale(c) => &mut c.description,
+            Self::ManagementForRent(c) => &mut c.description,
+            Self::ManagementForSale(c) => &mut c.description,
+            Self::Employment(c) => &mut c.description,
+            Self::ShortTermRent(c) => &mut c.description,
         }
     }
 
@@ -116,6 +185,7 @@ impl Contract {
             Self::Sale(c) => Some(c.realty_id),
             Self::ManagementForRent(c) => Some(c.realty_id),
             Self::ManagementForSale(c) => Some(c.realty_id),
+            Self::ShortTermRent(c) => Some(c.realty_id),
             Self::Employment(_) => None,
         }
     }
@@ -129,7 +199,10 @@ impl Contract {
         match self {
             Self::ManagementForRent(c) => Some(c.is_placed),
             Self::ManagementForSale(c) => Some(c.is_placed),
-            Self::Employment(_) | Self::Rent(_) | Self::Sale(_) => None,
+            Self::Employment(_)
+            | Self::Rent(_)
+            | Self::Sale(_)
+            | Self::ShortTermRent(_) => None,
         }
     }
 
@@ -142,7 +215,10 @@ impl Contract {
         match self {
             Self::ManagementForRent(c) => Some(&mut c.is_placed),
             Self::ManagementForSale(c) => Some(&mut c.is_placed),
-            Self::Employment(_) | Self::Rent(_) | Self::Sale(_) => None,
+            Self::Employment(_)
+            | Self::Rent(_)
+            | Self::Sale(_)
+            | Self::ShortTermRent(_) => None,
         }
     }
 
@@ -155,6 +231,7 @@ impl Contract {
             Self::ManagementForRent(c) => c.created_at,
             Self::ManagementForSale(c) => c.created_at,
             Self::Employment(c) => c.created_at,
+            Self::ShortTermRent(c) => c.created_at,
         }
     }
 
@@ -169,6 +246,35 @@ impl Contract {
             Self::ManagementForRent(c) => c.expires_at,
             Self::ManagementForSale(c) => c.expires_at,
             Self::Employment(c) => c.expires_at,
+            Self::ShortTermRent(c) => Some(c.expires_at),
+        }
+    }
+
+    /// Returns [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    #[must_use]
+    pub fn completed_at(&self) -> Option<CompletionDateTime> {
+        match self {
+            Self::Rent(c) => c.completed_at,
+            Self::Sale(c) => c.completed_at,
+            Self::ManagementForRent(c) => c.completed_at,
+            Self::ManagementForSale(c) => c.completed_at,
+            Self::Employment(c) => c.completed_at,
+            Self::ShortTermRent(c) => c.completed_at,
+        }
+    }
+
+    /// Returns [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    #[must_use]
+    pub fn completed_at_mut(&mut self) -> &mut Option<CompletionDateTime> {
+        match self {
+            Self::Rent(c) => &mut c.completed_at,
+            Self::Sale(c) => &mut c.completed_at,
+            Self::ManagementForRent(c) => &mut c.completed_at,
+            Self::ManagementForSale(c) => &mut c.completed_at,
+            Self::Employment(c) => &mut c.completed_at,
+            Self::ShortTermRent(c) => &mut c.completed_at,
         }
     }
 
@@ -181,6 +287,7 @@ impl Contract {
             Self::ManagementForRent(c) => c.terminated_at,
             Self::ManagementForSale(c) => c.terminated_at,
             Self::Employment(c) => c.terminated_at,
+            Self::ShortTermRent(c) => c.terminated_at,
         }
     }
 
@@ -193,6 +300,46 @@ impl Contract {
             Self::ManagementForRent(c) => &mut c.terminated_at,
             Self::ManagementForSale(c) => &mut c.terminated_at,
             Self::Employment(c) => &mut c.terminated_at,
+            Self::ShortTermRent(c) => &mut c.terminated_at,
+        }
+    }
+
+    /// Returns [`DateTime`] when this [`Contract`] was archived, if it was.
+    #[must_use]
+    pub fn archived_at(&self) -> Option<ArchivationDateTime> {
+        match self {
+            Self::Rent(c) => c.archived_at,
+            Self::Sale(c) => c.archived_at,
+            Self::ManagementForRent(c) => c.archived_at,
+            Self::ManagementForSale(c) => c.archived_at,
+            Self::Employment(c) => c.archived_at,
+            Self::ShortTermRent(c) => c.archived_at,
+        }
+    }
+
+    /// Returns [`DateTime`] when this [`Contract`] was archived, if it was.
+    #[must_use]
+    pub fn archived_at_mut(&mut self) -> &mut Option<ArchivationDateTime> {
+        match self {
+            Self::Rent(c) => &mut c.archived_at,
+            Self::Sale(c) => &mut c.archived_at,
+            Self::ManagementForRent(c) => &mut c.archived_at,
+            Self::ManagementForSale(c) => &mut c.archived_at,
+            Self::Employment(c) => &mut c.archived_at,
+            Self::ShortTermRent(c) => &mut c.archived_at,
+        }
+    }
+
+    /// Returns [`Version`] of this [`Contract`].
+    #[must_use]
+    pub fn version(&self) -> Version {
+        match self {
+            Self::Rent(c) => c.version,
+            Self::Sale(c) => c.version,
+            Self::ManagementForRent(c) => c.version,
+            Self::ManagementForSale(c) => c.version,
+            Self::Employment(c) => c.version,
+            Self::ShortTermRent(c) => c.version,
         }
     }
 
@@ -205,6 +352,7 @@ impl Contract {
             Self::ManagementForRent(c) => c.is_active(),
             Self::ManagementForSale(c) => c.is_active(),
             Self::Employment(c) => c.is_active(),
+            Self::ShortTermRent(c) => c.is_active(),
         }
     }
 }
@@ -237,7 +385,13 @@ impl Id {
 }
 
 /// Name of a [`Contract`].
-#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize,
+)]
 #[as_ref(str, String)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Name(String);
@@ -277,7 +431,11 @@ impl FromStr for Name {
 }
 
 /// Description of a [`Contract`].
-#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Description(String);
 
@@ -317,6 +475,65 @@ impl FromStr for Description {
     }
 }
 
+/// Human-readable number of a [`Contract`], unique within its [`Agency`].
+///
+/// Consists of a [`Kind`] prefix, a year of the [`Contract`] creation and a
+/// sequential number within them (`RENT-2025-00042`, for example).
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize,
+)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Number(String);
+
+impl Number {
+    /// Creates a new [`Number`] of the provided `seq`uential number of a
+    /// [`Contract`] of the provided [`Kind`] created in the provided `year`.
+    #[must_use]
+    pub fn new(kind: Kind, year: i32, seq: u32) -> Self {
+        Self(format!("{}-{year:04}-{seq:05}", Self::prefix(kind)))
+    }
+
+    /// Returns the prefix of the [`Number`]s of the provided [`Kind`].
+    #[must_use]
+    pub const fn prefix(kind: Kind) -> &'static str {
+        match kind {
+            Kind::Rent => "RENT",
+            Kind::Sale => "SALE",
+            Kind::ManagementForRent => "MGMR",
+            Kind::ManagementForSale => "MGMS",
+            Kind::Employment => "EMPL",
+            Kind::ShortTermRent => "STRN",
+        }
+    }
+
+    /// Checks whether the given `number` is a well-formed [`Number`].
+    fn check(number: impl AsRef<str>) -> bool {
+        let mut parts = number.as_ref().split('-');
+        let (Some(prefix), Some(year), Some(seq), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return false;
+        };
+        prefix.len() == 4
+            && prefix.bytes().all(|b| b.is_ascii_uppercase())
+            && year.len() == 4
+            && year.bytes().all(|b| b.is_ascii_digit())
+            && (5..=10).contains(&seq.len())
+            && seq.bytes().all(|b| b.is_ascii_digit())
+    }
+}
+
+impl FromStr for Number {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::check(s)
+            .then(|| Self(s.to_owned()))
+            .ok_or("invalid `Number`")
+    }
+}
+
 define_kind! {
     #[doc = "Kind of a [`Contract`]."]
     enum Kind {
@@ -334,6 +551,9 @@ define_kind! {
 
         #[doc = "[`Employment`] [`Contract`]."]
         Employment = 5,
+
+        #[doc = "[`ShortTermRent`] [`Contract`]."]
+        ShortTermRent = 6,
     }
 }
 
@@ -361,5 +581,19 @@ pub struct Expiration;
 /// [`DateTime`] when a [`Contract`] was expired.
 pub type ExpirationDateTime = DateTimeOf<(Contract, Expiration)>;
 
+/// Marker type indicating [`Contract`] completion.
+#[derive(Clone, Copy, Debug)]
+pub struct Completion;
+
+/// [`DateTime`] when a [`Contract`] was completed.
+pub type CompletionDateTime = DateTimeOf<(Contract, Completion)>;
+
 /// [`DateTime`] when a [`Contract`] was terminated.
 pub type TerminationDateTime = DateTimeOf<(Contract, unit::Deletion)>;
+
+/// Marker type indicating [`Contract`] archivation.
+#[derive(Clone, Copy, Debug)]
+pub struct Archivation;
+
+/// [`DateTime`] when a [`Contract`] was archived.
+pub type ArchivationDateTime = DateTimeOf<(Contract, Archivation)>;
@@ -0,0 +1,95 @@
+//! [`Party`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, DateTimeOf};
+
+use crate::domain::{contract, user, Contract};
+#[cfg(doc)]
+use crate::domain::{Realty, User};
+
+/// [`User`] being a signing party of a [`Contract`] in some [`Role`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Party {
+    /// ID of the [`Contract`] this [`Party`] signs.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`User`] being this [`Party`].
+    pub user_id: user::Id,
+
+    /// [`Role`] of this [`Party`] in the [`Contract`].
+    pub role: Role,
+
+    /// [`DateTime`] when this [`Party`] signed the [`Contract`], if it did.
+    pub signed_at: Option<SignatureDateTime>,
+}
+
+impl Party {
+    /// Returns the primary unsigned [`Party`]s of the provided [`Contract`],
+    /// referenced by its own fields.
+    #[must_use]
+    pub fn primary(contract: &Contract) -> Vec<Self> {
+        let id = contract.id();
+        let party = |user_id, role| Self {
+            contract_id: id,
+            user_id,
+            role,
+            signed_at: None,
+        };
+        match contract {
+            Contract::Rent(c) => vec![
+                party(c.landlord_id, Role::Landlord),
+                party(c.purchaser_id, Role::Purchaser),
+                party(c.employer_id, Role::Employer),
+            ],
+            Contract::Sale(c) => vec![
+                party(c.landlord_id, Role::Landlord),
+                party(c.purchaser_id, Role::Purchaser),
+                party(c.employer_id, Role::Employer),
+            ],
+            Contract::ShortTermRent(c) => vec![
+                party(c.landlord_id, Role::Landlord),
+                party(c.purchaser_id, Role::Purchaser),
+                party(c.employer_id, Role::Employer),
+            ],
+            Contract::ManagementForRent(c) => vec![
+                party(c.landlord_id, Role::Landlord),
+                party(c.employer_id, Role::Employer),
+            ],
+            Contract::ManagementForSale(c) => vec![
+                party(c.landlord_id, Role::Landlord),
+                party(c.employer_id, Role::Employer),
+            ],
+            Contract::Employment(c) => {
+                vec![party(c.employer_id, Role::Employer)]
+            }
+        }
+    }
+
+    /// Indicates whether this [`Party`] has signed the [`Contract`].
+    #[must_use]
+    pub fn is_signed(&self) -> bool {
+        self.signed_at.is_some()
+    }
+}
+
+define_kind! {
+    #[doc = "Role of a [`Party`] in a [`Contract`]."]
+    enum Role {
+        #[doc = "[`User`] owning the [`Realty`]."]
+        Landlord = 1,
+
+        #[doc = "[`User`] renting or buying the [`Realty`]."]
+        Purchaser = 2,
+
+        #[doc = "Employer [`User`] managing the [`Contract`]."]
+        Employer = 3,
+    }
+}
+
+/// Marker type indicating a signature of a [`Contract`].
+#[derive(Clone, Copy, Debug)]
+pub struct Signature;
+
+/// [`DateTime`] when a [`Party`] signed a [`Contract`].
+pub type SignatureDateTime = DateTimeOf<(Contract, Signature)>;
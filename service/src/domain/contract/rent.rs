@@ -1,14 +1,14 @@
 //! [`Rent`] [`Contract`] definition.
 
-use common::{DateTime, Money};
+use common::{DateTime, DateTimeOf, Money};
 
-use crate::domain::{realty, user};
+use crate::domain::{agency, realty, user, Version};
 #[cfg(doc)]
-use crate::domain::{Contract, Realty, User};
+use crate::domain::{Agency, Contract, Realty, User};
 
 use super::{
-    CreationDateTime, Description, ExpirationDateTime, Id, Name,
-    TerminationDateTime,
+    ArchivationDateTime, CompletionDateTime, CreationDateTime, Description,
+    ExpirationDateTime, Id, Name, Number, TerminationDateTime,
 };
 
 /// [`Contract`] allowing [`User`] to rent a [`Realty`].
@@ -17,6 +17,12 @@ pub struct Rent {
     /// ID of this [`Contract`].
     pub id: Id,
 
+    /// ID of the [`Agency`] this [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Number`] of this [`Contract`].
+    pub number: Number,
+
     /// [`Name`] of this [`Contract`].
     pub name: Name,
 
@@ -49,11 +55,57 @@ pub struct Rent {
     /// [`None`] means that this [`Contract`] is valid indefinitely.
     pub expires_at: Option<ExpirationDateTime>,
 
+    /// [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    pub completed_at: Option<CompletionDateTime>,
+
     /// [`DateTime`] when this [`Contract`] was terminated, if it was.
     pub terminated_at: Option<TerminationDateTime>,
+
+    /// [`DateTime`] when this [`Contract`] was archived, if it was.
+    ///
+    /// Archived [`Contract`]s are hidden from the working lists, but still
+    /// preserved.
+    pub archived_at: Option<ArchivationDateTime>,
+
+    /// [`Version`] of this [`Contract`].
+    pub version: Version,
 }
 
 impl Rent {
+    /// Returns [`DateTime`] when the rent installment with the provided
+    /// zero-based `number` is due.
+    ///
+    /// Installments of the [`Rent::price`] are due monthly, starting from
+    /// the [`Rent::created_at`]. [`None`] is returned if the installment
+    /// falls after this [`Contract`] is ended (expired, completed or
+    /// terminated).
+    #[must_use]
+    pub fn installment_due_at(&self, number: u32) -> Option<DateTime> {
+        let due_at =
+            self.created_at.coerce::<()>().checked_add_months(number)?;
+        let ended_at = [
+            self.expires_at.map(DateTimeOf::coerce),
+            self.completed_at.map(DateTimeOf::coerce),
+            self.terminated_at.map(DateTimeOf::coerce),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+        ended_at.is_none_or(|end| due_at < end).then_some(due_at)
+    }
+
+    /// Returns number of the rent installments due by the provided [`DateTime`]
+    /// (inclusive).
+    #[must_use]
+    pub fn installments_due_by(&self, at: DateTime) -> u32 {
+        let mut due = 0;
+        while self.installment_due_at(due).is_some_and(|d| d <= at) {
+            due += 1;
+        }
+        due
+    }
+
     /// Returns whether this [`Contract`] is active.
     #[must_use]
     pub fn is_active(&self) -> bool {
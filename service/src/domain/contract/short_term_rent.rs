@@ -0,0 +1,258 @@
+//! [`ShortTermRent`] [`Contract`] definition.
+
+use std::time::Duration;
+
+use common::{DateTime, DateTimeOf, Money};
+use rust_decimal::Decimal;
+
+use crate::domain::{agency, realty, user, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, Realty, User};
+
+use super::{
+    ArchivationDateTime, CompletionDateTime, CreationDateTime, Description,
+    ExpirationDateTime, Id, Name, Number, TerminationDateTime,
+};
+
+/// Duration of a single night of a [`ShortTermRent`].
+pub const NIGHT: Duration = Duration::from_hours(24);
+
+/// [`Contract`] booking a [`Realty`] for a [`User`] for a few nights.
+#[derive(Clone, Debug)]
+pub struct ShortTermRent {
+    /// ID of this [`Contract`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Number`] of this [`Contract`].
+    pub number: Number,
+
+    /// [`Name`] of this [`Contract`].
+    pub name: Name,
+
+    /// [`Description`] of this [`Contract`].
+    pub description: Description,
+
+    /// ID of the [`Realty`] this [`Contract`] books.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`User`] who books the [`Realty`].
+    pub purchaser_id: user::Id,
+
+    /// ID of the [`User`] who owns the [`Realty`].
+    pub landlord_id: user::Id,
+
+    /// ID of the [`User`] who manages the [`Realty`].
+    pub employer_id: user::Id,
+
+    /// Base nightly rate, applied to the nights not covered by any
+    /// [`SeasonalRate`].
+    pub nightly_rate: Money,
+
+    /// Total price of the booked nights.
+    pub price: Money,
+
+    /// Deposit paid by the purchaser at the moment of booking, if any.
+    pub deposit: Option<Money>,
+
+    /// [`DateTime`] when the purchaser checks in.
+    pub check_in_at: CheckInDateTime,
+
+    /// [`DateTime`] when this [`Contract`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`DateTime`] when the purchaser checks out, so this [`Contract`]
+    /// expires.
+    pub expires_at: ExpirationDateTime,
+
+    /// [`DateTime`] when this [`Contract`] was completed due to its
+    /// expiration, if it was.
+    pub completed_at: Option<CompletionDateTime>,
+
+    /// [`DateTime`] when this [`Contract`] was terminated, if it was.
+    pub terminated_at: Option<TerminationDateTime>,
+
+    /// [`DateTime`] when this [`Contract`] was archived, if it was.
+    ///
+    /// Archived [`Contract`]s are hidden from the working lists, but still
+    /// preserved.
+    pub archived_at: Option<ArchivationDateTime>,
+
+    /// [`Version`] of this [`Contract`].
+    pub version: Version,
+}
+
+impl ShortTermRent {
+    /// Returns number of the whole nights between the provided check-in and
+    /// check-out [`DateTime`]s.
+    #[must_use]
+    pub fn nights(check_in_at: DateTime, check_out_at: DateTime) -> u32 {
+        if check_out_at <= check_in_at {
+            return 0;
+        }
+        let nights = (check_out_at - check_in_at).as_secs() / NIGHT.as_secs();
+        u32::try_from(nights).unwrap_or(u32::MAX)
+    }
+
+    /// Quotes the total price of the nights between the provided check-in and
+    /// check-out [`DateTime`]s.
+    ///
+    /// Every night is priced by the [`SeasonalRate`] covering its start, or by
+    /// the base `nightly_rate` otherwise.
+    ///
+    /// [`None`] is returned if no whole night is booked, or the rates are in
+    /// different currencies.
+    #[must_use]
+    pub fn quote(
+        check_in_at: DateTime,
+        check_out_at: DateTime,
+        nightly_rate: Money,
+        seasonal_rates: &[SeasonalRate],
+    ) -> Option<Money> {
+        let nights = Self::nights(check_in_at, check_out_at);
+        if nights == 0 {
+            return None;
+        }
+        let mut total = Decimal::ZERO;
+        let mut night = check_in_at;
+        for _ in 0..nights {
+            let rate = seasonal_rates
+                .iter()
+                .find(|r| r.covers(night))
+                .map_or(nightly_rate, |r| r.nightly_rate);
+            if rate.currency != nightly_rate.currency {
+                return None;
+            }
+            total += rate.amount;
+            night = night + NIGHT;
+        }
+        Some(Money {
+            amount: total,
+            currency: nightly_rate.currency,
+        })
+    }
+
+    /// Returns whether this [`Contract`] is active.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.terminated_at.is_none()
+            && DateTime::now() < self.expires_at.coerce()
+    }
+}
+
+/// Marker type indicating [`ShortTermRent`] check-in.
+#[derive(Clone, Copy, Debug)]
+pub struct CheckIn;
+
+/// [`DateTime`] when a purchaser checks in by a [`ShortTermRent`].
+pub type CheckInDateTime = DateTimeOf<(ShortTermRent, CheckIn)>;
+
+/// Nightly rate of a [`Realty`] [`ShortTermRent`] within a season.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SeasonalRate {
+    /// ID of the [`Realty`] this [`SeasonalRate`] is applied to.
+    pub realty_id: realty::Id,
+
+    /// [`DateTime`] when the season starts (inclusive).
+    pub from: DateTime,
+
+    /// [`DateTime`] when the season ends (exclusive).
+    pub until: DateTime,
+
+    /// Nightly rate within the season.
+    pub nightly_rate: Money,
+}
+
+impl SeasonalRate {
+    /// Indicates whether this [`SeasonalRate`] covers the night starting at
+    /// the provided [`DateTime`].
+    #[must_use]
+    pub fn covers(&self, at: DateTime) -> bool {
+        self.from <= at && at < self.until
+    }
+
+    /// Indicates whether the provided [`SeasonalRate`]s are valid to be
+    /// applied together.
+    ///
+    /// Every season must be non-empty with a positive nightly rate, and the
+    /// seasons must not overlap each other.
+    #[must_use]
+    pub fn are_valid(rates: &[Self]) -> bool {
+        let mut rates = rates.to_vec();
+        rates.sort_by_key(|r| r.from);
+        rates
+            .iter()
+            .all(|r| r.from < r.until && r.nightly_rate.amount > Decimal::ZERO)
+            && rates.windows(2).all(|w| w[0].until <= w[1].from)
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, DateTime, Money};
+    use rust_decimal::Decimal;
+
+    use crate::domain::realty;
+
+    use super::{SeasonalRate, ShortTermRent, NIGHT};
+
+    fn usd(amount: i64) -> Money {
+        Money {
+            amount: Decimal::from(amount),
+            currency: Currency::Usd,
+        }
+    }
+
+    #[test]
+    fn quotes_nights_by_seasonal_rates() {
+        let check_in = DateTime::UNIX_EPOCH;
+        let season = SeasonalRate {
+            realty_id: realty::Id::new(),
+            from: check_in + NIGHT * 2,
+            until: check_in + NIGHT * 4,
+            nightly_rate: usd(150),
+        };
+
+        assert_eq!(
+            ShortTermRent::quote(
+                check_in,
+                check_in + NIGHT * 5,
+                usd(100),
+                &[season]
+            ),
+            Some(usd(600)),
+        );
+        assert_eq!(
+            ShortTermRent::quote(
+                check_in,
+                check_in + NIGHT * 2,
+                usd(100),
+                &[season]
+            ),
+            Some(usd(200)),
+        );
+        assert_eq!(
+            ShortTermRent::quote(check_in, check_in + NIGHT / 2, usd(100), &[]),
+            None,
+        );
+    }
+
+    #[test]
+    fn rejects_overlapping_seasons() {
+        let start = DateTime::UNIX_EPOCH;
+        let season = |from: u32, until: u32| SeasonalRate {
+            realty_id: realty::Id::new(),
+            from: start + NIGHT * from,
+            until: start + NIGHT * until,
+            nightly_rate: usd(150),
+        };
+
+        assert!(SeasonalRate::are_valid(&[season(0, 2), season(2, 4)]));
+        assert!(SeasonalRate::are_valid(&[]));
+
+        assert!(!SeasonalRate::are_valid(&[season(0, 3), season(2, 4)]));
+        assert!(!SeasonalRate::are_valid(&[season(2, 2)]));
+    }
+}
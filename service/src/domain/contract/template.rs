@@ -0,0 +1,307 @@
+//! [`Template`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{money::Locale, unit, DateTimeOf, Money};
+use derive_more::{Display, Error, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user, Realty, User};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract};
+
+/// Template of a [`Contract`] name and description, containing
+/// [`Placeholder`]s to be substituted with the [`Contract`] details.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Template {
+    /// ID of this [`Template`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Template`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Text`] of the [`contract::Name`] rendered by this [`Template`].
+    pub name: Text,
+
+    /// [`Text`] of the [`contract::Description`] rendered by this
+    /// [`Template`].
+    pub description: Text,
+
+    /// ID of the employer [`User`] who saved this [`Template`] last.
+    pub creator_id: user::Id,
+
+    /// [`DateTime`] when this [`Template`] was created.
+    pub created_at: CreationDateTime,
+}
+
+impl Template {
+    /// Renders a [`contract::Name`] of this [`Template`] with the provided
+    /// [`Fields`].
+    ///
+    /// [`None`] is returned if the rendered [`contract::Name`] is invalid (too
+    /// long, for example).
+    #[must_use]
+    pub fn render_name(&self, fields: &Fields<'_>) -> Option<contract::Name> {
+        contract::Name::new(self.name.render(fields))
+    }
+
+    /// Renders a [`contract::Description`] of this [`Template`] with the
+    /// provided [`Fields`].
+    ///
+    /// [`None`] is returned if the rendered [`contract::Description`] is
+    /// invalid (too long, for example).
+    #[must_use]
+    pub fn render_description(
+        &self,
+        fields: &Fields<'_>,
+    ) -> Option<contract::Description> {
+        contract::Description::new(self.description.render(fields))
+    }
+}
+
+/// ID of a [`Template`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Text of a [`Template`] with `{placeholder}`s.
+///
+/// Literal braces are escaped by doubling them: `{{` and `}}`.
+#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Text(String);
+
+impl Text {
+    /// Parses the provided `text` into a [`Text`], validating its
+    /// [`Placeholder`]s.
+    ///
+    /// # Errors
+    ///
+    /// If the provided `text` is empty, too long, has unbalanced braces or
+    /// refers an unknown [`Placeholder`].
+    pub fn parse(text: impl Into<String>) -> Result<Self, ParseError> {
+        let text = text.into();
+        if text.trim() != text || text.is_empty() || text.len() > 512 {
+            return Err(ParseError::Invalid);
+        }
+        walk(&text, |_| {})?;
+        Ok(Self(text))
+    }
+
+    /// Renders this [`Text`], substituting its [`Placeholder`]s with the
+    /// provided [`Fields`].
+    #[expect(clippy::missing_panics_doc, reason = "infallible")]
+    #[must_use]
+    pub fn render(&self, fields: &Fields<'_>) -> String {
+        let mut out = String::with_capacity(self.0.len());
+        walk(&self.0, |s| match s {
+            Segment::Literal(l) => out.push_str(l),
+            Segment::Placeholder(p) => out.push_str(&fields.value(p)),
+        })
+        .expect("`Text` is validated on creation");
+        out
+    }
+}
+
+impl FromStr for Text {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Error of parsing a [`Text`].
+#[derive(Clone, Debug, Display, Eq, Error, PartialEq)]
+pub enum ParseError {
+    /// [`Text`] is empty, too long or has leading/trailing whitespaces.
+    #[display("`Text` is empty or too long")]
+    Invalid,
+
+    /// [`Text`] has an unknown [`Placeholder`].
+    #[display("unknown placeholder `{{{_0}}}`")]
+    UnknownPlaceholder(#[error(not(source))] String),
+
+    /// [`Text`] has an unclosed or unopened brace.
+    #[display("unbalanced braces")]
+    Unbalanced,
+}
+
+/// Placeholder of a [`Text`], substituted with a [`Contract`] detail.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Placeholder {
+    /// Address of the [`Realty`].
+    #[display("realty.address")]
+    RealtyAddress,
+
+    /// City of the [`Realty`].
+    #[display("realty.city")]
+    RealtyCity,
+
+    /// Name of the landlord [`User`].
+    #[display("landlord.name")]
+    LandlordName,
+
+    /// Name of the purchaser [`User`].
+    #[display("purchaser.name")]
+    PurchaserName,
+
+    /// Name of the employer [`User`].
+    #[display("employer.name")]
+    EmployerName,
+
+    /// Price of the [`Contract`].
+    #[display("price")]
+    Price,
+}
+
+impl Placeholder {
+    /// All the existing [`Placeholder`]s.
+    pub const ALL: [Self; 6] = [
+        Self::RealtyAddress,
+        Self::RealtyCity,
+        Self::LandlordName,
+        Self::PurchaserName,
+        Self::EmployerName,
+        Self::Price,
+    ];
+}
+
+impl FromStr for Placeholder {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.to_string() == s)
+            .ok_or("unknown `Placeholder`")
+    }
+}
+
+/// [`Contract`] details substituting [`Placeholder`]s of a [`Text`].
+#[derive(Clone, Copy, Debug)]
+pub struct Fields<'a> {
+    /// [`Realty`] the [`Contract`] is concluded upon.
+    pub realty: &'a Realty,
+
+    /// Landlord [`User`] of the [`Realty`].
+    pub landlord: &'a User,
+
+    /// Purchaser [`User`] of the [`Contract`].
+    pub purchaser: &'a User,
+
+    /// Employer [`User`] managing the [`Contract`].
+    pub employer: &'a User,
+
+    /// Price of the [`Contract`].
+    pub price: Money,
+}
+
+impl Fields<'_> {
+    /// Returns the value substituting the provided [`Placeholder`].
+    fn value(&self, placeholder: Placeholder) -> String {
+        match placeholder {
+            Placeholder::RealtyAddress => self.realty.address.to_string(),
+            Placeholder::RealtyCity => self.realty.city.to_string(),
+            Placeholder::LandlordName => self.landlord.name.to_string(),
+            Placeholder::PurchaserName => self.purchaser.name.to_string(),
+            Placeholder::EmployerName => self.employer.name.to_string(),
+            Placeholder::Price => self.price.format(Locale::En),
+        }
+    }
+}
+
+/// Segment of a [`Text`].
+enum Segment<'a> {
+    /// Literal text.
+    Literal(&'a str),
+
+    /// [`Placeholder`] to be substituted.
+    Placeholder(Placeholder),
+}
+
+/// Walks over [`Segment`]s of the provided `text`.
+fn walk<'a>(
+    text: &'a str,
+    mut f: impl FnMut(Segment<'a>),
+) -> Result<(), ParseError> {
+    let mut rest = text;
+    while let Some(i) = rest.find(['{', '}']) {
+        f(Segment::Literal(&rest[..i]));
+        let tail = &rest[i..];
+        if let Some(r) = tail.strip_prefix("{{") {
+            f(Segment::Literal("{"));
+            rest = r;
+        } else if let Some(r) = tail.strip_prefix("}}") {
+            f(Segment::Literal("}"));
+            rest = r;
+        } else if tail.starts_with('}') {
+            return Err(ParseError::Unbalanced);
+        } else {
+            let end = tail.find('}').ok_or(ParseError::Unbalanced)?;
+            let name = &tail[1..end];
+            let placeholder = name
+                .parse()
+                .map_err(|_| ParseError::UnknownPlaceholder(name.to_owned()))?;
+            f(Segment::Placeholder(placeholder));
+            rest = &tail[end + 1..];
+        }
+    }
+    f(Segment::Literal(rest));
+    Ok(())
+}
+
+/// [`DateTime`] when a [`Template`] was created.
+pub type CreationDateTime = DateTimeOf<(Template, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use super::{ParseError, Placeholder, Text};
+
+    #[test]
+    fn parses_placeholders() {
+        assert!(
+            Text::parse("Rent of {realty.address} to {purchaser.name}").is_ok()
+        );
+        assert!(Text::parse("{{literal}} for {price}").is_ok());
+        for p in Placeholder::ALL {
+            assert_eq!(p.to_string().parse::<Placeholder>(), Ok(p));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_text() {
+        assert_eq!(
+            Text::parse("Rent of {realty.owner}"),
+            Err(ParseError::UnknownPlaceholder("realty.owner".into())),
+        );
+        assert_eq!(Text::parse("Rent of {price"), Err(ParseError::Unbalanced));
+        assert_eq!(Text::parse("Rent of price}"), Err(ParseError::Unbalanced));
+        assert_eq!(Text::parse(""), Err(ParseError::Invalid));
+    }
+}
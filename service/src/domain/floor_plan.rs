@@ -0,0 +1,178 @@
+//! [`FloorPlan`] definitions.
+
+use std::collections::HashSet;
+
+use common::define_kind;
+use derive_more::{AsRef, Display, FromStr};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+
+use crate::domain::realty;
+#[cfg(doc)]
+use crate::domain::Realty;
+
+/// Floor plan of a [`Realty`], kept apart from its ordinary photos.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FloorPlan {
+    /// ID of the [`Realty`] this [`FloorPlan`] belongs to.
+    pub realty_id: realty::Id,
+
+    /// [`realty::Floor`] this [`FloorPlan`] depicts.
+    pub floor: realty::Floor,
+
+    /// [`Label`] of this [`FloorPlan`].
+    pub label: Label,
+
+    /// [`Format`] of this [`FloorPlan`] file.
+    pub format: Format,
+
+    /// [`Url`] of this [`FloorPlan`] file.
+    pub url: Url,
+}
+
+impl FloorPlan {
+    /// Indicates whether the provided [`FloorPlan`]s may be set on the same
+    /// [`Realty`].
+    ///
+    /// Every [`Label`] must be used only once per [`realty::Floor`].
+    #[must_use]
+    pub fn are_valid(plans: &[Self]) -> bool {
+        let mut labels = HashSet::with_capacity(plans.len());
+        plans.iter().all(|p| labels.insert((p.floor, &p.label)))
+    }
+}
+
+define_kind! {
+    #[doc = "Format of a [`FloorPlan`] file."]
+    enum Format {
+        #[doc = "Raster or vector image."]
+        Image = 1,
+
+        #[doc = "PDF document."]
+        Pdf = 2,
+    }
+}
+
+/// Label of a [`FloorPlan`] (e.g. "Ground floor" or "Mezzanine").
+#[derive(
+    AsRef, Clone, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd,
+)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Label(String);
+
+impl Label {
+    /// Creates a new [`Label`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `label` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(label: impl Into<String>) -> Self {
+        Self(label.into())
+    }
+
+    /// Creates a new [`Label`] if the given `label` is valid.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Option<Self> {
+        let label = label.into();
+        Self::check(&label).then_some(Self(label))
+    }
+
+    /// Checks whether the given `label` is a valid [`Label`].
+    fn check(label: impl AsRef<str>) -> bool {
+        let label = label.as_ref();
+        label.trim() == label && !label.is_empty() && label.len() <= 256
+    }
+}
+
+impl FromStr for Label {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Label`")
+    }
+}
+
+/// URL of a [`FloorPlan`] file.
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Url(String);
+
+impl Url {
+    /// Creates a new [`Url`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `url` is a valid HTTP(S) URL.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+
+    /// Creates a new [`Url`] if the given `url` is valid.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Option<Self> {
+        let url = url.into();
+        Self::check(&url).then_some(Self(url))
+    }
+
+    /// Checks whether the given `url` is a valid [`Url`].
+    fn check(url: impl AsRef<str>) -> bool {
+        let url = url.as_ref();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"));
+        host.is_some_and(|h| !h.is_empty())
+            && !url.contains(char::is_whitespace)
+            && url.len() <= 2048
+    }
+}
+
+impl FromStr for Url {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Url`")
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::domain::realty;
+
+    use super::{FloorPlan, Format, Label, Url};
+
+    fn plan(floor: realty::Floor, label: &str) -> FloorPlan {
+        FloorPlan {
+            realty_id: realty::Id::default(),
+            floor,
+            label: Label::new(label).unwrap(),
+            format: Format::Image,
+            url: Url::new("https://cdn.example.com/plan.png").unwrap(),
+        }
+    }
+
+    #[test]
+    fn rejects_duplicate_labels_per_floor() {
+        assert!(FloorPlan::are_valid(&[
+            plan(1, "Layout"),
+            plan(2, "Layout"),
+            plan(2, "Furnished"),
+        ]));
+        assert!(!FloorPlan::are_valid(&[
+            plan(1, "Layout"),
+            plan(1, "Layout"),
+        ]));
+    }
+
+    #[test]
+    fn validates_url() {
+        assert!(Url::new("https://cdn.example.com/plan.pdf").is_some());
+        assert!(Url::new("ftp://example.com/plan.pdf").is_none());
+        assert!(Url::new("https://").is_none());
+    }
+}
@@ -0,0 +1,207 @@
+//! iCalendar [`Feed`] definitions.
+
+use common::{unit, DateTime, DateTimeOf};
+use derive_more::{AsRef, Display};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use rand::Rng as _;
+use sha2::{Digest as _, Sha256};
+
+use crate::domain::{user, Contract};
+#[cfg(doc)]
+use crate::domain::User;
+
+/// Product identifier of the rendered iCalendar feeds.
+const PRODID: &str = "-//Real Estate Agency//Calendar//EN";
+
+/// Maximum length (in octets) of an iCalendar content line, excluding the
+/// line break.
+const MAX_LINE_LEN: usize = 75;
+
+/// Personal iCalendar feed of a [`User`], subscribed to from calendar apps by
+/// its secret [`Token`].
+#[derive(Clone, Debug)]
+pub struct Feed {
+    /// ID of the [`User`] this [`Feed`] belongs to.
+    pub user_id: user::Id,
+
+    /// [`TokenHash`] of the [`Token`] this [`Feed`] is accessed with.
+    pub token_hash: TokenHash,
+
+    /// [`DateTime`] when this [`Feed`] was created.
+    pub created_at: CreationDateTime,
+}
+
+/// Secret token of a [`Feed`], embedded into its URL.
+#[derive(AsRef, Clone, Debug, Display)]
+#[as_ref(str)]
+pub struct Token(String);
+
+impl Token {
+    /// Generates a new random [`Token`].
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(hex::encode(rand::thread_rng().gen::<[u8; 32]>()))
+    }
+
+    /// Creates a new [`Token`] if the given `token` is valid.
+    #[must_use]
+    pub fn new(token: impl Into<String>) -> Option<Self> {
+        let token = token.into();
+        let is_valid = token.len() == 64
+            && token.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+        is_valid.then_some(Self(token))
+    }
+
+    /// Computes the [`TokenHash`] of this [`Token`], so the [`Token`] itself
+    /// is never stored.
+    #[must_use]
+    pub fn hash(&self) -> TokenHash {
+        TokenHash(hex::encode(Sha256::digest(self.0.as_bytes())))
+    }
+}
+
+/// SHA-256 hash of a [`Token`].
+#[derive(AsRef, Clone, Debug, Eq, PartialEq)]
+#[as_ref(str)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct TokenHash(String);
+
+/// Event of a [`Feed`].
+#[derive(Clone, Debug)]
+pub struct Event {
+    /// Globally unique and stable ID of this [`Event`].
+    pub uid: String,
+
+    /// Summary (title) of this [`Event`].
+    pub summary: String,
+
+    /// [`DateTime`] when this [`Event`] happens.
+    pub at: DateTime,
+}
+
+impl Event {
+    /// Creates a new [`Event`] of the provided [`Contract`] expiration, if it
+    /// expires at all.
+    #[must_use]
+    pub fn contract_expiry(contract: &Contract) -> Option<Self> {
+        Some(Self {
+            uid: format!(
+                "contract-expiry-{}@real-estate-agency",
+                contract.id(),
+            ),
+            summary: format!(
+                "Contract {} expires: {}",
+                contract.number(),
+                contract.name(),
+            ),
+            at: contract.expires_at()?.coerce(),
+        })
+    }
+}
+
+/// Renders the provided [`Event`]s as an [RFC 5545] iCalendar, stamped with
+/// the provided [`DateTime`].
+///
+/// [RFC 5545]: https://tools.ietf.org/html/rfc5545
+#[must_use]
+pub fn to_ics(events: &[Event], now: DateTime) -> String {
+    let mut ics = String::new();
+    let mut line = |l: &str| fold(&mut ics, l);
+
+    line("BEGIN:VCALENDAR");
+    line("VERSION:2.0");
+    line(&format!("PRODID:{PRODID}"));
+    line("CALSCALE:GREGORIAN");
+    line("METHOD:PUBLISH");
+    for e in events {
+        line("BEGIN:VEVENT");
+        line(&format!("UID:{}", escape(&e.uid)));
+        line(&format!("DTSTAMP:{}", utc(now)));
+        line(&format!("DTSTART:{}", utc(e.at)));
+        line(&format!("SUMMARY:{}", escape(&e.summary)));
+        line("END:VEVENT");
+    }
+    line("END:VCALENDAR");
+    ics
+}
+
+/// Formats the provided [`DateTime`] as an iCalendar UTC date-time (like
+/// `20240305T100000Z`).
+fn utc(at: DateTime) -> String {
+    let at = DateTime::from_unix_timestamp(at.unix_timestamp()).unwrap_or(at);
+    let mut utc = at.to_rfc3339()[..19].replace(['-', ':'], "");
+    utc.push('Z');
+    utc
+}
+
+/// Escapes the provided iCalendar text value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Appends the provided content `line` to the `ics`, folding it into
+/// [`MAX_LINE_LEN`] octets long lines, without splitting UTF-8 characters.
+fn fold(ics: &mut String, line: &str) {
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LEN {
+            ics.push_str("\r\n ");
+            // Leading space of the continuation line counts too.
+            len = 1;
+        }
+        ics.push(c);
+        len += c.len_utf8();
+    }
+    ics.push_str("\r\n");
+}
+
+/// [`DateTime`] when a [`Feed`] was created.
+pub type CreationDateTime = DateTimeOf<(Feed, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+
+    use super::{to_ics, Event, Token};
+
+    #[test]
+    fn validates_token() {
+        let token = Token::generate();
+        assert!(Token::new(token.to_string()).is_some());
+        assert_eq!(token.hash(), token.hash());
+        assert!(Token::new("secret").is_none());
+        assert!(Token::new(token.to_string().to_uppercase()).is_none());
+    }
+
+    #[test]
+    fn renders_escaped_and_folded_lines() {
+        let at = DateTime::from_rfc3339("2024-03-05T12:00:00+02:00").unwrap();
+        let ics = to_ics(
+            &[Event {
+                uid: "contract-expiry-1@real-estate-agency".into(),
+                summary: format!("Rent; flat, {}", "x".repeat(80)),
+                at,
+            }],
+            at,
+        );
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART:20240305T100000Z\r\n"));
+        assert!(ics.contains("SUMMARY:Rent\\; flat\\, x"));
+        assert!(ics.split("\r\n").all(|l| l.len() <= 75));
+    }
+}
@@ -0,0 +1,241 @@
+//! [`Insurance`] definitions.
+
+use std::time::Duration;
+
+use common::{unit, DateTime, DateTimeOf, Money};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty, User};
+
+/// Insurance policy covering a [`Realty`] under a [`contract::Rent`],
+/// [`contract::ManagementForRent`] or [`contract::ManagementForSale`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Insurance {
+    /// ID of this [`Insurance`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Insurance`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the contract this [`Insurance`] is attached to.
+    pub contract_id: contract::Id,
+
+    /// [`Provider`] issued this [`Insurance`].
+    pub provider: Provider,
+
+    /// [`PolicyNumber`] of this [`Insurance`], assigned by its [`Provider`].
+    pub policy_number: PolicyNumber,
+
+    /// Maximum amount covered by this [`Insurance`].
+    pub coverage: Money,
+
+    /// [`DateTime`] when this [`Insurance`] expires.
+    pub expires_at: ExpirationDateTime,
+
+    /// ID of the employer [`User`] who attached this [`Insurance`].
+    pub creator_id: user::Id,
+
+    /// [`DateTime`] when this [`Insurance`] was attached.
+    pub created_at: CreationDateTime,
+
+    /// [`DateTime`] when the employer [`User`] was notified about this
+    /// [`Insurance`] expiring, if it was.
+    pub notified_at: Option<NotificationDateTime>,
+}
+
+impl Insurance {
+    /// Indicates whether this [`Insurance`] is expired at the provided
+    /// [`DateTime`].
+    #[must_use]
+    pub fn is_expired(&self, at: DateTime) -> bool {
+        self.expires_at.coerce() <= at
+    }
+
+    /// Indicates whether this [`Insurance`] expires within the provided
+    /// `notice` period since the provided [`DateTime`].
+    #[must_use]
+    pub fn is_expiring(&self, notice: Duration, at: DateTime) -> bool {
+        self.expires_at.coerce() <= at + notice
+    }
+}
+
+/// ID of an [`Insurance`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Name of a company providing an [`Insurance`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Provider(String);
+
+impl Provider {
+    /// Creates a new [`Provider`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `provider` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(provider: impl Into<String>) -> Self {
+        Self(provider.into())
+    }
+
+    /// Creates a new [`Provider`] if the given `provider` is valid.
+    #[must_use]
+    pub fn new(provider: impl Into<String>) -> Option<Self> {
+        let provider = provider.into();
+        Self::check(&provider).then_some(Self(provider))
+    }
+
+    /// Checks whether the given `provider` is a valid [`Provider`].
+    fn check(provider: impl AsRef<str>) -> bool {
+        let provider = provider.as_ref();
+        provider.trim() == provider
+            && !provider.is_empty()
+            && provider.len() <= 256
+    }
+}
+
+impl FromStr for Provider {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Provider`")
+    }
+}
+
+/// Number of an [`Insurance`] policy, assigned by its [`Provider`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct PolicyNumber(String);
+
+impl PolicyNumber {
+    /// Creates a new [`PolicyNumber`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `number` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(number: impl Into<String>) -> Self {
+        Self(number.into())
+    }
+
+    /// Creates a new [`PolicyNumber`] if the given `number` is valid.
+    #[must_use]
+    pub fn new(number: impl Into<String>) -> Option<Self> {
+        let number = number.into();
+        Self::check(&number).then_some(Self(number))
+    }
+
+    /// Checks whether the given `number` is a valid [`PolicyNumber`].
+    fn check(number: impl AsRef<str>) -> bool {
+        let number = number.as_ref();
+        number.trim() == number && !number.is_empty() && number.len() <= 64
+    }
+}
+
+impl FromStr for PolicyNumber {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `PolicyNumber`")
+    }
+}
+
+/// [`DateTime`] when an [`Insurance`] was attached.
+pub type CreationDateTime = DateTimeOf<(Insurance, unit::Creation)>;
+
+/// Marker type indicating an expiration of an [`Insurance`].
+#[derive(Clone, Copy, Debug)]
+pub struct Expiration;
+
+/// [`DateTime`] when an [`Insurance`] expires.
+pub type ExpirationDateTime = DateTimeOf<(Insurance, Expiration)>;
+
+/// Marker type indicating a notification about an expiring [`Insurance`].
+#[derive(Clone, Copy, Debug)]
+pub struct Notification;
+
+/// [`DateTime`] when the employer [`User`] was notified about an expiring
+/// [`Insurance`].
+pub type NotificationDateTime = DateTimeOf<(Insurance, Notification)>;
+
+#[cfg(test)]
+mod spec {
+    use std::time::Duration;
+
+    use common::{money::Currency, DateTime, Money};
+    use rust_decimal::Decimal;
+
+    use crate::domain::{agency, contract, user};
+
+    use super::{Id, Insurance, PolicyNumber, Provider};
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn expires_within_notice_period() {
+        let insurance = Insurance {
+            id: Id::new(),
+            agency_id: agency::Id::default(),
+            contract_id: contract::Id::new(),
+            provider: Provider::new("Acme Insurance").unwrap(),
+            policy_number: PolicyNumber::new("POL-123").unwrap(),
+            coverage: Money {
+                amount: Decimal::from(100_000),
+                currency: Currency::Usd,
+            },
+            expires_at: datetime("2024-06-30T00:00:00Z").coerce(),
+            creator_id: user::Id::new(),
+            created_at: datetime("2024-01-01T00:00:00Z").coerce(),
+            notified_at: None,
+        };
+        let month = Duration::from_secs(60 * 60 * 24 * 30);
+
+        let at = datetime("2024-05-01T00:00:00Z");
+        assert!(!insurance.is_expiring(month, at));
+        assert!(!insurance.is_expired(at));
+
+        let at = datetime("2024-06-15T00:00:00Z");
+        assert!(insurance.is_expiring(month, at));
+        assert!(!insurance.is_expired(at));
+
+        let at = datetime("2024-06-30T00:00:00Z");
+        assert!(insurance.is_expiring(month, at));
+        assert!(insurance.is_expired(at));
+    }
+}
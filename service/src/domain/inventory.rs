@@ -0,0 +1,416 @@
+//! [`Inventory`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty, User};
+
+/// Checklist of the [`Item`]s of a [`Realty`] rented under a
+/// [`contract::Rent`], recorded at a move-in or a move-out.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Inventory {
+    /// ID of this [`Inventory`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Inventory`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`contract::Rent`] this [`Inventory`] is recorded under.
+    pub contract_id: contract::Id,
+
+    /// [`Stage`] this [`Inventory`] is recorded at.
+    pub stage: Stage,
+
+    /// [`Item`]s of this [`Inventory`], in the order they were listed.
+    pub items: Vec<Item>,
+
+    /// ID of the employer [`User`] who recorded this [`Inventory`].
+    pub recorder_id: user::Id,
+
+    /// [`DateTime`] when this [`Inventory`] was recorded.
+    pub recorded_at: RecordingDateTime,
+}
+
+impl Inventory {
+    /// Compares this move-in [`Inventory`] with the provided move-out one,
+    /// returning the [`Difference`]s of their [`Item`]s.
+    ///
+    /// [`Item`]s are matched by their [`Room`]s and [`ItemName`]s. Unchanged
+    /// [`Item`]s are omitted.
+    #[must_use]
+    pub fn diff(&self, move_out: &Self) -> Vec<Difference> {
+        let mut differences = self
+            .items
+            .iter()
+            .filter_map(|before| {
+                let after = move_out.items.iter().find(|i| i.is_same(before));
+                let condition = after.map(|i| i.condition);
+                (condition != Some(before.condition)).then(|| Difference {
+                    room: before.room.clone(),
+                    name: before.name.clone(),
+                    move_in: Some(before.condition),
+                    move_out: condition,
+                    photos: after.map(|i| i.photos.clone()).unwrap_or_default(),
+                })
+            })
+            .collect::<Vec<_>>();
+        differences.extend(
+            move_out
+                .items
+                .iter()
+                .filter(|after| !self.items.iter().any(|i| i.is_same(after)))
+                .map(|after| Difference {
+                    room: after.room.clone(),
+                    name: after.name.clone(),
+                    move_in: None,
+                    move_out: Some(after.condition),
+                    photos: after.photos.clone(),
+                }),
+        );
+        differences
+    }
+}
+
+/// Item of an [`Inventory`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Item {
+    /// [`Room`] this [`Item`] is located in.
+    pub room: Room,
+
+    /// [`ItemName`] of this [`Item`].
+    pub name: ItemName,
+
+    /// [`Condition`] of this [`Item`].
+    pub condition: Condition,
+
+    /// [`Photo`]s of this [`Item`].
+    pub photos: Vec<Photo>,
+}
+
+impl Item {
+    /// Indicates whether this [`Item`] is the same as the `other` one, having
+    /// the same [`Room`] and [`ItemName`].
+    #[must_use]
+    pub fn is_same(&self, other: &Self) -> bool {
+        self.room == other.room && self.name == other.name
+    }
+}
+
+/// Difference of an [`Item`] between a move-in and a move-out
+/// [`Inventory`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Difference {
+    /// [`Room`] the [`Item`] is located in.
+    pub room: Room,
+
+    /// [`ItemName`] of the [`Item`].
+    pub name: ItemName,
+
+    /// [`Condition`] of the [`Item`] at the move-in, if it was listed.
+    pub move_in: Option<Condition>,
+
+    /// [`Condition`] of the [`Item`] at the move-out, if it was listed.
+    pub move_out: Option<Condition>,
+
+    /// [`Photo`]s of the [`Item`] taken at the move-out.
+    pub photos: Vec<Photo>,
+}
+
+impl Difference {
+    /// Indicates whether the [`Item`] was damaged or lost since the move-in.
+    #[must_use]
+    pub fn is_damage(&self) -> bool {
+        match (self.move_in, self.move_out) {
+            (Some(before), Some(after)) => after.u8() > before.u8(),
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+}
+
+/// ID of an [`Inventory`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+define_kind! {
+    #[doc = "Stage of a [`contract::Rent`] an [`Inventory`] is recorded at."]
+    enum Stage {
+        #[doc = "Purchaser [`User`] moves into the [`Realty`]."]
+        MoveIn = 1,
+
+        #[doc = "Purchaser [`User`] moves out of the [`Realty`]."]
+        MoveOut = 2,
+    }
+}
+
+define_kind! {
+    #[doc = "Condition of an [`Item`], from the best to the worst."]
+    enum Condition {
+        #[doc = "[`Item`] is new or as new."]
+        Excellent = 1,
+
+        #[doc = "[`Item`] has minor signs of use."]
+        Good = 2,
+
+        #[doc = "[`Item`] has noticeable wear."]
+        Fair = 3,
+
+        #[doc = "[`Item`] is heavily worn."]
+        Poor = 4,
+
+        #[doc = "[`Item`] is damaged."]
+        Damaged = 5,
+
+        #[doc = "[`Item`] is missing."]
+        Missing = 6,
+    }
+}
+
+/// Room of a [`Realty`] an [`Item`] is located in (e.g. "Kitchen").
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Room(String);
+
+impl Room {
+    /// Creates a new [`Room`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `room` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(room: impl Into<String>) -> Self {
+        Self(room.into())
+    }
+
+    /// Creates a new [`Room`] if the given `room` is valid.
+    #[must_use]
+    pub fn new(room: impl Into<String>) -> Option<Self> {
+        let room = room.into();
+        Self::check(&room).then_some(Self(room))
+    }
+
+    /// Checks whether the given `room` is a valid [`Room`].
+    fn check(room: impl AsRef<str>) -> bool {
+        let room = room.as_ref();
+        room.trim() == room && !room.is_empty() && room.len() <= 256
+    }
+}
+
+impl FromStr for Room {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Room`")
+    }
+}
+
+/// Name of an [`Item`] (e.g. "Fridge").
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct ItemName(String);
+
+impl ItemName {
+    /// Creates a new [`ItemName`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `name` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Creates a new [`ItemName`] if the given `name` is valid.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Option<Self> {
+        let name = name.into();
+        Self::check(&name).then_some(Self(name))
+    }
+
+    /// Checks whether the given `name` is a valid [`ItemName`].
+    fn check(name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        name.trim() == name && !name.is_empty() && name.len() <= 256
+    }
+}
+
+impl FromStr for ItemName {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `ItemName`")
+    }
+}
+
+/// URL of a photo of an [`Item`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Photo(String);
+
+impl Photo {
+    /// Creates a new [`Photo`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `url` is a valid HTTP(S) URL.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+
+    /// Creates a new [`Photo`] if the given `url` is valid.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Option<Self> {
+        let url = url.into();
+        Self::check(&url).then_some(Self(url))
+    }
+
+    /// Checks whether the given `url` is a valid [`Photo`] URL.
+    fn check(url: impl AsRef<str>) -> bool {
+        let url = url.as_ref();
+        let host = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"));
+        host.is_some_and(|h| !h.is_empty())
+            && !url.contains(char::is_whitespace)
+            && url.len() <= 2048
+    }
+}
+
+impl FromStr for Photo {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Photo`")
+    }
+}
+
+/// Marker type indicating a recording of an [`Inventory`].
+#[derive(Clone, Copy, Debug)]
+pub struct Recording;
+
+/// [`DateTime`] when an [`Inventory`] was recorded.
+pub type RecordingDateTime = DateTimeOf<(Inventory, Recording)>;
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+
+    use crate::domain::{agency, contract, user};
+
+    use super::{Condition, Id, Inventory, Item, ItemName, Photo, Room, Stage};
+
+    fn item(room: &str, name: &str, condition: Condition) -> Item {
+        Item {
+            room: Room::new(room).unwrap(),
+            name: ItemName::new(name).unwrap(),
+            condition,
+            photos: vec![],
+        }
+    }
+
+    fn inventory(stage: Stage, items: Vec<Item>) -> Inventory {
+        Inventory {
+            id: Id::new(),
+            agency_id: agency::Id::default(),
+            contract_id: contract::Id::default(),
+            stage,
+            items,
+            recorder_id: user::Id::default(),
+            recorded_at: DateTime::now().coerce(),
+        }
+    }
+
+    #[test]
+    fn diffs_changed_items_only() {
+        let move_in = inventory(
+            Stage::MoveIn,
+            vec![
+                item("Kitchen", "Fridge", Condition::Good),
+                item("Kitchen", "Oven", Condition::Fair),
+                item("Bedroom", "Bed", Condition::Excellent),
+                item("Bedroom", "Lamp", Condition::Good),
+            ],
+        );
+        let move_out = inventory(
+            Stage::MoveOut,
+            vec![
+                item("Kitchen", "Fridge", Condition::Good),
+                item("Kitchen", "Oven", Condition::Good),
+                item("Bedroom", "Bed", Condition::Damaged),
+                item("Bedroom", "Chair", Condition::Good),
+            ],
+        );
+
+        let diff = move_in.diff(&move_out);
+        let summary = diff
+            .iter()
+            .map(|d| (d.name.to_string(), d.move_in, d.move_out, d.is_damage()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "Oven".into(),
+                    Some(Condition::Fair),
+                    Some(Condition::Good),
+                    false,
+                ),
+                (
+                    "Bed".into(),
+                    Some(Condition::Excellent),
+                    Some(Condition::Damaged),
+                    true,
+                ),
+                ("Lamp".into(), Some(Condition::Good), None, true),
+                ("Chair".into(), None, Some(Condition::Good), false),
+            ],
+        );
+    }
+
+    #[test]
+    fn validates_photo_urls() {
+        assert!(Photo::new("https://cdn.example.com/1.jpg").is_some());
+        assert!(Photo::new("http://example.com/a.png").is_some());
+        assert!(Photo::new("ftp://example.com/a.png").is_none());
+        assert!(Photo::new("https://").is_none());
+        assert!(Photo::new("https://example.com/a b.png").is_none());
+    }
+}
@@ -0,0 +1,130 @@
+//! Accounting [`Entry`] definitions.
+
+use std::fmt::Write as _;
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{unit, DateTimeOf, Money};
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::domain::{agency, contract, payment, Payment};
+
+/// Name of the account debited by an [`Entry`].
+pub const DEBIT_ACCOUNT: &str = "Cash";
+
+/// Name of the account credited by an [`Entry`].
+pub const CREDIT_ACCOUNT: &str = "Rental Income";
+
+/// Header of the [`to_csv()`] output.
+const CSV_HEADER: &str =
+    "JournalNumber,JournalDate,AccountName,Debit,Credit,Currency,Description";
+
+/// Entry of an accounting journal, posting a [`Payment`] received under a
+/// [`contract::Rent`] as a debit of the [`DEBIT_ACCOUNT`] and a credit of the
+/// [`CREDIT_ACCOUNT`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Entry {
+    /// ID of the [`Payment`] posted by this [`Entry`].
+    pub payment_id: payment::Id,
+
+    /// ID of the [`Agency`] this [`Entry`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`contract::Rent`] the [`Payment`] is made under.
+    pub contract_id: contract::Id,
+
+    /// Posted amount.
+    pub amount: Money,
+
+    /// [`DateTime`] when the [`Payment`] was made.
+    pub posted_at: payment::PaymentDateTime,
+}
+
+impl From<Payment> for Entry {
+    fn from(payment: Payment) -> Self {
+        Self {
+            payment_id: payment.id,
+            agency_id: payment.agency_id,
+            contract_id: payment.contract_id,
+            amount: payment.amount,
+            posted_at: payment.paid_at,
+        }
+    }
+}
+
+/// Record of an [`Entry`] being pushed into an external accounting system.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Export {
+    /// ID of the [`Payment`] posted by the pushed [`Entry`].
+    pub payment_id: payment::Id,
+
+    /// [`DateTime`] when the [`Entry`] was pushed.
+    pub exported_at: ExportDateTime,
+}
+
+/// [`DateTime`] when an [`Entry`] was pushed into an external accounting
+/// system.
+pub type ExportDateTime = DateTimeOf<(Export, unit::Creation)>;
+
+/// Renders the provided [`Entry`]s as CSV, importable as manual journals by
+/// most of accounting systems.
+///
+/// Every [`Entry`] is rendered as two balanced lines sharing the same journal
+/// number (the [`Payment`] ID), so re-importing them may be deduplicated.
+#[must_use]
+pub fn to_csv(entries: &[Entry]) -> String {
+    let mut csv = format!("{CSV_HEADER}\r\n");
+    for e in entries {
+        let date = &e.posted_at.to_rfc3339()[..10];
+        let (amount, currency) = (e.amount.amount, e.amount.currency);
+        let memo = format!("Rent payment under contract {}", e.contract_id);
+        _ = write!(
+            csv,
+            "{id},{date},{DEBIT_ACCOUNT},{amount:.2},,{currency},{memo}\r\n\
+             {id},{date},{CREDIT_ACCOUNT},,{amount:.2},{currency},{memo}\r\n",
+            id = e.payment_id,
+        );
+    }
+    csv
+}
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, DateTime, Money};
+    use rust_decimal::Decimal;
+
+    use crate::domain::{agency, contract, payment};
+
+    use super::{to_csv, Entry};
+
+    #[test]
+    fn renders_balanced_lines() {
+        let entry = Entry {
+            payment_id: payment::Id::new(),
+            agency_id: agency::Id::default(),
+            contract_id: contract::Id::new(),
+            amount: Money {
+                amount: Decimal::new(12_505, 1),
+                currency: Currency::Usd,
+            },
+            posted_at: DateTime::from_rfc3339("2024-03-05T10:00:00Z")
+                .unwrap()
+                .coerce(),
+        };
+
+        let csv = to_csv(&[entry]);
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("JournalNumber,"));
+        assert!(lines[1].starts_with(&format!(
+            "{},2024-03-05,Cash,1250.50,,USD,",
+            entry.payment_id,
+        )));
+        assert!(lines[2].starts_with(&format!(
+            "{},2024-03-05,Rental Income,,1250.50,USD,",
+            entry.payment_id,
+        )));
+    }
+}
@@ -0,0 +1,176 @@
+//! [`Key`] definitions.
+
+use common::{unit, DateTime, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, realty, user};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty, User};
+
+/// Physical key of a [`Realty`] managed by an [`Agency`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Key {
+    /// ID of this [`Key`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Key`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`Realty`] this [`Key`] opens.
+    pub realty_id: realty::Id,
+
+    /// [`Label`] of this [`Key`].
+    pub label: Label,
+
+    /// [`Checkout`] of this [`Key`], if it's checked out.
+    pub checkout: Option<Checkout>,
+
+    /// [`DateTime`] when this [`Key`] was registered.
+    pub created_at: CreationDateTime,
+}
+
+impl Key {
+    /// Indicates whether this [`Key`] is checked out and not returned by its
+    /// [`Checkout::due_at`] as of the provided [`DateTime`].
+    #[must_use]
+    pub fn is_overdue(&self, at: DateTime) -> bool {
+        self.checkout.is_some_and(|c| c.due_at.coerce() < at)
+    }
+}
+
+/// Checkout of a [`Key`] to its current holder.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Checkout {
+    /// ID of the [`User`] holding the [`Key`].
+    pub holder_id: user::Id,
+
+    /// ID of the employer [`User`] who checked out the [`Key`].
+    pub issuer_id: user::Id,
+
+    /// [`DateTime`] when the [`Key`] was checked out.
+    pub checked_out_at: CheckoutDateTime,
+
+    /// [`DateTime`] when the [`Key`] is due to be returned.
+    pub due_at: DueDateTime,
+}
+
+/// ID of a [`Key`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Label of a [`Key`], unique among the [`Key`]s of the same [`Realty`]
+/// (e.g. "Front door #2").
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Label(String);
+
+impl Label {
+    /// Creates a new [`Label`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `label` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(label: impl Into<String>) -> Self {
+        Self(label.into())
+    }
+
+    /// Creates a new [`Label`] if the given `label` is valid.
+    #[must_use]
+    pub fn new(label: impl Into<String>) -> Option<Self> {
+        let label = label.into();
+        Self::check(&label).then_some(Self(label))
+    }
+
+    /// Checks whether the given `label` is a valid [`Label`].
+    fn check(label: impl AsRef<str>) -> bool {
+        let label = label.as_ref();
+        label.trim() == label && !label.is_empty() && label.len() <= 256
+    }
+}
+
+impl FromStr for Label {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Label`")
+    }
+}
+
+/// [`DateTime`] when a [`Key`] was registered.
+pub type CreationDateTime = DateTimeOf<(Key, unit::Creation)>;
+
+/// [`DateTime`] when a [`Key`] was checked out.
+pub type CheckoutDateTime = DateTimeOf<(Key, Checkout)>;
+
+/// Marker type indicating a due return of a [`Key`].
+#[derive(Clone, Copy, Debug)]
+pub struct Due;
+
+/// [`DateTime`] when a checked out [`Key`] is due to be returned.
+pub type DueDateTime = DateTimeOf<(Key, Due)>;
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+
+    use crate::domain::{agency, realty, user};
+
+    use super::{Checkout, Id, Key, Label};
+
+    fn datetime(s: &str) -> DateTime {
+        DateTime::from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn overdue_only_when_not_returned_in_time() {
+        let mut key = Key {
+            id: Id::new(),
+            agency_id: agency::Id::default(),
+            realty_id: realty::Id::new(),
+            label: Label::new("Front door").unwrap(),
+            checkout: None,
+            created_at: datetime("2024-01-01T00:00:00Z").coerce(),
+        };
+        assert!(!key.is_overdue(datetime("2024-02-01T00:00:00Z")));
+
+        key.checkout = Some(Checkout {
+            holder_id: user::Id::new(),
+            issuer_id: user::Id::new(),
+            checked_out_at: datetime("2024-01-10T09:00:00Z").coerce(),
+            due_at: datetime("2024-01-10T18:00:00Z").coerce(),
+        });
+        assert!(!key.is_overdue(datetime("2024-01-10T12:00:00Z")));
+        assert!(!key.is_overdue(datetime("2024-01-10T18:00:00Z")));
+        assert!(key.is_overdue(datetime("2024-01-11T00:00:00Z")));
+    }
+}
@@ -0,0 +1,236 @@
+//! [`LateFee`] definitions.
+
+use std::time::Duration;
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{DateTimeOf, Money, Percent};
+use rust_decimal::Decimal;
+
+use crate::domain::contract;
+
+/// Duration of a single day late fees are charged for.
+const DAY: Duration = Duration::from_hours(24);
+
+/// Late fee charged under a [`contract::Rent`] for its overdue installments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LateFee {
+    /// ID of the [`contract::Rent`] this [`LateFee`] is charged under.
+    pub contract_id: contract::Id,
+
+    /// [`Policy`] of charging this [`LateFee`].
+    pub policy: Policy,
+
+    /// Total amount accrued so far, in the currency of the
+    /// [`contract::Rent::price`].
+    pub accrued: Money,
+
+    /// [`DateTime`] until which this [`LateFee`] is accrued, if it was.
+    pub accrued_until: Option<AccrualDateTime>,
+}
+
+impl LateFee {
+    /// Creates a new [`LateFee`] of the provided [`contract::Rent`] with
+    /// nothing accrued yet.
+    #[must_use]
+    pub fn new(rent: &contract::Rent, policy: Policy) -> Self {
+        Self {
+            contract_id: rent.id,
+            policy,
+            accrued: Money {
+                amount: Decimal::ZERO,
+                currency: rent.price.currency,
+            },
+            accrued_until: None,
+        }
+    }
+
+    /// Accrues this [`LateFee`] for the whole days passed since the provided
+    /// `overdue_since` [`DateTime`] (or since the last accrual, whichever is
+    /// later) till the provided `now`.
+    ///
+    /// Returns the newly accrued amount, being zero if not a whole day passed
+    /// yet, or the [`Policy::cap`] is reached already.
+    pub fn accrue(
+        &mut self,
+        overdue: Money,
+        overdue_since: AccrualDateTime,
+        now: AccrualDateTime,
+    ) -> Decimal {
+        let from = self
+            .accrued_until
+            .map_or(overdue_since, |until| until.max(overdue_since));
+        if from >= now || overdue.amount <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let days = (now - from).as_secs() / DAY.as_secs();
+        if days == 0 {
+            return Decimal::ZERO;
+        }
+
+        let daily = match self.policy.charge {
+            Charge::Fixed(fee) => fee.amount,
+            Charge::Percent(p) => p.of(overdue).amount,
+        };
+        let mut accrued = self.accrued.amount + daily * Decimal::from(days);
+        if let Some(cap) = self.policy.cap {
+            accrued = accrued.min(cap.amount);
+        }
+        let delta = (accrued - self.accrued.amount).max(Decimal::ZERO);
+
+        self.accrued.amount += delta;
+        self.accrued_until =
+            Some(from + DAY * u32::try_from(days).unwrap_or(0));
+        delta
+    }
+}
+
+/// Policy of charging a [`LateFee`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Policy {
+    /// [`Charge`] per each day an installment is overdue.
+    pub charge: Charge,
+
+    /// Maximum total amount of the [`LateFee`], if any.
+    pub cap: Option<Money>,
+}
+
+impl Policy {
+    /// Indicates whether this [`Policy`] is applicable to a
+    /// [`contract::Rent`] with the provided `price`.
+    ///
+    /// All the amounts must be positive and in the currency of the `price`.
+    #[must_use]
+    pub fn is_applicable_to(&self, price: Money) -> bool {
+        let is_valid =
+            |m: Money| m.currency == price.currency && m.amount > Decimal::ZERO;
+        let is_charge_valid = match self.charge {
+            Charge::Fixed(fee) => is_valid(fee),
+            Charge::Percent(p) => p != Percent::ZERO,
+        };
+        is_charge_valid && self.cap.is_none_or(is_valid)
+    }
+}
+
+/// Daily charge of a [`LateFee`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Charge {
+    /// Fixed amount per day.
+    Fixed(Money),
+
+    /// Percent of the overdue rent amount per day.
+    Percent(Percent),
+}
+
+/// Marker type indicating an accrual of a [`LateFee`].
+#[derive(Clone, Copy, Debug)]
+pub struct Accrual;
+
+/// [`DateTime`] until which a [`LateFee`] is accrued.
+pub type AccrualDateTime = DateTimeOf<(LateFee, Accrual)>;
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, DateTime, Money, Percent};
+    use rust_decimal::Decimal;
+
+    use crate::domain::contract;
+
+    use super::{AccrualDateTime, Charge, LateFee, Policy};
+
+    fn datetime(s: &str) -> AccrualDateTime {
+        DateTime::from_rfc3339(s).unwrap().coerce()
+    }
+
+    fn usd(amount: i64) -> Money {
+        Money {
+            amount: Decimal::from(amount),
+            currency: Currency::Usd,
+        }
+    }
+
+    fn late_fee(charge: Charge, cap: Option<Money>) -> LateFee {
+        LateFee {
+            contract_id: contract::Id::new(),
+            policy: Policy { charge, cap },
+            accrued: usd(0),
+            accrued_until: None,
+        }
+    }
+
+    #[test]
+    fn accrues_whole_days_only() {
+        let mut fee = late_fee(Charge::Fixed(usd(10)), None);
+        let since = datetime("2024-03-01T00:00:00Z");
+
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-01T23:00:00Z")),
+            Decimal::ZERO,
+        );
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-03T12:00:00Z")),
+            Decimal::from(20),
+        );
+        assert_eq!(fee.accrued, usd(20));
+        assert_eq!(fee.accrued_until, Some(datetime("2024-03-03T00:00:00Z")));
+
+        // Already accrued days are not charged twice.
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-03T18:00:00Z")),
+            Decimal::ZERO,
+        );
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-04T00:00:00Z")),
+            Decimal::from(10),
+        );
+    }
+
+    #[test]
+    fn charges_percent_of_overdue_amount() {
+        let percent = Percent::new(Decimal::ONE).unwrap();
+        let mut fee = late_fee(Charge::Percent(percent), None);
+
+        assert_eq!(
+            fee.accrue(
+                usd(500),
+                datetime("2024-03-01T00:00:00Z"),
+                datetime("2024-03-03T00:00:00Z"),
+            ),
+            Decimal::from(10),
+        );
+    }
+
+    #[test]
+    fn respects_cap() {
+        let mut fee = late_fee(Charge::Fixed(usd(10)), Some(usd(25)));
+        let since = datetime("2024-03-01T00:00:00Z");
+
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-11T00:00:00Z")),
+            Decimal::from(25),
+        );
+        assert_eq!(
+            fee.accrue(usd(1000), since, datetime("2024-03-12T00:00:00Z")),
+            Decimal::ZERO,
+        );
+        assert_eq!(fee.accrued, usd(25));
+    }
+
+    #[test]
+    fn validates_policy_against_price() {
+        let policy = |charge, cap| Policy { charge, cap };
+        let eur = Money {
+            amount: Decimal::ONE,
+            currency: Currency::Eur,
+        };
+
+        assert!(policy(Charge::Fixed(usd(1)), Some(usd(5)))
+            .is_applicable_to(usd(100)));
+        assert!(!policy(Charge::Fixed(eur), None).is_applicable_to(usd(100)));
+        assert!(!policy(Charge::Fixed(usd(0)), None).is_applicable_to(usd(100)));
+        assert!(!policy(Charge::Percent(Percent::ZERO), None)
+            .is_applicable_to(usd(100)));
+        assert!(!policy(Charge::Fixed(usd(1)), Some(eur))
+            .is_applicable_to(usd(100)));
+    }
+}
@@ -0,0 +1,181 @@
+//! [`Lead`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, realty, user, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty, User};
+
+/// Prospect of an [`Agency`], moving through its sales pipeline [`Stage`]s.
+#[derive(Clone, Debug)]
+pub struct Lead {
+    /// ID of this [`Lead`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Lead`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`Source`] this [`Lead`] came from.
+    pub source: Source,
+
+    /// Current [`Stage`] of this [`Lead`].
+    pub stage: Stage,
+
+    /// ID of the prospect [`User`], if known.
+    pub user_id: Option<user::Id>,
+
+    /// ID of the placed [`Realty`] this [`Lead`] is interested in, if any.
+    pub realty_id: Option<realty::Id>,
+
+    /// [`DateTime`] when this [`Lead`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`Version`] of this [`Lead`].
+    pub version: Version,
+}
+
+/// ID of a [`Lead`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Source of a [`Lead`] (a website form, a phone call, a referral, etc.).
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Source(String);
+
+impl Source {
+    /// Creates a new [`Source`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `source` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(source: impl Into<String>) -> Self {
+        Self(source.into())
+    }
+
+    /// Creates a new [`Source`] if the given `source` is valid.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Option<Self> {
+        let source = source.into();
+        Self::check(&source).then_some(Self(source))
+    }
+
+    /// Checks whether the given `source` is a valid [`Source`].
+    fn check(source: impl AsRef<str>) -> bool {
+        let source = source.as_ref();
+        source.trim() == source && !source.is_empty() && source.len() <= 64
+    }
+}
+
+impl FromStr for Source {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Source`")
+    }
+}
+
+define_kind! {
+    #[doc = "Stage of a [`Lead`] in the sales pipeline."]
+    enum Stage {
+        #[doc = "Newly received inquiry."]
+        New = 1,
+
+        #[doc = "Prospect has been contacted."]
+        Contacted = 2,
+
+        #[doc = "Prospect is viewing the [`Realty`]."]
+        Viewing = 3,
+
+        #[doc = "Prospect has made an offer."]
+        Offer = 4,
+
+        #[doc = "[`Lead`] is closed (either won or lost)."]
+        Closed = 5,
+    }
+}
+
+impl Stage {
+    /// Indicates whether a [`Lead`] may be moved from this [`Stage`] to the
+    /// provided one.
+    ///
+    /// The pipeline only moves forward, though any [`Stage`] may be skipped
+    /// (a [`Lead`] may be closed at any moment, for example).
+    #[must_use]
+    pub const fn can_move_to(self, to: Self) -> bool {
+        to.u8() > self.u8()
+    }
+}
+
+/// Recorded move of a [`Lead`] between [`Stage`]s, kept as its history.
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+    /// ID of the moved [`Lead`].
+    pub lead_id: Id,
+
+    /// [`Stage`] the [`Lead`] was moved from.
+    ///
+    /// [`None`] if the [`Lead`] was just created.
+    pub from: Option<Stage>,
+
+    /// [`Stage`] the [`Lead`] was moved to.
+    pub to: Stage,
+
+    /// ID of the [`User`] who moved the [`Lead`].
+    pub initiator_id: user::Id,
+
+    /// [`DateTime`] when the [`Lead`] was moved.
+    pub created_at: TransitionDateTime,
+}
+
+/// [`DateTime`] when a [`Lead`] was created.
+pub type CreationDateTime = DateTimeOf<(Lead, unit::Creation)>;
+
+/// [`DateTime`] when a [`Transition`] happened.
+pub type TransitionDateTime = DateTimeOf<(Transition, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use super::Stage;
+
+    #[test]
+    fn moves_only_forward() {
+        assert!(Stage::New.can_move_to(Stage::Contacted));
+        assert!(Stage::Contacted.can_move_to(Stage::Closed));
+        assert!(!Stage::Offer.can_move_to(Stage::Viewing));
+        assert!(!Stage::Closed.can_move_to(Stage::Closed));
+    }
+}
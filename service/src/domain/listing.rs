@@ -0,0 +1,286 @@
+//! External [`Listing`] definitions.
+
+use common::{define_kind, unit, DateTime, DateTimeOf, Money};
+use derive_more::{AsRef, Display, FromStr};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+
+use crate::domain::{agency, contract, realty, Realty, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract};
+
+/// Listing of a [`Realty`] pulled from an external portal or MLS feed.
+#[derive(Clone, Debug)]
+pub struct Listing {
+    /// [`ExternalId`] of this [`Listing`] in its [`Source`].
+    pub external_id: ExternalId,
+
+    /// [`Deal`] this [`Listing`] is offered for.
+    pub deal: Deal,
+
+    /// [`realty::Country`] of the listed [`Realty`].
+    pub country: realty::Country,
+
+    /// [`realty::State`] of the listed [`Realty`].
+    pub state: Option<realty::State>,
+
+    /// [`realty::City`] of the listed [`Realty`].
+    pub city: realty::City,
+
+    /// [`realty::Street`] of the listed [`Realty`].
+    pub street: realty::Street,
+
+    /// [`realty::ZipCode`] of the listed [`Realty`].
+    pub zip_code: Option<realty::ZipCode>,
+
+    /// [`realty::BuildingName`] of the listed [`Realty`].
+    pub building_name: realty::BuildingName,
+
+    /// [`realty::NumFloors`] of the listed [`Realty`].
+    pub num_floors: realty::NumFloors,
+
+    /// [`realty::Floor`] of the listed [`Realty`].
+    pub floor: Option<realty::Floor>,
+
+    /// [`realty::ApartmentNum`] of the listed [`Realty`].
+    pub apartment_num: Option<realty::ApartmentNum>,
+
+    /// [`realty::RoomNum`] of the listed [`Realty`].
+    pub room_num: Option<realty::RoomNum>,
+
+    /// [`realty::Area`] of the listed [`Realty`].
+    pub area: Option<realty::Area>,
+
+    /// Title of this [`Listing`], becoming the name of the management
+    /// [`Contract`].
+    pub name: contract::Name,
+
+    /// Description of this [`Listing`], becoming the description of the
+    /// management [`Contract`].
+    pub description: contract::Description,
+
+    /// Asked price of this [`Listing`].
+    pub price: Money,
+}
+
+impl Listing {
+    /// Returns the [`realty::Hash`] of the listed [`Realty`], used to
+    /// deduplicate it against the existing ones.
+    #[must_use]
+    pub fn hash(&self) -> realty::Hash {
+        realty::Hash::new(
+            &self.country,
+            self.state.as_ref(),
+            &self.city,
+            &self.street,
+            self.zip_code.as_ref(),
+            &self.building_name,
+            self.num_floors,
+            self.floor,
+            self.apartment_num.as_ref(),
+            self.room_num.as_ref(),
+        )
+    }
+
+    /// Creates a new [`Realty`] listed by this [`Listing`] in the [`Agency`]
+    /// with the provided ID.
+    #[must_use]
+    pub fn realty(&self, agency_id: agency::Id) -> Realty {
+        Realty {
+            id: realty::Id::new(),
+            agency_id,
+            assignee_id: None,
+            hash: self.hash(),
+            address: realty::Address::from_parts(
+                &self.country,
+                self.state.as_ref(),
+                &self.city,
+                &self.street,
+                self.zip_code.as_ref(),
+                &self.building_name,
+                self.floor,
+                self.apartment_num.as_ref(),
+                self.room_num.as_ref(),
+            ),
+            country: self.country.clone(),
+            state: self.state.clone(),
+            city: self.city.clone(),
+            street: self.street.clone(),
+            zip_code: self.zip_code.clone(),
+            building_name: self.building_name.clone(),
+            num_floors: self.num_floors,
+            floor: self.floor,
+            apartment_num: self.apartment_num.clone(),
+            room_num: self.room_num.clone(),
+            area: self.area,
+            created_at: DateTime::now().coerce(),
+            deleted_at: None,
+            version: Version::initial(),
+        }
+    }
+}
+
+define_kind! {
+    #[doc = "Deal a [`Listing`] is offered for."]
+    enum Deal {
+        #[doc = "[`Realty`] is offered for rent."]
+        Rent = 1,
+
+        #[doc = "[`Realty`] is offered for sale."]
+        Sale = 2,
+    }
+}
+
+/// Name of an external feed the [`Listing`]s are pulled from (like
+/// `rightmove`).
+#[derive(AsRef, Clone, Debug, Display, Eq, Hash, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Source(String);
+
+impl Source {
+    /// Creates a new [`Source`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `source` matches the format.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(source: impl Into<String>) -> Self {
+        Self(source.into())
+    }
+
+    /// Creates a new [`Source`] if the given `source` is valid.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Option<Self> {
+        let source = source.into();
+        Self::check(&source).then_some(Self(source))
+    }
+
+    /// Checks whether the given `source` is a valid [`Source`].
+    ///
+    /// Only lowercase ASCII letters, digits, `-` and `_` are allowed.
+    fn check(source: impl AsRef<str>) -> bool {
+        let source = source.as_ref();
+        !source.is_empty()
+            && source.len() <= 64
+            && source.bytes().all(|b| {
+                b.is_ascii_lowercase()
+                    || b.is_ascii_digit()
+                    || matches!(b, b'-' | b'_')
+            })
+    }
+}
+
+impl FromStr for Source {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Source`")
+    }
+}
+
+/// ID of a [`Listing`] in its [`Source`].
+#[derive(AsRef, Clone, Debug, Display, Eq, Hash, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct ExternalId(String);
+
+impl ExternalId {
+    /// Creates a new [`ExternalId`] if the given `id` is valid.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Option<Self> {
+        let id = id.into();
+        let is_valid = id.trim() == id && !id.is_empty() && id.len() <= 256;
+        is_valid.then_some(Self(id))
+    }
+}
+
+/// Record of a [`Listing`] imported from its [`Source`], marking the
+/// [`Realty`] as externally sourced.
+#[derive(Clone, Debug)]
+pub struct Import {
+    /// [`Source`] the [`Listing`] was imported from.
+    pub source: Source,
+
+    /// [`ExternalId`] of the [`Listing`] in its [`Source`].
+    pub external_id: ExternalId,
+
+    /// ID of the [`Realty`] the [`Listing`] was imported as.
+    pub realty_id: realty::Id,
+
+    /// ID of the management [`Contract`] created for the [`Listing`].
+    ///
+    /// [`None`] if the [`Listing`] matched an already existing [`Realty`],
+    /// which management is left intact.
+    pub contract_id: Option<contract::Id>,
+
+    /// [`DateTime`] when the [`Listing`] was imported.
+    pub imported_at: ImportDateTime,
+}
+
+/// Results of a single synchronization of the [`Listing`]s from a [`Source`].
+#[derive(Clone, Debug)]
+pub struct Sync {
+    /// [`Source`] the [`Listing`]s were pulled from.
+    pub source: Source,
+
+    /// [`DateTime`] when this [`Sync`] has started.
+    pub started_at: DateTime,
+
+    /// [`DateTime`] when this [`Sync`] has finished.
+    pub finished_at: DateTime,
+
+    /// Number of the [`Listing`]s imported as new [`Realty`]s along with
+    /// their management [`Contract`]s.
+    pub imported: u32,
+
+    /// Number of the [`Listing`]s matched the already existing [`Realty`]s
+    /// by their [`realty::Hash`].
+    pub deduplicated: u32,
+
+    /// Number of the [`Listing`]s imported by some previous [`Sync`].
+    pub unchanged: u32,
+
+    /// Number of the [`Listing`]s being malformed or failed to be imported.
+    pub rejected: u32,
+
+    /// Error of pulling the [`Listing`]s from the [`Source`], if it has
+    /// failed.
+    pub error: Option<String>,
+}
+
+impl Sync {
+    /// Creates a new empty [`Sync`] of the provided [`Source`], started at
+    /// the provided [`DateTime`].
+    #[must_use]
+    pub fn new(source: Source, started_at: DateTime) -> Self {
+        Self {
+            source,
+            started_at,
+            finished_at: started_at,
+            imported: 0,
+            deduplicated: 0,
+            unchanged: 0,
+            rejected: 0,
+            error: None,
+        }
+    }
+}
+
+/// [`DateTime`] when a [`Listing`] was imported.
+pub type ImportDateTime = DateTimeOf<(Import, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use super::Source;
+
+    #[test]
+    fn validates_source() {
+        assert!(Source::new("rightmove").is_some());
+        assert!(Source::new("mls_east-2").is_some());
+        assert!(Source::new("").is_none());
+        assert!(Source::new("Rightmove").is_none());
+        assert!(Source::new("../mls").is_none());
+    }
+}
@@ -0,0 +1,153 @@
+//! [`MediaLink`] definitions.
+
+use std::collections::HashSet;
+
+use common::define_kind;
+use derive_more::{AsRef, Display, FromStr};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+
+use crate::domain::realty;
+#[cfg(doc)]
+use crate::domain::Realty;
+
+/// Link to an external virtual tour or video of a [`Realty`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MediaLink {
+    /// ID of the [`Realty`] this [`MediaLink`] belongs to.
+    pub realty_id: realty::Id,
+
+    /// [`Url`] of this [`MediaLink`].
+    pub url: Url,
+}
+
+impl MediaLink {
+    /// Maximum number of [`MediaLink`]s a single [`Realty`] may have.
+    pub const MAX_PER_REALTY: usize = 16;
+
+    /// Indicates whether the provided [`MediaLink`]s may be set on the same
+    /// [`Realty`].
+    ///
+    /// Every [`Url`] must be used only once, and there must be no more than
+    /// [`MediaLink::MAX_PER_REALTY`] of them.
+    #[must_use]
+    pub fn are_valid(links: &[Self]) -> bool {
+        let mut urls = HashSet::with_capacity(links.len());
+        links.len() <= Self::MAX_PER_REALTY
+            && links.iter().all(|l| urls.insert(&l.url))
+    }
+}
+
+define_kind! {
+    #[doc = "Allowed provider of [`MediaLink`]s."]
+    enum Provider {
+        #[doc = "[Matterport](https://matterport.com) virtual tour."]
+        Matterport = 1,
+
+        #[doc = "[YouTube](https://youtube.com) video."]
+        Youtube = 2,
+    }
+}
+
+impl Provider {
+    /// Returns the [`Provider`] serving the provided `host`, if it's allowed.
+    fn of_host(host: &str) -> Option<Self> {
+        Some(match host {
+            "matterport.com" | "my.matterport.com" => Self::Matterport,
+            "youtube.com" | "www.youtube.com" | "m.youtube.com"
+            | "youtu.be" => Self::Youtube,
+            _ => return None,
+        })
+    }
+}
+
+/// HTTPS URL of a [`MediaLink`], served by some allowed [`Provider`].
+#[derive(AsRef, Clone, Debug, Display, Eq, Hash, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Url(String);
+
+impl Url {
+    /// Creates a new [`Url`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `url` is a valid HTTPS URL of an
+    /// allowed [`Provider`].
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+
+    /// Creates a new [`Url`] if the given `url` is valid.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Option<Self> {
+        let url = url.into();
+        Self::provider_of(&url).is_some().then_some(Self(url))
+    }
+
+    /// Returns the [`Provider`] serving this [`Url`].
+    #[must_use]
+    pub fn provider(&self) -> Provider {
+        Self::provider_of(&self.0).expect("`Url` should have `Provider`")
+    }
+
+    /// Returns the [`Provider`] serving the given `url`, if it's a valid
+    /// [`Url`].
+    ///
+    /// Credentials and ports are not allowed, so the host cannot be spoofed
+    /// (like `https://youtube.com@evil.com`).
+    fn provider_of(url: &str) -> Option<Provider> {
+        if url.len() > 2048 || url.contains(char::is_whitespace) {
+            return None;
+        }
+        let rest = url.strip_prefix("https://")?;
+        let (host, path) = rest
+            .find(['/', '?', '#'])
+            .map_or((rest, ""), |i| rest.split_at(i));
+        if host.contains(['@', ':']) || path.len() < 2 {
+            return None;
+        }
+        Provider::of_host(&host.to_ascii_lowercase())
+    }
+}
+
+impl FromStr for Url {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Url`")
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use super::{Provider, Url};
+
+    #[test]
+    fn detects_provider() {
+        for (url, provider) in [
+            ("https://my.matterport.com/show/?m=abc", Provider::Matterport),
+            ("https://www.youtube.com/watch?v=abc", Provider::Youtube),
+            ("https://YOUTU.BE/abc", Provider::Youtube),
+        ] {
+            assert_eq!(Url::new(url).map(|u| u.provider()), Some(provider));
+        }
+    }
+
+    #[test]
+    fn rejects_disallowed_urls() {
+        for url in [
+            "http://www.youtube.com/watch?v=abc",
+            "https://vimeo.com/123",
+            "https://youtube.com.evil.com/watch?v=abc",
+            "https://youtube.com@evil.com/watch?v=abc",
+            "https://youtube.com:8080/watch?v=abc",
+            "https://youtube.com/",
+            "https://youtube.com",
+        ] {
+            assert!(Url::new(url).is_none(), "`{url}` is accepted");
+        }
+    }
+}
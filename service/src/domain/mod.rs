@@ -1,7 +1,39 @@
 //! Domain definitions.
 
+pub mod agency;
+pub mod arrears;
+pub mod audit;
+pub mod branch;
+pub mod calendar;
+pub mod charge;
 pub mod contract;
+pub mod floor_plan;
+pub mod ical;
+pub mod insurance;
+pub mod inventory;
+pub mod journal;
+pub mod key;
+pub mod late_fee;
+pub mod lead;
+pub mod listing;
+pub mod media_link;
+pub mod offer;
+pub mod ownership;
+pub mod payment;
 pub mod realty;
+pub mod review;
+pub mod saved_search;
+pub mod sitemap;
+pub mod sms;
+pub mod task;
 pub mod user;
+pub mod version;
 
-pub use self::{contract::Contract, realty::Realty, user::User};
+pub use self::{
+    agency::Agency, arrears::Arrears, branch::Branch, calendar::Calendar,
+    charge::Charge, contract::Contract, floor_plan::FloorPlan,
+    insurance::Insurance, inventory::Inventory, key::Key, late_fee::LateFee,
+    lead::Lead, listing::Listing, media_link::MediaLink, offer::Offer,
+    ownership::Ownership, payment::Payment, realty::Realty, review::Review,
+    saved_search::SavedSearch, user::User, version::Version,
+};
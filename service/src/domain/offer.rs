@@ -0,0 +1,133 @@
+//! [`Offer`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf, Money};
+use derive_more::{Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, realty, user, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, Realty, User};
+
+/// Price offer of a [`User`] on a placed [`Realty`].
+#[derive(Clone, Copy, Debug)]
+pub struct Offer {
+    /// ID of this [`Offer`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Offer`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`contract::ManagementForRent`] or the
+    /// [`contract::ManagementForSale`] placing the [`Realty`].
+    pub contract_id: contract::Id,
+
+    /// ID of the placed [`Realty`].
+    pub realty_id: realty::Id,
+
+    /// ID of the [`User`] who submitted this [`Offer`].
+    pub bidder_id: user::Id,
+
+    /// ID of the [`User`] managing the [`Realty`], who decides upon this
+    /// [`Offer`].
+    pub employer_id: user::Id,
+
+    /// ID of the [`User`] who proposed this [`Offer`].
+    ///
+    /// Either the bidder, or the employer countering a previous [`Offer`].
+    pub proposer_id: user::Id,
+
+    /// ID of the previous [`Offer`] countered by this one, if any.
+    pub previous_id: Option<Id>,
+
+    /// ID of the [`contract::Rent`] or the [`contract::Sale`] concluded on the
+    /// terms of this [`Offer`], if any.
+    pub concluded_contract_id: Option<contract::Id>,
+
+    /// Offered price.
+    pub price: Money,
+
+    /// [`Status`] of this [`Offer`].
+    pub status: Status,
+
+    /// [`DateTime`] when this [`Offer`] was submitted.
+    pub created_at: CreationDateTime,
+
+    /// [`DateTime`] when this [`Offer`] was accepted or declined, if it was.
+    pub decided_at: Option<DecisionDateTime>,
+
+    /// [`Version`] of this [`Offer`].
+    pub version: Version,
+}
+
+impl Offer {
+    /// Returns ID of the [`User`] who decides upon this [`Offer`].
+    ///
+    /// This is the counterparty of the [`Offer::proposer_id`].
+    #[must_use]
+    pub fn decider_id(&self) -> user::Id {
+        if self.proposer_id == self.bidder_id {
+            self.employer_id
+        } else {
+            self.bidder_id
+        }
+    }
+}
+
+/// ID of an [`Offer`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+define_kind! {
+    #[doc = "Status of an [`Offer`]."]
+    enum Status {
+        #[doc = "[`Offer`] awaits a decision of the counterparty."]
+        Pending = 1,
+
+        #[doc = "[`Offer`] is accepted by the counterparty."]
+        Accepted = 2,
+
+        #[doc = "[`Offer`] is declined by the counterparty."]
+        Declined = 3,
+
+        #[doc = "[`Offer`] is countered by the counterparty with a new one."]
+        Countered = 4,
+    }
+}
+
+/// [`DateTime`] when an [`Offer`] was submitted.
+pub type CreationDateTime = DateTimeOf<(Offer, unit::Creation)>;
+
+/// Marker type indicating a decision upon an [`Offer`].
+#[derive(Clone, Copy, Debug)]
+pub struct Decision;
+
+/// [`DateTime`] when an [`Offer`] was accepted or declined.
+pub type DecisionDateTime = DateTimeOf<(Offer, Decision)>;
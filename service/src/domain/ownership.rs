@@ -0,0 +1,202 @@
+//! [`Ownership`] definitions.
+
+use std::collections::HashSet;
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{unit, DateTimeOf, Money, Percent};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::domain::{contract, realty, user};
+#[cfg(doc)]
+use crate::domain::{Realty, User};
+
+/// Share of a [`Realty`] owned by a [`User`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ownership {
+    /// ID of the owned [`Realty`].
+    pub realty_id: realty::Id,
+
+    /// ID of the [`User`] owning the [`Realty`].
+    pub owner_id: user::Id,
+
+    /// [`Percent`] share of the [`Realty`] owned by the [`User`].
+    pub share: Percent,
+}
+
+impl Ownership {
+    /// Indicates whether the provided [`Ownership`]s are a complete set of
+    /// co-owners of a [`Realty`].
+    ///
+    /// Every co-owner must be listed only once with a non-zero share, and the
+    /// shares must sum to `100%`.
+    #[must_use]
+    pub fn is_complete(ownerships: &[Self]) -> bool {
+        let mut owners = HashSet::with_capacity(ownerships.len());
+        let mut total = Percent::ZERO;
+        for o in ownerships {
+            if o.share == Percent::ZERO || !owners.insert(o.owner_id) {
+                return false;
+            }
+            let Some(sum) = total.checked_add(o.share) else {
+                return false;
+            };
+            total = sum;
+        }
+        total == Percent::HUNDRED
+    }
+
+    /// Splits the provided `amount` between the owners of the provided
+    /// [`Ownership`]s according to their shares.
+    ///
+    /// Parts are rounded to cents, and the rounding remainder is attributed
+    /// to the first owner, so the parts always sum to the `amount`.
+    #[must_use]
+    pub fn split(ownerships: &[Self], amount: Money) -> Vec<(user::Id, Money)> {
+        let mut parts = ownerships
+            .iter()
+            .map(|o| {
+                let mut part = o.share.of(amount);
+                part.amount = part.amount.round_dp_with_strategy(
+                    2,
+                    RoundingStrategy::MidpointAwayFromZero,
+                );
+                (o.owner_id, part)
+            })
+            .collect::<Vec<_>>();
+        let remainder = amount.amount
+            - parts.iter().map(|(_, m)| m.amount).sum::<Decimal>();
+        if let Some((_, first)) = parts.first_mut() {
+            first.amount += remainder;
+        }
+        parts
+    }
+}
+
+/// Transfer of a [`Realty`] ownership by a completed [`contract::Sale`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    /// ID of the [`contract::Sale`] transferring the ownership.
+    pub contract_id: contract::Id,
+
+    /// ID of the [`Realty`] which ownership is transferred.
+    pub realty_id: realty::Id,
+
+    /// ID of the landlord [`User`] selling the [`Realty`].
+    pub seller_id: user::Id,
+
+    /// ID of the purchaser [`User`] becoming the sole owner of the
+    /// [`Realty`].
+    pub buyer_id: user::Id,
+
+    /// [`DateTime`] when the ownership was transferred.
+    pub transferred_at: TransferDateTime,
+}
+
+impl Transfer {
+    /// Creates a new [`Transfer`] of the ownership by the provided completed
+    /// [`contract::Sale`].
+    ///
+    /// [`None`] is returned if the [`contract::Sale`] is not completed.
+    #[must_use]
+    pub fn of_sale(sale: &contract::Sale) -> Option<Self> {
+        Some(Self {
+            contract_id: sale.id,
+            realty_id: sale.realty_id,
+            seller_id: sale.landlord_id,
+            buyer_id: sale.purchaser_id,
+            transferred_at: sale.completed_at?.coerce(),
+        })
+    }
+
+    /// Returns the [`Ownership`] of the [`Realty`] after this [`Transfer`].
+    #[must_use]
+    pub fn ownership(&self) -> Ownership {
+        Ownership {
+            realty_id: self.realty_id,
+            owner_id: self.buyer_id,
+            share: Percent::HUNDRED,
+        }
+    }
+}
+
+/// [`DateTime`] when an [`Ownership`] was transferred.
+pub type TransferDateTime = DateTimeOf<(Transfer, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, Money, Percent};
+    use rust_decimal::Decimal;
+
+    use crate::domain::{realty, user};
+
+    use super::Ownership;
+
+    fn ownership(owner_id: user::Id, share: &str) -> Ownership {
+        Ownership {
+            realty_id: realty::Id::default(),
+            owner_id,
+            share: share.parse().unwrap(),
+        }
+    }
+
+    fn usd(amount: &str) -> Money {
+        Money {
+            amount: amount.parse().unwrap(),
+            currency: Currency::Usd,
+        }
+    }
+
+    #[test]
+    fn requires_shares_to_sum_to_hundred() {
+        let (a, b) = (user::Id::new(), user::Id::new());
+
+        assert!(Ownership::is_complete(&[ownership(a, "100")]));
+        assert!(Ownership::is_complete(&[
+            ownership(a, "62.5"),
+            ownership(b, "37.5"),
+        ]));
+
+        assert!(!Ownership::is_complete(&[]));
+        assert!(!Ownership::is_complete(&[
+            ownership(a, "50"),
+            ownership(b, "40"),
+        ]));
+        assert!(!Ownership::is_complete(&[
+            ownership(a, "60"),
+            ownership(b, "60"),
+        ]));
+        assert!(!Ownership::is_complete(&[
+            ownership(a, "50"),
+            ownership(a, "50"),
+        ]));
+        assert!(!Ownership::is_complete(&[
+            ownership(a, "100"),
+            Ownership {
+                share: Percent::ZERO,
+                ..ownership(b, "1")
+            },
+        ]));
+    }
+
+    #[test]
+    fn splits_amount_by_shares() {
+        let (a, b, c) = (user::Id::new(), user::Id::new(), user::Id::new());
+        let ownerships = [
+            ownership(a, "33.34"),
+            ownership(b, "33.33"),
+            ownership(c, "33.33"),
+        ];
+
+        let parts = Ownership::split(&ownerships, usd("100.01"));
+
+        assert_eq!(
+            parts,
+            [(a, usd("33.35")), (b, usd("33.33")), (c, usd("33.33"))],
+        );
+        assert_eq!(
+            parts.iter().map(|(_, m)| m.amount).sum::<Decimal>(),
+            usd("100.01").amount,
+        );
+    }
+}
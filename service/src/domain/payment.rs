@@ -0,0 +1,67 @@
+//! [`Payment`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{DateTimeOf, Money};
+use derive_more::{Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user};
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+
+/// Payment of a purchaser under a [`contract::Rent`], covering its monthly
+/// installments.
+#[derive(Clone, Copy, Debug)]
+pub struct Payment {
+    /// ID of this [`Payment`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Payment`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`contract::Rent`] this [`Payment`] is made under.
+    pub contract_id: contract::Id,
+
+    /// Paid amount, in the currency of the [`contract::Rent::price`].
+    pub amount: Money,
+
+    /// ID of the employer [`User`] who recorded this [`Payment`].
+    pub recorder_id: user::Id,
+
+    /// [`DateTime`] when this [`Payment`] was made.
+    pub paid_at: PaymentDateTime,
+}
+
+/// ID of a [`Payment`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// [`DateTime`] when a [`Payment`] was made.
+pub type PaymentDateTime = DateTimeOf<Payment>;
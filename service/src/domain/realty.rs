@@ -1,21 +1,34 @@
 //! [`Realty`] definitions.
 
+use std::ops::Range;
+
 #[cfg(doc)]
 use common::DateTime;
 use common::{define_kind, unit, DateTimeOf};
 use derive_more::{AsRef, Display, From, FromStr, Into};
 #[cfg(feature = "postgres")]
 use postgres_types::{FromSql, ToSql};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use xxhash_rust::xxh3;
 
+use crate::domain::{agency, user, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+
 /// Realty for rent or sale.
 #[derive(Clone, Debug)]
 pub struct Realty {
     /// ID of this [`Realty`].
     pub id: Id,
 
+    /// ID of the [`Agency`] this [`Realty`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the employed [`User`] responsible for this [`Realty`], if any.
+    pub assignee_id: Option<user::Id>,
+
     /// [`Hash`] of this [`Realty`] used for deduplication.
     ///
     /// [`Hash`]: struct@Hash
@@ -54,11 +67,17 @@ pub struct Realty {
     /// Room number of this [`Realty`], if any.
     pub room_num: Option<RoomNum>,
 
+    /// Floor [`Area`] of this [`Realty`], if known.
+    pub area: Option<Area>,
+
     /// [`DateTime`] when this [`Realty`] was created.
     pub created_at: CreationDateTime,
 
     /// [`DateTime`] when this [`Realty`] was deleted, if it was.
     pub deleted_at: Option<DeletionDateTime>,
+
+    /// [`Version`] of this [`Realty`].
+    pub version: Version,
 }
 
 impl Realty {
@@ -486,6 +505,48 @@ impl FromStr for RoomNum {
     }
 }
 
+/// Floor area of a [`Realty`] in square meters.
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Area(Decimal);
+
+impl Area {
+    /// Width of an [`Area::bucket`] in square meters.
+    pub const BUCKET_WIDTH: Decimal = Decimal::from_parts(25, 0, 0, false, 0);
+
+    /// Creates a new [`Area`] if the given square meters are positive.
+    #[must_use]
+    pub fn new(sq_meters: Decimal) -> Option<Self> {
+        (sq_meters > Decimal::ZERO).then_some(Self(sq_meters))
+    }
+
+    /// Returns the [`Area::BUCKET_WIDTH`] wide range of square meters this
+    /// [`Area`] falls into, so the [`Realty`]s of a similar size share the same
+    /// bucket.
+    #[must_use]
+    pub fn bucket(self) -> Range<Decimal> {
+        let start = (self.0 / Self::BUCKET_WIDTH).floor() * Self::BUCKET_WIDTH;
+        start..start + Self::BUCKET_WIDTH
+    }
+}
+
+impl From<Area> for Decimal {
+    fn from(area: Area) -> Self {
+        area.0
+    }
+}
+
+impl FromStr for Area {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s)
+            .ok()
+            .and_then(Self::new)
+            .ok_or("invalid `Area`")
+    }
+}
+
 define_kind! {
     #[doc = "Kind of a [`Realty`]."]
     enum Kind {
@@ -501,7 +562,13 @@ define_kind! {
 }
 
 /// Full address of a [`Realty`].
-#[derive(AsRef, Clone, Debug, Display, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only as a pagination cursor key, never stored"
+)]
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, Hash, PartialEq, Serialize,
+)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 #[as_ref(forward)]
 pub struct Address(String);
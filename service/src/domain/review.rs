@@ -0,0 +1,174 @@
+//! [`Review`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf};
+use derive_more::{AsRef, Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, contract, user, Version};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, User};
+
+/// Review of an employer left by a purchaser or a landlord of a completed
+/// [`Contract`].
+#[derive(Clone, Debug)]
+pub struct Review {
+    /// ID of this [`Review`].
+    pub id: Id,
+
+    /// ID of the [`Agency`] this [`Review`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// ID of the completed [`contract::Rent`] or [`contract::Sale`] this
+    /// [`Review`] is left on.
+    pub contract_id: contract::Id,
+
+    /// ID of the reviewed employer [`User`].
+    pub employer_id: user::Id,
+
+    /// ID of the [`User`] who left this [`Review`].
+    pub author_id: user::Id,
+
+    /// [`Rating`] of the employer.
+    pub rating: Rating,
+
+    /// [`Comment`] of the author, if any.
+    pub comment: Option<Comment>,
+
+    /// [`Status`] of this [`Review`].
+    pub status: Status,
+
+    /// [`DateTime`] when this [`Review`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`Version`] of this [`Review`].
+    pub version: Version,
+}
+
+/// ID of a [`Review`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Rating of an employer in a [`Review`], from `1` to `5`.
+#[derive(Clone, Copy, Debug, Display, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Rating(i16);
+
+impl Rating {
+    /// Lowest possible [`Rating`].
+    pub const MIN: Self = Self(1);
+
+    /// Highest possible [`Rating`].
+    pub const MAX: Self = Self(5);
+
+    /// Creates a new [`Rating`] if the given `rating` is within the
+    /// [`Rating::MIN`]..=[`Rating::MAX`] range.
+    #[must_use]
+    pub fn new(rating: i16) -> Option<Self> {
+        (Self::MIN.0..=Self::MAX.0)
+            .contains(&rating)
+            .then_some(Self(rating))
+    }
+
+    /// Returns the numeric value of this [`Rating`].
+    #[must_use]
+    pub const fn get(self) -> i16 {
+        self.0
+    }
+}
+
+/// Comment of a [`Review`].
+#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct Comment(String);
+
+impl Comment {
+    /// Creates a new [`Comment`].
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the given `comment` is not empty.
+    #[expect(unsafe_code, reason = "bypass")]
+    #[must_use]
+    pub unsafe fn new_unchecked(comment: impl Into<String>) -> Self {
+        Self(comment.into())
+    }
+
+    /// Creates a new [`Comment`] if the given `comment` is valid.
+    #[must_use]
+    pub fn new(comment: impl Into<String>) -> Option<Self> {
+        let comment = comment.into();
+        Self::check(&comment).then_some(Self(comment))
+    }
+
+    /// Checks whether the given `comment` is a valid [`Comment`].
+    fn check(comment: impl AsRef<str>) -> bool {
+        let comment = comment.as_ref();
+        comment.trim() == comment
+            && !comment.is_empty()
+            && comment.chars().count() <= 2000
+    }
+}
+
+impl FromStr for Comment {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Comment`")
+    }
+}
+
+define_kind! {
+    #[doc = "Moderation status of a [`Review`]."]
+    enum Status {
+        #[doc = "[`Review`] is visible and counted in the employer rating."]
+        Published = 1,
+
+        #[doc = "[`Review`] is hidden by a moderator."]
+        Hidden = 2,
+    }
+}
+
+/// [`DateTime`] when a [`Review`] was created.
+pub type CreationDateTime = DateTimeOf<(Review, unit::Creation)>;
+
+#[cfg(test)]
+mod spec {
+    use super::Rating;
+
+    #[test]
+    fn rating_is_within_range() {
+        assert!(Rating::new(0).is_none());
+        assert_eq!(Rating::new(1), Some(Rating::MIN));
+        assert_eq!(Rating::new(5), Some(Rating::MAX));
+        assert!(Rating::new(6).is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! [`SavedSearch`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf, Money};
+use derive_more::{Display, From, FromStr, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{agency, realty, user};
+#[cfg(doc)]
+use crate::{
+    domain::{contract, Agency, Realty, User},
+    read::Placement,
+};
+
+/// Filter of the [`Placement`]s saved by a [`User`] to be alerted about the
+/// newly placed ones matching it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavedSearch {
+    /// ID of this [`SavedSearch`].
+    pub id: Id,
+
+    /// ID of the [`User`] this [`SavedSearch`] belongs to.
+    pub user_id: user::Id,
+
+    /// ID of the [`Agency`] the [`Placement`]s are searched among.
+    pub agency_id: agency::Id,
+
+    /// [`realty::City`] of the placed [`Realty`]s to match.
+    ///
+    /// [`None`] means any [`realty::City`].
+    pub city: Option<realty::City>,
+
+    /// Minimal price (inclusive) of the [`Placement`]s to match.
+    ///
+    /// [`None`] means no lower bound.
+    pub min_price: Option<Money>,
+
+    /// Maximal price (inclusive) of the [`Placement`]s to match.
+    ///
+    /// [`None`] means no upper bound.
+    pub max_price: Option<Money>,
+
+    /// [`Kind`] of the [`Placement`]s to match.
+    ///
+    /// [`None`] means both.
+    pub kind: Option<Kind>,
+
+    /// [`DateTime`] when this [`SavedSearch`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`DateTime`] the [`User`] was last alerted about the [`Placement`]s
+    /// matching this [`SavedSearch`] by.
+    ///
+    /// Only the [`Placement`]s placed after it are matched.
+    pub alerted_at: AlertDateTime,
+}
+
+/// ID of a [`SavedSearch`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    FromStr,
+    Hash,
+    Into,
+    PartialEq,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+define_kind! {
+    #[doc = "Kind of the [`Placement`]s a [`SavedSearch`] matches."]
+    enum Kind {
+        #[doc = "[`Realty`] placed for rent by a \
+                 [`contract::ManagementForRent`]."]
+        Rent = 1,
+
+        #[doc = "[`Realty`] placed for sale by a \
+                 [`contract::ManagementForSale`]."]
+        Sale = 2,
+    }
+}
+
+/// [`DateTime`] when a [`SavedSearch`] was created.
+pub type CreationDateTime = DateTimeOf<(SavedSearch, unit::Creation)>;
+
+/// Marker type indicating an alert about the [`Placement`]s matching a
+/// [`SavedSearch`].
+#[derive(Clone, Copy, Debug)]
+pub struct Alert;
+
+/// [`DateTime`] the [`User`] was last alerted about the [`Placement`]s
+/// matching a [`SavedSearch`] by.
+pub type AlertDateTime = DateTimeOf<(SavedSearch, Alert)>;
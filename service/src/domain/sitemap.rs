@@ -0,0 +1,143 @@
+//! [Sitemap] definitions.
+//!
+//! [Sitemap]: https://www.sitemaps.org/protocol.html
+
+use common::DateTime;
+
+use crate::domain::{agency, realty};
+#[cfg(doc)]
+use crate::domain::{Agency, Realty};
+
+/// Maximum number of [`Entry`]s a single sitemap may contain.
+pub const MAX_ENTRIES: usize = 50_000;
+
+/// Maximum length of the human-readable part of an [`Entry::slug()`].
+const MAX_SLUG_LEN: usize = 80;
+
+/// Placed [`Realty`] listed in the sitemap of its [`Agency`].
+#[derive(Clone, Debug)]
+pub struct Entry {
+    /// ID of the [`Agency`] placing the [`Realty`].
+    pub agency_id: agency::Id,
+
+    /// ID of the placed [`Realty`].
+    pub realty_id: realty::Id,
+
+    /// [`realty::Address`] of the placed [`Realty`].
+    pub address: realty::Address,
+
+    /// [`DateTime`] when the placement of the [`Realty`] was last modified.
+    pub last_modified: DateTime,
+}
+
+impl Entry {
+    /// Returns the URL slug of the placed [`Realty`] (like
+    /// `uk-london-baker-street-221b-<realty ID>`).
+    ///
+    /// Only ASCII letters and digits of the [`realty::Address`] are kept, so
+    /// the slug is URL-safe. The trailing [`realty::Id`] keeps it unique and
+    /// stable, even if the [`realty::Address`] changes.
+    #[must_use]
+    pub fn slug(&self) -> String {
+        let mut slug = String::with_capacity(MAX_SLUG_LEN + 37);
+        for c in self.address.as_ref().chars() {
+            if slug.len() >= MAX_SLUG_LEN {
+                break;
+            }
+            if c.is_ascii_alphanumeric() {
+                slug.push(c.to_ascii_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        slug.push_str(&self.realty_id.to_string());
+        slug
+    }
+}
+
+/// Renders the provided [`Entry`]s as a sitemap XML, linking each of them as
+/// the [`Entry::slug()`] under the provided `base_url`.
+///
+/// Only the first [`MAX_ENTRIES`] are rendered.
+#[must_use]
+pub fn to_xml(base_url: &str, entries: &[Entry]) -> String {
+    let base_url = base_url.trim_end_matches('/');
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for e in entries.iter().take(MAX_ENTRIES) {
+        xml.push_str("  <url>\n    <loc>");
+        xml.push_str(&escape(&format!("{base_url}/{}", e.slug())));
+        xml.push_str("</loc>\n    <lastmod>");
+        xml.push_str(&e.last_modified.to_rfc3339());
+        xml.push_str("</lastmod>\n  </url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// Escapes the provided XML text value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+    use uuid::Uuid;
+
+    use crate::domain::realty;
+
+    use super::{to_xml, Entry};
+
+    fn entry(address: &str) -> Entry {
+        Entry {
+            agency_id: Uuid::nil().into(),
+            realty_id: Uuid::nil().into(),
+            address: realty::Address::new(address).unwrap(),
+            last_modified: DateTime::from_rfc3339("2024-03-05T12:00:00Z")
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn slugifies_address() {
+        let id = Uuid::nil();
+
+        assert_eq!(
+            entry("UK, London, Baker Street, 221B").slug(),
+            format!("uk-london-baker-street-221b-{id}"),
+        );
+        assert_eq!(entry("Москва, Тверская, 1").slug(), format!("1-{id}"));
+        assert_eq!(entry("Москва").slug(), id.to_string());
+    }
+
+    #[test]
+    fn renders_escaped_urls() {
+        let xml = to_xml("https://example.com/a&b/", &[entry("UK, London")]);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains(&format!(
+            "<loc>https://example.com/a&amp;b/uk-london-{}</loc>",
+            Uuid::nil(),
+        )));
+        assert!(xml.contains("<lastmod>2024-03-05T12:00:00Z</lastmod>"));
+        assert!(xml.ends_with("</urlset>\n"));
+    }
+}
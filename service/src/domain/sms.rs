@@ -0,0 +1,112 @@
+//! [`Delivery`] definitions.
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{define_kind, unit, DateTimeOf};
+use derive_more::{AsRef, Display, From, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use uuid::Uuid;
+
+use crate::domain::user;
+#[cfg(doc)]
+use crate::domain::User;
+
+/// Delivery of an SMS message to a [`user::Phone`], recorded along with its
+/// [`Status`] reported by an SMS gateway.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Delivery {
+    /// ID of this [`Delivery`].
+    pub id: Id,
+
+    /// [`user::Phone`] number the message is sent to.
+    pub phone: user::Phone,
+
+    /// [`ProviderId`] of the message, assigned by the SMS gateway.
+    ///
+    /// [`None`] if the message wasn't accepted by the SMS gateway.
+    pub provider_id: Option<ProviderId>,
+
+    /// Current [`Status`] of this [`Delivery`].
+    pub status: Status,
+
+    /// [`DateTime`] when this [`Delivery`] was created.
+    pub created_at: CreationDateTime,
+
+    /// [`DateTime`] when the [`Status`] of this [`Delivery`] was last updated.
+    pub updated_at: UpdateDateTime,
+}
+
+/// ID of a [`Delivery`].
+#[derive(
+    Clone, Copy, Debug, Default, Display, Eq, From, Hash, Into, PartialEq,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Id(Uuid);
+
+impl Id {
+    /// Creates a new random [`Id`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// ID of an SMS message, assigned by the SMS gateway it's sent via.
+#[derive(AsRef, Clone, Debug, Display, Eq, From, PartialEq)]
+#[as_ref(str, String)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct ProviderId(String);
+
+define_kind! {
+    #[doc = "Status of a [`Delivery`]."]
+    enum Status {
+        #[doc = "Message is logged instead of being sent, in sandbox mode."]
+        Sandboxed = 1,
+
+        #[doc = "Message is accepted by the SMS gateway."]
+        Queued = 2,
+
+        #[doc = "Message is sent to the carrier."]
+        Sent = 3,
+
+        #[doc = "Message is delivered to the [`User`] phone."]
+        Delivered = 4,
+
+        #[doc = "Message failed to be sent or delivered."]
+        Failed = 5,
+    }
+}
+
+impl Status {
+    /// Indicates whether this [`Status`] is final, so it cannot be changed
+    /// anymore.
+    #[must_use]
+    pub const fn is_final(self) -> bool {
+        matches!(self, Self::Sandboxed | Self::Delivered | Self::Failed)
+    }
+}
+
+/// [`DateTime`] when a [`Delivery`] was created.
+pub type CreationDateTime = DateTimeOf<(Delivery, unit::Creation)>;
+
+/// Marker type indicating an update of a [`Delivery`] [`Status`].
+#[derive(Clone, Copy, Debug)]
+pub struct StatusUpdate;
+
+/// [`DateTime`] when a [`Delivery`] [`Status`] was last updated.
+pub type UpdateDateTime = DateTimeOf<(Delivery, StatusUpdate)>;
+
+#[cfg(test)]
+mod spec {
+    use super::Status;
+
+    #[test]
+    fn final_statuses() {
+        assert!(!Status::Queued.is_final());
+        assert!(!Status::Sent.is_final());
+        assert!(Status::Delivered.is_final());
+        assert!(Status::Failed.is_final());
+        assert!(Status::Sandboxed.is_final());
+    }
+}
@@ -0,0 +1,94 @@
+//! Periodic task [`Settings`], [`Run`]s and [`DeadLetter`]s definitions.
+
+use std::time::Duration;
+
+use common::{define_kind, DateTime, DateTimeOf};
+
+/// Runtime-adjustable settings of a periodic task, overriding its
+/// configuration.
+#[derive(Clone, Copy, Debug)]
+pub struct Settings {
+    /// [`Kind`] of the task these [`Settings`] belong to.
+    pub task: Kind,
+
+    /// Interval between the task runs.
+    ///
+    /// [`None`] means the configured one.
+    pub interval: Option<Duration>,
+
+    /// Task-specific timeout (for example, the one after which a `Realty` is
+    /// considered unused).
+    ///
+    /// [`None`] means the configured one.
+    pub timeout: Option<Duration>,
+
+    /// [`DateTime`] when these [`Settings`] were updated.
+    pub updated_at: UpdateDateTime,
+}
+
+impl Settings {
+    /// Creates new empty [`Settings`] of the provided [`Kind`] of task,
+    /// keeping its configuration intact.
+    #[must_use]
+    pub fn new(task: Kind) -> Self {
+        Self {
+            task,
+            interval: None,
+            timeout: None,
+            updated_at: UpdateDateTime::now(),
+        }
+    }
+}
+
+define_kind! {
+    #[doc = "Kind of a periodic task with adjustable [`Settings`]."]
+    enum Kind {
+        #[doc = "`CleanUnusedRealties` task."]
+        CleanUnusedRealties = 1,
+    }
+}
+
+/// Marker type indicating [`Settings`] update.
+#[derive(Clone, Copy, Debug)]
+pub struct Update;
+
+/// [`DateTime`] when [`Settings`] were updated.
+pub type UpdateDateTime = DateTimeOf<(Settings, Update)>;
+
+/// Finished run of a periodic task, kept as its execution history.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// Name of the task.
+    pub task: String,
+
+    /// [`DateTime`] when this [`Run`] has started.
+    pub started_at: DateTime,
+
+    /// Duration of this [`Run`].
+    pub duration: Duration,
+
+    /// Number of the items (rows, documents, etc.) processed by this [`Run`].
+    pub processed: u64,
+
+    /// Error of this [`Run`], if it has failed.
+    pub error: Option<String>,
+}
+
+/// Work item a periodic task has failed to process, kept until requeued.
+#[derive(Clone, Debug)]
+pub struct DeadLetter {
+    /// Name of the task.
+    pub task: String,
+
+    /// ID of the failed work item (a `Contract`, for example).
+    pub item: String,
+
+    /// Error of the last processing attempt.
+    pub error: String,
+
+    /// Number of the failed processing attempts.
+    pub attempts: u32,
+
+    /// [`DateTime`] of the last processing attempt.
+    pub failed_at: DateTime,
+}
@@ -0,0 +1,378 @@
+//! CSV representation of [`User`]s, for their bulk import and export.
+
+use std::{borrow::Cow, collections::HashSet, fmt::Write as _, mem};
+
+use derive_more::Display;
+
+use crate::domain::{user, User};
+
+/// Header line of the CSV representation of [`User`]s.
+pub const HEADER: &str = "name,login,email,phone";
+
+/// Characters making spreadsheet applications evaluate a cell starting with
+/// them as a formula.
+const FORMULA_TRIGGERS: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// [`User`] data parsed from a CSV line.
+#[derive(Clone, Debug)]
+pub struct Row {
+    /// [`user::Name`] of the [`User`].
+    pub name: user::Name,
+
+    /// [`user::Login`] of the [`User`].
+    pub login: user::Login,
+
+    /// [`user::Email`] of the [`User`].
+    pub email: Option<user::Email>,
+
+    /// [`user::Phone`] of the [`User`].
+    pub phone: Option<user::Phone>,
+}
+
+/// Violation of a CSV line, preventing it from being parsed.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+#[display("line {line}: {reason}")]
+pub struct Violation {
+    /// Number of the violating line, starting from `1`.
+    pub line: usize,
+
+    /// [`Reason`] of this [`Violation`].
+    pub reason: Reason,
+}
+
+/// Reason of a [`Violation`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Reason {
+    /// Login is already used by a previous line.
+    #[display("duplicate login")]
+    DuplicateLogin,
+
+    /// Number of fields differs from the number of header columns.
+    #[display("wrong number of fields")]
+    FieldsCount,
+
+    /// Email is not a valid [`user::Email`].
+    #[display("invalid email")]
+    InvalidEmail,
+
+    /// Login is not a valid [`user::Login`].
+    #[display("invalid login")]
+    InvalidLogin,
+
+    /// Name is not a valid [`user::Name`].
+    #[display("invalid name")]
+    InvalidName,
+
+    /// Phone is not a valid [`user::Phone`].
+    #[display("invalid phone")]
+    InvalidPhone,
+
+    /// Required column is missing in the header.
+    #[display("missing `{_0}` column")]
+    MissingColumn(&'static str),
+
+    /// Neither email, nor phone is provided.
+    #[display("no email or phone")]
+    NoContactInfo,
+
+    /// Quoted field is not closed until the end of the line.
+    #[display("unterminated quoted field")]
+    UnterminatedQuote,
+}
+
+/// Parses the provided `csv` into [`Row`]s.
+///
+/// The first non-empty line is a header, naming the columns in any order.
+/// `name` and `login` columns are required, while `email` and `phone` ones
+/// are optional, and unknown columns are ignored. Fields may be quoted, as
+/// described in the [RFC 4180], but must not span multiple lines. `'` prefix
+/// guarding a field from being evaluated as a formula (see [`render`]) is
+/// removed.
+///
+/// # Errors
+///
+/// With all the found [`Violation`]s, one per line at most.
+///
+/// [RFC 4180]: https://datatracker.ietf.org/doc/html/rfc4180
+pub fn parse(csv: &str) -> Result<Vec<Row>, Vec<Violation>> {
+    let mut lines = csv
+        .split('\n')
+        .map(|l| l.strip_suffix('\r').unwrap_or(l))
+        .enumerate()
+        .map(|(n, l)| (n + 1, l))
+        .filter(|(_, l)| !l.trim().is_empty());
+
+    let Some((line, header)) = lines.next() else {
+        return Ok(vec![]);
+    };
+    let header = fields(header)
+        .map_err(|reason| vec![Violation { line, reason }])?
+        .into_iter()
+        .map(|c| c.trim().to_lowercase())
+        .collect::<Vec<_>>();
+    let column = |name| header.iter().position(|c| c == name);
+    let (Some(name_idx), Some(login_idx)) = (column("name"), column("login"))
+    else {
+        let missing = if column("name").is_none() {
+            "name"
+        } else {
+            "login"
+        };
+        return Err(vec![Violation {
+            line,
+            reason: Reason::MissingColumn(missing),
+        }]);
+    };
+    let (email_idx, phone_idx) = (column("email"), column("phone"));
+
+    let mut logins = HashSet::new();
+    let mut rows = vec![];
+    let mut violations = vec![];
+    for (line, l) in lines {
+        let row = fields(l).and_then(|fields| {
+            if fields.len() != header.len() {
+                return Err(Reason::FieldsCount);
+            }
+            let value = |i: usize| unguard(fields[i].trim());
+            let field =
+                |idx: Option<usize>| idx.map(value).filter(|f| !f.is_empty());
+
+            let row = Row {
+                name: user::Name::new(value(name_idx))
+                    .ok_or(Reason::InvalidName)?,
+                login: user::Login::new(value(login_idx))
+                    .ok_or(Reason::InvalidLogin)?,
+                email: field(email_idx)
+                    .map(|e| user::Email::new(e).ok_or(Reason::InvalidEmail))
+                    .transpose()?,
+                phone: field(phone_idx)
+                    .map(|p| user::Phone::new(p).ok_or(Reason::InvalidPhone))
+                    .transpose()?,
+            };
+            if row.email.is_none() && row.phone.is_none() {
+                return Err(Reason::NoContactInfo);
+            }
+            if !logins.insert(row.login.to_string()) {
+                return Err(Reason::DuplicateLogin);
+            }
+            Ok(row)
+        });
+        match row {
+            Ok(row) => rows.push(row),
+            Err(reason) => violations.push(Violation { line, reason }),
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(rows)
+    } else {
+        Err(violations)
+    }
+}
+
+/// Renders the provided [`User`]s as CSV, which may be [`parse`]d back.
+///
+/// Fields starting with a formula character (`=`, `+`, `-`, `@`, etc.) are
+/// prefixed with `'`, so spreadsheet applications don't evaluate them (see
+/// [CSV injection]).
+///
+/// [CSV injection]: https://owasp.org/www-community/attacks/CSV_Injection
+#[must_use]
+pub fn render(users: &[User]) -> String {
+    let mut csv = format!("{HEADER}\r\n");
+    for u in users {
+        let email = u.email.as_ref().map(ToString::to_string);
+        let phone = u.phone.as_ref().map(ToString::to_string);
+        _ = write!(
+            csv,
+            "{},{},{},{}\r\n",
+            escape(&u.name.to_string()),
+            escape(&u.login.to_string()),
+            escape(email.as_deref().unwrap_or_default()),
+            escape(phone.as_deref().unwrap_or_default()),
+        );
+    }
+    csv
+}
+
+/// Splits the provided CSV `line` into its (unquoted) fields.
+fn fields(line: &str) -> Result<Vec<String>, Reason> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                field.push('"');
+                _ = chars.next();
+            }
+            (true, '"') => quoted = false,
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => fields.push(mem::take(&mut field)),
+            (_, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(Reason::UnterminatedQuote);
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+/// Escapes the provided CSV `field`, quoting it, if necessary, and guarding
+/// it from being evaluated as a formula.
+fn escape(field: &str) -> String {
+    let field = if field.starts_with(FORMULA_TRIGGERS) {
+        Cow::Owned(format!("'{field}"))
+    } else {
+        Cow::Borrowed(field)
+    };
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.into_owned()
+    }
+}
+
+/// Removes the `'` prefix guarding the provided CSV `field` from being
+/// evaluated as a formula, if any.
+fn unguard(field: &str) -> &str {
+    field
+        .strip_prefix('\'')
+        .filter(|f| f.starts_with(FORMULA_TRIGGERS))
+        .unwrap_or(field)
+}
+
+#[cfg(test)]
+mod spec {
+    use common::DateTime;
+
+    use crate::domain::{user, User};
+
+    use super::{parse, render, Reason, Violation};
+
+    #[test]
+    fn parses_rows() {
+        let violations = parse(
+            "Phone,Login,Name\r\n\
+             555-123-4567,johndoe,\"Doe, John\"\r\n\
+             \r\n\
+             ,janedoe,\"Jane \"\"JD\"\" Doe\"\r\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            violations,
+            [Violation {
+                line: 4,
+                reason: Reason::NoContactInfo,
+            }],
+        );
+
+        let rows = parse(
+            "name,login,email,phone\n\
+             \"Doe, John\",johndoe,John@Example.com,\n\
+             \"Jane \"\"JD\"\" Doe\",janedoe,,555-123-4567\n",
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name.to_string(), "Doe, John");
+        assert_eq!(
+            rows[0].email.as_ref().map(ToString::to_string).as_deref(),
+            Some("John@example.com"),
+        );
+        assert!(rows[0].phone.is_none());
+        assert_eq!(rows[1].name.to_string(), "Jane \"JD\" Doe");
+        assert!(rows[1].email.is_none());
+    }
+
+    #[test]
+    fn reports_violations() {
+        assert_eq!(
+            parse("name,email\nJohn,john@example.com").unwrap_err(),
+            [Violation {
+                line: 1,
+                reason: Reason::MissingColumn("login"),
+            }],
+        );
+
+        let violations = parse(
+            "name,login,email\n\
+             John,johndoe,john@example.com\n\
+             John,johndoe,john@example.com\n\
+             John,john doe,john@example.com\n\
+             John,johnny,john\n\
+             John,johnny\n\
+             \"John,johnny,john@example.com\n",
+        )
+        .unwrap_err();
+        assert_eq!(
+            violations
+                .iter()
+                .map(|v| (v.line, v.reason))
+                .collect::<Vec<_>>(),
+            [
+                (3, Reason::DuplicateLogin),
+                (4, Reason::InvalidLogin),
+                (5, Reason::InvalidEmail),
+                (6, Reason::FieldsCount),
+                (7, Reason::UnterminatedQuote),
+            ],
+        );
+    }
+
+    #[test]
+    fn renders_parsable_rows() {
+        let user = User {
+            id: user::Id::new(),
+            name: user::Name::new("Doe, \"John\"").unwrap(),
+            login: user::Login::new("johndoe").unwrap(),
+            password_hash: user::PasswordHash::new(
+                &user::Password::new("secret").unwrap(),
+            ),
+            email: None,
+            phone: user::Phone::new("555-123-4567"),
+            created_at: DateTime::now().coerce(),
+            deleted_at: None,
+        };
+
+        let csv = render(&[user]);
+        assert_eq!(
+            csv,
+            "name,login,email,phone\r\n\
+             \"Doe, \"\"John\"\"\",johndoe,,555-123-4567\r\n",
+        );
+
+        let rows = parse(&csv).unwrap();
+        assert_eq!(rows[0].name.to_string(), "Doe, \"John\"");
+        assert_eq!(rows[0].login.to_string(), "johndoe");
+    }
+
+    #[test]
+    fn guards_formulas() {
+        let name = r#"=HYPERLINK("https://evil.example","Click")"#;
+        let user = User {
+            id: user::Id::new(),
+            name: user::Name::new(name).unwrap(),
+            login: user::Login::new("johndoe").unwrap(),
+            password_hash: user::PasswordHash::new(
+                &user::Password::new("secret").unwrap(),
+            ),
+            email: user::Email::new("john@example.com"),
+            phone: None,
+            created_at: DateTime::now().coerce(),
+            deleted_at: None,
+        };
+
+        let csv = render(&[user]);
+        assert_eq!(
+            csv,
+            "name,login,email,phone\r\n\
+             \"'=HYPERLINK(\"\"https://evil.example\"\",\"\"Click\"\")\",\
+             johndoe,john@example.com,\r\n",
+        );
+
+        let rows = parse(&csv).unwrap();
+        assert_eq!(rows[0].name.to_string(), name);
+    }
+}
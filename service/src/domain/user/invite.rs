@@ -0,0 +1,85 @@
+//! [`Invite`] definitions.
+
+use std::str::FromStr;
+
+#[cfg(doc)]
+use common::DateTime;
+use common::{unit, DateTimeOf};
+use derive_more::{AsRef, Display};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use rand::Rng as _;
+use sha2::{Digest as _, Sha256};
+
+#[cfg(doc)]
+use crate::domain::{Agency, User};
+use crate::domain::{agency, contract::Expiration, user};
+
+/// Invite of a [`User`] created without a password, letting the [`User`] to
+/// set one by the secret [`Token`] of the [`Invite`].
+#[derive(Clone, Debug)]
+pub struct Invite {
+    /// ID of the invited [`User`].
+    pub user_id: user::Id,
+
+    /// ID of the [`Agency`] the [`User`] is invited into.
+    pub agency_id: agency::Id,
+
+    /// [`TokenHash`] of the [`Token`] this [`Invite`] is accepted with.
+    pub token_hash: TokenHash,
+
+    /// [`DateTime`] when this [`Invite`] expires.
+    pub expires_at: ExpirationDateTime,
+
+    /// [`DateTime`] when this [`Invite`] was created.
+    pub created_at: CreationDateTime,
+}
+
+/// Secret token of an [`Invite`], embedded into its link.
+#[derive(AsRef, Clone, Debug, Display)]
+#[as_ref(str)]
+pub struct Token(String);
+
+impl Token {
+    /// Generates a new random [`Token`].
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(hex::encode(rand::thread_rng().gen::<[u8; 32]>()))
+    }
+
+    /// Creates a new [`Token`] if the given `token` is valid.
+    #[must_use]
+    pub fn new(token: impl Into<String>) -> Option<Self> {
+        let token = token.into();
+        let is_valid = token.len() == 64
+            && token.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'));
+        is_valid.then_some(Self(token))
+    }
+
+    /// Computes the [`TokenHash`] of this [`Token`], so the [`Token`] itself
+    /// is never stored.
+    #[must_use]
+    pub fn hash(&self) -> TokenHash {
+        TokenHash(hex::encode(Sha256::digest(self.0.as_bytes())))
+    }
+}
+
+impl FromStr for Token {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or("invalid `Token`")
+    }
+}
+
+/// SHA-256 hash of a [`Token`].
+#[derive(AsRef, Clone, Debug, Eq, PartialEq)]
+#[as_ref(str)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+pub struct TokenHash(String);
+
+/// [`DateTime`] when an [`Invite`] expires.
+pub type ExpirationDateTime = DateTimeOf<(Invite, Expiration)>;
+
+/// [`DateTime`] when an [`Invite`] was created.
+pub type CreationDateTime = DateTimeOf<(Invite, unit::Creation)>;
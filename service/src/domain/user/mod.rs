@@ -1,5 +1,8 @@
 //! [`User`] definitions.
 
+pub mod csv;
+pub mod invite;
+pub mod password;
 pub mod session;
 
 use std::sync::LazyLock;
@@ -15,10 +18,10 @@ use secrecy::{zeroize::Zeroize, CloneableSecret};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-pub use self::session::Session;
+pub use self::{invite::Invite, session::Session};
 
 /// Platform user.
-#[derive(Clone, Debug, From)]
+#[derive(Clone, Debug, Deserialize, From, Serialize)]
 pub struct User {
     /// ID of this [`User`]
     pub id: Id,
@@ -73,7 +76,13 @@ impl Id {
 }
 
 /// Name of a [`User`].
-#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize,
+)]
 #[as_ref(str, String)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Name(String);
@@ -113,7 +122,11 @@ impl FromStr for Name {
 }
 
 /// Login of a [`User`].
-#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Login(String);
 
@@ -161,6 +174,18 @@ impl FromStr for Login {
     }
 }
 
+/// Identifier of a [`User`] to create a [`Session`] with.
+#[derive(Clone, Debug, Display, From)]
+pub enum Identifier {
+    /// [`Login`] of a [`User`].
+    Login(Login),
+
+    /// [`Email`] of a [`User`].
+    ///
+    /// Identifies a [`User`] only if no other [`User`] has the same [`Email`].
+    Email(Email),
+}
+
 /// Password of a [`User`].
 #[derive(Clone, Debug, Display, Eq, From, PartialEq)]
 #[from(&str, String)]
@@ -208,7 +233,7 @@ impl Zeroize for Password {
 }
 
 /// Password hash of a [`User`].
-#[derive(Clone, Debug, Display, Eq, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct PasswordHash(String);
 
@@ -222,7 +247,13 @@ impl PasswordHash {
 }
 
 /// Email address of a [`User`].
-#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize,
+)]
 #[as_ref(str, String)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Email(String);
@@ -239,39 +270,39 @@ impl Email {
         Self(address.into())
     }
 
+    /// Maximum length of an [`Email`] address, as limited by the [RFC 5321]
+    /// forward-path.
+    ///
+    /// [RFC 5321]: https://datatracker.ietf.org/doc/html/rfc5321#section-4.5.3.1.3
+    pub const MAX_LENGTH: usize = 254;
+
     /// Creates a new [`Email`] if the given `address` is valid.
+    ///
+    /// The domain part of the `address` is lowercased, since it's
+    /// case-insensitive, while the local part is preserved as is.
     #[must_use]
-    pub fn new(address: impl Into<String>) -> Option<Self> {
-        let address = address.into();
-        Self::check(&address).then_some(Self(address))
+    pub fn new(address: impl AsRef<str>) -> Option<Self> {
+        let address = address.as_ref();
+        if address.len() > Self::MAX_LENGTH {
+            return None;
+        }
+
+        let parsed = email_address::EmailAddress::parse_with_options(
+            address,
+            email_address::Options::default().without_display_text(),
+        )
+        .ok()?;
+        Some(Self(format!(
+            "{}@{}",
+            parsed.local_part(),
+            parsed.domain().to_lowercase(),
+        )))
     }
 
-    /// Checks whether the given `address` is a valid [`Email`].
-    fn check(address: impl AsRef<str>) -> bool {
-        /// Regular expression checking [`Email`] format.
-        static REGEX: LazyLock<Regex> = LazyLock::new(|| {
-            Regex::new(
-                "^([^\\x00-\\x20\\x22\\x28\\x29\\x2c\\x2e\\x3a-\
-                     \\x3c\\x3e\\x40\\x5b-\\x5d\\x7f-\\xff]+\
-                  |\\x22([^\\x0d\\x22\\x5c\\x80-\\xff]\
-                  |\\x5c[\\x00-\\x7f])*\\x22)\
-                  (\\x2e([^\\x00-\\x20\\x22\\x28\\x29\\x2c\\x2e\\x3a-\
-                           \\x3c\\x3e\\x40\\x5b-\\x5d\\x7f-\\xff]+\
-                        |\\x22([^\\x0d\\x22\\x5c\\x80-\\xff]\
-                        |\\x5c[\\x00-\\x7f])*\\x22))*\\x40\
-                  ([^\\x00-\\x20\\x22\\x28\\x29\\x2c\\x2e\\x3a-\
-                     \\x3c\\x3e\\x40\\x5b-\\x5d\\x7f-\\xff]+\
-                  |\\x5b([^\\x0d\\x5b-\\x5d\\x80-\\xff]\
-                        |\\x5c[\\x00-\\x7f])*\\x5d)\
-                  (\\x2e([^\\x00-\\x20\\x22\\x28\\x29\\x2c\\x2e\\x3a-\
-                           \\x3c\\x3e\\x40\\x5b-\\x5d\\x7f-\\xff]+\
-                        |\\x5b([^\\x0d\\x5b-\\x5d\\x80-\\xff]\
-                        |\\x5c[\\x00-\\x7f])*\\x5d))*$",
-            )
-            .expect("valid regex")
-        });
-
-        REGEX.is_match(address.as_ref())
+    /// Returns the domain part of this [`Email`].
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
     }
 }
 
@@ -284,7 +315,13 @@ impl FromStr for Email {
 }
 
 /// Phone number of a [`User`].
-#[derive(AsRef, Clone, Debug, Display, Eq, PartialEq)]
+#[expect(
+    clippy::unsafe_derive_deserialize,
+    reason = "deserialized only from `Cache` populated by `Service` itself"
+)]
+#[derive(
+    AsRef, Clone, Debug, Deserialize, Display, Eq, PartialEq, Serialize,
+)]
 #[as_ref(str, String)]
 #[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
 pub struct Phone(String);
@@ -333,3 +370,31 @@ pub type CreationDateTime = DateTimeOf<(User, unit::Creation)>;
 
 /// [`DateTime`] when a [`User`] was deleted.
 pub type DeletionDateTime = DateTimeOf<(User, unit::Deletion)>;
+
+#[cfg(test)]
+mod spec {
+    use super::Email;
+
+    #[test]
+    fn parses_email() {
+        let email = Email::new("John.Doe@Example.COM").unwrap();
+        assert_eq!(email.to_string(), "John.Doe@example.com");
+        assert_eq!(email.domain(), "example.com");
+
+        assert!(Email::new("\"john doe\"@example.com").is_some());
+        assert!(Email::new("john@[127.0.0.1]").is_some());
+
+        assert!(Email::new("").is_none());
+        assert!(Email::new("john").is_none());
+        assert!(Email::new("john@").is_none());
+        assert!(Email::new("John <john@example.com>").is_none());
+        assert!(Email::new(format!("{}@example.com", "a".repeat(65)))
+            .is_none());
+        assert!(Email::new(format!(
+            "{}@{}.com",
+            "a".repeat(64),
+            ["b".repeat(63), "c".repeat(63), "d".repeat(60)].join("."),
+        ))
+        .is_none());
+    }
+}
@@ -0,0 +1,115 @@
+//! [`Policy`] definitions.
+
+use derive_more::Display;
+use smart_default::SmartDefault;
+
+use crate::domain::user::Password;
+
+/// Policy of [`Password`]s strength.
+#[derive(Clone, Copy, Debug, SmartDefault)]
+pub struct Policy {
+    /// Minimal number of characters in a [`Password`].
+    #[default(2)]
+    pub min_length: usize,
+
+    /// Indicator whether a [`Password`] must contain a lowercase letter.
+    pub require_lowercase: bool,
+
+    /// Indicator whether a [`Password`] must contain an uppercase letter.
+    pub require_uppercase: bool,
+
+    /// Indicator whether a [`Password`] must contain a digit.
+    pub require_digit: bool,
+
+    /// Indicator whether a [`Password`] must contain a character being
+    /// neither a letter, nor a digit.
+    pub require_special: bool,
+}
+
+impl Policy {
+    /// Returns [`Rule`]s of this [`Policy`] violated by the provided
+    /// [`Password`].
+    ///
+    /// [`Rule::Breached`] is never returned, since it requires an external
+    /// check.
+    #[must_use]
+    pub fn violations(&self, password: &Password) -> Vec<Rule> {
+        let password = password.0.as_str();
+        let has = |f: fn(char) -> bool| password.chars().any(f);
+
+        [
+            (password.chars().count() < self.min_length, Rule::MinLength),
+            (
+                self.require_lowercase && !has(char::is_lowercase),
+                Rule::Lowercase,
+            ),
+            (
+                self.require_uppercase && !has(char::is_uppercase),
+                Rule::Uppercase,
+            ),
+            (self.require_digit && !has(char::is_numeric), Rule::Digit),
+            (
+                self.require_special && !has(|c| !c.is_alphanumeric()),
+                Rule::Special,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(violated, rule)| violated.then_some(rule))
+        .collect()
+    }
+}
+
+/// Rule of a [`Policy`] a [`Password`] may violate.
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq)]
+pub enum Rule {
+    /// [`Password`] is shorter than the [`Policy::min_length`].
+    #[display("MIN_LENGTH")]
+    MinLength,
+
+    /// [`Password`] contains no lowercase letters.
+    #[display("LOWERCASE")]
+    Lowercase,
+
+    /// [`Password`] contains no uppercase letters.
+    #[display("UPPERCASE")]
+    Uppercase,
+
+    /// [`Password`] contains no digits.
+    #[display("DIGIT")]
+    Digit,
+
+    /// [`Password`] contains no special characters.
+    #[display("SPECIAL")]
+    Special,
+
+    /// [`Password`] is known to be breached.
+    #[display("BREACHED")]
+    Breached,
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::domain::user::Password;
+
+    use super::{Policy, Rule};
+
+    #[test]
+    fn lists_violations() {
+        let policy = Policy {
+            min_length: 8,
+            require_lowercase: true,
+            require_uppercase: true,
+            require_digit: true,
+            require_special: true,
+        };
+
+        assert_eq!(
+            policy.violations(&Password::from("pass")),
+            [Rule::MinLength, Rule::Uppercase, Rule::Digit, Rule::Special],
+        );
+        assert!(policy.violations(&Password::from("Пароль_123")).is_empty());
+        assert!(Policy::default()
+            .violations(&Password::from("pass"))
+            .is_empty());
+    }
+}
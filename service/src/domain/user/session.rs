@@ -16,6 +16,13 @@ pub struct Session {
     /// ID of the [`User`] this [`Session`] belongs to.
     pub user_id: user::Id,
 
+    /// ID of the admin [`User`] impersonating the [`Session::user_id`] one
+    /// via this [`Session`].
+    ///
+    /// [`None`] if this [`Session`] is not an impersonating one.
+    #[serde(default, rename = "imp", skip_serializing_if = "Option::is_none")]
+    pub impersonated_by: Option<user::Id>,
+
     /// [`DateTime`] when this [`Session`] expires.
     #[serde(rename = "exp", with = "common::datetime::serde::unix_timestamp")]
     pub expires_at: ExpirationDateTime,
@@ -0,0 +1,47 @@
+//! [`Version`] definitions.
+
+use derive_more::{Display, From, Into};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+
+#[cfg(doc)]
+use crate::domain::{Contract, Realty};
+
+/// Version of a mutable entity (like a [`Contract`] or a [`Realty`]),
+/// incremented on each its update.
+///
+/// Used for optimistic concurrency control: an entity is updated only if its
+/// stored [`Version`] still matches the one it was read with.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Display,
+    Eq,
+    From,
+    Hash,
+    Into,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[cfg_attr(feature = "postgres", derive(ToSql, FromSql), postgres(transparent))]
+pub struct Version(i32);
+
+impl Version {
+    /// Returns the [`Version`] of a newly created entity.
+    #[must_use]
+    pub const fn initial() -> Self {
+        Self(0)
+    }
+
+    /// Returns the [`Version`] following this one.
+    #[must_use]
+    pub const fn next(self) -> Self {
+        Self(self.0.wrapping_add(1))
+    }
+}
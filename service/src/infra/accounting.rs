@@ -0,0 +1,34 @@
+//! [`Accounting`]-related definitions.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError};
+use tracerr::Traced;
+
+use crate::domain::journal::Entry;
+#[cfg(doc)]
+use crate::{domain::Payment, task::ExportJournal};
+
+/// External accounting system (`QuickBooks`, `Xero`, etc), the journal
+/// [`Entry`]s of the recorded [`Payment`]s are pushed into by the
+/// [`ExportJournal`] task.
+#[async_trait]
+pub trait Accounting: fmt::Debug + Send + Sync {
+    /// Pushes the provided journal [`Entry`]s into this [`Accounting`]
+    /// system.
+    ///
+    /// The same [`Entry`]s may be pushed more than once, in case the previous
+    /// push wasn't recorded, so implementations should deduplicate them by
+    /// their [`Entry::payment_id`].
+    ///
+    /// # Errors
+    ///
+    /// If failed to push the [`Entry`]s into this [`Accounting`] system.
+    async fn push(&self, entries: &[Entry]) -> Result<(), Traced<Error>>;
+}
+
+/// [`Accounting`] error.
+#[derive(Debug, Display, StdError)]
+#[display("{_0}")]
+pub struct Error(#[error(not(source))] pub String);
@@ -0,0 +1,121 @@
+//! [`Cache`]-related implementations.
+
+#[cfg(feature = "redis")]
+pub mod redis;
+
+use std::{fmt, time::Duration};
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::infra::Database;
+
+#[cfg(feature = "redis")]
+pub use self::redis::Redis;
+
+/// Key-value cache of serialized values, sitting in front of a [`Database`].
+///
+/// [`Cache`] is never a source of truth: any of its errors may be safely
+/// ignored by falling back to the [`Database`].
+#[async_trait]
+pub trait Cache: fmt::Debug + Send + Sync {
+    /// Returns the value stored under the provided `key`, if any.
+    ///
+    /// # Errors
+    ///
+    /// If failed to read the value from this [`Cache`].
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Traced<Error>>;
+
+    /// Stores the provided `value` under the provided `key`, expiring it after
+    /// the provided `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// If failed to write the value into this [`Cache`].
+    async fn set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> Result<(), Traced<Error>>;
+
+    /// Removes values stored under the provided `keys`.
+    ///
+    /// # Errors
+    ///
+    /// If failed to remove the values from this [`Cache`].
+    async fn delete(&self, keys: &[String]) -> Result<(), Traced<Error>>;
+
+    /// Removes all values which keys start with the provided `prefix`.
+    ///
+    /// # Errors
+    ///
+    /// If failed to remove the values from this [`Cache`].
+    async fn delete_prefixed(&self, prefix: &str) -> Result<(), Traced<Error>>;
+}
+
+/// [`Cache`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    #[cfg(feature = "redis")]
+    /// [`Redis`] error.
+    Redis(redis::Error),
+}
+
+pub mod key {
+    //! [`Cache`] keys of the cached [`Database`] selections.
+
+    use crate::domain::{agency, realty, user};
+    #[cfg(doc)]
+    use crate::{
+        domain::{contract, Agency, User},
+        infra::{Cache, Database},
+        read::{contract::Active, Placement},
+    };
+
+    /// Prefix of all the [`Placement`]s list pages keys.
+    pub const PLACEMENTS: &str = "placements:";
+
+    /// Returns key of a [`User`] with the provided [`user::Id`].
+    #[must_use]
+    pub fn user(id: user::Id) -> String {
+        format!("user:{id}")
+    }
+
+    /// Returns key of an [`Active`] [`contract::Employment`] of a [`User`]
+    /// with the provided [`user::Id`].
+    #[must_use]
+    pub fn employment(user_id: user::Id) -> String {
+        format!("employment:{user_id}")
+    }
+
+    /// Returns key of the first [`Placement`]s list page with the provided
+    /// parameters.
+    ///
+    /// [`None`] `agency_id` means the [`Placement`]s of all the [`Agency`]s.
+    #[must_use]
+    pub fn placements(
+        agency_id: Option<agency::Id>,
+        first: usize,
+        sale: bool,
+        rent: bool,
+    ) -> String {
+        let agency =
+            agency_id.map_or_else(|| "*".to_owned(), |id| id.to_string());
+        format!("{PLACEMENTS}{agency}:{first}:{sale}:{rent}")
+    }
+
+    /// Returns key of a public [`Placement`]s feed page of an [`Agency`] with
+    /// the provided parameters.
+    #[must_use]
+    pub fn placements_feed(
+        agency_id: agency::Id,
+        first: usize,
+        after: Option<realty::Id>,
+    ) -> String {
+        let after = after.map_or_else(|| "*".to_owned(), |id| id.to_string());
+        format!("{PLACEMENTS}feed:{agency_id}:{first}:{after}")
+    }
+}
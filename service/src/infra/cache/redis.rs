@@ -0,0 +1,128 @@
+//! Redis [`Cache`] implementation.
+
+use std::{fmt, time::Duration};
+
+use async_trait::async_trait;
+use futures::StreamExt as _;
+use redis::{aio::ConnectionManager, AsyncCommands as _, Client};
+use tokio::sync::OnceCell;
+use tracerr::Traced;
+
+use crate::infra::{cache, Cache};
+
+pub use redis::RedisError as Error;
+
+/// Redis [`Cache`] client.
+pub struct Redis {
+    /// [`Client`] to establish the [`ConnectionManager`] with.
+    client: Client,
+
+    /// Lazily established multiplexed connection.
+    conn: OnceCell<ConnectionManager>,
+}
+
+impl fmt::Debug for Redis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Redis")
+            .field("connected", &self.conn.initialized())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Redis {
+    /// Creates a new [`Redis`] client connecting to the provided `url`.
+    ///
+    /// Connection is established lazily, on the first use, and re-established
+    /// automatically once lost.
+    ///
+    /// # Errors
+    ///
+    /// If the provided `url` is invalid.
+    pub fn new(url: &str) -> Result<Self, Traced<cache::Error>> {
+        let client = Client::open(url)
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))?;
+        Ok(Self {
+            client,
+            conn: OnceCell::new(),
+        })
+    }
+
+    /// Returns a [`ConnectionManager`] of this [`Redis`] client, establishing
+    /// it if necessary.
+    async fn connection(
+        &self,
+    ) -> Result<ConnectionManager, Traced<cache::Error>> {
+        self.conn
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await
+            .cloned()
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))
+    }
+}
+
+#[async_trait]
+impl Cache for Redis {
+    async fn get(
+        &self,
+        key: &str,
+    ) -> Result<Option<Vec<u8>>, Traced<cache::Error>> {
+        self.connection()
+            .await
+            .map_err(tracerr::wrap!())?
+            .get(key)
+            .await
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))
+    }
+
+    async fn set(
+        &self,
+        key: &str,
+        value: Vec<u8>,
+        ttl: Duration,
+    ) -> Result<(), Traced<cache::Error>> {
+        self.connection()
+            .await
+            .map_err(tracerr::wrap!())?
+            .set_ex(key, value, ttl.as_secs().max(1))
+            .await
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))
+    }
+
+    async fn delete(
+        &self,
+        keys: &[String],
+    ) -> Result<(), Traced<cache::Error>> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        self.connection()
+            .await
+            .map_err(tracerr::wrap!())?
+            .del(keys)
+            .await
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))
+    }
+
+    async fn delete_prefixed(
+        &self,
+        prefix: &str,
+    ) -> Result<(), Traced<cache::Error>> {
+        let mut pattern = String::with_capacity(prefix.len() + 1);
+        for c in prefix.chars() {
+            if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+                pattern.push('\\');
+            }
+            pattern.push(c);
+        }
+        pattern.push('*');
+
+        let mut conn = self.connection().await.map_err(tracerr::wrap!())?;
+        let keys = conn
+            .scan_match::<_, String>(pattern)
+            .await
+            .map_err(tracerr::from_and_wrap!(=> cache::Error))?
+            .collect::<Vec<_>>()
+            .await;
+        self.delete(&keys).await.map_err(tracerr::wrap!())
+    }
+}
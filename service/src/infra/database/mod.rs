@@ -18,3 +18,75 @@ pub enum Error {
     /// [`Postgres`] error.
     Postgres(postgres::Error),
 }
+
+/// Round trip to a [`Database`], checking whether it's reachable.
+#[derive(Clone, Copy, Debug)]
+pub struct Ping;
+
+/// Switching of a [`Database`] transaction into a consistent read-only view,
+/// isolated from the concurrent changes.
+///
+/// Must be performed before any other operation in the transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot;
+
+/// Statistics of a [`Database`] connections pool.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PoolStats {
+    /// Maximum number of connections in the pool.
+    pub max_size: usize,
+
+    /// Current number of connections in the pool.
+    pub size: usize,
+
+    /// Number of idle connections available in the pool.
+    pub available: usize,
+
+    /// Number of callers waiting for a connection.
+    pub waiting: usize,
+
+    /// Number of times waiting for a connection has timed out since the
+    /// process start.
+    pub timeouts: u64,
+}
+
+impl PoolStats {
+    /// Checks whether the pool is saturated: all its connections are in use
+    /// and no more can be created.
+    #[must_use]
+    pub fn is_saturated(&self) -> bool {
+        self.size >= self.max_size && self.available == 0
+    }
+}
+
+impl Error {
+    /// Checks if the error is caused by a [`Database`] operation timeout.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgres")]
+            Self::Postgres(e) => e.is_timeout(),
+        }
+    }
+
+    /// Checks if the error is caused by renting an already rented [`Realty`].
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    #[must_use]
+    pub fn is_realty_rented(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgres")]
+            Self::Postgres(e) => e.is_realty_rented(),
+        }
+    }
+
+    /// Checks if the error is caused by an updated entity being concurrently
+    /// modified since it has been read.
+    #[must_use]
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgres")]
+            Self::Postgres(e) => e.is_conflict(),
+        }
+    }
+}
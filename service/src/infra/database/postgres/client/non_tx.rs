@@ -1,14 +1,14 @@
 //! [`NonTx`] client definitions.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use tokio::sync::{RwLock, RwLockReadGuard};
-use tokio_postgres::{types::ToSql, Row, ToStatement};
+use tokio_postgres::{types::ToSql, Row};
 use tracerr::Traced;
 
 use crate::infra::database::{
     self,
-    postgres::{self, connection, Connection},
+    postgres::{connection, retry::Retry, Connection},
 };
 
 /// Non-transactional Postgres database client.
@@ -17,20 +17,40 @@ pub struct NonTx {
     /// [`connection::Pool`] to initialize the client.
     pub(crate) pool: connection::Pool,
 
+    /// [`Retry`] policy of checking out [`Connection`]s from the
+    /// [`connection::Pool`].
+    pub(crate) retry: Retry,
+
+    /// Timeout of the statements executed by this client, if any.
+    pub(crate) statement_timeout: Option<Duration>,
+
     /// Client to be used for non-transactional operations, if any.
     connection: Arc<RwLock<Option<connection::NonTx>>>,
 }
 
 impl NonTx {
-    /// Creates a new [`NonTx`] client from the provided [`connection::Pool`].
+    /// Creates a new [`NonTx`] client from the provided [`connection::Pool`],
+    /// checking out its [`Connection`]s with the provided [`Retry`] policy.
     #[must_use]
-    pub(crate) fn from_pool(pool: connection::Pool) -> Self {
+    pub(crate) fn from_pool(pool: connection::Pool, retry: Retry) -> Self {
         Self {
             pool,
+            retry,
+            statement_timeout: None,
             connection: Arc::new(RwLock::new(None)),
         }
     }
 
+    /// Creates a new [`NonTx`] client sharing the [`connection::Pool`] of this
+    /// one, which statements are limited by the provided `timeout`.
+    #[must_use]
+    pub(crate) fn with_statement_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            statement_timeout: Some(timeout),
+            ..Self::from_pool(self.pool.clone(), self.retry)
+        }
+    }
+
     /// Returns the underlying [`Connection`] of this [`NonTx`] client.
     pub(crate) async fn connection(
         &self,
@@ -43,11 +63,13 @@ impl NonTx {
             let mut connection = self.connection.write().await;
             if connection.is_none() {
                 *connection = Some(
-                    self.pool
-                        .get()
-                        .await
-                        .map_err(tracerr::from_and_wrap!(=> postgres::Error))
-                        .map_err(tracerr::map_from)?,
+                    connection::checkout(
+                        &self.pool,
+                        self.retry,
+                        self.statement_timeout,
+                    )
+                    .await
+                    .map_err(tracerr::wrap!())?,
                 );
             }
 
@@ -73,14 +95,11 @@ impl NonTx {
 }
 
 impl Connection for NonTx {
-    async fn query<T>(
+    async fn query(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Vec<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Vec<Row>, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
@@ -89,14 +108,11 @@ impl Connection for NonTx {
             .map_err(tracerr::wrap!())
     }
 
-    async fn query_opt<T>(
+    async fn query_opt(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Option<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Option<Row>, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
@@ -105,14 +121,11 @@ impl Connection for NonTx {
             .map_err(tracerr::wrap!())
     }
 
-    async fn exec<T>(
+    async fn exec(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<u64, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<u64, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
@@ -1,14 +1,14 @@
 //! [`Tx`] client definitions.
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use tokio::sync::{RwLock, RwLockReadGuard};
-use tokio_postgres::{types::ToSql, Row, ToStatement};
+use tokio_postgres::{types::ToSql, Row};
 use tracerr::Traced;
 
 use crate::infra::database::{
     self,
-    postgres::{self, connection, Connection},
+    postgres::{connection, retry::Retry, Connection},
 };
 
 use super::NonTx;
@@ -19,6 +19,13 @@ pub struct Tx {
     /// [`connection::Pool`] to retrieve the [`Connection`] from.
     pool: connection::Pool,
 
+    /// [`Retry`] policy of checking out [`Connection`]s from the
+    /// [`connection::Pool`].
+    retry: Retry,
+
+    /// Timeout of the statements executed by this client, if any.
+    statement_timeout: Option<Duration>,
+
     /// Inner representation of this client.
     inner: Arc<Inner>,
 }
@@ -39,6 +46,8 @@ impl Tx {
     pub fn from_non_tx(client: NonTx) -> Self {
         Self {
             pool: client.pool.clone(),
+            retry: client.retry,
+            statement_timeout: client.statement_timeout,
             inner: Arc::new(Inner {
                 non_tx: RwLock::new(Some(client)),
                 tx: Arc::new(RwLock::new(None)),
@@ -69,11 +78,13 @@ impl Tx {
                 let conn = if let Some(c) = existing {
                     c
                 } else {
-                    self.pool
-                        .get()
-                        .await
-                        .map_err(tracerr::from_and_wrap!(=> postgres::Error))
-                        .map_err(tracerr::map_from)?
+                    connection::checkout(
+                        &self.pool,
+                        self.retry,
+                        self.statement_timeout,
+                    )
+                    .await
+                    .map_err(tracerr::wrap!())?
                 };
 
                 *connection = Some(
@@ -118,14 +129,11 @@ impl Tx {
 }
 
 impl Connection for Tx {
-    async fn query<T>(
+    async fn query(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Vec<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Vec<Row>, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
@@ -134,14 +142,11 @@ impl Connection for Tx {
             .map_err(tracerr::wrap!())
     }
 
-    async fn query_opt<T>(
+    async fn query_opt(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Option<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Option<Row>, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
@@ -150,14 +155,11 @@ impl Connection for Tx {
             .map_err(tracerr::wrap!())
     }
 
-    async fn exec<T>(
+    async fn exec(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<u64, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<u64, Traced<database::Error>> {
         self.connection()
             .await
             .map_err(tracerr::wrap!())?
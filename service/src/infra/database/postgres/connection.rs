@@ -1,13 +1,23 @@
 //! [`Connection`] definitions.
 
-use std::{fmt, future::Future};
+use std::{
+    fmt,
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
 
+use deadpool_postgres::StatementCache;
 use futures::{FutureExt as _, TryFutureExt as _};
 use ouroboros::self_referencing;
-use tokio_postgres::{types::ToSql, Row, ToStatement};
+use tokio_postgres::{error::SqlState, types::ToSql, Row, Statement};
 use tracerr::Traced;
+use tracing as log;
 
-use crate::infra::database::{self, postgres};
+use crate::infra::database::{
+    self,
+    postgres::{self, retry::Retry},
+};
 
 pub use deadpool_postgres::{
     Client as NonTx, CreatePoolError as PoolCreationError, Pool, PoolError,
@@ -77,7 +87,146 @@ impl Tx {
     }
 }
 
+/// Number of times waiting for a free [`Pool`] slot has timed out.
+static POOL_TIMEOUTS: AtomicU64 = AtomicU64::new(0);
+
+/// Indicator whether a [`Pool`] was saturated on the last checkout.
+static POOL_SATURATED: AtomicBool = AtomicBool::new(false);
+
+/// Returns the current [`database::PoolStats`] of the provided [`Pool`].
+pub(crate) fn pool_stats(pool: &Pool) -> database::PoolStats {
+    let status = pool.status();
+    database::PoolStats {
+        max_size: status.max_size,
+        size: status.size,
+        available: status.available,
+        waiting: status.waiting,
+        timeouts: POOL_TIMEOUTS.load(Ordering::Relaxed),
+    }
+}
+
+/// Checks out a [`NonTx`] connection from the provided [`Pool`], retrying
+/// transient failures according to the provided [`Retry`] policy.
+///
+/// The checked out connection executes its statements within the provided
+/// `statement_timeout`, or within the database default one, if [`None`].
+///
+/// Logs a warning once the [`Pool`] becomes saturated.
+///
+/// # Errors
+///
+/// If failed to check out a connection within the [`Retry`] policy, or to
+/// set up its `statement_timeout`.
+pub(crate) async fn checkout(
+    pool: &Pool,
+    retry: Retry,
+    statement_timeout: Option<Duration>,
+) -> Result<NonTx, Traced<database::Error>> {
+    let stats = pool_stats(pool);
+    let saturated = stats.is_saturated();
+    if POOL_SATURATED.swap(saturated, Ordering::Relaxed) != saturated {
+        if saturated {
+            log::warn!("`Postgres` connections pool is saturated: {stats:?}");
+        } else {
+            log::info!("`Postgres` connections pool is no longer saturated");
+        }
+    }
+
+    let conn = retry
+        .run(
+            || {
+                pool.get().inspect_err(|e| {
+                    if matches!(e, PoolError::Timeout(_)) {
+                        _ = POOL_TIMEOUTS.fetch_add(1, Ordering::Relaxed);
+                    }
+                })
+            },
+            is_transient,
+        )
+        .await
+        .map_err(tracerr::from_and_wrap!(=> postgres::Error))
+        .map_err(tracerr::map_from)?;
+
+    // Connections are shared by the clients with different timeouts, so the
+    // timeout left by the previous client must be overridden.
+    let sql = statement_timeout.map_or_else(
+        || "RESET statement_timeout".to_owned(),
+        |t| format!("SET statement_timeout = {}", t.as_millis()),
+    );
+    conn.batch_exec(&sql).await.map_err(tracerr::wrap!())?;
+
+    Ok(conn)
+}
+
+/// Checks whether the provided [`PoolError`] is transient, so the checkout
+/// may succeed if retried.
+fn is_transient(err: &PoolError) -> bool {
+    match err {
+        PoolError::Timeout(_) => true,
+        PoolError::Backend(e) => e.code().is_none_or(|code| {
+            [SqlState::CANNOT_CONNECT_NOW, SqlState::TOO_MANY_CONNECTIONS]
+                .contains(code)
+        }),
+        PoolError::Closed
+        | PoolError::NoRuntimeSpecified
+        | PoolError::PostCreateHook(_) => false,
+    }
+}
+
+/// Number of prepared statements reused from a [`StatementCache`].
+static STATEMENT_CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Number of statements prepared due to their absence in a
+/// [`StatementCache`].
+static STATEMENT_CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+/// Statistics of the prepared statements caching.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StatementCacheStats {
+    /// Number of prepared statements reused from a cache.
+    pub hits: u64,
+
+    /// Number of statements prepared due to their absence in a cache.
+    pub misses: u64,
+}
+
+impl StatementCacheStats {
+    /// Returns the [`StatementCacheStats`] collected by all the
+    /// [`Connection`]s since the process start.
+    #[must_use]
+    pub fn collected() -> Self {
+        Self {
+            hits: STATEMENT_CACHE_HITS.load(Ordering::Relaxed),
+            misses: STATEMENT_CACHE_MISSES.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Awaits the provided `prepare` [`Future`] preparing a [`Statement`] via the
+/// provided [`StatementCache`], recording whether it was cached already.
+async fn prepare_cached(
+    cache: &StatementCache,
+    prepare: impl Future<Output = Result<Statement, Error>>,
+) -> Result<Statement, Traced<database::Error>> {
+    let size = cache.size();
+    let stmt = prepare
+        .await
+        .map_err(tracerr::from_and_wrap!(=> postgres::Error))
+        .map_err(tracerr::map_from)?;
+    // Cache only grows when a new statement is prepared, so concurrent
+    // preparations on the same connection may only skew the counters.
+    let counter = if cache.size() > size {
+        &STATEMENT_CACHE_MISSES
+    } else {
+        &STATEMENT_CACHE_HITS
+    };
+    _ = counter.fetch_add(1, Ordering::Relaxed);
+    Ok(stmt)
+}
+
 /// Generic database connection.
+///
+/// Statements are prepared once per connection and cached by their SQL text.
 pub trait Connection {
     /// Queries the provided statement with the given parameters and returns the
     /// resulting rows.
@@ -85,13 +234,11 @@ pub trait Connection {
     /// # Errors
     ///
     /// If failed to query the statement.
-    fn query<T>(
+    fn query(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> impl Future<Output = Result<Vec<Row>, Traced<database::Error>>>
-    where
-        T: ToStatement + ?Sized;
+    ) -> impl Future<Output = Result<Vec<Row>, Traced<database::Error>>>;
 
     /// Queries the provided statement with the given parameters and returns the
     /// optional resulting row.
@@ -99,13 +246,11 @@ pub trait Connection {
     /// # Errors
     ///
     /// If failed to query the statement.
-    fn query_opt<T>(
+    fn query_opt(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> impl Future<Output = Result<Option<Row>, Traced<database::Error>>>
-    where
-        T: ToStatement + ?Sized;
+    ) -> impl Future<Output = Result<Option<Row>, Traced<database::Error>>>;
 
     /// Executes the provided statement with the given parameters and returns
     /// the number of affected rows.
@@ -113,13 +258,11 @@ pub trait Connection {
     /// # Errors
     ///
     /// If failed to execute the statement.
-    fn exec<T>(
+    fn exec(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> impl Future<Output = Result<u64, Traced<database::Error>>>
-    where
-        T: ToStatement + ?Sized;
+    ) -> impl Future<Output = Result<u64, Traced<database::Error>>>;
 
     /// Executes the provided batch query.
     ///
@@ -133,46 +276,49 @@ pub trait Connection {
 }
 
 impl Connection for NonTx {
-    async fn query<T>(
+    async fn query(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Vec<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Vec<Row>, Traced<database::Error>> {
+        let stmt =
+            prepare_cached(&self.statement_cache, self.prepare_cached(stmt))
+                .await
+                .map_err(tracerr::wrap!())?;
         (**self)
-            .query(stmt, params)
+            .query(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
     }
 
-    async fn query_opt<T>(
+    async fn query_opt(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Option<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<Option<Row>, Traced<database::Error>> {
+        let stmt =
+            prepare_cached(&self.statement_cache, self.prepare_cached(stmt))
+                .await
+                .map_err(tracerr::wrap!())?;
         (**self)
-            .query_opt(stmt, params)
+            .query_opt(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
     }
 
-    async fn exec<T>(
+    async fn exec(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<u64, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
+    ) -> Result<u64, Traced<database::Error>> {
+        let stmt =
+            prepare_cached(&self.statement_cache, self.prepare_cached(stmt))
+                .await
+                .map_err(tracerr::wrap!())?;
         (**self)
-            .execute(stmt, params)
+            .execute(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
@@ -191,46 +337,46 @@ impl Connection for NonTx {
 }
 
 impl Connection for Tx {
-    async fn query<T>(
+    async fn query(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Vec<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
-        self.tx()
-            .query(stmt, params)
+    ) -> Result<Vec<Row>, Traced<database::Error>> {
+        let tx = self.tx();
+        let stmt = prepare_cached(&tx.statement_cache, tx.prepare_cached(stmt))
+            .await
+            .map_err(tracerr::wrap!())?;
+        tx.query(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
     }
 
-    async fn query_opt<T>(
+    async fn query_opt(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<Option<Row>, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
-        self.tx()
-            .query_opt(stmt, params)
+    ) -> Result<Option<Row>, Traced<database::Error>> {
+        let tx = self.tx();
+        let stmt = prepare_cached(&tx.statement_cache, tx.prepare_cached(stmt))
+            .await
+            .map_err(tracerr::wrap!())?;
+        tx.query_opt(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
     }
 
-    async fn exec<T>(
+    async fn exec(
         &self,
-        stmt: &T,
+        stmt: &str,
         params: &[&(dyn ToSql + Sync)],
-    ) -> Result<u64, Traced<database::Error>>
-    where
-        T: ToStatement + ?Sized,
-    {
-        self.tx()
-            .execute(stmt, params)
+    ) -> Result<u64, Traced<database::Error>> {
+        let tx = self.tx();
+        let stmt = prepare_cached(&tx.statement_cache, tx.prepare_cached(stmt))
+            .await
+            .map_err(tracerr::wrap!())?;
+        tx.execute(&stmt, params)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
             .map_err(tracerr::map_from)
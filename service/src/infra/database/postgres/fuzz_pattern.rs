@@ -4,7 +4,9 @@ use derive_more::Display;
 use itertools::Itertools as _;
 use postgres_types::{FromSql, ToSql};
 
-/// SQL pattern to be used for fuzzy searching.
+/// Pattern to be used for fuzzy searching via [`pg_trgm`] word similarity.
+///
+/// [`pg_trgm`]: https://www.postgresql.org/docs/current/pgtrgm.html
 #[derive(Clone, Debug, Display, Eq, FromSql, PartialEq, ToSql)]
 #[postgres(transparent)]
 pub struct FuzzPattern(String);
@@ -13,26 +15,6 @@ impl FuzzPattern {
     /// Creates a new [`FuzzPattern`] out of the given `input`.
     #[must_use]
     pub fn new(input: &str) -> Self {
-        Self(format!(
-            "({})",
-            input.split_ascii_whitespace().format_with("|", |word, f| {
-                f(&format_args!(
-                    "%{}%",
-                    word.replace('\\', r"\\")
-                        .replace('%', r"\%")
-                        .replace('|', r"\|")
-                        .replace('*', r"\*")
-                        .replace('+', r"\+")
-                        .replace('?', r"\?")
-                        .replace('{', r"\{")
-                        .replace('}', r"\}")
-                        .replace('(', r"\(")
-                        .replace(')', r"\)")
-                        .replace('[', r"\[")
-                        .replace(']', r"\]")
-                        .replace('_', r"\_")
-                ))
-            }),
-        ))
+        Self(input.split_whitespace().join(" ").to_lowercase())
     }
 }
@@ -0,0 +1,244 @@
+//! [`Agency`]- and [`agency::Settings`]-related [`Database`]
+//! implementations.
+
+use common::operations::{By, Insert, Select, Update};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, user, Agency},
+    infra::{
+        database::{
+            self,
+            postgres::{self, Connection},
+            Postgres,
+        },
+        Database,
+    },
+};
+
+use super::task::{duration_from_millis, duration_into_millis};
+
+impl<C> Database<Select<By<Option<Agency>, agency::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Agency>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Agency>, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: agency::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, name, admin_id, created_at, version \
+            FROM agencies \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| agency_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Agency>, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Agency>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Agency>, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // `Agency`s administrated by the `User`.
+        let admin_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, name, admin_id, created_at, version \
+            FROM agencies \
+            WHERE admin_id = $1::UUID";
+        Ok(self
+            .query(SQL, &[&admin_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(agency_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Agency>> for Postgres<C>
+where
+    C: Connection,
+    Self: Database<Update<Agency>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(agency): Insert<Agency>,
+    ) -> Result<Self::Ok, Self::Err> {
+        self.execute(Update(agency)).await.map_err(tracerr::wrap!())
+    }
+}
+
+impl<C> Database<Update<Agency>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(agency): Update<Agency>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Agency {
+            id,
+            name,
+            admin_id,
+            created_at,
+            version,
+        } = agency;
+
+        const SQL: &str = "\
+            INSERT INTO agencies (\
+                id, name, admin_id, created_at, version\
+            ) VALUES (\
+                $1::UUID, $2::VARCHAR, $3::UUID, $4::TIMESTAMPTZ, $5::INT4\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET name = EXCLUDED.name, \
+                admin_id = EXCLUDED.admin_id, \
+                created_at = EXCLUDED.created_at, \
+                version = EXCLUDED.version \
+            WHERE agencies.version = $6::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &name,
+                    &admin_id,
+                    &created_at,
+                    &version.next(),
+                    &version,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<C> Database<Select<By<Option<agency::Settings>, agency::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<agency::Settings>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<agency::Settings>, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let agency_id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT agency_id, currency, contract_duration_ms, commission, \
+                   locale, updated_at \
+            FROM agency_settings \
+            WHERE agency_id = $1::UUID";
+        Ok(self
+            .query_opt(SQL, &[&agency_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| agency::Settings {
+                agency_id: row.get("agency_id"),
+                currency: row.get("currency"),
+                contract_duration: row
+                    .get::<_, Option<i64>>("contract_duration_ms")
+                    .map(duration_from_millis),
+                commission: row.get("commission"),
+                locale: row.get("locale"),
+                updated_at: row.get("updated_at"),
+            }))
+    }
+}
+
+impl<C> Database<Update<agency::Settings>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(settings): Update<agency::Settings>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let agency::Settings {
+            agency_id,
+            currency,
+            contract_duration,
+            commission,
+            locale,
+            updated_at,
+        } = settings;
+        let contract_duration = contract_duration.map(duration_into_millis);
+
+        const SQL: &str = "\
+            INSERT INTO agency_settings (\
+                agency_id, currency, contract_duration_ms, commission, \
+                locale, updated_at\
+            ) VALUES (\
+                $1::UUID, $2::INT2, $3::INT8, $4::NUMERIC, \
+                $5::INT2, $6::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (agency_id) DO UPDATE \
+            SET currency = EXCLUDED.currency, \
+                contract_duration_ms = EXCLUDED.contract_duration_ms, \
+                commission = EXCLUDED.commission, \
+                locale = EXCLUDED.locale, \
+                updated_at = EXCLUDED.updated_at";
+        self.exec(
+            SQL,
+            &[
+                &agency_id,
+                &currency,
+                &contract_duration,
+                &commission,
+                &locale,
+                &updated_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `agencies` table into an [`Agency`].
+fn agency_from_row(row: &Row) -> Agency {
+    Agency {
+        id: row.get("id"),
+        name: row.get("name"),
+        admin_id: row.get("admin_id"),
+        created_at: row.get("created_at"),
+        version: row.get("version"),
+    }
+}
@@ -0,0 +1,146 @@
+//! [`contract::Approval`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::contract,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<contract::Approval>, contract::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<contract::Approval>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<contract::Approval>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, agency_id, requester_id, requested_at, \
+                   approver_id, approved_at \
+            FROM contract_approvals \
+            WHERE contract_id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| approval_from_row(&row)))
+    }
+}
+
+impl<C>
+    Database<
+        Select<By<Vec<contract::Approval>, read::contract::PendingApprovals>>,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<contract::Approval>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<contract::Approval>, read::contract::PendingApprovals>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::contract::PendingApprovals { agency_id } = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, agency_id, requester_id, requested_at, \
+                   approver_id, approved_at \
+            FROM contract_approvals \
+            WHERE agency_id = $1::UUID \
+              AND approved_at IS NULL \
+            ORDER BY requested_at, contract_id";
+        Ok(self
+            .query(SQL, &[&agency_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(approval_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<contract::Approval>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(approval): Insert<contract::Approval>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let contract::Approval {
+            contract_id,
+            agency_id,
+            requester_id,
+            requested_at,
+            approver_id,
+            approved_at,
+        } = approval;
+
+        // `contracts.is_pending_approval` mirrors the approval state for the
+        // `idx_contracts_realty_id_open_rent` unique index.
+        const SQL: &str = "\
+            WITH approval AS (\
+                INSERT INTO contract_approvals (\
+                    contract_id, agency_id, requester_id, requested_at, \
+                    approver_id, approved_at\
+                ) VALUES (\
+                    $1::UUID, $2::UUID, $3::UUID, $4::TIMESTAMPTZ, \
+                    $5::UUID, $6::TIMESTAMPTZ\
+                ) \
+                ON CONFLICT (contract_id) DO UPDATE \
+                SET approver_id = EXCLUDED.approver_id, \
+                    approved_at = EXCLUDED.approved_at\
+            ) \
+            UPDATE contracts \
+            SET is_pending_approval = $6::TIMESTAMPTZ IS NULL \
+            WHERE id = $1::UUID";
+        self.exec(
+            SQL,
+            &[
+                &contract_id,
+                &agency_id,
+                &requester_id,
+                &requested_at,
+                &approver_id,
+                &approved_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `contract_approvals` table into a
+/// [`contract::Approval`].
+fn approval_from_row(row: &Row) -> contract::Approval {
+    contract::Approval {
+        contract_id: row.get("contract_id"),
+        agency_id: row.get("agency_id"),
+        requester_id: row.get("requester_id"),
+        requested_at: row.get("requested_at"),
+        approver_id: row.get("approver_id"),
+        approved_at: row.get("approved_at"),
+    }
+}
@@ -0,0 +1,273 @@
+//! [`Arrears`]-related [`Database`] implementations.
+
+use common::{
+    money::Currency,
+    operations::{By, Delete, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, contract, Arrears, Contract},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+use super::{contract::contract_from_row, late_fee::late_fee_from_row};
+
+impl<C>
+    Database<Select<By<Vec<read::arrears::Balance>, read::arrears::Unsettled>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<read::arrears::Balance>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<read::arrears::Balance>, read::arrears::Unsettled>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::arrears::Unsettled { after, limit } = by.into_inner();
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+
+        const SQL: &str = "\
+            SELECT c.id, c.agency_id, c.number, c.kind, \
+                   c.name, c.description, \
+                   c.realty_id, c.employer_id, c.landlord_id, \
+                   c.purchaser_id, c.manager_id, c.branch_id, \
+                   c.price, c.price_currency, \
+                   c.deposit, c.deposit_currency, \
+                   c.one_time_fee, c.one_time_fee_currency, \
+                   c.monthly_fee, c.monthly_fee_currency, \
+                   c.percent_fee, \
+                   c.is_placed, \
+                   c.created_at, c.expires_at, c.completed_at, \
+                   c.terminated_at, \
+                   c.archived_at, c.version, \
+                   COALESCE(p.paid, 0) AS paid, \
+                   a.amount AS arrears_amount, \
+                   a.amount_currency AS arrears_currency, \
+                   f.fixed_fee, f.fixed_fee_currency, f.percent_fee, \
+                   f.cap, f.cap_currency, \
+                   f.accrued, f.accrued_currency, f.accrued_until \
+            FROM contracts c \
+            LEFT JOIN LATERAL (\
+                SELECT SUM(amount) AS paid \
+                FROM rent_payments \
+                WHERE contract_id = c.id\
+            ) p ON TRUE \
+            LEFT JOIN rent_arrears a ON a.contract_id = c.id \
+            LEFT JOIN rent_late_fees f ON f.contract_id = c.id \
+            WHERE c.kind = $1::INT2 \
+              AND ($2::UUID IS NULL OR c.id > $2::UUID) \
+              AND ((c.completed_at IS NULL AND c.terminated_at IS NULL) \
+                   OR a.contract_id IS NOT NULL) \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM contract_approvals ap \
+                  WHERE ap.contract_id = c.id AND ap.approved_at IS NULL\
+              ) \
+            ORDER BY c.id \
+            LIMIT $3::INT4";
+        Ok(self
+            .query(SQL, &[&contract::Kind::Rent, &after, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| {
+                let contract = match contract_from_row(row) {
+                    Contract::Rent(c) => c,
+                    Contract::Employment(_)
+                    | Contract::ManagementForRent(_)
+                    | Contract::ManagementForSale(_)
+                    | Contract::Sale(_)
+                    | Contract::ShortTermRent(_) => {
+                        unreachable!("already checked")
+                    }
+                };
+                read::arrears::Balance {
+                    paid: row.get("paid"),
+                    arrears: row.get::<_, Option<_>>("arrears_amount").map(
+                        |amount| Money {
+                            amount,
+                            currency: row.get("arrears_currency"),
+                        },
+                    ),
+                    late_fee: row
+                        .get::<_, Option<Currency>>("accrued_currency")
+                        .map(|_| late_fee_from_row(contract.id, row)),
+                    contract,
+                }
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Option<Arrears>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Arrears>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Arrears>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, agency_id, purchaser_id, employer_id, \
+                   amount, amount_currency, late_fees, \
+                   overdue_since, detected_at \
+            FROM rent_arrears \
+            WHERE contract_id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| arrears_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Arrears>, agency::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Arrears>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Arrears>, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let agency_id: agency::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, agency_id, purchaser_id, employer_id, \
+                   amount, amount_currency, late_fees, \
+                   overdue_since, detected_at \
+            FROM rent_arrears \
+            WHERE agency_id = $1::UUID \
+            ORDER BY overdue_since, contract_id";
+        Ok(self
+            .query(SQL, &[&agency_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(arrears_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Arrears>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(arrears): Insert<Arrears>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Arrears {
+            contract_id,
+            agency_id,
+            purchaser_id,
+            employer_id,
+            amount,
+            late_fees,
+            overdue_since,
+            detected_at,
+        } = arrears;
+
+        const SQL: &str = "\
+            INSERT INTO rent_arrears (\
+                contract_id, agency_id, purchaser_id, employer_id, \
+                amount, amount_currency, late_fees, \
+                overdue_since, detected_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::UUID, \
+                $5::NUMERIC, $6::INT2, $7::NUMERIC, \
+                $8::TIMESTAMPTZ, $9::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (contract_id) DO UPDATE \
+            SET employer_id = EXCLUDED.employer_id, \
+                amount = EXCLUDED.amount, \
+                amount_currency = EXCLUDED.amount_currency, \
+                late_fees = EXCLUDED.late_fees, \
+                overdue_since = EXCLUDED.overdue_since, \
+                detected_at = EXCLUDED.detected_at";
+        self.exec(
+            SQL,
+            &[
+                &contract_id,
+                &agency_id,
+                &purchaser_id,
+                &employer_id,
+                &amount.amount,
+                &amount.currency,
+                &late_fees.amount,
+                &overdue_since,
+                &detected_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<Arrears, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<Arrears, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM rent_arrears \
+            WHERE contract_id = $1::UUID";
+        self.exec(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `rent_arrears` table into [`Arrears`].
+fn arrears_from_row(row: &Row) -> Arrears {
+    Arrears {
+        contract_id: row.get("contract_id"),
+        agency_id: row.get("agency_id"),
+        purchaser_id: row.get("purchaser_id"),
+        employer_id: row.get("employer_id"),
+        amount: Money {
+            amount: row.get("amount"),
+            currency: row.get("amount_currency"),
+        },
+        late_fees: Money {
+            amount: row.get("late_fees"),
+            currency: row.get("amount_currency"),
+        },
+        overdue_since: row.get("overdue_since"),
+        detected_at: row.get("detected_at"),
+    }
+}
@@ -0,0 +1,244 @@
+//! [`audit::Entry`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use itertools::Itertools as _;
+use postgres_types::ToSql;
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::audit,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C>
+    Database<Select<By<read::audit::list::Page, read::audit::list::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::audit::list::Page;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<read::audit::list::Page, read::audit::list::Selector>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::audit::list::Selector {
+            arguments,
+            filter: read::audit::list::Filter {
+                agency_id,
+                actor_id,
+                entity,
+                entity_id,
+                start,
+                end,
+            },
+        } = by.into_inner();
+
+        let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![&agency_id, &limit];
+
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
+            ps.len()
+        });
+
+        let actor_id_idx = actor_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let entity_idx = entity.as_ref().map(|e| {
+            ps.push(e);
+            ps.len()
+        });
+
+        let entity_id_idx = entity_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let start_idx = start.as_ref().map(|at| {
+            ps.push(at);
+            ps.len()
+        });
+
+        let end_idx = end.as_ref().map(|at| {
+            ps.push(at);
+            ps.len()
+        });
+
+        // `audit::Entry`s are listed chronologically, while their IDs are
+        // random.
+        let sql = format!(
+            "SELECT id, agency_id, actor_id, action, entity, entity_id, \
+                    before::TEXT AS before, after::TEXT AS after, \
+                    created_at \
+             FROM audit_entries \
+             WHERE agency_id = $1::UUID \
+                   {actor_filtering} \
+                   {entity_filtering} \
+                   {entity_id_filtering} \
+                   {start_filtering} \
+                   {end_filtering} \
+                   {cursor} \
+             ORDER BY created_at {order}, id {order} \
+             LIMIT $2::INT4 \
+             {offset}",
+            actor_filtering =
+                actor_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND actor_id = ${idx}::UUID"))
+                }),
+            entity_filtering =
+                entity_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND entity = ${idx}::INT2"))
+                }),
+            entity_id_filtering =
+                entity_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND entity_id = ${idx}::UUID"))
+                }),
+            start_filtering = start_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("AND created_at >= ${idx}::TIMESTAMPTZ"))
+            }),
+            end_filtering = end_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("AND created_at < ${idx}::TIMESTAMPTZ"))
+            }),
+            cursor =
+                arguments.cursor().into_iter().format_with("", |cursor, f| {
+                    let op = arguments.kind().operator();
+
+                    ps.push(cursor);
+                    let idx = ps.len();
+
+                    f(&format_args!(
+                        "AND (created_at, id) {op} (\
+                             SELECT created_at, id \
+                             FROM audit_entries \
+                             WHERE id = ${idx}::UUID\
+                         )",
+                    ))
+                }),
+            order = arguments.kind().order().sql(),
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+        );
+        let rows = self
+            .query(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let has_more = rows.len() > arguments.limit();
+        let edges = rows
+            .into_iter()
+            .take(arguments.limit())
+            .map(|row| {
+                let node = entry_from_row(&row);
+                (node.id, node)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(read::audit::list::Page::new(&arguments, edges, has_more))
+    }
+}
+
+impl<C> Database<Insert<audit::Entry>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(entry): Insert<audit::Entry>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let audit::Entry {
+            id,
+            agency_id,
+            actor_id,
+            action,
+            entity,
+            entity_id,
+            before,
+            after,
+            created_at,
+        } = entry;
+
+        // `audit::Entry`s are immutable once recorded.
+        const SQL: &str = "\
+            INSERT INTO audit_entries (\
+                id, agency_id, actor_id, action, entity, entity_id, \
+                before, after, created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::INT2, $5::INT2, $6::UUID, \
+                $7::TEXT::JSONB, $8::TEXT::JSONB, $9::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (id) DO NOTHING";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &actor_id,
+                &action,
+                &entity,
+                &entity_id,
+                &before,
+                &after,
+                &created_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<audit::Entry, audit::CreationDateTime>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = u64;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<audit::Entry, audit::CreationDateTime>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let deadline: audit::CreationDateTime = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM audit_entries \
+            WHERE created_at < $1::TIMESTAMPTZ";
+        self.exec(SQL, &[&deadline])
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+/// Maps the provided [`Row`] of the `audit_entries` table into an
+/// [`audit::Entry`].
+fn entry_from_row(row: &Row) -> audit::Entry {
+    audit::Entry {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        actor_id: row.get("actor_id"),
+        action: row.get("action"),
+        entity: row.get("entity"),
+        entity_id: row.get("entity_id"),
+        before: row.get("before"),
+        after: row.get("after"),
+        created_at: row.get("created_at"),
+    }
+}
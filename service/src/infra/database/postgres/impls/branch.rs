@@ -0,0 +1,113 @@
+//! [`Branch`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, branch, Branch},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<Branch>, branch::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Branch>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Branch>, branch::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: branch::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, name, address, created_at \
+            FROM branches \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| branch_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Branch>, agency::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Branch>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Branch>, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let agency_id: agency::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, name, address, created_at \
+            FROM branches \
+            WHERE agency_id = $1::UUID \
+            ORDER BY name, id";
+        Ok(self
+            .query(SQL, &[&agency_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(branch_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Branch>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(branch): Insert<Branch>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Branch {
+            id,
+            agency_id,
+            name,
+            address,
+            created_at,
+        } = branch;
+
+        const SQL: &str = "\
+            INSERT INTO branches (\
+                id, agency_id, name, address, created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::VARCHAR, $4::VARCHAR, \
+                $5::TIMESTAMPTZ\
+            )";
+        self.exec(SQL, &[&id, &agency_id, &name, &address, &created_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `branches` table into a [`Branch`].
+fn branch_from_row(row: &Row) -> Branch {
+    Branch {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        name: row.get("name"),
+        address: row.get("address"),
+        created_at: row.get("created_at"),
+    }
+}
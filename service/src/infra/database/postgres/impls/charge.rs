@@ -0,0 +1,255 @@
+//! [`Charge`]-related [`Database`] implementations.
+
+use std::collections::HashMap;
+
+use common::{
+    operations::{By, Delete, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{charge, contract, Charge, Contract},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+use super::contract::contract_from_row;
+
+impl<C> Database<Select<By<Option<Charge>, charge::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Charge>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Charge>, charge::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: charge::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, name, amount, amount_currency, \
+                   bearer, creator_id, created_at \
+            FROM rent_charges \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| charge_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Charge>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Charge>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Charge>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, name, amount, amount_currency, \
+                   bearer, creator_id, created_at \
+            FROM rent_charges \
+            WHERE contract_id = $1::UUID \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(charge_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<read::charge::Rental>, read::charge::Active>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<read::charge::Rental>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<read::charge::Rental>, read::charge::Active>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::charge::Active(agency_id) = by.into_inner();
+
+        const CONTRACTS_SQL: &str = "\
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version \
+            FROM contracts \
+            WHERE agency_id = $1::UUID \
+              AND kind = $2::INT2 \
+              AND completed_at IS NULL \
+              AND terminated_at IS NULL \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM contract_approvals ap \
+                  WHERE ap.contract_id = contracts.id \
+                    AND ap.approved_at IS NULL\
+              ) \
+            ORDER BY id";
+        let contracts = self
+            .query(CONTRACTS_SQL, &[&agency_id, &contract::Kind::Rent])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| match contract_from_row(row) {
+                Contract::Rent(c) => c,
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_) => unreachable!("already checked"),
+            })
+            .collect::<Vec<_>>();
+        if contracts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = contracts.iter().map(|c| c.id).collect::<Vec<_>>();
+        const CHARGES_SQL: &str = "\
+            SELECT id, agency_id, contract_id, name, amount, amount_currency, \
+                   bearer, creator_id, created_at \
+            FROM rent_charges \
+            WHERE contract_id = ANY($1::UUID[]) \
+            ORDER BY created_at, id";
+        let mut charges = HashMap::<contract::Id, Vec<_>>::new();
+        for row in self
+            .query(CHARGES_SQL, &[&ids])
+            .await
+            .map_err(tracerr::wrap!())?
+        {
+            let charge = charge_from_row(&row);
+            charges.entry(charge.contract_id).or_default().push(charge);
+        }
+
+        Ok(contracts
+            .into_iter()
+            .map(|contract| read::charge::Rental {
+                charges: charges.remove(&contract.id).unwrap_or_default(),
+                contract,
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Charge>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(charge): Insert<Charge>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Charge {
+            id,
+            agency_id,
+            contract_id,
+            name,
+            amount,
+            bearer,
+            creator_id,
+            created_at,
+        } = charge;
+
+        const SQL: &str = "\
+            INSERT INTO rent_charges (\
+                id, agency_id, contract_id, name, amount, amount_currency, \
+                bearer, creator_id, created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::VARCHAR, $5::NUMERIC, \
+                $6::INT2, $7::INT2, $8::UUID, $9::TIMESTAMPTZ\
+            )";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &contract_id,
+                &name,
+                &amount.amount,
+                &amount.currency,
+                &bearer,
+                &creator_id,
+                &created_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<Charge, charge::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<Charge, charge::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: charge::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM rent_charges \
+            WHERE id = $1::UUID";
+        self.exec(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `rent_charges` table into a [`Charge`].
+fn charge_from_row(row: &Row) -> Charge {
+    Charge {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        contract_id: row.get("contract_id"),
+        name: row.get("name"),
+        amount: Money {
+            amount: row.get("amount"),
+            currency: row.get("amount_currency"),
+        },
+        bearer: row.get("bearer"),
+        creator_id: row.get("creator_id"),
+        created_at: row.get("created_at"),
+    }
+}
@@ -0,0 +1,118 @@
+//! [`contract::commission::Split`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::contract::{self, commission},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<commission::Split>, contract::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<commission::Split>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<commission::Split>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, employer_id, share \
+            FROM contract_commission_splits \
+            WHERE contract_id = $1::UUID \
+            ORDER BY share DESC, employer_id";
+        Ok(self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(split_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<commission::Split>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(splits): Insert<Vec<commission::Split>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if splits.is_empty() {
+            return Ok(());
+        }
+        let total = splits.len();
+
+        let mut contract_ids = Vec::with_capacity(total);
+        let mut employer_ids = Vec::with_capacity(total);
+        let mut shares = Vec::with_capacity(total);
+        for s in splits {
+            contract_ids.push(s.contract_id);
+            employer_ids.push(s.employer_id);
+            shares.push(s.share);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO contract_commission_splits (\
+                contract_id, employer_id, share\
+            ) \
+            SELECT contract_id, employer_id, share \
+            FROM unnest($1::UUID[], $2::UUID[], $3::NUMERIC[]) \
+                 AS s(contract_id, employer_id, share) \
+            ON CONFLICT (contract_id, employer_id) DO UPDATE \
+            SET share = EXCLUDED.share";
+        self.exec(SQL, &[&contract_ids, &employer_ids, &shares])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<commission::Split, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<commission::Split, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM contract_commission_splits \
+            WHERE contract_id = $1::UUID";
+        self.exec(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `contract_commission_splits` table into
+/// a [`commission::Split`].
+fn split_from_row(row: &Row) -> commission::Split {
+    commission::Split {
+        contract_id: row.get("contract_id"),
+        employer_id: row.get("employer_id"),
+        share: row.get("share"),
+    }
+}
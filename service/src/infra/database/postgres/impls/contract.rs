@@ -4,27 +4,36 @@ use std::{collections::HashMap, ops::RangeInclusive};
 
 use common::{
     money,
-    operations::{By, Insert, Lock, Select, Update},
+    operations::{By, Insert, Lock, Perform, Select, Update},
     Money, Percent,
 };
 use itertools::Itertools as _;
 use postgres_types::ToSql;
 use rust_decimal::Decimal;
+use tokio_postgres::Row;
 use tracerr::Traced;
 
 use crate::{
-    domain::{contract, realty, user, Contract},
+    domain::{agency, branch, contract, realty, user, Contract, Version},
     infra::{
         database::{
             self,
-            postgres::{Connection, FuzzPattern},
+            postgres::{self, Connection, FuzzPattern},
             Postgres,
         },
         Database,
     },
-    read::{self, contract::Active},
+    read::{
+        self,
+        contract::{
+            list::{SortBy, SortKey},
+            Active, Expired,
+        },
+    },
 };
 
+use super::LockSpace;
+
 impl<C, IDs> Database<Select<By<HashMap<contract::Id, Contract>, IDs>>>
     for Postgres<C>
 where
@@ -48,16 +57,19 @@ where
 
         #[expect(clippy::items_after_statements, reason = "more readable")]
         const SQL: &str = "\
-            SELECT id, kind, \
+            SELECT id, agency_id, number, kind, \
                    name, description, \
                    realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
                    price, price_currency, \
                    deposit, deposit_currency, \
                    one_time_fee, one_time_fee_currency, \
                    monthly_fee, monthly_fee_currency, \
                    percent_fee, \
                    is_placed, \
-                   created_at, expires_at, terminated_at \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
             FROM contracts \
             WHERE id IN (SELECT unnest($1::UUID[]) LIMIT $2::INT4) \
             LIMIT $2::INT4";
@@ -66,154 +78,7 @@ where
             .await
             .map_err(tracerr::wrap!())?
             .into_iter()
-            .map(|row| {
-                let id = row.get("id");
-                let name = row.get("name");
-                let description = row.get("description");
-                let employer_id = row.get("employer_id");
-                let created_at = row.get("created_at");
-                let expires_at = row.get("expires_at");
-                let terminated_at = row.get("terminated_at");
-                let user = match row.get("kind") {
-                    contract::Kind::Rent => contract::Rent {
-                        id,
-                        name,
-                        description,
-                        realty_id: row.get("realty_id"),
-                        purchaser_id: row.get("purchaser_id"),
-                        landlord_id: row.get("landlord_id"),
-                        employer_id,
-                        price: Money {
-                            amount: row.get("price"),
-                            currency: row.get("price_currency"),
-                        },
-                        deposit: row.get::<_, Option<_>>("deposit").map(
-                            |amount| Money {
-                                amount,
-                                currency: row.get("deposit_currency"),
-                            },
-                        ),
-                        created_at,
-                        expires_at,
-                        terminated_at,
-                    }
-                    .into(),
-                    contract::Kind::Sale => contract::Sale {
-                        id,
-                        name,
-                        description,
-                        realty_id: row.get("realty_id"),
-                        purchaser_id: row.get("purchaser_id"),
-                        landlord_id: row.get("landlord_id"),
-                        employer_id,
-                        price: Money {
-                            amount: row.get("price"),
-                            currency: row.get("price_currency"),
-                        },
-                        deposit: row.get::<_, Option<_>>("deposit").map(
-                            |amount| Money {
-                                amount,
-                                currency: row.get("deposit_currency"),
-                            },
-                        ),
-                        created_at,
-                        expires_at,
-                        terminated_at,
-                    }
-                    .into(),
-                    contract::Kind::ManagementForRent => {
-                        contract::ManagementForRent {
-                            id,
-                            name,
-                            description,
-                            realty_id: row.get("realty_id"),
-                            landlord_id: row.get("landlord_id"),
-                            employer_id,
-                            expected_price: Money {
-                                amount: row.get("price"),
-                                currency: row.get("price_currency"),
-                            },
-                            expected_deposit: row
-                                .get::<_, Option<_>>("deposit")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("deposit_currency"),
-                                }),
-                            one_time_fee: row
-                                .get::<_, Option<_>>("one_time_fee")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("one_time_fee_currency"),
-                                }),
-                            monthly_fee: row
-                                .get::<_, Option<_>>("monthly_fee")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("monthly_fee_currency"),
-                                }),
-                            percent_fee: row.get("percent_fee"),
-                            is_placed: row.get("is_placed"),
-                            created_at,
-                            expires_at,
-                            terminated_at,
-                        }
-                        .into()
-                    }
-                    contract::Kind::ManagementForSale => {
-                        contract::ManagementForSale {
-                            id,
-                            name,
-                            description,
-                            realty_id: row.get("realty_id"),
-                            landlord_id: row.get("landlord_id"),
-                            employer_id,
-                            expected_price: Money {
-                                amount: row.get("price"),
-                                currency: row.get("price_currency"),
-                            },
-                            expected_deposit: row
-                                .get::<_, Option<_>>("deposit")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("deposit_currency"),
-                                }),
-                            one_time_fee: row
-                                .get::<_, Option<_>>("one_time_fee")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("one_time_fee_currency"),
-                                }),
-                            monthly_fee: row
-                                .get::<_, Option<_>>("monthly_fee")
-                                .map(|amount| Money {
-                                    amount,
-                                    currency: row.get("monthly_fee_currency"),
-                                }),
-                            percent_fee: row.get("percent_fee"),
-                            is_placed: row.get("is_placed"),
-                            created_at,
-                            expires_at,
-                            terminated_at,
-                        }
-                        .into()
-                    }
-                    contract::Kind::Employment => contract::Employment {
-                        id,
-                        name,
-                        description,
-                        employer_id,
-                        base_salary: Money {
-                            amount: row.get("price"),
-                            currency: row.get("price_currency"),
-                        },
-                        created_at,
-                        expires_at,
-                        terminated_at,
-                    }
-                    .into(),
-                };
-                (id, user)
-            })
+            .map(|row| (row.get("id"), contract_from_row(&row)))
             .collect())
     }
 }
@@ -261,6 +126,87 @@ where
     }
 }
 
+impl<C> Database<Select<By<Option<Contract>, read::contract::ByNumber>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Contract>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Contract>, read::contract::ByNumber>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::contract::ByNumber { agency_id, number } = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
+            FROM contracts \
+            WHERE agency_id = $1::UUID \
+              AND number = $2::VARCHAR \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&agency_id, &number])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| contract_from_row(&row)))
+    }
+}
+
+impl<C> Database<Perform<By<contract::Number, read::contract::NumberSequence>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = contract::Number;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Perform(by): Perform<
+            By<contract::Number, read::contract::NumberSequence>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::contract::NumberSequence {
+            agency_id,
+            kind,
+            year,
+        } = by.into_inner();
+
+        // Incremented counter row stays locked until the end of the
+        // transaction, so the rolled back `Contract`s leave no gaps.
+        const SQL: &str = "\
+            INSERT INTO contract_number_sequences (\
+                agency_id, kind, year, last_value\
+            ) VALUES (\
+                $1::UUID, $2::INT2, $3::INT4, 1\
+            ) \
+            ON CONFLICT (agency_id, kind, year) DO UPDATE \
+            SET last_value = contract_number_sequences.last_value + 1 \
+            RETURNING last_value";
+        let seq: i32 = self
+            .query_opt(SQL, &[&agency_id, &kind, &year])
+            .await
+            .map_err(tracerr::wrap!())?
+            .expect("`RETURNING` always returns a row")
+            .get("last_value");
+        Ok(contract::Number::new(kind, year, seq.unsigned_abs()))
+    }
+}
+
 impl<C> Database<Update<Contract>> for Postgres<C>
 where
     C: Connection,
@@ -276,6 +222,8 @@ where
         #[expect(clippy::type_complexity, reason = "still readable")]
         let (
             id,
+            agency_id,
+            number,
             kind,
             name,
             description,
@@ -283,6 +231,8 @@ where
             employer_id,
             landlord_id,
             purchaser_id,
+            manager_id,
+            branch_id,
             price,
             price_currency,
             deposit,
@@ -295,9 +245,16 @@ where
             is_placed,
             created_at,
             expires_at,
+            completed_at,
             terminated_at,
+            archived_at,
+            version,
+            nightly_rate,
+            check_in_at,
         ): (
             contract::Id,
+            agency::Id,
+            contract::Number,
             contract::Kind,
             contract::Name,
             contract::Description,
@@ -305,6 +262,8 @@ where
             user::Id,
             Option<user::Id>,
             Option<user::Id>,
+            Option<user::Id>,
+            Option<branch::Id>,
             Decimal,
             money::Currency,
             Option<Decimal>,
@@ -317,10 +276,17 @@ where
             Option<bool>,
             contract::CreationDateTime,
             Option<contract::ExpirationDateTime>,
+            Option<contract::CompletionDateTime>,
             Option<contract::TerminationDateTime>,
+            Option<contract::ArchivationDateTime>,
+            Version,
+            Option<Decimal>,
+            Option<contract::short_term_rent::CheckInDateTime>,
         ) = match contract {
             Contract::Rent(c) => (
                 c.id,
+                c.agency_id,
+                c.number,
                 contract::Kind::Rent,
                 c.name,
                 c.description,
@@ -328,6 +294,8 @@ where
                 c.employer_id,
                 Some(c.landlord_id),
                 Some(c.purchaser_id),
+                None,
+                None,
                 c.price.amount,
                 c.price.currency,
                 c.deposit.map(|d| d.amount),
@@ -340,10 +308,17 @@ where
                 None,
                 c.created_at,
                 c.expires_at,
+                c.completed_at,
                 c.terminated_at,
+                c.archived_at,
+                c.version,
+                None,
+                None,
             ),
             Contract::Sale(c) => (
                 c.id,
+                c.agency_id,
+                c.number,
                 contract::Kind::Sale,
                 c.name,
                 c.description,
@@ -351,6 +326,8 @@ where
                 c.employer_id,
                 Some(c.landlord_id),
                 Some(c.purchaser_id),
+                None,
+                None,
                 c.price.amount,
                 c.price.currency,
                 c.deposit.map(|d| d.amount),
@@ -363,10 +340,17 @@ where
                 None,
                 c.created_at,
                 c.expires_at,
+                c.completed_at,
                 c.terminated_at,
+                c.archived_at,
+                c.version,
+                None,
+                None,
             ),
             Contract::ManagementForRent(c) => (
                 c.id,
+                c.agency_id,
+                c.number,
                 contract::Kind::ManagementForRent,
                 c.name,
                 c.description,
@@ -374,6 +358,8 @@ where
                 c.employer_id,
                 Some(c.landlord_id),
                 None,
+                None,
+                None,
                 c.expected_price.amount,
                 c.expected_price.currency,
                 c.expected_deposit.map(|d| d.amount),
@@ -386,10 +372,17 @@ where
                 Some(c.is_placed),
                 c.created_at,
                 c.expires_at,
+                c.completed_at,
                 c.terminated_at,
+                c.archived_at,
+                c.version,
+                None,
+                None,
             ),
             Contract::ManagementForSale(c) => (
                 c.id,
+                c.agency_id,
+                c.number,
                 contract::Kind::ManagementForSale,
                 c.name,
                 c.description,
@@ -397,6 +390,8 @@ where
                 c.employer_id,
                 Some(c.landlord_id),
                 None,
+                None,
+                None,
                 c.expected_price.amount,
                 c.expected_price.currency,
                 c.expected_deposit.map(|d| d.amount),
@@ -409,10 +404,17 @@ where
                 Some(c.is_placed),
                 c.created_at,
                 c.expires_at,
+                c.completed_at,
                 c.terminated_at,
+                c.archived_at,
+                c.version,
+                None,
+                None,
             ),
             Contract::Employment(c) => (
                 c.id,
+                c.agency_id,
+                c.number,
                 contract::Kind::Employment,
                 c.name,
                 c.description,
@@ -420,6 +422,8 @@ where
                 c.employer_id,
                 None,
                 None,
+                c.manager_id,
+                c.branch_id,
                 c.base_salary.amount,
                 c.base_salary.currency,
                 None,
@@ -432,42 +436,91 @@ where
                 None,
                 c.created_at,
                 c.expires_at,
+                c.completed_at,
                 c.terminated_at,
+                c.archived_at,
+                c.version,
+                None,
+                None,
+            ),
+            Contract::ShortTermRent(c) => (
+                c.id,
+                c.agency_id,
+                c.number,
+                contract::Kind::ShortTermRent,
+                c.name,
+                c.description,
+                Some(c.realty_id),
+                c.employer_id,
+                Some(c.landlord_id),
+                Some(c.purchaser_id),
+                None,
+                None,
+                c.price.amount,
+                c.price.currency,
+                c.deposit.map(|d| d.amount),
+                c.deposit.map(|d| d.currency),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                c.created_at,
+                Some(c.expires_at),
+                c.completed_at,
+                c.terminated_at,
+                c.archived_at,
+                c.version,
+                Some(c.nightly_rate.amount),
+                Some(c.check_in_at),
             ),
         };
 
         const SQL: &str = "\
             INSERT INTO contracts (\
-                id, kind, \
+                id, agency_id, number, kind, \
                 name, description, \
                 realty_id, employer_id, landlord_id, purchaser_id, \
+                manager_id, branch_id, \
                 price, price_currency, \
                 deposit, deposit_currency, \
                 one_time_fee, one_time_fee_currency, \
                 monthly_fee, monthly_fee_currency, \
                 percent_fee, \
                 is_placed, \
-                created_at, expires_at, terminated_at\
+                created_at, expires_at, completed_at, terminated_at, \
+                archived_at, version, \
+                nightly_rate, check_in_at, \
+                placed_at\
             ) VALUES (\
-                $1::UUID, $2::INT2, \
-                $3::VARCHAR, $4::VARCHAR, \
-                $5::UUID, $6::UUID, $7::UUID, $8::UUID, \
-                $9::NUMERIC, $10::INT2, \
-                $11::NUMERIC, $12::INT2, \
+                $1::UUID, $2::UUID, $3::VARCHAR, $4::INT2, \
+                $5::VARCHAR, $6::VARCHAR, \
+                $7::UUID, $8::UUID, $9::UUID, $10::UUID, \
+                $11::UUID, $12::UUID, \
                 $13::NUMERIC, $14::INT2, \
                 $15::NUMERIC, $16::INT2, \
-                $17::NUMERIC, \
-                $18::BOOLEAN, \
-                $19::TIMESTAMPTZ, $20::TIMESTAMPTZ, $21::TIMESTAMPTZ\
+                $17::NUMERIC, $18::INT2, \
+                $19::NUMERIC, $20::INT2, \
+                $21::NUMERIC, \
+                $22::BOOLEAN, \
+                $23::TIMESTAMPTZ, $24::TIMESTAMPTZ, \
+                $25::TIMESTAMPTZ, $26::TIMESTAMPTZ, \
+                $31::TIMESTAMPTZ, $27::INT4, \
+                $29::NUMERIC, $30::TIMESTAMPTZ, \
+                CASE WHEN $22::BOOLEAN THEN $23::TIMESTAMPTZ END\
             ) \
             ON CONFLICT (id) DO UPDATE \
-            SET kind = EXCLUDED.kind, \
+            SET agency_id = EXCLUDED.agency_id, \
+                kind = EXCLUDED.kind, \
                 name = EXCLUDED.name, \
                 description = EXCLUDED.description, \
                 realty_id = EXCLUDED.realty_id, \
                 employer_id = EXCLUDED.employer_id, \
                 landlord_id = EXCLUDED.landlord_id, \
                 purchaser_id = EXCLUDED.purchaser_id, \
+                manager_id = EXCLUDED.manager_id, \
+                branch_id = EXCLUDED.branch_id, \
                 price = EXCLUDED.price, \
                 price_currency = EXCLUDED.price_currency, \
                 deposit = EXCLUDED.deposit, \
@@ -478,38 +531,65 @@ where
                 monthly_fee_currency = EXCLUDED.monthly_fee_currency, \
                 percent_fee = EXCLUDED.percent_fee, \
                 is_placed = EXCLUDED.is_placed, \
+                placed_at = CASE \
+                    WHEN EXCLUDED.is_placed AND NOT contracts.is_placed \
+                        THEN NOW() \
+                    ELSE contracts.placed_at \
+                END, \
                 created_at = EXCLUDED.created_at, \
                 expires_at = EXCLUDED.expires_at, \
-                terminated_at = EXCLUDED.terminated_at";
-        self.exec(
-            SQL,
-            &[
-                &id,
-                &kind,
-                &name,
-                &description,
-                &realty_id,
-                &employer_id,
-                &landlord_id,
-                &purchaser_id,
-                &price,
-                &price_currency,
-                &deposit,
-                &deposit_currency,
-                &one_time_fee,
-                &one_time_fee_currency,
-                &monthly_fee,
-                &monthly_fee_currency,
-                &percent_fee,
-                &is_placed,
-                &created_at,
-                &expires_at,
-                &terminated_at,
-            ],
-        )
-        .await
-        .map_err(tracerr::wrap!())
-        .map(drop)
+                completed_at = EXCLUDED.completed_at, \
+                terminated_at = EXCLUDED.terminated_at, \
+                archived_at = EXCLUDED.archived_at, \
+                version = EXCLUDED.version, \
+                nightly_rate = EXCLUDED.nightly_rate, \
+                check_in_at = EXCLUDED.check_in_at \
+            WHERE contracts.version = $28::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &agency_id,
+                    &number,
+                    &kind,
+                    &name,
+                    &description,
+                    &realty_id,
+                    &employer_id,
+                    &landlord_id,
+                    &purchaser_id,
+                    &manager_id,
+                    &branch_id,
+                    &price,
+                    &price_currency,
+                    &deposit,
+                    &deposit_currency,
+                    &one_time_fee,
+                    &one_time_fee_currency,
+                    &monthly_fee,
+                    &monthly_fee_currency,
+                    &percent_fee,
+                    &is_placed,
+                    &created_at,
+                    &expires_at,
+                    &completed_at,
+                    &terminated_at,
+                    &version.next(),
+                    &version,
+                    &nightly_rate,
+                    &check_in_at,
+                    &archived_at,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
     }
 }
 
@@ -570,7 +650,10 @@ where
                     Contract::ManagementForRent(_)
                     | Contract::ManagementForSale(_)
                     | Contract::Rent(_)
-                    | Contract::Sale(_) => unreachable!("already checked"),
+                    | Contract::Sale(_)
+                    | Contract::ShortTermRent(_) => {
+                        unreachable!("already checked")
+                    }
                 })
                 .collect()
         })
@@ -604,6 +687,76 @@ where
     }
 }
 
+impl<C>
+    Database<
+        Select<
+            By<
+                Vec<Active<contract::Employment>>,
+                read::contract::DirectReports,
+            >,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+    Self: Database<
+        Select<By<HashMap<contract::Id, Contract>, Vec<contract::Id>>>,
+        Ok = HashMap<contract::Id, Contract>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Vec<Active<contract::Employment>>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<
+                Vec<Active<contract::Employment>>,
+                read::contract::DirectReports,
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::contract::DirectReports(manager_id) = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id \
+            FROM contracts \
+            WHERE kind = $1::INT2 \
+              AND manager_id = $2::UUID \
+              AND terminated_at IS NULL \
+              AND (expires_at IS NULL OR expires_at > NOW())";
+        let contract_ids = self
+            .query(SQL, &[&contract::Kind::Employment, &manager_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|rows| {
+                rows.into_iter()
+                    .map(|row| row.get("id"))
+                    .collect::<Vec<_>>()
+            })?;
+
+        let mut team = self
+            .execute(Select(By::<HashMap<contract::Id, Contract>, _>::new(
+                contract_ids,
+            )))
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_values()
+            .filter(Contract::is_active)
+            .map(|c| match c {
+                Contract::Employment(c) => Active(c),
+                Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_) => unreachable!("already checked"),
+            })
+            .collect::<Vec<_>>();
+        team.sort_by_key(|Active(c)| c.created_at);
+        Ok(team)
+    }
+}
+
 impl<C>
     Database<
         Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
@@ -653,7 +806,10 @@ where
                     Contract::Employment(_)
                     | Contract::ManagementForSale(_)
                     | Contract::Rent(_)
-                    | Contract::Sale(_) => unreachable!("already checked"),
+                    | Contract::Sale(_)
+                    | Contract::ShortTermRent(_) => {
+                        unreachable!("already checked")
+                    }
                 })
             })
     }
@@ -708,12 +864,128 @@ where
                     Contract::Employment(_)
                     | Contract::ManagementForRent(_)
                     | Contract::Rent(_)
-                    | Contract::Sale(_) => unreachable!("already checked"),
+                    | Contract::Sale(_)
+                    | Contract::ShortTermRent(_) => {
+                        unreachable!("already checked")
+                    }
                 })
             })
     }
 }
 
+impl<C> Database<Select<By<Vec<Active<contract::Rent>>, realty::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Active<contract::Rent>>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Active<contract::Rent>>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
+            FROM contracts \
+            WHERE kind = $1::INT2 \
+              AND realty_id = $2::UUID \
+              AND completed_at IS NULL \
+              AND terminated_at IS NULL \
+              AND (expires_at IS NULL OR expires_at > NOW()) \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM contract_approvals ap \
+                  WHERE ap.contract_id = contracts.id \
+                    AND ap.approved_at IS NULL\
+              ) \
+            ORDER BY created_at";
+        Ok(self
+            .query(SQL, &[&contract::Kind::Rent, &realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| match contract_from_row(row) {
+                Contract::Rent(c) => Active(c),
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_) => unreachable!("already checked"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Active<contract::ShortTermRent>>, realty::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Active<contract::ShortTermRent>>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<Active<contract::ShortTermRent>>, realty::Id>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
+            FROM contracts \
+            WHERE kind = $1::INT2 \
+              AND realty_id = $2::UUID \
+              AND completed_at IS NULL \
+              AND terminated_at IS NULL \
+              AND expires_at > NOW() \
+            ORDER BY check_in_at";
+        Ok(self
+            .query(SQL, &[&contract::Kind::ShortTermRent, &realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| match contract_from_row(row) {
+                Contract::ShortTermRent(c) => Active(c),
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Rent(_)
+                | Contract::Sale(_) => unreachable!("already checked"),
+            })
+            .collect())
+    }
+}
+
 impl<C> Database<Lock<By<Contract, contract::Id>>> for Postgres<C>
 where
     C: Connection,
@@ -728,14 +1000,9 @@ where
         // Avoid subtle change for SQL.
         let id: contract::Id = by.into_inner();
 
-        const SQL: &str = "\
-            INSERT INTO contracts_lock \
-            VALUES ($1::UUID) \
-            ON CONFLICT (id) DO NOTHING";
-        self.query(SQL, &[&id])
+        self.advisory_lock(LockSpace::Contract, &id)
             .await
             .map_err(tracerr::wrap!())
-            .map(drop)
     }
 }
 
@@ -757,19 +1024,23 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let read::contract::list::Selector {
             arguments,
-            filter: read::contract::list::Filter { name },
+            filter:
+                read::contract::list::Filter {
+                    agency_id,
+                    branch_id,
+                    name,
+                    include_archived,
+                    sort_by,
+                },
         } = by.into_inner();
 
         let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
 
         let mut ps: Vec<&(dyn ToSql + Sync)> = vec![&limit];
 
-        let cursor_idx = arguments.cursor().map(|c| {
-            ps.push(c);
-            ps.len()
-        });
-        let name_idx = name.as_ref().map(|n| {
-            ps.push(n);
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
             ps.len()
         });
 
@@ -779,31 +1050,102 @@ where
             ps.len()
         });
 
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let branch_id_idx = branch_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let relevance = name_pattern_idx.map_or_else(
+            || "0".to_owned(),
+            |idx| format!("${idx}::VARCHAR <<-> name"),
+        );
+        let sort_key = match sort_by {
+            SortBy::Relevance => name_pattern_idx.map(|_| relevance.as_str()),
+            SortBy::Name => Some("name"),
+            SortBy::CreatedAt => Some("created_at"),
+        };
+
+        // Cursor of another `SortBy` order can only be positioned by ID.
+        let cursor = arguments.cursor().map(|c| {
+            let key_idx = sort_key
+                .filter(|_| c.key.sort_by() == sort_by)
+                .map(|_| {
+                    let (key, ty): (&(dyn ToSql + Sync), _) = match &c.key {
+                        SortKey::Relevance(r) => (r, "FLOAT4"),
+                        SortKey::Name(n) => (n, "VARCHAR"),
+                        SortKey::CreatedAt(at) => (at, "TIMESTAMPTZ"),
+                    };
+                    ps.push(key);
+                    (ps.len(), ty)
+                });
+            ps.push(&c.id);
+            (key_idx, ps.len())
+        });
+
         let sql = format!(
-            "SELECT id, kind \
+            "SELECT id, agency_id, number, kind, \
+                    name, description, \
+                    realty_id, employer_id, landlord_id, purchaser_id, \
+                    manager_id, branch_id, \
+                    price, price_currency, \
+                    deposit, deposit_currency, \
+                    one_time_fee, one_time_fee_currency, \
+                    monthly_fee, monthly_fee_currency, \
+                    percent_fee, \
+                    is_placed, \
+                    created_at, expires_at, completed_at, terminated_at, \
+                    archived_at, version, \
+                    nightly_rate, check_in_at, \
+                    {relevance}::FLOAT4 AS relevance \
              FROM contracts \
              WHERE true \
+                   {agency_filtering} \
+                   {branch_filtering} \
+                   {archived_filtering} \
                    {cursor} \
                    {name_filtering} \
-             ORDER BY {name_ordering} \
-                      id ASC \
-             LIMIT $1::INT4",
-            cursor = cursor_idx.into_iter().format_with("", |idx, f| {
+             ORDER BY {sort_ordering} \
+                      id {order} \
+             LIMIT $1::INT4 \
+             {offset}",
+            cursor = cursor.into_iter().format_with("", |(key, idx), f| {
                 let op = arguments.kind().operator();
-                f(&format_args!("AND id {op} ${idx}::UUID"))
+                match (key, sort_key) {
+                    (Some((key_idx, ty)), Some(sort_key)) => f(&format_args!(
+                        "AND ({sort_key}, id) {op} \
+                             (${key_idx}::{ty}, ${idx}::UUID)"
+                    )),
+                    _ => f(&format_args!("AND id {op} ${idx}::UUID")),
+                }
             }),
+            order = arguments.kind().order().sql(),
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND agency_id = ${idx}::UUID"))
+                }),
+            branch_filtering =
+                branch_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND branch_id = ${idx}::UUID"))
+                }),
+            archived_filtering = (!include_archived)
+                .then_some("AND archived_at IS NULL")
+                .unwrap_or_default(),
             name_filtering =
                 name_pattern_idx.into_iter().format_with("", |idx, f| {
-                    f(&format_args!(
-                        "AND LOWER(name) SIMILAR TO LOWER(${idx}::VARCHAR)"
-                    ))
+                    f(&format_args!("AND ${idx}::VARCHAR <% name"))
                 }),
-            name_ordering = name_idx.into_iter().format_with("", |idx, f| {
+            sort_ordering = sort_key.into_iter().format_with("", |key, f| {
                 let order = arguments.kind().order().sql();
-                f(&format_args!(
-                    "LEVENSHTEIN(name, ${idx}::VARCHAR, 1, 1, 0) {order},"
-                ))
-            })
+                f(&format_args!("{key} {order},"))
+            }),
         );
         let rows = self
             .query(&sql, ps.as_slice())
@@ -815,9 +1157,13 @@ where
             .into_iter()
             .take(arguments.limit())
             .map(|row| {
-                let id = row.get("id");
-                let kind = row.get("kind");
-                (id, (id, kind))
+                let node = contract_from_row(&row);
+                let cursor = read::contract::list::Cursor::new(
+                    sort_by,
+                    &node,
+                    row.get("relevance"),
+                );
+                (cursor, node)
             })
             .collect::<Vec<_>>();
 
@@ -825,34 +1171,228 @@ where
     }
 }
 
-impl<C> Database<Select<By<read::contract::list::TotalCount, ()>>>
+impl<C> Database<Select<By<Vec<Contract>, Expired>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Contract>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Contract>, Expired>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Expired { limit, task } = by.into_inner();
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+
+        const SQL: &str = "\
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
+            FROM contracts \
+            WHERE completed_at IS NULL \
+              AND terminated_at IS NULL \
+              AND expires_at <= NOW() \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM task_dead_letters \
+                  WHERE task = $2::TEXT AND item = contracts.id::TEXT\
+              ) \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM contract_approvals ap \
+                  WHERE ap.contract_id = contracts.id \
+                    AND ap.approved_at IS NULL\
+              ) \
+            ORDER BY expires_at \
+            LIMIT $1::INT4";
+        Ok(self
+            .query(SQL, &[&limit, &task])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(contract_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Contract>, read::contract::Expiring>>>
     for Postgres<C>
 where
     C: Connection,
 {
-    type Ok = read::contract::list::TotalCount;
+    type Ok = Vec<Contract>;
     type Err = Traced<database::Error>;
 
     async fn execute(
         &self,
-        Select(_): Select<By<read::contract::list::TotalCount, ()>>,
+        Select(by): Select<By<Vec<Contract>, read::contract::Expiring>>,
     ) -> Result<Self::Ok, Self::Err> {
+        let read::contract::Expiring {
+            user_id,
+            since,
+            limit,
+        } = by.into_inner();
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+
         const SQL: &str = "\
-            SELECT COUNT(*)::INT4 \
-            FROM contracts";
-        self.query_opt(SQL, &[])
+            SELECT id, agency_id, number, kind, \
+                   name, description, \
+                   realty_id, employer_id, landlord_id, purchaser_id, \
+                   manager_id, branch_id, \
+                   price, price_currency, \
+                   deposit, deposit_currency, \
+                   one_time_fee, one_time_fee_currency, \
+                   monthly_fee, monthly_fee_currency, \
+                   percent_fee, \
+                   is_placed, \
+                   created_at, expires_at, completed_at, terminated_at, \
+                   archived_at, version, \
+                   nightly_rate, check_in_at \
+            FROM contracts \
+            WHERE completed_at IS NULL \
+              AND terminated_at IS NULL \
+              AND expires_at >= $2::TIMESTAMPTZ \
+              AND EXISTS (\
+                  SELECT 1 FROM contract_parties \
+                  WHERE contract_id = contracts.id AND user_id = $1::UUID\
+              ) \
+            ORDER BY expires_at \
+            LIMIT $3::INT4";
+        Ok(self
+            .query(SQL, &[&user_id, &since, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(contract_from_row)
+            .collect())
+    }
+}
+
+impl<C>
+    Database<
+        Select<
+            By<read::contract::list::TotalCount, read::contract::list::Filter>,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::contract::list::TotalCount;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<read::contract::list::TotalCount, read::contract::list::Filter>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let filter = by.into_inner();
+        let name_pattern =
+            filter.name.as_ref().map(|n| FuzzPattern::new(n.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT COUNT(*)::INT4 \
+             FROM contracts \
+             WHERE true {}",
+            list_filtering(&filter, name_pattern.as_ref(), &mut ps),
+        );
+        self.query_opt(&sql, ps.as_slice())
             .await
             .map_err(tracerr::wrap!())
             .map(|row| row.expect("always exists").get::<_, i32>(0).into())
     }
 }
 
+impl<C>
+    Database<
+        Select<
+            By<
+                read::Estimated<read::contract::list::TotalCount>,
+                read::contract::list::Filter,
+            >,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::Estimated<read::contract::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<
+                read::Estimated<read::contract::list::TotalCount>,
+                read::contract::list::Filter,
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let filter = by.into_inner();
+        let name_pattern =
+            filter.name.as_ref().map(|n| FuzzPattern::new(n.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT 1 \
+             FROM contracts \
+             WHERE true {}",
+            list_filtering(&filter, name_pattern.as_ref(), &mut ps),
+        );
+        self.estimate_rows(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|count| read::Estimated(count.into()))
+    }
+}
+
+impl<C>
+    Database<
+        Select<By<HashMap<agency::Id, read::contract::list::TotalCount>, ()>>,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = HashMap<agency::Id, read::contract::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Select<
+            By<HashMap<agency::Id, read::contract::list::TotalCount>, ()>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "\
+            SELECT agency_id, COUNT(*)::INT4 \
+            FROM contracts \
+            WHERE archived_at IS NULL \
+            GROUP BY agency_id";
+        self.query(SQL, &[])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| (row.get(0), row.get::<_, i32>(1).into()))
+                    .collect()
+            })
+    }
+}
+
 impl<C>
     Database<
         Select<
             By<
                 read::contract::list::TotalCount,
-                RangeInclusive<contract::CreationDateTime>,
+                (agency::Id, RangeInclusive<contract::CreationDateTime>),
             >,
         >,
     > for Postgres<C>
@@ -867,19 +1407,23 @@ where
         Select(by): Select<
             By<
                 read::contract::list::TotalCount,
-                RangeInclusive<contract::CreationDateTime>,
+                (agency::Id, RangeInclusive<contract::CreationDateTime>),
             >,
         >,
     ) -> Result<Self::Ok, Self::Err> {
         // Avoid subtle change for SQL.
-        let range: RangeInclusive<contract::CreationDateTime> = by.into_inner();
+        let (agency_id, range): (
+            agency::Id,
+            RangeInclusive<contract::CreationDateTime>,
+        ) = by.into_inner();
 
         const SQL: &str = "\
             SELECT COUNT(id)::INT4 \
             FROM contracts \
             WHERE kind IN (SELECT unnest($1::INT2[]) LIMIT $2::INT4) \
               AND created_at >= $3::TIMESTAMPTZ \
-              AND created_at <= $4::TIMESTAMPTZ";
+              AND created_at <= $4::TIMESTAMPTZ \
+              AND agency_id = $5::UUID";
         self.query_opt(
             SQL,
             &[
@@ -893,6 +1437,7 @@ where
                 &4i32,
                 range.start(),
                 range.end(),
+                &agency_id,
             ],
         )
         .await
@@ -905,36 +1450,46 @@ impl<C>
     Database<
         Select<
             By<
-                HashMap<user::Id, read::contract::list::TotalCount>,
-                RangeInclusive<contract::CreationDateTime>,
+                HashMap<user::Id, read::contract::Attribution>,
+                (agency::Id, RangeInclusive<contract::CreationDateTime>),
             >,
         >,
     > for Postgres<C>
 where
     C: Connection,
 {
-    type Ok = HashMap<user::Id, read::contract::list::TotalCount>;
+    type Ok = HashMap<user::Id, read::contract::Attribution>;
     type Err = Traced<database::Error>;
 
     async fn execute(
         &self,
         Select(by): Select<
             By<
-                HashMap<user::Id, read::contract::list::TotalCount>,
-                RangeInclusive<contract::CreationDateTime>,
+                HashMap<user::Id, read::contract::Attribution>,
+                (agency::Id, RangeInclusive<contract::CreationDateTime>),
             >,
         >,
     ) -> Result<Self::Ok, Self::Err> {
         // Avoid subtle change for SQL.
-        let range: RangeInclusive<contract::CreationDateTime> = by.into_inner();
+        let (agency_id, range): (
+            agency::Id,
+            RangeInclusive<contract::CreationDateTime>,
+        ) = by.into_inner();
 
+        // `Contract`s without commission splits are attributed solely to
+        // their signing employer.
         const SQL: &str = "\
-            SELECT employer_id, COUNT(id)::INT4 AS count \
-            FROM contracts \
-            WHERE kind IN (SELECT unnest($1::INT2[]) LIMIT $2::INT4) \
-              AND created_at >= $3::TIMESTAMPTZ \
-              AND created_at <= $4::TIMESTAMPTZ \
-            GROUP BY employer_id";
+            SELECT COALESCE(s.employer_id, c.employer_id) AS employer_id, \
+                   COUNT(c.id)::INT4 AS count, \
+                   SUM(COALESCE(s.share / 100, 1))::NUMERIC AS credit \
+            FROM contracts AS c \
+            LEFT JOIN contract_commission_splits AS s \
+                   ON s.contract_id = c.id \
+            WHERE c.kind IN (SELECT unnest($1::INT2[]) LIMIT $2::INT4) \
+              AND c.created_at >= $3::TIMESTAMPTZ \
+              AND c.created_at <= $4::TIMESTAMPTZ \
+              AND c.agency_id = $5::UUID \
+            GROUP BY COALESCE(s.employer_id, c.employer_id)";
         self.query(
             SQL,
             &[
@@ -948,6 +1503,7 @@ where
                 &4i32,
                 range.start(),
                 range.end(),
+                &agency_id,
             ],
         )
         .await
@@ -956,10 +1512,300 @@ where
             rows.into_iter()
                 .map(|row| {
                     let employer_id = row.get::<_, user::Id>("employer_id");
-                    let count = row.get::<_, i32>("count");
-                    (employer_id, count.into())
+                    let attribution = read::contract::Attribution {
+                        contracts: row.get::<_, i32>("count").into(),
+                        credit: row.get("credit"),
+                    };
+                    (employer_id, attribution)
                 })
                 .collect()
         })
     }
 }
+
+impl<C> Database<Select<By<read::contract::Counts, agency::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::contract::Counts;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<read::contract::Counts, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let agency_id = by.into_inner();
+
+        // Mirrors `Contract::status()`.
+        const SQL: &str = "\
+            SELECT CASE \
+                       WHEN terminated_at IS NOT NULL THEN 3 \
+                       WHEN completed_at IS NOT NULL \
+                         OR expires_at < NOW() THEN 2 \
+                       ELSE 1 \
+                   END::INT2 AS status, \
+                   kind, \
+                   COUNT(*)::INT4 AS count \
+            FROM contracts \
+            WHERE agency_id = $1::UUID \
+            GROUP BY 1, 2";
+        Ok(read::contract::Counts(
+            self.query(SQL, &[&agency_id])
+                .await
+                .map_err(tracerr::wrap!())?
+                .into_iter()
+                .map(|row| {
+                    let status = match row.get::<_, i16>("status") {
+                        1 => contract::Status::Active,
+                        2 => contract::Status::Completed,
+                        _ => contract::Status::Terminated,
+                    };
+                    let count = row.get::<_, i32>("count").into();
+                    (status, row.get("kind"), count)
+                })
+                .collect(),
+        ))
+    }
+}
+
+/// Maps the provided [`Row`] of the `contracts` table into a [`Contract`].
+pub(super) fn contract_from_row(row: &Row) -> Contract {
+    let id = row.get("id");
+    let agency_id = row.get("agency_id");
+    let number = row.get("number");
+    let name = row.get("name");
+    let description = row.get("description");
+    let employer_id = row.get("employer_id");
+    let created_at = row.get("created_at");
+    let expires_at = row.get("expires_at");
+    let completed_at = row.get("completed_at");
+    let terminated_at = row.get("terminated_at");
+    let archived_at = row.get("archived_at");
+    let version = row.get("version");
+    match row.get("kind") {
+        contract::Kind::Rent => contract::Rent {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            realty_id: row.get("realty_id"),
+            purchaser_id: row.get("purchaser_id"),
+            landlord_id: row.get("landlord_id"),
+            employer_id,
+            price: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            deposit: row.get::<_, Option<_>>("deposit").map(|amount| Money {
+                amount,
+                currency: row.get("deposit_currency"),
+            }),
+            created_at,
+            expires_at,
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+        contract::Kind::Sale => contract::Sale {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            realty_id: row.get("realty_id"),
+            purchaser_id: row.get("purchaser_id"),
+            landlord_id: row.get("landlord_id"),
+            employer_id,
+            price: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            deposit: row.get::<_, Option<_>>("deposit").map(|amount| Money {
+                amount,
+                currency: row.get("deposit_currency"),
+            }),
+            created_at,
+            expires_at,
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+        contract::Kind::ManagementForRent => contract::ManagementForRent {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            realty_id: row.get("realty_id"),
+            landlord_id: row.get("landlord_id"),
+            employer_id,
+            expected_price: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            expected_deposit: row
+                .get::<_, Option<_>>("deposit")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("deposit_currency"),
+                }),
+            one_time_fee: row
+                .get::<_, Option<_>>("one_time_fee")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("one_time_fee_currency"),
+                }),
+            monthly_fee: row
+                .get::<_, Option<_>>("monthly_fee")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("monthly_fee_currency"),
+                }),
+            percent_fee: row.get("percent_fee"),
+            is_placed: row.get("is_placed"),
+            created_at,
+            expires_at,
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+        contract::Kind::ManagementForSale => contract::ManagementForSale {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            realty_id: row.get("realty_id"),
+            landlord_id: row.get("landlord_id"),
+            employer_id,
+            expected_price: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            expected_deposit: row
+                .get::<_, Option<_>>("deposit")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("deposit_currency"),
+                }),
+            one_time_fee: row
+                .get::<_, Option<_>>("one_time_fee")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("one_time_fee_currency"),
+                }),
+            monthly_fee: row
+                .get::<_, Option<_>>("monthly_fee")
+                .map(|amount| Money {
+                    amount,
+                    currency: row.get("monthly_fee_currency"),
+                }),
+            percent_fee: row.get("percent_fee"),
+            is_placed: row.get("is_placed"),
+            created_at,
+            expires_at,
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+        contract::Kind::Employment => contract::Employment {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            employer_id,
+            manager_id: row.get("manager_id"),
+            branch_id: row.get("branch_id"),
+            base_salary: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            created_at,
+            expires_at,
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+        contract::Kind::ShortTermRent => contract::ShortTermRent {
+            id,
+            agency_id,
+            number,
+            name,
+            description,
+            realty_id: row.get("realty_id"),
+            purchaser_id: row.get("purchaser_id"),
+            landlord_id: row.get("landlord_id"),
+            employer_id,
+            nightly_rate: Money {
+                amount: row.get("nightly_rate"),
+                currency: row.get("price_currency"),
+            },
+            price: Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            },
+            deposit: row.get::<_, Option<_>>("deposit").map(|amount| Money {
+                amount,
+                currency: row.get("deposit_currency"),
+            }),
+            check_in_at: row.get("check_in_at"),
+            created_at,
+            expires_at: expires_at.expect("`ShortTermRent` always expires"),
+            completed_at,
+            terminated_at,
+            archived_at,
+            version,
+        }
+        .into(),
+    }
+}
+
+/// Returns the SQL conditions selecting the [`Contract`]s matching the
+/// provided [`read::contract::list::Filter`], pushing their parameters into
+/// the provided `ps`.
+///
+/// The `name_pattern` is expected to be built out of the
+/// [`read::contract::list::Filter::name`].
+fn list_filtering<'a>(
+    filter: &'a read::contract::list::Filter,
+    name_pattern: Option<&'a FuzzPattern>,
+    ps: &mut Vec<&'a (dyn ToSql + Sync)>,
+) -> String {
+    let read::contract::list::Filter {
+        agency_id,
+        branch_id,
+        name: _,
+        include_archived,
+        sort_by: _,
+    } = filter;
+
+    let mut conditions = vec![];
+    if let Some(id) = agency_id {
+        ps.push(id);
+        conditions.push(format!("AND agency_id = ${}::UUID", ps.len()));
+    }
+    if let Some(id) = branch_id {
+        ps.push(id);
+        conditions.push(format!("AND branch_id = ${}::UUID", ps.len()));
+    }
+    if !include_archived {
+        conditions.push("AND archived_at IS NULL".to_owned());
+    }
+    if let Some(pattern) = name_pattern {
+        ps.push(pattern);
+        conditions.push(format!("AND ${}::VARCHAR <% name", ps.len()));
+    }
+    conditions.join(" ")
+}
@@ -0,0 +1,112 @@
+//! [`read::Dashboard`]-related [`Database`] implementations.
+
+use common::{
+    money,
+    operations::{By, Select},
+    Money,
+};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, lead},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<read::Dashboard, read::dashboard::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::Dashboard;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<read::Dashboard, read::dashboard::Selector>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::dashboard::Selector {
+            agency_id,
+            expiring_until,
+            revenue_since,
+        } = by.into_inner();
+
+        // Everything is selected at once to avoid multiple round trips.
+        const SQL: &str = "\
+            SELECT (SELECT COUNT(*)::INT4 \
+                    FROM realties \
+                    WHERE agency_id = $1::UUID \
+                      AND EXISTS(SELECT id \
+                                 FROM contracts \
+                                 WHERE kind IN ($2::INT2, $3::INT2) \
+                                   AND is_placed \
+                                   AND terminated_at IS NULL \
+                                   AND (expires_at IS NULL \
+                                        OR expires_at > NOW()) \
+                                   AND realty_id = realties.id \
+                                 LIMIT 1)\
+                   ) AS active_placements, \
+                   (SELECT COUNT(*)::INT4 \
+                    FROM contracts \
+                    WHERE agency_id = $1::UUID \
+                      AND completed_at IS NULL \
+                      AND terminated_at IS NULL \
+                      AND expires_at > NOW() \
+                      AND expires_at <= $4::TIMESTAMPTZ\
+                   ) AS expiring_contracts, \
+                   (SELECT COUNT(*)::INT4 \
+                    FROM leads \
+                    WHERE agency_id = $1::UUID \
+                      AND stage <> $5::INT2\
+                   ) AS open_leads, \
+                   (SELECT COUNT(*)::INT4 \
+                    FROM rent_arrears \
+                    WHERE agency_id = $1::UUID\
+                   ) AS overdue_payments, \
+                   ARRAY(SELECT SUM(amount)::NUMERIC \
+                         FROM rent_payments \
+                         WHERE agency_id = $1::UUID \
+                           AND paid_at >= $6::TIMESTAMPTZ \
+                         GROUP BY amount_currency \
+                         ORDER BY amount_currency\
+                   ) AS revenue, \
+                   ARRAY(SELECT amount_currency \
+                         FROM rent_payments \
+                         WHERE agency_id = $1::UUID \
+                           AND paid_at >= $6::TIMESTAMPTZ \
+                         GROUP BY amount_currency \
+                         ORDER BY amount_currency\
+                   ) AS revenue_currency";
+        let row = self
+            .query_opt(
+                SQL,
+                &[
+                    &agency_id,
+                    &contract::Kind::ManagementForRent,
+                    &contract::Kind::ManagementForSale,
+                    &expiring_until,
+                    &lead::Stage::Closed,
+                    &revenue_since,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .expect("always exists");
+        Ok(read::Dashboard {
+            active_placements: row.get("active_placements"),
+            expiring_contracts: row.get("expiring_contracts"),
+            open_leads: row.get("open_leads"),
+            overdue_payments: row.get("overdue_payments"),
+            revenue: row
+                .get::<_, Vec<Decimal>>("revenue")
+                .into_iter()
+                .zip(row.get::<_, Vec<money::Currency>>("revenue_currency"))
+                .map(|(amount, currency)| Money { amount, currency })
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,130 @@
+//! [`FloorPlan`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{realty, FloorPlan},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<FloorPlan>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<FloorPlan>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<FloorPlan>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT realty_id, floor, label, format, url \
+            FROM realty_floor_plans \
+            WHERE realty_id = $1::UUID \
+            ORDER BY floor, label";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(floor_plan_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<FloorPlan>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(plans): Insert<Vec<FloorPlan>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if plans.is_empty() {
+            return Ok(());
+        }
+        let total = plans.len();
+
+        let mut realty_ids = Vec::with_capacity(total);
+        let mut floors = Vec::with_capacity(total);
+        let mut labels = Vec::with_capacity(total);
+        let mut formats = Vec::with_capacity(total);
+        let mut urls = Vec::with_capacity(total);
+        for p in plans {
+            realty_ids.push(p.realty_id);
+            floors.push(i32::from(p.floor));
+            labels.push(p.label);
+            formats.push(p.format);
+            urls.push(p.url);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO realty_floor_plans (\
+                realty_id, floor, label, format, url\
+            ) \
+            SELECT realty_id, floor, label, format, url \
+            FROM unnest(\
+                $1::UUID[], \
+                $2::INT4[], \
+                $3::VARCHAR[], \
+                $4::INT2[], \
+                $5::VARCHAR[]\
+            ) AS p(realty_id, floor, label, format, url) \
+            ON CONFLICT (realty_id, floor, label) DO UPDATE \
+            SET format = EXCLUDED.format, \
+                url = EXCLUDED.url";
+        self.exec(SQL, &[&realty_ids, &floors, &labels, &formats, &urls])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<FloorPlan, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<FloorPlan, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM realty_floor_plans \
+            WHERE realty_id = $1::UUID";
+        self.exec(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `realty_floor_plans` table into a
+/// [`FloorPlan`].
+fn floor_plan_from_row(row: &Row) -> FloorPlan {
+    FloorPlan {
+        realty_id: row.get("realty_id"),
+        floor: u16::try_from(row.get::<_, i32>("floor"))
+            .expect("`floor` overflow"),
+        label: row.get("label"),
+        format: row.get("format"),
+        url: row.get("url"),
+    }
+}
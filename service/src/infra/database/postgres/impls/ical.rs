@@ -0,0 +1,96 @@
+//! [`ical::Feed`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Select, Update};
+use tracerr::Traced;
+
+use crate::{
+    domain::{ical, user},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<ical::Feed>, ical::TokenHash>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<ical::Feed>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<ical::Feed>, ical::TokenHash>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let token_hash = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT user_id, token_hash, created_at \
+            FROM user_calendar_feeds \
+            WHERE token_hash = $1::VARCHAR";
+        Ok(self
+            .query_opt(SQL, &[&token_hash])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| ical::Feed {
+                user_id: row.get("user_id"),
+                token_hash: row.get("token_hash"),
+                created_at: row.get("created_at"),
+            }))
+    }
+}
+
+impl<C> Database<Update<ical::Feed>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(feed): Update<ical::Feed>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let ical::Feed {
+            user_id,
+            token_hash,
+            created_at,
+        } = feed;
+
+        const SQL: &str = "\
+            INSERT INTO user_calendar_feeds (user_id, token_hash, created_at) \
+            VALUES ($1::UUID, $2::VARCHAR, $3::TIMESTAMPTZ) \
+            ON CONFLICT (user_id) DO UPDATE \
+            SET token_hash = EXCLUDED.token_hash, \
+                created_at = EXCLUDED.created_at";
+        self.exec(SQL, &[&user_id, &token_hash, &created_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<ical::Feed, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<ical::Feed, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let user_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM user_calendar_feeds \
+            WHERE user_id = $1::UUID";
+        self.exec(SQL, &[&user_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
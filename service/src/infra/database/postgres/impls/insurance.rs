@@ -0,0 +1,214 @@
+//! [`Insurance`]-related [`Database`] implementations.
+
+use common::{
+    operations::{By, Delete, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, Insurance},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<Insurance>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Insurance>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Insurance>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, provider, policy_number, \
+                   coverage, coverage_currency, expires_at, \
+                   creator_id, created_at, notified_at \
+            FROM contract_insurances \
+            WHERE contract_id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| insurance_from_row(&row)))
+    }
+}
+
+impl<C>
+    Database<
+        Select<
+            By<Vec<read::insurance::Expiring>, read::insurance::Unnotified>,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<read::insurance::Expiring>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<read::insurance::Expiring>, read::insurance::Unnotified>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::insurance::Unnotified {
+            expiring_by,
+            after,
+            limit,
+        } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            SELECT i.id, i.agency_id, i.contract_id, \
+                   i.provider, i.policy_number, \
+                   i.coverage, i.coverage_currency, i.expires_at, \
+                   i.creator_id, i.created_at, i.notified_at, \
+                   c.employer_id, u.email AS employer_email, s.locale \
+            FROM contract_insurances i \
+            INNER JOIN contracts c ON c.id = i.contract_id \
+            LEFT JOIN users u ON u.id = c.employer_id \
+                             AND u.deleted_at IS NULL \
+            LEFT JOIN agency_settings s ON s.agency_id = i.agency_id \
+            WHERE i.notified_at IS NULL \
+              AND i.expires_at <= $1::TIMESTAMPTZ \
+              AND ($2::UUID IS NULL OR i.id > $2::UUID) \
+              AND c.completed_at IS NULL \
+              AND c.terminated_at IS NULL \
+            ORDER BY i.id \
+            LIMIT $3::INT8";
+        Ok(self
+            .query(SQL, &[&expiring_by, &after, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| read::insurance::Expiring {
+                insurance: insurance_from_row(row),
+                employer_id: row.get("employer_id"),
+                employer_email: row.get("employer_email"),
+                locale: row.get("locale"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Insurance>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(insurance): Insert<Insurance>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Insurance {
+            id,
+            agency_id,
+            contract_id,
+            provider,
+            policy_number,
+            coverage,
+            expires_at,
+            creator_id,
+            created_at,
+            notified_at,
+        } = insurance;
+
+        const SQL: &str = "\
+            INSERT INTO contract_insurances (\
+                id, agency_id, contract_id, provider, policy_number, \
+                coverage, coverage_currency, expires_at, \
+                creator_id, created_at, notified_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::VARCHAR, $5::VARCHAR, \
+                $6::NUMERIC, $7::INT2, $8::TIMESTAMPTZ, \
+                $9::UUID, $10::TIMESTAMPTZ, $11::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (contract_id) DO UPDATE \
+            SET id = EXCLUDED.id, \
+                provider = EXCLUDED.provider, \
+                policy_number = EXCLUDED.policy_number, \
+                coverage = EXCLUDED.coverage, \
+                coverage_currency = EXCLUDED.coverage_currency, \
+                expires_at = EXCLUDED.expires_at, \
+                creator_id = EXCLUDED.creator_id, \
+                created_at = EXCLUDED.created_at, \
+                notified_at = EXCLUDED.notified_at";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &contract_id,
+                &provider,
+                &policy_number,
+                &coverage.amount,
+                &coverage.currency,
+                &expires_at,
+                &creator_id,
+                &created_at,
+                &notified_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<Insurance, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<Insurance, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM contract_insurances \
+            WHERE contract_id = $1::UUID";
+        self.exec(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `contract_insurances` table into an
+/// [`Insurance`].
+fn insurance_from_row(row: &Row) -> Insurance {
+    Insurance {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        contract_id: row.get("contract_id"),
+        provider: row.get("provider"),
+        policy_number: row.get("policy_number"),
+        coverage: Money {
+            amount: row.get("coverage"),
+            currency: row.get("coverage_currency"),
+        },
+        expires_at: row.get("expires_at"),
+        creator_id: row.get("creator_id"),
+        created_at: row.get("created_at"),
+        notified_at: row.get("notified_at"),
+    }
+}
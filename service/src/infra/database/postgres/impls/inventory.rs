@@ -0,0 +1,136 @@
+//! [`Inventory`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, inventory, Inventory},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<Inventory>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Inventory>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Inventory>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT v.id, v.agency_id, v.contract_id, v.stage, \
+                   v.recorder_id, v.recorded_at, \
+                   i.room, i.name, i.condition, i.photos \
+            FROM rent_inventories v \
+            LEFT JOIN rent_inventory_items i ON i.inventory_id = v.id \
+            WHERE v.contract_id = $1::UUID \
+            ORDER BY v.stage, i.position";
+        let mut inventories = Vec::<Inventory>::new();
+        for row in self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+        {
+            let id: inventory::Id = row.get("id");
+            if inventories.last().is_none_or(|i| i.id != id) {
+                inventories.push(Inventory {
+                    id,
+                    agency_id: row.get("agency_id"),
+                    contract_id: row.get("contract_id"),
+                    stage: row.get("stage"),
+                    items: vec![],
+                    recorder_id: row.get("recorder_id"),
+                    recorded_at: row.get("recorded_at"),
+                });
+            }
+            if let Some(room) = row.get("room") {
+                inventories.last_mut().expect("pushed above").items.push(
+                    inventory::Item {
+                        room,
+                        name: row.get("name"),
+                        condition: row.get("condition"),
+                        photos: row.get("photos"),
+                    },
+                );
+            }
+        }
+        Ok(inventories)
+    }
+}
+
+impl<C> Database<Insert<Inventory>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(inventory): Insert<Inventory>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Inventory {
+            id,
+            agency_id,
+            contract_id,
+            stage,
+            items,
+            recorder_id,
+            recorded_at,
+        } = inventory;
+
+        const SQL: &str = "\
+            INSERT INTO rent_inventories (\
+                id, agency_id, contract_id, stage, recorder_id, recorded_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::INT2, $5::UUID, \
+                $6::TIMESTAMPTZ\
+            )";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &contract_id,
+                &stage,
+                &recorder_id,
+                &recorded_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)?;
+
+        const ITEM_SQL: &str = "\
+            INSERT INTO rent_inventory_items (\
+                inventory_id, position, room, name, condition, photos\
+            ) VALUES (\
+                $1::UUID, $2::INT4, $3::VARCHAR, $4::VARCHAR, $5::INT2, \
+                $6::VARCHAR[]\
+            )";
+        for (position, item) in (0_i32..).zip(items) {
+            let inventory::Item {
+                room,
+                name,
+                condition,
+                photos,
+            } = item;
+            self.exec(
+                ITEM_SQL,
+                &[&id, &position, &room, &name, &condition, &photos],
+            )
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+//! [`user::Invite`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tracerr::Traced;
+
+use crate::{
+    domain::user,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<user::Invite>, user::invite::TokenHash>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<user::Invite>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<user::Invite>, user::invite::TokenHash>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let token_hash = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT user_id, agency_id, token_hash, expires_at, created_at \
+            FROM user_invites \
+            WHERE token_hash = $1::VARCHAR";
+        Ok(self
+            .query_opt(SQL, &[&token_hash])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| user::Invite {
+                user_id: row.get("user_id"),
+                agency_id: row.get("agency_id"),
+                token_hash: row.get("token_hash"),
+                expires_at: row.get("expires_at"),
+                created_at: row.get("created_at"),
+            }))
+    }
+}
+
+impl<C> Database<Insert<user::Invite>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(invite): Insert<user::Invite>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let user::Invite {
+            user_id,
+            agency_id,
+            token_hash,
+            expires_at,
+            created_at,
+        } = invite;
+
+        // Re-inviting a `User` revokes the previous `user::Invite`.
+        const SQL: &str = "\
+            INSERT INTO user_invites (\
+                user_id, agency_id, token_hash, expires_at, created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::VARCHAR, \
+                $4::TIMESTAMPTZ, $5::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (user_id) DO UPDATE \
+            SET agency_id = EXCLUDED.agency_id, \
+                token_hash = EXCLUDED.token_hash, \
+                expires_at = EXCLUDED.expires_at, \
+                created_at = EXCLUDED.created_at";
+        self.exec(
+            SQL,
+            &[&user_id, &agency_id, &token_hash, &expires_at, &created_at],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<user::Invite, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<user::Invite, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let user_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM user_invites \
+            WHERE user_id = $1::UUID";
+        self.exec(SQL, &[&user_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
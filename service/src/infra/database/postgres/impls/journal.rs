@@ -0,0 +1,39 @@
+//! [`journal::Export`]-related [`Database`] implementations.
+
+use common::operations::Insert;
+use tracerr::Traced;
+
+use crate::{
+    domain::journal,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Insert<journal::Export>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(export): Insert<journal::Export>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let journal::Export {
+            payment_id,
+            exported_at,
+        } = export;
+
+        const SQL: &str = "\
+            INSERT INTO journal_exports (payment_id, exported_at) \
+            VALUES ($1::UUID, $2::TIMESTAMPTZ) \
+            ON CONFLICT (payment_id) DO NOTHING";
+        self.exec(SQL, &[&payment_id, &exported_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
@@ -0,0 +1,180 @@
+//! [`Key`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{key, realty, Key},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<Key>, key::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Key>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Key>, key::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: key::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, realty_id, label, \
+                   holder_id, issuer_id, checked_out_at, due_at, \
+                   created_at \
+            FROM realty_keys \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| key_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Key>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Key>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Key>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, realty_id, label, \
+                   holder_id, issuer_id, checked_out_at, due_at, \
+                   created_at \
+            FROM realty_keys \
+            WHERE realty_id = $1::UUID \
+            ORDER BY label";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(key_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Key>, read::key::Overdue>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Key>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Key>, read::key::Overdue>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::key::Overdue { agency_id, at } = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, realty_id, label, \
+                   holder_id, issuer_id, checked_out_at, due_at, \
+                   created_at \
+            FROM realty_keys \
+            WHERE agency_id = $1::UUID \
+              AND due_at < $2::TIMESTAMPTZ \
+            ORDER BY due_at, id";
+        Ok(self
+            .query(SQL, &[&agency_id, &at])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(key_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Key>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(key): Insert<Key>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Key {
+            id,
+            agency_id,
+            realty_id,
+            label,
+            checkout,
+            created_at,
+        } = key;
+
+        const SQL: &str = "\
+            INSERT INTO realty_keys (\
+                id, agency_id, realty_id, label, \
+                holder_id, issuer_id, checked_out_at, due_at, \
+                created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::VARCHAR, \
+                $5::UUID, $6::UUID, $7::TIMESTAMPTZ, $8::TIMESTAMPTZ, \
+                $9::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET label = EXCLUDED.label, \
+                holder_id = EXCLUDED.holder_id, \
+                issuer_id = EXCLUDED.issuer_id, \
+                checked_out_at = EXCLUDED.checked_out_at, \
+                due_at = EXCLUDED.due_at";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &realty_id,
+                &label,
+                &checkout.map(|c| c.holder_id),
+                &checkout.map(|c| c.issuer_id),
+                &checkout.map(|c| c.checked_out_at),
+                &checkout.map(|c| c.due_at),
+                &created_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `realty_keys` table into a [`Key`].
+fn key_from_row(row: &Row) -> Key {
+    Key {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        realty_id: row.get("realty_id"),
+        label: row.get("label"),
+        checkout: row.get::<_, Option<_>>("holder_id").map(|holder_id| {
+            key::Checkout {
+                holder_id,
+                issuer_id: row.get("issuer_id"),
+                checked_out_at: row.get("checked_out_at"),
+                due_at: row.get("due_at"),
+            }
+        }),
+        created_at: row.get("created_at"),
+    }
+}
@@ -0,0 +1,136 @@
+//! [`LateFee`]-related [`Database`] implementations.
+
+use common::{
+    operations::{By, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, late_fee, LateFee},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<LateFee>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<LateFee>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<LateFee>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT fixed_fee, fixed_fee_currency, percent_fee, \
+                   cap, cap_currency, \
+                   accrued, accrued_currency, accrued_until \
+            FROM rent_late_fees \
+            WHERE contract_id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| late_fee_from_row(contract_id, &row)))
+    }
+}
+
+impl<C> Database<Insert<LateFee>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(late_fee): Insert<LateFee>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let LateFee {
+            contract_id,
+            policy: late_fee::Policy { charge, cap },
+            accrued,
+            accrued_until,
+        } = late_fee;
+        let (fixed_fee, percent_fee) = match charge {
+            late_fee::Charge::Fixed(fee) => (Some(fee), None),
+            late_fee::Charge::Percent(p) => (None, Some(p)),
+        };
+
+        const SQL: &str = "\
+            INSERT INTO rent_late_fees (\
+                contract_id, fixed_fee, fixed_fee_currency, percent_fee, \
+                cap, cap_currency, \
+                accrued, accrued_currency, accrued_until\
+            ) VALUES (\
+                $1::UUID, $2::NUMERIC, $3::INT2, $4::NUMERIC, \
+                $5::NUMERIC, $6::INT2, \
+                $7::NUMERIC, $8::INT2, $9::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (contract_id) DO UPDATE \
+            SET fixed_fee = EXCLUDED.fixed_fee, \
+                fixed_fee_currency = EXCLUDED.fixed_fee_currency, \
+                percent_fee = EXCLUDED.percent_fee, \
+                cap = EXCLUDED.cap, \
+                cap_currency = EXCLUDED.cap_currency, \
+                accrued = EXCLUDED.accrued, \
+                accrued_currency = EXCLUDED.accrued_currency, \
+                accrued_until = EXCLUDED.accrued_until";
+        self.exec(
+            SQL,
+            &[
+                &contract_id,
+                &fixed_fee.map(|f| f.amount),
+                &fixed_fee.map(|f| f.currency),
+                &percent_fee,
+                &cap.map(|c| c.amount),
+                &cap.map(|c| c.currency),
+                &accrued.amount,
+                &accrued.currency,
+                &accrued_until,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `rent_late_fees` table into a [`LateFee`]
+/// of the [`contract::Rent`] with the provided ID.
+pub(super) fn late_fee_from_row(
+    contract_id: contract::Id,
+    row: &Row,
+) -> LateFee {
+    let charge = match row.get::<_, Option<_>>("percent_fee") {
+        Some(p) => late_fee::Charge::Percent(p),
+        None => late_fee::Charge::Fixed(Money {
+            amount: row.get("fixed_fee"),
+            currency: row.get("fixed_fee_currency"),
+        }),
+    };
+    LateFee {
+        contract_id,
+        policy: late_fee::Policy {
+            charge,
+            cap: row.get::<_, Option<_>>("cap").map(|amount| Money {
+                amount,
+                currency: row.get("cap_currency"),
+            }),
+        },
+        accrued: Money {
+            amount: row.get("accrued"),
+            currency: row.get("accrued_currency"),
+        },
+        accrued_until: row.get("accrued_until"),
+    }
+}
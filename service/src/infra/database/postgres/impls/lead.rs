@@ -0,0 +1,291 @@
+//! [`Lead`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select, Update};
+use itertools::Itertools as _;
+use postgres_types::ToSql;
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{lead, Lead},
+    infra::{
+        database::{
+            self,
+            postgres::{self, Connection},
+            Postgres,
+        },
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<Lead>, lead::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Lead>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Lead>, lead::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: lead::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, source, stage, user_id, realty_id, \
+                   created_at, version \
+            FROM leads \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| lead_from_row(&row)))
+    }
+}
+
+impl<C> Database<Insert<Lead>> for Postgres<C>
+where
+    C: Connection,
+    Self: Database<Update<Lead>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(lead): Insert<Lead>,
+    ) -> Result<Self::Ok, Self::Err> {
+        self.execute(Update(lead)).await.map_err(tracerr::wrap!())
+    }
+}
+
+impl<C> Database<Update<Lead>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(lead): Update<Lead>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Lead {
+            id,
+            agency_id,
+            source,
+            stage,
+            user_id,
+            realty_id,
+            created_at,
+            version,
+        } = lead;
+
+        const SQL: &str = "\
+            INSERT INTO leads (\
+                id, agency_id, source, stage, user_id, realty_id, \
+                created_at, version\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::VARCHAR, $4::INT2, $5::UUID, \
+                $6::UUID, $7::TIMESTAMPTZ, $8::INT4\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET agency_id = EXCLUDED.agency_id, \
+                source = EXCLUDED.source, \
+                stage = EXCLUDED.stage, \
+                user_id = EXCLUDED.user_id, \
+                realty_id = EXCLUDED.realty_id, \
+                created_at = EXCLUDED.created_at, \
+                version = EXCLUDED.version \
+            WHERE leads.version = $9::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &agency_id,
+                    &source,
+                    &stage,
+                    &user_id,
+                    &realty_id,
+                    &created_at,
+                    &version.next(),
+                    &version,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<C> Database<Insert<lead::Transition>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(transition): Insert<lead::Transition>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let lead::Transition {
+            lead_id,
+            from,
+            to,
+            initiator_id,
+            created_at,
+        } = transition;
+
+        const SQL: &str = "\
+            INSERT INTO lead_transitions (\
+                lead_id, from_stage, to_stage, initiator_id, created_at\
+            ) VALUES (\
+                $1::UUID, $2::INT2, $3::INT2, $4::UUID, $5::TIMESTAMPTZ\
+            )";
+        self.exec(SQL, &[&lead_id, &from, &to, &initiator_id, &created_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Select<By<Vec<lead::Transition>, lead::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<lead::Transition>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<lead::Transition>, lead::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let lead_id: lead::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT lead_id, from_stage, to_stage, initiator_id, created_at \
+            FROM lead_transitions \
+            WHERE lead_id = $1::UUID \
+            ORDER BY created_at";
+        Ok(self
+            .query(SQL, &[&lead_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| lead::Transition {
+                lead_id: row.get("lead_id"),
+                from: row.get("from_stage"),
+                to: row.get("to_stage"),
+                initiator_id: row.get("initiator_id"),
+                created_at: row.get("created_at"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<read::lead::list::Page, read::lead::list::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::lead::list::Page;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<read::lead::list::Page, read::lead::list::Selector>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::lead::list::Selector {
+            arguments,
+            filter: read::lead::list::Filter { agency_id, stages },
+        } = by.into_inner();
+
+        let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![&agency_id, &limit];
+
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
+            ps.len()
+        });
+
+        let stages_idx = stages.as_ref().map(|s| {
+            ps.push(s);
+            ps.len()
+        });
+
+        let sql = format!(
+            "SELECT id, agency_id, source, stage, user_id, realty_id, \
+                    created_at, version \
+             FROM leads \
+             WHERE agency_id = $1::UUID \
+                   {stage_filtering} \
+                   {cursor} \
+             ORDER BY id {order} \
+             LIMIT $2::INT4 \
+             {offset}",
+            stage_filtering =
+                stages_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND stage = ANY(${idx}::INT2[])"))
+                }),
+            cursor =
+                arguments.cursor().into_iter().format_with("", |cursor, f| {
+                    let op = arguments.kind().operator();
+
+                    ps.push(cursor);
+                    let idx = ps.len();
+
+                    f(&format_args!("AND id {op} ${idx}::UUID"))
+                }),
+            order = arguments.kind().order().sql(),
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+        );
+        let rows = self
+            .query(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let has_more = rows.len() > arguments.limit();
+        let edges = rows
+            .into_iter()
+            .take(arguments.limit())
+            .map(|row| {
+                let node = lead_from_row(&row);
+                (node.id, node)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(read::lead::list::Page::new(&arguments, edges, has_more))
+    }
+}
+
+/// Maps the provided [`Row`] of the `leads` table into a [`Lead`].
+fn lead_from_row(row: &Row) -> Lead {
+    Lead {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        source: row.get("source"),
+        stage: row.get("stage"),
+        user_id: row.get("user_id"),
+        realty_id: row.get("realty_id"),
+        created_at: row.get("created_at"),
+        version: row.get("version"),
+    }
+}
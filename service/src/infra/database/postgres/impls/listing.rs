@@ -0,0 +1,227 @@
+//! [`listing::Import`]- and [`listing::Sync`]-related [`Database`]
+//! implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{listing, realty},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C>
+    Database<
+        Select<
+            By<Option<listing::Import>, (listing::Source, listing::ExternalId)>,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<listing::Import>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Option<listing::Import>, (listing::Source, listing::ExternalId)>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (source, external_id) = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT source, external_id, realty_id, contract_id, imported_at \
+            FROM external_listings \
+            WHERE source = $1::VARCHAR \
+              AND external_id = $2::VARCHAR";
+        Ok(self
+            .query_opt(SQL, &[&source, &external_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(import_from_row))
+    }
+}
+
+impl<C> Database<Select<By<Option<listing::Import>, realty::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<listing::Import>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<listing::Import>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        // A deduplicated `Realty` may be matched by several listings, so the
+        // earliest one is considered as its origin.
+        const SQL: &str = "\
+            SELECT source, external_id, realty_id, contract_id, imported_at \
+            FROM external_listings \
+            WHERE realty_id = $1::UUID \
+            ORDER BY imported_at \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(import_from_row))
+    }
+}
+
+impl<C> Database<Insert<listing::Import>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(import): Insert<listing::Import>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let listing::Import {
+            source,
+            external_id,
+            realty_id,
+            contract_id,
+            imported_at,
+        } = import;
+
+        const SQL: &str = "\
+            INSERT INTO external_listings (\
+                source, external_id, realty_id, contract_id, imported_at\
+            ) \
+            VALUES (\
+                $1::VARCHAR, $2::VARCHAR, $3::UUID, $4::UUID, $5::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (source, external_id) DO NOTHING";
+        self.exec(
+            SQL,
+            &[&source, &external_id, &realty_id, &contract_id, &imported_at],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Insert<listing::Sync>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(sync): Insert<listing::Sync>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let listing::Sync {
+            source,
+            started_at,
+            finished_at,
+            imported,
+            deduplicated,
+            unchanged,
+            rejected,
+            error,
+        } = sync;
+        let [imported, deduplicated, unchanged, rejected] =
+            [imported, deduplicated, unchanged, rejected]
+                .map(|n| i32::try_from(n).unwrap_or(i32::MAX));
+
+        const SQL: &str = "\
+            INSERT INTO listing_syncs (\
+                source, started_at, finished_at, \
+                imported, deduplicated, unchanged, rejected, error\
+            ) \
+            VALUES (\
+                $1::VARCHAR, $2::TIMESTAMPTZ, $3::TIMESTAMPTZ, \
+                $4::INT4, $5::INT4, $6::INT4, $7::INT4, $8::TEXT\
+            ) \
+            ON CONFLICT (source, started_at) DO NOTHING";
+        self.exec(
+            SQL,
+            &[
+                &source,
+                &started_at,
+                &finished_at,
+                &imported,
+                &deduplicated,
+                &unchanged,
+                &rejected,
+                &error,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Select<By<Vec<listing::Sync>, read::listing::History>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<listing::Sync>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<listing::Sync>, read::listing::History>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::listing::History { source, limit } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            SELECT source, started_at, finished_at, \
+                   imported, deduplicated, unchanged, rejected, error \
+            FROM listing_syncs \
+            WHERE $1::VARCHAR IS NULL OR source = $1::VARCHAR \
+            ORDER BY started_at DESC \
+            LIMIT $2::INT8";
+        Ok(self
+            .query(SQL, &[&source, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| {
+                let count = |col: &str| {
+                    u32::try_from(row.get::<_, i32>(col)).unwrap_or_default()
+                };
+                listing::Sync {
+                    source: row.get("source"),
+                    started_at: row.get("started_at"),
+                    finished_at: row.get("finished_at"),
+                    imported: count("imported"),
+                    deduplicated: count("deduplicated"),
+                    unchanged: count("unchanged"),
+                    rejected: count("rejected"),
+                    error: row.get("error"),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Constructs a [`listing::Import`] from the provided [`Row`].
+fn import_from_row(row: Row) -> listing::Import {
+    listing::Import {
+        source: row.get("source"),
+        external_id: row.get("external_id"),
+        realty_id: row.get("realty_id"),
+        contract_id: row.get("contract_id"),
+        imported_at: row.get("imported_at"),
+    }
+}
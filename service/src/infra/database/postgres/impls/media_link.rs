@@ -0,0 +1,110 @@
+//! [`MediaLink`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tracerr::Traced;
+
+use crate::{
+    domain::{realty, MediaLink},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<MediaLink>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<MediaLink>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<MediaLink>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT realty_id, url \
+            FROM realty_media_links \
+            WHERE realty_id = $1::UUID \
+            ORDER BY position";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| MediaLink {
+                realty_id: row.get("realty_id"),
+                url: row.get("url"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<MediaLink>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(links): Insert<Vec<MediaLink>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if links.is_empty() {
+            return Ok(());
+        }
+        let total = links.len();
+
+        let mut realty_ids = Vec::with_capacity(total);
+        let mut positions = Vec::with_capacity(total);
+        let mut urls = Vec::with_capacity(total);
+        for (position, l) in links.into_iter().enumerate() {
+            realty_ids.push(l.realty_id);
+            positions.push(
+                i16::try_from(position).expect("`position` overflow"),
+            );
+            urls.push(l.url);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO realty_media_links (realty_id, position, url) \
+            SELECT realty_id, position, url \
+            FROM unnest(\
+                $1::UUID[], \
+                $2::INT2[], \
+                $3::VARCHAR[]\
+            ) AS l(realty_id, position, url)";
+        self.exec(SQL, &[&realty_ids, &positions, &urls])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<MediaLink, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<MediaLink, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM realty_media_links \
+            WHERE realty_id = $1::UUID";
+        self.exec(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
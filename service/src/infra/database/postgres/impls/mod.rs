@@ -6,13 +6,47 @@
 )]
 #![allow(clippy::too_many_lines, reason = "SQL-related code a bit verbose")]
 
+mod agency;
+mod approval;
+mod arrears;
+mod audit;
+mod branch;
+mod charge;
+mod commission;
 mod contract;
+mod dashboard;
+mod floor_plan;
+mod ical;
+mod insurance;
+mod invite;
+mod inventory;
+mod journal;
+mod key;
+mod late_fee;
+mod lead;
+mod listing;
+mod media_link;
+mod offer;
+mod ownership;
+mod party;
+mod payment;
 mod placement;
 mod realty;
+mod review;
+mod saved_search;
+mod search;
+mod seasonal_rate;
+mod sms;
+mod snapshot;
+mod task;
+mod template;
+mod time_series;
+mod trash;
 mod user;
 
 use async_trait::async_trait;
-use common::operations::{Commit, Transact};
+use common::operations::{By, Commit, Perform, Select, Transact};
+use postgres_types::ToSql;
 use refinery_core::{
     traits::r#async::{AsyncQuery, AsyncTransaction},
     AsyncMigrate, Migration,
@@ -21,7 +55,84 @@ use tracerr::Traced;
 
 use crate::infra::{database, postgres, Database};
 
-use super::{NonTx, Postgres, Tx};
+use super::{connection, Connection, NonTx, Postgres, Tx};
+
+/// Namespace of an [advisory lock][1], separating locks of different entities.
+///
+/// [1]: https://postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+#[derive(Clone, Copy, Debug)]
+#[repr(i32)]
+enum LockSpace {
+    /// Locks of [`User`]s by their IDs.
+    ///
+    /// [`User`]: crate::domain::User
+    User = 1,
+
+    /// Locks of [`Realty`]s by their IDs.
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    Realty = 2,
+
+    /// Locks of [`Realty`]s creation by their hashes.
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    RealtyCreation = 3,
+
+    /// Locks of [`Contract`]s by their IDs.
+    ///
+    /// [`Contract`]: crate::domain::Contract
+    Contract = 4,
+}
+
+impl<C: Connection> Postgres<C> {
+    /// Acquires a transaction-level [advisory lock][1] on the provided `key`
+    /// in the provided [`LockSpace`], awaiting for it to be released if it's
+    /// held by another transaction.
+    ///
+    /// The lock is released automatically once the transaction ends, so
+    /// nothing needs to be cleaned up afterwards. The `key` is hashed, so
+    /// collisions are possible, but only cause excessive waiting.
+    ///
+    /// [1]: https://postgresql.org/docs/current/explicit-locking.html#ADVISORY-LOCKS
+    async fn advisory_lock(
+        &self,
+        space: LockSpace,
+        key: &(dyn ToSql + Sync),
+    ) -> Result<(), Traced<database::Error>> {
+        const SQL: &str = "\
+            SELECT pg_advisory_xact_lock($1::INT4, hashtext($2::UUID::TEXT))";
+        self.query(SQL, &[&(space as i32), key])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+
+    /// Estimates the number of rows returned by the provided `sql` query with
+    /// the provided `params`, as [planned][1] by Postgres, without executing
+    /// the query.
+    ///
+    /// The estimate is only as precise as the table statistics are fresh.
+    ///
+    /// [1]: https://postgresql.org/docs/current/using-explain.html
+    async fn estimate_rows(
+        &self,
+        sql: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<i32, Traced<database::Error>> {
+        let plan = self
+            .query(&format!("EXPLAIN {sql}"), params)
+            .await
+        .map_err(tracerr::wrap!())?;
+
+        // The top plan node goes first, like:
+        // `Seq Scan on contracts  (cost=0.00..35.50 rows=2550 width=4)`
+        let rows = plan.first().and_then(|row| {
+            let (_, rows) = row.get::<_, &str>(0).split_once(" rows=")?;
+            rows.split(' ').next()?.parse::<i64>().ok()
+        });
+        Ok(rows.map_or(0, |r| i32::try_from(r).unwrap_or(i32::MAX)))
+    }
+}
 
 impl Database<Transact> for Postgres<NonTx> {
     type Ok = Postgres<Tx>;
@@ -50,6 +161,47 @@ impl Database<Commit> for Postgres<Tx> {
     }
 }
 
+impl Database<Perform<database::Snapshot>> for Postgres<Tx> {
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Perform<database::Snapshot>,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "\
+            SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY";
+
+        self.batch_exec(SQL).await.map_err(tracerr::wrap!())
+    }
+}
+
+impl Database<Select<By<database::PoolStats, ()>>> for Postgres<NonTx> {
+    type Ok = database::PoolStats;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Select<By<database::PoolStats, ()>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        Ok(connection::pool_stats(&self.0.pool))
+    }
+}
+
+impl Database<Perform<database::Ping>> for Postgres<NonTx> {
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Perform<database::Ping>,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "SELECT 1";
+
+        self.batch_exec(SQL).await.map_err(tracerr::wrap!())
+    }
+}
+
 #[async_trait]
 impl AsyncTransaction for Postgres {
     type Error = Traced<database::Error>;
@@ -58,13 +210,13 @@ impl AsyncTransaction for Postgres {
         &mut self,
         queries: &[&str],
     ) -> Result<usize, Self::Error> {
-        let mut conn = self
-            .0
-            .pool
-            .get()
-            .await
-            .map_err(tracerr::from_and_wrap!(=> postgres::Error))
-            .map_err(tracerr::map_from)?;
+        let mut conn = connection::checkout(
+            &self.0.pool,
+            self.0.retry,
+            self.0.statement_timeout,
+        )
+        .await
+        .map_err(tracerr::wrap!())?;
         AsyncTransaction::execute(&mut **conn, queries)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
@@ -78,13 +230,13 @@ impl AsyncQuery<Vec<Migration>> for Postgres {
         &mut self,
         query: &str,
     ) -> Result<Vec<Migration>, <Self as AsyncTransaction>::Error> {
-        let mut conn = self
-            .0
-            .pool
-            .get()
-            .await
-            .map_err(tracerr::from_and_wrap!(=> postgres::Error))
-            .map_err(tracerr::map_from)?;
+        let mut conn = connection::checkout(
+            &self.0.pool,
+            self.0.retry,
+            self.0.statement_timeout,
+        )
+        .await
+        .map_err(tracerr::wrap!())?;
         AsyncQuery::query(&mut **conn, query)
             .await
             .map_err(tracerr::from_and_wrap!(=> postgres::Error))
@@ -93,3 +245,26 @@ impl AsyncQuery<Vec<Migration>> for Postgres {
 }
 
 impl AsyncMigrate for Postgres {}
+
+impl Postgres {
+    /// Reverts the applied migration of the provided `version` by executing
+    /// the provided rollback `sql` and removing the migration from the
+    /// applied migrations history, in a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// If failed to execute the rollback `sql` or to update the history.
+    pub async fn revert_migration(
+        &mut self,
+        version: u32,
+        sql: &str,
+    ) -> Result<(), Traced<database::Error>> {
+        let history = format!(
+            "DELETE FROM refinery_schema_history WHERE version = {version}",
+        );
+        AsyncTransaction::execute(self, &[sql, &history])
+            .await
+            .map(drop)
+            .map_err(tracerr::wrap!())
+    }
+}
@@ -0,0 +1,241 @@
+//! [`Offer`]-related [`Database`] implementations.
+
+use common::{
+    operations::{By, Insert, Select, Update},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{offer, realty, Offer},
+    infra::{
+        database::{
+            self,
+            postgres::{self, Connection},
+            Postgres,
+        },
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<Offer>, offer::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Offer>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Offer>, offer::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: offer::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, realty_id, \
+                   bidder_id, employer_id, proposer_id, previous_id, \
+                   concluded_contract_id, price, price_currency, status, \
+                   created_at, decided_at, version \
+            FROM offers \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| offer_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Offer>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Offer>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Offer>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, realty_id, \
+                   bidder_id, employer_id, proposer_id, previous_id, \
+                   concluded_contract_id, price, price_currency, status, \
+                   created_at, decided_at, version \
+            FROM offers \
+            WHERE realty_id = $1::UUID \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(offer_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Offer>, read::offer::Negotiation>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Offer>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Offer>, read::offer::Negotiation>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::offer::Negotiation(contract_id) = by.into_inner();
+
+        const SQL: &str = "\
+            WITH RECURSIVE negotiation AS (\
+                SELECT * \
+                FROM offers \
+                WHERE concluded_contract_id = $1::UUID \
+                UNION ALL \
+                SELECT o.* \
+                FROM offers o \
+                JOIN negotiation n ON o.id = n.previous_id\
+            ) \
+            SELECT id, agency_id, contract_id, realty_id, \
+                   bidder_id, employer_id, proposer_id, previous_id, \
+                   concluded_contract_id, price, price_currency, status, \
+                   created_at, decided_at, version \
+            FROM negotiation \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(offer_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Offer>> for Postgres<C>
+where
+    C: Connection,
+    Self: Database<Update<Offer>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(offer): Insert<Offer>,
+    ) -> Result<Self::Ok, Self::Err> {
+        self.execute(Update(offer)).await.map_err(tracerr::wrap!())
+    }
+}
+
+impl<C> Database<Update<Offer>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(offer): Update<Offer>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Offer {
+            id,
+            agency_id,
+            contract_id,
+            realty_id,
+            bidder_id,
+            employer_id,
+            proposer_id,
+            previous_id,
+            concluded_contract_id,
+            price,
+            status,
+            created_at,
+            decided_at,
+            version,
+        } = offer;
+
+        const SQL: &str = "\
+            INSERT INTO offers (\
+                id, agency_id, contract_id, realty_id, \
+                bidder_id, employer_id, proposer_id, previous_id, \
+                concluded_contract_id, price, price_currency, status, \
+                created_at, decided_at, version\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::UUID, \
+                $5::UUID, $6::UUID, $7::UUID, $8::UUID, \
+                $9::UUID, $10::NUMERIC, $11::INT2, $12::INT2, \
+                $13::TIMESTAMPTZ, $14::TIMESTAMPTZ, $15::INT4\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET concluded_contract_id = EXCLUDED.concluded_contract_id, \
+                status = EXCLUDED.status, \
+                decided_at = EXCLUDED.decided_at, \
+                version = EXCLUDED.version \
+            WHERE offers.version = $16::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &agency_id,
+                    &contract_id,
+                    &realty_id,
+                    &bidder_id,
+                    &employer_id,
+                    &proposer_id,
+                    &previous_id,
+                    &concluded_contract_id,
+                    &price.amount,
+                    &price.currency,
+                    &status,
+                    &created_at,
+                    &decided_at,
+                    &version.next(),
+                    &version,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Maps the provided [`Row`] of the `offers` table into an [`Offer`].
+fn offer_from_row(row: &Row) -> Offer {
+    Offer {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        contract_id: row.get("contract_id"),
+        realty_id: row.get("realty_id"),
+        bidder_id: row.get("bidder_id"),
+        employer_id: row.get("employer_id"),
+        proposer_id: row.get("proposer_id"),
+        previous_id: row.get("previous_id"),
+        concluded_contract_id: row.get("concluded_contract_id"),
+        price: Money {
+            amount: row.get("price"),
+            currency: row.get("price_currency"),
+        },
+        status: row.get("status"),
+        created_at: row.get("created_at"),
+        decided_at: row.get("decided_at"),
+        version: row.get("version"),
+    }
+}
@@ -0,0 +1,193 @@
+//! [`Ownership`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{ownership, realty, Ownership},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<Ownership>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Ownership>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Ownership>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT realty_id, owner_id, share \
+            FROM realty_ownerships \
+            WHERE realty_id = $1::UUID \
+            ORDER BY share DESC, owner_id";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(ownership_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<Ownership>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(ownerships): Insert<Vec<Ownership>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if ownerships.is_empty() {
+            return Ok(());
+        }
+        let total = ownerships.len();
+
+        let mut realty_ids = Vec::with_capacity(total);
+        let mut owner_ids = Vec::with_capacity(total);
+        let mut shares = Vec::with_capacity(total);
+        for o in ownerships {
+            realty_ids.push(o.realty_id);
+            owner_ids.push(o.owner_id);
+            shares.push(o.share);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO realty_ownerships (realty_id, owner_id, share) \
+            SELECT realty_id, owner_id, share \
+            FROM unnest($1::UUID[], $2::UUID[], $3::NUMERIC[]) \
+                 AS o(realty_id, owner_id, share) \
+            ON CONFLICT (realty_id, owner_id) DO UPDATE \
+            SET share = EXCLUDED.share";
+        self.exec(SQL, &[&realty_ids, &owner_ids, &shares])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<Ownership, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<Ownership, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM realty_ownerships \
+            WHERE realty_id = $1::UUID";
+        self.exec(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Select<By<Vec<ownership::Transfer>, realty::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<ownership::Transfer>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<ownership::Transfer>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, realty_id, seller_id, buyer_id, \
+                   transferred_at \
+            FROM realty_ownership_transfers \
+            WHERE realty_id = $1::UUID \
+            ORDER BY transferred_at DESC";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(transfer_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<ownership::Transfer>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(transfer): Insert<ownership::Transfer>,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "\
+            INSERT INTO realty_ownership_transfers (\
+                contract_id, realty_id, seller_id, buyer_id, transferred_at\
+            ) \
+            VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::UUID, $5::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (contract_id) DO NOTHING";
+        self.exec(
+            SQL,
+            &[
+                &transfer.contract_id,
+                &transfer.realty_id,
+                &transfer.seller_id,
+                &transfer.buyer_id,
+                &transfer.transferred_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `realty_ownerships` table into an
+/// [`Ownership`].
+fn ownership_from_row(row: &Row) -> Ownership {
+    Ownership {
+        realty_id: row.get("realty_id"),
+        owner_id: row.get("owner_id"),
+        share: row.get("share"),
+    }
+}
+
+/// Maps the provided [`Row`] of the `realty_ownership_transfers` table into
+/// an [`ownership::Transfer`].
+fn transfer_from_row(row: &Row) -> ownership::Transfer {
+    ownership::Transfer {
+        contract_id: row.get("contract_id"),
+        realty_id: row.get("realty_id"),
+        seller_id: row.get("seller_id"),
+        buyer_id: row.get("buyer_id"),
+        transferred_at: row.get("transferred_at"),
+    }
+}
@@ -0,0 +1,99 @@
+//! [`contract::Party`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::contract,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<contract::Party>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<contract::Party>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<contract::Party>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT contract_id, user_id, role, signed_at \
+            FROM contract_parties \
+            WHERE contract_id = $1::UUID \
+            ORDER BY role, user_id";
+        Ok(self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(party_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<contract::Party>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(parties): Insert<Vec<contract::Party>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if parties.is_empty() {
+            return Ok(());
+        }
+        let total = parties.len();
+
+        let mut contract_ids = Vec::with_capacity(total);
+        let mut user_ids = Vec::with_capacity(total);
+        let mut roles = Vec::with_capacity(total);
+        let mut signature_dates = Vec::with_capacity(total);
+        for party in parties {
+            contract_ids.push(party.contract_id);
+            user_ids.push(party.user_id);
+            roles.push(party.role);
+            signature_dates.push(party.signed_at);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO contract_parties (\
+                contract_id, user_id, role, signed_at\
+            ) \
+            SELECT contract_id, user_id, role, signed_at \
+            FROM unnest(\
+                $1::UUID[], $2::UUID[], $3::INT2[], $4::TIMESTAMPTZ[]\
+            ) AS p(\
+                contract_id, user_id, role, signed_at\
+            ) \
+            ON CONFLICT (contract_id, user_id, role) DO UPDATE \
+            SET signed_at = EXCLUDED.signed_at";
+        self.exec(SQL, &[&contract_ids, &user_ids, &roles, &signature_dates])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `contract_parties` table into a
+/// [`contract::Party`].
+fn party_from_row(row: &Row) -> contract::Party {
+    contract::Party {
+        contract_id: row.get("contract_id"),
+        user_id: row.get("user_id"),
+        role: row.get("role"),
+        signed_at: row.get("signed_at"),
+    }
+}
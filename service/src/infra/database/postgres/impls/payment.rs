@@ -0,0 +1,179 @@
+//! [`Payment`]-related [`Database`] implementations.
+
+use common::{
+    operations::{By, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, Payment},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Vec<Payment>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Payment>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Payment>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, amount, amount_currency, \
+                   recorder_id, paid_at \
+            FROM rent_payments \
+            WHERE contract_id = $1::UUID \
+            ORDER BY paid_at, id";
+        Ok(self
+            .query(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(payment_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Payment>, read::payment::Period>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Payment>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Payment>, read::payment::Period>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::payment::Period {
+            agency_id,
+            since,
+            until,
+        } = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, amount, amount_currency, \
+                   recorder_id, paid_at \
+            FROM rent_payments \
+            WHERE agency_id = $1::UUID \
+              AND paid_at >= $2::TIMESTAMPTZ \
+              AND paid_at < $3::TIMESTAMPTZ \
+            ORDER BY paid_at, id";
+        Ok(self
+            .query(SQL, &[&agency_id, &since, &until])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(payment_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Vec<Payment>, read::payment::Unexported>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Payment>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Payment>, read::payment::Unexported>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::payment::Unexported { after, limit } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            SELECT p.id, p.agency_id, p.contract_id, \
+                   p.amount, p.amount_currency, \
+                   p.recorder_id, p.paid_at \
+            FROM rent_payments p \
+            LEFT JOIN journal_exports e ON e.payment_id = p.id \
+            WHERE e.payment_id IS NULL \
+              AND ($1::UUID IS NULL OR p.id > $1::UUID) \
+            ORDER BY p.id \
+            LIMIT $2::INT8";
+        Ok(self
+            .query(SQL, &[&after, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(payment_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Payment>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(payment): Insert<Payment>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Payment {
+            id,
+            agency_id,
+            contract_id,
+            amount,
+            recorder_id,
+            paid_at,
+        } = payment;
+
+        const SQL: &str = "\
+            INSERT INTO rent_payments (\
+                id, agency_id, contract_id, amount, amount_currency, \
+                recorder_id, paid_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::NUMERIC, $5::INT2, \
+                $6::UUID, $7::TIMESTAMPTZ\
+            )";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &contract_id,
+                &amount.amount,
+                &amount.currency,
+                &recorder_id,
+                &paid_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `rent_payments` table into a [`Payment`].
+fn payment_from_row(row: &Row) -> Payment {
+    Payment {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        contract_id: row.get("contract_id"),
+        amount: Money {
+            amount: row.get("amount"),
+            currency: row.get("amount_currency"),
+        },
+        recorder_id: row.get("recorder_id"),
+        paid_at: row.get("paid_at"),
+    }
+}
@@ -1,12 +1,20 @@
-use common::operations::{By, Select};
+use common::{
+    operations::{By, Select},
+    Money,
+};
 use itertools::Itertools as _;
 use postgres_types::ToSql;
+use rust_decimal::Decimal;
 use tracerr::Traced;
 
 use crate::{
-    domain::contract,
+    domain::{contract, realty, sitemap},
     infra::{
-        database::{self, postgres::Connection, Postgres},
+        database::{
+            self,
+            postgres::{Connection, FuzzPattern},
+            Postgres,
+        },
         Database,
     },
     read::{placement, Placement},
@@ -28,10 +36,17 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let placement::list::Selector {
             arguments,
-            filter: placement::list::Filter { rent, sale },
+            filter: placement::list::Filter {
+                agency_id,
+                rent,
+                sale,
+                address,
+                realty_ids,
+            },
         } = by.into_inner();
 
         let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
 
         let mut ps: Vec<&(dyn ToSql + Sync)> = vec![
             &contract::Kind::ManagementForRent,
@@ -39,11 +54,34 @@ where
             &limit,
         ];
 
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
+            ps.len()
+        });
+
+        let address_pattern =
+            address.as_ref().map(|a| FuzzPattern::new(a.as_ref()));
+        let address_pattern_idx = address_pattern.as_ref().map(|a| {
+            ps.push(a);
+            ps.len()
+        });
+
+        let realty_ids_idx = realty_ids.as_ref().map(|ids| {
+            ps.push(ids);
+            ps.len()
+        });
+
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
         let sql = format!(
             "SELECT realty_id, \
                     rent_contract_id, \
                     sale_contract_id \
              FROM (SELECT id AS realty_id, \
+                          address, \
                           (SELECT id \
                            FROM contracts \
                            WHERE kind = $1::INT2 \
@@ -62,16 +100,19 @@ where
                                   OR expires_at > NOW()) \
                              AND realty_id = realties.id \
                            LIMIT 1) AS sale_contract_id \
-                   FROM realties) AS realty \
+                   FROM realties \
+                   {agency_filtering}) AS realty \
              WHERE (rent_contract_id IS NOT NULL \
                     OR sale_contract_id IS NOT NULL) \
                    {cursor} \
                    {no_rent} \
                    {no_sale} \
+                   {address_filtering} \
              ORDER BY realty_id {order}, \
                       rent_contract_id {order}, \
                       sale_contract_id {order}
-             LIMIT $3::INT4",
+             LIMIT $3::INT4 \
+             {offset}",
             cursor =
                 arguments.cursor().into_iter().format_with("", |cursor, f| {
                     let op = arguments.kind().operator();
@@ -81,13 +122,28 @@ where
 
                     f(&format_args!("AND realty_id {op} ${idx}::UUID"))
                 }),
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("WHERE agency_id = ${idx}::UUID"))
+                }),
             no_rent = (!rent)
                 .then_some("AND rent_contract_id IS NULL")
                 .unwrap_or_default(),
             no_sale = (!sale)
                 .then_some("AND sale_contract_id IS NULL")
                 .unwrap_or_default(),
+            // `Search`-found IDs are already matching the `address`.
+            address_filtering = match (realty_ids_idx, address_pattern_idx) {
+                (Some(idx), _) => {
+                    format!("AND realty_id = ANY(${idx}::UUID[])")
+                }
+                (None, Some(idx)) => format!("AND ${idx}::VARCHAR <% address"),
+                (None, None) => String::new(),
+            },
             order = arguments.kind().order().sql(),
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
         );
         let rows = self
             .query(&sql, ps.as_slice())
@@ -115,7 +171,245 @@ where
     }
 }
 
-impl<C> Database<Select<By<placement::list::TotalCount, ()>>> for Postgres<C>
+impl<C> Database<Select<By<placement::list::Facets, placement::list::Filter>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = placement::list::Facets;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<placement::list::Facets, placement::list::Filter>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let placement::list::Filter {
+            agency_id,
+            rent,
+            sale,
+            address,
+            realty_ids,
+        } = by.into_inner();
+
+        let buckets = placement::list::PRICE_BUCKETS
+            .into_iter()
+            .map(Decimal::from)
+            .collect::<Vec<_>>();
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![
+            &contract::Kind::ManagementForRent,
+            &contract::Kind::ManagementForSale,
+            &buckets,
+        ];
+
+        let address_pattern =
+            address.as_ref().map(|a| FuzzPattern::new(a.as_ref()));
+        let address_pattern_idx = address_pattern.as_ref().map(|a| {
+            ps.push(a);
+            ps.len()
+        });
+
+        let realty_ids_idx = realty_ids.as_ref().map(|ids| {
+            ps.push(ids);
+            ps.len()
+        });
+
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let sql = format!(
+            "WITH placed AS (\
+                SELECT r.city, \
+                       rent.id AS rent_id, \
+                       rent.price AS rent_price, \
+                       rent.price_currency AS rent_currency, \
+                       sale.id AS sale_id, \
+                       sale.price AS sale_price, \
+                       sale.price_currency AS sale_currency \
+                FROM realties AS r \
+                LEFT JOIN LATERAL (SELECT id, price, price_currency \
+                                   FROM contracts \
+                                   WHERE kind = $1::INT2 \
+                                     AND is_placed \
+                                     AND terminated_at IS NULL \
+                                     AND (expires_at IS NULL \
+                                          OR expires_at > NOW()) \
+                                     AND realty_id = r.id \
+                                   ORDER BY id \
+                                   LIMIT 1) AS rent ON TRUE \
+                LEFT JOIN LATERAL (SELECT id, price, price_currency \
+                                   FROM contracts \
+                                   WHERE kind = $2::INT2 \
+                                     AND is_placed \
+                                     AND terminated_at IS NULL \
+                                     AND (expires_at IS NULL \
+                                          OR expires_at > NOW()) \
+                                     AND realty_id = r.id \
+                                   ORDER BY id \
+                                   LIMIT 1) AS sale ON TRUE \
+                WHERE (rent.id IS NOT NULL OR sale.id IS NOT NULL) \
+                      {agency_filtering} \
+                      {no_rent} \
+                      {no_sale} \
+                      {address_filtering}\
+             ) \
+             SELECT 'city' AS facet, city, \
+                    NULL::INT2 AS currency, NULL::INT4 AS bucket, \
+                    COUNT(*)::INT4 AS count \
+             FROM placed \
+             GROUP BY city \
+             UNION ALL \
+             SELECT 'rent', NULL, \
+                    rent_currency, width_bucket(rent_price, $3::NUMERIC[]), \
+                    COUNT(*)::INT4 \
+             FROM placed \
+             WHERE rent_id IS NOT NULL \
+             GROUP BY 3, 4 \
+             UNION ALL \
+             SELECT 'sale', NULL, \
+                    sale_currency, width_bucket(sale_price, $3::NUMERIC[]), \
+                    COUNT(*)::INT4 \
+             FROM placed \
+             WHERE sale_id IS NOT NULL \
+             GROUP BY 3, 4 \
+             ORDER BY 1, 3, 4, 5 DESC, 2",
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND r.agency_id = ${idx}::UUID"))
+                }),
+            no_rent = (!rent)
+                .then_some("AND rent.id IS NULL")
+                .unwrap_or_default(),
+            no_sale = (!sale)
+                .then_some("AND sale.id IS NULL")
+                .unwrap_or_default(),
+            // `Search`-found IDs are already matching the `address`.
+            address_filtering = match (realty_ids_idx, address_pattern_idx) {
+                (Some(idx), _) => format!("AND r.id = ANY(${idx}::UUID[])"),
+                (None, Some(idx)) => {
+                    format!("AND ${idx}::VARCHAR <% r.address")
+                }
+                (None, None) => String::new(),
+            },
+        );
+        let rows = self
+            .query(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let mut facets = placement::list::Facets::default();
+        for row in rows {
+            let count = row.get("count");
+            let (counter, prices) = match row.get::<_, &str>("facet") {
+                "city" => {
+                    facets.cities.push(placement::list::CityFacet {
+                        city: row.get("city"),
+                        count,
+                    });
+                    continue;
+                }
+                "rent" => (&mut facets.rent, &mut facets.rent_prices),
+                _ => (&mut facets.sale, &mut facets.sale_prices),
+            };
+            *counter += count;
+
+            let currency = row.get("currency");
+            let money = |amount: u32| Money {
+                amount: amount.into(),
+                currency,
+            };
+            // `width_bucket()` returns `1` for the first bucket.
+            let bucket = usize::try_from(row.get::<_, i32>("bucket"))
+                .expect("`bucket` overflow")
+                .saturating_sub(1);
+            prices.push(placement::list::PriceFacet {
+                min: money(placement::list::PRICE_BUCKETS[bucket]),
+                max: placement::list::PRICE_BUCKETS
+                    .get(bucket + 1)
+                    .copied()
+                    .map(money),
+                count,
+            });
+        }
+        Ok(facets)
+    }
+}
+
+impl<C> Database<Select<By<Vec<sitemap::Entry>, placement::Sitemap>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<sitemap::Entry>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<sitemap::Entry>, placement::Sitemap>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let placement::Sitemap { after, limit } = by.into_inner();
+
+        let limit = i32::try_from(limit).unwrap();
+
+        const SQL: &str = "\
+            SELECT r.agency_id, r.id, r.address, \
+                   COALESCE(GREATEST(rent.placed_at, sale.placed_at), \
+                            r.created_at) AS last_modified \
+            FROM realties AS r \
+            LEFT JOIN LATERAL (SELECT id, placed_at \
+                               FROM contracts \
+                               WHERE kind = $1::INT2 \
+                                 AND is_placed \
+                                 AND terminated_at IS NULL \
+                                 AND (expires_at IS NULL \
+                                      OR expires_at > NOW()) \
+                                 AND realty_id = r.id \
+                               ORDER BY id \
+                               LIMIT 1) AS rent ON TRUE \
+            LEFT JOIN LATERAL (SELECT id, placed_at \
+                               FROM contracts \
+                               WHERE kind = $2::INT2 \
+                                 AND is_placed \
+                                 AND terminated_at IS NULL \
+                                 AND (expires_at IS NULL \
+                                      OR expires_at > NOW()) \
+                                 AND realty_id = r.id \
+                               ORDER BY id \
+                               LIMIT 1) AS sale ON TRUE \
+            WHERE (rent.id IS NOT NULL OR sale.id IS NOT NULL) \
+              AND ($3::UUID IS NULL OR r.id > $3::UUID) \
+            ORDER BY r.id \
+            LIMIT $4::INT4";
+        Ok(self
+            .query(
+                SQL,
+                &[
+                    &contract::Kind::ManagementForRent,
+                    &contract::Kind::ManagementForSale,
+                    &after,
+                    &limit,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| sitemap::Entry {
+                agency_id: row.get("agency_id"),
+                realty_id: row.get("id"),
+                address: row.get("address"),
+                last_modified: row.get("last_modified"),
+            })
+            .collect())
+    }
+}
+
+impl<C>
+    Database<Select<By<placement::list::TotalCount, placement::list::Filter>>>
+    for Postgres<C>
 where
     C: Connection,
 {
@@ -124,38 +418,241 @@ where
 
     async fn execute(
         &self,
-        Select(_): Select<By<placement::list::TotalCount, ()>>,
+        Select(by): Select<
+            By<placement::list::TotalCount, placement::list::Filter>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let placement::list::Filter {
+            agency_id,
+            rent,
+            sale,
+            address,
+            realty_ids,
+        } = by.into_inner();
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![
+            &contract::Kind::ManagementForRent,
+            &contract::Kind::ManagementForSale,
+        ];
+
+        let address_pattern =
+            address.as_ref().map(|a| FuzzPattern::new(a.as_ref()));
+        let address_pattern_idx = address_pattern.as_ref().map(|a| {
+            ps.push(a);
+            ps.len()
+        });
+
+        let realty_ids_idx = realty_ids.as_ref().map(|ids| {
+            ps.push(ids);
+            ps.len()
+        });
+
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let sql = format!(
+            "SELECT COUNT(*)::INT4 \
+             FROM (SELECT id AS realty_id, \
+                          address, \
+                          (SELECT id \
+                           FROM contracts \
+                           WHERE kind = $1::INT2 \
+                             AND is_placed \
+                             AND terminated_at IS NULL \
+                             AND (expires_at IS NULL \
+                                  OR expires_at > NOW()) \
+                             AND realty_id = realties.id \
+                           LIMIT 1) AS rent_contract_id, \
+                          (SELECT id \
+                           FROM contracts \
+                           WHERE kind = $2::INT2 \
+                             AND is_placed \
+                             AND terminated_at IS NULL \
+                             AND (expires_at IS NULL \
+                                  OR expires_at > NOW()) \
+                             AND realty_id = realties.id \
+                           LIMIT 1) AS sale_contract_id \
+                   FROM realties \
+                   {agency_filtering}) AS realty \
+             WHERE (rent_contract_id IS NOT NULL \
+                    OR sale_contract_id IS NOT NULL) \
+                   {no_rent} \
+                   {no_sale} \
+                   {address_filtering}",
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("WHERE agency_id = ${idx}::UUID"))
+                }),
+            no_rent = (!rent)
+                .then_some("AND rent_contract_id IS NULL")
+                .unwrap_or_default(),
+            no_sale = (!sale)
+                .then_some("AND sale_contract_id IS NULL")
+                .unwrap_or_default(),
+            // `Search`-found IDs are already matching the `address`.
+            address_filtering = match (realty_ids_idx, address_pattern_idx) {
+                (Some(idx), _) => {
+                    format!("AND realty_id = ANY(${idx}::UUID[])")
+                }
+                (None, Some(idx)) => format!("AND ${idx}::VARCHAR <% address"),
+                (None, None) => String::new(),
+            },
+        );
+        self.query_opt(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|row| row.expect("always exists").get::<_, i32>(0).into())
+    }
+}
+
+impl<C> Database<Select<By<placement::feed::Page, placement::feed::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = placement::feed::Page;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<placement::feed::Page, placement::feed::Selector>,
+        >,
     ) -> Result<Self::Ok, Self::Err> {
+        let placement::feed::Selector {
+            agency_id,
+            first,
+            after,
+        } = by.into_inner();
+
+        let limit = i32::try_from(first).unwrap() + 1;
+
         const SQL: &str = "\
-            SELECT COUNT(*)::INT4 \
-            FROM realties \
-            WHERE EXISTS(SELECT id \
-                   FROM contracts \
-                   WHERE kind = $1::INT2 \
-                     AND is_placed \
-                     AND terminated_at IS NULL \
-                     AND (expires_at IS NULL \
-                          OR expires_at > NOW()) \
-                     AND realty_id = realties.id \
-                   LIMIT 1) \
-               OR EXISTS(SELECT id \
-                   FROM contracts \
-                   WHERE kind = $2::INT2 \
-                     AND is_placed \
-                     AND terminated_at IS NULL \
-                     AND (expires_at IS NULL \
-                          OR expires_at > NOW()) \
-                     AND realty_id = realties.id \
-                   LIMIT 1)";
-        self.query_opt(
-            SQL,
-            &[
-                &contract::Kind::ManagementForRent,
-                &contract::Kind::ManagementForSale,
-            ],
-        )
-        .await
-        .map_err(tracerr::wrap!())
-        .map(|row| row.expect("always exists").get::<_, i32>(0).into())
+            SELECT r.id, r.address, r.area, r.room_num, \
+                   r.floor, r.num_floors, \
+                   rent.price AS rent_price, \
+                   rent.price_currency AS rent_price_currency, \
+                   rent.placed_at AS rent_placed_at, \
+                   sale.price AS sale_price, \
+                   sale.price_currency AS sale_price_currency, \
+                   sale.placed_at AS sale_placed_at, \
+                   ARRAY(SELECT floor \
+                         FROM realty_floor_plans \
+                         WHERE realty_id = r.id \
+                         ORDER BY floor, label) AS floor_plan_floors, \
+                   ARRAY(SELECT label \
+                         FROM realty_floor_plans \
+                         WHERE realty_id = r.id \
+                         ORDER BY floor, label) AS floor_plan_labels, \
+                   ARRAY(SELECT url \
+                         FROM realty_floor_plans \
+                         WHERE realty_id = r.id \
+                         ORDER BY floor, label) AS floor_plan_urls, \
+                   ARRAY(SELECT url \
+                         FROM realty_media_links \
+                         WHERE realty_id = r.id \
+                         ORDER BY position) AS media_links \
+            FROM realties AS r \
+            LEFT JOIN LATERAL (SELECT price, price_currency, placed_at \
+                               FROM contracts \
+                               WHERE kind = $1::INT2 \
+                                 AND is_placed \
+                                 AND terminated_at IS NULL \
+                                 AND (expires_at IS NULL \
+                                      OR expires_at > NOW()) \
+                                 AND realty_id = r.id \
+                               ORDER BY id \
+                               LIMIT 1) AS rent ON TRUE \
+            LEFT JOIN LATERAL (SELECT price, price_currency, placed_at \
+                               FROM contracts \
+                               WHERE kind = $2::INT2 \
+                                 AND is_placed \
+                                 AND terminated_at IS NULL \
+                                 AND (expires_at IS NULL \
+                                      OR expires_at > NOW()) \
+                                 AND realty_id = r.id \
+                               ORDER BY id \
+                               LIMIT 1) AS sale ON TRUE \
+            WHERE r.agency_id = $3::UUID \
+              AND (rent.price IS NOT NULL OR sale.price IS NOT NULL) \
+              AND ($4::UUID IS NULL OR r.id > $4::UUID) \
+            ORDER BY r.id \
+            LIMIT $5::INT4";
+        let rows = self
+            .query(
+                SQL,
+                &[
+                    &contract::Kind::ManagementForRent,
+                    &contract::Kind::ManagementForSale,
+                    &agency_id,
+                    &after,
+                    &limit,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let has_next_page = rows.len() > first;
+        let items = rows
+            .iter()
+            .take(first)
+            .map(|row| {
+                let offer = |deal: &str| {
+                    row.get::<_, Option<_>>(format!("{deal}_price").as_str())
+                        .map(|amount| placement::feed::Offer {
+                            price: Money {
+                                amount,
+                                currency: row.get(
+                                    format!("{deal}_price_currency").as_str(),
+                                ),
+                            },
+                            placed_at: row
+                                .get(format!("{deal}_placed_at").as_str()),
+                        })
+                };
+                let floors: Vec<i32> = row.get("floor_plan_floors");
+                let labels: Vec<String> = row.get("floor_plan_labels");
+                let urls: Vec<String> = row.get("floor_plan_urls");
+
+                placement::feed::Item {
+                    realty_id: row.get("id"),
+                    address: row.get("address"),
+                    area: row.get("area"),
+                    room_num: row.get("room_num"),
+                    floor: row
+                        .get::<_, Option<i32>>("floor")
+                        .map(realty::Floor::try_from)
+                        .transpose()
+                        .expect("`floor` overflow"),
+                    num_floors: realty::NumFloors::try_from(
+                        row.get::<_, i32>("num_floors"),
+                    )
+                    .expect("`num_floors` overflow"),
+                    rent: offer("rent"),
+                    sale: offer("sale"),
+                    floor_plans: floors
+                        .into_iter()
+                        .zip(labels)
+                        .zip(urls)
+                        .map(|((floor, label), url)| {
+                            placement::feed::FloorPlan {
+                                floor: realty::Floor::try_from(floor)
+                                    .expect("`floor` overflow"),
+                                label,
+                                url,
+                            }
+                        })
+                        .collect(),
+                    media_links: row.get("media_links"),
+                }
+            })
+            .collect();
+
+        Ok(placement::feed::Page {
+            items,
+            has_next_page,
+        })
     }
 }
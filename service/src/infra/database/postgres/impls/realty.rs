@@ -2,24 +2,36 @@
 
 use std::collections::HashMap;
 
-use common::operations::{By, Delete, Insert, Lock, Select, Update};
+use common::{
+    operations::{By, Delete, Insert, Lock, Select, Update},
+    Money,
+};
 use itertools::Itertools as _;
 use postgres_types::ToSql;
+use tokio_postgres::Row;
 use tracerr::Traced;
 
 use crate::{
-    domain::{contract, realty, Realty},
+    domain::{agency, contract, realty, Realty},
     infra::{
         database::{
             self,
-            postgres::{Connection, FuzzPattern},
+            postgres::{self, Connection, FuzzPattern},
             Postgres,
         },
         Database,
     },
-    read,
+    read::{
+        self,
+        realty::{
+            list::{SortBy, SortKey},
+            Deleted,
+        },
+    },
 };
 
+use super::LockSpace;
+
 impl<C, IDs> Database<Select<By<HashMap<realty::Id, Realty>, IDs>>>
     for Postgres<C>
 where
@@ -42,51 +54,21 @@ where
         let limit = i32::try_from(ids.len()).unwrap();
 
         const SQL: &str = "\
-            SELECT id, hash, address, \
+            SELECT id, agency_id, assignee_id, hash, address, \
                    country, state, city, street, zip_code, building_name, \
                    num_floors, floor, \
-                   apartment_num, room_num, \
-                   created_at \
+                   apartment_num, room_num, area, \
+                   created_at, deleted_at, version \
             FROM realties \
             WHERE id IN (SELECT unnest($1::UUID[]) LIMIT $2::INT4) \
+              AND deleted_at IS NULL \
             LIMIT $2::INT4";
         Ok(self
             .query(SQL, &[&ids, &limit])
             .await
             .map_err(tracerr::wrap!())?
             .into_iter()
-            .map(|row| {
-                let id = row.get("id");
-                (
-                    id,
-                    Realty {
-                        id,
-                        hash: row.get("hash"),
-                        address: row.get("address"),
-                        country: row.get("country"),
-                        state: row.get("state"),
-                        city: row.get("city"),
-                        street: row.get("street"),
-                        zip_code: row.get("zip_code"),
-                        building_name: row.get("building_name"),
-                        num_floors: u16::try_from(
-                            row.get::<_, i32>("num_floors"),
-                        )
-                        .expect("`num_floors` overflow"),
-                        floor: row
-                            .get::<_, Option<i32>>("floor")
-                            .map(u16::try_from)
-                            .transpose()
-                            .expect("`floor` overflow"),
-                        apartment_num: row.get("apartment_num"),
-                        room_num: row.get("room_num"),
-                        created_at: row.get("created_at"),
-                        // OK, because `Realty` removed from database completely
-                        // once deleted.
-                        deleted_at: None,
-                    },
-                )
-            })
+            .map(|row| (row.get("id"), realty_from_row(&row)))
             .collect())
     }
 }
@@ -116,41 +98,71 @@ where
     }
 }
 
-impl<C> Database<Select<By<Option<Realty>, realty::Hash>>> for Postgres<C>
+impl<C> Database<Select<By<Option<Realty>, (agency::Id, realty::Hash)>>>
+    for Postgres<C>
 where
     C: Connection,
-    Self: Database<
-        Select<By<Option<Realty>, realty::Id>>,
-        Ok = Option<Realty>,
-        Err = Traced<database::Error>,
-    >,
 {
     type Ok = Option<Realty>;
     type Err = Traced<database::Error>;
 
     async fn execute(
         &self,
-        Select(by): Select<By<Option<Realty>, realty::Hash>>,
+        Select(by): Select<By<Option<Realty>, (agency::Id, realty::Hash)>>,
     ) -> Result<Self::Ok, Self::Err> {
         // Avoid subtle change for SQL.
-        let hash: realty::Hash = by.into_inner();
+        let (agency_id, hash): (agency::Id, realty::Hash) = by.into_inner();
 
+        // Deleted `Realty`s are selected too, as they still occupy their
+        // `realty::Hash` until purged.
         const SQL: &str = "\
-            SELECT id \
+            SELECT id, agency_id, assignee_id, hash, address, \
+                   country, state, city, street, zip_code, building_name, \
+                   num_floors, floor, \
+                   apartment_num, room_num, area, \
+                   created_at, deleted_at, version \
             FROM realties \
-            WHERE hash = $1::UUID \
+            WHERE agency_id = $1::UUID \
+              AND hash = $2::UUID \
             LIMIT 1";
-        let Some(row) = self
-            .query_opt(SQL, &[&hash])
+        Ok(self
+            .query_opt(SQL, &[&agency_id, &hash])
             .await
             .map_err(tracerr::wrap!())?
-        else {
-            return Ok(None);
-        };
+            .map(|row| realty_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Option<Deleted<Realty>>, realty::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Deleted<Realty>>;
+    type Err = Traced<database::Error>;
 
-        self.execute(Select(By::new(row.get("id"))))
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Deleted<Realty>>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, assignee_id, hash, address, \
+                   country, state, city, street, zip_code, building_name, \
+                   num_floors, floor, \
+                   apartment_num, room_num, area, \
+                   created_at, deleted_at, version \
+            FROM realties \
+            WHERE id = $1::UUID \
+              AND deleted_at IS NOT NULL \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
             .await
-            .map_err(tracerr::wrap!())
+            .map_err(tracerr::wrap!())?
+            .map(|row| Deleted(realty_from_row(&row))))
     }
 }
 
@@ -183,6 +195,8 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let Realty {
             id,
+            agency_id,
+            assignee_id,
             hash,
             address,
             country,
@@ -195,46 +209,38 @@ where
             floor,
             apartment_num,
             room_num,
+            area,
             created_at,
             deleted_at,
+            version,
         } = realty;
 
         let num_floors = i32::from(num_floors);
         let floor = floor.map(i32::from);
 
-        if deleted_at.is_some() {
-            const SQL: &str = "\
-                DELETE FROM realties \
-                WHERE id = $1::UUID \
-                LIMIT 1";
-            return self
-                .exec(SQL, &[&id])
-                .await
-                .map_err(tracerr::wrap!())
-                .map(drop);
-        }
-
         const SQL: &str = "\
             INSERT INTO realties (\
-                id, hash, address, \
+                id, agency_id, assignee_id, hash, address, \
                 country, state, city, street, zip_code, building_name, \
                 num_floors, floor, \
-                apartment_num, room_num, \
-                created_at \
+                apartment_num, room_num, area, \
+                created_at, deleted_at, version \
             ) VALUES (\
-                $1::UUID, $2::UUID, $3::VARCHAR, \
-                $4::VARCHAR, \
-                $5::VARCHAR, \
+                $1::UUID, $2::UUID, $3::UUID, $4::UUID, $5::VARCHAR, \
                 $6::VARCHAR, \
                 $7::VARCHAR, \
                 $8::VARCHAR, \
                 $9::VARCHAR, \
-                $10::INT4, $11::INT4, \
-                $12::VARCHAR, $13::VARCHAR, \
-                $14::TIMESTAMPTZ \
+                $10::VARCHAR, \
+                $11::VARCHAR, \
+                $12::INT4, $13::INT4, \
+                $14::VARCHAR, $15::VARCHAR, $19::NUMERIC, \
+                $16::TIMESTAMPTZ, $20::TIMESTAMPTZ, $17::INT4 \
             ) \
             ON CONFLICT (id) DO UPDATE \
-            SET hash = EXCLUDED.hash, \
+            SET agency_id = EXCLUDED.agency_id, \
+                assignee_id = EXCLUDED.assignee_id, \
+                hash = EXCLUDED.hash, \
                 address = EXCLUDED.address, \
                 country = EXCLUDED.country, \
                 state = EXCLUDED.state, \
@@ -246,29 +252,45 @@ where
                 floor = EXCLUDED.floor, \
                 apartment_num = EXCLUDED.apartment_num, \
                 room_num = EXCLUDED.room_num, \
-                created_at = EXCLUDED.created_at";
-        self.exec(
-            SQL,
-            &[
-                &id,
-                &hash,
-                &address,
-                &country,
-                &state,
-                &city,
-                &street,
-                &zip_code,
-                &building_name,
-                &num_floors,
-                &floor,
-                &apartment_num,
-                &room_num,
-                &created_at,
-            ],
-        )
-        .await
-        .map_err(tracerr::wrap!())
-        .map(drop)
+                area = EXCLUDED.area, \
+                created_at = EXCLUDED.created_at, \
+                deleted_at = EXCLUDED.deleted_at, \
+                version = EXCLUDED.version \
+            WHERE realties.version = $18::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &agency_id,
+                    &assignee_id,
+                    &hash,
+                    &address,
+                    &country,
+                    &state,
+                    &city,
+                    &street,
+                    &zip_code,
+                    &building_name,
+                    &num_floors,
+                    &floor,
+                    &apartment_num,
+                    &room_num,
+                    &created_at,
+                    &version.next(),
+                    &version,
+                    &area,
+                    &deleted_at,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
     }
 }
 
@@ -286,14 +308,9 @@ where
         // Avoid subtle change for SQL.
         let id: realty::Id = by.into_inner();
 
-        const SQL: &str = "\
-            INSERT INTO realties_lock \
-            VALUES ($1::UUID) \
-            ON CONFLICT (id) DO NOTHING";
-        self.query(SQL, &[&id])
+        self.advisory_lock(LockSpace::Realty, &id)
             .await
             .map_err(tracerr::wrap!())
-            .map(drop)
     }
 }
 
@@ -311,14 +328,9 @@ where
         // Avoid subtle change for SQL.
         let hash: realty::Hash = by.into_inner();
 
-        const SQL: &str = "\
-            INSERT INTO realties_creation_lock \
-            VALUES ($1::UUID) \
-            ON CONFLICT (hash) DO NOTHING";
-        self.query(SQL, &[&hash])
+        self.advisory_lock(LockSpace::RealtyCreation, &hash)
             .await
             .map_err(tracerr::wrap!())
-            .map(drop)
     }
 }
 
@@ -344,6 +356,11 @@ where
               AND terminated_at IS NULL \
               AND (expires_at IS NULL \
                    OR expires_at > NOW()) \
+              AND NOT EXISTS (\
+                  SELECT 1 FROM contract_approvals ap \
+                  WHERE ap.contract_id = contracts.id \
+                    AND ap.approved_at IS NULL\
+              ) \
             LIMIT 1";
         self.query_opt(SQL, &[&contract::Kind::Rent, &realty_id])
             .await
@@ -352,6 +369,84 @@ where
     }
 }
 
+impl<C> Database<Select<By<Vec<Money>, read::realty::Comparables>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Money>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Money>, read::realty::Comparables>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::realty::Comparables {
+            realty_id,
+            agency_id,
+            purpose,
+            country,
+            city,
+            kind,
+            area,
+            since,
+            limit,
+        } = by.into_inner();
+        let contract_kind = match purpose {
+            read::realty::Purpose::Rent => contract::Kind::Rent,
+            read::realty::Purpose::Sale => contract::Kind::Sale,
+        };
+        let (area_from, area_until) = area.map(|a| (a.start, a.end)).unzip();
+        let limit = i32::from(limit);
+
+        const SQL: &str = "\
+            SELECT c.price, c.price_currency \
+            FROM contracts AS c \
+            INNER JOIN realties AS r \
+                    ON r.id = c.realty_id \
+            WHERE c.agency_id = $1::UUID \
+              AND c.kind = $2::INT2 \
+              AND c.realty_id <> $3::UUID \
+              AND c.price IS NOT NULL \
+              AND c.terminated_at IS NULL \
+              AND c.created_at >= $4::TIMESTAMPTZ \
+              AND r.country = $5::VARCHAR \
+              AND r.city = $6::VARCHAR \
+              AND (CASE WHEN r.room_num IS NOT NULL THEN 3 \
+                        WHEN r.apartment_num IS NOT NULL THEN 1 \
+                        ELSE 2 \
+                   END) = $7::INT2 \
+              AND ($8::NUMERIC IS NULL \
+                   OR (r.area >= $8::NUMERIC AND r.area < $9::NUMERIC)) \
+            ORDER BY c.created_at DESC \
+            LIMIT $10::INT4";
+        Ok(self
+            .query(
+                SQL,
+                &[
+                    &agency_id,
+                    &contract_kind,
+                    &realty_id,
+                    &since,
+                    &country,
+                    &city,
+                    &kind,
+                    &area_from,
+                    &area_until,
+                    &limit,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| Money {
+                amount: row.get("price"),
+                currency: row.get("price_currency"),
+            })
+            .collect())
+    }
+}
+
 impl<C>
     Database<Select<By<read::realty::list::Page, read::realty::list::Selector>>>
     for Postgres<C>
@@ -369,19 +464,23 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let read::realty::list::Selector {
             arguments,
-            filter: read::realty::list::Filter { address },
+            filter:
+                read::realty::list::Filter {
+                    agency_id,
+                    assignee_id,
+                    address,
+                    ids,
+                    sort_by,
+                },
         } = by.into_inner();
 
         let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
 
         let mut ps: Vec<&(dyn ToSql + Sync)> = vec![&limit];
 
-        let cursor_idx = arguments.cursor().map(|c| {
-            ps.push(c);
-            ps.len()
-        });
-        let address_idx = address.as_ref().map(|n| {
-            ps.push(n);
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
             ps.len()
         });
 
@@ -392,34 +491,99 @@ where
             ps.len()
         });
 
+        let ids_idx = ids.as_ref().map(|ids| {
+            ps.push(ids);
+            ps.len()
+        });
+
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let assignee_id_idx = assignee_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let relevance = address_pattern_idx.map_or_else(
+            || "0".to_owned(),
+            |idx| format!("${idx}::VARCHAR <<-> address"),
+        );
+        let sort_key = match sort_by {
+            SortBy::Relevance => {
+                address_pattern_idx.map(|_| relevance.as_str())
+            }
+            SortBy::Address => Some("address"),
+            SortBy::CreatedAt => Some("created_at"),
+        };
+
+        // Cursor of another `SortBy` order can only be positioned by ID.
+        let cursor = arguments.cursor().map(|c| {
+            let key_idx = sort_key
+                .filter(|_| c.key.sort_by() == sort_by)
+                .map(|_| {
+                    let (key, ty): (&(dyn ToSql + Sync), _) = match &c.key {
+                        SortKey::Relevance(r) => (r, "FLOAT4"),
+                        SortKey::Address(n) => (n, "VARCHAR"),
+                        SortKey::CreatedAt(at) => (at, "TIMESTAMPTZ"),
+                    };
+                    ps.push(key);
+                    (ps.len(), ty)
+                });
+            ps.push(&c.id);
+            (key_idx, ps.len())
+        });
+
         let sql = format!(
-            "SELECT id \
+            "SELECT id, agency_id, assignee_id, hash, address, \
+                    country, state, city, street, zip_code, building_name, \
+                    num_floors, floor, \
+                    apartment_num, room_num, area, \
+                    created_at, deleted_at, version, \
+                    {relevance}::FLOAT4 AS relevance \
              FROM realties \
-             WHERE true \
+             WHERE deleted_at IS NULL \
+                   {agency_filtering} \
+                   {assignee_filtering} \
                    {cursor} \
                    {address_filtering} \
-             ORDER BY {address_ordering} \
+             ORDER BY {sort_ordering} \
                       id {order} \
-             LIMIT $1::INT4",
-            cursor = cursor_idx.into_iter().format_with("", |idx, f| {
+             LIMIT $1::INT4 \
+             {offset}",
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND agency_id = ${idx}::UUID"))
+                }),
+            assignee_filtering =
+                assignee_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND assignee_id = ${idx}::UUID"))
+                }),
+            cursor = cursor.into_iter().format_with("", |(key, idx), f| {
                 let op = arguments.kind().operator();
-                f(&format_args!("AND id {op} ${idx}::UUID"))
+                match (key, sort_key) {
+                    (Some((key_idx, ty)), Some(sort_key)) => f(&format_args!(
+                        "AND ({sort_key}, id) {op} \
+                             (${key_idx}::{ty}, ${idx}::UUID)"
+                    )),
+                    _ => f(&format_args!("AND id {op} ${idx}::UUID")),
+                }
             }),
             order = arguments.kind().order().sql(),
-            address_filtering =
-                address_pattern_idx.into_iter().format_with("", |idx, f| {
-                    f(&format_args!(
-                        "AND LOWER(address) SIMILAR TO LOWER(${idx}::VARCHAR)"
-                    ))
-                }),
-            address_ordering =
-                address_idx.into_iter().format_with("", |idx, f| {
-                    let order = arguments.kind().order().sql();
-                    f(&format_args!(
-                        "LEVENSHTEIN(address, ${idx}::VARCHAR, 1, 1, 0) \
-                         {order},"
-                    ))
-                })
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+            // `Search`-found IDs are already matching the `address`.
+            address_filtering = match (ids_idx, address_pattern_idx) {
+                (Some(idx), _) => format!("AND id = ANY(${idx}::UUID[])"),
+                (None, Some(idx)) => format!("AND ${idx}::VARCHAR <% address"),
+                (None, None) => String::new(),
+            },
+            sort_ordering = sort_key.into_iter().format_with("", |key, f| {
+                let order = arguments.kind().order().sql();
+                f(&format_args!("{key} {order},"))
+            }),
         );
         let rows = self
             .query(&sql, ps.as_slice())
@@ -431,8 +595,13 @@ where
             .into_iter()
             .take(arguments.limit())
             .map(|row| {
-                let id = row.get("id");
-                (id, id)
+                let node = realty_from_row(&row);
+                let cursor = read::realty::list::Cursor::new(
+                    sort_by,
+                    &node,
+                    row.get("relevance"),
+                );
+                (cursor, node)
             })
             .collect::<Vec<_>>();
 
@@ -440,7 +609,10 @@ where
     }
 }
 
-impl<C> Database<Select<By<read::realty::list::TotalCount, ()>>> for Postgres<C>
+impl<C>
+    Database<
+        Select<By<read::realty::list::TotalCount, read::realty::list::Filter>>,
+    > for Postgres<C>
 where
     C: Connection,
 {
@@ -449,23 +621,107 @@ where
 
     async fn execute(
         &self,
-        Select(_): Select<By<read::realty::list::TotalCount, ()>>,
+        Select(by): Select<
+            By<read::realty::list::TotalCount, read::realty::list::Filter>,
+        >,
     ) -> Result<Self::Ok, Self::Err> {
-        const SQL: &str = "\
-            SELECT COUNT(*)::INT4 \
-            FROM realties";
-        self.query_opt(SQL, &[])
+        let filter = by.into_inner();
+        let address_pattern =
+            filter.address.as_ref().map(|a| FuzzPattern::new(a.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT COUNT(*)::INT4 \
+             FROM realties \
+             WHERE deleted_at IS NULL {}",
+            list_filtering(&filter, address_pattern.as_ref(), &mut ps),
+        );
+        self.query_opt(&sql, ps.as_slice())
             .await
             .map_err(tracerr::wrap!())
             .map(|row| row.expect("always exists").get::<_, i32>(0).into())
     }
 }
 
+impl<C>
+    Database<
+        Select<
+            By<
+                read::Estimated<read::realty::list::TotalCount>,
+                read::realty::list::Filter,
+            >,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::Estimated<read::realty::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<
+                read::Estimated<read::realty::list::TotalCount>,
+                read::realty::list::Filter,
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let filter = by.into_inner();
+        let address_pattern =
+            filter.address.as_ref().map(|a| FuzzPattern::new(a.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT 1 \
+             FROM realties \
+             WHERE deleted_at IS NULL {}",
+            list_filtering(&filter, address_pattern.as_ref(), &mut ps),
+        );
+        self.estimate_rows(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|count| read::Estimated(count.into()))
+    }
+}
+
+impl<C>
+    Database<
+        Select<By<HashMap<agency::Id, read::realty::list::TotalCount>, ()>>,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = HashMap<agency::Id, read::realty::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Select<
+            By<HashMap<agency::Id, read::realty::list::TotalCount>, ()>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "\
+            SELECT agency_id, COUNT(*)::INT4 \
+            FROM realties \
+            WHERE deleted_at IS NULL \
+            GROUP BY agency_id";
+        self.query(SQL, &[])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| (row.get(0), row.get::<_, i32>(1).into()))
+                    .collect()
+            })
+    }
+}
+
 impl<C> Database<Delete<By<Realty, realty::CreationDateTime>>> for Postgres<C>
 where
     C: Connection,
 {
-    type Ok = ();
+    type Ok = u64;
     type Err = Traced<database::Error>;
 
     async fn execute(
@@ -474,18 +730,113 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let deadline: realty::CreationDateTime = by.into_inner();
 
+        // `Realty`s are only soft-deleted here, so may be restored until
+        // purged.
         const SQL: &str = "\
-            DELETE FROM realties \
+            UPDATE realties \
+            SET deleted_at = NOW(), \
+                version = version + 1 \
             WHERE (SELECT COUNT(*) \
                    FROM contracts \
                    WHERE realty_id = realties.id \
                      AND terminated_at IS NULL \
                      AND (expires_at IS NULL \
                           OR expires_at > NOW())) = 0 \
-              AND created_at < $1";
+              AND created_at < $1 \
+              AND deleted_at IS NULL";
         self.exec(SQL, &[&deadline])
             .await
             .map_err(tracerr::wrap!())
-            .map(drop)
     }
 }
+
+impl<C> Database<Delete<By<Realty, realty::DeletionDateTime>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = u64;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<Realty, realty::DeletionDateTime>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let deadline: realty::DeletionDateTime = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM realties \
+            WHERE deleted_at < $1::TIMESTAMPTZ";
+        self.exec(SQL, &[&deadline])
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+/// Maps the provided [`Row`] of the `realties` table into a [`Realty`].
+fn realty_from_row(row: &Row) -> Realty {
+    Realty {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        assignee_id: row.get("assignee_id"),
+        hash: row.get("hash"),
+        address: row.get("address"),
+        country: row.get("country"),
+        state: row.get("state"),
+        city: row.get("city"),
+        street: row.get("street"),
+        zip_code: row.get("zip_code"),
+        building_name: row.get("building_name"),
+        num_floors: u16::try_from(row.get::<_, i32>("num_floors"))
+            .expect("`num_floors` overflow"),
+        floor: row
+            .get::<_, Option<i32>>("floor")
+            .map(u16::try_from)
+            .transpose()
+            .expect("`floor` overflow"),
+        apartment_num: row.get("apartment_num"),
+        room_num: row.get("room_num"),
+        area: row.get("area"),
+        created_at: row.get("created_at"),
+        deleted_at: row.get("deleted_at"),
+        version: row.get("version"),
+    }
+}
+
+/// Returns the SQL conditions selecting the [`Realty`]s matching the provided
+/// [`read::realty::list::Filter`], pushing their parameters into the provided
+/// `ps`.
+///
+/// The `address_pattern` is expected to be built out of the
+/// [`read::realty::list::Filter::address`].
+fn list_filtering<'a>(
+    filter: &'a read::realty::list::Filter,
+    address_pattern: Option<&'a FuzzPattern>,
+    ps: &mut Vec<&'a (dyn ToSql + Sync)>,
+) -> String {
+    let read::realty::list::Filter {
+        agency_id,
+        assignee_id,
+        address: _,
+        ids,
+        sort_by: _,
+    } = filter;
+
+    let mut conditions = vec![];
+    if let Some(id) = agency_id {
+        ps.push(id);
+        conditions.push(format!("AND agency_id = ${}::UUID", ps.len()));
+    }
+    if let Some(id) = assignee_id {
+        ps.push(id);
+        conditions.push(format!("AND assignee_id = ${}::UUID", ps.len()));
+    }
+    // `Search`-found IDs are already matching the `address`.
+    if let Some(ids) = ids {
+        ps.push(ids);
+        conditions.push(format!("AND id = ANY(${}::UUID[])", ps.len()));
+    } else if let Some(pattern) = address_pattern {
+        ps.push(pattern);
+        conditions.push(format!("AND ${}::VARCHAR <% address", ps.len()));
+    }
+    conditions.join(" ")
+}
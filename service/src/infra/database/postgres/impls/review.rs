@@ -0,0 +1,234 @@
+//! [`Review`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select, Update};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, review, user, Review},
+    infra::{
+        database::{
+            self,
+            postgres::{self, Connection},
+            Postgres,
+        },
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<Review>, review::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Review>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Review>, review::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: review::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, employer_id, author_id, \
+                   rating, comment, status, created_at, version \
+            FROM reviews \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| review_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Option<Review>, contract::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Review>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Review>, contract::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let contract_id: contract::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, employer_id, author_id, \
+                   rating, comment, status, created_at, version \
+            FROM reviews \
+            WHERE contract_id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&contract_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| review_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<Review>, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Review>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Review>, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let employer_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, contract_id, employer_id, author_id, \
+                   rating, comment, status, created_at, version \
+            FROM reviews \
+            WHERE employer_id = $1::UUID \
+            ORDER BY created_at DESC, id";
+        Ok(self
+            .query(SQL, &[&employer_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(review_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Select<By<Option<read::review::Rating>, user::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<read::review::Rating>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<read::review::Rating>, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let employer_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT AVG(rating)::FLOAT8 AS average, COUNT(*)::INT4 AS count \
+            FROM reviews \
+            WHERE employer_id = $1::UUID \
+              AND status = $2::INT2 \
+            HAVING COUNT(*) > 0";
+        Ok(self
+            .query_opt(SQL, &[&employer_id, &review::Status::Published])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| read::review::Rating {
+                average: row.get("average"),
+                count: row.get::<_, i32>("count").unsigned_abs(),
+            }))
+    }
+}
+
+impl<C> Database<Insert<Review>> for Postgres<C>
+where
+    C: Connection,
+    Self: Database<Update<Review>, Ok = (), Err = Traced<database::Error>>,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(review): Insert<Review>,
+    ) -> Result<Self::Ok, Self::Err> {
+        self.execute(Update(review)).await.map_err(tracerr::wrap!())
+    }
+}
+
+impl<C> Database<Update<Review>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(review): Update<Review>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Review {
+            id,
+            agency_id,
+            contract_id,
+            employer_id,
+            author_id,
+            rating,
+            comment,
+            status,
+            created_at,
+            version,
+        } = review;
+
+        const SQL: &str = "\
+            INSERT INTO reviews (\
+                id, agency_id, contract_id, employer_id, author_id, \
+                rating, comment, status, created_at, version\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::UUID, $5::UUID, \
+                $6::INT2, $7::VARCHAR, $8::INT2, $9::TIMESTAMPTZ, $10::INT4\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET rating = EXCLUDED.rating, \
+                comment = EXCLUDED.comment, \
+                status = EXCLUDED.status, \
+                version = EXCLUDED.version \
+            WHERE reviews.version = $11::INT4";
+        let updated = self
+            .exec(
+                SQL,
+                &[
+                    &id,
+                    &agency_id,
+                    &contract_id,
+                    &employer_id,
+                    &author_id,
+                    &rating,
+                    &comment,
+                    &status,
+                    &created_at,
+                    &version.next(),
+                    &version,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?;
+        if updated == 0 {
+            return Err(tracerr::new!(database::Error::from(
+                postgres::Error::VersionConflict,
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Maps the provided [`Row`] of the `reviews` table into a [`Review`].
+fn review_from_row(row: &Row) -> Review {
+    Review {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        contract_id: row.get("contract_id"),
+        employer_id: row.get("employer_id"),
+        author_id: row.get("author_id"),
+        rating: row.get("rating"),
+        comment: row.get("comment"),
+        status: row.get("status"),
+        created_at: row.get("created_at"),
+        version: row.get("version"),
+    }
+}
@@ -0,0 +1,293 @@
+//! [`SavedSearch`]-related [`Database`] implementations.
+
+use common::{
+    money::Currency,
+    operations::{By, Delete, Insert, Select},
+    Money,
+};
+use rust_decimal::Decimal;
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, saved_search, user, SavedSearch},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<SavedSearch>, saved_search::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<SavedSearch>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<SavedSearch>, saved_search::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: saved_search::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, user_id, agency_id, city, \
+                   min_price, max_price, price_currency, kind, \
+                   created_at, alerted_at \
+            FROM saved_searches \
+            WHERE id = $1::UUID";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| saved_search_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<SavedSearch>, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<SavedSearch>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<SavedSearch>, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let user_id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, user_id, agency_id, city, \
+                   min_price, max_price, price_currency, kind, \
+                   created_at, alerted_at \
+            FROM saved_searches \
+            WHERE user_id = $1::UUID \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&user_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(saved_search_from_row)
+            .collect())
+    }
+}
+
+impl<C>
+    Database<
+        Select<
+            By<Vec<read::saved_search::Matched>, read::saved_search::Unalerted>,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<read::saved_search::Matched>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<read::saved_search::Matched>, read::saved_search::Unalerted>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::saved_search::Unalerted {
+            placed_by,
+            after,
+            limit,
+        } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let max_matched =
+            i32::try_from(read::saved_search::MAX_MATCHED_REALTIES)
+                .unwrap_or(i32::MAX);
+
+        // A `Realty` placed for both rent and sale is matched once.
+        const SQL: &str = "\
+            SELECT s.id, s.user_id, s.agency_id, s.city, \
+                   s.min_price, s.max_price, s.price_currency, s.kind, \
+                   s.created_at, s.alerted_at, \
+                   m.count, m.realty_ids, m.addresses, \
+                   u.email AS user_email, st.locale \
+            FROM saved_searches AS s \
+            INNER JOIN LATERAL (\
+                SELECT COUNT(*)::INT8 AS count, \
+                       (ARRAY_AGG(id ORDER BY id))[1:$7::INT4] \
+                           AS realty_ids, \
+                       (ARRAY_AGG(address ORDER BY id))[1:$7::INT4] \
+                           AS addresses \
+                FROM (SELECT DISTINCT r.id, r.address \
+                      FROM contracts AS c \
+                      INNER JOIN realties AS r ON r.id = c.realty_id \
+                      WHERE r.agency_id = s.agency_id \
+                        AND c.is_placed \
+                        AND c.terminated_at IS NULL \
+                        AND (c.expires_at IS NULL OR c.expires_at > NOW()) \
+                        AND c.placed_at > s.alerted_at \
+                        AND c.placed_at <= $5::TIMESTAMPTZ \
+                        AND ((c.kind = $3::INT2 \
+                              AND (s.kind IS NULL OR s.kind = $1::INT2)) \
+                             OR (c.kind = $4::INT2 \
+                                 AND (s.kind IS NULL OR s.kind = $2::INT2))) \
+                        AND (s.city IS NULL \
+                             OR LOWER(r.city) = LOWER(s.city)) \
+                        AND (s.min_price IS NULL \
+                             OR (c.price_currency = s.price_currency \
+                                 AND c.price >= s.min_price)) \
+                        AND (s.max_price IS NULL \
+                             OR (c.price_currency = s.price_currency \
+                                 AND c.price <= s.max_price))\
+                     ) AS matched\
+            ) AS m ON m.count > 0 \
+            LEFT JOIN users u ON u.id = s.user_id \
+                             AND u.deleted_at IS NULL \
+            LEFT JOIN agency_settings st ON st.agency_id = s.agency_id \
+            WHERE ($6::UUID IS NULL OR s.id > $6::UUID) \
+            ORDER BY s.id \
+            LIMIT $8::INT8";
+        Ok(self
+            .query(
+                SQL,
+                &[
+                    &saved_search::Kind::Rent,
+                    &saved_search::Kind::Sale,
+                    &contract::Kind::ManagementForRent,
+                    &contract::Kind::ManagementForSale,
+                    &placed_by,
+                    &after,
+                    &max_matched,
+                    &limit,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(|row| read::saved_search::Matched {
+                search: saved_search_from_row(row),
+                count: u32::try_from(row.get::<_, i64>("count"))
+                    .unwrap_or(u32::MAX),
+                realty_ids: row.get("realty_ids"),
+                addresses: row.get("addresses"),
+                user_email: row.get("user_email"),
+                locale: row.get("locale"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<SavedSearch>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(search): Insert<SavedSearch>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let SavedSearch {
+            id,
+            user_id,
+            agency_id,
+            city,
+            min_price,
+            max_price,
+            kind,
+            created_at,
+            alerted_at,
+        } = search;
+
+        let price_currency =
+            min_price.or(max_price).map(|price| price.currency);
+        let min_price = min_price.map(|price| price.amount);
+        let max_price = max_price.map(|price| price.amount);
+
+        const SQL: &str = "\
+            INSERT INTO saved_searches (\
+                id, user_id, agency_id, city, \
+                min_price, max_price, price_currency, kind, \
+                created_at, alerted_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::UUID, $4::VARCHAR, \
+                $5::NUMERIC, $6::NUMERIC, $7::INT2, $8::INT2, \
+                $9::TIMESTAMPTZ, $10::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET city = EXCLUDED.city, \
+                min_price = EXCLUDED.min_price, \
+                max_price = EXCLUDED.max_price, \
+                price_currency = EXCLUDED.price_currency, \
+                kind = EXCLUDED.kind, \
+                alerted_at = EXCLUDED.alerted_at";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &user_id,
+                &agency_id,
+                &city,
+                &min_price,
+                &max_price,
+                &price_currency,
+                &kind,
+                &created_at,
+                &alerted_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<SavedSearch, saved_search::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<SavedSearch, saved_search::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: saved_search::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM saved_searches \
+            WHERE id = $1::UUID";
+        self.exec(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `saved_searches` table into a
+/// [`SavedSearch`].
+fn saved_search_from_row(row: &Row) -> SavedSearch {
+    let currency = row.get::<_, Option<Currency>>("price_currency");
+    let price = |column: &str| {
+        row.get::<_, Option<Decimal>>(column)
+            .zip(currency)
+            .map(|(amount, currency)| Money { amount, currency })
+    };
+
+    SavedSearch {
+        id: row.get("id"),
+        user_id: row.get("user_id"),
+        agency_id: row.get("agency_id"),
+        city: row.get("city"),
+        min_price: price("min_price"),
+        max_price: price("max_price"),
+        kind: row.get("kind"),
+        created_at: row.get("created_at"),
+        alerted_at: row.get("alerted_at"),
+    }
+}
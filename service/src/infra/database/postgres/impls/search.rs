@@ -0,0 +1,134 @@
+//! Global search [`Database`] implementations.
+
+use common::operations::{By, Select};
+use tracerr::Traced;
+
+use crate::{
+    domain::contract,
+    infra::{
+        database::{
+            self,
+            postgres::{Connection, FuzzPattern},
+            Postgres,
+        },
+        Database,
+    },
+    read::search::{Match, Selector, RECENCY_HALF_LIFE_DAYS},
+};
+
+impl<C> Database<Select<By<Vec<Match>, Selector>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<Match>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<Match>, Selector>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Selector {
+            query,
+            limit,
+            agency_id,
+            price,
+            ranking,
+        } = by.into_inner();
+        let pattern = FuzzPattern::new(&query);
+        let limit = i32::try_from(limit).unwrap();
+        let (price, currency) = price.map(|p| (p.amount, p.currency)).unzip();
+        let half_life = f64::from(RECENCY_HALF_LIFE_DAYS);
+
+        // Each branch is limited separately to make use of the trigram
+        // indexes ordering. `Placement`s are ranked by the weighted sum of
+        // their text distance, freshness and price distance instead.
+        const SQL: &str = "\
+            SELECT kind, id \
+            FROM ((SELECT 'user' AS kind, id, \
+                          $1::VARCHAR <<-> name AS distance \
+                   FROM users \
+                   WHERE deleted_at IS NULL \
+                     AND $1::VARCHAR <% name \
+                   ORDER BY distance \
+                   LIMIT $2::INT4) \
+                  UNION ALL \
+                  (SELECT 'realty' AS kind, id, \
+                          $1::VARCHAR <<-> address AS distance \
+                   FROM realties \
+                   WHERE deleted_at IS NULL \
+                     AND $1::VARCHAR <% address \
+                     AND ($5::UUID IS NULL OR agency_id = $5::UUID) \
+                   ORDER BY distance \
+                   LIMIT $2::INT4) \
+                  UNION ALL \
+                  (SELECT 'contract' AS kind, id, \
+                          $1::VARCHAR <<-> name AS distance \
+                   FROM contracts \
+                   WHERE $1::VARCHAR <% name \
+                     AND ($5::UUID IS NULL OR agency_id = $5::UUID) \
+                   ORDER BY distance \
+                   LIMIT $2::INT4) \
+                  UNION ALL \
+                  (SELECT 'placement' AS kind, r.id, \
+                          $8::FLOAT8 * ($1::VARCHAR <<-> r.address) \
+                          - $9::FLOAT8 * COALESCE(p.freshness, 0) \
+                          + $10::FLOAT8 * p.price_distance AS distance \
+                   FROM realties AS r \
+                   INNER JOIN LATERAL (\
+                       SELECT MAX($11::FLOAT8 / ($11::FLOAT8 \
+                                  + EXTRACT(EPOCH FROM NOW() - placed_at) \
+                                        ::FLOAT8 / 86400)) AS freshness, \
+                              CASE WHEN $6::NUMERIC IS NULL THEN 0 \
+                                   ELSE COALESCE(MIN(\
+                                       CASE WHEN price_currency = $7::INT2 \
+                                            THEN COALESCE(\
+                                                ABS(price - $6::NUMERIC) \
+                                                / NULLIF(GREATEST(\
+                                                    price, $6::NUMERIC\
+                                                ), 0), 0)::FLOAT8 \
+                                       END), 1) \
+                              END AS price_distance \
+                       FROM contracts \
+                       WHERE kind IN ($3::INT2, $4::INT2) \
+                         AND is_placed \
+                         AND terminated_at IS NULL \
+                         AND (expires_at IS NULL OR expires_at > NOW()) \
+                         AND realty_id = r.id \
+                       GROUP BY realty_id\
+                   ) AS p ON TRUE \
+                   WHERE $1::VARCHAR <% r.address \
+                     AND ($5::UUID IS NULL OR r.agency_id = $5::UUID) \
+                   ORDER BY distance \
+                   LIMIT $2::INT4)) AS matches \
+            ORDER BY distance ASC, kind ASC, id ASC \
+            LIMIT $2::INT4";
+        Ok(self
+            .query(
+                SQL,
+                &[
+                    &pattern,
+                    &limit,
+                    &contract::Kind::ManagementForRent,
+                    &contract::Kind::ManagementForSale,
+                    &agency_id,
+                    &price,
+                    &currency,
+                    &ranking.text_match,
+                    &ranking.recency_boost,
+                    &ranking.price_proximity,
+                    &half_life,
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| match row.get::<_, &str>("kind") {
+                "user" => Match::User(row.get("id")),
+                "realty" => Match::Realty(row.get("id")),
+                "contract" => Match::Contract(row.get("id")),
+                "placement" => Match::Placement(row.get("id")),
+                kind => unreachable!("unknown `{kind}` search match kind"),
+            })
+            .collect())
+    }
+}
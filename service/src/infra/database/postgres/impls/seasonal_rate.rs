@@ -0,0 +1,150 @@
+//! [`SeasonalRate`]-related [`Database`] implementations.
+
+use common::{
+    operations::{By, Delete, Insert, Select},
+    Money,
+};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract::short_term_rent::SeasonalRate, realty},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Vec<SeasonalRate>, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<SeasonalRate>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<SeasonalRate>, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT realty_id, starts_at, ends_at, \
+                   nightly_rate, nightly_rate_currency \
+            FROM realty_seasonal_rates \
+            WHERE realty_id = $1::UUID \
+            ORDER BY starts_at";
+        Ok(self
+            .query(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(seasonal_rate_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<Vec<SeasonalRate>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(rates): Insert<Vec<SeasonalRate>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if rates.is_empty() {
+            return Ok(());
+        }
+        let total = rates.len();
+
+        let mut realty_ids = Vec::with_capacity(total);
+        let mut start_dates = Vec::with_capacity(total);
+        let mut end_dates = Vec::with_capacity(total);
+        let mut nightly_rates = Vec::with_capacity(total);
+        let mut nightly_rate_currencies = Vec::with_capacity(total);
+        for r in rates {
+            realty_ids.push(r.realty_id);
+            start_dates.push(r.from);
+            end_dates.push(r.until);
+            nightly_rates.push(r.nightly_rate.amount);
+            nightly_rate_currencies.push(r.nightly_rate.currency);
+        }
+
+        const SQL: &str = "\
+            INSERT INTO realty_seasonal_rates (\
+                realty_id, starts_at, ends_at, \
+                nightly_rate, nightly_rate_currency\
+            ) \
+            SELECT realty_id, starts_at, ends_at, \
+                   nightly_rate, nightly_rate_currency \
+            FROM unnest(\
+                $1::UUID[], \
+                $2::TIMESTAMPTZ[], \
+                $3::TIMESTAMPTZ[], \
+                $4::NUMERIC[], \
+                $5::INT2[]\
+            ) AS r(\
+                realty_id, starts_at, ends_at, \
+                nightly_rate, nightly_rate_currency\
+            ) \
+            ON CONFLICT (realty_id, starts_at) DO UPDATE \
+            SET ends_at = EXCLUDED.ends_at, \
+                nightly_rate = EXCLUDED.nightly_rate, \
+                nightly_rate_currency = EXCLUDED.nightly_rate_currency";
+        self.exec(
+            SQL,
+            &[
+                &realty_ids,
+                &start_dates,
+                &end_dates,
+                &nightly_rates,
+                &nightly_rate_currencies,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<SeasonalRate, realty::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<SeasonalRate, realty::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let realty_id: realty::Id = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM realty_seasonal_rates \
+            WHERE realty_id = $1::UUID";
+        self.exec(SQL, &[&realty_id])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `realty_seasonal_rates` table into a
+/// [`SeasonalRate`].
+fn seasonal_rate_from_row(row: &Row) -> SeasonalRate {
+    SeasonalRate {
+        realty_id: row.get("realty_id"),
+        from: row.get("starts_at"),
+        until: row.get("ends_at"),
+        nightly_rate: Money {
+            amount: row.get("nightly_rate"),
+            currency: row.get("nightly_rate_currency"),
+        },
+    }
+}
@@ -0,0 +1,116 @@
+//! [`sms::Delivery`]-related [`Database`] implementations.
+
+use common::operations::{By, Delete, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::sms,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<sms::Delivery>, sms::ProviderId>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<sms::Delivery>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<sms::Delivery>, sms::ProviderId>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let provider_id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, phone, provider_id, status, created_at, updated_at \
+            FROM sms_deliveries \
+            WHERE provider_id = $1::VARCHAR";
+        Ok(self
+            .query_opt(SQL, &[&provider_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .as_ref()
+            .map(delivery_from_row))
+    }
+}
+
+impl<C> Database<Insert<sms::Delivery>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(delivery): Insert<sms::Delivery>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let sms::Delivery {
+            id,
+            phone,
+            provider_id,
+            status,
+            created_at,
+            updated_at,
+        } = delivery;
+
+        const SQL: &str = "\
+            INSERT INTO sms_deliveries (\
+                id, phone, provider_id, status, created_at, updated_at\
+            ) VALUES (\
+                $1::UUID, $2::VARCHAR, $3::VARCHAR, $4::INT2, \
+                $5::TIMESTAMPTZ, $6::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET provider_id = EXCLUDED.provider_id, \
+                status = EXCLUDED.status, \
+                updated_at = EXCLUDED.updated_at";
+        self.exec(
+            SQL,
+            &[&id, &phone, &provider_id, &status, &created_at, &updated_at],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<sms::Delivery, sms::UpdateDateTime>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = u64;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<sms::Delivery, sms::UpdateDateTime>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let deadline: sms::UpdateDateTime = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM sms_deliveries \
+            WHERE updated_at < $1::TIMESTAMPTZ";
+        self.exec(SQL, &[&deadline])
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+/// Constructs a [`sms::Delivery`] from the provided [`Row`].
+fn delivery_from_row(row: &Row) -> sms::Delivery {
+    sms::Delivery {
+        id: row.get("id"),
+        phone: row.get("phone"),
+        provider_id: row.get("provider_id"),
+        status: row.get("status"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
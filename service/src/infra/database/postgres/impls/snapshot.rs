@@ -0,0 +1,121 @@
+//! Snapshot-related [`Database`] implementations.
+
+use common::operations::{By, Select};
+use tracerr::Traced;
+
+use crate::{
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+/// Tables of all the entities captured by a [`database::Snapshot`], in the
+/// order they're selected.
+const ENTITIES: &[&str] = &[
+    "agencies",
+    "agency_settings",
+    "branches",
+    "users",
+    "user_calendar_feeds",
+    "user_invites",
+    "realties",
+    "realty_ownerships",
+    "realty_ownership_transfers",
+    "realty_keys",
+    "realty_floor_plans",
+    "realty_media_links",
+    "realty_seasonal_rates",
+    "contracts",
+    "contract_number_sequences",
+    "contract_parties",
+    "contract_approvals",
+    "contract_commission_splits",
+    "contract_insurances",
+    "contract_templates",
+    "rent_payments",
+    "rent_arrears",
+    "rent_late_fees",
+    "rent_charges",
+    "rent_inventories",
+    "rent_inventory_items",
+    "leads",
+    "lead_transitions",
+    "offers",
+    "reviews",
+    "saved_searches",
+    "external_listings",
+    "listing_syncs",
+    "journal_exports",
+    "sms_deliveries",
+    "audit_entries",
+    "task_settings",
+    "task_runs",
+    "task_dead_letters",
+];
+
+impl<C>
+    Database<Select<By<Vec<read::snapshot::Record>, read::snapshot::Chunk>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<read::snapshot::Record>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<Vec<read::snapshot::Record>, read::snapshot::Chunk>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::snapshot::Chunk { after, limit } = by.into_inner();
+
+        // Unknown entities are considered to be past the last one.
+        let (start, mut after_tid) = after.map_or((0, None), |p| {
+            let idx = ENTITIES
+                .iter()
+                .position(|e| *e == p.entity)
+                .unwrap_or(ENTITIES.len());
+            (idx, Some(p.row))
+        });
+
+        let mut records = Vec::with_capacity(limit);
+        for &entity in &ENTITIES[start..] {
+            if records.len() >= limit {
+                break;
+            }
+            let remaining = i64::try_from(limit - records.len()).unwrap();
+            let after_tid = after_tid.take().unwrap_or_else(|| "(0,0)".into());
+
+            // Physical row positions are stable inside the snapshot, so are
+            // used for seeking through the tables without primary keys
+            // knowledge. Table names are constants, so are safe to format.
+            let sql = format!(
+                "SELECT t.ctid::TEXT AS tid, row_to_json(t)::TEXT AS data \
+                 FROM {entity} AS t \
+                 WHERE t.ctid > $1::TEXT::TID \
+                 ORDER BY t.ctid \
+                 LIMIT $2::INT8",
+            );
+            let rows = self
+                .query(&sql, &[&after_tid, &remaining])
+                .await
+                .map_err(tracerr::wrap!())?;
+
+            records.extend(rows.into_iter().map(|row| {
+                read::snapshot::Record {
+                    entity,
+                    data: row.get("data"),
+                    position: read::snapshot::Position {
+                        entity,
+                        row: row.get("tid"),
+                    },
+                }
+            }));
+        }
+
+        Ok(records)
+    }
+}
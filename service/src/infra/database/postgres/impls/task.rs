@@ -0,0 +1,293 @@
+//! [`task::Settings`]-, [`task::Run`]- and [`task::DeadLetter`]-related
+//! [`Database`] implementations.
+
+use std::time::Duration;
+
+use common::{
+    operations::{By, Delete, Insert, Select, Update},
+    DateTime,
+};
+use tracerr::Traced;
+
+use crate::{
+    domain::task,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C> Database<Select<By<Option<task::Settings>, task::Kind>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<task::Settings>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<task::Settings>, task::Kind>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let kind = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT task, interval_ms, timeout_ms, updated_at \
+            FROM task_settings \
+            WHERE task = $1::INT2";
+        Ok(self
+            .query_opt(SQL, &[&kind])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| task::Settings {
+                task: row.get("task"),
+                interval: row
+                    .get::<_, Option<i64>>("interval_ms")
+                    .map(duration_from_millis),
+                timeout: row
+                    .get::<_, Option<i64>>("timeout_ms")
+                    .map(duration_from_millis),
+                updated_at: row.get("updated_at"),
+            }))
+    }
+}
+
+impl<C> Database<Update<task::Settings>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Update(settings): Update<task::Settings>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let task::Settings {
+            task,
+            interval,
+            timeout,
+            updated_at,
+        } = settings;
+        let interval = interval.map(duration_into_millis);
+        let timeout = timeout.map(duration_into_millis);
+
+        const SQL: &str = "\
+            INSERT INTO task_settings (\
+                task, interval_ms, timeout_ms, updated_at\
+            ) \
+            VALUES ($1::INT2, $2::INT8, $3::INT8, $4::TIMESTAMPTZ) \
+            ON CONFLICT (task) DO UPDATE \
+            SET interval_ms = EXCLUDED.interval_ms, \
+                timeout_ms = EXCLUDED.timeout_ms, \
+                updated_at = EXCLUDED.updated_at";
+        self.exec(SQL, &[&task, &interval, &timeout, &updated_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Insert<task::Run>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(run): Insert<task::Run>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let task::Run {
+            task,
+            started_at,
+            duration,
+            processed,
+            error,
+        } = run;
+        let duration = duration_into_millis(duration);
+        let processed = i64::try_from(processed).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            INSERT INTO task_runs (\
+                task, started_at, duration_ms, processed, error\
+            ) \
+            VALUES ($1::TEXT, $2::TIMESTAMPTZ, $3::INT8, $4::INT8, $5::TEXT) \
+            ON CONFLICT (task, started_at) DO NOTHING";
+        self.exec(SQL, &[&task, &started_at, &duration, &processed, &error])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Delete<By<task::Run, DateTime>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = u64;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<task::Run, DateTime>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let deadline = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM task_runs \
+            WHERE started_at < $1::TIMESTAMPTZ";
+        self.exec(SQL, &[&deadline])
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+impl<C> Database<Select<By<Vec<task::Run>, read::task::History>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<task::Run>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<task::Run>, read::task::History>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::task::History { task, limit } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            SELECT task, started_at, duration_ms, processed, error \
+            FROM task_runs \
+            WHERE $1::TEXT IS NULL OR task = $1::TEXT \
+            ORDER BY started_at DESC \
+            LIMIT $2::INT8";
+        Ok(self
+            .query(SQL, &[&task, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| task::Run {
+                task: row.get("task"),
+                started_at: row.get("started_at"),
+                duration: duration_from_millis(row.get("duration_ms")),
+                processed: u64::try_from(row.get::<_, i64>("processed"))
+                    .unwrap_or_default(),
+                error: row.get("error"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<task::DeadLetter>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(letter): Insert<task::DeadLetter>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let task::DeadLetter {
+            task,
+            item,
+            error,
+            attempts,
+            failed_at,
+        } = letter;
+        let attempts = i32::try_from(attempts).unwrap_or(i32::MAX);
+
+        // Repeated failures of the same item accumulate their attempts.
+        const SQL: &str = "\
+            INSERT INTO task_dead_letters (\
+                task, item, error, attempts, failed_at\
+            ) \
+            VALUES ($1::TEXT, $2::TEXT, $3::TEXT, $4::INT4, $5::TIMESTAMPTZ) \
+            ON CONFLICT (task, item) DO UPDATE \
+            SET error = EXCLUDED.error, \
+                attempts = task_dead_letters.attempts + EXCLUDED.attempts, \
+                failed_at = EXCLUDED.failed_at";
+        self.exec(SQL, &[&task, &item, &error, &attempts, &failed_at])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)
+    }
+}
+
+impl<C> Database<Select<By<Vec<task::DeadLetter>, read::task::DeadLetters>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<task::DeadLetter>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<task::DeadLetter>, read::task::DeadLetters>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::task::DeadLetters { task, limit } = by.into_inner();
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+
+        const SQL: &str = "\
+            SELECT task, item, error, attempts, failed_at \
+            FROM task_dead_letters \
+            WHERE $1::TEXT IS NULL OR task = $1::TEXT \
+            ORDER BY failed_at DESC \
+            LIMIT $2::INT8";
+        Ok(self
+            .query(SQL, &[&task, &limit])
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| task::DeadLetter {
+                task: row.get("task"),
+                item: row.get("item"),
+                error: row.get("error"),
+                attempts: u32::try_from(row.get::<_, i32>("attempts"))
+                    .unwrap_or_default(),
+                failed_at: row.get("failed_at"),
+            })
+            .collect())
+    }
+}
+
+impl<C> Database<Delete<By<task::DeadLetter, (String, Option<String>)>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = u64;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Delete(by): Delete<By<task::DeadLetter, (String, Option<String>)>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (task, item) = by.into_inner();
+
+        const SQL: &str = "\
+            DELETE FROM task_dead_letters \
+            WHERE task = $1::TEXT \
+              AND ($2::TEXT IS NULL OR item = $2::TEXT)";
+        self.exec(SQL, &[&task, &item])
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+/// Converts the provided `INT8` milliseconds into a [`Duration`].
+pub(super) fn duration_from_millis(millis: i64) -> Duration {
+    Duration::from_millis(u64::try_from(millis).unwrap_or_default())
+}
+
+/// Converts the provided [`Duration`] into `INT8` milliseconds.
+pub(super) fn duration_into_millis(duration: Duration) -> i64 {
+    i64::try_from(duration.as_millis()).unwrap_or(i64::MAX)
+}
@@ -0,0 +1,132 @@
+//! [`contract::Template`]-related [`Database`] implementations.
+
+use common::operations::{By, Insert, Select};
+use tokio_postgres::Row;
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, contract, contract::template},
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+};
+
+impl<C> Database<Select<By<Option<contract::Template>, template::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<contract::Template>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<contract::Template>, template::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: template::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, name, description, creator_id, created_at \
+            FROM contract_templates \
+            WHERE id = $1::UUID \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| template_from_row(&row)))
+    }
+}
+
+impl<C> Database<Select<By<Vec<contract::Template>, agency::Id>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<contract::Template>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<contract::Template>, agency::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let agency_id: agency::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, agency_id, name, description, creator_id, created_at \
+            FROM contract_templates \
+            WHERE agency_id = $1::UUID \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&agency_id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(template_from_row)
+            .collect())
+    }
+}
+
+impl<C> Database<Insert<contract::Template>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = ();
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Insert(template): Insert<contract::Template>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let contract::Template {
+            id,
+            agency_id,
+            name,
+            description,
+            creator_id,
+            created_at,
+        } = template;
+
+        const SQL: &str = "\
+            INSERT INTO contract_templates (\
+                id, agency_id, name, description, creator_id, created_at\
+            ) VALUES (\
+                $1::UUID, $2::UUID, $3::VARCHAR, $4::VARCHAR, \
+                $5::UUID, $6::TIMESTAMPTZ\
+            ) \
+            ON CONFLICT (id) DO UPDATE \
+            SET name = EXCLUDED.name, \
+                description = EXCLUDED.description, \
+                creator_id = EXCLUDED.creator_id";
+        self.exec(
+            SQL,
+            &[
+                &id,
+                &agency_id,
+                &name,
+                &description,
+                &creator_id,
+                &created_at,
+            ],
+        )
+        .await
+        .map_err(tracerr::wrap!())
+        .map(drop)
+    }
+}
+
+/// Maps the provided [`Row`] of the `contract_templates` table into a
+/// [`contract::Template`].
+fn template_from_row(row: &Row) -> contract::Template {
+    contract::Template {
+        id: row.get("id"),
+        agency_id: row.get("agency_id"),
+        name: row.get("name"),
+        description: row.get("description"),
+        creator_id: row.get("creator_id"),
+        created_at: row.get("created_at"),
+    }
+}
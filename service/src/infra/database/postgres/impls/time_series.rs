@@ -0,0 +1,94 @@
+//! [`read::TimeSeries`]-related [`Database`] implementations.
+
+use common::operations::{By, Select};
+use tracerr::Traced;
+
+use crate::{
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read::{self, time_series::Point},
+};
+
+impl<C> Database<Select<By<read::TimeSeries, read::time_series::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::TimeSeries;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<read::TimeSeries, read::time_series::Selector>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::time_series::Selector {
+            agency_id,
+            granularity,
+            start,
+            end,
+            time_zone,
+        } = by.into_inner();
+
+        // All the events are bucketed at once to avoid multiple round trips.
+        // A contract is considered signed once its last party has signed it.
+        const SQL: &str = "\
+            WITH events AS (\
+                SELECT placed_at AS at, \
+                       1 AS placed, 0 AS signed, 0 AS terminated \
+                FROM contracts \
+                WHERE agency_id = $1::UUID \
+                  AND placed_at >= $3::TIMESTAMPTZ \
+                  AND placed_at <= $4::TIMESTAMPTZ \
+              UNION ALL \
+                SELECT MAX(p.signed_at) AS at, \
+                       0 AS placed, 1 AS signed, 0 AS terminated \
+                FROM contract_parties AS p \
+                INNER JOIN contracts AS c ON c.id = p.contract_id \
+                WHERE c.agency_id = $1::UUID \
+                GROUP BY p.contract_id \
+                HAVING BOOL_AND(p.signed_at IS NOT NULL) \
+                   AND MAX(p.signed_at) >= $3::TIMESTAMPTZ \
+                   AND MAX(p.signed_at) <= $4::TIMESTAMPTZ \
+              UNION ALL \
+                SELECT terminated_at AS at, \
+                       0 AS placed, 0 AS signed, 1 AS terminated \
+                FROM contracts \
+                WHERE agency_id = $1::UUID \
+                  AND terminated_at >= $3::TIMESTAMPTZ \
+                  AND terminated_at <= $4::TIMESTAMPTZ\
+            ) \
+            SELECT date_trunc(\
+                       $2::TEXT, at AT TIME ZONE $5::TEXT\
+                   ) AT TIME ZONE $5::TEXT AS at, \
+                   SUM(placed)::INT4 AS placements, \
+                   SUM(signed)::INT4 AS signed_contracts, \
+                   SUM(terminated)::INT4 AS terminations \
+            FROM events \
+            GROUP BY 1 \
+            ORDER BY 1";
+        Ok(read::TimeSeries(
+            self.query(
+                SQL,
+                &[
+                    &agency_id,
+                    &granularity.as_sql(),
+                    &start,
+                    &end,
+                    &time_zone.name(),
+                ],
+            )
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|row| Point {
+                at: row.get("at"),
+                placements: row.get("placements"),
+                signed_contracts: row.get("signed_contracts"),
+                terminations: row.get("terminations"),
+            })
+            .collect(),
+        ))
+    }
+}
@@ -0,0 +1,160 @@
+//! Trash-related [`Database`] implementations.
+
+use common::operations::{By, Select};
+use itertools::Itertools as _;
+use postgres_types::ToSql;
+use tracerr::Traced;
+
+use crate::{
+    domain::audit,
+    infra::{
+        database::{self, postgres::Connection, Postgres},
+        Database,
+    },
+    read,
+};
+
+impl<C>
+    Database<Select<By<read::trash::list::Page, read::trash::list::Selector>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::trash::list::Page;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<read::trash::list::Page, read::trash::list::Selector>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::trash::list::Selector {
+            arguments,
+            filter:
+                read::trash::list::Filter {
+                    agency_id,
+                    include_users,
+                    entity,
+                },
+        } = by.into_inner();
+
+        let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
+
+        let (user, realty, contract) = (
+            audit::Entity::User,
+            audit::Entity::Realty,
+            audit::Entity::Contract,
+        );
+        let archivation = audit::Action::ArchiveContract;
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> =
+            vec![&agency_id, &limit, &realty, &contract, &archivation];
+
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
+            ps.len()
+        });
+
+        let users_idx = include_users.then(|| {
+            ps.push(&user);
+            ps.len()
+        });
+
+        let entity_idx = entity.as_ref().map(|e| {
+            ps.push(e);
+            ps.len()
+        });
+
+        // Only archivation of `Contract`s is performed by `User`s, while
+        // `Realty`s are deleted by the system, and `User`s deletion is not
+        // recorded into the audit log.
+        let sql = format!(
+            "SELECT t.entity, t.id, t.deleted_at, \
+                    a.actor_id AS deleted_by \
+             FROM (SELECT $3::INT2 AS entity, id, deleted_at, \
+                          NULL::INT2 AS deletion \
+                   FROM realties \
+                   WHERE agency_id = $1::UUID \
+                     AND deleted_at IS NOT NULL \
+                   UNION ALL \
+                   SELECT $4::INT2 AS entity, id, \
+                          archived_at AS deleted_at, \
+                          $5::INT2 AS deletion \
+                   FROM contracts \
+                   WHERE agency_id = $1::UUID \
+                     AND archived_at IS NOT NULL \
+                   {users}\
+             ) AS t \
+             LEFT JOIN LATERAL (\
+                 SELECT actor_id \
+                 FROM audit_entries \
+                 WHERE agency_id = $1::UUID \
+                   AND entity = t.entity \
+                   AND entity_id = t.id \
+                   AND action = t.deletion \
+                 ORDER BY created_at DESC \
+                 LIMIT 1\
+             ) AS a ON true \
+             WHERE true \
+                   {entity_filtering} \
+                   {cursor} \
+             ORDER BY t.deleted_at {order}, t.id {order} \
+             LIMIT $2::INT4 \
+             {offset}",
+            users = users_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!(
+                    "UNION ALL \
+                     SELECT ${idx}::INT2 AS entity, id, deleted_at, \
+                            NULL::INT2 AS deletion \
+                     FROM users \
+                     WHERE deleted_at IS NOT NULL",
+                ))
+            }),
+            entity_filtering =
+                entity_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND t.entity = ${idx}::INT2"))
+                }),
+            cursor =
+                arguments.cursor().into_iter().format_with("", |cursor, f| {
+                    let op = arguments.kind().operator();
+
+                    ps.push(&cursor.deleted_at);
+                    let at_idx = ps.len();
+                    ps.push(&cursor.id);
+                    let id_idx = ps.len();
+
+                    f(&format_args!(
+                        "AND (t.deleted_at, t.id) {op} \
+                             (${at_idx}::TIMESTAMPTZ, ${id_idx}::UUID)",
+                    ))
+                }),
+            order = arguments.kind().order().sql(),
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+        );
+        let rows = self
+            .query(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let has_more = rows.len() > arguments.limit();
+        let edges = rows
+            .into_iter()
+            .take(arguments.limit())
+            .map(|row| {
+                let node = read::trash::list::Item {
+                    entity: row.get("entity"),
+                    id: row.get("id"),
+                    deleted_at: row.get("deleted_at"),
+                    deleted_by: row.get("deleted_by"),
+                };
+                (read::trash::list::Cursor::from(&node), node)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(read::trash::list::Page::new(&arguments, edges, has_more))
+    }
+}
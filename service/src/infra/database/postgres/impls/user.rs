@@ -5,10 +5,11 @@ use std::collections::HashMap;
 use common::operations::{By, Insert, Lock, Select, Update};
 use itertools::Itertools as _;
 use postgres_types::ToSql;
+use tokio_postgres::Row;
 use tracerr::Traced;
 
 use crate::{
-    domain::{user, User},
+    domain::{agency, contract, user, User},
     infra::{
         database::{
             self,
@@ -17,9 +18,17 @@ use crate::{
         },
         Database,
     },
-    read,
+    read::{
+        self,
+        user::{
+            list::{SortBy, SortKey},
+            Deleted,
+        },
+    },
 };
 
+use super::LockSpace;
+
 impl<C, IDs> Database<Select<By<HashMap<user::Id, User>, IDs>>> for Postgres<C>
 where
     C: Connection,
@@ -54,22 +63,7 @@ where
             .await
             .map_err(tracerr::wrap!())?
             .into_iter()
-            .map(|row| {
-                let id = row.get("id");
-                (
-                    id,
-                    User {
-                        id,
-                        name: row.get("name"),
-                        login: row.get("login"),
-                        password_hash: row.get("password_hash"),
-                        email: row.get("email"),
-                        phone: row.get("phone"),
-                        created_at: row.get("created_at"),
-                        deleted_at: row.get("deleted_at"),
-                    },
-                )
-            })
+            .map(|row| (row.get("id"), user_from_row(&row)))
             .collect())
     }
 }
@@ -99,6 +93,37 @@ where
     }
 }
 
+impl<C> Database<Select<By<Option<Deleted<User>>, user::Id>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Option<Deleted<User>>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<Deleted<User>>, user::Id>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        // Avoid subtle change for SQL.
+        let id: user::Id = by.into_inner();
+
+        const SQL: &str = "\
+            SELECT id, name, \
+                   login, password_hash, \
+                   email, phone, \
+                   created_at, deleted_at \
+            FROM users \
+            WHERE id = $1::UUID \
+              AND deleted_at IS NOT NULL \
+            LIMIT 1";
+        Ok(self
+            .query_opt(SQL, &[&id])
+            .await
+            .map_err(tracerr::wrap!())?
+            .map(|row| Deleted(user_from_row(&row))))
+    }
+}
+
 impl<C> Database<Insert<User>> for Postgres<C>
 where
     C: Connection,
@@ -192,14 +217,9 @@ where
         // Avoid subtle change for SQL.
         let id: user::Id = by.into_inner();
 
-        const SQL: &str = "\
-            INSERT INTO users_lock \
-            VALUES ($1::UUID) \
-            ON CONFLICT (id) DO NOTHING";
-        self.query(SQL, &[&id])
+        self.advisory_lock(LockSpace::User, &id)
             .await
             .map_err(tracerr::wrap!())
-            .map(drop)
     }
 }
 
@@ -242,6 +262,44 @@ where
     }
 }
 
+impl<'l, C> Database<Select<By<Option<User>, &'l user::Email>>> for Postgres<C>
+where
+    C: Connection,
+    Self: Database<
+        Select<By<Option<User>, user::Id>>,
+        Ok = Option<User>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Option<User>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Option<User>, &'l user::Email>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let email = by.into_inner();
+
+        // `Email`s are not unique, so an ambiguous one identifies nobody.
+        const SQL: &str = "\
+            SELECT id \
+            FROM users \
+            WHERE lower(email) = lower($1::VARCHAR) \
+              AND deleted_at IS NULL \
+            LIMIT 2";
+        let rows =
+            self.query(SQL, &[&email]).await.map_err(tracerr::wrap!())?;
+        let [row] = rows.as_slice() else {
+            return Ok(None);
+        };
+
+        let user_id = row.get("id");
+        self.execute(Select(By::new(user_id)))
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
 impl<C> Database<Select<By<read::user::list::Page, read::user::list::Selector>>>
     for Postgres<C>
 where
@@ -258,19 +316,22 @@ where
     ) -> Result<Self::Ok, Self::Err> {
         let read::user::list::Selector {
             arguments,
-            filter: read::user::list::Filter { name },
+            filter:
+                read::user::list::Filter {
+                    agency_id,
+                    branch_id,
+                    name,
+                    sort_by,
+                },
         } = by.into_inner();
 
         let limit = i32::try_from(arguments.limit()).unwrap() + 1;
+        let offset = i64::try_from(arguments.offset()).unwrap();
 
         let mut ps: Vec<&(dyn ToSql + Sync)> = vec![&limit];
 
-        let cursor_idx = arguments.cursor().map(|c| {
-            ps.push(c);
-            ps.len()
-        });
-        let name_idx = name.as_ref().map(|n| {
-            ps.push(n);
+        let offset_idx = (offset > 0).then(|| {
+            ps.push(&offset);
             ps.len()
         });
 
@@ -280,32 +341,113 @@ where
             ps.len()
         });
 
+        let agency_id_idx = agency_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.push(&contract::Kind::Employment);
+            ps.len() - 1
+        });
+
+        let branch_id_idx = branch_id.as_ref().map(|id| {
+            ps.push(id);
+            ps.len()
+        });
+
+        let relevance = name_pattern_idx.map_or_else(
+            || "0".to_owned(),
+            |idx| format!("${idx}::VARCHAR <<-> name"),
+        );
+        let sort_key = match sort_by {
+            SortBy::Relevance => name_pattern_idx.map(|_| relevance.as_str()),
+            SortBy::Name => Some("name"),
+            SortBy::CreatedAt => Some("created_at"),
+        };
+
+        // Cursor of another `SortBy` order can only be positioned by ID.
+        let cursor = arguments.cursor().map(|c| {
+            let key_idx = sort_key
+                .filter(|_| c.key.sort_by() == sort_by)
+                .map(|_| {
+                    let (key, ty): (&(dyn ToSql + Sync), _) = match &c.key {
+                        SortKey::Relevance(r) => (r, "FLOAT4"),
+                        SortKey::Name(n) => (n, "VARCHAR"),
+                        SortKey::CreatedAt(at) => (at, "TIMESTAMPTZ"),
+                    };
+                    ps.push(key);
+                    (ps.len(), ty)
+                });
+            ps.push(&c.id);
+            (key_idx, ps.len())
+        });
+
         let sql = format!(
-            "SELECT id \
+            "SELECT id, name, \
+                    login, password_hash, \
+                    email, phone, \
+                    created_at, deleted_at, \
+                    {relevance}::FLOAT4 AS relevance \
              FROM users \
              WHERE deleted_at IS NULL \
+                   {agency_filtering} \
+                   {branch_filtering} \
                    {cursor} \
                    {name_filtering} \
-             ORDER BY {name_ordering} \
+             ORDER BY {sort_ordering} \
                       id {order} \
-             LIMIT $1::INT4",
-            cursor = cursor_idx.into_iter().format_with("", |idx, f| {
+             LIMIT $1::INT4 \
+             {offset}",
+            cursor = cursor.into_iter().format_with("", |(key, idx), f| {
                 let op = arguments.kind().operator();
-                f(&format_args!("AND id {op} ${idx}::UUID"))
+                match (key, sort_key) {
+                    (Some((key_idx, ty)), Some(sort_key)) => f(&format_args!(
+                        "AND ({sort_key}, id) {op} \
+                             (${key_idx}::{ty}, ${idx}::UUID)"
+                    )),
+                    _ => f(&format_args!("AND id {op} ${idx}::UUID")),
+                }
             }),
             order = arguments.kind().order().sql(),
-            name_filtering =
-                name_pattern_idx.into_iter().format_with("", |idx, f| {
+            offset = offset_idx.into_iter().format_with("", |idx, f| {
+                f(&format_args!("OFFSET ${idx}::INT8"))
+            }),
+            agency_filtering =
+                agency_id_idx.into_iter().format_with("", |idx, f| {
                     f(&format_args!(
-                        "AND LOWER(name) SIMILAR TO LOWER(${idx}::VARCHAR)"
+                        "AND (id IN (\
+                             SELECT employer_id \
+                             FROM contracts \
+                             WHERE kind = ${kind_idx}::INT2 \
+                               AND agency_id = ${idx}::UUID \
+                               AND terminated_at IS NULL \
+                               AND (expires_at IS NULL OR expires_at > NOW())\
+                         ) OR id IN (\
+                             SELECT user_id \
+                             FROM user_invites \
+                             WHERE agency_id = ${idx}::UUID \
+                               AND expires_at > NOW()\
+                         ))",
+                        kind_idx = idx + 1,
                     ))
                 }),
-            name_ordering = name_idx.into_iter().format_with("", |idx, f| {
+            branch_filtering =
+                branch_id_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!(
+                        "AND id IN (\
+                             SELECT employer_id \
+                             FROM contracts \
+                             WHERE branch_id = ${idx}::UUID \
+                               AND terminated_at IS NULL \
+                               AND (expires_at IS NULL OR expires_at > NOW())\
+                         )"
+                    ))
+                }),
+            name_filtering =
+                name_pattern_idx.into_iter().format_with("", |idx, f| {
+                    f(&format_args!("AND ${idx}::VARCHAR <% name"))
+                }),
+            sort_ordering = sort_key.into_iter().format_with("", |key, f| {
                 let order = arguments.kind().order().sql();
-                f(&format_args!(
-                    "LEVENSHTEIN(name, ${idx}::VARCHAR, 1, 1, 0) {order},"
-                ))
-            })
+                f(&format_args!("{key} {order},"))
+            }),
         );
         let rows = self
             .query(&sql, ps.as_slice())
@@ -317,8 +459,13 @@ where
             .into_iter()
             .take(arguments.limit())
             .map(|row| {
-                let id = row.get("id");
-                (id, id)
+                let node = user_from_row(&row);
+                let cursor = read::user::list::Cursor::new(
+                    sort_by,
+                    &node,
+                    row.get("relevance"),
+                );
+                (cursor, node)
             })
             .collect::<Vec<_>>();
 
@@ -326,7 +473,10 @@ where
     }
 }
 
-impl<C> Database<Select<By<read::user::list::TotalCount, ()>>> for Postgres<C>
+impl<C>
+    Database<
+        Select<By<read::user::list::TotalCount, read::user::list::Filter>>,
+    > for Postgres<C>
 where
     C: Connection,
 {
@@ -335,15 +485,228 @@ where
 
     async fn execute(
         &self,
-        Select(_): Select<By<read::user::list::TotalCount, ()>>,
+        Select(by): Select<
+            By<read::user::list::TotalCount, read::user::list::Filter>,
+        >,
     ) -> Result<Self::Ok, Self::Err> {
-        const SQL: &str = "\
-            SELECT COUNT(*)::INT4 \
-            FROM users \
-            WHERE deleted_at IS NULL";
-        self.query_opt(SQL, &[])
+        let filter = by.into_inner();
+        let name_pattern =
+            filter.name.as_ref().map(|n| FuzzPattern::new(n.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT COUNT(*)::INT4 \
+             FROM users \
+             WHERE deleted_at IS NULL {}",
+            list_filtering(&filter, name_pattern.as_ref(), &mut ps),
+        );
+        self.query_opt(&sql, ps.as_slice())
             .await
             .map_err(tracerr::wrap!())
             .map(|row| row.expect("always exists").get::<_, i32>(0).into())
     }
 }
+
+impl<C>
+    Database<
+        Select<
+            By<
+                read::Estimated<read::user::list::TotalCount>,
+                read::user::list::Filter,
+            >,
+        >,
+    > for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = read::Estimated<read::user::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<
+            By<
+                read::Estimated<read::user::list::TotalCount>,
+                read::user::list::Filter,
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let filter = by.into_inner();
+        let name_pattern =
+            filter.name.as_ref().map(|n| FuzzPattern::new(n.as_ref()));
+
+        let mut ps: Vec<&(dyn ToSql + Sync)> = vec![];
+        let sql = format!(
+            "SELECT 1 \
+             FROM users \
+             WHERE deleted_at IS NULL {}",
+            list_filtering(&filter, name_pattern.as_ref(), &mut ps),
+        );
+        self.estimate_rows(&sql, ps.as_slice())
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|count| read::Estimated(count.into()))
+    }
+}
+
+impl<C>
+    Database<Select<By<HashMap<agency::Id, read::user::list::TotalCount>, ()>>>
+    for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = HashMap<agency::Id, read::user::list::TotalCount>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        _: Select<By<HashMap<agency::Id, read::user::list::TotalCount>, ()>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        const SQL: &str = "\
+            SELECT agency_id, COUNT(DISTINCT id)::INT4 \
+            FROM (\
+                SELECT c.agency_id, c.employer_id AS id \
+                FROM contracts c \
+                WHERE c.kind = $1::INT2 \
+                  AND c.terminated_at IS NULL \
+                  AND (c.expires_at IS NULL OR c.expires_at > NOW()) \
+                UNION ALL \
+                SELECT i.agency_id, i.user_id AS id \
+                FROM user_invites i \
+                WHERE i.expires_at > NOW()\
+            ) AS agency_users \
+            WHERE id IN (SELECT id FROM users WHERE deleted_at IS NULL) \
+            GROUP BY agency_id";
+        self.query(SQL, &[&contract::Kind::Employment])
+            .await
+            .map_err(tracerr::wrap!())
+            .map(|rows| {
+                rows.iter()
+                    .map(|row| (row.get(0), row.get::<_, i32>(1).into()))
+                    .collect()
+            })
+    }
+}
+
+impl<C> Database<Select<By<Vec<User>, read::user::Export>>> for Postgres<C>
+where
+    C: Connection,
+{
+    type Ok = Vec<User>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Select(by): Select<By<Vec<User>, read::user::Export>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let read::user::Export {
+            agency_id,
+            branch_id,
+        } = by.into_inner();
+
+        // Invited `User`s are not assigned to any `Branch` yet.
+        const SQL: &str = "\
+            SELECT id, name, \
+                   login, password_hash, \
+                   email, phone, \
+                   created_at, deleted_at \
+            FROM users \
+            WHERE deleted_at IS NULL \
+              AND (id IN (\
+                  SELECT employer_id \
+                  FROM contracts \
+                  WHERE kind = $3::INT2 \
+                    AND agency_id = $1::UUID \
+                    AND ($2::UUID IS NULL OR branch_id = $2::UUID) \
+                    AND terminated_at IS NULL \
+                    AND (expires_at IS NULL OR expires_at > NOW())\
+              ) OR $2::UUID IS NULL AND id IN (\
+                  SELECT user_id \
+                  FROM user_invites \
+                  WHERE agency_id = $1::UUID \
+                    AND expires_at > NOW()\
+              )) \
+            ORDER BY created_at, id";
+        Ok(self
+            .query(SQL, &[&agency_id, &branch_id, &contract::Kind::Employment])
+            .await
+            .map_err(tracerr::wrap!())?
+            .iter()
+            .map(user_from_row)
+            .collect())
+    }
+}
+
+/// Maps the provided [`Row`] of the `users` table into a [`User`].
+fn user_from_row(row: &Row) -> User {
+    User {
+        id: row.get("id"),
+        name: row.get("name"),
+        login: row.get("login"),
+        password_hash: row.get("password_hash"),
+        email: row.get("email"),
+        phone: row.get("phone"),
+        created_at: row.get("created_at"),
+        deleted_at: row.get("deleted_at"),
+    }
+}
+
+/// Returns the SQL conditions selecting the [`User`]s matching the provided
+/// [`read::user::list::Filter`], pushing their parameters into the provided
+/// `ps`.
+///
+/// The `name_pattern` is expected to be built out of the
+/// [`read::user::list::Filter::name`].
+fn list_filtering<'a>(
+    filter: &'a read::user::list::Filter,
+    name_pattern: Option<&'a FuzzPattern>,
+    ps: &mut Vec<&'a (dyn ToSql + Sync)>,
+) -> String {
+    let read::user::list::Filter {
+        agency_id,
+        branch_id,
+        name: _,
+        sort_by: _,
+    } = filter;
+
+    let mut conditions = vec![];
+    if let Some(id) = agency_id {
+        ps.push(id);
+        ps.push(&contract::Kind::Employment);
+        conditions.push(format!(
+            "AND (id IN (\
+                 SELECT employer_id \
+                 FROM contracts \
+                 WHERE kind = ${kind_idx}::INT2 \
+                   AND agency_id = ${idx}::UUID \
+                   AND terminated_at IS NULL \
+                   AND (expires_at IS NULL OR expires_at > NOW())\
+             ) OR id IN (\
+                 SELECT user_id \
+                 FROM user_invites \
+                 WHERE agency_id = ${idx}::UUID \
+                   AND expires_at > NOW()\
+             ))",
+            idx = ps.len() - 1,
+            kind_idx = ps.len(),
+        ));
+    }
+    if let Some(id) = branch_id {
+        ps.push(id);
+        conditions.push(format!(
+            "AND id IN (\
+                 SELECT employer_id \
+                 FROM contracts \
+                 WHERE branch_id = ${}::UUID \
+                   AND terminated_at IS NULL \
+                   AND (expires_at IS NULL OR expires_at > NOW())\
+             )",
+            ps.len(),
+        ));
+    }
+    if let Some(pattern) = name_pattern {
+        ps.push(pattern);
+        conditions.push(format!("AND ${}::VARCHAR <% name", ps.len()));
+    }
+    conditions.join(" ")
+}
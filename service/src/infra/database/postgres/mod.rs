@@ -4,8 +4,12 @@ pub mod client;
 pub mod connection;
 mod fuzz_pattern;
 mod impls;
+pub mod retry;
+pub mod tls;
 
-use deadpool_postgres::Runtime;
+use std::time::Duration;
+
+use deadpool_postgres::{Runtime, SslMode};
 use derive_more::{Deref, Display, Error as StdError, From};
 use tokio_postgres::{error::SqlState, NoTls};
 use tracerr::Traced;
@@ -18,7 +22,7 @@ pub use refinery::embed_migrations;
 
 pub use self::{
     client::{NonTx, Tx},
-    connection::Connection,
+    connection::{Connection, StatementCacheStats},
     fuzz_pattern::FuzzPattern,
 };
 
@@ -29,17 +33,58 @@ pub use deadpool_postgres::Config;
 pub struct Postgres<T = NonTx>(T);
 
 impl Postgres {
-    /// Creates a new [`Postgres`] client with the provided [`Config`].
+    /// Creates a new [`Postgres`] client with the provided [`Config`], using
+    /// the provided [`tls::Config`] for its connections.
+    ///
+    /// Awaits the database to become available according to the
+    /// [`retry::Config::startup`] policy.
     ///
     /// # Errors
     ///
-    /// If failed to create a new [`Postgres`] client.
-    pub fn new(conf: &Config) -> Result<Self, Traced<database::Error>> {
-        let pool = conf
-            .create_pool(Some(Runtime::Tokio1), NoTls)
-            .map_err(tracerr::from_and_wrap!(=> Error))
+    /// If failed to create a new [`Postgres`] client, or the database is not
+    /// available.
+    pub async fn new(
+        conf: &Config,
+        tls: &tls::Config,
+        retry: retry::Config,
+    ) -> Result<Self, Traced<database::Error>> {
+        let connector = tls
+            .connector()
+            .map_err(tracerr::map_from_and_wrap!(=> Error))
             .map_err(tracerr::map_from)?;
-        Ok(Self(NonTx::from_pool(pool)))
+
+        let mut conf = conf.clone();
+        conf.ssl_mode = Some(if connector.is_some() {
+            SslMode::Require
+        } else {
+            SslMode::Disable
+        });
+
+        let rt = Some(Runtime::Tokio1);
+        let pool = if let Some(connector) = connector {
+            conf.create_pool(rt, connector)
+        } else {
+            conf.create_pool(rt, NoTls)
+        }
+        .map_err(tracerr::from_and_wrap!(=> Error))
+        .map_err(tracerr::map_from)?;
+
+        drop(
+            connection::checkout(&pool, retry.startup, None)
+                .await
+                .map_err(tracerr::wrap!())?,
+        );
+
+        Ok(Self(NonTx::from_pool(pool, retry.checkout)))
+    }
+
+    /// Creates a new [`Postgres`] client sharing the [`connection::Pool`] of
+    /// this one, which statements are limited by the provided `timeout`.
+    ///
+    /// Statements executing longer are canceled by the database.
+    #[must_use]
+    pub fn with_statement_timeout(&self, timeout: Duration) -> Self {
+        Self(self.0.with_statement_timeout(timeout))
     }
 }
 
@@ -57,6 +102,17 @@ pub enum Error {
     /// [`connection::Pool`] error.
     #[display("`connection::Pool` error: {_0}")]
     PoolError(connection::PoolError),
+
+    /// [TLS] configuration error.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    #[display("TLS error: {_0}")]
+    Tls(tls::Error),
+
+    /// Updated entity was concurrently modified since it has been read.
+    #[display("Entity was concurrently modified")]
+    #[from(ignore)]
+    VersionConflict,
 }
 
 impl Error {
@@ -70,7 +126,42 @@ impl Error {
                         e.as_db_error().and_then(|e| e.constraint()) == Some(c)
                     })
             }
-            Self::PoolError(..) | Self::PoolCreationError(..) => false,
+            Self::PoolError(..)
+            | Self::PoolCreationError(..)
+            | Self::Tls(..)
+            | Self::VersionConflict => false,
         }
     }
+
+    /// Checks if the error is caused by renting an already rented [`Realty`],
+    /// as the `idx_contracts_realty_id_open_rent` unique index guards.
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    #[must_use]
+    pub fn is_realty_rented(&self) -> bool {
+        self.is_unique_violation(Some("idx_contracts_realty_id_open_rent"))
+    }
+
+    /// Checks if the error is caused by a timeout: either a query being
+    /// canceled (due to `statement_timeout`), or waiting for a free
+    /// [`connection::Pool`] slot for too long.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            Self::Connection(e) => e.code() == Some(&SqlState::QUERY_CANCELED),
+            Self::PoolError(e) => {
+                matches!(e, connection::PoolError::Timeout(_))
+            }
+            Self::PoolCreationError(..)
+            | Self::Tls(..)
+            | Self::VersionConflict => false,
+        }
+    }
+
+    /// Checks if the error is caused by an updated entity being concurrently
+    /// modified since it has been read.
+    #[must_use]
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::VersionConflict)
+    }
 }
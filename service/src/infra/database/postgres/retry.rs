@@ -0,0 +1,78 @@
+//! [`Retry`] policies of [`Postgres`] operations.
+
+use std::{fmt, future::Future, time::Duration};
+
+use smart_default::SmartDefault;
+use tokio::time;
+use tracing as log;
+
+#[cfg(doc)]
+use super::{connection::Pool, Connection, Postgres};
+
+/// [`Retry`] policies of a [`Postgres`] client.
+#[derive(Clone, Copy, Debug, SmartDefault)]
+pub struct Config {
+    /// [`Retry`] policy of establishing the first [`Connection`] on
+    /// [`Postgres::new()`], awaiting the database to become available.
+    #[default(Retry {
+        attempts: 10,
+        initial_backoff: Duration::from_millis(500),
+        max_backoff: Duration::from_secs(10),
+    })]
+    pub startup: Retry,
+
+    /// [`Retry`] policy of checking out a [`Connection`] from the [`Pool`].
+    #[default(Retry {
+        attempts: 3,
+        initial_backoff: Duration::from_millis(50),
+        max_backoff: Duration::from_millis(500),
+    })]
+    pub checkout: Retry,
+}
+
+/// Policy of retrying transient failures with an exponential backoff.
+#[derive(Clone, Copy, Debug)]
+pub struct Retry {
+    /// Maximum number of attempts, including the first one.
+    pub attempts: u32,
+
+    /// Delay before the second attempt.
+    ///
+    /// Each next delay is twice as long, up to the [`Retry::max_backoff`].
+    pub initial_backoff: Duration,
+
+    /// Maximum delay between attempts.
+    pub max_backoff: Duration,
+}
+
+impl Retry {
+    /// Runs the provided `op`eration, retrying it while it fails with an error
+    /// considered `transient` and attempts are not exhausted.
+    pub(crate) async fn run<T, E, Fut>(
+        &self,
+        mut op: impl FnMut() -> Fut,
+        transient: impl Fn(&E) -> bool,
+    ) -> Result<T, E>
+    where
+        Fut: Future<Output = Result<T, E>>,
+        E: fmt::Display,
+    {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            match op().await {
+                Err(e) if attempt < self.attempts && transient(&e) => {
+                    log::warn!(
+                        "`Postgres` operation failed \
+                         (attempt {attempt}/{}), retrying in {backoff:?}: {e}",
+                        self.attempts,
+                    );
+                    time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.max_backoff);
+                    attempt += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! [TLS] support of [`Postgres`] connections.
+//!
+//! [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use derive_more::{Display, Error as StdError, From};
+use rustls::{
+    client::{
+        danger::{
+            HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier,
+        },
+        VerifierBuilderError, WebPkiServerVerifier,
+    },
+    crypto::ring,
+    pki_types::{CertificateDer, PrivateKeyDer, ServerName, UnixTime},
+    CertificateError, ClientConfig, DigitallySignedStruct, RootCertStore,
+    SignatureScheme,
+};
+use tokio_postgres_rustls::MakeRustlsConnect;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use super::Postgres;
+
+/// [TLS] configuration of [`Postgres`] connections.
+///
+/// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// [`Mode`] of [TLS] usage.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    pub mode: Mode,
+
+    /// Path to the PEM-encoded root certificates to verify the server with.
+    ///
+    /// If [`None`], the [Mozilla's root certificates][1] are used.
+    ///
+    /// [1]: https://wiki.mozilla.org/CA/Included_Certificates
+    pub root_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded client certificate chain to authenticate with.
+    ///
+    /// Requires [`Config::client_key`] to be set as well.
+    pub client_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded private key of the [`Config::client_cert`].
+    pub client_key: Option<PathBuf>,
+}
+
+/// Mode of [TLS] usage, mirroring the [`sslmode`] of `libpq`.
+///
+/// [`sslmode`]: https://postgresql.org/docs/current/libpq-ssl.html#LIBPQ-SSL-PROTECTION
+/// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// [TLS] is not used.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    #[default]
+    Disable,
+
+    /// [TLS] is required, but the server certificate is not verified.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    Require,
+
+    /// [TLS] is required and the server certificate is verified to be signed
+    /// by a trusted root, ignoring the server host name.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    VerifyCa,
+
+    /// [TLS] is required and the server certificate is verified to be signed
+    /// by a trusted root and to match the server host name.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    VerifyFull,
+}
+
+impl Config {
+    /// Creates a new [`MakeRustlsConnect`] out of this [`Config`].
+    ///
+    /// [`None`] is returned if [TLS] is [`Mode::Disable`]d.
+    ///
+    /// # Errors
+    ///
+    /// If failed to read the configured certificates or keys, or they are
+    /// invalid.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    pub fn connector(
+        &self,
+    ) -> Result<Option<MakeRustlsConnect>, Traced<Error>> {
+        if self.mode == Mode::Disable {
+            return Ok(None);
+        }
+
+        let provider = Arc::new(ring::default_provider());
+
+        let mut roots = RootCertStore::empty();
+        if let Some(path) = &self.root_cert {
+            for cert in read_certs(path).map_err(tracerr::wrap!())? {
+                roots
+                    .add(cert)
+                    .map_err(tracerr::from_and_wrap!(=> Error))?;
+            }
+        } else {
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        }
+
+        let builder =
+            ClientConfig::builder_with_provider(Arc::clone(&provider))
+                .with_safe_default_protocol_versions()
+                .map_err(tracerr::from_and_wrap!(=> Error))?;
+        let builder = if self.mode == Mode::VerifyFull {
+            builder.with_root_certificates(roots)
+        } else {
+            let inner = WebPkiServerVerifier::builder_with_provider(
+                Arc::new(roots),
+                provider,
+            )
+            .build()
+            .map_err(tracerr::from_and_wrap!(=> Error))?;
+            builder
+                .dangerous()
+                .with_custom_certificate_verifier(Arc::new(Verifier {
+                    inner,
+                    verify_chain: self.mode == Mode::VerifyCa,
+                }))
+        };
+
+        let config = match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => builder
+                .with_client_auth_cert(
+                    read_certs(cert).map_err(tracerr::wrap!())?,
+                    read_key(key).map_err(tracerr::wrap!())?,
+                )
+                .map_err(tracerr::from_and_wrap!(=> Error))?,
+            (None, None) => builder.with_no_client_auth(),
+            (Some(_), None) | (None, Some(_)) => {
+                return Err(tracerr::new!(Error::IncompleteClientAuth));
+            }
+        };
+
+        Ok(Some(MakeRustlsConnect::new(config)))
+    }
+}
+
+/// Reads PEM-encoded certificates from the file at the provided `path`.
+fn read_certs(
+    path: &Path,
+) -> Result<Vec<CertificateDer<'static>>, Traced<Error>> {
+    let file = File::open(path).map_err(tracerr::from_and_wrap!(=> Error))?;
+    rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<Result<_, _>>()
+        .map_err(tracerr::from_and_wrap!(=> Error))
+}
+
+/// Reads a PEM-encoded private key from the file at the provided `path`.
+fn read_key(path: &Path) -> Result<PrivateKeyDer<'static>, Traced<Error>> {
+    let file = File::open(path).map_err(tracerr::from_and_wrap!(=> Error))?;
+    rustls_pemfile::private_key(&mut BufReader::new(file))
+        .map_err(tracerr::from_and_wrap!(=> Error))?
+        .ok_or_else(|| tracerr::new!(Error::NoPrivateKey))
+}
+
+/// [`ServerCertVerifier`] relaxing the [`WebPkiServerVerifier`] checks
+/// according to the [`Mode`].
+#[derive(Debug)]
+struct Verifier {
+    /// [`WebPkiServerVerifier`] performing the actual checks.
+    inner: Arc<WebPkiServerVerifier>,
+
+    /// Indicator whether the server certificate chain should be verified
+    /// (ignoring the server host name).
+    verify_chain: bool,
+}
+
+impl ServerCertVerifier for Verifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        if !self.verify_chain {
+            return Ok(ServerCertVerified::assertion());
+        }
+        match self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName,
+            )) => Ok(ServerCertVerified::assertion()),
+            res => res,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// [TLS] configuration error.
+///
+/// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Only one of the client certificate and key is configured.
+    #[display("Both client certificate and key must be configured")]
+    IncompleteClientAuth,
+
+    /// Failed to read a certificate or a key file.
+    #[display("Failed to read certificate or key: {_0}")]
+    Io(io::Error),
+
+    /// Private key file contains no keys.
+    #[display("No private key found")]
+    NoPrivateKey,
+
+    /// Invalid [TLS] configuration.
+    ///
+    /// [TLS]: https://wikipedia.org/wiki/Transport_Layer_Security
+    #[display("Invalid TLS configuration: {_0}")]
+    Rustls(rustls::Error),
+
+    /// Failed to build the server certificate verifier.
+    #[display("Failed to build server certificate verifier: {_0}")]
+    Verifier(VerifierBuilderError),
+}
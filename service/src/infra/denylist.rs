@@ -0,0 +1,21 @@
+//! [`PasswordDenylist`]-related definitions.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+use crate::domain::user::Password;
+
+/// Denylist of the [`Password`]s known to be breached (e.g. the
+/// [Have I Been Pwned] database).
+///
+/// [Have I Been Pwned]: https://haveibeenpwned.com/Passwords
+#[async_trait]
+pub trait PasswordDenylist: fmt::Debug + Send + Sync {
+    /// Indicates whether the provided [`Password`] is denied.
+    ///
+    /// Should return `false` whenever the check itself fails (e.g. a remote
+    /// service is unavailable), so passwords are never rejected because of an
+    /// infrastructure issue.
+    async fn contains(&self, password: &Password) -> bool;
+}
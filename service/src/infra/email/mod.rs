@@ -0,0 +1,292 @@
+//! [`Mailer`]-related implementations.
+
+pub mod queue;
+#[cfg(feature = "ses")]
+pub mod ses;
+#[cfg(feature = "smtp")]
+pub mod smtp;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use common::DateTime;
+use derive_more::{Display, Error as StdError, From};
+use serde_json::json;
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, insurance, realty, user},
+    infra::template,
+};
+#[cfg(doc)]
+use crate::domain::{Contract, Insurance, Realty, SavedSearch, User};
+
+pub use self::queue::{Queue, Retry};
+#[cfg(feature = "ses")]
+pub use self::ses::Ses;
+#[cfg(feature = "smtp")]
+pub use self::smtp::Smtp;
+
+/// External email delivery service, sending [`Message`]s to [`User`]s.
+#[async_trait]
+pub trait Mailer: fmt::Debug + Send + Sync {
+    /// Sends the provided [`Message`].
+    ///
+    /// # Errors
+    ///
+    /// If this [`Mailer`] failed to accept the [`Message`] for delivery.
+    async fn send(&self, message: &Message) -> Result<(), Traced<Error>>;
+}
+
+/// Email message sent by a [`Mailer`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// [`user::Email`] address of the recipient.
+    pub to: user::Email,
+
+    /// Subject line of this [`Message`].
+    pub subject: String,
+
+    /// Plain text body of this [`Message`].
+    pub body: String,
+}
+
+impl Message {
+    /// Renders a new [`Message`] from the provided [`Template`] via the
+    /// provided [`template::Engine`] in the provided [`agency::Locale`],
+    /// addressed to the provided [`user::Email`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`Template`] fails to render.
+    pub fn render(
+        engine: &template::Engine,
+        locale: Option<agency::Locale>,
+        to: user::Email,
+        template: &Template,
+    ) -> Result<Self, Traced<template::Error>> {
+        let name = template.name();
+        let payload = template.payload();
+        Ok(Self {
+            to,
+            subject: engine.render(
+                &format!("email/{name}/subject"),
+                locale,
+                &payload,
+            )?,
+            body: engine.render(
+                &format!("email/{name}/body"),
+                locale,
+                &payload,
+            )?,
+        })
+    }
+}
+
+/// Template of a [`Message`] sent to a [`User`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Template {
+    /// [`Insurance`] of a [`Contract`] managed by the employer [`User`] is
+    /// expiring soon.
+    InsuranceExpiring {
+        /// [`insurance::Provider`] issued the [`Insurance`].
+        provider: insurance::Provider,
+
+        /// [`insurance::PolicyNumber`] of the [`Insurance`].
+        policy_number: insurance::PolicyNumber,
+
+        /// [`DateTime`] when the [`Insurance`] expires.
+        expires_at: DateTime,
+    },
+
+    /// New [`Realty`]s matching a [`SavedSearch`] of the [`User`] have been
+    /// placed.
+    SavedSearchMatched {
+        /// Total number of the matching [`Realty`]s.
+        count: u32,
+
+        /// [`realty::Address`]es of the first matching [`Realty`]s.
+        addresses: Vec<realty::Address>,
+    },
+
+    /// [`user::Password`] of the [`User`] has been changed.
+    PasswordChanged,
+
+    /// [`user::Email`] of the [`User`] has been changed, sent to the previous
+    /// address.
+    EmailChanged {
+        /// New [`user::Email`] address of the [`User`].
+        ///
+        /// [`None`] if the address has been removed.
+        address: Option<user::Email>,
+    },
+}
+
+impl Template {
+    /// Returns the name of this [`Template`] in the [`template::DEFINITIONS`]
+    /// (without the `email/` prefix and the `/subject` or `/body` suffix).
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::InsuranceExpiring { .. } => "insurance_expiring",
+            Self::SavedSearchMatched { .. } => "saved_search_matched",
+            Self::PasswordChanged => "password_changed",
+            Self::EmailChanged { .. } => "email_changed",
+        }
+    }
+
+    /// Returns the placeholders values of this [`Template`].
+    #[must_use]
+    pub fn payload(&self) -> serde_json::Value {
+        match self {
+            Self::InsuranceExpiring {
+                provider,
+                policy_number,
+                expires_at,
+            } => json!({
+                "provider": provider.to_string(),
+                "policy_number": policy_number.to_string(),
+                "expires_at": expires_at.to_rfc3339(),
+            }),
+            Self::SavedSearchMatched { count, addresses } => json!({
+                "count": count.to_string(),
+                "addresses": addresses
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            }),
+            Self::PasswordChanged => json!({}),
+            Self::EmailChanged { address } => json!({
+                "address": address.as_ref().map(ToString::to_string),
+            }),
+        }
+    }
+}
+
+/// [`Mailer`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// [`Message`] cannot be represented in the [`Mailer`] format.
+    #[display("invalid `Message`: {_0}")]
+    #[from(ignore)]
+    InvalidMessage(#[error(not(source))] String),
+
+    #[cfg(feature = "ses")]
+    /// [`Ses`] error.
+    Ses(ses::Error),
+
+    #[cfg(feature = "smtp")]
+    /// [`Smtp`] error.
+    Smtp(smtp::Error),
+}
+
+#[cfg(test)]
+mod spec {
+    use std::collections::HashSet;
+
+    use common::DateTime;
+
+    use crate::{
+        domain::{insurance, realty, user},
+        infra::template,
+    };
+
+    use super::{Message, Template};
+
+    fn email(address: &str) -> user::Email {
+        user::Email::new(address).unwrap()
+    }
+
+    fn templates() -> Vec<Template> {
+        vec![
+            Template::InsuranceExpiring {
+                provider: insurance::Provider::new("Acme Insurance").unwrap(),
+                policy_number: insurance::PolicyNumber::new("POL-123").unwrap(),
+                expires_at: DateTime::from_rfc3339("2024-06-30T00:00:00Z")
+                    .unwrap(),
+            },
+            Template::SavedSearchMatched {
+                count: 12,
+                addresses: vec![
+                    realty::Address::new("UK, London, Baker Street, 221B")
+                        .unwrap(),
+                ],
+            },
+            Template::PasswordChanged,
+            Template::EmailChanged { address: None },
+        ]
+    }
+
+    #[test]
+    fn renders_insurance_expiring() {
+        let msg = Message::render(
+            &template::Engine::default(),
+            None,
+            email("employer@example.com"),
+            &templates()[0],
+        )
+        .unwrap();
+
+        assert_eq!(msg.to, email("employer@example.com"));
+        assert_eq!(msg.subject, "Insurance policy POL-123 is expiring");
+        assert!(msg.body.contains("issued by Acme Insurance"));
+        assert!(msg.body.contains("2024-06-30T00:00:00Z"));
+    }
+
+    #[test]
+    fn renders_saved_search_matched() {
+        let msg = Message::render(
+            &template::Engine::default(),
+            None,
+            email("client@example.com"),
+            &templates()[1],
+        )
+        .unwrap();
+
+        assert_eq!(msg.subject, "12 new properties match your saved search");
+        assert!(msg.body.contains("UK, London, Baker Street, 221B"));
+    }
+
+    #[test]
+    fn renders_email_changed() {
+        let engine = template::Engine::default();
+        let render = |address| {
+            Message::render(
+                &engine,
+                None,
+                email("old@example.com"),
+                &Template::EmailChanged { address },
+            )
+            .unwrap()
+            .body
+        };
+
+        assert!(render(Some(email("new@example.com")))
+            .contains("changed to new@example.com"));
+        assert!(render(None).contains("removed from your account"));
+    }
+
+    #[test]
+    fn payloads_match_definitions() {
+        for t in templates() {
+            let payload = t.payload();
+            let keys = payload
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect::<HashSet<_>>();
+            for part in ["subject", "body"] {
+                let name = format!("email/{}/{part}", t.name());
+                let def = template::definition(&name).unwrap();
+                let defined = def
+                    .placeholders
+                    .iter()
+                    .map(|(p, _)| *p)
+                    .collect::<HashSet<_>>();
+                assert_eq!(keys, defined, "`{name}` placeholders mismatch");
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! [`Queue`] of the [`Message`]s to be sent by a [`Mailer`].
+
+use std::{convert::Infallible, sync::Arc, time::Duration};
+
+use smart_default::SmartDefault;
+use tokio::{
+    sync::{mpsc, Mutex},
+    time,
+};
+use tokio_util::sync::CancellationToken;
+use tracing as log;
+
+use super::{Mailer, Message};
+
+/// Maximum number of the [`Message`]s waiting in a [`Queue`].
+const CAPACITY: usize = 1024;
+
+/// Policy of retrying a [`Message`] failed to be sent, with an exponential
+/// backoff.
+#[derive(Clone, Copy, Debug, SmartDefault)]
+pub struct Retry {
+    /// Maximum number of attempts to send a [`Message`], after which it's
+    /// dropped.
+    #[default(5)]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry.
+    ///
+    /// Each next delay is twice as long, up to the [`Retry::max_backoff`].
+    #[default(Duration::from_secs(1))]
+    pub initial_backoff: Duration,
+
+    /// Maximum delay between retries.
+    #[default(Duration::from_mins(1))]
+    pub max_backoff: Duration,
+}
+
+/// Queue of the [`Message`]s to be sent by a [`Mailer`] in background, so
+/// the sending doesn't delay the operation requesting it.
+#[derive(Clone, Debug)]
+pub struct Queue {
+    /// Sender of the queued [`Message`]s.
+    tx: mpsc::Sender<Message>,
+}
+
+impl Queue {
+    /// Creates a new [`Queue`] sending its [`Message`]s via the provided
+    /// [`Mailer`], along with the [`Worker`] to be run in background.
+    #[must_use]
+    pub fn new(mailer: Arc<dyn Mailer>, retry: Retry) -> (Self, Worker) {
+        let (tx, rx) = mpsc::channel(CAPACITY);
+        let worker = Worker {
+            mailer,
+            retry,
+            rx: Arc::new(Mutex::new(rx)),
+        };
+        (Self { tx }, worker)
+    }
+
+    /// Enqueues the provided [`Message`] to be sent.
+    ///
+    /// The [`Message`] is dropped if this [`Queue`] is full or its [`Worker`]
+    /// is gone, since sending emails is a best-effort notification.
+    pub fn push(&self, message: Message) {
+        if let Err(e) = self.tx.try_send(message) {
+            log::warn!("Failed to enqueue email: {e}");
+        }
+    }
+}
+
+/// Worker sending the [`Message`]s of a [`Queue`].
+///
+/// Cloning a [`Worker`] shares its [`Queue`], so it may be restarted without
+/// losing the enqueued [`Message`]s.
+#[derive(Clone, Debug)]
+pub struct Worker {
+    /// [`Mailer`] to send [`Message`]s with.
+    mailer: Arc<dyn Mailer>,
+
+    /// [`Retry`] policy of the failed [`Message`]s.
+    retry: Retry,
+
+    /// Receiver of the queued [`Message`]s.
+    rx: Arc<Mutex<mpsc::Receiver<Message>>>,
+}
+
+impl Worker {
+    /// Sends the queued [`Message`]s one by one, until the provided
+    /// [`CancellationToken`] is cancelled or the [`Queue`] is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Never, since the [`Message`]s failed to be sent according to the
+    /// [`Retry`] policy are logged and dropped.
+    pub async fn run(
+        &self,
+        cancel: CancellationToken,
+    ) -> Result<(), Infallible> {
+        let mut rx = self.rx.lock().await;
+        loop {
+            let message = tokio::select! {
+                m = rx.recv() => m,
+                () = cancel.cancelled() => None,
+            };
+            let Some(message) = message else {
+                return Ok(());
+            };
+            self.send(&message, &cancel).await;
+        }
+    }
+
+    /// Sends the provided [`Message`], retrying it according to the [`Retry`]
+    /// policy.
+    async fn send(&self, message: &Message, cancel: &CancellationToken) {
+        let Retry {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+        } = self.retry;
+
+        let mut backoff = initial_backoff;
+        let mut attempt = 1;
+        loop {
+            let Err(e) = self.mailer.send(message).await else {
+                return;
+            };
+            if attempt >= max_attempts || cancel.is_cancelled() {
+                log::error!(
+                    "Failed to send email to `{}`, giving up after {attempt} \
+                     attempts: {e}",
+                    message.to,
+                );
+                return;
+            }
+            log::warn!(
+                "Failed to send email to `{}`, retrying in {backoff:?}: {e}",
+                message.to,
+            );
+
+            tokio::select! {
+                () = time::sleep(backoff) => {}
+                () = cancel.cancelled() => {}
+            }
+            backoff = (backoff * 2).min(max_backoff);
+            attempt += 1;
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! [AWS SES] [`Mailer`] implementation.
+//!
+//! [AWS SES]: https://aws.amazon.com/ses
+
+use async_trait::async_trait;
+use aws_config::BehaviorVersion;
+use aws_sdk_sesv2::{
+    error::BuildError,
+    types::{Body, Content, Destination, EmailContent, Message as Simple},
+    Client,
+};
+use derive_more::{Display, Error as StdError, From};
+use tracerr::Traced;
+
+use crate::infra::{
+    email::{self, Message},
+    Mailer,
+};
+
+/// Charset of the [`Message`]s sent via [AWS SES].
+///
+/// [AWS SES]: https://aws.amazon.com/ses
+const CHARSET: &str = "UTF-8";
+
+/// [AWS SES] [`Mailer`] client.
+///
+/// [AWS SES]: https://aws.amazon.com/ses
+#[derive(Debug)]
+pub struct Ses {
+    /// [AWS SES] API [`Client`].
+    ///
+    /// [AWS SES]: https://aws.amazon.com/ses
+    client: Client,
+
+    /// Verified address the [`Message`]s are sent from.
+    from: String,
+}
+
+impl Ses {
+    /// Creates a new [`Ses`] client sending [`Message`]s from the provided
+    /// verified `from` address.
+    ///
+    /// Region and credentials are resolved from the environment, as usual for
+    /// the AWS SDKs (`AWS_REGION`, `AWS_ACCESS_KEY_ID`, etc).
+    pub async fn new(from: impl Into<String>) -> Self {
+        let config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        Self {
+            client: Client::new(&config),
+            from: from.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for Ses {
+    async fn send(
+        &self,
+        message: &Message,
+    ) -> Result<(), Traced<email::Error>> {
+        let content = |data: &str| {
+            Content::builder()
+                .data(data)
+                .charset(CHARSET)
+                .build()
+                .map_err(|e| email::Error::InvalidMessage(e.to_string()))
+                .map_err(tracerr::wrap!())
+        };
+        let simple = Simple::builder()
+            .subject(content(&message.subject)?)
+            .body(Body::builder().text(content(&message.body)?).build())
+            .build()
+            .map_err(|e: BuildError| {
+                email::Error::InvalidMessage(e.to_string())
+            })
+            .map_err(tracerr::wrap!())?;
+
+        _ = self
+            .client
+            .send_email()
+            .from_email_address(&self.from)
+            .destination(
+                Destination::builder()
+                    .to_addresses(message.to.to_string())
+                    .build(),
+            )
+            .content(EmailContent::builder().simple(simple).build())
+            .send()
+            .await
+            .map_err(aws_sdk_sesv2::Error::from)
+            .map_err(Error::Api)
+            .map_err(tracerr::from_and_wrap!(=> email::Error))?;
+        Ok(())
+    }
+}
+
+/// [`Ses`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Request to the [AWS SES] API failed.
+    ///
+    /// [AWS SES]: https://aws.amazon.com/ses
+    #[display("AWS SES request failed: {_0}")]
+    Api(aws_sdk_sesv2::Error),
+}
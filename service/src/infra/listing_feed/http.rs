@@ -0,0 +1,199 @@
+//! Generic HTTP [`ListingFeed`] implementation.
+
+use async_trait::async_trait;
+use common::Money;
+use derive_more::{Display, Error as StdError, From};
+use reqwest::Client;
+use rust_decimal::Decimal;
+use secrecy::{ExposeSecret as _, SecretString};
+use serde::Deserialize;
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, listing, realty, Listing},
+    infra::{
+        listing_feed::{self, Fetched},
+        ListingFeed,
+    },
+};
+
+/// Generic HTTP [`ListingFeed`] client, for plugging in any portal or MLS
+/// (directly or via a thin adapter).
+///
+/// The feed is requested with a `GET` request, and is expected to respond with
+/// a JSON array of listings:
+/// ```json
+/// [{
+///     "id": "A-1024",
+///     "deal": "RENT",
+///     "country": "UK",
+///     "city": "London",
+///     "street": "Baker Street",
+///     "buildingName": "221B",
+///     "numFloors": 4,
+///     "floor": 1,
+///     "apartmentNum": "B",
+///     "area": "54.5",
+///     "title": "Flat on Baker Street",
+///     "description": "Cozy flat in the city centre",
+///     "price": {"amount": "2500", "currency": "GBP"}
+/// }]
+/// ```
+///
+/// Malformed listings are skipped.
+#[derive(Debug)]
+pub struct Http {
+    /// HTTP [`Client`] to perform requests with.
+    client: Client,
+
+    /// URL of the feed.
+    url: String,
+
+    /// API key to authorize requests with, if any.
+    api_key: Option<SecretString>,
+}
+
+impl Http {
+    /// Creates a new [`Http`] client pulling the feed from the provided `url`.
+    #[must_use]
+    pub fn new(url: impl Into<String>, api_key: Option<SecretString>) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.into(),
+            api_key,
+        }
+    }
+}
+
+#[async_trait]
+impl ListingFeed for Http {
+    async fn fetch(&self) -> Result<Fetched, Traced<listing_feed::Error>> {
+        let req = self.client.get(&self.url);
+        let req = match &self.api_key {
+            Some(key) => req.bearer_auth(key.expose_secret()),
+            None => req,
+        };
+        let entries = req
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> listing_feed::Error))?
+            .json::<Vec<serde_json::Value>>()
+            .await
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> listing_feed::Error))?;
+
+        let mut fetched = Fetched::default();
+        for entry in entries {
+            match serde_json::from_value::<Entry>(entry)
+                .ok()
+                .and_then(Entry::into_listing)
+            {
+                Some(l) => fetched.listings.push(l),
+                None => fetched.rejected += 1,
+            }
+        }
+        Ok(fetched)
+    }
+}
+
+/// Raw entry of an [`Http`] feed.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    /// ID of the listing in the feed.
+    id: String,
+
+    /// Deal the listing is offered for (`RENT` or `SALE`).
+    deal: String,
+
+    /// Country of the listed realty.
+    country: String,
+
+    /// State of the listed realty.
+    state: Option<String>,
+
+    /// City of the listed realty.
+    city: String,
+
+    /// Street of the listed realty.
+    street: String,
+
+    /// Zip code of the listed realty.
+    zip_code: Option<String>,
+
+    /// Building name of the listed realty.
+    building_name: String,
+
+    /// Number of floors of the listed realty.
+    num_floors: realty::NumFloors,
+
+    /// Floor of the listed realty.
+    floor: Option<realty::Floor>,
+
+    /// Apartment number of the listed realty.
+    apartment_num: Option<String>,
+
+    /// Room number of the listed realty.
+    room_num: Option<String>,
+
+    /// Area of the listed realty, in square meters.
+    area: Option<Decimal>,
+
+    /// Title of the listing.
+    title: String,
+
+    /// Description of the listing.
+    description: String,
+
+    /// Asked price of the listing.
+    price: Money,
+}
+
+impl Entry {
+    /// Converts this [`Entry`] into a [`Listing`], if it's well-formed.
+    fn into_listing(self) -> Option<Listing> {
+        /// Parses the provided optional value with the provided `parse`
+        /// function, failing only if it's present and invalid.
+        fn optional<T, V>(
+            value: Option<V>,
+            parse: impl FnOnce(V) -> Option<T>,
+        ) -> Option<Option<T>> {
+            match value {
+                Some(v) => parse(v).map(Some),
+                None => Some(None),
+            }
+        }
+
+        Some(Listing {
+            external_id: listing::ExternalId::new(self.id)?,
+            deal: self.deal.parse().ok()?,
+            country: realty::Country::new(self.country)?,
+            state: optional(self.state, realty::State::new)?,
+            city: realty::City::new(self.city)?,
+            street: realty::Street::new(self.street)?,
+            zip_code: optional(self.zip_code, realty::ZipCode::new)?,
+            building_name: realty::BuildingName::new(self.building_name)?,
+            num_floors: self.num_floors,
+            floor: self.floor,
+            apartment_num: optional(
+                self.apartment_num,
+                realty::ApartmentNum::new,
+            )?,
+            room_num: optional(self.room_num, realty::RoomNum::new)?,
+            area: optional(self.area, realty::Area::new)?,
+            name: contract::Name::new(self.title)?,
+            description: contract::Description::new(self.description)?,
+            price: self.price,
+        })
+    }
+}
+
+/// [`Http`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// HTTP request to the feed failed.
+    #[display("HTTP request failed: {_0}")]
+    Http(reqwest::Error),
+}
@@ -0,0 +1,48 @@
+//! [`ListingFeed`]-related implementations.
+
+#[cfg(feature = "http-listings")]
+pub mod http;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use tracerr::Traced;
+
+use crate::domain::Listing;
+#[cfg(doc)]
+use crate::task::SyncListings;
+
+#[cfg(feature = "http-listings")]
+pub use self::http::Http;
+
+/// External portal or MLS feed, the [`Listing`]s are periodically pulled from
+/// by the [`SyncListings`] task.
+#[async_trait]
+pub trait ListingFeed: fmt::Debug + Send + Sync {
+    /// Pulls all the [`Listing`]s currently published in this
+    /// [`ListingFeed`].
+    ///
+    /// # Errors
+    ///
+    /// If failed to pull the [`Listing`]s from this [`ListingFeed`].
+    async fn fetch(&self) -> Result<Fetched, Traced<Error>>;
+}
+
+/// [`Listing`]s pulled from a [`ListingFeed`].
+#[derive(Clone, Debug, Default)]
+pub struct Fetched {
+    /// Well-formed [`Listing`]s.
+    pub listings: Vec<Listing>,
+
+    /// Number of the malformed entries skipped.
+    pub rejected: u32,
+}
+
+/// [`ListingFeed`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    #[cfg(feature = "http-listings")]
+    /// [`Http`] error.
+    Http(http::Error),
+}
@@ -1,7 +1,36 @@
 //! Infrastructure layer.
 
+pub mod accounting;
+pub mod cache;
 pub mod database;
+pub mod denylist;
+pub mod email;
+pub mod listing_feed;
+pub mod mx;
+pub mod search;
+pub mod sms;
+pub mod storage;
+pub mod template;
 
-pub use self::database::Database;
+pub use self::{
+    accounting::Accounting, cache::Cache, database::Database, denylist::PasswordDenylist,
+    email::Mailer, listing_feed::ListingFeed, mx::MxCheck, search::Search, sms::SmsGateway, storage::Storage,
+};
+#[cfg(feature = "redis")]
+pub use self::cache::{redis, Redis};
 #[cfg(feature = "postgres")]
 pub use self::database::{postgres, Postgres};
+#[cfg(feature = "meilisearch")]
+pub use self::search::{meilisearch, Meilisearch};
+#[cfg(feature = "ses")]
+pub use self::email::{ses, Ses};
+#[cfg(feature = "smtp")]
+pub use self::email::{smtp, Smtp};
+#[cfg(feature = "gcs")]
+pub use self::storage::{gcs, Gcs};
+#[cfg(feature = "local-storage")]
+pub use self::storage::{local, Local};
+#[cfg(feature = "s3")]
+pub use self::storage::{s3, S3};
+#[cfg(feature = "twilio")]
+pub use self::sms::{twilio, Twilio};
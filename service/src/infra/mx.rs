@@ -0,0 +1,22 @@
+//! [`MxCheck`]-related definitions.
+
+use std::fmt;
+
+use async_trait::async_trait;
+
+#[cfg(doc)]
+use crate::domain::{user::Email, User};
+
+/// Check of whether an [`Email`] domain is able to receive mail (usually, by
+/// resolving its [MX records]).
+///
+/// [MX records]: https://datatracker.ietf.org/doc/html/rfc5321#section-5.1
+#[async_trait]
+pub trait MxCheck: fmt::Debug + Send + Sync {
+    /// Indicates whether the provided `domain` accepts mail.
+    ///
+    /// Should return `true` whenever the check itself fails (e.g. a DNS
+    /// resolver is unavailable), so [`User`]s are never rejected because of
+    /// an infrastructure issue.
+    async fn accepts_mail(&self, domain: &str) -> bool;
+}
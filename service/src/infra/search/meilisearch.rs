@@ -0,0 +1,206 @@
+//! [Meilisearch] [`Search`] implementation.
+//!
+//! [Meilisearch]: https://www.meilisearch.com
+
+use std::{collections::HashSet, sync::Mutex};
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use itertools::Itertools as _;
+use reqwest::{Client, RequestBuilder};
+use secrecy::{ExposeSecret as _, SecretString};
+use serde::Deserialize;
+use serde_json::json;
+use tracerr::Traced;
+use uuid::Uuid;
+
+use crate::infra::{
+    search::{self, Document, Facet, Index, Request},
+    Search,
+};
+
+/// [Meilisearch] [`Search`] client.
+///
+/// [Meilisearch]: https://www.meilisearch.com
+#[derive(Debug)]
+pub struct Meilisearch {
+    /// HTTP [`Client`] to perform requests with.
+    client: Client,
+
+    /// Base URL of the [Meilisearch] server.
+    ///
+    /// [Meilisearch]: https://www.meilisearch.com
+    url: String,
+
+    /// API key to authorize requests with, if any.
+    api_key: Option<SecretString>,
+
+    /// [`Index`]es which filterable attributes are already configured.
+    configured: Mutex<HashSet<Index>>,
+}
+
+impl Meilisearch {
+    /// Creates a new [`Meilisearch`] client of the server at the provided
+    /// `url`.
+    #[must_use]
+    pub fn new(url: &str, api_key: Option<SecretString>) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.trim_end_matches('/').to_owned(),
+            api_key,
+            configured: Mutex::default(),
+        }
+    }
+
+    /// Creates a new [`RequestBuilder`] of the provided `method` to the
+    /// provided `path` of this [`Meilisearch`] server.
+    fn request(&self, method: reqwest::Method, path: &str) -> RequestBuilder {
+        let req = self.client.request(method, format!("{}{path}", self.url));
+        match &self.api_key {
+            Some(key) => req.bearer_auth(key.expose_secret()),
+            None => req,
+        }
+    }
+
+    /// Makes the provided `facets` of the provided [`Index`] filterable, if
+    /// it's not done yet.
+    async fn configure(
+        &self,
+        index: Index,
+        facets: impl IntoIterator<Item = &'static str>,
+    ) -> Result<(), Traced<search::Error>> {
+        if self.configured.lock().unwrap().contains(&index) {
+            return Ok(());
+        }
+
+        let attributes = facets
+            .into_iter()
+            .chain(["revision"])
+            .unique()
+            .collect::<Vec<_>>();
+        _ = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!("/indexes/{index}/settings"),
+            )
+            .json(&json!({ "filterableAttributes": attributes }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> search::Error))?;
+
+        _ = self.configured.lock().unwrap().insert(index);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Search for Meilisearch {
+    async fn upsert(
+        &self,
+        index: Index,
+        documents: Vec<Document>,
+    ) -> Result<(), Traced<search::Error>> {
+        let Some(first) = documents.first() else {
+            return Ok(());
+        };
+        self.configure(index, first.facets.keys().copied())
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        _ = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/indexes/{index}/documents?primaryKey=id"),
+            )
+            .json(&documents)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> search::Error))?;
+        Ok(())
+    }
+
+    async fn purge(
+        &self,
+        index: Index,
+        revision: i64,
+    ) -> Result<(), Traced<search::Error>> {
+        _ = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/indexes/{index}/documents/delete"),
+            )
+            .json(&json!({ "filter": format!("revision < {revision}") }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> search::Error))?;
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        index: Index,
+        request: &Request,
+    ) -> Result<Vec<Uuid>, Traced<search::Error>> {
+        /// Response of a [Meilisearch] search.
+        ///
+        /// [Meilisearch]: https://www.meilisearch.com
+        #[derive(Deserialize)]
+        struct Response {
+            /// Found documents.
+            hits: Vec<Hit>,
+        }
+
+        /// Document found by a [Meilisearch] search.
+        ///
+        /// [Meilisearch]: https://www.meilisearch.com
+        #[derive(Deserialize)]
+        struct Hit {
+            /// ID of the found document.
+            id: Uuid,
+        }
+
+        let filter = request
+            .facets
+            .iter()
+            .map(|(name, value)| match value {
+                Facet::Bool(b) => format!("{name} = {b}"),
+                Facet::Text(t) => format!("{name} = {}", json!(t)),
+            })
+            .join(" AND ");
+        let resp = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/indexes/{index}/search"),
+            )
+            .json(&json!({
+                "q": request.query,
+                "filter": (!filter.is_empty()).then_some(filter),
+                "limit": request.limit,
+                "attributesToRetrieve": ["id"],
+            }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> search::Error))?
+            .json::<Response>()
+            .await
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> search::Error))?;
+        Ok(resp.hits.into_iter().map(|h| h.id).collect())
+    }
+}
+
+/// [`Meilisearch`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// HTTP request to the [`Meilisearch`] server failed.
+    #[display("HTTP request failed: {_0}")]
+    Http(reqwest::Error),
+}
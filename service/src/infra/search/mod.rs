@@ -0,0 +1,126 @@
+//! [`Search`]-related implementations.
+
+#[cfg(feature = "meilisearch")]
+pub mod meilisearch;
+
+use std::{collections::BTreeMap, fmt};
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use serde::Serialize;
+use tracerr::Traced;
+use uuid::Uuid;
+
+#[cfg(doc)]
+use crate::{
+    domain::Realty, infra::Database, read::Placement, task::IndexSearch,
+};
+
+#[cfg(feature = "meilisearch")]
+pub use self::meilisearch::Meilisearch;
+
+/// External full-text search engine, mirroring some of the [`Database`]
+/// entities for typo-tolerant and faceted searching.
+///
+/// [`Search`] is never a source of truth: it only narrows down the entities
+/// to be selected from the [`Database`], so any of its errors may be safely
+/// ignored by falling back to the [`Database`] searching.
+#[async_trait]
+pub trait Search: fmt::Debug + Send + Sync {
+    /// Inserts the provided [`Document`]s into the provided [`Index`],
+    /// replacing the existing ones with the same IDs.
+    ///
+    /// # Errors
+    ///
+    /// If failed to write the [`Document`]s into this [`Search`].
+    async fn upsert(
+        &self,
+        index: Index,
+        documents: Vec<Document>,
+    ) -> Result<(), Traced<Error>>;
+
+    /// Removes all the [`Document`]s from the provided [`Index`] having their
+    /// [`Document::revision`] lower than the provided one.
+    ///
+    /// # Errors
+    ///
+    /// If failed to remove the [`Document`]s from this [`Search`].
+    async fn purge(
+        &self,
+        index: Index,
+        revision: i64,
+    ) -> Result<(), Traced<Error>>;
+
+    /// Returns IDs of the [`Document`]s matching the provided [`Request`],
+    /// ordered by their relevance.
+    ///
+    /// # Errors
+    ///
+    /// If failed to query this [`Search`].
+    async fn search(
+        &self,
+        index: Index,
+        request: &Request,
+    ) -> Result<Vec<Uuid>, Traced<Error>>;
+}
+
+/// Index of [`Document`]s in a [`Search`].
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Index {
+    /// [`Placement`]s, searchable by the [`Realty`] address.
+    #[display("placements")]
+    Placements,
+
+    /// [`Realty`]s, searchable by their address.
+    #[display("realties")]
+    Realties,
+}
+
+/// Document stored in a [`Search`] [`Index`].
+#[derive(Clone, Debug, Serialize)]
+pub struct Document {
+    /// ID of the entity this [`Document`] mirrors.
+    pub id: Uuid,
+
+    /// Full text of this [`Document`] to search in.
+    pub text: String,
+
+    /// Facets of this [`Document`] to filter by.
+    #[serde(flatten)]
+    pub facets: BTreeMap<&'static str, Facet>,
+
+    /// Revision of the [`IndexSearch`] run this [`Document`] was written by.
+    pub revision: i64,
+}
+
+/// Value of a [`Document`] facet.
+#[derive(Clone, Debug, Eq, From, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Facet {
+    /// Boolean facet.
+    Bool(bool),
+
+    /// Textual facet.
+    Text(String),
+}
+
+/// Request of [`Search::search()`].
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// Text to search for.
+    pub query: String,
+
+    /// Facets the found [`Document`]s must have.
+    pub facets: BTreeMap<&'static str, Facet>,
+
+    /// Maximum number of the returned [`Document`] IDs.
+    pub limit: usize,
+}
+
+/// [`Search`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    #[cfg(feature = "meilisearch")]
+    /// [`Meilisearch`] error.
+    Meilisearch(meilisearch::Error),
+}
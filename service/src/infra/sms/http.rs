@@ -0,0 +1,128 @@
+//! Generic HTTP [`SmsGateway`] implementation.
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use reqwest::Client;
+use secrecy::{ExposeSecret as _, SecretString};
+use serde::Deserialize;
+use serde_json::json;
+use tracerr::Traced;
+
+use crate::infra::{
+    sms::{self, Message, Receipt},
+    SmsGateway,
+};
+
+/// Generic HTTP [`SmsGateway`] client, for plugging in any SMS provider
+/// (directly or via a thin adapter).
+///
+/// Each [`Message`] is sent as a `POST` request with a JSON body of the
+/// following shape:
+/// ```json
+/// {"from": "...", "to": "...", "body": "...", "status_callback": "..."}
+/// ```
+/// And the provider is expected to respond with the ID of the accepted
+/// message and its delivery status (in the [`sms::parse_status()`]
+/// vocabulary):
+/// ```json
+/// {"id": "...", "status": "queued"}
+/// ```
+/// Delivery status changes are expected to be reported to the
+/// `status_callback` URL in the [Twilio status callback][1] format.
+///
+/// [1]: https://www.twilio.com/docs/messaging/guides/track-outbound-message-status
+#[derive(Debug)]
+pub struct Http {
+    /// HTTP [`Client`] to perform requests with.
+    client: Client,
+
+    /// URL to send [`Message`]s to.
+    url: String,
+
+    /// API key to authorize requests with, if any.
+    api_key: Option<SecretString>,
+
+    /// Sender ID to send [`Message`]s from.
+    from: String,
+
+    /// URL the provider reports the delivery status changes to, if any.
+    status_callback: Option<String>,
+}
+
+impl Http {
+    /// Creates a new [`Http`] client sending [`Message`]s to the provided
+    /// `url` from the provided `from` sender ID.
+    ///
+    /// If the `status_callback` URL is provided, the delivery status changes
+    /// are requested to be reported to it.
+    #[must_use]
+    pub fn new(
+        url: impl Into<String>,
+        api_key: Option<SecretString>,
+        from: impl Into<String>,
+        status_callback: Option<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            url: url.into(),
+            api_key,
+            from: from.into(),
+            status_callback,
+        }
+    }
+}
+
+#[async_trait]
+impl SmsGateway for Http {
+    async fn send(
+        &self,
+        message: &Message,
+    ) -> Result<Receipt, Traced<sms::Error>> {
+        /// Response of the provider accepting a message.
+        #[derive(Deserialize)]
+        struct Response {
+            /// ID of the accepted message.
+            id: String,
+
+            /// Delivery status of the accepted message.
+            status: String,
+        }
+
+        let req = self.client.post(&self.url).json(&json!({
+            "from": self.from,
+            "to": message.to,
+            "body": message.body,
+            "status_callback": self.status_callback,
+        }));
+        let req = match &self.api_key {
+            Some(key) => req.bearer_auth(key.expose_secret()),
+            None => req,
+        };
+        let resp = req
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> sms::Error))?
+            .json::<Response>()
+            .await
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> sms::Error))?;
+
+        let status = sms::parse_status(&resp.status)
+            .ok_or(sms::Error::UnknownStatus(resp.status))
+            .map_err(tracerr::wrap!())?;
+        Ok(Receipt {
+            provider_id: resp.id.into(),
+            status,
+        })
+    }
+}
+
+/// [`Http`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// HTTP request to the provider failed.
+    #[display("HTTP request failed: {_0}")]
+    Http(reqwest::Error),
+}
@@ -0,0 +1,158 @@
+//! [`SmsGateway`]-related implementations.
+
+#[cfg(feature = "sms-http")]
+pub mod http;
+pub mod sandbox;
+#[cfg(feature = "twilio")]
+pub mod twilio;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use serde_json::json;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Lead, Realty, User};
+use crate::{
+    domain::{agency, sms, user},
+    infra::template,
+};
+
+#[cfg(feature = "sms-http")]
+pub use self::http::Http;
+pub use self::sandbox::Sandbox;
+#[cfg(feature = "twilio")]
+pub use self::twilio::Twilio;
+
+/// External SMS delivery service, sending urgent [`Message`]s to [`User`]s.
+#[async_trait]
+pub trait SmsGateway: fmt::Debug + Send + Sync {
+    /// Sends the provided [`Message`], returning the [`Receipt`] of its
+    /// acceptance.
+    ///
+    /// # Errors
+    ///
+    /// If this [`SmsGateway`] failed to accept the [`Message`] for delivery.
+    async fn send(&self, message: &Message) -> Result<Receipt, Traced<Error>>;
+}
+
+/// SMS message sent by an [`SmsGateway`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// [`user::Phone`] number of the recipient.
+    pub to: user::Phone,
+
+    /// Text of this [`Message`].
+    pub body: String,
+}
+
+impl Message {
+    /// Renders a new [`Message`] from the provided [`Template`] via the
+    /// provided [`template::Engine`] in the provided [`agency::Locale`],
+    /// addressed to the provided [`user::Phone`].
+    ///
+    /// # Errors
+    ///
+    /// If the [`Template`] fails to render.
+    pub fn render(
+        engine: &template::Engine,
+        locale: Option<agency::Locale>,
+        to: user::Phone,
+        template: Template,
+    ) -> Result<Self, Traced<template::Error>> {
+        Ok(Self {
+            to,
+            body: engine.render(
+                &format!("sms/{}", template.name()),
+                locale,
+                &json!({}),
+            )?,
+        })
+    }
+}
+
+/// Receipt of a [`Message`] accepted by an [`SmsGateway`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    /// [`sms::ProviderId`] assigned to the [`Message`].
+    pub provider_id: sms::ProviderId,
+
+    /// Initial [`sms::Status`] of the [`Message`] delivery.
+    pub status: sms::Status,
+}
+
+/// Template of a [`Message`] sent to a [`User`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Template {
+    /// [`user::Phone`] number has been linked to the [`User`] account.
+    PhoneLinked,
+
+    /// [`Lead`] of the [`User`] has moved to viewing the [`Realty`].
+    Viewing,
+}
+
+impl Template {
+    /// Returns the name of this [`Template`] in the [`template::DEFINITIONS`]
+    /// (without the `sms/` prefix).
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::PhoneLinked => "phone_linked",
+            Self::Viewing => "viewing",
+        }
+    }
+}
+
+/// Parses the provided delivery status, reported by an [`SmsGateway`] in the
+/// [Twilio vocabulary][1], into an [`sms::Status`].
+///
+/// [`None`] is returned if the status is unknown.
+///
+/// [1]: https://www.twilio.com/docs/messaging/api/message-resource#message-status-values
+#[must_use]
+pub fn parse_status(status: &str) -> Option<sms::Status> {
+    use sms::Status as S;
+
+    Some(match status {
+        "accepted" | "queued" | "scheduled" | "sending" => S::Queued,
+        "sent" => S::Sent,
+        "delivered" | "read" => S::Delivered,
+        "canceled" | "failed" | "undelivered" => S::Failed,
+        _ => return None,
+    })
+}
+
+/// [`SmsGateway`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    #[cfg(feature = "sms-http")]
+    /// [`Http`] error.
+    Http(http::Error),
+
+    #[cfg(feature = "twilio")]
+    /// [`Twilio`] error.
+    Twilio(twilio::Error),
+
+    /// [`SmsGateway`] responded with an unknown delivery status.
+    #[display("unknown delivery status: {_0}")]
+    #[from(ignore)]
+    UnknownStatus(#[error(not(source))] String),
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::domain::sms::Status;
+
+    use super::parse_status;
+
+    #[test]
+    fn parses_twilio_statuses() {
+        assert_eq!(parse_status("queued"), Some(Status::Queued));
+        assert_eq!(parse_status("sent"), Some(Status::Sent));
+        assert_eq!(parse_status("delivered"), Some(Status::Delivered));
+        assert_eq!(parse_status("undelivered"), Some(Status::Failed));
+        assert_eq!(parse_status("unknown"), None);
+    }
+}
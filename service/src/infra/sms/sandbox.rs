@@ -0,0 +1,35 @@
+//! Sandbox [`SmsGateway`] implementation.
+
+use async_trait::async_trait;
+use tracerr::Traced;
+use tracing as log;
+use uuid::Uuid;
+
+use crate::{
+    domain::sms,
+    infra::{
+        sms::{self as gateway, Message, Receipt},
+        SmsGateway,
+    },
+};
+
+/// Sandbox [`SmsGateway`], logging the [`Message`]s instead of sending them.
+///
+/// Useful for development and testing environments, where no real phones
+/// should be bothered.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sandbox;
+
+#[async_trait]
+impl SmsGateway for Sandbox {
+    async fn send(
+        &self,
+        message: &Message,
+    ) -> Result<Receipt, Traced<gateway::Error>> {
+        log::info!("SMS to `{}` (sandboxed): {}", message.to, message.body);
+        Ok(Receipt {
+            provider_id: format!("sandbox-{}", Uuid::new_v4()).into(),
+            status: sms::Status::Sandboxed,
+        })
+    }
+}
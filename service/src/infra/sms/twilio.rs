@@ -0,0 +1,137 @@
+//! [Twilio] [`SmsGateway`] implementation.
+//!
+//! [Twilio]: https://www.twilio.com/docs/messaging
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use reqwest::Client;
+use secrecy::{ExposeSecret as _, SecretString};
+use serde::Deserialize;
+use tracerr::Traced;
+
+use crate::infra::{
+    sms::{self, Message, Receipt},
+    SmsGateway,
+};
+
+/// Base URL of the [Twilio] REST API.
+///
+/// [Twilio]: https://www.twilio.com/docs/messaging
+const API_URL: &str = "https://api.twilio.com/2010-04-01";
+
+/// [Twilio] [`SmsGateway`] client.
+///
+/// [Twilio]: https://www.twilio.com/docs/messaging
+#[derive(Debug)]
+pub struct Twilio {
+    /// HTTP [`Client`] to perform requests with.
+    client: Client,
+
+    /// SID of the [Twilio] account to send [`Message`]s from.
+    ///
+    /// [Twilio]: https://www.twilio.com/docs/messaging
+    account_sid: String,
+
+    /// Auth token of the [Twilio] account.
+    ///
+    /// [Twilio]: https://www.twilio.com/docs/messaging
+    auth_token: SecretString,
+
+    /// Phone number (or messaging service SID) to send [`Message`]s from.
+    from: String,
+
+    /// URL the [Twilio] reports the delivery status changes to, if any.
+    ///
+    /// [Twilio]: https://www.twilio.com/docs/messaging
+    status_callback: Option<String>,
+}
+
+impl Twilio {
+    /// Creates a new [`Twilio`] client of the provided account, sending
+    /// [`Message`]s from the provided `from` number.
+    ///
+    /// If the `status_callback` URL is provided, the delivery status changes
+    /// are reported to it.
+    #[must_use]
+    pub fn new(
+        account_sid: impl Into<String>,
+        auth_token: SecretString,
+        from: impl Into<String>,
+        status_callback: Option<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            account_sid: account_sid.into(),
+            auth_token,
+            from: from.into(),
+            status_callback,
+        }
+    }
+}
+
+#[async_trait]
+impl SmsGateway for Twilio {
+    async fn send(
+        &self,
+        message: &Message,
+    ) -> Result<Receipt, Traced<sms::Error>> {
+        /// Created [Twilio] message resource.
+        ///
+        /// [Twilio]: https://www.twilio.com/docs/messaging
+        #[derive(Deserialize)]
+        struct Response {
+            /// SID of the created message.
+            sid: String,
+
+            /// Delivery status of the created message.
+            status: String,
+        }
+
+        let to = message.to.to_string();
+        let mut form = vec![
+            ("To", to.as_str()),
+            ("From", self.from.as_str()),
+            ("Body", message.body.as_str()),
+        ];
+        if let Some(url) = &self.status_callback {
+            form.push(("StatusCallback", url.as_str()));
+        }
+
+        let resp = self
+            .client
+            .post(format!(
+                "{API_URL}/Accounts/{}/Messages.json",
+                self.account_sid,
+            ))
+            .basic_auth(
+                &self.account_sid,
+                Some(self.auth_token.expose_secret()),
+            )
+            .form(&form)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> sms::Error))?
+            .json::<Response>()
+            .await
+            .map_err(Error::Http)
+            .map_err(tracerr::from_and_wrap!(=> sms::Error))?;
+
+        let status = sms::parse_status(&resp.status)
+            .ok_or(sms::Error::UnknownStatus(resp.status))
+            .map_err(tracerr::wrap!())?;
+        Ok(Receipt {
+            provider_id: resp.sid.into(),
+            status,
+        })
+    }
+}
+
+/// [`Twilio`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// HTTP request to the [`Twilio`] API failed.
+    #[display("HTTP request failed: {_0}")]
+    Http(reqwest::Error),
+}
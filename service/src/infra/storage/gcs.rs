@@ -0,0 +1,152 @@
+//! [Google Cloud Storage] [`Storage`] implementation.
+//!
+//! [Google Cloud Storage]: https://cloud.google.com/storage
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use google_cloud_storage::{
+    client::{google_cloud_auth, Client, ClientConfig},
+    http::{
+        self,
+        objects::{
+            delete::DeleteObjectRequest,
+            download::Range,
+            get::GetObjectRequest,
+            upload::{Media, UploadObjectRequest, UploadType},
+        },
+    },
+};
+use tracerr::Traced;
+
+use crate::infra::{
+    storage::{self, Key},
+    Storage,
+};
+
+/// [Google Cloud Storage] [`Storage`] client.
+///
+/// [Google Cloud Storage]: https://cloud.google.com/storage
+pub struct Gcs {
+    /// [Google Cloud Storage] API [`Client`].
+    ///
+    /// [Google Cloud Storage]: https://cloud.google.com/storage
+    client: Client,
+
+    /// Bucket the objects are stored in.
+    bucket: String,
+
+    /// Prefix of the object names within the [`Gcs::bucket`].
+    prefix: String,
+}
+
+impl fmt::Debug for Gcs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gcs")
+            .field("bucket", &self.bucket)
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Gcs {
+    /// Creates a new [`Gcs`] client storing objects under the provided
+    /// `prefix` in the `bucket`.
+    ///
+    /// Credentials are resolved from the environment, as usual for the Google
+    /// Cloud SDKs (`GOOGLE_APPLICATION_CREDENTIALS`, metadata server, etc).
+    ///
+    /// # Errors
+    ///
+    /// If the credentials cannot be resolved.
+    pub async fn new(
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+    ) -> Result<Self, Traced<Error>> {
+        let config = ClientConfig::default()
+            .with_auth()
+            .await
+            .map_err(tracerr::from_and_wrap!(=> Error))?;
+        Ok(Self {
+            client: Client::new(config),
+            bucket: bucket.into(),
+            prefix: prefix.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl Storage for Gcs {
+    async fn put(
+        &self,
+        key: &Key,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Traced<storage::Error>> {
+        let mut media = Media::new(key.path(&self.prefix));
+        media.content_type = content_type.to_owned().into();
+        _ = self
+            .client
+            .upload_object(
+                &UploadObjectRequest {
+                    bucket: self.bucket.clone(),
+                    ..UploadObjectRequest::default()
+                },
+                data,
+                &UploadType::Simple(media),
+            )
+            .await
+            .map_err(Error::Api)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        key: &Key,
+    ) -> Result<Option<Vec<u8>>, Traced<storage::Error>> {
+        let req = GetObjectRequest {
+            bucket: self.bucket.clone(),
+            object: key.path(&self.prefix),
+            ..GetObjectRequest::default()
+        };
+        match self.client.download_object(&req, &Range::default()).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if is_not_found(&e) => Ok(None),
+            Err(e) => Err(tracerr::new!(storage::Error::from(Error::Api(e)))),
+        }
+    }
+
+    async fn delete(&self, key: &Key) -> Result<(), Traced<storage::Error>> {
+        let req = DeleteObjectRequest {
+            bucket: self.bucket.clone(),
+            object: key.path(&self.prefix),
+            ..DeleteObjectRequest::default()
+        };
+        match self.client.delete_object(&req).await {
+            Ok(()) => Ok(()),
+            Err(e) if is_not_found(&e) => Ok(()),
+            Err(e) => Err(tracerr::new!(storage::Error::from(Error::Api(e)))),
+        }
+    }
+}
+
+/// Checks whether the provided [`http::Error`] means a missing object.
+fn is_not_found(e: &http::Error) -> bool {
+    matches!(e, http::Error::Response(r) if r.code == 404)
+}
+
+/// [`Gcs`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Request to the [Google Cloud Storage] API failed.
+    ///
+    /// [Google Cloud Storage]: https://cloud.google.com/storage
+    #[display("Google Cloud Storage request failed: {_0}")]
+    Api(http::Error),
+
+    /// Credentials failed to be resolved.
+    #[display("failed to resolve Google Cloud credentials: {_0}")]
+    Auth(google_cloud_auth::error::Error),
+}
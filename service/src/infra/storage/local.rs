@@ -0,0 +1,101 @@
+//! Local filesystem [`Storage`] implementation.
+
+use std::{io, path::PathBuf};
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use tokio::fs;
+use tracerr::Traced;
+use uuid::Uuid;
+
+use crate::infra::{
+    storage::{self, Key},
+    Storage,
+};
+
+/// [`Storage`] keeping objects as files in a local directory.
+///
+/// Suitable for development and single-node deployments only, as the objects
+/// are not shared between nodes.
+#[derive(Debug)]
+pub struct Local {
+    /// Root directory the objects are stored in.
+    root: PathBuf,
+
+    /// Prefix of the object paths within the [`Local::root`].
+    prefix: String,
+}
+
+impl Local {
+    /// Creates a new [`Local`] [`Storage`] keeping objects under the provided
+    /// `prefix` in the `root` directory.
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>, prefix: impl Into<String>) -> Self {
+        Self {
+            root: root.into(),
+            prefix: prefix.into(),
+        }
+    }
+
+    /// Returns the path of the file storing the object by the provided
+    /// [`Key`].
+    fn file(&self, key: &Key) -> PathBuf {
+        self.root.join(key.path(&self.prefix))
+    }
+}
+
+#[async_trait]
+impl Storage for Local {
+    async fn put(
+        &self,
+        key: &Key,
+        _: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Traced<storage::Error>> {
+        let file = self.file(key);
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)
+                .await
+                .map_err(Error::Io)
+                .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        }
+        // Write into a temporary file first, so a concurrent reader never
+        // observes a partially written object.
+        let tmp = file.with_extension(format!("{}.tmp", Uuid::new_v4()));
+        fs::write(&tmp, data)
+            .await
+            .map_err(Error::Io)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        fs::rename(&tmp, &file)
+            .await
+            .map_err(Error::Io)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))
+    }
+
+    async fn get(
+        &self,
+        key: &Key,
+    ) -> Result<Option<Vec<u8>>, Traced<storage::Error>> {
+        match fs::read(self.file(key)).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(tracerr::new!(storage::Error::from(Error::Io(e)))),
+        }
+    }
+
+    async fn delete(&self, key: &Key) -> Result<(), Traced<storage::Error>> {
+        match fs::remove_file(self.file(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(tracerr::new!(storage::Error::from(Error::Io(e)))),
+        }
+    }
+}
+
+/// [`Local`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Filesystem operation failed.
+    #[display("filesystem operation failed: {_0}")]
+    Io(io::Error),
+}
@@ -0,0 +1,194 @@
+//! [`Storage`]-related implementations.
+
+#[cfg(feature = "gcs")]
+pub mod gcs;
+#[cfg(feature = "local-storage")]
+pub mod local;
+#[cfg(feature = "s3")]
+pub mod s3;
+pub mod signer;
+
+use std::fmt;
+
+use async_trait::async_trait;
+use derive_more::{Display, Error as StdError, From};
+use tracerr::Traced;
+
+#[cfg(feature = "gcs")]
+pub use self::gcs::Gcs;
+#[cfg(feature = "local-storage")]
+pub use self::local::Local;
+#[cfg(feature = "s3")]
+pub use self::s3::S3;
+pub use self::signer::Signer;
+
+/// Object storage of the files (photos, contract attachments, report exports,
+/// etc) kept outside the [`Database`].
+///
+/// [`Database`]: crate::infra::Database
+#[async_trait]
+pub trait Storage: fmt::Debug + Send + Sync {
+    /// Stores the provided `data` of the `content_type` by the provided
+    /// [`Key`], overwriting the existing object, if any.
+    ///
+    /// # Errors
+    ///
+    /// If this [`Storage`] failed to store the object.
+    async fn put(
+        &self,
+        key: &Key,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Traced<Error>>;
+
+    /// Returns the data of the object stored by the provided [`Key`], if any.
+    ///
+    /// # Errors
+    ///
+    /// If this [`Storage`] failed to read the object.
+    async fn get(&self, key: &Key) -> Result<Option<Vec<u8>>, Traced<Error>>;
+
+    /// Deletes the object stored by the provided [`Key`], if any.
+    ///
+    /// # Errors
+    ///
+    /// If this [`Storage`] failed to delete the object.
+    async fn delete(&self, key: &Key) -> Result<(), Traced<Error>>;
+}
+
+/// Namespace separating the objects of different kinds in a [`Storage`].
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, PartialEq)]
+pub enum Namespace {
+    /// Photos of [`Realty`]s.
+    ///
+    /// [`Realty`]: crate::domain::Realty
+    #[display("photos")]
+    Photos,
+
+    /// Attachments of [`Contract`]s.
+    ///
+    /// [`Contract`]: crate::domain::Contract
+    #[display("attachments")]
+    Attachments,
+
+    /// Exported reports.
+    #[display("exports")]
+    Exports,
+}
+
+/// Key of an object in a [`Storage`].
+#[derive(Clone, Debug, Display, Eq, Hash, PartialEq)]
+#[display("{namespace}/{name}")]
+pub struct Key {
+    /// [`Namespace`] of the object.
+    namespace: Namespace,
+
+    /// `/`-separated name of the object within its [`Namespace`].
+    name: String,
+}
+
+impl Key {
+    /// Creates a new [`Key`] of the object with the provided `name` in the
+    /// provided [`Namespace`].
+    ///
+    /// Returns [`None`] if the `name` is empty, absolute, or contains empty,
+    /// `.` or `..` segments, so it cannot escape its [`Namespace`].
+    #[must_use]
+    pub fn new(namespace: Namespace, name: impl Into<String>) -> Option<Self> {
+        let name = name.into();
+        let is_valid = !name.is_empty()
+            && !name.contains('\\')
+            && name.split('/').all(|s| !matches!(s, "" | "." | ".."));
+        is_valid.then_some(Self { namespace, name })
+    }
+
+    /// Parses a [`Key`] from the provided `path` without a prefix (like
+    /// `photos/1.jpg`).
+    ///
+    /// Returns [`None`] if the `path` has an unknown [`Namespace`] or an
+    /// invalid name (see [`Key::new()`]).
+    #[must_use]
+    pub fn parse(path: &str) -> Option<Self> {
+        let (namespace, name) = path.split_once('/')?;
+        let namespace = match namespace {
+            "photos" => Namespace::Photos,
+            "attachments" => Namespace::Attachments,
+            "exports" => Namespace::Exports,
+            _ => return None,
+        };
+        Self::new(namespace, name)
+    }
+
+    /// Returns the [`Namespace`] of this [`Key`].
+    #[must_use]
+    pub const fn namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    /// Returns the name of this [`Key`] within its [`Namespace`].
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the full path of this [`Key`] under the provided `prefix`
+    /// (like `prod/photos/1.jpg` for the `prod` one).
+    #[must_use]
+    pub fn path(&self, prefix: &str) -> String {
+        let prefix = prefix.trim_matches('/');
+        if prefix.is_empty() {
+            self.to_string()
+        } else {
+            format!("{prefix}/{self}")
+        }
+    }
+}
+
+/// [`Storage`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    #[cfg(feature = "gcs")]
+    /// [`Gcs`] error.
+    Gcs(gcs::Error),
+
+    #[cfg(feature = "local-storage")]
+    /// [`Local`] error.
+    Local(local::Error),
+
+    #[cfg(feature = "s3")]
+    /// [`S3`] error.
+    S3(s3::Error),
+}
+
+#[cfg(test)]
+mod spec {
+    use super::{Key, Namespace};
+
+    #[test]
+    fn rejects_escaping_names() {
+        for name in ["", "/a.jpg", "a//b", "a/./b", "../a", "a/..", "a\\b"] {
+            assert!(
+                Key::new(Namespace::Photos, name).is_none(),
+                "`{name}` is accepted",
+            );
+        }
+        assert!(Key::new(Namespace::Photos, "1/front.jpg").is_some());
+    }
+
+    #[test]
+    fn parses_own_display() {
+        let key = Key::new(Namespace::Attachments, "1/lease.pdf").unwrap();
+
+        assert_eq!(Key::parse(&key.to_string()), Some(key));
+        assert_eq!(Key::parse("videos/1.mp4"), None);
+        assert_eq!(Key::parse("photos/../exports/1.csv"), None);
+    }
+
+    #[test]
+    fn prefixes_path() {
+        let key = Key::new(Namespace::Exports, "journal/1.csv").unwrap();
+
+        assert_eq!(key.path(""), "exports/journal/1.csv");
+        assert_eq!(key.path("prod/"), "prod/exports/journal/1.csv");
+    }
+}
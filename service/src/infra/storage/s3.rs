@@ -0,0 +1,141 @@
+//! [AWS S3] [`Storage`] implementation.
+//!
+//! [AWS S3]: https://aws.amazon.com/s3
+
+use async_trait::async_trait;
+use aws_config::BehaviorVersion;
+use aws_sdk_s3::{
+    operation::get_object::GetObjectError,
+    primitives::{ByteStream, ByteStreamError},
+    Client,
+};
+use derive_more::{Display, Error as StdError, From};
+use tracerr::Traced;
+
+use crate::infra::{
+    storage::{self, Key},
+    Storage,
+};
+
+/// [AWS S3] [`Storage`] client.
+///
+/// [AWS S3]: https://aws.amazon.com/s3
+#[derive(Debug)]
+pub struct S3 {
+    /// [AWS S3] API [`Client`].
+    ///
+    /// [AWS S3]: https://aws.amazon.com/s3
+    client: Client,
+
+    /// Bucket the objects are stored in.
+    bucket: String,
+
+    /// Prefix of the object keys within the [`S3::bucket`].
+    prefix: String,
+}
+
+impl S3 {
+    /// Creates a new [`S3`] client storing objects under the provided
+    /// `prefix` in the `bucket`.
+    ///
+    /// Region and credentials are resolved from the environment, as usual for
+    /// the AWS SDKs (`AWS_REGION`, `AWS_ACCESS_KEY_ID`, etc).
+    pub async fn new(
+        bucket: impl Into<String>,
+        prefix: impl Into<String>,
+    ) -> Self {
+        let config = aws_config::load_defaults(BehaviorVersion::latest()).await;
+        Self {
+            client: Client::new(&config),
+            bucket: bucket.into(),
+            prefix: prefix.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Storage for S3 {
+    async fn put(
+        &self,
+        key: &Key,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), Traced<storage::Error>> {
+        _ = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key.path(&self.prefix))
+            .content_type(content_type)
+            .body(ByteStream::from(data))
+            .send()
+            .await
+            .map_err(aws_sdk_s3::Error::from)
+            .map_err(Error::Api)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        key: &Key,
+    ) -> Result<Option<Vec<u8>>, Traced<storage::Error>> {
+        let object = match self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key.path(&self.prefix))
+            .send()
+            .await
+        {
+            Ok(o) => o,
+            Err(e)
+                if matches!(
+                    e.as_service_error(),
+                    Some(GetObjectError::NoSuchKey(_)),
+                ) =>
+            {
+                return Ok(None);
+            }
+            Err(e) => {
+                let e = Error::Api(e.into());
+                return Err(tracerr::new!(storage::Error::from(e)));
+            }
+        };
+        let data = object
+            .body
+            .collect()
+            .await
+            .map_err(Error::Body)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        Ok(Some(data.into_bytes().to_vec()))
+    }
+
+    async fn delete(&self, key: &Key) -> Result<(), Traced<storage::Error>> {
+        _ = self
+            .client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key.path(&self.prefix))
+            .send()
+            .await
+            .map_err(aws_sdk_s3::Error::from)
+            .map_err(Error::Api)
+            .map_err(tracerr::from_and_wrap!(=> storage::Error))?;
+        Ok(())
+    }
+}
+
+/// [`S3`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Request to the [AWS S3] API failed.
+    ///
+    /// [AWS S3]: https://aws.amazon.com/s3
+    #[display("AWS S3 request failed: {_0}")]
+    Api(aws_sdk_s3::Error),
+
+    /// Object body failed to be read.
+    #[display("failed to read AWS S3 object body: {_0}")]
+    Body(ByteStreamError),
+}
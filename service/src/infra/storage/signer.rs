@@ -0,0 +1,218 @@
+//! [`Signer`] of the time-limited [`Storage`] object URLs.
+
+use std::time::Duration;
+
+use common::DateTime;
+use derive_more::{Debug, Display, Error as StdError};
+use hmac::{Hmac, Mac as _};
+use secrecy::{ExposeSecret as _, SecretString};
+use sha2::Sha256;
+
+#[cfg(doc)]
+use crate::infra::Storage;
+
+use super::Key;
+
+/// Signer of the URLs granting a time-limited access to the private
+/// [`Storage`] objects, so they may be downloaded without proxying their
+/// bytes through the API server.
+///
+/// A signed URL looks like
+/// `{base_url}/{key}?expires={unix_timestamp}&signature={hex}`, where the
+/// `signature` is an HMAC-SHA256 of the `{key}\n{unix_timestamp}`, checked by
+/// the [`Signer::verify()`] (or by an edge proxy sharing the secret).
+#[derive(Debug)]
+pub struct Signer {
+    /// Secret the signatures are computed with.
+    #[debug(skip)]
+    secret: SecretString,
+
+    /// Base URL the signed [`Key`]s are appended to.
+    base_url: String,
+
+    /// Time-to-live of the signed URLs.
+    ttl: Duration,
+}
+
+impl Signer {
+    /// Creates a new [`Signer`] of the URLs under the provided `base_url`,
+    /// valid for the provided `ttl`.
+    #[must_use]
+    pub fn new(
+        secret: SecretString,
+        base_url: impl Into<String>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            secret,
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            ttl,
+        }
+    }
+
+    /// Returns the URL of the object by the provided [`Key`], signed to be
+    /// valid for the [`Signer::ttl`] since now.
+    #[must_use]
+    pub fn sign(&self, key: &Key) -> String {
+        self.sign_at(key, DateTime::now())
+    }
+
+    /// Returns the URL of the object by the provided [`Key`], signed to be
+    /// valid for the [`Signer::ttl`] since the provided [`DateTime`].
+    fn sign_at(&self, key: &Key, now: DateTime) -> String {
+        let expires = (now + self.ttl).unix_timestamp();
+        let signature = hex::encode(
+            self.mac(&key.to_string(), expires).finalize().into_bytes(),
+        );
+        format!(
+            "{}/{key}?expires={expires}&signature={signature}",
+            self.base_url,
+        )
+    }
+
+    /// Verifies the provided `signature` of the URL with the provided `path`
+    /// (the part after the [`Signer::base_url`]), expiring at the provided
+    /// `expires` timestamp, returning the signed [`Key`].
+    ///
+    /// # Errors
+    ///
+    /// If the URL is expired, or its `signature` doesn't match.
+    pub fn verify(
+        &self,
+        path: &str,
+        expires: i64,
+        signature: &str,
+    ) -> Result<Key, VerifyError> {
+        self.verify_at(path, expires, signature, DateTime::now())
+    }
+
+    /// Verifies the provided `signature` at the provided [`DateTime`].
+    ///
+    /// See [`Signer::verify()`] for details.
+    fn verify_at(
+        &self,
+        path: &str,
+        expires: i64,
+        signature: &str,
+        now: DateTime,
+    ) -> Result<Key, VerifyError> {
+        let signature =
+            hex::decode(signature).map_err(|_| VerifyError::Mismatch)?;
+        self.mac(path, expires)
+            .verify_slice(&signature)
+            .map_err(|_| VerifyError::Mismatch)?;
+        if now.unix_timestamp() >= expires {
+            return Err(VerifyError::Expired);
+        }
+        Key::parse(path).ok_or(VerifyError::Mismatch)
+    }
+
+    /// Returns the [`Hmac`] of the provided `path` and `expires` timestamp.
+    fn mac(&self, path: &str, expires: i64) -> Hmac<Sha256> {
+        let secret = self.secret.expose_secret().as_bytes();
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret)
+            .expect("HMAC should accept keys of any size");
+        mac.update(path.as_bytes());
+        mac.update(b"\n");
+        mac.update(expires.to_string().as_bytes());
+        mac
+    }
+}
+
+/// Error of a signed URL verification by a [`Signer`].
+#[derive(Clone, Copy, Debug, Display, Eq, PartialEq, StdError)]
+pub enum VerifyError {
+    /// URL is expired.
+    #[display("signed URL is expired")]
+    Expired,
+
+    /// URL signature doesn't match.
+    #[display("signed URL signature mismatch")]
+    Mismatch,
+}
+
+#[cfg(test)]
+mod spec {
+    use std::time::Duration;
+
+    use common::DateTime;
+
+    use crate::infra::storage::{Key, Namespace};
+
+    use super::{Signer, VerifyError};
+
+    /// Splits the provided signed `url` into its path, expiration timestamp
+    /// and signature.
+    fn split(url: &str) -> (&str, i64, &str) {
+        let (path, query) = url
+            .strip_prefix("https://files.example.com/")
+            .unwrap()
+            .split_once('?')
+            .unwrap();
+        let (expires, signature) = query
+            .strip_prefix("expires=")
+            .unwrap()
+            .split_once("&signature=")
+            .unwrap();
+        (path, expires.parse().unwrap(), signature)
+    }
+
+    fn signer() -> Signer {
+        Signer::new(
+            "secret".to_owned().into(),
+            "https://files.example.com/",
+            Duration::from_secs(60),
+        )
+    }
+
+    #[test]
+    fn verifies_signed_url() {
+        let key = Key::new(Namespace::Attachments, "1/lease.pdf").unwrap();
+        let now = DateTime::now();
+        let url = signer().sign_at(&key, now);
+        let (path, expires, signature) = split(&url);
+
+        assert_eq!(path, "attachments/1/lease.pdf");
+        assert_eq!(signer().verify_at(path, expires, signature, now), Ok(key));
+    }
+
+    #[test]
+    fn rejects_expired_url() {
+        let key = Key::new(Namespace::Attachments, "1/lease.pdf").unwrap();
+        let now = DateTime::now();
+        let url = signer().sign_at(&key, now);
+        let (path, expires, signature) = split(&url);
+
+        assert_eq!(
+            signer().verify_at(
+                path,
+                expires,
+                signature,
+                now + Duration::from_secs(61),
+            ),
+            Err(VerifyError::Expired),
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_url() {
+        let key = Key::new(Namespace::Attachments, "1/lease.pdf").unwrap();
+        let now = DateTime::now();
+        let url = signer().sign_at(&key, now);
+        let (path, expires, signature) = split(&url);
+
+        assert_eq!(
+            signer().verify_at(
+                "attachments/2/lease.pdf",
+                expires,
+                signature,
+                now,
+            ),
+            Err(VerifyError::Mismatch),
+        );
+        assert_eq!(
+            signer().verify_at(path, expires + 3600, signature, now),
+            Err(VerifyError::Mismatch),
+        );
+    }
+}
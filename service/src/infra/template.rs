@@ -0,0 +1,377 @@
+//! [`Engine`] rendering the outgoing message templates.
+//!
+//! Every message part (an email subject or body, an SMS text) is rendered
+//! from a [`Definition`] having a built-in English [MiniJinja] source, which
+//! may be overridden per [`agency::Locale`] by the files of a directory
+//! (see [`Engine::load()`]).
+//!
+//! [MiniJinja]: https://docs.rs/minijinja
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    path::Path,
+};
+
+use derive_more::{Display, Error as StdError, From};
+use minijinja::{Environment, UndefinedBehavior};
+use serde::Serialize;
+use tracerr::Traced;
+
+use crate::domain::agency;
+
+/// [`agency::Locale`] of the built-in [`Definition::source`]s, used as a
+/// fallback for other [`agency::Locale`]s.
+const DEFAULT_LOCALE: agency::Locale = agency::Locale::En;
+
+/// All the [`agency::Locale`]s templates may be provided for.
+const LOCALES: [agency::Locale; 2] = [agency::Locale::En, agency::Locale::Ru];
+
+/// Definition of a message template.
+#[derive(Clone, Copy, Debug)]
+pub struct Definition {
+    /// Name of the template (like `email/password_changed/subject`).
+    pub name: &'static str,
+
+    /// Placeholders the template may refer to, along with their sample
+    /// values used for previews.
+    pub placeholders: &'static [(&'static str, &'static str)],
+
+    /// Built-in English source of the template.
+    pub source: &'static str,
+}
+
+/// [`Definition`]s of all the message templates.
+pub const DEFINITIONS: &[Definition] = &[
+    Definition {
+        name: "email/insurance_expiring/subject",
+        placeholders: INSURANCE_EXPIRING,
+        source: "Insurance policy {{ policy_number }} is expiring",
+    },
+    Definition {
+        name: "email/insurance_expiring/body",
+        placeholders: INSURANCE_EXPIRING,
+        source: "Insurance policy {{ policy_number }} issued by \
+                 {{ provider }} expires at {{ expires_at }}.\n\
+                 Please, renew it or attach a new one to the contract.",
+    },
+    Definition {
+        name: "email/saved_search_matched/subject",
+        placeholders: SAVED_SEARCH_MATCHED,
+        source: "{{ count }} new properties match your saved search",
+    },
+    Definition {
+        name: "email/saved_search_matched/body",
+        placeholders: SAVED_SEARCH_MATCHED,
+        source: "{{ count }} new properties matching your saved search \
+                 have been published:\n\
+                 {{ addresses }}\n\
+                 Please, visit our website to see the details.",
+    },
+    Definition {
+        name: "email/password_changed/subject",
+        placeholders: &[],
+        source: "Your password has been changed",
+    },
+    Definition {
+        name: "email/password_changed/body",
+        placeholders: &[],
+        source: "The password of your account has been changed.\n\
+                 If it wasn't you, please, contact the agency immediately.",
+    },
+    Definition {
+        name: "email/email_changed/subject",
+        placeholders: EMAIL_CHANGED,
+        source: "Your email address has been changed",
+    },
+    Definition {
+        name: "email/email_changed/body",
+        placeholders: EMAIL_CHANGED,
+        source: "The email address of your account has been \
+                 {% if address %}changed to {{ address }}\
+                 {% else %}removed from your account{% endif %}.\n\
+                 If it wasn't you, please, contact the agency immediately.",
+    },
+    Definition {
+        name: "sms/phone_linked",
+        placeholders: &[],
+        source: "This phone number has been linked to your account. \
+                 If it wasn't you, please, contact the agency.",
+    },
+    Definition {
+        name: "sms/viewing",
+        placeholders: &[],
+        source: "Your property viewing is being arranged. \
+                 An agent will contact you shortly to confirm the time.",
+    },
+];
+
+/// Placeholders of the `email/insurance_expiring/*` templates.
+const INSURANCE_EXPIRING: &[(&str, &str)] = &[
+    ("provider", "Acme Insurance"),
+    ("policy_number", "POL-123"),
+    ("expires_at", "2024-06-30T00:00:00Z"),
+];
+
+/// Placeholders of the `email/saved_search_matched/*` templates.
+const SAVED_SEARCH_MATCHED: &[(&str, &str)] = &[
+    ("count", "2"),
+    ("addresses", "UK, London, Baker Street, 221B\nUK, London, Abbey Road, 3"),
+];
+
+/// Placeholders of the `email/email_changed/*` templates.
+const EMAIL_CHANGED: &[(&str, &str)] = &[("address", "new@example.com")];
+
+/// Engine rendering message templates by their [`Definition`]s.
+#[derive(Debug)]
+pub struct Engine {
+    /// [MiniJinja] environment holding the templates, keyed by `{name}` in
+    /// the default English locale, and by `{locale}/{name}` in others.
+    ///
+    /// [MiniJinja]: https://docs.rs/minijinja
+    env: Environment<'static>,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        let mut env = Environment::new();
+        env.set_undefined_behavior(UndefinedBehavior::Strict);
+        for def in DEFINITIONS {
+            env.add_template(def.name, def.source)
+                .expect("built-in templates should be valid");
+        }
+        Self { env }
+    }
+}
+
+impl Engine {
+    /// Loads an [`Engine`] with the built-in templates overridden by the
+    /// files of the provided `dir`ectory.
+    ///
+    /// A template with the `{name}` is read from the `{locale}/{name}.j2`
+    /// file (like `ru/sms/viewing.j2`), if it exists.
+    ///
+    /// # Errors
+    ///
+    /// If a template file cannot be read, is malformed or refers to a
+    /// placeholder not defined by its [`Definition`].
+    pub fn load(dir: &Path) -> Result<Self, Traced<Error>> {
+        let mut engine = Self::default();
+        for locale in LOCALES {
+            for def in DEFINITIONS {
+                let path = dir
+                    .join(locale_tag(locale))
+                    .join(format!("{}.j2", def.name));
+                let source = match fs::read_to_string(&path) {
+                    Ok(s) => s,
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                    Err(e) => {
+                        return Err(tracerr::new!(Error::Io(e)));
+                    }
+                };
+                engine.add(locale, def, source)?;
+            }
+        }
+        Ok(engine)
+    }
+
+    /// Adds the provided `source` of the template by the provided
+    /// [`Definition`] in the provided [`agency::Locale`].
+    ///
+    /// # Errors
+    ///
+    /// If the `source` is malformed or refers to a placeholder not defined by
+    /// the [`Definition`].
+    pub fn add(
+        &mut self,
+        locale: agency::Locale,
+        def: &Definition,
+        source: String,
+    ) -> Result<(), Traced<Error>> {
+        let key = if locale == DEFAULT_LOCALE {
+            def.name.to_owned()
+        } else {
+            format!("{}/{}", locale_tag(locale), def.name)
+        };
+        // Validate in a separate environment, so an invalid `source` doesn't
+        // replace the valid one.
+        let mut probe = Environment::new();
+        probe
+            .add_template_owned(def.name, source.clone())
+            .map_err(tracerr::from_and_wrap!(=> Error))?;
+        let used = probe
+            .get_template(def.name)
+            .map_err(tracerr::from_and_wrap!(=> Error))?
+            .undeclared_variables(false);
+        let allowed = def
+            .placeholders
+            .iter()
+            .map(|(p, _)| *p)
+            .collect::<HashSet<_>>();
+        if let Some(p) =
+            used.into_iter().find(|p| !allowed.contains(p.as_str()))
+        {
+            return Err(tracerr::new!(Error::UnknownPlaceholder {
+                template: def.name,
+                placeholder: p,
+            }));
+        }
+
+        self.env
+            .add_template_owned(key, source)
+            .map_err(tracerr::from_and_wrap!(=> Error))
+    }
+
+    /// Renders the template with the provided `name` in the provided
+    /// [`agency::Locale`] (falling back to the English one, if missing),
+    /// using the provided `payload` as the placeholders values.
+    ///
+    /// # Errors
+    ///
+    /// If there is no template with the provided `name`, or the `payload`
+    /// misses a placeholder the template refers to.
+    pub fn render<P: Serialize>(
+        &self,
+        name: &str,
+        locale: Option<agency::Locale>,
+        payload: &P,
+    ) -> Result<String, Traced<Error>> {
+        let localized = locale.filter(|l| *l != DEFAULT_LOCALE).and_then(|l| {
+            self.env
+                .get_template(&format!("{}/{name}", locale_tag(l)))
+                .ok()
+        });
+        let template = match localized {
+            Some(t) => t,
+            None => self
+                .env
+                .get_template(name)
+                .map_err(|_| Error::UnknownTemplate(name.to_owned()))
+                .map_err(tracerr::wrap!())?,
+        };
+        template
+            .render(payload)
+            .map_err(tracerr::from_and_wrap!(=> Error))
+    }
+
+    /// Renders the template with the provided `name` in the provided
+    /// [`agency::Locale`], using the sample values of its
+    /// [`Definition::placeholders`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no template with the provided `name`.
+    pub fn preview(
+        &self,
+        name: &str,
+        locale: Option<agency::Locale>,
+    ) -> Result<String, Traced<Error>> {
+        let def = definition(name)
+            .ok_or_else(|| Error::UnknownTemplate(name.to_owned()))
+            .map_err(tracerr::wrap!())?;
+        let payload =
+            def.placeholders.iter().copied().collect::<HashMap<_, _>>();
+        self.render(name, locale, &payload)
+    }
+}
+
+/// Returns the [`Definition`] of the template with the provided `name`, if
+/// any.
+#[must_use]
+pub fn definition(name: &str) -> Option<&'static Definition> {
+    DEFINITIONS.iter().find(|d| d.name == name)
+}
+
+/// Returns the lowercase tag of the provided [`agency::Locale`] (like `en`).
+fn locale_tag(locale: agency::Locale) -> String {
+    locale.to_string().to_lowercase()
+}
+
+/// [`Engine`] error.
+#[derive(Debug, Display, From, StdError)]
+pub enum Error {
+    /// Template file cannot be read.
+    #[display("failed to read template file: {_0}")]
+    Io(io::Error),
+
+    /// Template is malformed or failed to render.
+    #[display("template failed: {_0}")]
+    Template(minijinja::Error),
+
+    /// Template refers to a placeholder not defined by its [`Definition`].
+    #[display("`{template}` template refers to unknown `{placeholder}`")]
+    #[from(ignore)]
+    UnknownPlaceholder {
+        /// Name of the template.
+        template: &'static str,
+
+        /// Name of the unknown placeholder.
+        placeholder: String,
+    },
+
+    /// There is no template with the provided name.
+    #[display("unknown `{_0}` template")]
+    #[from(ignore)]
+    UnknownTemplate(#[error(not(source))] String),
+}
+
+#[cfg(test)]
+mod spec {
+    use crate::domain::agency;
+
+    use super::{definition, Engine, Error};
+
+    #[test]
+    fn previews_all_definitions() {
+        let engine = Engine::default();
+        for def in super::DEFINITIONS {
+            let text = engine.preview(def.name, None).unwrap();
+            assert!(!text.is_empty(), "`{}` renders empty", def.name);
+        }
+    }
+
+    #[test]
+    fn falls_back_to_default_locale() {
+        let mut engine = Engine::default();
+        let def = definition("sms/viewing").unwrap();
+        engine
+            .add(agency::Locale::Ru, def, "Просмотр назначается.".into())
+            .unwrap();
+
+        assert_eq!(
+            engine
+                .preview("sms/viewing", Some(agency::Locale::Ru))
+                .unwrap(),
+            "Просмотр назначается.",
+        );
+        assert!(engine
+            .preview("sms/phone_linked", Some(agency::Locale::Ru))
+            .unwrap()
+            .starts_with("This phone number"));
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        let mut engine = Engine::default();
+        let def = definition("email/password_changed/body").unwrap();
+        let err = engine
+            .add(agency::Locale::Ru, def, "Пароль {{ password }}".into())
+            .unwrap_err();
+
+        assert!(matches!(
+            err.as_ref(),
+            Error::UnknownPlaceholder { placeholder, .. }
+                if placeholder == "password",
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_payload() {
+        let engine = Engine::default();
+        let err = engine
+            .render("email/email_changed/body", None, &serde_json::json!({}))
+            .unwrap_err();
+
+        assert!(matches!(err.as_ref(), Error::Template(_)));
+    }
+}
@@ -36,11 +36,27 @@ pub mod query;
 pub mod read;
 pub mod task;
 
-use common::operations::{By, Start};
+use std::{sync::Arc, time::Duration};
+
+use common::{
+    operations::{By, Insert, Start},
+    DateTime,
+};
 use derive_more::{Debug, Display, Error};
+use rust_decimal::Decimal;
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
 
-#[cfg(doc)]
-use infra::Database;
+use crate::{
+    domain::{Arrears, Contract, Offer},
+    infra::{
+        cache, database, email, sms, storage, template, Accounting, Cache,
+        Database, Mailer, MxCheck, PasswordDenylist, Search, SmsGateway,
+        Storage,
+    },
+};
 
 pub use self::{command::Command, query::Query, task::Task};
 
@@ -59,8 +75,147 @@ pub struct Config {
     #[debug(skip)]
     pub jwt_decoding_key: jsonwebtoken::DecodingKey,
 
+    /// [`task::AlertSavedSearches`] configuration.
+    pub alert_saved_searches: task::alert_saved_searches::Config,
+
     /// [`task::CleanUnusedRealties`] configuration.
     pub clean_unused_realties: task::clean_unused_realties::Config,
+
+    /// [`task::CompleteExpiredContracts`] configuration.
+    pub complete_expired_contracts: task::complete_expired_contracts::Config,
+
+    /// [`task::DatabaseHealthcheck`] configuration.
+    pub database_healthcheck: task::database_healthcheck::Config,
+
+    /// [`task::DetectRentArrears`] configuration.
+    pub detect_rent_arrears: task::detect_rent_arrears::Config,
+
+    /// [`task::ExportJournal`] configuration.
+    pub export_journal: task::export_journal::Config,
+
+    /// [`task::GenerateSitemaps`] configuration.
+    pub generate_sitemaps: task::generate_sitemaps::Config,
+
+    /// [`task::IndexSearch`] configuration.
+    pub index_search: task::index_search::Config,
+
+    /// [`task::NotifyExpiringInsurance`] configuration.
+    pub notify_expiring_insurance: task::notify_expiring_insurance::Config,
+
+    /// [`task::RefreshTotalCounts`] configuration.
+    pub refresh_total_counts: task::refresh_total_counts::Config,
+
+    /// [`task::RetentionPurge`] configuration.
+    pub retention_purge: task::retention_purge::Config,
+
+    /// [`task::SyncListings`] configuration.
+    pub sync_listings: task::sync_listings::Config,
+
+    /// [`task::Restart`] policy of the failed [`Task`]s.
+    pub task_restart: task::Restart,
+
+    /// Minimal estimated total count of a list, starting from which it's
+    /// returned instead of counting the list exactly.
+    pub estimated_count_threshold: i32,
+
+    /// Price amount, above which new [`domain::contract::Rent`]s and
+    /// [`domain::contract::Sale`]s require a [`domain::contract::Approval`]
+    /// before becoming active.
+    ///
+    /// [`None`] disables approvals.
+    pub approval_threshold: Option<Decimal>,
+
+    /// Base URL of the page accepting [`domain::user::Invite`]s, their
+    /// [`domain::user::invite::Token`]s are appended to as the `token` query
+    /// parameter.
+    pub invite_url: String,
+
+    /// [`Cache`] of the frequently selected [`Database`] values.
+    ///
+    /// [`None`] disables caching.
+    pub cache: Option<Arc<dyn Cache>>,
+
+    /// Time-to-live of the values stored in the [`Config::cache`].
+    pub cache_ttl: Duration,
+
+    /// [`Search`] engine mirroring the [`Database`] values.
+    ///
+    /// [`None`] disables it in favor of the [`Database`] searching.
+    pub search: Option<Arc<dyn Search>>,
+
+    /// [`read::search::Ranking`] of the [`read::Placement`]s found by the
+    /// [`query::search::Global`].
+    pub search_ranking: read::search::Ranking,
+
+    /// [`MxCheck`] of the [`domain::user::Email`]s provided by [`User`]s.
+    ///
+    /// [`None`] disables it, accepting any well-formed address.
+    ///
+    /// [`User`]: domain::User
+    pub mx_check: Option<Arc<dyn MxCheck>>,
+
+    /// [`domain::user::password::Policy`] of the [`domain::user::Password`]s
+    /// set by [`User`]s.
+    ///
+    /// [`User`]: domain::User
+    pub password_policy: domain::user::password::Policy,
+
+    /// [`PasswordDenylist`] of the breached [`domain::user::Password`]s.
+    ///
+    /// [`None`] disables it.
+    pub password_denylist: Option<Arc<dyn PasswordDenylist>>,
+
+    /// [`Accounting`] system the journal of the recorded
+    /// [`domain::Payment`]s is pushed into by the [`task::ExportJournal`].
+    ///
+    /// [`None`] disables pushing, leaving the journal to be exported on
+    /// demand only.
+    pub accounting: Option<Arc<dyn Accounting>>,
+
+    /// [`Mailer`] sending the notification emails to [`User`]s.
+    ///
+    /// [`None`] disables sending emails.
+    ///
+    /// [`User`]: domain::User
+    pub mailer: Option<Arc<dyn Mailer>>,
+
+    /// [`email::Retry`] policy of the emails failed to be sent by the
+    /// [`Config::mailer`].
+    pub email_retry: email::Retry,
+
+    /// [`SmsGateway`] sending the urgent SMS notifications to [`User`]s.
+    ///
+    /// [`None`] disables sending SMS.
+    ///
+    /// [`User`]: domain::User
+    pub sms_gateway: Option<Arc<dyn SmsGateway>>,
+
+    /// [`template::Engine`] rendering the emails and SMS sent to [`User`]s.
+    ///
+    /// [`User`]: domain::User
+    pub templates: Arc<template::Engine>,
+
+    /// [`Storage`] of the files (photos, contract attachments, report
+    /// exports).
+    ///
+    /// [`None`] disables storing files.
+    pub storage: Option<Arc<dyn Storage>>,
+
+    /// [`storage::Signer`] of the URLs granting a time-limited access to the
+    /// files in the [`Config::storage`].
+    ///
+    /// No API returns such URLs yet, as nothing stored is exposed to clients
+    /// (contract attachments and documents are not uploaded so far), so only
+    /// the URLs signed outside this [`Service`] may be verified.
+    ///
+    /// [`None`] disables downloading files.
+    pub url_signer: Option<Arc<storage::Signer>>,
+
+    /// [`task::sync_listings::Feed`]s the [`domain::Listing`]s are pulled
+    /// from by the [`task::SyncListings`].
+    ///
+    /// Empty disables importing listings.
+    pub listing_feeds: Vec<task::sync_listings::Feed>,
 }
 
 /// Domain service.
@@ -71,32 +226,401 @@ pub struct Service<Db> {
 
     /// [`Database`] of this [`Service`].
     database: Db,
+
+    /// Sender of the reloaded [`task::AlertSavedSearches`] configuration.
+    alert_saved_searches:
+        Arc<watch::Sender<task::alert_saved_searches::Config>>,
+
+    /// Sender of the [`read::saved_search::Matched`]es alerted about by the
+    /// [`task::AlertSavedSearches`].
+    saved_search_matches: broadcast::Sender<read::saved_search::Matched>,
+
+    /// Sender of the reloaded [`task::CleanUnusedRealties`] configuration.
+    clean_unused_realties:
+        Arc<watch::Sender<task::clean_unused_realties::Config>>,
+
+    /// Sender of the reloaded [`task::CompleteExpiredContracts`]
+    /// configuration.
+    complete_expired_contracts:
+        Arc<watch::Sender<task::complete_expired_contracts::Config>>,
+
+    /// Sender of the [`Contract`]s completed by the
+    /// [`task::CompleteExpiredContracts`].
+    completed_contracts: broadcast::Sender<Contract>,
+
+    /// Sender of the reloaded [`task::DatabaseHealthcheck`] configuration.
+    database_healthcheck:
+        Arc<watch::Sender<task::database_healthcheck::Config>>,
+
+    /// Receiver of the [`Database`] health status, reported by the
+    /// [`task::DatabaseHealthcheck`].
+    database_status: watch::Receiver<task::database_healthcheck::Status>,
+
+    /// Sender of the reloaded [`task::DetectRentArrears`] configuration.
+    detect_rent_arrears: Arc<watch::Sender<task::detect_rent_arrears::Config>>,
+
+    /// Sender of the [`Arrears`] detected by the [`task::DetectRentArrears`].
+    arrears: broadcast::Sender<Arrears>,
+
+    /// Sender of the reloaded [`task::ExportJournal`] configuration.
+    export_journal: Arc<watch::Sender<task::export_journal::Config>>,
+
+    /// Sender of the reloaded [`task::GenerateSitemaps`] configuration.
+    generate_sitemaps: Arc<watch::Sender<task::generate_sitemaps::Config>>,
+
+    /// Receiver of the sitemaps, generated by the [`task::GenerateSitemaps`].
+    sitemaps: watch::Receiver<task::generate_sitemaps::Sitemaps>,
+
+    /// Sender of the [`DateTime`] when the [`read::Placement`]s were last
+    /// changed, making the [`task::GenerateSitemaps`] regenerate the sitemaps.
+    placements_changed_at: Arc<watch::Sender<DateTime>>,
+
+    /// Sender of the reloaded [`task::IndexSearch`] configuration.
+    index_search: Arc<watch::Sender<task::index_search::Config>>,
+
+    /// Sender of the reloaded [`task::NotifyExpiringInsurance`]
+    /// configuration.
+    notify_expiring_insurance:
+        Arc<watch::Sender<task::notify_expiring_insurance::Config>>,
+
+    /// Sender of the [`Insurance`]s, which expiration is notified about by
+    /// the [`task::NotifyExpiringInsurance`].
+    ///
+    /// [`Insurance`]: domain::Insurance
+    expiring_insurances: broadcast::Sender<read::insurance::Expiring>,
+
+    /// Sender of the [`Offer`]s submitted, accepted or declined.
+    offers: broadcast::Sender<Offer>,
+
+    /// Sender of the reloaded [`task::RefreshTotalCounts`] configuration.
+    refresh_total_counts:
+        Arc<watch::Sender<task::refresh_total_counts::Config>>,
+
+    /// Receiver of the exact total counts, cached by the
+    /// [`task::RefreshTotalCounts`].
+    total_counts: watch::Receiver<task::refresh_total_counts::TotalCounts>,
+
+    /// Sender of the reloaded [`task::RetentionPurge`] configuration.
+    retention_purge: Arc<watch::Sender<task::retention_purge::Config>>,
+
+    /// Receiver of the [`task::retention_purge::Stats`], reported by the
+    /// [`task::RetentionPurge`].
+    retention_stats: watch::Receiver<task::retention_purge::Stats>,
+
+    /// Sender of the reloaded [`task::SyncListings`] configuration.
+    sync_listings: Arc<watch::Sender<task::sync_listings::Config>>,
+
+    /// Sender of the [`task::Statuses`], reported by the periodic [`Task`]s.
+    task_statuses: Arc<watch::Sender<task::Statuses>>,
+
+    /// [`email::Queue`] of the emails to be sent by the [`Config::mailer`].
+    ///
+    /// [`None`] if the [`Config::mailer`] is not configured.
+    emails: Option<email::Queue>,
 }
 
 impl<Db> Service<Db> {
     /// Creates a new [`Service`] with the provided parameters.
+    #[expect(clippy::too_many_lines, reason = "spawns all the `Task`s")]
     pub fn new(config: Config, database: Db) -> (Self, task::Background)
     where
         Self: Task<
+                Start<
+                    By<
+                        task::AlertSavedSearches<Self>,
+                        AlertSavedSearchesChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
                 Start<
                     By<
                         task::CleanUnusedRealties<Self>,
-                        task::clean_unused_realties::Config,
+                        CleanUnusedRealtiesChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::CompleteExpiredContracts<Self>,
+                        CompleteExpiredContractsChannels,
                     >,
                 >,
                 Ok = (),
                 Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::DatabaseHealthcheck<Self>,
+                        DatabaseHealthcheckChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::DetectRentArrears<Self>,
+                        DetectRentArrearsChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::ExportJournal<Self>,
+                        ExportJournalChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::GenerateSitemaps<Self>,
+                        GenerateSitemapsChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::IndexSearch<Self>,
+                        IndexSearchChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::NotifyExpiringInsurance<Self>,
+                        NotifyExpiringInsuranceChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<
+                    By<
+                        task::RefreshTotalCounts<Self>,
+                        RefreshTotalCountsChannels,
+                    >,
+                >,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<By<task::RetentionPurge<Self>, RetentionPurgeChannels>>,
+                Ok = (),
+                Err: Error,
+            > + Task<
+                Start<By<task::SyncListings<Self>, SyncListingsChannels>>,
+                Ok = (),
+                Err: Error,
             > + Clone
             + 'static,
     {
-        let this = Service { config, database };
+        let (alert_saved_searches, alert_saved_searches_rx) =
+            watch::channel(config.alert_saved_searches.clone());
+        let (saved_search_matches, _) =
+            broadcast::channel(SAVED_SEARCH_MATCHES_CAPACITY);
+        let (clean_unused_realties, clean_unused_realties_rx) =
+            watch::channel(config.clean_unused_realties.clone());
+        let (complete_expired_contracts, complete_expired_contracts_rx) =
+            watch::channel(config.complete_expired_contracts.clone());
+        let (completed_contracts, _) =
+            broadcast::channel(COMPLETED_CONTRACTS_CAPACITY);
+        let (database_healthcheck, database_healthcheck_rx) =
+            watch::channel(config.database_healthcheck.clone());
+        let (database_status_tx, database_status) =
+            watch::channel(task::database_healthcheck::Status::default());
+        let (detect_rent_arrears, detect_rent_arrears_rx) =
+            watch::channel(config.detect_rent_arrears.clone());
+        let (arrears, _) = broadcast::channel(ARREARS_CAPACITY);
+        let (export_journal, export_journal_rx) =
+            watch::channel(config.export_journal.clone());
+        let (generate_sitemaps, generate_sitemaps_rx) =
+            watch::channel(config.generate_sitemaps.clone());
+        let (sitemaps_tx, sitemaps) =
+            watch::channel(task::generate_sitemaps::Sitemaps::default());
+        let (index_search, index_search_rx) =
+            watch::channel(config.index_search.clone());
+        let (notify_expiring_insurance, notify_expiring_insurance_rx) =
+            watch::channel(config.notify_expiring_insurance.clone());
+        let (expiring_insurances, _) =
+            broadcast::channel(EXPIRING_INSURANCES_CAPACITY);
+        let (offers, _) = broadcast::channel(OFFERS_CAPACITY);
+        let (refresh_total_counts, refresh_total_counts_rx) =
+            watch::channel(config.refresh_total_counts.clone());
+        let (total_counts_tx, total_counts) = watch::channel(
+            task::refresh_total_counts::TotalCounts::default(),
+        );
+        let (retention_purge, retention_purge_rx) =
+            watch::channel(config.retention_purge.clone());
+        let (retention_stats_tx, retention_stats) =
+            watch::channel(task::retention_purge::Stats::default());
+        let (sync_listings, sync_listings_rx) =
+            watch::channel(config.sync_listings.clone());
+        let (emails, email_worker) = config
+            .mailer
+            .clone()
+            .map(|mailer| email::Queue::new(mailer, config.email_retry))
+            .unzip();
+        let this = Service {
+            config,
+            database,
+            alert_saved_searches: Arc::new(alert_saved_searches),
+            saved_search_matches: saved_search_matches.clone(),
+            clean_unused_realties: Arc::new(clean_unused_realties),
+            complete_expired_contracts: Arc::new(complete_expired_contracts),
+            completed_contracts: completed_contracts.clone(),
+            database_healthcheck: Arc::new(database_healthcheck),
+            database_status,
+            detect_rent_arrears: Arc::new(detect_rent_arrears),
+            arrears: arrears.clone(),
+            export_journal: Arc::new(export_journal),
+            generate_sitemaps: Arc::new(generate_sitemaps),
+            sitemaps,
+            placements_changed_at: Arc::new(watch::Sender::new(
+                DateTime::now(),
+            )),
+            index_search: Arc::new(index_search),
+            notify_expiring_insurance: Arc::new(notify_expiring_insurance),
+            expiring_insurances: expiring_insurances.clone(),
+            offers,
+            refresh_total_counts: Arc::new(refresh_total_counts),
+            total_counts,
+            retention_purge: Arc::new(retention_purge),
+            retention_stats,
+            sync_listings: Arc::new(sync_listings),
+            task_statuses: Arc::new(watch::Sender::new(task::Statuses::new())),
+            emails,
+        };
 
-        let mut bg = task::Background::default();
+        let mut bg = task::Background::new(this.config.task_restart);
+        let svc = this.clone();
+        bg.spawn("task::AlertSavedSearches", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    alert_saved_searches_rx.clone(),
+                    saved_search_matches.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::CleanUnusedRealties", move |cancel| {
+            let (svc, channels) =
+                (svc.clone(), (clean_unused_realties_rx.clone(), cancel));
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::CompleteExpiredContracts", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    complete_expired_contracts_rx.clone(),
+                    completed_contracts.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::DatabaseHealthcheck", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    database_healthcheck_rx.clone(),
+                    database_status_tx.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
         let svc = this.clone();
-        bg.spawn(async move {
-            svc.execute(Start(By::new(svc.config().clean_unused_realties)))
-                .await
+        bg.spawn("task::DetectRentArrears", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (detect_rent_arrears_rx.clone(), arrears.clone(), cancel),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
         });
+        let svc = this.clone();
+        bg.spawn("task::ExportJournal", move |cancel| {
+            let (svc, channels) =
+                (svc.clone(), (export_journal_rx.clone(), cancel));
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::GenerateSitemaps", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (generate_sitemaps_rx.clone(), sitemaps_tx.clone(), cancel),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::IndexSearch", move |cancel| {
+            let (svc, channels) =
+                (svc.clone(), (index_search_rx.clone(), cancel));
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::NotifyExpiringInsurance", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    notify_expiring_insurance_rx.clone(),
+                    expiring_insurances.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::RefreshTotalCounts", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    refresh_total_counts_rx.clone(),
+                    total_counts_tx.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::RetentionPurge", move |cancel| {
+            let (svc, channels) = (
+                svc.clone(),
+                (
+                    retention_purge_rx.clone(),
+                    retention_stats_tx.clone(),
+                    cancel,
+                ),
+            );
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        let svc = this.clone();
+        bg.spawn("task::SyncListings", move |cancel| {
+            let (svc, channels) =
+                (svc.clone(), (sync_listings_rx.clone(), cancel));
+            async move { svc.execute(Start(By::new(channels))).await }
+        });
+        if let Some(worker) = email_worker {
+            bg.spawn("email::Queue", move |cancel| {
+                let worker = worker.clone();
+                async move { worker.run(cancel).await }
+            });
+        }
 
         (this, bg)
     }
@@ -106,13 +630,396 @@ impl<Db> Service<Db> {
         &self.config
     }
 
+    /// Applies the reloadable parts of the provided [`Config`] to this
+    /// [`Service`].
+    ///
+    /// Only [`Task`]s configuration is reloadable, and it's picked up by the
+    /// running [`Task`]s on their next iteration. [`Service::config()`] keeps
+    /// returning the initial [`Config`].
+    pub fn reload(&self, config: &Config) {
+        _ = self
+            .alert_saved_searches
+            .send_replace(config.alert_saved_searches.clone());
+        // Persisted settings keep overriding the reloaded configuration.
+        self.clean_unused_realties.send_modify(|c| {
+            *c = task::clean_unused_realties::Config {
+                settings: c.settings,
+                ..config.clean_unused_realties.clone()
+            };
+        });
+        _ = self
+            .complete_expired_contracts
+            .send_replace(config.complete_expired_contracts.clone());
+        _ = self
+            .database_healthcheck
+            .send_replace(config.database_healthcheck.clone());
+        _ = self
+            .detect_rent_arrears
+            .send_replace(config.detect_rent_arrears.clone());
+        _ = self
+            .export_journal
+            .send_replace(config.export_journal.clone());
+        _ = self
+            .generate_sitemaps
+            .send_replace(config.generate_sitemaps.clone());
+        _ = self.index_search.send_replace(config.index_search.clone());
+        _ = self
+            .notify_expiring_insurance
+            .send_replace(config.notify_expiring_insurance.clone());
+        _ = self
+            .refresh_total_counts
+            .send_replace(config.refresh_total_counts.clone());
+        _ = self
+            .retention_purge
+            .send_replace(config.retention_purge.clone());
+        _ = self.sync_listings.send_replace(config.sync_listings.clone());
+    }
+
+    /// Applies the provided persisted [`domain::task::Settings`] to the running
+    /// [`Task`] they belong to, rescheduling it, if necessary.
+    pub(crate) fn apply_task_settings(&self, settings: domain::task::Settings) {
+        use domain::task::{Kind, Settings};
+
+        let kind = settings.task;
+        // Empty `Settings` override nothing.
+        let settings = Some(settings)
+            .filter(|s| s.interval.is_some() || s.timeout.is_some());
+        let overrides = |s: &Settings| (s.interval, s.timeout);
+        match kind {
+            Kind::CleanUnusedRealties => {
+                _ = self.clean_unused_realties.send_if_modified(|c| {
+                    let changed = c.settings.as_ref().map(overrides)
+                        != settings.as_ref().map(overrides);
+                    c.settings = settings;
+                    changed
+                });
+            }
+        }
+    }
+
+    /// Records the provided finished [`domain::task::Run`] into the execution
+    /// history of the periodic [`Task`]s.
+    ///
+    /// Errors are logged only, since the history is informational.
+    pub(crate) async fn record_task_run(&self, run: domain::task::Run)
+    where
+        Db: Database<
+            Insert<domain::task::Run>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        >,
+    {
+        let task = run.task.clone();
+        if let Err(e) = self.database.execute(Insert(run)).await {
+            log::warn!("Failed to record `{task}` run: {e}");
+        }
+    }
+
+    /// Returns the [`Database`] health status, reported by the
+    /// [`task::DatabaseHealthcheck`].
+    ///
+    /// The returned [`watch::Receiver`] may be used to await the status
+    /// changes.
+    #[must_use]
+    pub fn database_status(
+        &self,
+    ) -> watch::Receiver<task::database_healthcheck::Status> {
+        self.database_status.clone()
+    }
+
+    /// Returns the [`task::retention_purge::Stats`] of the purged entities,
+    /// reported by the [`task::RetentionPurge`].
+    #[must_use]
+    pub fn retention_stats(
+        &self,
+    ) -> watch::Receiver<task::retention_purge::Stats> {
+        self.retention_stats.clone()
+    }
+
+    /// Subscribes to the [`Contract`]s completed by the
+    /// [`task::CompleteExpiredContracts`].
+    ///
+    /// The returned [`broadcast::Receiver`] lags, if it's not read fast
+    /// enough.
+    #[must_use]
+    pub fn completed_contracts(&self) -> broadcast::Receiver<Contract> {
+        self.completed_contracts.subscribe()
+    }
+
+    /// Subscribes to the [`Arrears`] newly detected or grown by the
+    /// [`task::DetectRentArrears`].
+    ///
+    /// The returned [`broadcast::Receiver`] lags, if it's not read fast
+    /// enough.
+    #[must_use]
+    pub fn arrears(&self) -> broadcast::Receiver<Arrears> {
+        self.arrears.subscribe()
+    }
+
+    /// Subscribes to the expiring [`Insurance`]s notified about by the
+    /// [`task::NotifyExpiringInsurance`].
+    ///
+    /// The returned [`broadcast::Receiver`] lags, if it's not read fast
+    /// enough.
+    ///
+    /// [`Insurance`]: domain::Insurance
+    #[must_use]
+    pub fn expiring_insurances(
+        &self,
+    ) -> broadcast::Receiver<read::insurance::Expiring> {
+        self.expiring_insurances.subscribe()
+    }
+
+    /// Subscribes to the [`read::saved_search::Matched`]es alerted about by
+    /// the [`task::AlertSavedSearches`].
+    ///
+    /// The returned [`broadcast::Receiver`] lags, if it's not read fast
+    /// enough.
+    #[must_use]
+    pub fn saved_search_matches(
+        &self,
+    ) -> broadcast::Receiver<read::saved_search::Matched> {
+        self.saved_search_matches.subscribe()
+    }
+
+    /// Returns the sitemap XML of the [`read::Placement`]s of the
+    /// [`domain::Agency`] with the provided ID, generated by the
+    /// [`task::GenerateSitemaps`].
+    ///
+    /// [`None`] is returned until the sitemaps are generated for the first
+    /// time.
+    #[must_use]
+    pub fn sitemap(&self, agency_id: domain::agency::Id) -> Option<Arc<str>> {
+        let sitemaps = self.sitemaps.borrow();
+        sitemaps.generated_at?;
+        let sitemap = sitemaps.documents.get(&agency_id).cloned();
+        Some(sitemap.unwrap_or_else(|| {
+            let base_url = &self.generate_sitemaps.borrow().base_url;
+            domain::sitemap::to_xml(base_url, &[]).into()
+        }))
+    }
+
+    /// Marks the [`read::Placement`]s as changed, invalidating their pages in
+    /// the [`Config::cache`], so the [`task::GenerateSitemaps`] regenerates
+    /// the sitemaps on its next run.
+    pub(crate) async fn placements_changed(&self) {
+        self.invalidate_cache_prefixed(cache::key::PLACEMENTS).await;
+        _ = self.placements_changed_at.send_replace(DateTime::now());
+    }
+
+    /// Subscribes to the [`Offer`]s being submitted, accepted or declined.
+    ///
+    /// The returned [`broadcast::Receiver`] lags, if it's not read fast
+    /// enough.
+    #[must_use]
+    pub fn offers(&self) -> broadcast::Receiver<Offer> {
+        self.offers.subscribe()
+    }
+
     /// Returns [`Database`] of this [`Service`].
     #[must_use]
     pub fn database(&self) -> &Db {
         &self.database
     }
+
+    /// Returns a copy of this [`Service`] executing its operations on the
+    /// provided [`Database`], while sharing the [`Task`]s and the channels
+    /// with this [`Service`].
+    ///
+    /// Allows to use differently configured [`Database`]s for serving
+    /// requests and for running the background [`Task`]s.
+    #[must_use]
+    pub fn with_database<D>(&self, database: D) -> Service<D> {
+        Service {
+            database,
+            config: self.config.clone(),
+            alert_saved_searches: self.alert_saved_searches.clone(),
+            saved_search_matches: self.saved_search_matches.clone(),
+            clean_unused_realties: self.clean_unused_realties.clone(),
+            complete_expired_contracts: self.complete_expired_contracts.clone(),
+            completed_contracts: self.completed_contracts.clone(),
+            database_healthcheck: self.database_healthcheck.clone(),
+            database_status: self.database_status.clone(),
+            detect_rent_arrears: self.detect_rent_arrears.clone(),
+            arrears: self.arrears.clone(),
+            export_journal: self.export_journal.clone(),
+            generate_sitemaps: self.generate_sitemaps.clone(),
+            sitemaps: self.sitemaps.clone(),
+            placements_changed_at: self.placements_changed_at.clone(),
+            index_search: self.index_search.clone(),
+            notify_expiring_insurance: self.notify_expiring_insurance.clone(),
+            expiring_insurances: self.expiring_insurances.clone(),
+            offers: self.offers.clone(),
+            refresh_total_counts: self.refresh_total_counts.clone(),
+            total_counts: self.total_counts.clone(),
+            retention_purge: self.retention_purge.clone(),
+            retention_stats: self.retention_stats.clone(),
+            sync_listings: self.sync_listings.clone(),
+            task_statuses: self.task_statuses.clone(),
+            emails: self.emails.clone(),
+        }
+    }
+
+    /// Indicates whether the provided [`domain::user::Email`] accepts mail,
+    /// according to the [`Config::mx_check`], if any.
+    pub(crate) async fn accepts_mail(
+        &self,
+        email: &domain::user::Email,
+    ) -> bool {
+        match &self.config.mx_check {
+            Some(check) => check.accepts_mail(email.domain()).await,
+            None => true,
+        }
+    }
+
+    /// Returns [`domain::user::password::Rule`]s violated by the provided
+    /// [`domain::user::Password`], according to the
+    /// [`Config::password_policy`] and the [`Config::password_denylist`], if
+    /// any.
+    pub(crate) async fn password_violations(
+        &self,
+        password: &domain::user::Password,
+    ) -> Vec<domain::user::password::Rule> {
+        let mut violations = self.config.password_policy.violations(password);
+        if let Some(denylist) = &self.config.password_denylist {
+            if denylist.contains(password).await {
+                violations.push(domain::user::password::Rule::Breached);
+            }
+        }
+        violations
+    }
+
+    /// Notifies the [`Service::offers()`] subscribers about the provided
+    /// [`Offer`] change.
+    pub(crate) fn notify_offer(&self, offer: Offer) {
+        // No subscribers is not an error.
+        _ = self.offers.send(offer);
+    }
+
+    /// Enqueues an email rendered from the provided [`email::Template`] in
+    /// the provided [`domain::agency::Locale`] to be sent to the provided
+    /// [`domain::user::Email`] via the [`Config::mailer`], if any.
+    ///
+    /// Sending happens in background, so its failures are logged only.
+    pub(crate) fn send_email(
+        &self,
+        to: domain::user::Email,
+        locale: Option<domain::agency::Locale>,
+        template: &email::Template,
+    ) {
+        let Some(emails) = &self.emails else {
+            return;
+        };
+        match email::Message::render(
+            &self.config.templates,
+            locale,
+            to,
+            template,
+        ) {
+            Ok(message) => emails.push(message),
+            Err(e) => log::error!(
+                "Failed to render `{}` email: {e}",
+                template.name(),
+            ),
+        }
+    }
+
+    /// Sends an SMS rendered from the provided [`sms::Template`] in the
+    /// provided [`domain::agency::Locale`] to the provided
+    /// [`domain::user::Phone`] via the [`Config::sms_gateway`], if any,
+    /// recording its [`domain::sms::Delivery`].
+    ///
+    /// Errors are logged only, since SMS is a best-effort notification.
+    pub(crate) async fn send_sms(
+        &self,
+        to: domain::user::Phone,
+        locale: Option<domain::agency::Locale>,
+        template: sms::Template,
+    ) where
+        Db: Database<
+            Insert<domain::sms::Delivery>,
+            Err = Traced<database::Error>,
+        >,
+    {
+        let Some(gateway) = &self.config.sms_gateway else {
+            return;
+        };
+
+        let message = match sms::Message::render(
+            &self.config.templates,
+            locale,
+            to,
+            template,
+        ) {
+            Ok(m) => m,
+            Err(e) => {
+                log::error!("Failed to render `{}` SMS: {e}", template.name());
+                return;
+            }
+        };
+        let (provider_id, status) = match gateway.send(&message).await {
+            Ok(receipt) => (Some(receipt.provider_id), receipt.status),
+            Err(e) => {
+                log::error!("Failed to send SMS to `{}`: {e}", message.to);
+                (None, domain::sms::Status::Failed)
+            }
+        };
+        let now = common::DateTime::now();
+        let delivery = domain::sms::Delivery {
+            id: domain::sms::Id::new(),
+            phone: message.to,
+            provider_id,
+            status,
+            created_at: now.coerce(),
+            updated_at: now.coerce(),
+        };
+        if let Err(e) = self.database.execute(Insert(delivery)).await {
+            log::warn!("Failed to record SMS delivery: {e}");
+        }
+    }
+
+    /// Removes the values stored under the provided `keys` from the
+    /// [`Config::cache`], if any.
+    ///
+    /// Errors are logged only, since a stale value expires anyway after
+    /// [`Config::cache_ttl`].
+    pub(crate) async fn invalidate_cache(&self, keys: &[String]) {
+        if let Some(cache) = &self.config.cache {
+            if let Err(e) = cache.delete(keys).await {
+                log::warn!("Failed to invalidate {keys:?} cache keys: {e}");
+            }
+        }
+    }
+
+    /// Removes the values which keys start with the provided `prefix` from
+    /// the [`Config::cache`], if any.
+    ///
+    /// Errors are logged only, since a stale value expires anyway after
+    /// [`Config::cache_ttl`].
+    pub(crate) async fn invalidate_cache_prefixed(&self, prefix: &str) {
+        if let Some(cache) = &self.config.cache {
+            if let Err(e) = cache.delete_prefixed(prefix).await {
+                log::warn!("Failed to invalidate `{prefix}*` cache keys: {e}");
+            }
+        }
+    }
 }
 
+/// Capacity of the [`Service::completed_contracts()`] channel.
+const COMPLETED_CONTRACTS_CAPACITY: usize = 256;
+
+/// Capacity of the [`Service::arrears()`] channel.
+const ARREARS_CAPACITY: usize = 256;
+
+/// Capacity of the [`Service::expiring_insurances()`] channel.
+const EXPIRING_INSURANCES_CAPACITY: usize = 256;
+
+/// Capacity of the [`Service::offers()`] channel.
+const OFFERS_CAPACITY: usize = 256;
+
+/// Capacity of the [`Service::saved_search_matches()`] channel.
+const SAVED_SEARCH_MATCHES_CAPACITY: usize = 256;
+
 /// Shortcut for the error of starting a [`Task`].
 type TaskStartError<Svc, T, Args> = <Svc as Task<Start<By<T, Args>>>>::Err;
 
@@ -121,20 +1028,499 @@ type TaskStartError<Svc, T, Args> = <Svc as Task<Start<By<T, Args>>>>::Err;
 pub enum StartupError<Svc>
 where
     Svc: Task<
+        Start<
+            By<
+                task::AlertSavedSearches<Svc>,
+                AlertSavedSearchesChannels,
+            >,
+        >,
+    > + Task<
         Start<
             By<
                 task::CleanUnusedRealties<Svc>,
-                task::clean_unused_realties::Config,
+                CleanUnusedRealtiesChannels,
             >,
         >,
-    >,
+    > + Task<
+        Start<
+            By<
+                task::CompleteExpiredContracts<Svc>,
+                CompleteExpiredContractsChannels,
+            >,
+        >,
+    > + Task<
+        Start<
+            By<
+                task::DatabaseHealthcheck<Svc>,
+                DatabaseHealthcheckChannels,
+            >,
+        >,
+    > + Task<
+        Start<
+            By<
+                task::DetectRentArrears<Svc>,
+                DetectRentArrearsChannels,
+            >,
+        >,
+    > + Task<
+        Start<By<task::ExportJournal<Svc>, ExportJournalChannels>>,
+    > + Task<
+        Start<By<task::GenerateSitemaps<Svc>, GenerateSitemapsChannels>>,
+    > + Task<
+        Start<
+            By<
+                task::IndexSearch<Svc>,
+                IndexSearchChannels,
+            >,
+        >,
+    > + Task<
+        Start<
+            By<
+                task::NotifyExpiringInsurance<Svc>,
+                NotifyExpiringInsuranceChannels,
+            >,
+        >,
+    > + Task<
+        Start<
+            By<task::RefreshTotalCounts<Svc>, RefreshTotalCountsChannels>,
+        >,
+    > + Task<
+        Start<By<task::RetentionPurge<Svc>, RetentionPurgeChannels>>,
+    > + Task<Start<By<task::SyncListings<Svc>, SyncListingsChannels>>>,
 {
+    /// [`task::AlertSavedSearches`] failed to start.
+    AlertSavedSearchesTask(
+        TaskStartError<
+            Svc,
+            task::AlertSavedSearches<Svc>,
+            AlertSavedSearchesChannels,
+        >,
+    ),
+
     /// [`task::CleanUnusedRealties`] failed to start.
     CleanUnusedRealtiesTask(
         TaskStartError<
             Svc,
             task::CleanUnusedRealties<Svc>,
-            task::clean_unused_realties::Config,
+            CleanUnusedRealtiesChannels,
+        >,
+    ),
+
+    /// [`task::CompleteExpiredContracts`] failed to start.
+    CompleteExpiredContractsTask(
+        TaskStartError<
+            Svc,
+            task::CompleteExpiredContracts<Svc>,
+            CompleteExpiredContractsChannels,
+        >,
+    ),
+
+    /// [`task::DatabaseHealthcheck`] failed to start.
+    DatabaseHealthcheckTask(
+        TaskStartError<
+            Svc,
+            task::DatabaseHealthcheck<Svc>,
+            DatabaseHealthcheckChannels,
+        >,
+    ),
+
+    /// [`task::DetectRentArrears`] failed to start.
+    DetectRentArrearsTask(
+        TaskStartError<
+            Svc,
+            task::DetectRentArrears<Svc>,
+            DetectRentArrearsChannels,
+        >,
+    ),
+
+    /// [`task::ExportJournal`] failed to start.
+    ExportJournalTask(
+        TaskStartError<
+            Svc,
+            task::ExportJournal<Svc>,
+            ExportJournalChannels,
+        >,
+    ),
+
+    /// [`task::GenerateSitemaps`] failed to start.
+    GenerateSitemapsTask(
+        TaskStartError<
+            Svc,
+            task::GenerateSitemaps<Svc>,
+            GenerateSitemapsChannels,
+        >,
+    ),
+
+    /// [`task::IndexSearch`] failed to start.
+    IndexSearchTask(
+        TaskStartError<
+            Svc,
+            task::IndexSearch<Svc>,
+            IndexSearchChannels,
+        >,
+    ),
+
+    /// [`task::NotifyExpiringInsurance`] failed to start.
+    NotifyExpiringInsuranceTask(
+        TaskStartError<
+            Svc,
+            task::NotifyExpiringInsurance<Svc>,
+            NotifyExpiringInsuranceChannels,
+        >,
+    ),
+
+    /// [`task::RefreshTotalCounts`] failed to start.
+    RefreshTotalCountsTask(
+        TaskStartError<
+            Svc,
+            task::RefreshTotalCounts<Svc>,
+            RefreshTotalCountsChannels,
+        >,
+    ),
+
+    /// [`task::RetentionPurge`] failed to start.
+    RetentionPurgeTask(
+        TaskStartError<
+            Svc,
+            task::RetentionPurge<Svc>,
+            RetentionPurgeChannels,
         >,
     ),
+
+    /// [`task::SyncListings`] failed to start.
+    SyncListingsTask(
+        TaskStartError<Svc, task::SyncListings<Svc>, SyncListingsChannels>,
+    ),
+}
+
+/// Shortcut for the channels a [`task::AlertSavedSearches`] is started with.
+type AlertSavedSearchesChannels = (
+    watch::Receiver<task::alert_saved_searches::Config>,
+    broadcast::Sender<read::saved_search::Matched>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::CleanUnusedRealties`] is started with.
+type CleanUnusedRealtiesChannels = (
+    watch::Receiver<task::clean_unused_realties::Config>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::CompleteExpiredContracts`] is started
+/// with.
+type CompleteExpiredContractsChannels = (
+    watch::Receiver<task::complete_expired_contracts::Config>,
+    broadcast::Sender<Contract>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::DatabaseHealthcheck`] is started with.
+type DatabaseHealthcheckChannels = (
+    watch::Receiver<task::database_healthcheck::Config>,
+    watch::Sender<task::database_healthcheck::Status>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::DetectRentArrears`] is started with.
+type DetectRentArrearsChannels = (
+    watch::Receiver<task::detect_rent_arrears::Config>,
+    broadcast::Sender<Arrears>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::ExportJournal`] is started with.
+type ExportJournalChannels = (
+    watch::Receiver<task::export_journal::Config>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::GenerateSitemaps`] is started with.
+type GenerateSitemapsChannels = (
+    watch::Receiver<task::generate_sitemaps::Config>,
+    watch::Sender<task::generate_sitemaps::Sitemaps>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::IndexSearch`] is started with.
+type IndexSearchChannels = (
+    watch::Receiver<task::index_search::Config>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::NotifyExpiringInsurance`] is started
+/// with.
+type NotifyExpiringInsuranceChannels = (
+    watch::Receiver<task::notify_expiring_insurance::Config>,
+    broadcast::Sender<read::insurance::Expiring>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::RefreshTotalCounts`] is started with.
+type RefreshTotalCountsChannels = (
+    watch::Receiver<task::refresh_total_counts::Config>,
+    watch::Sender<task::refresh_total_counts::TotalCounts>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::RetentionPurge`] is started with.
+type RetentionPurgeChannels = (
+    watch::Receiver<task::retention_purge::Config>,
+    watch::Sender<task::retention_purge::Stats>,
+    CancellationToken,
+);
+
+/// Shortcut for the channels a [`task::SyncListings`] is started with.
+type SyncListingsChannels = (
+    watch::Receiver<task::sync_listings::Config>,
+    CancellationToken,
+);
+
+#[cfg(test)]
+pub(crate) mod spec {
+    //! Helpers for the specs of the [`Service`] operations.
+
+    use std::{
+        sync::{Arc, Mutex, PoisonError},
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use common::DateTime;
+    use tokio::sync::{broadcast, watch};
+    use tracerr::Traced;
+
+    use crate::{
+        domain,
+        infra::{cache, email, template, Cache},
+        read, task, Config, Service,
+    };
+
+    /// [`Cache`] storing nothing, but recording the keys removed from it.
+    #[derive(Debug, Default)]
+    pub(crate) struct RecordingCache {
+        /// Keys removed via [`Cache::delete()`].
+        deleted: Mutex<Vec<String>>,
+
+        /// Prefixes removed via [`Cache::delete_prefixed()`].
+        deleted_prefixes: Mutex<Vec<String>>,
+    }
+
+    impl RecordingCache {
+        /// Returns the keys removed from this [`RecordingCache`] so far.
+        pub(crate) fn deleted(&self) -> Vec<String> {
+            self.deleted
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+
+        /// Returns the key prefixes removed from this [`RecordingCache`] so
+        /// far.
+        pub(crate) fn deleted_prefixes(&self) -> Vec<String> {
+            self.deleted_prefixes
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+    }
+
+    #[async_trait]
+    impl Cache for RecordingCache {
+        async fn get(
+            &self,
+            _: &str,
+        ) -> Result<Option<Vec<u8>>, Traced<cache::Error>> {
+            Ok(None)
+        }
+
+        async fn set(
+            &self,
+            _: &str,
+            _: Vec<u8>,
+            _: Duration,
+        ) -> Result<(), Traced<cache::Error>> {
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            keys: &[String],
+        ) -> Result<(), Traced<cache::Error>> {
+            self.deleted
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .extend_from_slice(keys);
+            Ok(())
+        }
+
+        async fn delete_prefixed(
+            &self,
+            prefix: &str,
+        ) -> Result<(), Traced<cache::Error>> {
+            self.deleted_prefixes
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(prefix.to_owned());
+            Ok(())
+        }
+    }
+
+    /// Creates a [`Service`] on top of the provided `database` and `cache`,
+    /// without starting its [`Task`]s.
+    ///
+    /// [`Task`]: crate::Task
+    pub(crate) fn service<Db>(
+        database: Db,
+        cache: Arc<dyn Cache>,
+    ) -> Service<Db> {
+        let schedule = || task::Schedule::every(Duration::from_secs(60 * 60));
+        let config = Config {
+            jwt_encoding_key: jsonwebtoken::EncodingKey::from_secret(b"spec"),
+            jwt_decoding_key: jsonwebtoken::DecodingKey::from_secret(b"spec"),
+            alert_saved_searches: task::alert_saved_searches::Config {
+                schedule: schedule(),
+            },
+            clean_unused_realties: task::clean_unused_realties::Config {
+                schedule: schedule(),
+                timeout: Duration::from_secs(60),
+                settings: None,
+            },
+            complete_expired_contracts:
+                task::complete_expired_contracts::Config {
+                    schedule: schedule(),
+                },
+            database_healthcheck: task::database_healthcheck::Config {
+                schedule: schedule(),
+                timeout: Duration::from_secs(60),
+            },
+            detect_rent_arrears: task::detect_rent_arrears::Config {
+                schedule: schedule(),
+                grace_period: Duration::ZERO,
+            },
+            export_journal: task::export_journal::Config {
+                schedule: schedule(),
+            },
+            generate_sitemaps: task::generate_sitemaps::Config {
+                schedule: schedule(),
+                max_age: Duration::from_secs(60 * 60),
+                base_url: "https://example.com".to_owned(),
+            },
+            index_search: task::index_search::Config {
+                schedule: schedule(),
+            },
+            notify_expiring_insurance:
+                task::notify_expiring_insurance::Config {
+                    schedule: schedule(),
+                    notice_period: Duration::ZERO,
+                },
+            refresh_total_counts: task::refresh_total_counts::Config {
+                schedule: schedule(),
+            },
+            retention_purge: task::retention_purge::Config {
+                schedule: schedule(),
+                dry_run: false,
+                policies: task::retention_purge::Policies::default(),
+            },
+            sync_listings: task::sync_listings::Config {
+                schedule: schedule(),
+            },
+            task_restart: task::Restart::default(),
+            estimated_count_threshold: 0,
+            approval_threshold: None,
+            invite_url: "https://example.com/invite".to_owned(),
+            cache: Some(cache),
+            cache_ttl: Duration::from_secs(60),
+            search: None,
+            search_ranking: read::search::Ranking::default(),
+            mx_check: None,
+            password_policy: domain::user::password::Policy::default(),
+            password_denylist: None,
+            accounting: None,
+            mailer: None,
+            email_retry: email::Retry::default(),
+            sms_gateway: None,
+            templates: Arc::new(template::Engine::default()),
+            storage: None,
+            url_signer: None,
+            listing_feeds: vec![],
+        };
+        Service {
+            alert_saved_searches: Arc::new(watch::Sender::new(
+                config.alert_saved_searches.clone(),
+            )),
+            saved_search_matches: broadcast::channel(1).0,
+            clean_unused_realties: Arc::new(watch::Sender::new(
+                config.clean_unused_realties.clone(),
+            )),
+            complete_expired_contracts: Arc::new(watch::Sender::new(
+                config.complete_expired_contracts.clone(),
+            )),
+            completed_contracts: broadcast::channel(1).0,
+            database_healthcheck: Arc::new(watch::Sender::new(
+                config.database_healthcheck.clone(),
+            )),
+            database_status: watch::channel(
+                task::database_healthcheck::Status::default(),
+            )
+            .1,
+            detect_rent_arrears: Arc::new(watch::Sender::new(
+                config.detect_rent_arrears.clone(),
+            )),
+            arrears: broadcast::channel(1).0,
+            export_journal: Arc::new(watch::Sender::new(
+                config.export_journal.clone(),
+            )),
+            generate_sitemaps: Arc::new(watch::Sender::new(
+                config.generate_sitemaps.clone(),
+            )),
+            sitemaps: watch::channel(
+                task::generate_sitemaps::Sitemaps::default(),
+            )
+            .1,
+            placements_changed_at: Arc::new(watch::Sender::new(
+                DateTime::now(),
+            )),
+            index_search: Arc::new(watch::Sender::new(
+                config.index_search.clone(),
+            )),
+            notify_expiring_insurance: Arc::new(watch::Sender::new(
+                config.notify_expiring_insurance.clone(),
+            )),
+            expiring_insurances: broadcast::channel(1).0,
+            offers: broadcast::channel(1).0,
+            refresh_total_counts: Arc::new(watch::Sender::new(
+                config.refresh_total_counts.clone(),
+            )),
+            total_counts: watch::channel(
+                task::refresh_total_counts::TotalCounts::default(),
+            )
+            .1,
+            retention_purge: Arc::new(watch::Sender::new(
+                config.retention_purge.clone(),
+            )),
+            retention_stats: watch::channel(
+                task::retention_purge::Stats::default(),
+            )
+            .1,
+            sync_listings: Arc::new(watch::Sender::new(
+                config.sync_listings.clone(),
+            )),
+            task_statuses: Arc::new(watch::Sender::new(task::Statuses::new())),
+            emails: None,
+            config,
+            database,
+        }
+    }
+
+    #[test]
+    fn placements_changed_invalidates_cache() {
+        let recorder = Arc::new(RecordingCache::default());
+        let service = service((), recorder.clone());
+        let mut changed_at = service.placements_changed_at.subscribe();
+
+        futures::executor::block_on(service.placements_changed());
+
+        assert_eq!(recorder.deleted_prefixes(), [cache::key::PLACEMENTS]);
+        assert!(changed_at.has_changed().unwrap());
+    }
 }
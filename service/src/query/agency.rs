@@ -0,0 +1,15 @@
+//! [`Query`] collection related to a single [`Agency`].
+
+use common::operations::By;
+
+use crate::domain::{agency, Agency};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries an [`Agency`] by its [`agency::Id`].
+pub type ById = DatabaseQuery<By<Option<Agency>, agency::Id>>;
+
+/// Queries [`agency::Settings`] of an [`Agency`] by its [`agency::Id`].
+pub type SettingsById = DatabaseQuery<By<Option<agency::Settings>, agency::Id>>;
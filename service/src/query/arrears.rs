@@ -0,0 +1,13 @@
+//! [`Query`] collection related to [`Arrears`].
+
+use common::operations::By;
+
+use crate::domain::{contract, Arrears};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries the current [`Arrears`] of a [`contract::Rent`], if it's in
+/// arrears.
+pub type ByContract = DatabaseQuery<By<Option<Arrears>, contract::Id>>;
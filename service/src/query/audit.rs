@@ -0,0 +1,13 @@
+//! [`Query`] collection related to the audit log.
+
+use common::operations::By;
+
+use crate::read;
+#[cfg(doc)]
+use crate::{domain::audit, Query};
+
+use super::DatabaseQuery;
+
+/// Queries a list of [`audit::Entry`]s.
+pub type List =
+    DatabaseQuery<By<read::audit::list::Page, read::audit::list::Selector>>;
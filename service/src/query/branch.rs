@@ -0,0 +1,16 @@
+//! [`Query`] collection related to [`Branch`]es.
+
+use common::operations::By;
+
+use crate::domain::{agency, branch, Branch};
+#[cfg(doc)]
+use crate::{domain::Agency, Query};
+
+use super::DatabaseQuery;
+
+/// Queries a [`Branch`] by its [`branch::Id`].
+pub type ById = DatabaseQuery<By<Option<Branch>, branch::Id>>;
+
+/// Queries all the [`Branch`]es of an [`Agency`], ordered by their
+/// [`branch::Name`]s.
+pub type ByAgency = DatabaseQuery<By<Vec<Branch>, agency::Id>>;
@@ -0,0 +1,13 @@
+//! [`Query`] collection related to [`Charge`]s.
+
+use common::operations::By;
+
+use crate::domain::{contract, Charge};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries all the [`Charge`]s billed under a [`contract::Rent`], in the
+/// order they were added.
+pub type ByContract = DatabaseQuery<By<Vec<Charge>, contract::Id>>;
@@ -3,23 +3,51 @@
 use common::operations::By;
 
 use crate::{
-    domain::{contract, realty, user, Contract},
-    read::contract::Active,
+    domain::{agency, contract, realty, user, Contract},
+    infra::cache,
+    read::{
+        self,
+        contract::{Active, DirectReports},
+    },
 };
 #[cfg(doc)]
 use crate::{
-    domain::{Realty, User},
+    domain::{Agency, Realty, User},
+    infra::Cache,
     Query,
 };
 
-use super::DatabaseQuery;
+use super::{CacheKey, CachedDatabaseQuery, DatabaseQuery};
+
+/// Queries the active [`contract::Rent`]s of a [`Realty`], in the order of
+/// their creation.
+pub type ActiveRents =
+    DatabaseQuery<By<Vec<Active<contract::Rent>>, realty::Id>>;
+
+/// Queries the active [`contract::ShortTermRent`]s of a [`Realty`], in the
+/// order of their check-in.
+pub type ActiveShortTermRents =
+    DatabaseQuery<By<Vec<Active<contract::ShortTermRent>>, realty::Id>>;
+
+/// Queries a [`contract::Approval`] of a [`Contract`], if any.
+pub type Approval = DatabaseQuery<By<Option<contract::Approval>, contract::Id>>;
 
 /// Queries a [`Contract`] by its [`contract::Id`].
 pub type ById = DatabaseQuery<By<Option<Contract>, contract::Id>>;
 
+/// Queries a [`Contract`] by its [`contract::Number`] in an [`Agency`].
+pub type ByNumber =
+    DatabaseQuery<By<Option<Contract>, read::contract::ByNumber>>;
+
+/// Queries the [`contract::commission::Split`]s of a [`Contract`].
+pub type CommissionSplits =
+    DatabaseQuery<By<Vec<contract::commission::Split>, contract::Id>>;
+
 /// Queries an active [`contract::Employment`] by ID of the employed [`User`].
+///
+/// Results are stored in the [`Cache`].
 pub type Employment =
-    DatabaseQuery<By<Option<Active<contract::Employment>>, user::Id>>;
+    CachedDatabaseQuery<By<Option<Active<contract::Employment>>, user::Id>>;
 
 /// Queries an active [`contract::ManagementForRent`] by ID of the related
 /// [`Realty`].
@@ -30,3 +58,29 @@ pub type ManagementForRent =
 /// [`Realty`].
 pub type ManagementForSale =
     DatabaseQuery<By<Option<Active<contract::ManagementForSale>>, realty::Id>>;
+
+/// Queries all the [`contract::Party`]s of a [`Contract`].
+pub type Parties = DatabaseQuery<By<Vec<contract::Party>, contract::Id>>;
+
+/// Queries the pending [`contract::Approval`]s of an [`Agency`].
+pub type PendingApprovals = DatabaseQuery<
+    By<Vec<contract::Approval>, read::contract::PendingApprovals>,
+>;
+
+/// Queries active [`contract::Employment`]s of the [`User`]s reporting
+/// directly to the specified one.
+pub type Team =
+    DatabaseQuery<By<Vec<Active<contract::Employment>>, DirectReports>>;
+
+/// Queries a [`contract::Template`] by its [`contract::template::Id`].
+pub type TemplateById =
+    DatabaseQuery<By<Option<contract::Template>, contract::template::Id>>;
+
+/// Queries all the [`contract::Template`]s of an [`Agency`].
+pub type Templates = DatabaseQuery<By<Vec<contract::Template>, agency::Id>>;
+
+impl CacheKey<Option<Active<contract::Employment>>> for user::Id {
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::key::employment(*self))
+    }
+}
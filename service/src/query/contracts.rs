@@ -2,16 +2,61 @@
 
 use common::operations::By;
 
-use crate::read;
+use crate::{
+    domain::{agency, Contract},
+    read,
+    task::refresh_total_counts::TotalCounts,
+};
 #[cfg(doc)]
-use crate::{domain::Contract, Query};
+use crate::{
+    domain::{contract, Agency},
+    Query,
+};
 
-use super::DatabaseQuery;
+use super::{CachedTotalCount, DatabaseQuery, TotalCountQuery};
 
 /// Queries a list of [`Contract`]s.
 pub type List = DatabaseQuery<
     By<read::contract::list::Page, read::contract::list::Selector>,
 >;
 
-/// Queries total count of [`Contract`]s.
-pub type TotalCount = DatabaseQuery<By<read::contract::list::TotalCount, ()>>;
+/// Queries numbers of the [`Contract`]s of an [`Agency`], grouped by their
+/// [`contract::Status`] and [`contract::Kind`].
+pub type CountsByStatusAndKind =
+    DatabaseQuery<By<read::contract::Counts, agency::Id>>;
+
+/// Queries the active [`Contract`]s a [`User`] takes part in, expiring since
+/// some [`DateTime`].
+///
+/// [`DateTime`]: common::DateTime
+/// [`User`]: crate::domain::User
+pub type Expiring = DatabaseQuery<By<Vec<Contract>, read::contract::Expiring>>;
+
+/// Queries total count of [`Contract`]s matching a
+/// [`read::contract::list::Filter`].
+pub type TotalCount = TotalCountQuery<
+    read::contract::list::TotalCount,
+    read::contract::list::Filter,
+>;
+
+impl CachedTotalCount for read::contract::list::TotalCount {
+    type Filter = read::contract::list::Filter;
+
+    fn cached(counts: &TotalCounts, filter: &Self::Filter) -> Option<Self> {
+        // Only the non-archived `Contract`s of an `Agency` are cached.
+        let read::contract::list::Filter {
+            agency_id: Some(agency_id),
+            branch_id: None,
+            name: None,
+            include_archived: false,
+            sort_by: _,
+        } = filter
+        else {
+            return None;
+        };
+        counts
+            .contracts
+            .as_ref()
+            .map(|c| c.get(agency_id).copied().unwrap_or(Self::from(0)))
+    }
+}
@@ -0,0 +1,7 @@
+//! [`Query`] collection of financial calculations.
+//!
+//! [`Query`]: crate::Query
+
+pub mod mortgage;
+
+pub use self::mortgage::Mortgage;
@@ -0,0 +1,304 @@
+//! [`Mortgage`] definition.
+
+use common::{Money, Percent};
+use derive_more::{Display, Error};
+use rust_decimal::{Decimal, RoundingStrategy};
+use tracerr::Traced;
+
+use crate::{Query, Service};
+
+/// [`Query`] to calculate an annuity mortgage: its monthly payment and
+/// amortization schedule.
+///
+/// Doesn't touch any infrastructure, so can be executed by any [`Service`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mortgage {
+    /// Borrowed amount.
+    pub principal: Money,
+
+    /// Annual interest rate.
+    pub rate: Percent,
+
+    /// Term of the [`Mortgage`] in months.
+    pub term_months: u16,
+}
+
+impl Mortgage {
+    /// Maximum supported term of a [`Mortgage`] in months (50 years).
+    pub const MAX_TERM_MONTHS: u16 = 600;
+}
+
+/// Output of the [`Mortgage`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// Regular monthly payment.
+    ///
+    /// The last [`Installment`] may slightly differ due to rounding.
+    pub monthly_payment: Money,
+
+    /// Total amount paid over the whole term.
+    pub total_payment: Money,
+
+    /// Total interest paid over the whole term.
+    pub total_interest: Money,
+
+    /// Amortization schedule, one [`Installment`] per month.
+    pub schedule: Vec<Installment>,
+}
+
+/// Monthly installment in the amortization schedule of a [`Mortgage`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Installment {
+    /// Number of the month, starting from `1`.
+    pub month: u16,
+
+    /// Amount paid in this month.
+    pub payment: Money,
+
+    /// Part of the [`Installment::payment`] repaying the principal.
+    pub principal: Money,
+
+    /// Part of the [`Installment::payment`] paying the interest.
+    pub interest: Money,
+
+    /// Principal remaining after this [`Installment`].
+    pub balance: Money,
+}
+
+impl<Db> Query<Mortgage> for Service<Db> {
+    type Ok = Output;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, query: Mortgage) -> Result<Self::Ok, Self::Err> {
+        query.calculate().map_err(tracerr::wrap!())
+    }
+}
+
+impl Mortgage {
+    /// Calculates the [`Output`] of this [`Mortgage`].
+    fn calculate(self) -> Result<Output, ExecutionError> {
+        use ExecutionError as E;
+
+        let Self {
+            principal,
+            rate,
+            term_months,
+        } = self;
+
+        if principal.amount.is_sign_negative() {
+            return Err(E::NegativePrincipal);
+        }
+        if term_months == 0 || term_months > Self::MAX_TERM_MONTHS {
+            return Err(E::TermOutOfRange(term_months));
+        }
+
+        let money = |amount| Money {
+            amount: round(amount),
+            currency: principal.currency,
+        };
+
+        let monthly_rate = rate.ratio() / Decimal::from(12);
+        let months = Decimal::from(term_months);
+        let payment = if monthly_rate.is_zero() {
+            principal.amount / months
+        } else {
+            // Annuity formula: `P * r / (1 - (1 + r)^-n)`, arranged as
+            // `P * r * g / (g - 1)`, where `g = (1 + r)^n`, dividing first to
+            // keep intermediate values in range.
+            let step =
+                Decimal::ONE.checked_add(monthly_rate).ok_or(E::Overflow)?;
+            let growth = (0..term_months)
+                .try_fold(Decimal::ONE, |acc, _| acc.checked_mul(step))
+                .ok_or(E::Overflow)?;
+            growth
+                .checked_sub(Decimal::ONE)
+                .and_then(|g| growth.checked_div(g))
+                .and_then(|f| f.checked_mul(monthly_rate))
+                .and_then(|f| f.checked_mul(principal.amount))
+                .ok_or(E::Overflow)?
+        };
+        let payment = round(payment);
+
+        let mut balance = principal.amount;
+        let mut schedule = Vec::with_capacity(term_months.into());
+        for month in 1..=term_months {
+            let interest =
+                round(balance.checked_mul(monthly_rate).ok_or(E::Overflow)?);
+            let repaid = if month == term_months {
+                balance
+            } else {
+                payment
+                    .checked_sub(interest)
+                    .ok_or(E::Overflow)?
+                    .min(balance)
+            };
+            balance = balance.checked_sub(repaid).ok_or(E::Overflow)?;
+            schedule.push(Installment {
+                month,
+                payment: money(
+                    repaid.checked_add(interest).ok_or(E::Overflow)?,
+                ),
+                principal: money(repaid),
+                interest: money(interest),
+                balance: money(balance),
+            });
+        }
+
+        let total_payment = schedule
+            .iter()
+            .try_fold(Decimal::ZERO, |sum, i| sum.checked_add(i.payment.amount))
+            .ok_or(E::Overflow)?;
+        let total_interest = total_payment
+            .checked_sub(principal.amount)
+            .ok_or(E::Overflow)?;
+        Ok(Output {
+            monthly_payment: money(payment),
+            total_payment: money(total_payment),
+            total_interest: money(total_interest),
+            schedule,
+        })
+    }
+}
+
+/// Rounds the provided `amount` to cents.
+fn round(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+}
+
+/// Error of the [`Mortgage`] [`Query`] execution.
+#[derive(Clone, Copy, Debug, Display, Error, Eq, PartialEq)]
+pub enum ExecutionError {
+    /// Principal of the [`Mortgage`] is negative.
+    #[display("`Mortgage` principal cannot be negative")]
+    NegativePrincipal,
+
+    /// Term of the [`Mortgage`] is out of the supported range.
+    #[display(
+        "`Mortgage` term of {_0} months is out of `1..={}` range",
+        Mortgage::MAX_TERM_MONTHS
+    )]
+    TermOutOfRange(#[error(not(source))] u16),
+
+    /// Calculation overflowed the supported precision.
+    #[display("`Mortgage` calculation overflowed")]
+    Overflow,
+}
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, Money, Percent};
+    use rust_decimal::Decimal;
+
+    use super::{ExecutionError, Mortgage};
+
+    fn usd(s: &str) -> Money {
+        Money {
+            amount: s.parse().unwrap(),
+            currency: Currency::Usd,
+        }
+    }
+
+    fn percent(s: &str) -> Percent {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn calculates_annuity() {
+        let output = Mortgage {
+            principal: usd("200000"),
+            rate: percent("6"),
+            term_months: 360,
+        }
+        .calculate()
+        .unwrap();
+
+        assert_eq!(output.monthly_payment, usd("1199.10"));
+        assert_eq!(output.schedule.len(), 360);
+        assert_eq!(output.schedule[0].interest, usd("1000.00"));
+        assert_eq!(output.schedule[0].principal, usd("199.10"));
+        assert_eq!(
+            output.schedule.last().unwrap().balance.amount,
+            Decimal::ZERO
+        );
+        assert_eq!(
+            output.total_interest.amount,
+            output.total_payment.amount - Decimal::from(200_000),
+        );
+    }
+
+    #[test]
+    fn calculates_zero_rate() {
+        let output = Mortgage {
+            principal: usd("1200"),
+            rate: Percent::ZERO,
+            term_months: 12,
+        }
+        .calculate()
+        .unwrap();
+
+        assert_eq!(output.monthly_payment, usd("100.00"));
+        assert_eq!(output.total_interest, usd("0.00"));
+        assert!(output.schedule.iter().all(|i| i.payment == usd("100.00")));
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let mortgage = Mortgage {
+            principal: usd("1000"),
+            rate: percent("5"),
+            term_months: 0,
+        };
+
+        assert_eq!(
+            mortgage.calculate(),
+            Err(ExecutionError::TermOutOfRange(0))
+        );
+        assert_eq!(
+            Mortgage {
+                term_months: 601,
+                ..mortgage
+            }
+            .calculate(),
+            Err(ExecutionError::TermOutOfRange(601)),
+        );
+        assert_eq!(
+            Mortgage {
+                principal: usd("-1"),
+                term_months: 12,
+                ..mortgage
+            }
+            .calculate(),
+            Err(ExecutionError::NegativePrincipal),
+        );
+    }
+
+    #[test]
+    fn handles_extreme_input() {
+        let output = Mortgage {
+            principal: usd("10000000000"),
+            rate: percent("100"),
+            term_months: Mortgage::MAX_TERM_MONTHS,
+        }
+        .calculate()
+        .unwrap();
+
+        assert_eq!(output.schedule.len(), 600);
+        assert_eq!(
+            output.schedule.last().unwrap().balance.amount,
+            Decimal::ZERO,
+        );
+
+        assert_eq!(
+            Mortgage {
+                principal: Money {
+                    amount: Decimal::MAX,
+                    currency: Currency::Usd,
+                },
+                rate: percent("100"),
+                term_months: Mortgage::MAX_TERM_MONTHS,
+            }
+            .calculate(),
+            Err(ExecutionError::Overflow),
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! [`Query`] collection related to the infrastructure state.
+
+use common::operations::By;
+
+use crate::infra::database;
+#[cfg(doc)]
+use crate::{infra::Database, Query};
+
+use super::DatabaseQuery;
+
+/// Queries the current [`database::PoolStats`] of the [`Database`].
+pub type PoolStats = DatabaseQuery<By<database::PoolStats, ()>>;
@@ -0,0 +1,12 @@
+//! [`Query`] collection related to [`Insurance`]s.
+
+use common::operations::By;
+
+use crate::domain::{contract, Insurance};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries the [`Insurance`] attached to a contract, if any.
+pub type ByContract = DatabaseQuery<By<Option<Insurance>, contract::Id>>;
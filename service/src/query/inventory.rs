@@ -0,0 +1,13 @@
+//! [`Query`] collection related to [`Inventory`]s.
+
+use common::operations::By;
+
+use crate::domain::{contract, Inventory};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries all the [`Inventory`]s recorded under a [`contract::Rent`], the
+/// move-in one first.
+pub type ByContract = DatabaseQuery<By<Vec<Inventory>, contract::Id>>;
@@ -0,0 +1,22 @@
+//! [`Query`] collection related to [`Key`]s.
+
+use common::operations::By;
+
+use crate::{
+    domain::{realty, Key},
+    read,
+};
+#[cfg(doc)]
+use crate::{
+    domain::{Agency, Realty},
+    Query,
+};
+
+use super::DatabaseQuery;
+
+/// Queries all the [`Key`]s of a [`Realty`], in the order of their labels.
+pub type ByRealty = DatabaseQuery<By<Vec<Key>, realty::Id>>;
+
+/// Queries all the overdue [`Key`]s of an [`Agency`], the longest overdue
+/// first.
+pub type Overdue = DatabaseQuery<By<Vec<Key>, read::key::Overdue>>;
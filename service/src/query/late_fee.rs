@@ -0,0 +1,12 @@
+//! [`Query`] collection related to [`LateFee`]s.
+
+use common::operations::By;
+
+use crate::domain::{contract, LateFee};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries the [`LateFee`] charged under a [`contract::Rent`], if any.
+pub type ByContract = DatabaseQuery<By<Option<LateFee>, contract::Id>>;
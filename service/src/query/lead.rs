@@ -0,0 +1,15 @@
+//! [`Query`] collection related to a single [`Lead`].
+
+use common::operations::By;
+
+use crate::domain::{lead, Lead};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries a [`Lead`] by its [`lead::Id`].
+pub type ById = DatabaseQuery<By<Option<Lead>, lead::Id>>;
+
+/// Queries [`lead::Transition`]s of a [`Lead`] in their chronological order.
+pub type History = DatabaseQuery<By<Vec<lead::Transition>, lead::Id>>;
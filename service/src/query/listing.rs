@@ -0,0 +1,12 @@
+//! [`Query`] collection related to the external [`Listing`]s.
+
+use common::operations::By;
+
+#[cfg(doc)]
+use crate::{domain::Listing, Query};
+use crate::{domain::listing, read};
+
+use super::DatabaseQuery;
+
+/// Queries the latest [`listing::Sync`]s of the external [`Listing`] feeds.
+pub type Syncs = DatabaseQuery<By<Vec<listing::Sync>, read::listing::History>>;
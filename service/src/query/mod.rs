@@ -1,19 +1,50 @@
 //! [`Query`] definition.
 
+pub mod agency;
+pub mod arrears;
+pub mod audit;
+pub mod branch;
+pub mod charge;
 pub mod contract;
 pub mod contracts;
+pub mod finance;
+pub mod infra;
+pub mod insurance;
+pub mod inventory;
+pub mod key;
+pub mod late_fee;
+pub mod lead;
+pub mod leads;
+pub mod listing;
+pub mod offer;
+pub mod payment;
 pub mod placements;
 pub mod realties;
 pub mod realty;
 pub mod report;
+pub mod review;
+pub mod saved_search;
+pub mod search;
+pub mod task;
+pub mod template;
+pub mod trash;
 pub mod user;
 pub mod users;
 
+use std::marker::PhantomData;
+
 use common::operations::{By, Select};
+use serde::{de::DeserializeOwned, Serialize};
 use tracerr::Traced;
+use tracing as log;
+use uuid::Uuid;
 
+#[cfg(doc)]
+use crate::infra::{Cache, Search};
 use crate::{
-    infra::{database, Database},
+    infra::{database, search as search_engine, Database},
+    read,
+    task::refresh_total_counts::TotalCounts,
     Service,
 };
 
@@ -50,3 +81,255 @@ where
             .map_err(tracerr::wrap!())
     }
 }
+
+/// [`DatabaseQuery`] which results are stored in the [`Cache`] (if it's
+/// configured).
+#[derive(Clone, Copy, Debug)]
+pub struct CachedDatabaseQuery<T>(T);
+
+impl<W, B> CachedDatabaseQuery<By<W, B>> {
+    /// Creates a new [`CachedDatabaseQuery`] selecting a `W` by the provided
+    /// `B`.
+    #[must_use]
+    pub fn by(by: B) -> Self {
+        Self(By::new(by))
+    }
+}
+
+/// Selector of a `W` which result may be stored in the [`Cache`].
+pub trait CacheKey<W> {
+    /// Returns the [`Cache`] key of the `W` selected by this selector.
+    ///
+    /// [`None`] means that the result shouldn't be cached.
+    fn cache_key(&self) -> Option<String>;
+}
+
+impl<Db, W, B> Query<CachedDatabaseQuery<By<W, B>>> for Service<Db>
+where
+    Db: Database<Select<By<W, B>>, Ok = W, Err = Traced<database::Error>>,
+    W: DeserializeOwned + Serialize,
+    B: CacheKey<W>,
+{
+    type Ok = W;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        CachedDatabaseQuery(by): CachedDatabaseQuery<By<W, B>>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let by = by.into_inner();
+        let cache = self.config().cache.as_ref();
+        let Some((cache, key)) = cache.zip(by.cache_key()) else {
+            return self
+                .database()
+                .execute(Select(By::new(by)))
+                .await
+                .map_err(tracerr::wrap!());
+        };
+
+        match cache.get(&key).await {
+            Ok(Some(bytes)) => match serde_json::from_slice(&bytes) {
+                Ok(value) => return Ok(value),
+                Err(e) => log::warn!("Failed to decode `{key}` cache: {e}"),
+            },
+            Ok(None) => {}
+            Err(e) => log::warn!("Failed to read `{key}` cache: {e}"),
+        }
+
+        let value = self
+            .database()
+            .execute(Select(By::new(by)))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        match serde_json::to_vec(&value) {
+            Ok(bytes) => {
+                let ttl = self.config().cache_ttl;
+                if let Err(e) = cache.set(&key, bytes, ttl).await {
+                    log::warn!("Failed to write `{key}` cache: {e}");
+                }
+            }
+            Err(e) => log::warn!("Failed to encode `{key}` cache: {e}"),
+        }
+
+        Ok(value)
+    }
+}
+
+/// Maximum number of IDs requested from a [`Search`] by a [`SearchableQuery`].
+const SEARCH_HITS_LIMIT: usize = 1000;
+
+/// [`Query`] wrapping a selector.
+pub trait WithSelector: Sized {
+    /// Type of the wrapped selector.
+    type Selector;
+
+    /// Unwraps the selector of this [`Query`].
+    fn into_selector(self) -> Self::Selector;
+
+    /// Wraps the provided `selector` into this [`Query`].
+    fn from_selector(selector: Self::Selector) -> Self;
+}
+
+impl<W, B> WithSelector for DatabaseQuery<By<W, B>> {
+    type Selector = B;
+
+    fn into_selector(self) -> Self::Selector {
+        self.0.into_inner()
+    }
+
+    fn from_selector(selector: Self::Selector) -> Self {
+        Self::by(selector)
+    }
+}
+
+impl<W, B> WithSelector for CachedDatabaseQuery<By<W, B>> {
+    type Selector = B;
+
+    fn into_selector(self) -> Self::Selector {
+        self.0.into_inner()
+    }
+
+    fn from_selector(selector: Self::Selector) -> Self {
+        Self::by(selector)
+    }
+}
+
+/// `Q`uery which fuzzy searching is performed by the [`Search`] (if it's
+/// configured).
+#[derive(Clone, Copy, Debug)]
+pub struct SearchableQuery<Q>(Q);
+
+impl<Q: WithSelector> SearchableQuery<Q> {
+    /// Creates a new [`SearchableQuery`] with the provided selector.
+    #[must_use]
+    pub fn by(by: Q::Selector) -> Self {
+        Self(Q::from_selector(by))
+    }
+}
+
+/// Selector which fuzzy searching may be performed by a [`Search`].
+pub trait Searchable {
+    /// Returns the [`search_engine::Request`] to be performed in the returned
+    /// [`search_engine::Index`].
+    ///
+    /// [`None`] means that nothing is searched for.
+    fn search_request(
+        &self,
+    ) -> Option<(search_engine::Index, search_engine::Request)>;
+
+    /// Restricts this selector to the entities with the provided IDs, found
+    /// by a [`Search`].
+    fn restrict(&mut self, ids: Vec<Uuid>);
+}
+
+impl<Db, Q> Query<SearchableQuery<Q>> for Service<Db>
+where
+    Self: Query<Q, Err = Traced<database::Error>>,
+    Q: WithSelector<Selector: Searchable>,
+{
+    type Ok = <Self as Query<Q>>::Ok;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        SearchableQuery(query): SearchableQuery<Q>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let mut by = query.into_selector();
+        let search = self.config().search.as_ref();
+        if let Some((search, (index, request))) =
+            search.zip(by.search_request())
+        {
+            // Fallback to the `Database` searching on failure.
+            match search.search(index, &request).await {
+                Ok(ids) => by.restrict(ids),
+                Err(e) => log::warn!("Failed to search `{index}` index: {e}"),
+            }
+        }
+
+        self.execute(Q::from_selector(by))
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
+
+/// [`Query`] of a total count `T` of some list, scoped by its filter `F`.
+///
+/// Prefers the exact count cached by the [`task::RefreshTotalCounts`], then
+/// the [`read::Estimated`] one (if it's big enough to not require
+/// precision), and only then falls back to counting in a [`Database`].
+///
+/// [`task::RefreshTotalCounts`]: crate::task::RefreshTotalCounts
+#[derive(Clone, Debug)]
+pub struct TotalCountQuery<T, F>(F, PhantomData<T>);
+
+impl<T, F> TotalCountQuery<T, F> {
+    /// Creates a new [`TotalCountQuery`] of the list items matching the
+    /// provided `filter`.
+    #[must_use]
+    pub fn by(filter: F) -> Self {
+        Self(filter, PhantomData)
+    }
+}
+
+impl<T, F> WithSelector for TotalCountQuery<T, F> {
+    type Selector = F;
+
+    fn into_selector(self) -> Self::Selector {
+        self.0
+    }
+
+    fn from_selector(selector: Self::Selector) -> Self {
+        Self::by(selector)
+    }
+}
+
+/// Total count which exact value may be cached in [`TotalCounts`].
+pub trait CachedTotalCount: Sized {
+    /// Filter of the list items being counted.
+    type Filter;
+
+    /// Returns the cached value of this total count of the list items
+    /// matching the provided `filter`, if any.
+    fn cached(counts: &TotalCounts, filter: &Self::Filter) -> Option<Self>;
+}
+
+impl<Db, T, F> Query<TotalCountQuery<T, F>> for Service<Db>
+where
+    Db: Database<Select<By<T, F>>, Ok = T, Err = Traced<database::Error>>
+        + Database<
+            Select<By<read::Estimated<T>, F>>,
+            Ok = read::Estimated<T>,
+            Err = Traced<database::Error>,
+        >,
+    T: CachedTotalCount<Filter = F> + Copy,
+    F: Clone,
+    i32: From<T>,
+{
+    type Ok = T;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        TotalCountQuery(filter, _): TotalCountQuery<T, F>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let cached = T::cached(&self.total_counts.borrow(), &filter);
+        if let Some(count) = cached {
+            return Ok(count);
+        }
+
+        let read::Estimated(estimated) = self
+            .database()
+            .execute(Select(By::<read::Estimated<T>, _>::new(filter.clone())))
+            .await
+            .map_err(tracerr::wrap!())?;
+        if i32::from(estimated) >= self.config().estimated_count_threshold {
+            return Ok(estimated);
+        }
+
+        self.database()
+            .execute(Select(By::<T, _>::new(filter)))
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
@@ -0,0 +1,25 @@
+//! [`Query`] collection related to [`Offer`]s.
+
+use common::operations::By;
+
+use crate::{
+    domain::{offer, realty, Offer},
+    read,
+};
+#[cfg(doc)]
+use crate::{
+    domain::{Contract, Realty},
+    Query,
+};
+
+use super::DatabaseQuery;
+
+/// Queries an [`Offer`] by its [`offer::Id`].
+pub type ById = DatabaseQuery<By<Option<Offer>, offer::Id>>;
+
+/// Queries all the [`Offer`]s on a placed [`Realty`] in the order of their
+/// submission.
+pub type ByRealty = DatabaseQuery<By<Vec<Offer>, realty::Id>>;
+
+/// Queries the [`Offer`]s negotiating the terms of a concluded [`Contract`].
+pub type Negotiation = DatabaseQuery<By<Vec<Offer>, read::offer::Negotiation>>;
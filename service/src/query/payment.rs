@@ -0,0 +1,13 @@
+//! [`Query`] collection related to [`Payment`]s.
+
+use common::operations::By;
+
+use crate::domain::{contract, Payment};
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries all the [`Payment`]s made under a [`contract::Rent`], in the
+/// order they were made.
+pub type ByContract = DatabaseQuery<By<Vec<Payment>, contract::Id>>;
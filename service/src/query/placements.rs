@@ -1,16 +1,122 @@
 //! [`Query`] collection related to the multiple [`Placement`]s.
 
-use common::operations::By;
+use common::{operations::By, pagination::Arguments};
+use uuid::Uuid;
 
-use crate::read::placement;
+use crate::{
+    infra::{cache, search},
+    read::placement,
+};
 #[cfg(doc)]
-use crate::{read::Placement, Query};
+use crate::{
+    domain::Agency,
+    infra::{Cache, Search},
+    read::Placement,
+    Query,
+};
 
-use super::DatabaseQuery;
+use super::{
+    CacheKey, CachedDatabaseQuery, DatabaseQuery, Searchable, SearchableQuery,
+    SEARCH_HITS_LIMIT,
+};
 
 /// Queries a list of [`Placement`]s.
-pub type List =
-    DatabaseQuery<By<placement::list::Page, placement::list::Selector>>;
+///
+/// Searching by an address is performed by the [`Search`] (if it's
+/// configured).
+///
+/// Only the first unfiltered page is stored in the [`Cache`].
+pub type List = SearchableQuery<
+    CachedDatabaseQuery<By<placement::list::Page, placement::list::Selector>>,
+>;
 
-/// Queries total count of [`Placement`]s.
-pub type TotalCount = DatabaseQuery<By<placement::list::TotalCount, ()>>;
+/// Queries [`placement::list::Facets`] of the [`Placement`]s.
+///
+/// Searching by an address is performed by the [`Search`] (if it's
+/// configured).
+pub type Facets = SearchableQuery<
+    DatabaseQuery<By<placement::list::Facets, placement::list::Filter>>,
+>;
+
+impl Searchable for placement::list::Selector {
+    fn search_request(&self) -> Option<(search::Index, search::Request)> {
+        self.filter.search_request()
+    }
+
+    fn restrict(&mut self, ids: Vec<Uuid>) {
+        self.filter.restrict(ids);
+    }
+}
+
+impl Searchable for placement::list::Filter {
+    fn search_request(&self) -> Option<(search::Index, search::Request)> {
+        self.address.as_ref().map(|address| {
+            // Only exclusion is expressible as a facet.
+            let facets = [("rent", self.rent), ("sale", self.sale)]
+                .into_iter()
+                .filter(|(_, included)| !included)
+                .map(|(name, _)| (name, false.into()))
+                .collect();
+            (
+                search::Index::Placements,
+                search::Request {
+                    query: address.to_string(),
+                    facets,
+                    limit: SEARCH_HITS_LIMIT,
+                },
+            )
+        })
+    }
+
+    fn restrict(&mut self, ids: Vec<Uuid>) {
+        self.realty_ids = Some(ids.into_iter().map(Into::into).collect());
+    }
+}
+
+impl CacheKey<placement::list::Page> for placement::list::Selector {
+    fn cache_key(&self) -> Option<String> {
+        if self.filter.address.is_some() || self.filter.realty_ids.is_some() {
+            return None;
+        }
+        match self.arguments {
+            Arguments::Forward {
+                first,
+                after: None,
+                including: false,
+            } => Some(cache::key::placements(
+                self.filter.agency_id,
+                first,
+                self.filter.sale,
+                self.filter.rent,
+            )),
+            Arguments::Forward { .. }
+            | Arguments::Backward { .. }
+            | Arguments::Offset { .. } => None,
+        }
+    }
+}
+
+/// Queries a page of the public [`Placement`]s feed of an [`Agency`].
+///
+/// Every page is stored in the [`Cache`].
+pub type Feed =
+    CachedDatabaseQuery<By<placement::feed::Page, placement::feed::Selector>>;
+
+impl CacheKey<placement::feed::Page> for placement::feed::Selector {
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::key::placements_feed(
+            self.agency_id,
+            self.first,
+            self.after,
+        ))
+    }
+}
+
+/// Queries total count of [`Placement`]s matching a
+/// [`placement::list::Filter`].
+///
+/// Searching by an address is performed by the [`Search`] (if it's
+/// configured), the same way as for the [`List`].
+pub type TotalCount = SearchableQuery<
+    DatabaseQuery<By<placement::list::TotalCount, placement::list::Filter>>,
+>;
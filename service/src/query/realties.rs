@@ -1,16 +1,82 @@
 //! [`Query`] collection related to the multiple [`Realty`].
 
+use std::collections::BTreeMap;
+
 use common::operations::By;
+use uuid::Uuid;
 
-use crate::read;
 #[cfg(doc)]
 use crate::{domain::Realty, Query};
+use crate::{infra::search, read, task::refresh_total_counts::TotalCounts};
 
-use super::DatabaseQuery;
+use super::{
+    CachedTotalCount, DatabaseQuery, Searchable, SearchableQuery,
+    TotalCountQuery, SEARCH_HITS_LIMIT,
+};
 
 /// Queries a list of [`Realty`].
-pub type List =
-    DatabaseQuery<By<read::realty::list::Page, read::realty::list::Selector>>;
+pub type List = SearchableQuery<
+    DatabaseQuery<By<read::realty::list::Page, read::realty::list::Selector>>,
+>;
+
+impl Searchable for read::realty::list::Selector {
+    fn search_request(&self) -> Option<(search::Index, search::Request)> {
+        self.filter.search_request()
+    }
+
+    fn restrict(&mut self, ids: Vec<Uuid>) {
+        self.filter.restrict(ids);
+    }
+}
+
+impl Searchable for read::realty::list::Filter {
+    fn search_request(&self) -> Option<(search::Index, search::Request)> {
+        self.address.as_ref().map(|address| {
+            (
+                search::Index::Realties,
+                search::Request {
+                    query: address.to_string(),
+                    facets: BTreeMap::new(),
+                    limit: SEARCH_HITS_LIMIT,
+                },
+            )
+        })
+    }
+
+    fn restrict(&mut self, ids: Vec<Uuid>) {
+        self.ids = Some(ids.into_iter().map(Into::into).collect());
+    }
+}
+
+/// Queries total count of [`Realty`] list items matching a
+/// [`read::realty::list::Filter`].
+///
+/// Searching by an address is performed by the [`Search`] (if it's
+/// configured), the same way as for the [`List`].
+///
+/// [`Search`]: crate::infra::Search
+pub type TotalCount = SearchableQuery<
+    TotalCountQuery<read::realty::list::TotalCount, read::realty::list::Filter>,
+>;
+
+impl CachedTotalCount for read::realty::list::TotalCount {
+    type Filter = read::realty::list::Filter;
 
-/// Queries total count of [`Realty`] list items.
-pub type TotalCount = DatabaseQuery<By<read::realty::list::TotalCount, ()>>;
+    fn cached(counts: &TotalCounts, filter: &Self::Filter) -> Option<Self> {
+        // Only the count of all the `Realty`s of an `Agency` is cached.
+        let read::realty::list::Filter {
+            agency_id: Some(agency_id),
+            assignee_id: None,
+            address: None,
+            ids: None,
+            sort_by: _,
+        } = filter
+        else {
+            return None;
+        };
+        counts
+            .realties
+            .as_ref()
+            .map(|c| c.get(agency_id).copied().unwrap_or(Self::from(0)))
+    }
+}
@@ -1,12 +1,228 @@
 //! [`Query`] collection related to a single [`Realty`].
 
-use common::operations::By;
+use std::time::Duration;
+
+use common::{
+    operations::{By, Select},
+    DateTime, Money, Percent,
+};
+use rust_decimal::Decimal;
+use tracerr::Traced;
 
-use crate::domain::{realty, Realty};
 #[cfg(doc)]
-use crate::Query;
+use crate::domain::Contract;
+use crate::{
+    domain::{
+        contract::short_term_rent::SeasonalRate, listing, ownership, realty,
+        FloorPlan, MediaLink, Ownership, Realty,
+    },
+    infra::{database, Database},
+    read, Query, Service,
+};
 
 use super::DatabaseQuery;
 
 /// Queries a [`Realty`] by its [`realty::Id`].
 pub type ById = DatabaseQuery<By<Option<Realty>, realty::Id>>;
+
+/// Queries the [`listing::Import`] a [`Realty`] was imported with from an
+/// external feed, if it's externally sourced.
+pub type ExternalListing =
+    DatabaseQuery<By<Option<listing::Import>, realty::Id>>;
+
+/// Queries all the [`FloorPlan`]s of a [`Realty`], in the order of their
+/// floors and labels.
+pub type FloorPlans = DatabaseQuery<By<Vec<FloorPlan>, realty::Id>>;
+
+/// Queries all the [`MediaLink`]s of a [`Realty`], in the order they were
+/// set.
+pub type MediaLinks = DatabaseQuery<By<Vec<MediaLink>, realty::Id>>;
+
+/// Queries all the [`Ownership`]s of a [`Realty`].
+pub type Owners = DatabaseQuery<By<Vec<Ownership>, realty::Id>>;
+
+/// Queries the [`ownership::Transfer`]s of a [`Realty`], from the most recent
+/// one.
+pub type OwnershipHistory =
+    DatabaseQuery<By<Vec<ownership::Transfer>, realty::Id>>;
+
+/// Queries all the [`SeasonalRate`]s of a [`Realty`], in the order of their
+/// seasons.
+pub type SeasonalRates = DatabaseQuery<By<Vec<SeasonalRate>, realty::Id>>;
+
+/// [`Query`] to suggest a price of a [`Realty`] from the prices of the recent
+/// [`Contract`]s on the comparable [`Realty`]s of the same [`Agency`].
+///
+/// [`Realty`]s are comparable if they are located in the same
+/// [`realty::City`], are of the same [`realty::Kind`] and, if the [`Realty`]
+/// has a known [`realty::Area`], fall into the same [`realty::Area::bucket`].
+///
+/// No [`Suggestion`] is made if the [`Realty`] doesn't exist or there are no
+/// comparable [`Contract`]s.
+///
+/// [`Agency`]: crate::domain::Agency
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SuggestedPrice {
+    /// ID of the [`Realty`] to suggest a price of.
+    pub realty_id: realty::Id,
+
+    /// [`read::realty::Purpose`] to suggest a price for.
+    pub purpose: read::realty::Purpose,
+}
+
+impl SuggestedPrice {
+    /// How long ago the [`Contract`]s are still considered recent.
+    pub const RECENCY: Duration = Duration::from_hours(365 * 24);
+
+    /// Maximum number of the most recent comparable [`Contract`]s to consider.
+    pub const MAX_COMPARABLES: u16 = 100;
+
+    /// Number of comparable [`Contract`]s giving a full confidence in a
+    /// [`Suggestion`].
+    pub const CONFIDENT_COMPARABLES: u16 = 10;
+}
+
+/// Price suggested by the [`SuggestedPrice`] [`Query`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Suggestion {
+    /// Median price of the comparable [`Contract`]s.
+    pub price: Money,
+
+    /// Number of the comparable [`Contract`]s the [`Suggestion::price`] is
+    /// calculated from.
+    pub comparables: u16,
+
+    /// Confidence in the [`Suggestion::price`], growing with the number of
+    /// the comparable [`Contract`]s up to the
+    /// [`SuggestedPrice::CONFIDENT_COMPARABLES`].
+    pub confidence: Percent,
+}
+
+impl Suggestion {
+    /// Suggests a price from the provided prices of the comparable
+    /// [`Contract`]s, from the most recent one.
+    ///
+    /// Only the prices in the most frequent currency are considered (the one
+    /// of the most recent price on a tie).
+    ///
+    /// [`None`] is returned if no prices are provided.
+    #[must_use]
+    pub fn of_prices(prices: &[Money]) -> Option<Self> {
+        let frequency = |c| prices.iter().filter(|p| p.currency == c).count();
+        let currency = prices
+            .iter()
+            .map(|p| p.currency)
+            .rev()
+            .max_by_key(|c| frequency(*c))?;
+        let mut amounts = prices
+            .iter()
+            .filter(|p| p.currency == currency)
+            .map(|p| p.amount)
+            .collect::<Vec<_>>();
+        amounts.sort_unstable();
+
+        let mid = amounts.len() / 2;
+        let median = if amounts.len() % 2 == 0 {
+            (amounts[mid - 1] + amounts[mid]) / Decimal::TWO
+        } else {
+            amounts[mid]
+        };
+        let comparables = u16::try_from(amounts.len()).unwrap_or(u16::MAX);
+        Some(Self {
+            price: Money {
+                amount: median.round_dp(2),
+                currency,
+            },
+            comparables,
+            confidence: Percent::clamped_ratio(
+                Decimal::from(comparables)
+                    / Decimal::from(SuggestedPrice::CONFIDENT_COMPARABLES),
+            ),
+        })
+    }
+}
+
+impl<Db> Query<SuggestedPrice> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Realty>, realty::Id>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Money>, read::realty::Comparables>>,
+            Ok = Vec<Money>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = Option<Suggestion>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        SuggestedPrice { realty_id, purpose }: SuggestedPrice,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Some(realty) = self
+            .database()
+            .execute(Select(By::<Option<Realty>, _>::new(realty_id)))
+            .await
+            .map_err(tracerr::wrap!())?
+        else {
+            return Ok(None);
+        };
+
+        let prices = self
+            .database()
+            .execute(Select(By::<Vec<Money>, _>::new(
+                read::realty::Comparables {
+                    realty_id,
+                    agency_id: realty.agency_id,
+                    purpose,
+                    kind: realty.kind(),
+                    country: realty.country,
+                    city: realty.city,
+                    area: realty.area.map(realty::Area::bucket),
+                    since: DateTime::now() - SuggestedPrice::RECENCY,
+                    limit: SuggestedPrice::MAX_COMPARABLES,
+                },
+            )))
+            .await
+            .map_err(tracerr::wrap!())?;
+        Ok(Suggestion::of_prices(&prices))
+    }
+}
+
+#[cfg(test)]
+mod spec {
+    use common::{money::Currency, Money, Percent};
+    use rust_decimal::Decimal;
+
+    use super::Suggestion;
+
+    fn money(amount: i64, currency: Currency) -> Money {
+        Money {
+            amount: Decimal::from(amount),
+            currency,
+        }
+    }
+
+    #[test]
+    fn suggests_median_of_most_frequent_currency() {
+        let suggestion = Suggestion::of_prices(&[
+            money(300, Currency::Usd),
+            money(9000, Currency::Rub),
+            money(100, Currency::Usd),
+            money(200, Currency::Usd),
+            money(400, Currency::Usd),
+        ])
+        .unwrap();
+
+        assert_eq!(suggestion.price, money(250, Currency::Usd));
+        assert_eq!(suggestion.comparables, 4);
+        assert_eq!(suggestion.confidence, Percent::new(40.into()).unwrap());
+    }
+
+    #[test]
+    fn suggests_nothing_without_comparables() {
+        assert_eq!(Suggestion::of_prices(&[]), None);
+    }
+}
@@ -0,0 +1,129 @@
+//! [`Arrears`] definition.
+
+use std::collections::HashMap;
+
+use common::{
+    operations::{By, Select},
+    Money,
+};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{contract, Agency, LateFee, User};
+use crate::{
+    domain::{self, agency, arrears, user},
+    infra::{database, Database},
+    Query, Service,
+};
+
+use super::add_amount;
+
+/// [`Query`] to aggregate the overdue amounts of [`contract::Rent`]s in
+/// [`domain::Arrears`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Arrears {
+    /// ID of the [`Agency`] to aggregate the [`domain::Arrears`] of.
+    pub agency_id: agency::Id,
+}
+
+/// Output of the [`Arrears`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// [`domain::Arrears`] per [`contract::Rent`], the longest overdue first.
+    pub rows: Vec<domain::Arrears>,
+
+    /// [`TenantRow`]s of the report, aggregating its [`Output::rows`] per
+    /// purchaser [`User`], the longest overdue first.
+    pub tenants: Vec<TenantRow>,
+
+    /// Total overdue amounts, one per a currency.
+    pub totals: Vec<Money>,
+
+    /// Total unpaid [`LateFee`]s included into the [`Output::totals`], one
+    /// per a currency.
+    pub late_fees: Vec<Money>,
+}
+
+/// Aggregation of the [`Output::rows`] of the [`Arrears`] [`Query`] per
+/// purchaser [`User`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TenantRow {
+    /// ID of the purchaser [`User`] the [`domain::Arrears`] are aggregated
+    /// for.
+    pub purchaser_id: user::Id,
+
+    /// Number of the [`contract::Rent`]s of the [`User`] in arrears.
+    pub contracts: usize,
+
+    /// Total overdue amounts of the [`User`], one per a currency.
+    pub amounts: Vec<Money>,
+
+    /// Total unpaid [`LateFee`]s of the [`User`] included into the
+    /// [`TenantRow::amounts`], one per a currency.
+    pub late_fees: Vec<Money>,
+
+    /// Earliest [`domain::Arrears::overdue_since`] of the [`User`].
+    pub overdue_since: arrears::OverdueDateTime,
+}
+
+impl TenantRow {
+    /// Aggregates the provided [`domain::Arrears`] into [`TenantRow`]s,
+    /// preserving the order of their first occurrence.
+    fn aggregate(rows: &[domain::Arrears]) -> Vec<Self> {
+        let mut tenants = Vec::<Self>::new();
+        let mut indices = HashMap::new();
+        for row in rows {
+            let idx = *indices.entry(row.purchaser_id).or_insert_with(|| {
+                tenants.push(Self {
+                    purchaser_id: row.purchaser_id,
+                    contracts: 0,
+                    amounts: vec![],
+                    late_fees: vec![],
+                    overdue_since: row.overdue_since,
+                });
+                tenants.len() - 1
+            });
+            let tenant = &mut tenants[idx];
+            tenant.contracts += 1;
+            tenant.overdue_since = tenant.overdue_since.min(row.overdue_since);
+            add_amount(&mut tenant.amounts, row.amount);
+            add_amount(&mut tenant.late_fees, row.late_fees);
+        }
+        tenants
+    }
+}
+
+impl<Db> Query<Arrears> for Service<Db>
+where
+    Db: Database<
+        Select<By<Vec<domain::Arrears>, agency::Id>>,
+        Ok = Vec<domain::Arrears>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Output;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Arrears { agency_id }: Arrears,
+    ) -> Result<Self::Ok, Self::Err> {
+        let rows = self
+            .database()
+            .execute(Select(By::<Vec<domain::Arrears>, _>::new(agency_id)))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let (mut totals, mut late_fees) = (vec![], vec![]);
+        for row in &rows {
+            add_amount(&mut totals, row.amount);
+            add_amount(&mut late_fees, row.late_fees);
+        }
+        Ok(Output {
+            tenants: TenantRow::aggregate(&rows),
+            rows,
+            totals,
+            late_fees,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+//! [`Dashboard`] definition.
+
+use std::time::Duration;
+
+use common::{
+    operations::{By, Select},
+    DateTime, TimeZone,
+};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, Contract};
+use crate::{
+    domain::agency,
+    infra::{database, Database},
+    read, Query, Service,
+};
+
+/// [`Query`] to summarize an [`Agency`] activity for its back-office home
+/// screen in one round trip.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Dashboard {
+    /// ID of the [`Agency`] to summarize.
+    pub agency_id: agency::Id,
+
+    /// [`TimeZone`] which calendar the month-to-date revenue is aligned to.
+    ///
+    /// [`TimeZone::UTC`] if not specified.
+    pub time_zone: Option<TimeZone>,
+}
+
+impl Dashboard {
+    /// Period, within which the [`Contract`]s are considered expiring.
+    pub const EXPIRING_WITHIN: Duration = Duration::from_hours(30 * 24);
+}
+
+impl<Db> Query<Dashboard> for Service<Db>
+where
+    Db: Database<
+        Select<By<read::Dashboard, read::dashboard::Selector>>,
+        Ok = read::Dashboard,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = read::Dashboard;
+    type Err = Traced<database::Error>;
+
+    async fn execute(&self, query: Dashboard) -> Result<Self::Ok, Self::Err> {
+        let Dashboard {
+            agency_id,
+            time_zone,
+        } = query;
+
+        let now = DateTime::now();
+        self.database()
+            .execute(Select(By::new(read::dashboard::Selector {
+                agency_id,
+                expiring_until: (now + Dashboard::EXPIRING_WITHIN).coerce(),
+                revenue_since: now
+                    .start_of_month_in(time_zone.unwrap_or_default()),
+            })))
+            .await
+            .map_err(tracerr::wrap!())
+    }
+}
@@ -0,0 +1,73 @@
+//! [`InventoryDiff`] definition.
+
+use common::operations::{By, Select};
+use tracerr::Traced;
+
+use crate::{
+    domain::{contract, inventory, Inventory},
+    infra::{database, Database},
+    Query, Service,
+};
+
+/// [`Query`] to compare the move-in and the move-out [`Inventory`]s of a
+/// [`contract::Rent`], to be used in deposit disputes.
+///
+/// Returns [`None`] unless both the [`Inventory`]s are recorded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InventoryDiff {
+    /// ID of the [`contract::Rent`] to compare the [`Inventory`]s of.
+    pub contract_id: contract::Id,
+}
+
+/// Output of the [`InventoryDiff`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// Move-in [`Inventory`].
+    pub move_in: Inventory,
+
+    /// Move-out [`Inventory`].
+    pub move_out: Inventory,
+
+    /// [`inventory::Difference`]s of the changed [`inventory::Item`]s.
+    pub differences: Vec<inventory::Difference>,
+}
+
+impl<Db> Query<InventoryDiff> for Service<Db>
+where
+    Db: Database<
+        Select<By<Vec<Inventory>, contract::Id>>,
+        Ok = Vec<Inventory>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Option<Output>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        InventoryDiff { contract_id }: InventoryDiff,
+    ) -> Result<Self::Ok, Self::Err> {
+        let mut inventories = self
+            .database()
+            .execute(Select(By::<Vec<Inventory>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let mut take = |stage| {
+            let idx = inventories.iter().position(|i| i.stage == stage)?;
+            Some(inventories.swap_remove(idx))
+        };
+        let (Some(move_in), Some(move_out)) = (
+            take(inventory::Stage::MoveIn),
+            take(inventory::Stage::MoveOut),
+        ) else {
+            return Ok(None);
+        };
+
+        Ok(Some(Output {
+            differences: move_in.diff(&move_out),
+            move_in,
+            move_out,
+        }))
+    }
+}
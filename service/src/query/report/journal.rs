@@ -0,0 +1,64 @@
+//! [`Journal`] definition.
+
+use common::{
+    operations::{By, Select},
+    DateTime,
+};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{contract, Agency};
+use crate::{
+    domain::{agency, journal, Payment},
+    infra::{database, Database},
+    read, Query, Service,
+};
+
+/// [`Query`] to build the accounting journal of the [`Payment`]s received by
+/// an [`Agency`] under its [`contract::Rent`]s within the provided period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Journal {
+    /// ID of the [`Agency`] to build the journal of.
+    pub agency_id: agency::Id,
+
+    /// [`DateTime`] (inclusive) the journal starts at.
+    pub since: DateTime,
+
+    /// [`DateTime`] (exclusive) the journal ends at.
+    pub until: DateTime,
+}
+
+impl<Db> Query<Journal> for Service<Db>
+where
+    Db: Database<
+        Select<By<Vec<Payment>, read::payment::Period>>,
+        Ok = Vec<Payment>,
+        Err = Traced<database::Error>,
+    >,
+{
+    /// [`journal::Entry`]s in the order the [`Payment`]s were made.
+    type Ok = Vec<journal::Entry>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Journal {
+            agency_id,
+            since,
+            until,
+        }: Journal,
+    ) -> Result<Self::Ok, Self::Err> {
+        let payments = self
+            .database()
+            .execute(Select(By::<Vec<Payment>, _>::new(
+                read::payment::Period {
+                    agency_id,
+                    since,
+                    until,
+                },
+            )))
+            .await
+            .map_err(tracerr::wrap!())?;
+        Ok(payments.into_iter().map(Into::into).collect())
+    }
+}
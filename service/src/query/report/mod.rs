@@ -2,6 +2,27 @@
 //!
 //! [`Query`]: crate::Query
 
+pub mod arrears;
+pub mod dashboard;
+pub mod inventory_diff;
+pub mod journal;
+pub mod revenue;
 pub mod salary;
+pub mod statement;
+pub mod time_series;
 
-pub use self::salary::Salary;
+use common::Money;
+
+pub use self::{
+    arrears::Arrears, dashboard::Dashboard, inventory_diff::InventoryDiff,
+    journal::Journal, revenue::Revenue, salary::Salary, statement::Statement,
+    time_series::TimeSeries,
+};
+
+/// Adds the provided `amount` to the `totals` of its currency.
+fn add_amount(totals: &mut Vec<Money>, amount: Money) {
+    match totals.iter_mut().find(|t| t.currency == amount.currency) {
+        Some(total) => total.amount += amount.amount,
+        None => totals.push(amount),
+    }
+}
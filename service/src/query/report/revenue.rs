@@ -0,0 +1,128 @@
+//! [`Revenue`] definition.
+
+use common::{
+    operations::{By, Select},
+    Money,
+};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, Charge};
+use crate::{
+    domain::{agency, charge, contract},
+    infra::{database, Database},
+    read, Query, Service,
+};
+
+use super::add_amount;
+
+/// [`Query`] to calculate the monthly revenue of the active
+/// [`contract::Rent`]s of an [`Agency`], along with their [`Charge`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Revenue {
+    /// ID of the [`Agency`] to calculate the revenue of.
+    pub agency_id: agency::Id,
+}
+
+/// Output of the [`Revenue`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// Rows of the report, in the order of the [`contract::Rent`] IDs.
+    pub rows: Vec<Row>,
+
+    /// Total monthly rents, one per a currency.
+    pub rents: Vec<Money>,
+
+    /// Total monthly [`Charge`]s borne by tenants, one per a currency.
+    pub tenant_charges: Vec<Money>,
+
+    /// Total monthly [`Charge`]s borne by landlords, one per a currency.
+    pub landlord_charges: Vec<Money>,
+
+    /// Total monthly [`Charge`]s borne by the [`Agency`], one per a currency.
+    pub agency_charges: Vec<Money>,
+}
+
+/// Row in the [`Output`] of the [`Revenue`] [`Query`].
+///
+/// All the amounts are in the currency of the [`contract::Rent::price`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Row {
+    /// ID of the [`contract::Rent`] this [`Row`] is of.
+    pub contract_id: contract::Id,
+
+    /// Monthly rent.
+    pub rent: Money,
+
+    /// Monthly [`Charge`]s borne by the tenant.
+    pub tenant_charges: Money,
+
+    /// Monthly [`Charge`]s borne by the landlord.
+    pub landlord_charges: Money,
+
+    /// Monthly [`Charge`]s borne by the [`Agency`].
+    pub agency_charges: Money,
+}
+
+impl<Db> Query<Revenue> for Service<Db>
+where
+    Db: Database<
+        Select<By<Vec<read::charge::Rental>, read::charge::Active>>,
+        Ok = Vec<read::charge::Rental>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Output;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Revenue { agency_id }: Revenue,
+    ) -> Result<Self::Ok, Self::Err> {
+        let rentals = self
+            .database()
+            .execute(Select(By::<Vec<read::charge::Rental>, _>::new(
+                read::charge::Active(agency_id),
+            )))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let rows = rentals
+            .into_iter()
+            .map(|read::charge::Rental { contract, charges }| {
+                let borne_by = |bearer| Money {
+                    amount: charges
+                        .iter()
+                        .filter(|c| c.bearer == bearer)
+                        .map(|c| c.amount.amount)
+                        .sum::<Decimal>(),
+                    currency: contract.price.currency,
+                };
+                Row {
+                    contract_id: contract.id,
+                    rent: contract.price,
+                    tenant_charges: borne_by(charge::Bearer::Tenant),
+                    landlord_charges: borne_by(charge::Bearer::Landlord),
+                    agency_charges: borne_by(charge::Bearer::Agency),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut output = Output {
+            rows: vec![],
+            rents: vec![],
+            tenant_charges: vec![],
+            landlord_charges: vec![],
+            agency_charges: vec![],
+        };
+        for row in &rows {
+            add_amount(&mut output.rents, row.rent);
+            add_amount(&mut output.tenant_charges, row.tenant_charges);
+            add_amount(&mut output.landlord_charges, row.landlord_charges);
+            add_amount(&mut output.agency_charges, row.agency_charges);
+        }
+        output.rows = rows;
+        Ok(output)
+    }
+}
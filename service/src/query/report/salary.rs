@@ -2,16 +2,16 @@
 
 use common::{
     operations::{By, Select},
-    DateTime, Money,
+    DateTime, Money, Percent, TimeZone,
 };
 use rust_decimal::Decimal;
 use std::{collections::HashMap, ops::RangeInclusive};
 use tracerr::Traced;
 
 #[cfg(doc)]
-use crate::domain::{Contract, User};
+use crate::domain::{Agency, Branch, Contract, User};
 use crate::{
-    domain::{contract, user},
+    domain::{agency, branch, contract, user},
     infra::{database, Database},
     read::{self, contract::Active},
     Query, Service,
@@ -20,21 +20,44 @@ use crate::{
 /// [`Query`] to calculate salaries for a given period.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Salary {
+    /// ID of the [`Agency`] to calculate salaries of the employees of.
+    pub agency_id: agency::Id,
+
     /// Start of the period.
     pub start: DateTime,
 
     /// End of the period.
     pub end: DateTime,
+
+    /// [`TimeZone`] which calendar the period is aligned to.
+    ///
+    /// If specified, the period is extended to the whole days of this
+    /// [`TimeZone`].
+    pub time_zone: Option<TimeZone>,
 }
 
 /// Output of the [`Salary`] [`Query`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Output {
+    /// Start of the period, aligned to the [`Output::time_zone`].
+    pub start: DateTime,
+
+    /// End of the period, aligned to the [`Output::time_zone`].
+    pub end: DateTime,
+
+    /// [`TimeZone`] the period is aligned to.
+    ///
+    /// [`TimeZone::UTC`] if none was specified.
+    pub time_zone: TimeZone,
+
     /// Total count of [`Contract`]s in the period.
     pub total_contracts: read::contract::list::TotalCount,
 
     /// Rows of the report.
     pub rows: Vec<Row>,
+
+    /// [`BranchRow`]s of the report, aggregating its [`Row`]s per [`Branch`].
+    pub branches: Vec<BranchRow>,
 }
 
 /// Row in the [`Output`] of the [`Salary`] [`Query`].
@@ -43,20 +66,78 @@ pub struct Row {
     /// ID of the [`User`] the salary is calculated for.
     pub user_id: user::Id,
 
-    /// Number of [`Contract`]s the [`User`] made in the period.
+    /// ID of the [`Branch`] the [`User`] is assigned to, if any.
+    pub branch_id: Option<branch::Id>,
+
+    /// Number of [`Contract`]s the [`User`] took part in during the period.
     pub contracts: read::contract::list::TotalCount,
 
     /// Calculated salary for the [`User`].
+    ///
+    /// Bonus part of the salary is attributed according to the commission
+    /// [`Split`]s of the [`Contract`]s.
+    ///
+    /// [`Split`]: contract::commission::Split
     pub salary: Money,
 }
 
+/// Aggregation of the [`Row`]s in the [`Output`] of the [`Salary`] [`Query`]
+/// per [`Branch`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BranchRow {
+    /// ID of the [`Branch`] the [`Row`]s are aggregated for.
+    ///
+    /// [`None`] aggregates the [`User`]s not assigned to any [`Branch`].
+    pub branch_id: Option<branch::Id>,
+
+    /// Number of the [`User`]s assigned to the [`Branch`].
+    pub employees: usize,
+
+    /// Number of [`Contract`]s the [`User`]s made in the period.
+    pub contracts: read::contract::list::TotalCount,
+
+    /// Total salaries of the [`User`]s, one per a salary currency.
+    pub salaries: Vec<Money>,
+}
+
+impl BranchRow {
+    /// Aggregates the provided [`Row`]s into [`BranchRow`]s, ordered by
+    /// [`BranchRow::branch_id`].
+    fn aggregate(rows: &[Row]) -> Vec<Self> {
+        let mut branches = HashMap::<_, Self>::new();
+        for row in rows {
+            let branch =
+                branches.entry(row.branch_id).or_insert_with(|| Self {
+                    branch_id: row.branch_id,
+                    employees: 0,
+                    contracts: 0.into(),
+                    salaries: vec![],
+                });
+            branch.employees += 1;
+            branch.contracts =
+                (i32::from(branch.contracts) + i32::from(row.contracts)).into();
+            match branch
+                .salaries
+                .iter_mut()
+                .find(|s| s.currency == row.salary.currency)
+            {
+                Some(total) => total.amount += row.salary.amount,
+                None => branch.salaries.push(row.salary),
+            }
+        }
+        let mut branches = branches.into_values().collect::<Vec<_>>();
+        branches.sort_by_key(|b| b.branch_id);
+        branches
+    }
+}
+
 impl<Db> Query<Salary> for Service<Db>
 where
     Db: Database<
             Select<
                 By<
                     read::contract::list::TotalCount,
-                    RangeInclusive<contract::CreationDateTime>,
+                    (agency::Id, RangeInclusive<contract::CreationDateTime>),
                 >,
             >,
             Ok = read::contract::list::TotalCount,
@@ -64,11 +145,11 @@ where
         > + Database<
             Select<
                 By<
-                    HashMap<user::Id, read::contract::list::TotalCount>,
-                    RangeInclusive<contract::CreationDateTime>,
+                    HashMap<user::Id, read::contract::Attribution>,
+                    (agency::Id, RangeInclusive<contract::CreationDateTime>),
                 >,
             >,
-            Ok = HashMap<user::Id, read::contract::list::TotalCount>,
+            Ok = HashMap<user::Id, read::contract::Attribution>,
             Err = Traced<database::Error>,
         > + Database<
             Select<
@@ -86,34 +167,49 @@ where
 
     async fn execute(
         &self,
-        Salary { start, end }: Salary,
+        Salary {
+            agency_id,
+            start,
+            end,
+            time_zone,
+        }: Salary,
     ) -> Result<Self::Ok, Self::Err> {
+        let (start, end) = match time_zone {
+            Some(tz) => (start.start_of_day_in(tz), end.end_of_day_in(tz)),
+            None => (start, end),
+        };
+        let time_zone = time_zone.unwrap_or_default();
         let range = RangeInclusive::new(start.coerce(), end.coerce());
 
         let total_count = self
             .database()
-            .execute(Select(By::<read::contract::list::TotalCount, _>::new(
+            .execute(Select(By::<read::contract::list::TotalCount, _>::new((
+                agency_id,
                 range.clone(),
-            )))
+            ))))
             .await
             .map_err(tracerr::wrap!())?;
         if i32::from(total_count) == 0 {
             return Ok(Output {
+                start,
+                end,
+                time_zone,
                 total_contracts: total_count,
                 rows: vec![],
+                branches: vec![],
             });
         }
 
-        let total_by_user = self
+        let attributions = self
             .database()
             .execute(Select(By::<
-                HashMap<user::Id, read::contract::list::TotalCount>,
+                HashMap<user::Id, read::contract::Attribution>,
                 _,
-            >::new(range)))
+            >::new((agency_id, range))))
             .await
             .map_err(tracerr::wrap!())?;
 
-        let user_ids = total_by_user.keys().copied().collect::<Vec<_>>();
+        let user_ids = attributions.keys().copied().collect::<Vec<_>>();
         let contracts = self
             .database()
             .execute(Select(By::<
@@ -123,30 +219,35 @@ where
             .await
             .map_err(tracerr::wrap!())?;
 
-        let rows = total_by_user
+        let total = Decimal::from(i32::from(total_count));
+        let rows = attributions
             .into_iter()
-            .filter_map(|(user_id, count)| {
+            .filter_map(|(user_id, attribution)| {
                 let Active(employment) = contracts.get(&user_id)?;
 
-                let coef = Decimal::try_from(count / total_count)
-                    .expect("in `[0..1]` range");
-                let bonus = employment.base_salary.amount * coef;
-                let amount = employment.base_salary.amount + bonus;
+                let share = Percent::clamped_ratio(attribution.credit / total);
+                let bonus = share.of(employment.base_salary);
 
                 Some(Row {
                     user_id,
-                    contracts: count,
+                    branch_id: employment.branch_id,
+                    contracts: attribution.contracts,
                     salary: Money {
-                        amount,
+                        amount: employment.base_salary.amount + bonus.amount,
                         currency: employment.base_salary.currency,
                     },
                 })
             })
-            .collect();
+            .collect::<Vec<_>>();
+        let branches = BranchRow::aggregate(&rows);
 
         Ok(Output {
+            start,
+            end,
+            time_zone,
             total_contracts: total_count,
             rows,
+            branches,
         })
     }
 }
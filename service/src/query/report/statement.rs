@@ -0,0 +1,160 @@
+//! [`Statement`] definition.
+
+use common::{
+    operations::{By, Select},
+    DateTime, Money,
+};
+use rust_decimal::Decimal;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{charge, contract, Charge, Contract, LateFee, Payment},
+    infra::{database, Database},
+    Query, Service,
+};
+
+/// [`Query`] to build a statement of the amounts billed to and paid by the
+/// purchaser [`User`] under a [`contract::Rent`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Statement {
+    /// ID of the [`contract::Rent`] to build the statement of.
+    pub contract_id: contract::Id,
+}
+
+/// Output of the [`Statement`] [`Query`].
+///
+/// All the amounts are in the currency of the [`contract::Rent::price`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// Number of the rent installments due so far.
+    pub installments: u32,
+
+    /// Total rent billed for the due installments.
+    pub rent: Money,
+
+    /// [`ChargeRow`]s of the [`Charge`]s borne by the purchaser [`User`], in
+    /// the order they were added.
+    pub charges: Vec<ChargeRow>,
+
+    /// Total accrued [`LateFee`]s.
+    pub late_fees: Money,
+
+    /// Total amount of the [`Payment`]s made.
+    pub paid: Money,
+
+    /// Remaining balance to be paid.
+    ///
+    /// Negative if overpaid.
+    pub balance: Money,
+}
+
+/// [`Charge`] billed in the [`Output`] of the [`Statement`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChargeRow {
+    /// Billed [`Charge`].
+    pub charge: Charge,
+
+    /// Number of the rent installments the [`Charge`] is billed with.
+    pub installments: u32,
+
+    /// Total billed amount of the [`Charge`].
+    pub amount: Money,
+}
+
+impl<Db> Query<Statement> for Service<Db>
+where
+    Db: Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Charge>, contract::Id>>,
+            Ok = Vec<Charge>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Vec<Payment>, contract::Id>>,
+            Ok = Vec<Payment>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<LateFee>, contract::Id>>,
+            Ok = Option<LateFee>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = Option<Output>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Statement { contract_id }: Statement,
+    ) -> Result<Self::Ok, Self::Err> {
+        let rent = match self
+            .database()
+            .execute(Select(By::<Option<Contract>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::wrap!())?
+        {
+            Some(Contract::Rent(c)) => c,
+            Some(
+                Contract::Employment(_)
+                | Contract::ManagementForRent(_)
+                | Contract::ManagementForSale(_)
+                | Contract::Sale(_)
+                | Contract::ShortTermRent(_),
+            )
+            | None => return Ok(None),
+        };
+        let currency = rent.price.currency;
+        let money = |amount| Money { amount, currency };
+
+        let charges = self
+            .database()
+            .execute(Select(By::<Vec<Charge>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::wrap!())?;
+        let paid = self
+            .database()
+            .execute(Select(By::<Vec<Payment>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::wrap!())?
+            .into_iter()
+            .map(|p| p.amount.amount)
+            .sum();
+        let late_fees = self
+            .database()
+            .execute(Select(By::<Option<LateFee>, _>::new(contract_id)))
+            .await
+            .map_err(tracerr::wrap!())?
+            .map_or(Decimal::ZERO, |f| f.accrued.amount);
+
+        let now = DateTime::now();
+        let installments = rent.installments_due_by(now);
+        let rent_amount = rent.price.amount * Decimal::from(installments);
+        let charges = charges
+            .into_iter()
+            .filter(|c| c.bearer == charge::Bearer::Tenant)
+            .map(|charge| {
+                let installments = charge.installments_billed_by(&rent, now);
+                ChargeRow {
+                    amount: money(
+                        charge.amount.amount * Decimal::from(installments),
+                    ),
+                    installments,
+                    charge,
+                }
+            })
+            .collect::<Vec<_>>();
+        let billed = charges.iter().map(|c| c.amount.amount).sum::<Decimal>();
+
+        Ok(Some(Output {
+            installments,
+            rent: money(rent_amount),
+            charges,
+            late_fees: money(late_fees),
+            paid: money(paid),
+            balance: money(rent_amount + billed + late_fees - paid),
+        }))
+    }
+}
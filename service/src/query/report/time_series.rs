@@ -0,0 +1,86 @@
+//! [`TimeSeries`] definition.
+
+use common::{
+    operations::{By, Select},
+    DateTime, TimeZone,
+};
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, Contract};
+use crate::{
+    domain::agency,
+    infra::{database, Database},
+    read::{self, time_series::Granularity},
+    Query, Service,
+};
+
+/// [`Query`] to count the placed, signed and terminated [`Contract`]s of an
+/// [`Agency`] within the provided period, bucketed by time for charting.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimeSeries {
+    /// ID of the [`Agency`] to count the [`Contract`]s of.
+    pub agency_id: agency::Id,
+
+    /// [`Granularity`] of the buckets.
+    pub granularity: Granularity,
+
+    /// Start of the period.
+    pub start: DateTime,
+
+    /// End of the period.
+    pub end: DateTime,
+
+    /// [`TimeZone`] which calendar the period and the buckets are aligned to.
+    ///
+    /// [`TimeZone::UTC`] if not specified.
+    pub time_zone: Option<TimeZone>,
+}
+
+/// Output of the [`TimeSeries`] [`Query`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Output {
+    /// [`TimeZone`] the buckets are aligned to.
+    ///
+    /// [`TimeZone::UTC`] if none was specified.
+    pub time_zone: TimeZone,
+
+    /// Counted [`read::time_series::Point`]s, in the order of their buckets.
+    pub points: Vec<read::time_series::Point>,
+}
+
+impl<Db> Query<TimeSeries> for Service<Db>
+where
+    Db: Database<
+        Select<By<read::TimeSeries, read::time_series::Selector>>,
+        Ok = read::TimeSeries,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Output;
+    type Err = Traced<database::Error>;
+
+    async fn execute(&self, query: TimeSeries) -> Result<Self::Ok, Self::Err> {
+        let TimeSeries {
+            agency_id,
+            granularity,
+            start,
+            end,
+            time_zone,
+        } = query;
+
+        let time_zone = time_zone.unwrap_or_default();
+        let read::TimeSeries(points) = self
+            .database()
+            .execute(Select(By::new(read::time_series::Selector {
+                agency_id,
+                granularity,
+                start: start.start_of_day_in(time_zone),
+                end: end.end_of_day_in(time_zone),
+                time_zone,
+            })))
+            .await
+            .map_err(tracerr::wrap!())?;
+        Ok(Output { time_zone, points })
+    }
+}
@@ -0,0 +1,22 @@
+//! [`Query`] collection related to [`Review`]s.
+
+use common::operations::By;
+
+#[cfg(doc)]
+use crate::{domain::User, Query};
+use crate::{
+    domain::{review, user, Review},
+    read,
+};
+
+use super::DatabaseQuery;
+
+/// Queries a [`Review`] by its [`review::Id`].
+pub type ById = DatabaseQuery<By<Option<Review>, review::Id>>;
+
+/// Queries all the [`Review`]s of an employer [`User`], the most recent
+/// first.
+pub type ByEmployer = DatabaseQuery<By<Vec<Review>, user::Id>>;
+
+/// Queries the aggregated [`read::review::Rating`] of an employer [`User`].
+pub type Rating = DatabaseQuery<By<Option<read::review::Rating>, user::Id>>;
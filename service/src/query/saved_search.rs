@@ -0,0 +1,13 @@
+//! [`Query`] collection related to [`SavedSearch`]es.
+
+use common::operations::By;
+
+#[cfg(doc)]
+use crate::{domain::User, Query};
+use crate::domain::{user, SavedSearch};
+
+use super::DatabaseQuery;
+
+/// Queries all the [`SavedSearch`]es of a [`User`], in the order of their
+/// creation.
+pub type ByUser = DatabaseQuery<By<Vec<SavedSearch>, user::Id>>;
@@ -0,0 +1,174 @@
+//! [`Query`] collection related to the global search.
+
+use std::collections::HashMap;
+
+use common::{
+    operations::{By, Select},
+    pagination::Arguments,
+    Money,
+};
+use tracerr::Traced;
+
+use crate::{
+    domain::{agency, contract, realty, user, Contract, Realty, User},
+    infra::{database, Database},
+    read::{self, placement, search::Match, Placement},
+    Query, Service,
+};
+
+/// [`Query`] searching for [`User`]s, [`Realty`]s, [`Contract`]s and
+/// [`Placement`]s at once.
+#[derive(Clone, Debug)]
+pub struct Global {
+    /// Text to fuzzy search for.
+    pub query: String,
+
+    /// Maximum number of the returned [`Hit`]s.
+    pub limit: usize,
+
+    /// ID of the [`Agency`] to restrict the found [`Realty`]s, [`Contract`]s
+    /// and [`Placement`]s to.
+    ///
+    /// [`User`]s are platform-wide, so are never restricted.
+    ///
+    /// [`Agency`]: crate::domain::Agency
+    pub agency_id: Option<agency::Id>,
+
+    /// Price to rank the found [`Placement`]s by the proximity to, according
+    /// to the [`Config::search_ranking`].
+    ///
+    /// [`Config::search_ranking`]: crate::Config::search_ranking
+    pub price: Option<Money>,
+}
+
+/// Entity found by the [`Global`] [`Query`].
+#[derive(Clone, Debug)]
+pub enum Hit {
+    /// Found [`User`].
+    User(User),
+
+    /// Found [`Realty`].
+    Realty(Realty),
+
+    /// Found [`Contract`].
+    Contract(Contract),
+
+    /// Found [`Placement`].
+    Placement(Placement),
+}
+
+impl<Db> Query<Global> for Service<Db>
+where
+    Db: Database<
+            Select<By<Vec<Match>, read::search::Selector>>,
+            Ok = Vec<Match>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<user::Id, User>, Vec<user::Id>>>,
+            Ok = HashMap<user::Id, User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<realty::Id, Realty>, Vec<realty::Id>>>,
+            Ok = HashMap<realty::Id, Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<contract::Id, Contract>, Vec<contract::Id>>>,
+            Ok = HashMap<contract::Id, Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<placement::list::Page, placement::list::Selector>>,
+            Ok = placement::list::Page,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = Vec<Hit>;
+    type Err = Traced<database::Error>;
+
+    async fn execute(
+        &self,
+        Global {
+            query,
+            limit,
+            agency_id,
+            price,
+        }: Global,
+    ) -> Result<Self::Ok, Self::Err> {
+        let matches = self
+            .database()
+            .execute(Select(By::<Vec<Match>, _>::new(
+                read::search::Selector {
+                    query,
+                    limit,
+                    agency_id,
+                    price,
+                    ranking: self.config().search_ranking,
+                },
+            )))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let (mut user_ids, mut realty_ids, mut contract_ids, mut placed_ids) =
+            (vec![], vec![], vec![], vec![]);
+        for m in &matches {
+            match *m {
+                Match::User(id) => user_ids.push(id),
+                Match::Realty(id) => realty_ids.push(id),
+                Match::Contract(id) => contract_ids.push(id),
+                Match::Placement(id) => placed_ids.push(id),
+            }
+        }
+
+        let mut users = self
+            .database()
+            .execute(Select(By::<HashMap<_, _>, _>::new(user_ids)))
+            .await
+            .map_err(tracerr::wrap!())?;
+        let mut realties = self
+            .database()
+            .execute(Select(By::<HashMap<_, _>, _>::new(realty_ids)))
+            .await
+            .map_err(tracerr::wrap!())?;
+        let mut contracts = self
+            .database()
+            .execute(Select(By::<HashMap<_, _>, _>::new(contract_ids)))
+            .await
+            .map_err(tracerr::wrap!())?;
+        let mut placements = if placed_ids.is_empty() {
+            HashMap::new()
+        } else {
+            let selector = placement::list::Selector {
+                arguments: Arguments::Forward {
+                    first: placed_ids.len(),
+                    after: None,
+                    including: false,
+                },
+                filter: placement::list::Filter {
+                    agency_id,
+                    realty_ids: Some(placed_ids),
+                    ..placement::list::Filter::default()
+                },
+            };
+            self.database()
+                .execute(Select(By::<placement::list::Page, _>::new(selector)))
+                .await
+                .map_err(tracerr::wrap!())?
+                .edges
+                .into_iter()
+                .map(|e| (e.node.realty_id, e.node))
+                .collect()
+        };
+
+        // Entities may disappear between the selections, so are skipped.
+        Ok(matches
+            .into_iter()
+            .filter_map(|m| match m {
+                Match::User(id) => users.remove(&id).map(Hit::User),
+                Match::Realty(id) => realties.remove(&id).map(Hit::Realty),
+                Match::Contract(id) => contracts.remove(&id).map(Hit::Contract),
+                Match::Placement(id) => {
+                    placements.remove(&id).map(Hit::Placement)
+                }
+            })
+            .collect())
+    }
+}
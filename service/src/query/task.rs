@@ -0,0 +1,33 @@
+//! [`Query`] collection related to the background [`Task`]s.
+
+use std::convert::Infallible;
+
+use common::operations::By;
+
+#[cfg(doc)]
+use crate::Task;
+use crate::{domain, read, task, Query, Service};
+
+use super::DatabaseQuery;
+
+/// Queries the latest [`domain::task::DeadLetter`]s of the periodic [`Task`]s.
+pub type DeadLetters = DatabaseQuery<
+    By<Vec<domain::task::DeadLetter>, read::task::DeadLetters>,
+>;
+
+/// Queries the latest [`domain::task::Run`]s of the periodic [`Task`]s.
+pub type Runs = DatabaseQuery<By<Vec<domain::task::Run>, read::task::History>>;
+
+/// [`Query`] of the [`task::Status`]es of all the periodic [`Task`]s, keyed
+/// by their names.
+#[derive(Clone, Copy, Debug)]
+pub struct Status;
+
+impl<Db> Query<Status> for Service<Db> {
+    type Ok = task::Statuses;
+    type Err = Infallible;
+
+    async fn execute(&self, _: Status) -> Result<Self::Ok, Self::Err> {
+        Ok(self.task_statuses.borrow().clone())
+    }
+}
@@ -0,0 +1,30 @@
+//! [`Query`] collection related to the outgoing message templates.
+
+use tracerr::Traced;
+
+use crate::{domain::agency, infra::template, Query, Service};
+
+/// [`Query`] rendering a message template with the sample values of its
+/// [`template::Definition::placeholders`], as configured in the
+/// [`template::Engine`].
+#[derive(Clone, Debug)]
+pub struct Preview {
+    /// Name of the template to render.
+    pub name: String,
+
+    /// [`agency::Locale`] to render the template in.
+    ///
+    /// [`None`] means the default English one.
+    pub locale: Option<agency::Locale>,
+}
+
+impl<Db> Query<Preview> for Service<Db> {
+    type Ok = String;
+    type Err = Traced<template::Error>;
+
+    async fn execute(&self, query: Preview) -> Result<Self::Ok, Self::Err> {
+        let Preview { name, locale } = query;
+
+        self.config().templates.preview(&name, locale)
+    }
+}
@@ -0,0 +1,13 @@
+//! [`Query`] collection related to the trash of deleted entities.
+
+use common::operations::By;
+
+use crate::read;
+#[cfg(doc)]
+use crate::Query;
+
+use super::DatabaseQuery;
+
+/// Queries a list of deleted entities, which may be restored yet.
+pub type List =
+    DatabaseQuery<By<read::trash::list::Page, read::trash::list::Selector>>;
@@ -2,11 +2,26 @@
 
 use common::operations::By;
 
-use crate::domain::{user, User};
+use crate::{
+    domain::{ical, user, User},
+    infra::cache,
+};
 #[cfg(doc)]
-use crate::Query;
+use crate::{infra::Cache, Query};
 
-use super::DatabaseQuery;
+use super::{CacheKey, CachedDatabaseQuery, DatabaseQuery};
+
+/// Queries an [`ical::Feed`] by the [`ical::TokenHash`] of its
+/// [`ical::Token`].
+pub type CalendarFeed = DatabaseQuery<By<Option<ical::Feed>, ical::TokenHash>>;
 
 /// Queries a [`User`] by its [`user::Id`].
-pub type ById = DatabaseQuery<By<Option<User>, user::Id>>;
+///
+/// Results are stored in the [`Cache`].
+pub type ById = CachedDatabaseQuery<By<Option<User>, user::Id>>;
+
+impl CacheKey<Option<User>> for user::Id {
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::key::user(*self))
+    }
+}
@@ -9,16 +9,42 @@ use crate::Query;
 use crate::{
     domain::{user, User},
     read,
+    task::refresh_total_counts::TotalCounts,
 };
 
-use super::DatabaseQuery;
+use super::{CachedTotalCount, DatabaseQuery, TotalCountQuery};
 
 /// Queries multiple [`User`]s by their [`user::Id`]s.
 pub type ByIds = DatabaseQuery<By<HashMap<user::Id, User>, Vec<user::Id>>>;
 
+/// Queries all the [`User`]s to be exported.
+pub type Export = DatabaseQuery<By<Vec<User>, read::user::Export>>;
+
 /// Queries a list of [`User`]s.
 pub type List =
     DatabaseQuery<By<read::user::list::Page, read::user::list::Selector>>;
 
-/// Queries total count of [`User`]s.
-pub type TotalCount = DatabaseQuery<By<read::user::list::TotalCount, ()>>;
+/// Queries total count of [`User`]s matching a [`read::user::list::Filter`].
+pub type TotalCount =
+    TotalCountQuery<read::user::list::TotalCount, read::user::list::Filter>;
+
+impl CachedTotalCount for read::user::list::TotalCount {
+    type Filter = read::user::list::Filter;
+
+    fn cached(counts: &TotalCounts, filter: &Self::Filter) -> Option<Self> {
+        // Only the `User`s of an `Agency` are cached.
+        let read::user::list::Filter {
+            agency_id: Some(agency_id),
+            branch_id: None,
+            name: None,
+            sort_by: _,
+        } = filter
+        else {
+            return None;
+        };
+        counts
+            .users
+            .as_ref()
+            .map(|c| c.get(agency_id).copied().unwrap_or(Self::from(0)))
+    }
+}
@@ -0,0 +1,40 @@
+//! [`Arrears`] read model definition.
+
+use common::Money;
+use rust_decimal::Decimal;
+
+use crate::domain::{contract, LateFee};
+#[cfg(doc)]
+use crate::domain::{Arrears, Payment};
+
+/// Selector of the [`contract::Rent`]s to detect [`Arrears`] of, in the order
+/// of their IDs.
+///
+/// Selects the active [`contract::Rent`]s along with the ended ones still
+/// being in [`Arrears`]. [`contract::Rent`]s pending a [`contract::Approval`]
+/// are not selected.
+#[derive(Clone, Copy, Debug)]
+pub struct Unsettled {
+    /// ID of the [`contract::Rent`] to select the ones after.
+    pub after: Option<contract::Id>,
+
+    /// Maximum number of the [`contract::Rent`]s to select.
+    pub limit: usize,
+}
+
+/// Balance of a [`contract::Rent`] payments.
+#[derive(Clone, Debug)]
+pub struct Balance {
+    /// [`contract::Rent`] this [`Balance`] is of.
+    pub contract: contract::Rent,
+
+    /// Total amount of the [`Payment`]s made under the [`contract::Rent`].
+    pub paid: Decimal,
+
+    /// Amount of the currently recorded [`Arrears`] of the
+    /// [`contract::Rent`], if any.
+    pub arrears: Option<Money>,
+
+    /// [`LateFee`] charged under the [`contract::Rent`], if any.
+    pub late_fee: Option<LateFee>,
+}
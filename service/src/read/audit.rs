@@ -0,0 +1,57 @@
+//! [`audit::Entry`]-related read definitions.
+
+#[cfg(doc)]
+use crate::domain::audit;
+
+pub mod list {
+    //! [`audit::Entry`]s list definitions.
+
+    use common::{define_pagination, DateTime};
+    use uuid::Uuid;
+
+    #[cfg(doc)]
+    use crate::domain::{Agency, User};
+    use crate::domain::{agency, audit, user};
+
+    define_pagination!(Cursor, Node, Filter);
+
+    /// Node in a [`Connection`].
+    pub type Node = audit::Entry;
+
+    /// Cursor pointing to a specific [`audit::Entry`] in a list.
+    ///
+    /// [`audit::Entry`]s are listed in their chronological order.
+    pub type Cursor = audit::Id;
+
+    /// Filter for [`Selector`].
+    #[derive(Clone, Debug)]
+    pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        pub agency_id: agency::Id,
+
+        /// ID of the [`User`] who performed the recorded actions.
+        ///
+        /// [`None`] means any [`User`].
+        pub actor_id: Option<user::Id>,
+
+        /// [`audit::Entity`] kind the recorded actions were performed on.
+        ///
+        /// [`None`] means any [`audit::Entity`].
+        pub entity: Option<audit::Entity>,
+
+        /// ID of the entity the recorded actions were performed on.
+        ///
+        /// [`None`] means any entity.
+        pub entity_id: Option<Uuid>,
+
+        /// [`DateTime`] (inclusive) to list the [`audit::Entry`]s since.
+        ///
+        /// [`None`] means no lower bound.
+        pub start: Option<DateTime>,
+
+        /// [`DateTime`] (exclusive) to list the [`audit::Entry`]s until.
+        ///
+        /// [`None`] means no upper bound.
+        pub end: Option<DateTime>,
+    }
+}
@@ -0,0 +1,23 @@
+//! [`Charge`] read model definition.
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::domain::{agency, contract, Charge};
+
+/// Selector of the active [`contract::Rent`]s of the [`Agency`] with the
+/// provided ID, along with their [`Charge`]s, in the order of their IDs.
+///
+/// [`contract::Rent`]s pending a [`contract::Approval`] are not selected.
+#[derive(Clone, Copy, Debug)]
+pub struct Active(pub agency::Id);
+
+/// [`contract::Rent`] along with the [`Charge`]s billed under it.
+#[derive(Clone, Debug)]
+pub struct Rental {
+    /// [`contract::Rent`] the [`Charge`]s are billed under.
+    pub contract: contract::Rent,
+
+    /// [`Charge`]s billed under the [`contract::Rent`], in the order they
+    /// were added.
+    pub charges: Vec<Charge>,
+}
@@ -1,39 +1,262 @@
 //! [`Contract`] read model definition.
 
+use common::DateTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{agency, contract, user};
 #[cfg(doc)]
-use crate::domain::Contract;
+use crate::domain::{task::DeadLetter, Agency, Contract, User};
 
 /// Wrapper around [`Contract`] indicating that it [`is_active()`].
 ///
 /// [`is_active()`]: Contract::is_active
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
 pub struct Active<T>(pub T);
 
+/// Selector of the expired [`Contract`]s, which are neither completed nor
+/// terminated yet, in the order of their expiration.
+///
+/// [`Contract`]s pending a [`contract::Approval`] are not selected, as they're
+/// not active yet.
+#[derive(Clone, Copy, Debug)]
+pub struct Expired {
+    /// Maximum number of the [`Contract`]s to select.
+    pub limit: usize,
+
+    /// Name of the task, which [`DeadLetter`]s are excluded from the
+    /// selection.
+    pub task: &'static str,
+}
+
+/// Selector of the active [`Contract`]s a [`User`] takes part in, expiring
+/// since the provided [`DateTime`], in the order of their expiration.
+#[derive(Clone, Copy, Debug)]
+pub struct Expiring {
+    /// ID of the [`User`] taking part in the [`Contract`]s.
+    pub user_id: user::Id,
+
+    /// [`DateTime`] (inclusive) the [`Contract`]s expire since.
+    pub since: DateTime,
+
+    /// Maximum number of the [`Contract`]s to select.
+    pub limit: usize,
+}
+
+/// [`Contract`]s attributed to an employer [`User`] according to their
+/// [`commission::Split`]s.
+///
+/// [`commission::Split`]: contract::commission::Split
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Attribution {
+    /// Number of the [`Contract`]s the [`User`] takes part in.
+    pub contracts: list::TotalCount,
+
+    /// Sum of the commission shares of the [`Contract`]s attributed to the
+    /// [`User`], as ratios (`1` being a whole [`Contract`]).
+    pub credit: Decimal,
+}
+
+/// Numbers of the [`Contract`]s of an [`Agency`], grouped by their
+/// [`contract::Status`] and [`contract::Kind`].
+///
+/// Absent combinations have no [`Contract`]s at all.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counts(
+    pub Vec<(contract::Status, contract::Kind, list::TotalCount)>,
+);
+
+impl Counts {
+    /// Returns the number of the [`Contract`]s of the provided
+    /// [`contract::Status`] and [`contract::Kind`].
+    #[must_use]
+    pub fn get(
+        &self,
+        status: contract::Status,
+        kind: contract::Kind,
+    ) -> list::TotalCount {
+        self.0
+            .iter()
+            .find(|(s, k, _)| *s == status && *k == kind)
+            .map_or_else(|| 0.into(), |(_, _, count)| *count)
+    }
+}
+
+/// Selector of the active [`contract::Employment`]s of the [`User`]s
+/// reporting directly to the [`User`] with the provided ID.
+#[derive(Clone, Copy, Debug)]
+pub struct DirectReports(pub user::Id);
+
+/// Selector of the pending [`contract::Approval`]s of an [`Agency`].
+#[derive(Clone, Copy, Debug)]
+pub struct PendingApprovals {
+    /// ID of the [`Agency`] the [`Contract`]s belong to.
+    pub agency_id: agency::Id,
+}
+
+/// Selector of a [`Contract`] by its [`contract::Number`] in an [`Agency`].
+#[derive(Clone, Debug)]
+pub struct ByNumber {
+    /// ID of the [`Agency`] the [`Contract`] belongs to.
+    pub agency_id: agency::Id,
+
+    /// [`contract::Number`] of the [`Contract`].
+    pub number: contract::Number,
+}
+
+/// Sequence of the [`contract::Number`]s of the [`Contract`]s of the same
+/// [`contract::Kind`], created in the same [`Agency`] within the same year.
+#[derive(Clone, Copy, Debug)]
+pub struct NumberSequence {
+    /// ID of the [`Agency`] the [`Contract`]s belong to.
+    pub agency_id: agency::Id,
+
+    /// [`contract::Kind`] of the [`Contract`]s.
+    pub kind: contract::Kind,
+
+    /// Year the [`Contract`]s are created in.
+    pub year: i32,
+}
+
 pub mod list {
     //! [`Contract`]s list definitions.
 
-    use std::ops;
+    use std::{fmt, ops, str::FromStr};
 
     use common::define_pagination;
     use derive_more::{From, Into};
+    use serde::{Deserialize, Serialize};
 
-    use crate::domain::contract;
-    #[cfg(doc)]
-    use crate::domain::Contract;
+    use crate::{
+        domain::{agency, branch, contract, Contract},
+        read::cursor,
+    };
 
     define_pagination!(Cursor, Node, Filter);
 
     /// Node in a [`Connection`].
-    pub type Node = (contract::Id, contract::Kind);
+    pub type Node = Contract;
 
     /// Cursor pointing to a specific [`Contract`] in a list.
-    pub type Cursor = contract::Id;
+    ///
+    /// Encodes the [`SortKey`] of the [`Contract`] along with its ID, so the
+    /// list may be continued regardless of the [`SortBy`] order.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct Cursor {
+        /// [`SortKey`] of the [`Contract`] this [`Cursor`] points to.
+        pub key: SortKey,
+
+        /// ID of the [`Contract`] this [`Cursor`] points to.
+        pub id: contract::Id,
+    }
+
+    impl Cursor {
+        /// Creates a new [`Cursor`] pointing to the provided [`Contract`] in a
+        /// list ordered by the provided [`SortBy`].
+        ///
+        /// `relevance` is only used for [`SortBy::Relevance`].
+        #[must_use]
+        pub fn new(sort_by: SortBy, node: &Contract, relevance: f32) -> Self {
+            Self {
+                key: match sort_by {
+                    SortBy::Relevance => SortKey::Relevance(relevance),
+                    SortBy::Name => SortKey::Name(node.name().clone()),
+                    SortBy::CreatedAt => SortKey::CreatedAt(node.created_at()),
+                },
+                id: node.id(),
+            }
+        }
+    }
+
+    impl From<contract::Id> for Cursor {
+        fn from(id: contract::Id) -> Self {
+            Self {
+                key: SortKey::Relevance(0.0),
+                id,
+            }
+        }
+    }
+
+    impl fmt::Display for Cursor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&cursor::encode(self))
+        }
+    }
+
+    impl FromStr for Cursor {
+        type Err = cursor::DecodeError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            cursor::decode(s)
+        }
+    }
+
+    /// Order of [`Contract`]s in a list.
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub enum SortBy {
+        /// Relevance to the [`Filter::name`] being searched for.
+        ///
+        /// Equals to ordering by ID if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// [`contract::Name`] in lexicographical order.
+        Name,
+
+        /// [`contract::CreationDateTime`] in chronological order.
+        CreatedAt,
+    }
+
+    /// Value of a [`SortBy`] key of a specific [`Contract`].
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub enum SortKey {
+        /// Word similarity distance to the [`Filter::name`] being searched for.
+        Relevance(f32),
+
+        /// [`contract::Name`] of the [`Contract`].
+        Name(contract::Name),
+
+        /// [`contract::CreationDateTime`] of the [`Contract`].
+        CreatedAt(contract::CreationDateTime),
+    }
+
+    impl SortKey {
+        /// Returns [`SortBy`] order this [`SortKey`] belongs to.
+        #[must_use]
+        pub const fn sort_by(&self) -> SortBy {
+            match self {
+                Self::Relevance(_) => SortBy::Relevance,
+                Self::Name(_) => SortBy::Name,
+                Self::CreatedAt(_) => SortBy::CreatedAt,
+            }
+        }
+    }
 
     /// Filter for [`Selector`].
     #[derive(Clone, Debug, Default)]
     pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        ///
+        /// [`None`] means all the [`Agency`]s.
+        ///
+        /// [`Agency`]: crate::domain::Agency
+        pub agency_id: Option<agency::Id>,
+
+        /// ID of the [`Branch`] to restrict the list to the
+        /// [`contract::Employment`]s of.
+        ///
+        /// [`Branch`]: crate::domain::Branch
+        pub branch_id: Option<branch::Id>,
+
         /// [`contract::Name`] (or its part) to fuzzy search for.
         pub name: Option<contract::Name>,
+
+        /// Indicator whether the archived [`Contract`]s should be listed too.
+        pub include_archived: bool,
+
+        /// [`SortBy`] order of the list.
+        pub sort_by: SortBy,
     }
 
     /// Total count of [`Contract`]s.
@@ -0,0 +1,46 @@
+//! Opaque pagination cursors encoding.
+
+use std::fmt::Write as _;
+
+use derive_more::{Display, Error};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Encodes the provided cursor `value` into an opaque token.
+#[expect(clippy::missing_panics_doc, reason = "infallible")]
+#[must_use]
+pub fn encode<T: Serialize>(value: &T) -> String {
+    let bytes = serde_json::to_vec(value).expect("infallible");
+    bytes.iter().fold(
+        String::with_capacity(bytes.len() * 2),
+        |mut token, b| {
+            _ = write!(token, "{b:02x}");
+            token
+        },
+    )
+}
+
+/// Decodes the provided opaque `token` into a cursor value.
+///
+/// # Errors
+///
+/// Returns [`DecodeError`] if the `token` wasn't produced by [`encode()`].
+pub fn decode<T: DeserializeOwned>(token: &str) -> Result<T, DecodeError> {
+    if !token.len().is_multiple_of(2) {
+        return Err(DecodeError);
+    }
+    let bytes = (0..token.len())
+        .step_by(2)
+        .map(|i| {
+            token
+                .get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(DecodeError)?;
+    serde_json::from_slice(&bytes).map_err(|_| DecodeError)
+}
+
+/// Error of [`decode()`]ing a malformed cursor token.
+#[derive(Clone, Copy, Debug, Display, Error)]
+#[display("malformed cursor")]
+pub struct DecodeError;
@@ -0,0 +1,41 @@
+//! [`Dashboard`] read model definition.
+
+use common::{DateTime, Money};
+
+use crate::domain::{agency, contract};
+#[cfg(doc)]
+use crate::domain::{contract::Rent, Agency, Contract, Lead, Payment, Realty};
+
+/// Summary of an [`Agency`] activity for its back-office home screen.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Dashboard {
+    /// Number of the placed [`Realty`]s.
+    pub active_placements: i32,
+
+    /// Number of the active [`Contract`]s expiring until the
+    /// [`Selector::expiring_until`].
+    pub expiring_contracts: i32,
+
+    /// Number of the [`Lead`]s which are not closed yet.
+    pub open_leads: i32,
+
+    /// Number of the [`Rent`]s with overdue payments.
+    pub overdue_payments: i32,
+
+    /// Total amounts of the [`Payment`]s made since the
+    /// [`Selector::revenue_since`], one per a currency.
+    pub revenue: Vec<Money>,
+}
+
+/// Selector of a [`Dashboard`].
+#[derive(Clone, Copy, Debug)]
+pub struct Selector {
+    /// ID of the [`Agency`] to summarize.
+    pub agency_id: agency::Id,
+
+    /// [`DateTime`] until which the expiring [`Contract`]s are counted.
+    pub expiring_until: contract::ExpirationDateTime,
+
+    /// [`DateTime`] since which the revenue is summed up.
+    pub revenue_since: DateTime,
+}
@@ -0,0 +1,41 @@
+//! [`Insurance`] read model definition.
+
+use common::DateTime;
+
+use crate::domain::{agency, insurance, user, Insurance};
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, User};
+
+/// Selector of the [`Insurance`]s of the active [`Contract`]s, expiring by
+/// the provided [`DateTime`], whose employer [`User`]s are not notified yet,
+/// in the order of their IDs.
+#[derive(Clone, Copy, Debug)]
+pub struct Unnotified {
+    /// [`DateTime`] the [`Insurance`]s expire by.
+    pub expiring_by: DateTime,
+
+    /// ID of the [`Insurance`] to select the ones after.
+    pub after: Option<insurance::Id>,
+
+    /// Maximum number of the [`Insurance`]s to select.
+    pub limit: usize,
+}
+
+/// Expiring [`Insurance`] along with the employer [`User`] responsible for
+/// its [`Contract`].
+#[derive(Clone, Debug)]
+pub struct Expiring {
+    /// Expiring [`Insurance`].
+    pub insurance: Insurance,
+
+    /// ID of the employer [`User`] responsible for the [`Contract`].
+    pub employer_id: user::Id,
+
+    /// [`user::Email`] of the employer [`User`] responsible for the
+    /// [`Contract`], if any.
+    pub employer_email: Option<user::Email>,
+
+    /// Default [`agency::Locale`] of the [`Agency`] the [`Insurance`] belongs
+    /// to, if any.
+    pub locale: Option<agency::Locale>,
+}
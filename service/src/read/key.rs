@@ -0,0 +1,18 @@
+//! [`Key`] read model definition.
+
+use common::DateTime;
+
+use crate::domain::agency;
+#[cfg(doc)]
+use crate::domain::{key::Checkout, Agency, Key};
+
+/// Selector of the checked out [`Key`]s of an [`Agency`], not returned by
+/// their [`Checkout::due_at`], in the order of their due [`DateTime`]s.
+#[derive(Clone, Copy, Debug)]
+pub struct Overdue {
+    /// ID of the [`Agency`] to select the [`Key`]s of.
+    pub agency_id: agency::Id,
+
+    /// [`DateTime`] the [`Key`]s are overdue at.
+    pub at: DateTime,
+}
@@ -0,0 +1,34 @@
+//! [`Lead`]-related read definitions.
+
+#[cfg(doc)]
+use crate::domain::Lead;
+
+pub mod list {
+    //! [`Lead`]s list definitions.
+
+    use common::define_pagination;
+
+    #[cfg(doc)]
+    use crate::domain::Agency;
+    use crate::domain::{agency, lead, Lead};
+
+    define_pagination!(Cursor, Node, Filter);
+
+    /// Node in a [`Connection`].
+    pub type Node = Lead;
+
+    /// Cursor pointing to a specific [`Lead`] in a list.
+    pub type Cursor = lead::Id;
+
+    /// Filter for [`Selector`].
+    #[derive(Clone, Debug)]
+    pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        pub agency_id: agency::Id,
+
+        /// [`lead::Stage`]s to restrict the list to.
+        ///
+        /// [`None`] means all the [`lead::Stage`]s.
+        pub stages: Option<Vec<lead::Stage>>,
+    }
+}
@@ -0,0 +1,18 @@
+//! External listing read model definitions.
+
+#[cfg(doc)]
+use crate::domain::listing::Sync;
+use crate::domain::listing;
+
+/// Selector of the latest [`Sync`]s of external listing feeds, in the reverse
+/// order of their start.
+#[derive(Clone, Debug)]
+pub struct History {
+    /// [`listing::Source`] to select the [`Sync`]s of.
+    ///
+    /// [`None`] selects the [`Sync`]s of all the sources.
+    pub source: Option<listing::Source>,
+
+    /// Maximum number of the [`Sync`]s to select.
+    pub limit: usize,
+}
@@ -1,8 +1,32 @@
 //! Read entities definitions.
 
+pub mod arrears;
+pub mod audit;
+pub mod charge;
 pub mod contract;
+pub mod cursor;
+pub mod dashboard;
+pub mod insurance;
+pub mod key;
+pub mod lead;
+pub mod listing;
+pub mod offer;
+pub mod payment;
 pub mod placement;
 pub mod realty;
+pub mod review;
+pub mod saved_search;
+pub mod search;
+pub mod snapshot;
+pub mod task;
+pub mod time_series;
+pub mod trash;
 pub mod user;
 
-pub use self::placement::Placement;
+pub use self::{
+    dashboard::Dashboard, placement::Placement, time_series::TimeSeries,
+};
+
+/// Estimated (approximate) value of a `T`.
+#[derive(Clone, Copy, Debug)]
+pub struct Estimated<T>(pub T);
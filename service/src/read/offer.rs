@@ -0,0 +1,13 @@
+//! [`Offer`] read model definition.
+
+use crate::domain::contract;
+#[cfg(doc)]
+use crate::domain::{Contract, Offer};
+
+/// Selector of the [`Offer`]s negotiating the terms of the concluded
+/// [`Contract`] with the provided ID, in the order of their submission.
+///
+/// Includes the accepted [`Offer`] linked to the [`Contract`] along with all
+/// the [`Offer`]s it counters (directly or transitively).
+#[derive(Clone, Copy, Debug)]
+pub struct Negotiation(pub contract::Id);
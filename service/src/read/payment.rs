@@ -0,0 +1,32 @@
+//! [`Payment`] read model definition.
+
+use common::DateTime;
+
+use crate::domain::{agency, payment};
+#[cfg(doc)]
+use crate::domain::{journal::Entry, Agency, Payment};
+
+/// Selector of the [`Payment`]s of an [`Agency`] made within the provided
+/// period, in the order they were made.
+#[derive(Clone, Copy, Debug)]
+pub struct Period {
+    /// ID of the [`Agency`] to select the [`Payment`]s of.
+    pub agency_id: agency::Id,
+
+    /// [`DateTime`] (inclusive) the [`Payment`]s are made since.
+    pub since: DateTime,
+
+    /// [`DateTime`] (exclusive) the [`Payment`]s are made until.
+    pub until: DateTime,
+}
+
+/// Selector of the [`Payment`]s, which journal [`Entry`]s are not pushed into
+/// an external accounting system yet, in the order of their IDs.
+#[derive(Clone, Copy, Debug)]
+pub struct Unexported {
+    /// ID of the [`Payment`] to select the ones after.
+    pub after: Option<payment::Id>,
+
+    /// Maximum number of the [`Payment`]s to select.
+    pub limit: usize,
+}
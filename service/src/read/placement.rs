@@ -1,11 +1,13 @@
 //! [`Placement`] read model definition.
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(doc)]
-use crate::domain::Realty;
+use crate::domain::{sitemap, Realty};
 use crate::domain::{contract, realty};
 
 /// Placement of a [`Realty`] in the real estate market.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct Placement {
     /// ID of the placed [`Realty`].
     pub realty_id: realty::Id,
@@ -17,14 +19,27 @@ pub struct Placement {
     pub sale_contract_id: Option<contract::Id>,
 }
 
+/// Selector of the [`sitemap::Entry`]s of all the [`Placement`]s, in the order
+/// of the placed [`Realty`] IDs.
+#[derive(Clone, Copy, Debug)]
+pub struct Sitemap {
+    /// ID of the placed [`Realty`] to select the [`sitemap::Entry`]s after.
+    pub after: Option<realty::Id>,
+
+    /// Maximum number of the [`sitemap::Entry`]s to select.
+    pub limit: usize,
+}
+
 pub mod list {
     //! [`Placement`]s list definitions.
 
-    use common::define_pagination;
+    use common::{define_pagination, Money};
     use derive_more::{From, Into};
     use smart_default::SmartDefault;
 
-    use crate::domain::realty;
+    #[cfg(doc)]
+    use crate::domain::{contract, Realty};
+    use crate::domain::{agency, realty};
 
     use super::Placement;
 
@@ -37,8 +52,15 @@ pub mod list {
     pub type Cursor = realty::Id;
 
     /// Filter for [`Selector`].
-    #[derive(Clone, Copy, Debug, SmartDefault)]
+    #[derive(Clone, Debug, SmartDefault)]
     pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        ///
+        /// [`None`] means all the [`Agency`]s.
+        ///
+        /// [`Agency`]: crate::domain::Agency
+        pub agency_id: Option<agency::Id>,
+
         /// Include sale [`Placement`].
         #[default(true)]
         pub sale: bool,
@@ -46,9 +68,177 @@ pub mod list {
         /// Include rent [`Placement`].
         #[default(true)]
         pub rent: bool,
+
+        /// [`realty::Address`] (or its part) of the placed [`Realty`] to
+        /// fuzzy search for.
+        pub address: Option<realty::Address>,
+
+        /// IDs of the placed [`Realty`]s to restrict the list to.
+        ///
+        /// Used instead of the [`Filter::address`] fuzzy matching, once the
+        /// matching [`Placement`]s are found by a [`Search`].
+        ///
+        /// [`Search`]: crate::infra::Search
+        pub realty_ids: Option<Vec<realty::Id>>,
     }
 
     /// Total count of [`Placement`]s.
     #[derive(Clone, Copy, Debug, Eq, From, Hash, Into, PartialEq)]
     pub struct TotalCount(i32);
+
+    /// Lower bounds of the [`PriceFacet`]s, in any currency.
+    pub const PRICE_BUCKETS: [u32; 12] = [
+        0, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000, 250_000,
+        500_000, 1_000_000,
+    ];
+
+    /// Counts of the [`Placement`]s matching a [`Filter`], grouped by their
+    /// different properties.
+    #[derive(Clone, Debug, Default)]
+    pub struct Facets {
+        /// [`CityFacet`]s, in the descending order of their counts.
+        pub cities: Vec<CityFacet>,
+
+        /// Number of the [`Placement`]s for rent.
+        pub rent: i32,
+
+        /// Number of the [`Placement`]s for sale.
+        pub sale: i32,
+
+        /// [`PriceFacet`]s of the [`Placement`]s for rent, in the order of
+        /// their currencies and prices.
+        pub rent_prices: Vec<PriceFacet>,
+
+        /// [`PriceFacet`]s of the [`Placement`]s for sale, in the order of
+        /// their currencies and prices.
+        pub sale_prices: Vec<PriceFacet>,
+    }
+
+    /// Number of the [`Placement`]s in a [`realty::City`].
+    #[derive(Clone, Debug)]
+    pub struct CityFacet {
+        /// [`realty::City`] of the placed [`Realty`]s.
+        pub city: realty::City,
+
+        /// Number of the [`Placement`]s in the [`realty::City`].
+        pub count: i32,
+    }
+
+    /// Number of the [`Placement`]s within a [`PRICE_BUCKETS`] price range.
+    ///
+    /// Prices are the expected ones of the [`contract::ManagementForRent`]s
+    /// and [`contract::ManagementForSale`]s.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PriceFacet {
+        /// Minimal price (inclusive) of the range.
+        pub min: Money,
+
+        /// Maximal price (exclusive) of the range.
+        ///
+        /// [`None`] means the range is unbounded.
+        pub max: Option<Money>,
+
+        /// Number of the [`Placement`]s within the range.
+        pub count: i32,
+    }
+}
+
+pub mod feed {
+    //! Public [`Placement`]s feed definitions.
+
+    use common::{DateTime, Money};
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Serialize};
+
+    #[cfg(doc)]
+    use crate::domain::{contract, Agency, MediaLink, Realty};
+    use crate::domain::{agency, realty};
+
+    #[cfg(doc)]
+    use super::Placement;
+
+    /// Selector of a [`Page`] of the public [`Placement`]s feed of an
+    /// [`Agency`], in the order of the placed [`Realty`] IDs.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Selector {
+        /// ID of the [`Agency`] to select the [`Placement`]s of.
+        pub agency_id: agency::Id,
+
+        /// Maximum number of the [`Item`]s in the [`Page`].
+        pub first: usize,
+
+        /// ID of the placed [`Realty`] to select the [`Item`]s after.
+        ///
+        /// [`None`] means the first [`Page`].
+        pub after: Option<realty::Id>,
+    }
+
+    /// Page of the public [`Placement`]s feed.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Page {
+        /// [`Item`]s of this [`Page`].
+        pub items: Vec<Item>,
+
+        /// Indicator whether there are more [`Item`]s after this [`Page`].
+        pub has_next_page: bool,
+    }
+
+    /// [`Placement`] in the public feed, denormalized along with its placed
+    /// [`Realty`].
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Item {
+        /// ID of the placed [`Realty`].
+        pub realty_id: realty::Id,
+
+        /// [`realty::Address`] of the placed [`Realty`].
+        pub address: realty::Address,
+
+        /// [`realty::Area`] of the placed [`Realty`], in square meters.
+        pub area: Option<Decimal>,
+
+        /// [`realty::RoomNum`] of the placed [`Realty`].
+        pub room_num: Option<String>,
+
+        /// [`realty::Floor`] of the placed [`Realty`].
+        pub floor: Option<realty::Floor>,
+
+        /// [`realty::NumFloors`] of the placed [`Realty`] building.
+        pub num_floors: realty::NumFloors,
+
+        /// [`Offer`] of the [`Realty`] for rent, if any.
+        pub rent: Option<Offer>,
+
+        /// [`Offer`] of the [`Realty`] for sale, if any.
+        pub sale: Option<Offer>,
+
+        /// [`FloorPlan`]s of the placed [`Realty`].
+        pub floor_plans: Vec<FloorPlan>,
+
+        /// URLs of the [`MediaLink`]s of the placed [`Realty`].
+        pub media_links: Vec<String>,
+    }
+
+    /// Offer of a placed management [`contract::ManagementForRent`] or
+    /// [`contract::ManagementForSale`].
+    #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+    pub struct Offer {
+        /// Expected price of the [`Realty`].
+        pub price: Money,
+
+        /// [`DateTime`] when the [`Realty`] was placed.
+        pub placed_at: Option<DateTime>,
+    }
+
+    /// Floor plan of a placed [`Realty`].
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct FloorPlan {
+        /// [`realty::Floor`] the [`FloorPlan`] depicts.
+        pub floor: realty::Floor,
+
+        /// Label of the [`FloorPlan`].
+        pub label: String,
+
+        /// URL of the [`FloorPlan`] file.
+        pub url: String,
+    }
 }
@@ -1,11 +1,24 @@
 //! [`Realty`]-related read definitions.
 
+use std::ops::Range;
+
+use common::DateTime;
 use derive_more::Deref;
+use rust_decimal::Decimal;
 
+use crate::domain::{agency, realty};
 #[cfg(doc)]
-use crate::domain::Realty;
+use crate::domain::{contract, Agency, Contract, Realty};
+
+/// Wrapper around a [`Realty`] indicating that it's deleted, but not purged
+/// yet, so may be restored.
+#[derive(Clone, Copy, Debug)]
+pub struct Deleted<T>(pub T);
 
 /// Indicator whether a [`Realty`] is rented or not.
+///
+/// [`Realty`] is not rented by a [`contract::Rent`] pending a
+/// [`contract::Approval`].
 #[derive(Clone, Copy, Debug, Deref, Eq, Hash, PartialEq)]
 pub struct IsRented(pub bool);
 
@@ -15,29 +28,194 @@ impl PartialEq<bool> for IsRented {
     }
 }
 
+/// Purpose a [`Realty`] is managed for.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Purpose {
+    /// [`Realty`] is rented out.
+    Rent,
+
+    /// [`Realty`] is sold.
+    Sale,
+}
+
+/// Selector of the prices of the recent [`Contract`]s on the [`Realty`]s
+/// comparable to some one, from the most recent [`Contract`].
+#[derive(Clone, Debug)]
+pub struct Comparables {
+    /// ID of the [`Realty`] to compare with, so its own [`Contract`]s are
+    /// excluded.
+    pub realty_id: realty::Id,
+
+    /// ID of the [`Agency`] the [`Contract`]s belong to.
+    pub agency_id: agency::Id,
+
+    /// [`Purpose`] of the [`Contract`]s.
+    pub purpose: Purpose,
+
+    /// [`realty::Country`] the comparable [`Realty`]s are located in.
+    pub country: realty::Country,
+
+    /// [`realty::City`] the comparable [`Realty`]s are located in.
+    pub city: realty::City,
+
+    /// [`realty::Kind`] of the comparable [`Realty`]s.
+    pub kind: realty::Kind,
+
+    /// Range of square meters the [`realty::Area`]s of the comparable
+    /// [`Realty`]s fall into, if the [`Realty`] to compare with has one.
+    pub area: Option<Range<Decimal>>,
+
+    /// [`DateTime`] the [`Contract`]s must be created since.
+    pub since: DateTime,
+
+    /// Maximum number of the [`Contract`] prices to select.
+    pub limit: u16,
+}
+
 pub mod list {
     //! [`Realty`] list definitions.
 
+    use std::{fmt, str::FromStr};
+
     use common::define_pagination;
     use derive_more::{From, Into};
+    use serde::{Deserialize, Serialize};
 
-    use crate::domain::realty;
-    #[cfg(doc)]
-    use crate::domain::Realty;
+    use crate::{
+        domain::{agency, realty, user, Realty},
+        read::cursor,
+    };
 
     define_pagination!(Cursor, Node, Filter);
 
     /// Node in a [`Connection`].
-    pub type Node = realty::Id;
+    pub type Node = Realty;
 
     /// Cursor pointing to a specific [`Realty`] in a list.
-    pub type Cursor = realty::Id;
+    ///
+    /// Encodes the [`SortKey`] of the [`Realty`] along with its ID, so the
+    /// list may be continued regardless of the [`SortBy`] order.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct Cursor {
+        /// [`SortKey`] of the [`Realty`] this [`Cursor`] points to.
+        pub key: SortKey,
+
+        /// ID of the [`Realty`] this [`Cursor`] points to.
+        pub id: realty::Id,
+    }
+
+    impl Cursor {
+        /// Creates a new [`Cursor`] pointing to the provided [`Realty`] in a
+        /// list ordered by the provided [`SortBy`].
+        ///
+        /// `relevance` is only used for [`SortBy::Relevance`].
+        #[must_use]
+        pub fn new(sort_by: SortBy, node: &Realty, relevance: f32) -> Self {
+            Self {
+                key: match sort_by {
+                    SortBy::Relevance => SortKey::Relevance(relevance),
+                    SortBy::Address => SortKey::Address(node.address.clone()),
+                    SortBy::CreatedAt => SortKey::CreatedAt(node.created_at),
+                },
+                id: node.id,
+            }
+        }
+    }
+
+    impl From<realty::Id> for Cursor {
+        fn from(id: realty::Id) -> Self {
+            Self {
+                key: SortKey::Relevance(0.0),
+                id,
+            }
+        }
+    }
+
+    impl fmt::Display for Cursor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&cursor::encode(self))
+        }
+    }
+
+    impl FromStr for Cursor {
+        type Err = cursor::DecodeError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            cursor::decode(s)
+        }
+    }
+
+    /// Order of [`Realty`]s in a list.
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub enum SortBy {
+        /// Relevance to the [`Filter::address`] being searched for.
+        ///
+        /// Equals to ordering by ID if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// [`realty::Address`] in lexicographical order.
+        Address,
+
+        /// [`realty::CreationDateTime`] in chronological order.
+        CreatedAt,
+    }
+
+    /// Value of a [`SortBy`] key of a specific [`Realty`].
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub enum SortKey {
+        /// Word similarity distance to the [`Filter::address`] being searched
+        /// for.
+        Relevance(f32),
+
+        /// [`realty::Address`] of the [`Realty`].
+        Address(realty::Address),
+
+        /// [`realty::CreationDateTime`] of the [`Realty`].
+        CreatedAt(realty::CreationDateTime),
+    }
+
+    impl SortKey {
+        /// Returns [`SortBy`] order this [`SortKey`] belongs to.
+        #[must_use]
+        pub const fn sort_by(&self) -> SortBy {
+            match self {
+                Self::Relevance(_) => SortBy::Relevance,
+                Self::Address(_) => SortBy::Address,
+                Self::CreatedAt(_) => SortBy::CreatedAt,
+            }
+        }
+    }
 
     /// Filter for [`Selector`].
     #[derive(Clone, Debug, Default)]
     pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        ///
+        /// [`None`] means all the [`Agency`]s.
+        ///
+        /// [`Agency`]: crate::domain::Agency
+        pub agency_id: Option<agency::Id>,
+
+        /// ID of the [`User`] to restrict the list to the [`Realty`]s
+        /// assigned to.
+        ///
+        /// [`User`]: crate::domain::User
+        pub assignee_id: Option<user::Id>,
+
         /// [`realty::Address`] (or its part) to fuzzy search for.
         pub address: Option<realty::Address>,
+
+        /// IDs of the [`Realty`]s to restrict the list to.
+        ///
+        /// Used instead of the [`Filter::address`] fuzzy matching, once the
+        /// matching [`Realty`]s are found by a [`Search`].
+        ///
+        /// [`Search`]: crate::infra::Search
+        pub ids: Option<Vec<realty::Id>>,
+
+        /// [`SortBy`] order of the list.
+        pub sort_by: SortBy,
     }
 
     /// Total count of [`Realty`] list items.
@@ -0,0 +1,15 @@
+//! [`Review`] read model definition.
+
+#[cfg(doc)]
+use crate::domain::{review, Review, User};
+
+/// Rating of an employer [`User`] aggregated over all its published
+/// [`Review`]s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rating {
+    /// Average [`review::Rating`] of the employer.
+    pub average: f64,
+
+    /// Number of the published [`Review`]s of the employer.
+    pub count: u32,
+}
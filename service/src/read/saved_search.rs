@@ -0,0 +1,54 @@
+//! [`SavedSearch`] read model definition.
+
+use common::DateTime;
+
+use crate::domain::{agency, realty, saved_search, user, SavedSearch};
+#[cfg(doc)]
+use crate::{
+    domain::{Agency, Realty, User},
+    read::Placement,
+};
+
+/// Maximum number of the placed [`Realty`]s listed in a [`Matched`].
+pub const MAX_MATCHED_REALTIES: usize = 10;
+
+/// Selector of the [`SavedSearch`]es having [`Placement`]s placed since they
+/// were last alerted about, in the order of their IDs.
+#[derive(Clone, Copy, Debug)]
+pub struct Unalerted {
+    /// [`DateTime`] the [`Placement`]s are placed by.
+    ///
+    /// The ones placed later are left for the next alert.
+    pub placed_by: DateTime,
+
+    /// ID of the [`SavedSearch`] to select the ones after.
+    pub after: Option<saved_search::Id>,
+
+    /// Maximum number of the [`SavedSearch`]es to select.
+    pub limit: usize,
+}
+
+/// [`SavedSearch`] along with the newly placed [`Realty`]s matching it.
+#[derive(Clone, Debug)]
+pub struct Matched {
+    /// [`SavedSearch`] being matched.
+    pub search: SavedSearch,
+
+    /// Total number of the newly placed [`Realty`]s matching the
+    /// [`SavedSearch`].
+    pub count: u32,
+
+    /// IDs of the first [`MAX_MATCHED_REALTIES`] matching [`Realty`]s.
+    pub realty_ids: Vec<realty::Id>,
+
+    /// [`realty::Address`]es of the first [`MAX_MATCHED_REALTIES`] matching
+    /// [`Realty`]s.
+    pub addresses: Vec<realty::Address>,
+
+    /// [`user::Email`] of the [`User`] owning the [`SavedSearch`], if any.
+    pub user_email: Option<user::Email>,
+
+    /// Default [`agency::Locale`] of the [`Agency`] the [`SavedSearch`]
+    /// belongs to, if any.
+    pub locale: Option<agency::Locale>,
+}
@@ -0,0 +1,77 @@
+//! Global search read model definitions.
+
+use common::Money;
+use smart_default::SmartDefault;
+
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, Realty, User};
+use crate::domain::{agency, contract, realty, user};
+#[cfg(doc)]
+use crate::read::Placement;
+
+/// Entity matching a global search.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Match {
+    /// Matching [`User`].
+    User(user::Id),
+
+    /// Matching [`Realty`].
+    Realty(realty::Id),
+
+    /// Matching [`Contract`].
+    Contract(contract::Id),
+
+    /// Matching [`Placement`] of the [`Realty`] with the provided ID.
+    Placement(realty::Id),
+}
+
+/// Selector of [`Match`]es, ordered by their relevance.
+#[derive(Clone, Debug)]
+pub struct Selector {
+    /// Text to fuzzy search for.
+    pub query: String,
+
+    /// Maximum number of the selected [`Match`]es.
+    pub limit: usize,
+
+    /// ID of the [`Agency`] to restrict the [`Realty`], [`Contract`] and
+    /// [`Placement`] [`Match`]es to.
+    ///
+    /// [`None`] means all the [`Agency`]s.
+    pub agency_id: Option<agency::Id>,
+
+    /// Price the [`Placement`] [`Match`]es are ranked by the proximity to.
+    ///
+    /// [`None`] disables the [`Ranking::price_proximity`].
+    pub price: Option<Money>,
+
+    /// [`Ranking`] of the [`Placement`] [`Match`]es.
+    pub ranking: Ranking,
+}
+
+/// Weights of the [`Placement`] [`Match`]es relevance factors.
+///
+/// Each factor is normalized into the `[0, 1]` range, and the weighted sum of
+/// them is compared with the text distances of the other [`Match`]es, so the
+/// weights around `1` keep all the [`Match`]es comparable.
+#[derive(Clone, Copy, Debug, PartialEq, SmartDefault)]
+pub struct Ranking {
+    /// Weight of the text distance between the [`Placement`] address and the
+    /// searched text.
+    #[default(1.0)]
+    pub text_match: f64,
+
+    /// Weight of the [`Placement`] freshness, decaying to a half in
+    /// [`RECENCY_HALF_LIFE_DAYS`] since it has been placed.
+    pub recency_boost: f64,
+
+    /// Weight of the relative distance between the [`Placement`] price and the
+    /// searched [`Selector::price`].
+    ///
+    /// [`Placement`]s in other currencies are ranked as the most distant ones.
+    pub price_proximity: f64,
+}
+
+/// Number of days a [`Placement`] loses a half of its
+/// [`Ranking::recency_boost`] in.
+pub const RECENCY_HALF_LIFE_DAYS: u32 = 30;
@@ -0,0 +1,43 @@
+//! Logical snapshot read model definitions.
+
+#[cfg(doc)]
+use crate::infra::database::Snapshot;
+
+/// Single row of an entity, captured by a [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// Name of the entity this [`Record`] belongs to.
+    pub entity: &'static str,
+
+    /// JSON object of this [`Record`]'s fields.
+    pub data: String,
+
+    /// [`Position`] of this [`Record`] in the [`Snapshot`].
+    pub position: Position,
+}
+
+/// Position of a [`Record`] in a [`Snapshot`], to continue selecting the
+/// [`Record`]s after.
+#[derive(Clone, Debug)]
+pub struct Position {
+    /// Name of the entity the [`Record`] belongs to.
+    pub entity: &'static str,
+
+    /// Opaque position of the [`Record`] among the ones of its entity.
+    pub row: String,
+}
+
+/// Selector of the next [`Record`]s of all the entities in a [`Snapshot`].
+///
+/// [`Record`]s are selected entity by entity, in a stable order, so an empty
+/// selection means the end of the [`Snapshot`].
+#[derive(Clone, Debug)]
+pub struct Chunk {
+    /// [`Position`] of the last already selected [`Record`].
+    ///
+    /// [`None`] selects from the very beginning.
+    pub after: Option<Position>,
+
+    /// Maximum number of the [`Record`]s to select.
+    pub limit: usize,
+}
@@ -0,0 +1,30 @@
+//! Periodic task read model definitions.
+
+#[cfg(doc)]
+use crate::domain::task::{DeadLetter, Run};
+
+/// Selector of the latest [`Run`]s of periodic tasks, in the reverse order of
+/// their start.
+#[derive(Clone, Debug)]
+pub struct History {
+    /// Name of the task to select the [`Run`]s of.
+    ///
+    /// [`None`] selects the [`Run`]s of all the tasks.
+    pub task: Option<String>,
+
+    /// Maximum number of the [`Run`]s to select.
+    pub limit: usize,
+}
+
+/// Selector of the [`DeadLetter`]s of periodic tasks, starting from the latest
+/// failed one.
+#[derive(Clone, Debug)]
+pub struct DeadLetters {
+    /// Name of the task to select the [`DeadLetter`]s of.
+    ///
+    /// [`None`] selects the [`DeadLetter`]s of all the tasks.
+    pub task: Option<String>,
+
+    /// Maximum number of the [`DeadLetter`]s to select.
+    pub limit: usize,
+}
@@ -0,0 +1,70 @@
+//! [`TimeSeries`] read model definition.
+
+use common::{DateTime, TimeZone};
+
+use crate::domain::agency;
+#[cfg(doc)]
+use crate::domain::{Agency, Contract};
+
+/// Numbers of the [`Agency`] activity events, bucketed by time.
+///
+/// Buckets without any events are omitted.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TimeSeries(pub Vec<Point>);
+
+/// Point of a [`TimeSeries`], counting the events of a single bucket.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Point {
+    /// Start of the bucket.
+    pub at: DateTime,
+
+    /// Number of the [`Contract`]s placed within the bucket.
+    pub placements: i32,
+
+    /// Number of the [`Contract`]s signed by all their parties within the
+    /// bucket.
+    pub signed_contracts: i32,
+
+    /// Number of the [`Contract`]s terminated within the bucket.
+    pub terminations: i32,
+}
+
+/// Granularity of a [`TimeSeries`] buckets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Granularity {
+    /// Daily buckets.
+    Day,
+
+    /// Weekly buckets, starting on Mondays.
+    Week,
+}
+
+impl Granularity {
+    /// Returns the SQL `date_trunc()` field of this [`Granularity`].
+    #[must_use]
+    pub const fn as_sql(self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+/// Selector of a [`TimeSeries`].
+#[derive(Clone, Copy, Debug)]
+pub struct Selector {
+    /// ID of the [`Agency`] to count the events of.
+    pub agency_id: agency::Id,
+
+    /// [`Granularity`] of the buckets.
+    pub granularity: Granularity,
+
+    /// Start of the period (inclusive).
+    pub start: DateTime,
+
+    /// End of the period (inclusive).
+    pub end: DateTime,
+
+    /// [`TimeZone`] which calendar the buckets are aligned to.
+    pub time_zone: TimeZone,
+}
@@ -0,0 +1,98 @@
+//! Trash read model definitions, listing the deleted entities, which may be
+//! restored yet.
+
+pub mod list {
+    //! Trash [`Item`]s list definitions.
+
+    use std::{fmt, str::FromStr};
+
+    use common::{define_pagination, DateTime};
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    #[cfg(doc)]
+    use crate::domain::{Agency, Contract, Realty, User};
+    use crate::{
+        domain::{agency, audit, user},
+        read::cursor,
+    };
+
+    define_pagination!(Cursor, Node, Filter);
+
+    /// Node in a [`Connection`].
+    pub type Node = Item;
+
+    /// Deleted entity in the trash.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct Item {
+        /// [`audit::Entity`] kind of this [`Item`].
+        pub entity: audit::Entity,
+
+        /// ID of the deleted entity.
+        pub id: Uuid,
+
+        /// [`DateTime`] when the entity was deleted.
+        ///
+        /// For [`Contract`]s, this is the [`DateTime`] they were archived at.
+        pub deleted_at: DateTime,
+
+        /// ID of the [`User`] who deleted the entity.
+        ///
+        /// [`None`] if the entity was deleted by the system (like unused
+        /// [`Realty`]s), or the deletion wasn't recorded into the audit log.
+        pub deleted_by: Option<user::Id>,
+    }
+
+    /// Cursor pointing to a specific [`Item`] in a list.
+    ///
+    /// [`Item`]s are listed in the chronological order of their deletion.
+    #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct Cursor {
+        /// [`DateTime`] when the entity this [`Cursor`] points to was deleted.
+        pub deleted_at: DateTime,
+
+        /// ID of the entity this [`Cursor`] points to.
+        pub id: Uuid,
+    }
+
+    impl From<&Item> for Cursor {
+        fn from(item: &Item) -> Self {
+            Self {
+                deleted_at: item.deleted_at,
+                id: item.id,
+            }
+        }
+    }
+
+    impl fmt::Display for Cursor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&cursor::encode(self))
+        }
+    }
+
+    impl FromStr for Cursor {
+        type Err = cursor::DecodeError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            cursor::decode(s)
+        }
+    }
+
+    /// Filter for [`Selector`].
+    #[derive(Clone, Copy, Debug)]
+    pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to.
+        pub agency_id: agency::Id,
+
+        /// Indicator whether deleted [`User`]s should be listed too.
+        ///
+        /// [`User`]s are not bound to any [`Agency`], so are only listed for
+        /// the default one.
+        pub include_users: bool,
+
+        /// [`audit::Entity`] kind to restrict the list to.
+        ///
+        /// [`None`] means any [`audit::Entity`].
+        pub entity: Option<audit::Entity>,
+    }
+}
@@ -1,30 +1,164 @@
 //! [`User`] read model definition.
-//!
-//! [`User`]: crate::domain::User
+
+#[cfg(doc)]
+use crate::domain::{user::Invite, Agency, Branch, User};
+use crate::domain::{agency, branch};
+
+/// Wrapper around a [`User`] indicating that it's deleted, so may be
+/// restored.
+#[derive(Clone, Debug)]
+pub struct Deleted<T>(pub T);
+
+/// Selector of all the non-deleted [`User`]s of an [`Agency`] to be exported,
+/// in the order of their creation.
+///
+/// [`User`]s of an [`Agency`] are the ones actively employed by it, or having
+/// a pending [`Invite`] into it.
+#[derive(Clone, Copy, Debug)]
+pub struct Export {
+    /// ID of the [`Agency`] to export the [`User`]s of.
+    pub agency_id: agency::Id,
+
+    /// ID of the [`Branch`] to restrict the export to the employees of.
+    pub branch_id: Option<branch::Id>,
+}
 
 pub mod list {
     //! [`User`]s list definitions.
 
+    use std::{fmt, str::FromStr};
+
     use common::define_pagination;
     use derive_more::{From, Into};
+    use serde::{Deserialize, Serialize};
 
-    use crate::domain::user;
-    #[cfg(doc)]
-    use crate::domain::User;
+    use crate::{
+        domain::{agency, branch, user, User},
+        read::cursor,
+    };
 
     define_pagination!(Cursor, Node, Filter);
 
     /// Node in a [`Connection`].
-    pub type Node = user::Id;
+    pub type Node = User;
 
     /// Cursor pointing to a specific [`User`] in a list.
-    pub type Cursor = user::Id;
+    ///
+    /// Encodes the [`SortKey`] of the [`User`] along with its ID, so the
+    /// list may be continued regardless of the [`SortBy`] order.
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub struct Cursor {
+        /// [`SortKey`] of the [`User`] this [`Cursor`] points to.
+        pub key: SortKey,
+
+        /// ID of the [`User`] this [`Cursor`] points to.
+        pub id: user::Id,
+    }
+
+    impl Cursor {
+        /// Creates a new [`Cursor`] pointing to the provided [`User`] in a
+        /// list ordered by the provided [`SortBy`].
+        ///
+        /// `relevance` is only used for [`SortBy::Relevance`].
+        #[must_use]
+        pub fn new(sort_by: SortBy, node: &User, relevance: f32) -> Self {
+            Self {
+                key: match sort_by {
+                    SortBy::Relevance => SortKey::Relevance(relevance),
+                    SortBy::Name => SortKey::Name(node.name.clone()),
+                    SortBy::CreatedAt => SortKey::CreatedAt(node.created_at),
+                },
+                id: node.id,
+            }
+        }
+    }
+
+    impl From<user::Id> for Cursor {
+        fn from(id: user::Id) -> Self {
+            Self {
+                key: SortKey::Relevance(0.0),
+                id,
+            }
+        }
+    }
+
+    impl fmt::Display for Cursor {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&cursor::encode(self))
+        }
+    }
+
+    impl FromStr for Cursor {
+        type Err = cursor::DecodeError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            cursor::decode(s)
+        }
+    }
+
+    /// Order of [`User`]s in a list.
+    #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+    pub enum SortBy {
+        /// Relevance to the [`Filter::name`] being searched for.
+        ///
+        /// Equals to ordering by ID if nothing is searched for.
+        #[default]
+        Relevance,
+
+        /// [`user::Name`] in lexicographical order.
+        Name,
+
+        /// [`user::CreationDateTime`] in chronological order.
+        CreatedAt,
+    }
+
+    /// Value of a [`SortBy`] key of a specific [`User`].
+    #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+    pub enum SortKey {
+        /// Word similarity distance to the [`Filter::name`] being searched for.
+        Relevance(f32),
+
+        /// [`user::Name`] of the [`User`].
+        Name(user::Name),
+
+        /// [`user::CreationDateTime`] of the [`User`].
+        CreatedAt(user::CreationDateTime),
+    }
+
+    impl SortKey {
+        /// Returns [`SortBy`] order this [`SortKey`] belongs to.
+        #[must_use]
+        pub const fn sort_by(&self) -> SortBy {
+            match self {
+                Self::Relevance(_) => SortBy::Relevance,
+                Self::Name(_) => SortBy::Name,
+                Self::CreatedAt(_) => SortBy::CreatedAt,
+            }
+        }
+    }
 
     /// Filter for [`Selector`].
     #[derive(Clone, Debug, Default)]
     pub struct Filter {
+        /// ID of the [`Agency`] to restrict the list to the [`User`]s of.
+        ///
+        /// [`User`]s of an [`Agency`] are the ones actively employed by it, or
+        /// having a pending [`Invite`] into it.
+        ///
+        /// [`Agency`]: crate::domain::Agency
+        /// [`Invite`]: crate::domain::user::Invite
+        pub agency_id: Option<agency::Id>,
+
+        /// ID of the [`Branch`] to restrict the list to the employees of.
+        ///
+        /// [`Branch`]: crate::domain::Branch
+        pub branch_id: Option<branch::Id>,
+
         /// [`user::Name`] (or its part) to fuzzy search for.
         pub name: Option<user::Name>,
+
+        /// [`SortBy`] order of the list.
+        pub sort_by: SortBy,
     }
 
     /// Total count of [`User`]s.
@@ -0,0 +1,191 @@
+//! [`AlertSavedSearches`] [`Task`].
+
+use std::{convert::Infallible, error::Error};
+
+use common::{
+    operations::{By, Insert, Perform, Select, Start},
+    DateTime,
+};
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+#[cfg(doc)]
+use crate::domain::User;
+use crate::{
+    domain::{task, SavedSearch},
+    infra::{database, email, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`AlertSavedSearches`] [`Task`].
+const NAME: &str = "task::AlertSavedSearches";
+
+/// Number of [`SavedSearch`]es alerted about at once.
+const BATCH_SIZE: usize = 100;
+
+/// Configuration for [`AlertSavedSearches`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of matching the newly placed [`read::Placement`]s.
+    pub schedule: Schedule,
+}
+
+/// [`Task`] for periodically matching the newly placed [`read::Placement`]s
+/// against the [`SavedSearch`]es, alerting their [`User`]s about the matching
+/// ones.
+///
+/// Every [`read::saved_search::Matched`] is sent to the subscribers of
+/// [`Service::saved_search_matches()`] and emailed to the [`User`]. Every
+/// [`read::Placement`] is alerted about only once per [`SavedSearch`].
+#[derive(Clone, Debug)]
+pub struct AlertSavedSearches<S> {
+    /// Sender of the [`read::saved_search::Matched`]es.
+    matches: broadcast::Sender<read::saved_search::Matched>,
+
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                AlertSavedSearches<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<read::saved_search::Matched>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    AlertSavedSearches<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<Insert<task::Run>, Ok = (), Err = Traced<database::Error>>,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                AlertSavedSearches<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<read::saved_search::Matched>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, matches, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |_| {
+                let task = AlertSavedSearches {
+                    matches: matches.clone(),
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for AlertSavedSearches<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<
+                    Vec<read::saved_search::Matched>,
+                    read::saved_search::Unalerted,
+                >,
+            >,
+            Ok = Vec<read::saved_search::Matched>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<SavedSearch>, Err = Traced<database::Error>>,
+{
+    type Ok = u64;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        // `Placement`s placed during the matching are left for the next run.
+        let now = DateTime::now();
+        let (mut count, mut after) = (0, None);
+        loop {
+            let matches = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<read::saved_search::Matched>, _>::new(
+                    read::saved_search::Unalerted {
+                        placed_by: now,
+                        after,
+                        limit: BATCH_SIZE,
+                    },
+                )))
+                .await
+                .map_err(tracerr::wrap!())?;
+            let is_last = matches.len() < BATCH_SIZE;
+            after = matches.last().map(|m| m.search.id);
+
+            for mut m in matches {
+                m.search.alerted_at = now.coerce();
+                self.service
+                    .database()
+                    .execute(Insert(m.search.clone()))
+                    .await
+                    .map_err(tracerr::wrap!())
+                    .map(drop)?;
+                count += 1;
+
+                log::info!(
+                    "{} new `Placement`s match `SavedSearch(id: {})` of \
+                     `User(id: {})`",
+                    m.count,
+                    m.search.id,
+                    m.search.user_id,
+                );
+                if let Some(to) = m.user_email.clone() {
+                    self.service.send_email(
+                        to,
+                        m.locale,
+                        &email::Template::SavedSearchMatched {
+                            count: m.count,
+                            addresses: m.addresses.clone(),
+                        },
+                    );
+                }
+                _ = self.matches.send(m);
+            }
+
+            if is_last || self.cancel.is_cancelled() {
+                break;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Error of [`AlertSavedSearches`] execution.
+pub type ExecutionError = Traced<database::Error>;
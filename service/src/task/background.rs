@@ -4,37 +4,188 @@ use std::{
     error::Error,
     future::{Future, IntoFuture},
     iter,
+    time::Duration,
 };
 
 use futures::{
-    future::{self, LocalBoxFuture},
+    future::{self, Either, LocalBoxFuture},
     FutureExt as _, TryFutureExt as _,
 };
-use tokio::task;
+use smart_default::SmartDefault;
+use tokio::{
+    task,
+    time::{self, Instant},
+};
+use tokio_util::sync::CancellationToken;
+use tracing as log;
 
 #[cfg(doc)]
 use crate::Task;
 
+/// Policy of restarting a failed [`Task`] with an exponential backoff.
+#[derive(Clone, Copy, Debug, SmartDefault)]
+pub struct Restart {
+    /// Maximum number of consecutive restarts, after which the failed [`Task`]
+    /// is given up.
+    ///
+    /// [`None`] means restarting indefinitely.
+    pub max_restarts: Option<u32>,
+
+    /// Delay before the first restart.
+    ///
+    /// Each next delay is twice as long, up to the [`Restart::max_backoff`].
+    #[default(Duration::from_secs(1))]
+    pub initial_backoff: Duration,
+
+    /// Maximum delay between restarts.
+    ///
+    /// A [`Task`] running longer than this delay before failing is considered
+    /// recovered, so its restarts are counted anew.
+    #[default(Duration::from_mins(1))]
+    pub max_backoff: Duration,
+}
+
 /// Background environment for running [`Task`]s.
+///
+/// Each spawned [`Task`] is supervised: once it fails or panics, the failure
+/// is reported and the [`Task`] is restarted according to the [`Restart`]
+/// policy, without affecting the other ones.
+///
+/// Each spawned [`Task`] is provided with a [`CancellationToken`], cancelled on
+/// graceful shutdown, so the [`Task`] may stop cooperatively.
 #[derive(Debug, Default)]
 pub struct Background {
+    /// [`Restart`] policy of the spawned [`Task`]s.
+    restart: Restart,
+
+    /// [`CancellationToken`] of the spawned [`Task`]s.
+    cancel: CancellationToken,
+
     /// Local set of tasks.
     set: task::LocalSet,
 
     /// Handles of spawned tasks.
-    handles: Vec<task::JoinHandle<Result<(), Box<dyn Error + 'static>>>>,
+    handles: Vec<task::JoinHandle<()>>,
 }
 
 impl Background {
-    /// Spawns a new [`Task`] inside the [`Background`] environment.
-    pub fn spawn<F, E>(&mut self, future: F)
+    /// Creates a new [`Background`] environment restarting the failed
+    /// [`Task`]s according to the provided [`Restart`] policy.
+    #[must_use]
+    pub fn new(restart: Restart) -> Self {
+        Self {
+            restart,
+            ..Self::default()
+        }
+    }
+
+    /// Spawns a new supervised [`Task`] inside the [`Background`]
+    /// environment.
+    ///
+    /// The `start` function is called to start the [`Task`] initially and on
+    /// each restart, with the [`CancellationToken`] the [`Task`] should stop
+    /// on. Once the [`Restart::max_restarts`] are exhausted, the [`Task`] is
+    /// given up, leaving the other [`Task`]s running.
+    pub fn spawn<F, Fut, E>(&mut self, name: &'static str, mut start: F)
     where
-        F: Future<Output = Result<(), E>> + 'static,
+        F: FnMut(CancellationToken) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
         E: Error + 'static,
     {
-        self.handles.push(self.set.spawn_local(
-            future.map_err(|e| Box::<dyn Error + 'static>::from(Box::new(e))),
-        ));
+        let Restart {
+            max_restarts,
+            initial_backoff,
+            max_backoff,
+        } = self.restart;
+        let cancel = self.cancel.clone();
+        self.handles.push(self.set.spawn_local(async move {
+            let mut backoff = initial_backoff;
+            let mut restarts = 0;
+            loop {
+                let started = Instant::now();
+                let mut run =
+                    AbortOnDrop(task::spawn_local(start(cancel.clone())));
+                let e = match (&mut run.0).await {
+                    Ok(Ok(())) => return,
+                    Ok(Err(e)) => Box::<dyn Error + 'static>::from(Box::new(e)),
+                    Err(e) if e.is_cancelled() => return,
+                    Err(e) => Box::<dyn Error + 'static>::from(Box::new(e)),
+                };
+
+                if started.elapsed() >= max_backoff {
+                    backoff = initial_backoff;
+                    restarts = 0;
+                }
+                if max_restarts.is_some_and(|max| restarts >= max) {
+                    log::error!(
+                        "`{name}` failed, giving up after {restarts} \
+                         restarts: {e}",
+                    );
+                    return;
+                }
+                if cancel.is_cancelled() {
+                    log::error!("`{name}` failed while stopping: {e}");
+                    return;
+                }
+                log::error!("`{name}` failed, restarting in {backoff:?}: {e}");
+
+                tokio::select! {
+                    () = time::sleep(backoff) => {}
+                    () = cancel.cancelled() => return,
+                }
+                backoff = (backoff * 2).min(max_backoff);
+                restarts += 1;
+            }
+        }));
+    }
+
+    /// Runs this [`Background`] environment until all the spawned [`Task`]s
+    /// complete or the provided `signal` resolves.
+    ///
+    /// Once the `signal` resolves, the [`CancellationToken`] of the spawned
+    /// [`Task`]s is cancelled, so they finish their in-progress work and stop.
+    /// The [`Task`]s not stopped within the provided `timeout` are aborted at
+    /// their next `.await` point, and the returned [`Future`] resolves as soon
+    /// as they're dropped. Any uncommitted database transaction held by an
+    /// aborted [`Task`] is rolled back on drop.
+    pub fn with_graceful_shutdown<S>(
+        self,
+        signal: S,
+        timeout: Duration,
+    ) -> LocalBoxFuture<'static, Result<(), Box<dyn Error>>>
+    where
+        S: Future<Output = ()> + 'static,
+    {
+        let cancel = self.cancel.clone();
+        let aborts = self
+            .handles
+            .iter()
+            .map(task::JoinHandle::abort_handle)
+            .collect::<Vec<_>>();
+
+        future::select(self.into_future(), signal.boxed_local())
+            .then(move |res| match res {
+                Either::Left((res, _)) => future::ready(res).boxed_local(),
+                Either::Right(((), mut run)) => {
+                    cancel.cancel();
+                    async move {
+                        tokio::select! {
+                            res = &mut run => return res,
+                            () = time::sleep(timeout) => {}
+                        }
+                        log::warn!(
+                            "background tasks haven't stopped in \
+                             {timeout:?}, aborting them",
+                        );
+                        for h in aborts {
+                            h.abort();
+                        }
+                        run.await
+                    }
+                    .boxed_local()
+                }
+            })
+            .boxed_local()
     }
 }
 
@@ -43,12 +194,17 @@ impl IntoFuture for Background {
     type IntoFuture = LocalBoxFuture<'static, Self::Output>;
 
     fn into_future(self) -> Self::IntoFuture {
-        let Self { set, handles } = self;
+        let Self {
+            restart: _,
+            cancel: _,
+            set,
+            handles,
+        } = self;
         future::try_join_all(iter::once(set.map(Ok).boxed_local()).chain(
             handles.into_iter().map(|h| {
                 h.map(|r| match r {
-                    Ok(Ok(())) => Ok(()),
-                    Ok(Err(e)) => Err(e),
+                    Ok(()) => Ok(()),
+                    Err(e) if e.is_cancelled() => Ok(()),
                     Err(e) => {
                         Err(Box::<dyn Error + 'static>::from(Box::new(e)))
                     }
@@ -60,3 +216,14 @@ impl IntoFuture for Background {
         .boxed_local()
     }
 }
+
+/// Guard aborting the guarded [`task::JoinHandle`] on drop.
+///
+/// Ensures a supervised [`Task`] doesn't outlive its cancelled supervisor.
+struct AbortOnDrop<T>(task::JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -2,31 +2,65 @@
 
 use std::{convert::Infallible, error::Error, time};
 
-use common::operations::{By, Delete, Perform, Start};
-use tokio::time::interval;
+use common::operations::{By, Delete, Insert, Perform, Select, Start};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
 use tracerr::Traced;
-use tracing as log;
 
 use crate::{
-    domain::{realty, Realty},
+    domain::{realty, task, Realty},
     infra::{database, Database},
     Service,
 };
 
-use super::Task;
+#[cfg(doc)]
+use super::RetentionPurge;
+use super::{
+    scheduler::{self, Schedule, Timing},
+    Task,
+};
 
 /// Configuration for [`CleanUnusedRealties`] [`Task`].
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct Config {
-    /// Interval between [`Realty`] entities cleaning.
-    pub interval: time::Duration,
+    /// [`Schedule`] of [`Realty`] entities cleaning.
+    pub schedule: Schedule,
 
     /// Timeout after which a [`Realty`] is considered unused.
     pub timeout: time::Duration,
+
+    /// Persisted [`task::Settings`] overriding this [`Config`], as seen by the
+    /// last run.
+    pub settings: Option<task::Settings>,
+}
+
+impl Config {
+    /// Returns the effective [`Schedule`], considering [`Config::settings`].
+    #[must_use]
+    pub fn schedule(&self) -> Schedule {
+        match self.settings.and_then(|s| s.interval) {
+            Some(interval) => Schedule {
+                timing: Timing::Every(interval),
+                jitter: self.schedule.jitter,
+            },
+            None => self.schedule.clone(),
+        }
+    }
+
+    /// Returns the effective timeout, considering [`Config::settings`].
+    #[must_use]
+    pub fn timeout(&self) -> time::Duration {
+        self.settings
+            .and_then(|s| s.timeout)
+            .unwrap_or(self.timeout)
+    }
 }
 
 /// [`Task`] for cleaning unused [`Realty`] entities.
-#[derive(Clone, Copy, Debug)]
+///
+/// Unused [`Realty`] entities are soft-deleted only, and purged completely by
+/// the [`RetentionPurge`] [`Task`] later.
+#[derive(Clone, Debug)]
 pub struct CleanUnusedRealties<S> {
     /// [`Config`] of this [`Task`].
     config: Config,
@@ -35,10 +69,23 @@ pub struct CleanUnusedRealties<S> {
     service: S,
 }
 
-impl<Db> Task<Start<By<CleanUnusedRealties<Self>, Config>>> for Service<Db>
+impl<Db>
+    Task<
+        Start<
+            By<
+                CleanUnusedRealties<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
+    > for Service<Db>
 where
     CleanUnusedRealties<Service<Db>>:
-        Task<Perform<()>, Ok = (), Err: Error> + Send + Sync + 'static,
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
     Self: Clone,
 {
     type Ok = ();
@@ -46,37 +93,70 @@ where
 
     async fn execute(
         &self,
-        Start(by): Start<By<CleanUnusedRealties<Self>, Config>>,
+        Start(by): Start<
+            By<
+                CleanUnusedRealties<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
     ) -> Result<Self::Ok, Self::Err> {
-        let config = by.into_inner();
-        let task = CleanUnusedRealties {
+        let (config, cancel) = by.into_inner();
+        scheduler::run(
+            "task::CleanUnusedRealties",
+            &self.task_statuses,
             config,
-            service: self.clone(),
-        };
-
-        let mut interval = interval(task.config.interval);
-        loop {
-            let _ = interval.tick().await;
-            _ = task.execute(Perform(())).await.map_err(|e| {
-                log::error!("`task::CleanUnusedRealties` failed: {e}");
-            });
-        }
+            Config::schedule,
+            |config| {
+                let task = CleanUnusedRealties {
+                    config,
+                    service: self.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
     }
 }
 
 impl<Db> Task<Perform<()>> for CleanUnusedRealties<Service<Db>>
 where
     Db: Database<
-        Delete<By<Realty, realty::CreationDateTime>>,
-        Ok = (),
-        Err = Traced<database::Error>,
-    >,
+            Select<By<Option<task::Settings>, task::Kind>>,
+            Ok = Option<task::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<Realty, realty::CreationDateTime>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        >,
 {
-    type Ok = ();
+    type Ok = u64;
     type Err = ExecutionError;
 
     async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
-        let deadline = realty::CreationDateTime::now() - self.config.timeout;
+        let settings = self
+            .service
+            .database()
+            .execute(Select(By::<Option<task::Settings>, _>::new(
+                task::Kind::CleanUnusedRealties,
+            )))
+            .await
+            .map_err(tracerr::wrap!())?;
+        // Reschedules the next run, if the interval has been changed.
+        self.service.apply_task_settings(
+            settings.unwrap_or_else(|| {
+                task::Settings::new(task::Kind::CleanUnusedRealties)
+            }),
+        );
+
+        let config = Config {
+            settings,
+            ..self.config.clone()
+        };
+        let deadline = realty::CreationDateTime::now() - config.timeout();
         self.service
             .database()
             .execute(Delete(By::new(deadline)))
@@ -0,0 +1,350 @@
+//! [`CompleteExpiredContracts`] [`Task`].
+
+use std::{convert::Infallible, error::Error};
+
+use common::{
+    operations::{
+        By, Commit, Delete, Insert, Lock, Perform, Select, Start, Transact,
+        Transacted,
+    },
+    DateTime,
+};
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+use crate::{
+    domain::{contract, ownership, realty, task, Contract, Ownership, Realty},
+    infra::{cache, database, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`CompleteExpiredContracts`] [`Task`].
+const NAME: &str = "task::CompleteExpiredContracts";
+
+/// Number of expired [`Contract`]s selected at once.
+const BATCH_SIZE: usize = 100;
+
+/// Configuration for [`CompleteExpiredContracts`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of expired [`Contract`]s completion.
+    pub schedule: Schedule,
+}
+
+/// [`Task`] for periodically completing the expired [`Contract`]s, which are
+/// not terminated.
+///
+/// Completed [`Contract`]s are deplaced (if placed) and sent to the
+/// subscribers of [`Service::completed_contracts()`]. Completed
+/// [`contract::Sale`]s transfer the [`Ownership`] of their [`Realty`] to the
+/// purchaser. [`Contract`]s failed to
+/// be completed are recorded as [`task::DeadLetter`]s, and skipped until
+/// requeued.
+#[derive(Clone, Debug)]
+pub struct CompleteExpiredContracts<S> {
+    /// Sender of the completed [`Contract`]s.
+    completed: broadcast::Sender<Contract>,
+
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                CompleteExpiredContracts<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<Contract>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    CompleteExpiredContracts<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                CompleteExpiredContracts<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<Contract>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, completed, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |_| {
+                let task = CompleteExpiredContracts {
+                    completed: completed.clone(),
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for CompleteExpiredContracts<Service<Db>>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Vec<Contract>, read::contract::Expired>>,
+            Ok = Vec<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Insert<task::DeadLetter>,
+            Ok = (),
+            Err = Traced<database::Error>,
+        >,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<ownership::Transfer>, Err = Traced<database::Error>>
+        + Database<
+            Delete<By<Ownership, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<Ownership>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = u64;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let (mut count, mut deplaced) = (0, false);
+        loop {
+            let expired = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<Contract>, _>::new(
+                    read::contract::Expired {
+                        limit: BATCH_SIZE,
+                        task: NAME,
+                    },
+                )))
+                .await
+                .map_err(tracerr::wrap!())?;
+            let is_last = expired.len() < BATCH_SIZE;
+
+            let mut progressed = false;
+            for contract in expired {
+                let res = self.complete(&contract).await;
+                let completed = match res {
+                    Ok(c) => c,
+                    // Will be retried on the next run.
+                    Err(e) if e.as_ref().is_conflict() => {
+                        log::warn!(
+                            "`{NAME}` skipped `Contract(id: {})`: {e}",
+                            contract.id(),
+                        );
+                        continue;
+                    }
+                    // Excluded from the next runs until requeued.
+                    Err(e) => {
+                        log::error!(
+                            "`{NAME}` dead-lettered `Contract(id: {})`: {e}",
+                            contract.id(),
+                        );
+                        self.service
+                            .database()
+                            .execute(Insert(task::DeadLetter {
+                                task: NAME.into(),
+                                item: contract.id().to_string(),
+                                error: e.to_string(),
+                                attempts: 1,
+                                failed_at: DateTime::now(),
+                            }))
+                            .await
+                            .map_err(tracerr::wrap!())?;
+                        progressed = true;
+                        continue;
+                    }
+                };
+                progressed = true;
+                let Some(completed) = completed else {
+                    continue;
+                };
+
+                log::info!("`Contract(id: {})` is completed", completed.id());
+                count += 1;
+                match &completed {
+                    Contract::Employment(c) => {
+                        self.service
+                            .invalidate_cache(&[cache::key::employment(
+                                c.employer_id,
+                            )])
+                            .await;
+                    }
+                    Contract::ManagementForRent(_)
+                    | Contract::ManagementForSale(_) => {
+                        deplaced = true;
+                    }
+                    Contract::Rent(_)
+                    | Contract::Sale(_)
+                    | Contract::ShortTermRent(_) => {}
+                }
+                _ = self.completed.send(completed);
+            }
+
+            // The completed batch is flushed below, even if cancelled.
+            if is_last || !progressed || self.cancel.is_cancelled() {
+                break;
+            }
+        }
+        if deplaced {
+            self.service.placements_changed().await;
+        }
+        Ok(count)
+    }
+}
+
+impl<Db> CompleteExpiredContracts<Service<Db>>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Contract, contract::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Lock<By<Realty, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Contract>, contract::Id>>,
+            Ok = Option<Contract>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<ownership::Transfer>, Err = Traced<database::Error>>
+        + Database<
+            Delete<By<Ownership, realty::Id>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Vec<Ownership>>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    /// Completes the provided expired [`Contract`], deplacing it, if placed,
+    /// and recording an [`ownership::Transfer`], if it's a
+    /// [`contract::Sale`].
+    ///
+    /// [`None`] is returned if the [`Contract`] has been completed or
+    /// terminated concurrently.
+    async fn complete(
+        &self,
+        contract: &Contract,
+    ) -> Result<Option<Contract>, ExecutionError> {
+        let tx = self
+            .service
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        if let Some(realty_id) = contract.realty_id() {
+            // Avoid concurrent actions upon the same `Realty`.
+            tx.execute(Lock(By::new(realty_id)))
+                .await
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        // Avoid concurrent terminations.
+        tx.execute(Lock(By::new(contract.id())))
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        let Some(mut contract) = tx
+            .execute(Select(By::<Option<Contract>, _>::new(contract.id())))
+            .await
+            .map_err(tracerr::wrap!())?
+            .filter(|c| c.terminated_at().is_none())
+            .filter(|c| c.completed_at().is_none())
+        else {
+            return Ok(None);
+        };
+
+        _ = contract.completed_at_mut().replace(DateTime::now().coerce());
+        if let Some(is_placed) = contract.is_placed_mut() {
+            // Placement of an expired management makes no sense.
+            *is_placed = false;
+        }
+
+        tx.execute(Insert(contract.clone()))
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        // Completed sale makes the purchaser the sole owner of the `Realty`.
+        if let Some(transfer) = match &contract {
+            Contract::Sale(c) => ownership::Transfer::of_sale(c),
+            Contract::Employment(_)
+            | Contract::ManagementForRent(_)
+            | Contract::ManagementForSale(_)
+            | Contract::Rent(_)
+            | Contract::ShortTermRent(_) => None,
+        } {
+            tx.execute(Insert(transfer))
+                .await
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+            tx.execute(Delete(By::<Ownership, _>::new(transfer.realty_id)))
+                .await
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+            tx.execute(Insert(vec![transfer.ownership()]))
+                .await
+                .map_err(tracerr::wrap!())
+                .map(drop)?;
+        }
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+
+        Ok(Some(contract))
+    }
+}
+
+/// Error of [`CompleteExpiredContracts`] execution.
+pub type ExecutionError = Traced<database::Error>;
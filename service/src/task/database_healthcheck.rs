@@ -0,0 +1,198 @@
+//! [`DatabaseHealthcheck`] [`Task`].
+
+use std::{convert::Infallible, error::Error, time};
+
+use common::{
+    operations::{By, Insert, Perform, Start},
+    DateTime,
+};
+use derive_more::{Display, Error as StdError, From};
+use tokio::{
+    sync::watch,
+    time::{timeout, Instant},
+};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+use crate::{
+    domain::task,
+    infra::{database, Database},
+    Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Configuration for [`DatabaseHealthcheck`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of [`Database`] pings.
+    pub schedule: Schedule,
+
+    /// Timeout after which a [`Database`] ping is considered failed.
+    pub timeout: time::Duration,
+}
+
+/// Health [`Status`] of a [`Database`], as seen by the last
+/// [`DatabaseHealthcheck`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Status {
+    /// Indicator whether the [`Database`] is reachable.
+    ///
+    /// [`false`] until the first ping succeeds.
+    pub reachable: bool,
+
+    /// Round-trip latency of the last successful ping.
+    pub latency: Option<time::Duration>,
+
+    /// [`DateTime`] of the last ping.
+    pub checked_at: Option<DateTime>,
+}
+
+/// [`Task`] for periodically pinging a [`Database`] and reporting its health
+/// [`Status`].
+#[derive(Clone, Debug)]
+pub struct DatabaseHealthcheck<S> {
+    /// [`Config`] of this [`Task`].
+    config: Config,
+
+    /// [`Service`] instance.
+    service: S,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                DatabaseHealthcheck<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Status>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    DatabaseHealthcheck<Service<Db>>:
+        Task<Perform<()>, Ok = time::Duration, Err: Error>
+            + Send
+            + Sync
+            + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                DatabaseHealthcheck<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Status>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, status, cancel) = by.into_inner();
+        let status = &status;
+        scheduler::run(
+            "task::DatabaseHealthcheck",
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            move |config| {
+                let task = DatabaseHealthcheck {
+                    config,
+                    service: self.clone(),
+                };
+                async move {
+                    let res = task.execute(Perform(())).await;
+                    let was_reachable = status.borrow().reachable;
+                    let new = match res {
+                        Ok(latency) => {
+                            if !was_reachable {
+                                log::info!(
+                                    "`Database` is reachable \
+                                     (latency: {latency:?})",
+                                );
+                            }
+                            Status {
+                                reachable: true,
+                                latency: Some(latency),
+                                checked_at: Some(DateTime::now()),
+                            }
+                        }
+                        Err(e) => {
+                            if was_reachable {
+                                log::error!("`Database` is unreachable: {e}");
+                            } else {
+                                log::warn!(
+                                    "`task::DatabaseHealthcheck` failed: {e}",
+                                );
+                            }
+                            Status {
+                                reachable: false,
+                                latency: None,
+                                checked_at: Some(DateTime::now()),
+                            }
+                        }
+                    };
+                    _ = status.send_replace(new);
+                    Ok::<_, Infallible>(0)
+                }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for DatabaseHealthcheck<Service<Db>>
+where
+    Db: Database<
+        Perform<database::Ping>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = time::Duration;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let start = Instant::now();
+        timeout(
+            self.config.timeout,
+            self.service.database().execute(Perform(database::Ping)),
+        )
+        .await
+        .map_err(|_| tracerr::new!(ExecutionError::Timeout))?
+        .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+        Ok(start.elapsed())
+    }
+}
+
+/// Error of [`DatabaseHealthcheck`] execution.
+#[derive(Debug, Display, From, StdError)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` ping failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Database`] ping timed out.
+    #[display("`Database` ping timed out")]
+    Timeout,
+}
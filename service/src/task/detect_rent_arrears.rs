@@ -0,0 +1,273 @@
+//! [`DetectRentArrears`] [`Task`].
+
+use std::{convert::Infallible, error::Error, time};
+
+use common::{
+    operations::{By, Delete, Insert, Perform, Select, Start},
+    DateTime,
+};
+use rust_decimal::Decimal;
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+#[cfg(doc)]
+use crate::domain::Payment;
+use crate::{
+    domain::{arrears::DetectionDateTime, contract, task, Arrears, LateFee},
+    infra::{database, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`DetectRentArrears`] [`Task`].
+const NAME: &str = "task::DetectRentArrears";
+
+/// Number of [`contract::Rent`]s checked at once.
+const BATCH_SIZE: usize = 100;
+
+/// Configuration for [`DetectRentArrears`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of [`Arrears`] detection.
+    pub schedule: Schedule,
+
+    /// Period after the due date of a rent installment, during which it's not
+    /// considered overdue yet.
+    pub grace_period: time::Duration,
+}
+
+/// [`Task`] for periodically detecting [`Arrears`] of [`contract::Rent`]s by
+/// comparing their due installments with the recorded [`Payment`]s.
+///
+/// [`LateFee`]s of the [`contract::Rent`]s are accrued for their overdue
+/// installments beforehand, and included into the [`Arrears`].
+///
+/// Newly detected or grown [`Arrears`] are sent to the subscribers of
+/// [`Service::arrears()`], while the settled ones are removed.
+#[derive(Clone, Debug)]
+pub struct DetectRentArrears<S> {
+    /// [`Config`] of this [`Task`].
+    config: Config,
+
+    /// Sender of the newly detected or grown [`Arrears`].
+    detected: broadcast::Sender<Arrears>,
+
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                DetectRentArrears<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<Arrears>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    DetectRentArrears<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<Insert<task::Run>, Ok = (), Err = Traced<database::Error>>,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                DetectRentArrears<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<Arrears>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, detected, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |config| {
+                let task = DetectRentArrears {
+                    config,
+                    detected: detected.clone(),
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for DetectRentArrears<Service<Db>>
+where
+    Db: Database<
+            Select<By<Vec<read::arrears::Balance>, read::arrears::Unsettled>>,
+            Ok = Vec<read::arrears::Balance>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Arrears>, Err = Traced<database::Error>>
+        + Database<Insert<LateFee>, Err = Traced<database::Error>>
+        + Database<
+            Delete<By<Arrears, contract::Id>>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = u64;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let now = DateTime::now().coerce();
+        let (mut count, mut after) = (0, None);
+        loop {
+            let balances = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<read::arrears::Balance>, _>::new(
+                    read::arrears::Unsettled {
+                        after,
+                        limit: BATCH_SIZE,
+                    },
+                )))
+                .await
+                .map_err(tracerr::wrap!())?;
+            let is_last = balances.len() < BATCH_SIZE;
+            after = balances.last().map(|b| b.contract.id);
+
+            for balance in balances {
+                let fees_before = balance
+                    .late_fee
+                    .map_or(Decimal::ZERO, |f| f.accrued.amount);
+                let late_fees = match balance.late_fee {
+                    Some(fee) => self
+                        .accrue(&balance, fee, now)
+                        .await?
+                        .map_or(fees_before, |f| f.accrued.amount),
+                    None => fees_before,
+                };
+
+                let detected = Arrears::detect(
+                    &balance.contract,
+                    balance.paid,
+                    late_fees,
+                    self.config.grace_period,
+                    now,
+                );
+                match (detected, balance.arrears) {
+                    (Some(arrears), recorded) => {
+                        if recorded == Some(arrears.amount) {
+                            continue;
+                        }
+                        self.service
+                            .database()
+                            .execute(Insert(arrears))
+                            .await
+                            .map_err(tracerr::wrap!())
+                            .map(drop)?;
+                        count += 1;
+
+                        // Only new debts are worth notifying about, while the
+                        // late fees are accrued on the already notified ones.
+                        let accrued = late_fees - fees_before;
+                        if recorded.is_none_or(|r| {
+                            r.amount + accrued < arrears.amount.amount
+                        }) {
+                            log::info!(
+                                "`Contract(id: {})` is in arrears of {}",
+                                arrears.contract_id,
+                                arrears.amount,
+                            );
+                            _ = self.detected.send(arrears);
+                        }
+                    }
+                    (None, Some(_)) => {
+                        self.service
+                            .database()
+                            .execute(Delete(By::<Arrears, _>::new(
+                                balance.contract.id,
+                            )))
+                            .await
+                            .map_err(tracerr::wrap!())
+                            .map(drop)?;
+                        count += 1;
+                    }
+                    (None, None) => {}
+                }
+            }
+
+            if is_last || self.cancel.is_cancelled() {
+                break;
+            }
+        }
+        Ok(count)
+    }
+}
+
+impl<Db> DetectRentArrears<Service<Db>>
+where
+    Db: Database<Insert<LateFee>, Err = Traced<database::Error>>,
+{
+    /// Accrues the provided [`LateFee`] for the overdue rent of the provided
+    /// [`read::arrears::Balance`], if any.
+    ///
+    /// Returns the updated [`LateFee`], if anything was accrued.
+    async fn accrue(
+        &self,
+        balance: &read::arrears::Balance,
+        mut fee: LateFee,
+        now: DetectionDateTime,
+    ) -> Result<Option<LateFee>, ExecutionError> {
+        // Late fees are charged on the overdue rent only, not on themselves.
+        let Some(overdue) = Arrears::detect(
+            &balance.contract,
+            balance.paid,
+            Decimal::ZERO,
+            self.config.grace_period,
+            now,
+        ) else {
+            return Ok(None);
+        };
+
+        let accrued = fee.accrue(
+            overdue.amount,
+            overdue.overdue_since.coerce(),
+            now.coerce(),
+        );
+        if accrued.is_zero() {
+            return Ok(None);
+        }
+        self.service
+            .database()
+            .execute(Insert(fee))
+            .await
+            .map_err(tracerr::wrap!())
+            .map(drop)?;
+        Ok(Some(fee))
+    }
+}
+
+/// Error of [`DetectRentArrears`] execution.
+pub type ExecutionError = Traced<database::Error>;
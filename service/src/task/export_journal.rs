@@ -0,0 +1,205 @@
+//! [`ExportJournal`] [`Task`].
+
+use std::{convert::Infallible, error::Error};
+
+use common::{
+    operations::{By, Insert, Perform, Select, Start},
+    DateTime,
+};
+use derive_more::{Display, Error as StdError, From};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::infra::{Accounting, Storage};
+use crate::{
+    domain::{journal, task, Payment},
+    infra::{accounting, database, storage, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`ExportJournal`] [`Task`].
+const NAME: &str = "task::ExportJournal";
+
+/// Number of [`journal::Entry`]s pushed at once.
+const BATCH_SIZE: usize = 100;
+
+/// Configuration for [`ExportJournal`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of pushing the journal.
+    pub schedule: Schedule,
+}
+
+/// [`Task`] for periodically pushing the journal [`journal::Entry`]s of the
+/// newly recorded [`Payment`]s into an [`Accounting`] system (if it's
+/// configured).
+///
+/// Every pushed batch is also archived as CSV into the [`Storage`] (if it's
+/// configured), under the [`storage::Namespace::Exports`].
+///
+/// Every [`journal::Entry`] is pushed once, unless the push fails to be
+/// recorded.
+#[derive(Clone, Debug)]
+pub struct ExportJournal<S> {
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                ExportJournal<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
+    > for Service<Db>
+where
+    ExportJournal<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<Insert<task::Run>, Ok = (), Err = Traced<database::Error>>,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                ExportJournal<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |_| {
+                let task = ExportJournal {
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for ExportJournal<Service<Db>>
+where
+    Db: Database<
+            Select<By<Vec<Payment>, read::payment::Unexported>>,
+            Ok = Vec<Payment>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<journal::Export>, Err = Traced<database::Error>>,
+{
+    type Ok = u64;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let Some(accounting) = self.service.config().accounting.as_deref()
+        else {
+            return Ok(0);
+        };
+
+        let (mut count, mut after) = (0, None);
+        loop {
+            let payments = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<Payment>, _>::new(
+                    read::payment::Unexported {
+                        after,
+                        limit: BATCH_SIZE,
+                    },
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+            let is_last = payments.len() < BATCH_SIZE;
+            after = payments.last().map(|p| p.id);
+
+            let entries = payments
+                .into_iter()
+                .map(journal::Entry::from)
+                .collect::<Vec<_>>();
+            if let Some(first) = entries.first() {
+                accounting
+                    .push(&entries)
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+                if let Some(storage) = self.service.config().storage.as_deref()
+                {
+                    let key = storage::Key::new(
+                        storage::Namespace::Exports,
+                        format!("journal/{}.csv", first.payment_id),
+                    )
+                    .expect("journal export key should be valid");
+                    storage
+                        .put(
+                            &key,
+                            "text/csv; charset=utf-8",
+                            journal::to_csv(&entries).into_bytes(),
+                        )
+                        .await
+                        .map_err(
+                            tracerr::map_from_and_wrap!(=> ExecutionError),
+                        )?;
+                }
+            }
+
+            let exported_at = DateTime::now().coerce();
+            for e in &entries {
+                self.service
+                    .database()
+                    .execute(Insert(journal::Export {
+                        payment_id: e.payment_id,
+                        exported_at,
+                    }))
+                    .await
+                    .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))
+                    .map(drop)?;
+                count += 1;
+            }
+
+            if is_last || self.cancel.is_cancelled() {
+                break;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Error of [`ExportJournal`] execution.
+#[derive(Debug, Display, From, StdError)]
+pub enum ExecutionError {
+    /// [`Accounting`] error.
+    #[display("`Accounting` operation failed: {_0}")]
+    Accounting(accounting::Error),
+
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Storage`] error.
+    #[display("`Storage` operation failed: {_0}")]
+    Storage(storage::Error),
+}
@@ -0,0 +1,222 @@
+//! [`GenerateSitemaps`] [`Task`].
+
+use std::{collections::HashMap, convert::Infallible, error::Error, sync::Arc};
+
+use common::{
+    operations::{By, Insert, Perform, Select, Start},
+    DateTime,
+};
+use tokio::{sync::watch, time};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::Agency;
+use crate::{
+    domain::{agency, sitemap, task},
+    infra::{database, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`GenerateSitemaps`] [`Task`].
+const NAME: &str = "task::GenerateSitemaps";
+
+/// Number of [`sitemap::Entry`]s selected at once.
+const BATCH_SIZE: usize = 1000;
+
+/// Configuration for [`GenerateSitemaps`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of checking whether the [`Sitemaps`] are stale.
+    pub schedule: Schedule,
+
+    /// Maximum age of the [`Sitemaps`], after which they're regenerated
+    /// regardless of the [`read::Placement`]s changes.
+    ///
+    /// Picks up the changes made by other [`Service`] instances.
+    pub max_age: time::Duration,
+
+    /// Base URL of the public storefront, the [`sitemap::Entry::slug()`]s are
+    /// linked under.
+    pub base_url: String,
+}
+
+/// Sitemaps of all the [`Agency`]s, as generated by the last
+/// [`GenerateSitemaps`].
+#[derive(Clone, Debug, Default)]
+pub struct Sitemaps {
+    /// [`DateTime`] when these [`Sitemaps`] were generated.
+    ///
+    /// [`None`] until the first generation succeeds.
+    pub generated_at: Option<DateTime>,
+
+    /// Rendered sitemap XMLs of the [`Agency`]s having [`read::Placement`]s.
+    pub documents: HashMap<agency::Id, Arc<str>>,
+}
+
+/// [`Task`] for keeping the [`Sitemaps`] of the placed [`read::Placement`]s
+/// up to date.
+///
+/// [`Sitemaps`] are regenerated once the [`read::Placement`]s change, or once
+/// they get older than the [`Config::max_age`].
+#[derive(Clone, Debug)]
+pub struct GenerateSitemaps<S> {
+    /// [`Service`] instance.
+    service: S,
+
+    /// Current [`Config`] of this [`Task`].
+    config: Config,
+
+    /// [`DateTime`] when the current [`Sitemaps`] were generated, if any.
+    generated_at: Option<DateTime>,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                GenerateSitemaps<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Sitemaps>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    GenerateSitemaps<Service<Db>>:
+        Task<Perform<()>, Ok = Option<Sitemaps>, Err: Error>
+            + Send
+            + Sync
+            + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                GenerateSitemaps<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Sitemaps>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, sitemaps, cancel) = by.into_inner();
+        let sitemaps = &sitemaps;
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            move |config| {
+                let task = GenerateSitemaps {
+                    service: self.clone(),
+                    config,
+                    generated_at: sitemaps.borrow().generated_at,
+                };
+                async move {
+                    task.execute(Perform(())).await.map(|new| {
+                        new.map_or(0, |new| {
+                            let count = new.documents.len();
+                            _ = sitemaps.send_replace(new);
+                            u64::try_from(count).unwrap_or(u64::MAX)
+                        })
+                    })
+                }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for GenerateSitemaps<Service<Db>>
+where
+    Db: Database<
+        Select<By<Vec<sitemap::Entry>, read::placement::Sitemap>>,
+        Ok = Vec<sitemap::Entry>,
+        Err = Traced<database::Error>,
+    >,
+{
+    type Ok = Option<Sitemaps>;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        if !self.is_stale() {
+            return Ok(None);
+        }
+
+        // Changes made during the generation must make it stale again.
+        let generated_at = DateTime::now();
+
+        let mut entries = HashMap::<_, Vec<_>>::new();
+        let mut after = None;
+        loop {
+            let batch = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<sitemap::Entry>, _>::new(
+                    read::placement::Sitemap {
+                        after,
+                        limit: BATCH_SIZE,
+                    },
+                )))
+                .await
+                .map_err(tracerr::wrap!())?;
+            let is_last = batch.len() < BATCH_SIZE;
+            after = batch.last().map(|e| e.realty_id);
+
+            for e in batch {
+                entries.entry(e.agency_id).or_default().push(e);
+            }
+            if is_last {
+                break;
+            }
+        }
+
+        Ok(Some(Sitemaps {
+            generated_at: Some(generated_at),
+            documents: entries
+                .into_iter()
+                .map(|(agency_id, entries)| {
+                    let xml = sitemap::to_xml(&self.config.base_url, &entries);
+                    (agency_id, xml.into())
+                })
+                .collect(),
+        }))
+    }
+}
+
+impl<Db> GenerateSitemaps<Service<Db>> {
+    /// Indicates whether the current [`Sitemaps`] should be regenerated.
+    fn is_stale(&self) -> bool {
+        let Some(generated_at) = self.generated_at else {
+            return true;
+        };
+        let changed_at = *self.service.placements_changed_at.borrow();
+        changed_at >= generated_at
+            || DateTime::now() - self.config.max_age >= generated_at
+    }
+}
+
+/// Error of [`GenerateSitemaps`] execution.
+pub type ExecutionError = Traced<database::Error>;
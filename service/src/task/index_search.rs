@@ -0,0 +1,329 @@
+//! [`IndexSearch`] [`Task`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::Infallible,
+    error::Error,
+};
+
+use common::{
+    operations::{By, Insert, Perform, Select, Start},
+    pagination::Arguments,
+    DateTime,
+};
+use derive_more::{Display, Error as StdError, From};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+
+use crate::{
+    domain::{realty, task, Realty},
+    infra::{
+        database,
+        search::{self, Document},
+        Database, Search,
+    },
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Number of entities mirrored into a [`Search`] at once.
+const BATCH_SIZE: usize = 500;
+
+/// Configuration for [`IndexSearch`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of [`Search`] re-indexing.
+    pub schedule: Schedule,
+}
+
+/// [`Task`] for periodically mirroring [`Realty`]s and [`read::Placement`]s
+/// into a [`Search`] (if it's configured).
+#[derive(Clone, Debug)]
+pub struct IndexSearch<S> {
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<IndexSearch<Self>, (watch::Receiver<Config>, CancellationToken)>,
+        >,
+    > for Service<Db>
+where
+    IndexSearch<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<IndexSearch<Self>, (watch::Receiver<Config>, CancellationToken)>,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, cancel) = by.into_inner();
+        scheduler::run(
+            "task::IndexSearch",
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |_| {
+                let task = IndexSearch {
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for IndexSearch<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<read::realty::list::Page, read::realty::list::Selector>,
+            >,
+            Ok = read::realty::list::Page,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<
+                By<
+                    read::placement::list::Page,
+                    read::placement::list::Selector,
+                >,
+            >,
+            Ok = read::placement::list::Page,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<realty::Id, Realty>, Vec<realty::Id>>>,
+            Ok = HashMap<realty::Id, Realty>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = u64;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let Some(search) = self.service.config().search.as_deref() else {
+            return Ok(0);
+        };
+        let revision = DateTime::now().unix_timestamp();
+
+        let realties = self
+            .index_realties(search, revision)
+            .await
+            .map_err(tracerr::wrap!())?;
+        if self.cancel.is_cancelled() {
+            return Ok(realties);
+        }
+        let placements = self
+            .index_placements(search, revision)
+            .await
+            .map_err(tracerr::wrap!())?;
+        Ok(realties + placements)
+    }
+}
+
+impl<Db> IndexSearch<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<read::realty::list::Page, read::realty::list::Selector>,
+            >,
+            Ok = read::realty::list::Page,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<
+                By<
+                    read::placement::list::Page,
+                    read::placement::list::Selector,
+                >,
+            >,
+            Ok = read::placement::list::Page,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<realty::Id, Realty>, Vec<realty::Id>>>,
+            Ok = HashMap<realty::Id, Realty>,
+            Err = Traced<database::Error>,
+        >,
+{
+    /// Mirrors all the [`Realty`]s into the provided [`Search`], removing
+    /// the ones written by the previous `revision`s.
+    ///
+    /// Stops after the current batch once [`IndexSearch::cancel`] is
+    /// cancelled, without removing anything.
+    ///
+    /// Returns the number of the mirrored [`Realty`]s.
+    async fn index_realties(
+        &self,
+        search: &dyn Search,
+        revision: i64,
+    ) -> Result<u64, Traced<ExecutionError>> {
+        let (mut after, mut count) = (None, 0);
+        loop {
+            let selector = read::realty::list::Selector {
+                arguments: Arguments::Forward {
+                    first: BATCH_SIZE,
+                    after,
+                    including: false,
+                },
+                filter: read::realty::list::Filter::default(),
+            };
+            let page = self
+                .service
+                .database()
+                .execute(Select(
+                    By::<read::realty::list::Page, _>::new(selector),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+            after = page.edges.last().map(|e| e.cursor.clone());
+            let documents: Vec<_> = page
+                .edges
+                .into_iter()
+                .map(|e| Document {
+                    id: e.node.id.into(),
+                    text: e.node.address.to_string(),
+                    facets: BTreeMap::from([
+                        ("country", e.node.country.to_string().into()),
+                        ("city", e.node.city.to_string().into()),
+                    ]),
+                    revision,
+                })
+                .collect();
+            count += documents.len() as u64;
+            search
+                .upsert(search::Index::Realties, documents)
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+            if !page.has_more {
+                break;
+            }
+            if self.cancel.is_cancelled() {
+                // Documents of the previous revisions are kept, since not all
+                // of them have been rewritten yet.
+                return Ok(count);
+            }
+        }
+
+        search
+            .purge(search::Index::Realties, revision)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+        Ok(count)
+    }
+
+    /// Mirrors all the [`read::Placement`]s into the provided [`Search`],
+    /// removing the ones written by the previous `revision`s.
+    ///
+    /// Stops after the current batch once [`IndexSearch::cancel`] is
+    /// cancelled, without removing anything.
+    ///
+    /// Returns the number of the mirrored [`read::Placement`]s.
+    async fn index_placements(
+        &self,
+        search: &dyn Search,
+        revision: i64,
+    ) -> Result<u64, Traced<ExecutionError>> {
+        let (mut after, mut count) = (None, 0);
+        loop {
+            let selector = read::placement::list::Selector {
+                arguments: Arguments::Forward {
+                    first: BATCH_SIZE,
+                    after,
+                    including: false,
+                },
+                filter: read::placement::list::Filter::default(),
+            };
+            let page = self
+                .service
+                .database()
+                .execute(Select(
+                    By::<read::placement::list::Page, _>::new(selector),
+                ))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+            after = page.edges.last().map(|e| e.cursor);
+            let mut realties = self
+                .service
+                .database()
+                .execute(Select(By::<HashMap<_, _>, Vec<_>>::new(
+                    page.edges.iter().map(|e| e.node.realty_id).collect(),
+                )))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+            let documents: Vec<_> = page
+                .edges
+                .into_iter()
+                .filter_map(|e| {
+                    let realty = realties.remove(&e.node.realty_id)?;
+                    Some(Document {
+                        id: e.node.realty_id.into(),
+                        text: realty.address.to_string(),
+                        facets: BTreeMap::from([
+                            ("rent", e.node.rent_contract_id.is_some().into()),
+                            ("sale", e.node.sale_contract_id.is_some().into()),
+                        ]),
+                        revision,
+                    })
+                })
+                .collect();
+            count += documents.len() as u64;
+            search
+                .upsert(search::Index::Placements, documents)
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+
+            if !page.has_more {
+                break;
+            }
+            if self.cancel.is_cancelled() {
+                // Documents of the previous revisions are kept, since not all
+                // of them have been rewritten yet.
+                return Ok(count);
+            }
+        }
+
+        search
+            .purge(search::Index::Placements, revision)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))?;
+        Ok(count)
+    }
+}
+
+/// Error of [`IndexSearch`] execution.
+#[derive(Debug, Display, From, StdError)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+
+    /// [`Search`] error.
+    #[display("`Search` operation failed: {_0}")]
+    Search(search::Error),
+}
@@ -1,10 +1,37 @@
 //! Background [`Task`]s definitions.
 
+pub mod alert_saved_searches;
 mod background;
 pub mod clean_unused_realties;
+pub mod complete_expired_contracts;
+pub mod database_healthcheck;
+pub mod detect_rent_arrears;
+pub mod export_journal;
+pub mod generate_sitemaps;
+pub mod index_search;
+pub mod notify_expiring_insurance;
+pub mod refresh_total_counts;
+pub mod retention_purge;
+pub mod scheduler;
+mod status;
+pub mod sync_listings;
 
 pub use common::Handler as Task;
 
 pub use self::{
-    background::Background, clean_unused_realties::CleanUnusedRealties,
+    alert_saved_searches::AlertSavedSearches,
+    background::{Background, Restart},
+    clean_unused_realties::CleanUnusedRealties,
+    complete_expired_contracts::CompleteExpiredContracts,
+    database_healthcheck::DatabaseHealthcheck,
+    detect_rent_arrears::DetectRentArrears,
+    export_journal::ExportJournal,
+    generate_sitemaps::GenerateSitemaps,
+    index_search::IndexSearch,
+    notify_expiring_insurance::NotifyExpiringInsurance,
+    refresh_total_counts::RefreshTotalCounts,
+    retention_purge::RetentionPurge,
+    scheduler::Schedule,
+    status::{Status, Statuses},
+    sync_listings::SyncListings,
 };
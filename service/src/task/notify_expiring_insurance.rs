@@ -0,0 +1,197 @@
+//! [`NotifyExpiringInsurance`] [`Task`].
+
+use std::{convert::Infallible, error::Error, time};
+
+use common::{
+    operations::{By, Insert, Perform, Select, Start},
+    DateTime,
+};
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+#[cfg(doc)]
+use crate::domain::{Contract, User};
+use crate::{
+    domain::{task, Insurance},
+    infra::{database, email, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`NotifyExpiringInsurance`] [`Task`].
+const NAME: &str = "task::NotifyExpiringInsurance";
+
+/// Number of [`Insurance`]s notified about at once.
+const BATCH_SIZE: usize = 100;
+
+/// Configuration for [`NotifyExpiringInsurance`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of expiring [`Insurance`]s notification.
+    pub schedule: Schedule,
+
+    /// Period before the expiration of an [`Insurance`], during which it's
+    /// considered expiring.
+    pub notice_period: time::Duration,
+}
+
+/// [`Task`] for periodically notifying employer [`User`]s about the
+/// [`Insurance`]s of their active [`Contract`]s expiring soon.
+///
+/// Every expiring [`Insurance`] is sent to the subscribers of
+/// [`Service::expiring_insurances()`] and emailed to its employer [`User`]
+/// only once.
+#[derive(Clone, Debug)]
+pub struct NotifyExpiringInsurance<S> {
+    /// [`Config`] of this [`Task`].
+    config: Config,
+
+    /// Sender of the expiring [`Insurance`]s.
+    expiring: broadcast::Sender<read::insurance::Expiring>,
+
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between batches.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                NotifyExpiringInsurance<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<read::insurance::Expiring>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    NotifyExpiringInsurance<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<Insert<task::Run>, Ok = (), Err = Traced<database::Error>>,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                NotifyExpiringInsurance<Self>,
+                (
+                    watch::Receiver<Config>,
+                    broadcast::Sender<read::insurance::Expiring>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, expiring, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |config| {
+                let task = NotifyExpiringInsurance {
+                    config,
+                    expiring: expiring.clone(),
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for NotifyExpiringInsurance<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<
+                    Vec<read::insurance::Expiring>,
+                    read::insurance::Unnotified,
+                >,
+            >,
+            Ok = Vec<read::insurance::Expiring>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Insurance>, Err = Traced<database::Error>>,
+{
+    type Ok = u64;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let now = DateTime::now();
+        let expiring_by = now + self.config.notice_period;
+        let (mut count, mut after) = (0, None);
+        loop {
+            let expiring = self
+                .service
+                .database()
+                .execute(Select(By::<Vec<read::insurance::Expiring>, _>::new(
+                    read::insurance::Unnotified {
+                        expiring_by,
+                        after,
+                        limit: BATCH_SIZE,
+                    },
+                )))
+                .await
+                .map_err(tracerr::wrap!())?;
+            let is_last = expiring.len() < BATCH_SIZE;
+            after = expiring.last().map(|e| e.insurance.id);
+
+            for mut e in expiring {
+                e.insurance.notified_at = Some(now.coerce());
+                self.service
+                    .database()
+                    .execute(Insert(e.insurance.clone()))
+                    .await
+                    .map_err(tracerr::wrap!())
+                    .map(drop)?;
+                count += 1;
+
+                log::info!(
+                    "`Insurance(id: {})` of `Contract(id: {})` is expiring",
+                    e.insurance.id,
+                    e.insurance.contract_id,
+                );
+                if let Some(to) = e.employer_email.clone() {
+                    self.service.send_email(
+                        to,
+                        e.locale,
+                        &email::Template::InsuranceExpiring {
+                            provider: e.insurance.provider.clone(),
+                            policy_number: e.insurance.policy_number.clone(),
+                            expires_at: e.insurance.expires_at.coerce(),
+                        },
+                    );
+                }
+                _ = self.expiring.send(e);
+            }
+
+            if is_last || self.cancel.is_cancelled() {
+                break;
+            }
+        }
+        Ok(count)
+    }
+}
+
+/// Error of [`NotifyExpiringInsurance`] execution.
+pub type ExecutionError = Traced<database::Error>;
@@ -0,0 +1,184 @@
+//! [`RefreshTotalCounts`] [`Task`].
+
+use std::{collections::HashMap, convert::Infallible, error::Error};
+
+use common::operations::{By, Insert, Perform, Select, Start};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+
+#[cfg(doc)]
+use crate::domain::{Agency, Contract, Realty, User};
+use crate::{
+    domain::{agency, task},
+    infra::{database, Database},
+    read, Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Configuration for [`RefreshTotalCounts`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of [`TotalCounts`] refreshes.
+    pub schedule: Schedule,
+}
+
+/// Exact total counts of unfiltered lists, as seen by the last
+/// [`RefreshTotalCounts`].
+///
+/// [`None`] until the first refresh succeeds.
+#[derive(Clone, Debug, Default)]
+pub struct TotalCounts {
+    /// Total counts of non-archived [`Contract`]s of every [`Agency`].
+    ///
+    /// [`Agency`]s without [`Contract`]s are omitted.
+    pub contracts:
+        Option<HashMap<agency::Id, read::contract::list::TotalCount>>,
+
+    /// Total counts of [`Realty`]s of every [`Agency`].
+    ///
+    /// [`Agency`]s without [`Realty`]s are omitted.
+    pub realties: Option<HashMap<agency::Id, read::realty::list::TotalCount>>,
+
+    /// Total counts of [`User`]s of every [`Agency`].
+    ///
+    /// [`Agency`]s without [`User`]s are omitted.
+    pub users: Option<HashMap<agency::Id, read::user::list::TotalCount>>,
+}
+
+/// [`Task`] for periodically refreshing the exact [`TotalCounts`], so they
+/// aren't counted on every request.
+#[derive(Clone, Debug)]
+pub struct RefreshTotalCounts<S> {
+    /// [`Service`] instance.
+    service: S,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                RefreshTotalCounts<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<TotalCounts>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    RefreshTotalCounts<Service<Db>>:
+        Task<Perform<()>, Ok = TotalCounts, Err: Error>
+            + Send
+            + Sync
+            + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                RefreshTotalCounts<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<TotalCounts>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, counts, cancel) = by.into_inner();
+        let counts = &counts;
+        scheduler::run(
+            "task::RefreshTotalCounts",
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            move |_| {
+                let task = RefreshTotalCounts {
+                    service: self.clone(),
+                };
+                async move {
+                    task.execute(Perform(())).await.map(|new| {
+                        _ = counts.send_replace(new);
+                        0
+                    })
+                }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for RefreshTotalCounts<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<HashMap<agency::Id, read::contract::list::TotalCount>, ()>,
+            >,
+            Ok = HashMap<agency::Id, read::contract::list::TotalCount>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<
+                By<HashMap<agency::Id, read::realty::list::TotalCount>, ()>,
+            >,
+            Ok = HashMap<agency::Id, read::realty::list::TotalCount>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<HashMap<agency::Id, read::user::list::TotalCount>, ()>>,
+            Ok = HashMap<agency::Id, read::user::list::TotalCount>,
+            Err = Traced<database::Error>,
+        >,
+{
+    type Ok = TotalCounts;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let db = self.service.database();
+        Ok(TotalCounts {
+            contracts: Some(
+                db.execute(Select(By::<
+                    HashMap<_, read::contract::list::TotalCount>,
+                    _,
+                >::new(())))
+                .await
+                .map_err(tracerr::wrap!())?,
+            ),
+            realties: Some(
+                db.execute(Select(By::<
+                    HashMap<_, read::realty::list::TotalCount>,
+                    _,
+                >::new(())))
+                .await
+                .map_err(tracerr::wrap!())?,
+            ),
+            users: Some(
+                db.execute(Select(By::<
+                    HashMap<_, read::user::list::TotalCount>,
+                    _,
+                >::new(())))
+                .await
+                .map_err(tracerr::wrap!())?,
+            ),
+        })
+    }
+}
+
+/// Error of [`RefreshTotalCounts`] execution.
+pub type ExecutionError = Traced<database::Error>;
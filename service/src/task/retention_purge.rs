@@ -0,0 +1,286 @@
+//! [`RetentionPurge`] [`Task`].
+
+use std::{collections::BTreeMap, convert::Infallible, error::Error, time};
+
+use common::{
+    operations::{
+        By, Commit, Delete, Insert, Perform, Start, Transact, Transacted,
+    },
+    DateTime,
+};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+#[cfg(doc)]
+use crate::domain::user::Session;
+use crate::{
+    domain::{audit, realty, sms, task, Realty},
+    infra::{database, Database},
+    Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Configuration for [`RetentionPurge`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of purges.
+    pub schedule: Schedule,
+
+    /// Indicator whether the purged rows should only be counted, without
+    /// being deleted actually.
+    pub dry_run: bool,
+
+    /// [`Policies`] of the purged entities.
+    pub policies: Policies,
+}
+
+/// Retention periods of the entities, after which they're purged completely.
+///
+/// [`None`] keeps the entity forever. [`Session`]s are not persisted, so need
+/// no retention.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Policies {
+    /// Retention period of the [`audit::Entry`]s.
+    pub audit_entries: Option<time::Duration>,
+
+    /// Retention period of the [`sms::Delivery`]s, since their last
+    /// [`sms::Status`] update.
+    pub sms_deliveries: Option<time::Duration>,
+
+    /// Retention period of the [`task::Run`]s history.
+    pub task_runs: Option<time::Duration>,
+
+    /// Period during which a deleted [`Realty`] may be restored, before being
+    /// purged completely.
+    pub deleted_realties: Option<time::Duration>,
+}
+
+/// Numbers of the purged rows, keyed by the names of their [`Policies`].
+pub type Purged = BTreeMap<&'static str, u64>;
+
+/// Statistics of the [`RetentionPurge`] runs.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    /// Total numbers of the purged rows.
+    ///
+    /// Dry runs are not counted.
+    pub total: Purged,
+
+    /// Numbers of the rows purged by the last run, or which would be purged,
+    /// if it was a dry one.
+    pub last: Purged,
+}
+
+/// [`Task`] for periodically purging the entities outlived their retention
+/// [`Policies`].
+///
+/// Entities of each policy are purged in a separate transaction, which is
+/// rolled back in the [`Config::dry_run`] mode.
+#[derive(Clone, Debug)]
+pub struct RetentionPurge<S> {
+    /// [`Config`] of this [`Task`].
+    config: Config,
+
+    /// [`Service`] instance.
+    service: S,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                RetentionPurge<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Stats>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    > for Service<Db>
+where
+    RetentionPurge<Service<Db>>:
+        Task<Perform<()>, Ok = Purged, Err: Error> + Send + Sync + 'static,
+    Db: Database<
+        Insert<task::Run>,
+        Ok = (),
+        Err = Traced<database::Error>,
+    >,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                RetentionPurge<Self>,
+                (
+                    watch::Receiver<Config>,
+                    watch::Sender<Stats>,
+                    CancellationToken,
+                ),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, stats, cancel) = by.into_inner();
+        let stats = &stats;
+        scheduler::run(
+            "task::RetentionPurge",
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            move |config| {
+                let dry_run = config.dry_run;
+                let task = RetentionPurge {
+                    config,
+                    service: self.clone(),
+                };
+                async move {
+                    task.execute(Perform(())).await.map(|purged| {
+                        stats.send_modify(|s| {
+                            if !dry_run {
+                                for (&name, &count) in &purged {
+                                    *s.total.entry(name).or_default() += count;
+                                }
+                            }
+                            s.last.clone_from(&purged);
+                        });
+                        purged.values().sum()
+                    })
+                }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for RetentionPurge<Service<Db>>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Delete<By<audit::Entry, audit::CreationDateTime>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<sms::Delivery, sms::UpdateDateTime>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<task::Run, DateTime>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        > + Database<
+            Delete<By<Realty, realty::DeletionDateTime>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        > + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = Purged;
+    type Err = ExecutionError;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let Config {
+            dry_run,
+            policies:
+                Policies {
+                    audit_entries,
+                    sms_deliveries,
+                    task_runs,
+                    deleted_realties,
+                },
+            ..
+        } = self.config;
+        let now = DateTime::now();
+
+        let mut results = vec![];
+        if let Some(retention) = audit_entries {
+            let deadline: audit::CreationDateTime = (now - retention).coerce();
+            let res = self.purge::<audit::Entry, _>(deadline).await;
+            results.push(("audit_entries", res));
+        }
+        if let Some(retention) = sms_deliveries {
+            let deadline: sms::UpdateDateTime = (now - retention).coerce();
+            let res = self.purge::<sms::Delivery, _>(deadline).await;
+            results.push(("sms_deliveries", res));
+        }
+        if let Some(retention) = task_runs {
+            let res = self.purge::<task::Run, _>(now - retention).await;
+            results.push(("task_runs", res));
+        }
+        if let Some(retention) = deleted_realties {
+            let deadline: realty::DeletionDateTime = (now - retention).coerce();
+            let res = self.purge::<Realty, _>(deadline).await;
+            results.push(("deleted_realties", res));
+        }
+
+        let (mut purged, mut failure) = (Purged::new(), None);
+        for (name, res) in results {
+            match res {
+                Ok(count) => _ = purged.insert(name, count),
+                Err(e) => {
+                    log::error!(
+                        "`task::RetentionPurge` failed to purge `{name}`: {e}",
+                    );
+                    _ = failure.get_or_insert(e);
+                }
+            }
+        }
+        if dry_run {
+            log::info!("`task::RetentionPurge` dry run would purge {purged:?}");
+        }
+
+        failure.map_or(Ok(purged), Err)
+    }
+}
+
+impl<Db> RetentionPurge<Service<Db>>
+where
+    Db: Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<Commit, Err = Traced<database::Error>>,
+{
+    /// Purges the `W` entities outlived the provided `deadline` in their own
+    /// transaction, so a failure of one policy doesn't roll back the others.
+    ///
+    /// The transaction is rolled back in the [`Config::dry_run`] mode.
+    async fn purge<W, D>(&self, deadline: D) -> Result<u64, ExecutionError>
+    where
+        Transacted<Db>: Database<
+            Delete<By<W, D>>,
+            Ok = u64,
+            Err = Traced<database::Error>,
+        >,
+    {
+        let tx = self
+            .service
+            .database()
+            .execute(Transact)
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        let count = tx
+            .execute(Delete(By::<W, _>::new(deadline)))
+            .await
+            .map_err(tracerr::wrap!())?;
+
+        // Dropping the transaction rolls it back.
+        if !self.config.dry_run {
+            tx.execute(Commit).await.map_err(tracerr::wrap!())?;
+        }
+
+        Ok(count)
+    }
+}
+
+/// Error of [`RetentionPurge`] execution.
+pub type ExecutionError = Traced<database::Error>;
@@ -0,0 +1,289 @@
+//! Scheduling of periodic [`Task`]s.
+
+use std::{fmt, future::Future, str::FromStr, time};
+
+use common::DateTime;
+use derive_more::{Display, Error};
+use rand::Rng as _;
+use serde::Deserialize;
+use tokio::{
+    sync::watch,
+    time::{sleep_until, Instant},
+};
+use tokio_util::sync::CancellationToken;
+use tracing as log;
+
+use crate::domain::task::Run;
+
+use super::status::Statuses;
+#[cfg(doc)]
+use super::{status::Status, Task};
+
+/// [`Schedule`] of a periodic [`Task`].
+#[derive(Clone, Debug)]
+pub struct Schedule {
+    /// [`Timing`] of the [`Task`] runs.
+    pub timing: Timing,
+
+    /// Maximum random delay added to each [`Task`] run, so the runs of
+    /// multiple instances don't happen simultaneously.
+    pub jitter: time::Duration,
+}
+
+impl Schedule {
+    /// Creates a new [`Schedule`] running a [`Task`] with the provided fixed
+    /// `interval` between the runs and no jitter.
+    #[must_use]
+    pub const fn every(interval: time::Duration) -> Self {
+        Self {
+            timing: Timing::Every(interval),
+            jitter: time::Duration::ZERO,
+        }
+    }
+
+    /// Returns the delay before the next [`Task`] run, starting from now.
+    #[must_use]
+    pub fn next_delay(&self) -> time::Duration {
+        let delay = match &self.timing {
+            Timing::Every(interval) => *interval,
+            Timing::Cron(cron) => cron.next_delay(),
+        };
+        if self.jitter.is_zero() {
+            return delay;
+        }
+        let jitter =
+            rand::thread_rng().gen_range(time::Duration::ZERO..=self.jitter);
+        delay.saturating_add(jitter)
+    }
+}
+
+/// Timing of the [`Task`] runs in a [`Schedule`].
+#[expect(
+    clippy::large_enum_variant,
+    reason = "created only on configuration (re)load"
+)]
+#[derive(Clone, Debug)]
+pub enum Timing {
+    /// Fixed interval between the end of a run and the start of the next one.
+    Every(time::Duration),
+
+    /// [`Cron`] expression matching the start of each run.
+    Cron(Cron),
+}
+
+/// [Cron expression] (in UTC, with seconds) of a [`Schedule`].
+///
+/// [Cron expression]: https://en.wikipedia.org/wiki/Cron#Cron_expression
+#[derive(Clone, Debug, Deserialize, Display)]
+#[serde(try_from = "String")]
+pub struct Cron(cron::Schedule);
+
+impl Cron {
+    /// Returns the delay before the next time matching this [`Cron`]
+    /// expression, starting from now.
+    ///
+    /// [`time::Duration::MAX`] is returned if there is no such time.
+    #[must_use]
+    pub fn next_delay(&self) -> time::Duration {
+        let now = chrono::Utc::now();
+        self.0
+            .after(&now)
+            .next()
+            .map_or(time::Duration::MAX, |next| {
+                (next - now).to_std().unwrap_or_default()
+            })
+    }
+}
+
+impl FromStr for Cron {
+    type Err = CronParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        cron::Schedule::from_str(s)
+            .map(Self)
+            .map_err(|_| CronParseError)
+    }
+}
+
+impl TryFrom<String> for Cron {
+    type Error = CronParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Error of parsing a malformed [`Cron`] expression.
+#[derive(Clone, Copy, Debug, Display, Error)]
+#[display("malformed cron expression")]
+pub struct CronParseError;
+
+/// Runs the provided `job` according to the [`Schedule`] of its current
+/// configuration `C`, until the provided `cancel` token is cancelled.
+///
+/// The `job` is run right away, and then each time its [`Schedule`] fires.
+/// Reconfiguration is picked up as soon as the `config` changes, rescheduling
+/// the next run. Errors of the `job` are logged only, so the next run happens
+/// anyway. Cancellation is picked up between the runs only, so the in-progress
+/// run is never interrupted.
+///
+/// The `job` returns the number of the items it has processed. [`Status`] of
+/// the `job` is reported into the provided `statuses` under its `name`, and
+/// each its finished [`Run`] is passed to the provided `record`.
+pub async fn run<C, Job, Fut, E, Rec, RecFut>(
+    name: &'static str,
+    statuses: &watch::Sender<Statuses>,
+    mut config: watch::Receiver<C>,
+    schedule: impl Fn(&C) -> Schedule,
+    mut job: Job,
+    record: Rec,
+    cancel: &CancellationToken,
+)
+where
+    C: Clone + fmt::Debug,
+    Job: FnMut(C) -> Fut,
+    Fut: Future<Output = Result<u64, E>>,
+    E: Display,
+    Rec: Fn(Run) -> RecFut,
+    RecFut: Future<Output = ()>,
+{
+    let mut current = config.borrow_and_update().clone();
+    let mut next_run = Instant::now();
+    statuses.send_modify(|s| {
+        s.entry(name).or_default().next_run_at = Some(DateTime::now());
+    });
+    loop {
+        tokio::select! {
+            () = sleep_until(next_run) => {
+                let (started_at, start) = (DateTime::now(), Instant::now());
+                let res = job(current.clone()).await;
+                let duration = start.elapsed();
+                let error = res.as_ref().err().map(ToString::to_string);
+                if let Some(e) = &error {
+                    log::error!("`{name}` failed: {e}");
+                }
+                let processed = res.unwrap_or_default();
+                let (deadline, next_run_at) = deadline(&schedule(&current));
+                next_run = deadline;
+                statuses.send_modify(|s| {
+                    let status = s.entry(name).or_default();
+                    status.last_run_at = Some(started_at);
+                    status.last_duration = Some(duration);
+                    status.next_run_at = Some(next_run_at);
+                    status.runs += 1;
+                    status.processed += processed;
+                    if let Some(e) = &error {
+                        status.last_error = Some(e.clone());
+                        status.consecutive_failures += 1;
+                        status.failures += 1;
+                    } else {
+                        status.last_error = None;
+                        status.consecutive_failures = 0;
+                    }
+                });
+                record(Run {
+                    task: name.into(),
+                    started_at,
+                    duration,
+                    processed,
+                    error,
+                })
+                .await;
+            }
+            Ok(()) = config.changed() => {
+                current = config.borrow_and_update().clone();
+                let (deadline, next_run_at) = deadline(&schedule(&current));
+                next_run = deadline;
+                statuses.send_modify(|s| {
+                    s.entry(name).or_default().next_run_at = Some(next_run_at);
+                });
+                log::info!("`{name}` reconfigured: {current:?}");
+            }
+            () = cancel.cancelled() => {
+                statuses.send_modify(|s| {
+                    s.entry(name).or_default().next_run_at = None;
+                });
+                log::info!("`{name}` stopped");
+                return;
+            }
+        }
+    }
+}
+
+/// Returns the [`Instant`] and the [`DateTime`] of the next run according to
+/// the provided [`Schedule`].
+fn deadline(schedule: &Schedule) -> (Instant, DateTime) {
+    // Far enough in the future to never happen in practice.
+    const NEVER: time::Duration = time::Duration::from_hours(24 * 365);
+
+    let delay = schedule.next_delay().min(NEVER);
+    (Instant::now() + delay, DateTime::now() + delay)
+}
+
+#[cfg(test)]
+mod spec {
+    use std::time;
+
+    use super::{Cron, Schedule, Timing};
+
+    #[test]
+    fn parses_cron() {
+        assert!("0 0 3 * * *".parse::<Cron>().is_ok());
+        assert!("0 */15 * * * Mon-Fri".parse::<Cron>().is_ok());
+        assert!("0 0 3 * * * 2099".parse::<Cron>().is_ok());
+
+        assert!("".parse::<Cron>().is_err());
+        assert!("* * *".parse::<Cron>().is_err());
+        assert!("0 0 25 * * *".parse::<Cron>().is_err());
+        assert!("every day".parse::<Cron>().is_err());
+    }
+
+    #[test]
+    fn cron_next_delay_is_bounded_by_its_period() {
+        let every_second = "* * * * * *".parse::<Cron>().unwrap();
+        assert!(every_second.next_delay() <= time::Duration::from_secs(1));
+
+        let hourly = "0 0 * * * *".parse::<Cron>().unwrap();
+        assert!(hourly.next_delay() <= time::Duration::from_hours(1));
+    }
+
+    #[test]
+    fn cron_next_delay_is_max_without_next_time() {
+        let past = "0 0 0 1 1 * 2000".parse::<Cron>().unwrap();
+
+        assert_eq!(past.next_delay(), time::Duration::MAX);
+    }
+
+    #[test]
+    fn next_delay_without_jitter_is_exact() {
+        let interval = time::Duration::from_secs(30);
+
+        assert_eq!(Schedule::every(interval).next_delay(), interval);
+    }
+
+    #[test]
+    fn next_delay_jitter_is_bounded() {
+        let interval = time::Duration::from_secs(30);
+        let jitter = time::Duration::from_secs(5);
+        let schedule = Schedule {
+            timing: Timing::Every(interval),
+            jitter,
+        };
+
+        for _ in 0..1000 {
+            let delay = schedule.next_delay();
+            assert!(delay >= interval, "{delay:?} < {interval:?}");
+            assert!(delay <= interval + jitter, "{delay:?} > jittered");
+        }
+    }
+
+    #[test]
+    fn next_delay_jitter_saturates() {
+        let schedule = Schedule {
+            timing: Timing::Every(time::Duration::MAX),
+            jitter: time::Duration::from_secs(5),
+        };
+
+        assert_eq!(schedule.next_delay(), time::Duration::MAX);
+    }
+}
@@ -0,0 +1,43 @@
+//! [`Status`] of periodic [`Task`]s.
+
+use std::{collections::BTreeMap, time};
+
+use common::DateTime;
+
+#[cfg(doc)]
+use super::{scheduler, Task};
+
+/// [`Status`] of a periodic [`Task`], as seen by its [`scheduler`].
+#[derive(Clone, Debug, Default)]
+pub struct Status {
+    /// [`DateTime`] when the last run of the [`Task`] has started.
+    ///
+    /// [`None`] if the [`Task`] hasn't run yet.
+    pub last_run_at: Option<DateTime>,
+
+    /// Duration of the last run of the [`Task`].
+    ///
+    /// [`None`] if the [`Task`] hasn't run yet.
+    pub last_duration: Option<time::Duration>,
+
+    /// Error of the last run of the [`Task`], if it has failed.
+    pub last_error: Option<String>,
+
+    /// [`DateTime`] when the next run of the [`Task`] is scheduled at.
+    pub next_run_at: Option<DateTime>,
+
+    /// Number of the consecutive failed runs of the [`Task`].
+    pub consecutive_failures: u32,
+
+    /// Total number of the runs of the [`Task`].
+    pub runs: u64,
+
+    /// Total number of the failed runs of the [`Task`].
+    pub failures: u64,
+
+    /// Total number of the items processed by the runs of the [`Task`].
+    pub processed: u64,
+}
+
+/// [`Status`]es of periodic [`Task`]s, keyed by their names.
+pub type Statuses = BTreeMap<&'static str, Status>;
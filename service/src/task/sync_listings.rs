@@ -0,0 +1,536 @@
+//! [`SyncListings`] [`Task`].
+
+use std::{convert::Infallible, error::Error, sync::Arc};
+
+use common::{
+    operations::{
+        By, Commit, Insert, Lock, Perform, Select, Start, Transact, Transacted,
+    },
+    DateTime,
+};
+use derive_more::{Display, Error as StdError, From};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracerr::Traced;
+use tracing as log;
+
+use crate::{
+    domain::{
+        agency, contract, listing, realty, task, user, Contract, Listing,
+        Realty, User, Version,
+    },
+    infra::{database, Database, ListingFeed},
+    read::{self, contract::Active},
+    Service,
+};
+
+use super::{
+    scheduler::{self, Schedule},
+    Task,
+};
+
+/// Name of the [`SyncListings`] [`Task`].
+const NAME: &str = "task::SyncListings";
+
+/// Configuration for [`SyncListings`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// [`Schedule`] of pulling the [`Feed`]s.
+    pub schedule: Schedule,
+}
+
+/// External [`ListingFeed`] synchronized by the [`SyncListings`] [`Task`].
+#[derive(Clone, Debug)]
+pub struct Feed {
+    /// [`listing::Source`] name of this [`Feed`].
+    pub source: listing::Source,
+
+    /// ID of the [`Agency`] the [`Listing`]s of this [`Feed`] are imported
+    /// into.
+    ///
+    /// [`Agency`]: crate::domain::Agency
+    pub agency_id: agency::Id,
+
+    /// ID of the employed [`User`] managing the imported [`Realty`]s.
+    pub employer_id: user::Id,
+
+    /// ID of the [`User`] representing this [`Feed`] as the landlord of the
+    /// imported [`Realty`]s.
+    pub landlord_id: user::Id,
+
+    /// [`ListingFeed`] client to pull the [`Listing`]s with.
+    pub client: Arc<dyn ListingFeed>,
+}
+
+/// [`Task`] for periodically pulling the [`Listing`]s from the configured
+/// [`Feed`]s, importing the new ones as [`Realty`]s with placed management
+/// [`Contract`]s.
+///
+/// A [`Listing`] matching an existing [`Realty`] of the [`Agency`] by its
+/// [`realty::Hash`], which is already managed for the same [`listing::Deal`],
+/// is only linked to it, leaving its management intact. Every [`Listing`] is
+/// imported once, so its later changes in the [`Feed`] are not synchronized.
+///
+/// Results of every [`Feed`] pulling are recorded as a [`listing::Sync`].
+///
+/// [`Agency`]: crate::domain::Agency
+#[derive(Clone, Debug)]
+pub struct SyncListings<S> {
+    /// [`Service`] instance.
+    service: S,
+
+    /// [`CancellationToken`] stopping this [`Task`] between [`Feed`]s.
+    cancel: CancellationToken,
+}
+
+impl<Db>
+    Task<
+        Start<
+            By<
+                SyncListings<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
+    > for Service<Db>
+where
+    SyncListings<Service<Db>>:
+        Task<Perform<()>, Ok = u64, Err: Error> + Send + Sync + 'static,
+    Db: Database<Insert<task::Run>, Ok = (), Err = Traced<database::Error>>,
+    Self: Clone,
+{
+    type Ok = ();
+    type Err = Infallible;
+
+    async fn execute(
+        &self,
+        Start(by): Start<
+            By<
+                SyncListings<Self>,
+                (watch::Receiver<Config>, CancellationToken),
+            >,
+        >,
+    ) -> Result<Self::Ok, Self::Err> {
+        let (config, cancel) = by.into_inner();
+        scheduler::run(
+            NAME,
+            &self.task_statuses,
+            config,
+            |config| config.schedule.clone(),
+            |_| {
+                let task = SyncListings {
+                    service: self.clone(),
+                    cancel: cancel.clone(),
+                };
+                async move { task.execute(Perform(())).await }
+            },
+            |run| self.record_task_run(run),
+            &cancel,
+        )
+        .await;
+        Ok(())
+    }
+}
+
+impl<Db> Task<Perform<()>> for SyncListings<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<
+                    Option<listing::Import>,
+                    (listing::Source, listing::ExternalId),
+                >,
+            >,
+            Ok = Option<listing::Import>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<listing::Sync>, Err = Traced<database::Error>>
+        + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Hash>>,
+            Err = Traced<database::Error>,
+        > + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Realty>, (agency::Id, realty::Hash)>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
+            Ok = Option<Active<contract::ManagementForRent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::ManagementForSale>>, realty::Id>>,
+            Ok = Option<Active<contract::ManagementForSale>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Realty>, Err = Traced<database::Error>>
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Insert<listing::Import>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    type Ok = u64;
+    type Err = Traced<ExecutionError>;
+
+    async fn execute(&self, _: Perform<()>) -> Result<Self::Ok, Self::Err> {
+        let mut count = 0;
+        for feed in &self.service.config().listing_feeds {
+            if self.cancel.is_cancelled() {
+                break;
+            }
+
+            let mut sync =
+                listing::Sync::new(feed.source.clone(), DateTime::now());
+            match feed.client.fetch().await {
+                Ok(fetched) => {
+                    sync.rejected = fetched.rejected;
+                    for l in fetched.listings {
+                        match self.import(feed, l).await? {
+                            Outcome::Imported => sync.imported += 1,
+                            Outcome::Deduplicated => sync.deduplicated += 1,
+                            Outcome::Unchanged => sync.unchanged += 1,
+                            Outcome::Rejected => sync.rejected += 1,
+                        }
+                    }
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Failed to pull `{}` listings: {e}",
+                        feed.source,
+                    );
+                    sync.error = Some(e.to_string());
+                }
+            }
+            sync.finished_at = DateTime::now();
+            count += u64::from(sync.imported + sync.deduplicated);
+
+            self.service
+                .database()
+                .execute(Insert(sync))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> ExecutionError))
+                .map(drop)?;
+        }
+        Ok(count)
+    }
+}
+
+impl<Db> SyncListings<Service<Db>>
+where
+    Db: Database<
+            Select<
+                By<
+                    Option<listing::Import>,
+                    (listing::Source, listing::ExternalId),
+                >,
+            >,
+            Ok = Option<listing::Import>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<User>, user::Id>>,
+            Ok = Option<User>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::Employment>>, user::Id>>,
+            Ok = Option<Active<contract::Employment>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<agency::Settings>, agency::Id>>,
+            Ok = Option<agency::Settings>,
+            Err = Traced<database::Error>,
+        > + Database<Transact, Err = Traced<database::Error>>,
+    Transacted<Db>: Database<
+            Lock<By<Realty, realty::Hash>>,
+            Err = Traced<database::Error>,
+        > + Database<Lock<By<Realty, realty::Id>>, Err = Traced<database::Error>>
+        + Database<
+            Select<By<Option<Realty>, (agency::Id, realty::Hash)>>,
+            Ok = Option<Realty>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::ManagementForRent>>, realty::Id>>,
+            Ok = Option<Active<contract::ManagementForRent>>,
+            Err = Traced<database::Error>,
+        > + Database<
+            Select<By<Option<Active<contract::ManagementForSale>>, realty::Id>>,
+            Ok = Option<Active<contract::ManagementForSale>>,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Realty>, Err = Traced<database::Error>>
+        + Database<
+            Perform<By<contract::Number, read::contract::NumberSequence>>,
+            Ok = contract::Number,
+            Err = Traced<database::Error>,
+        > + Database<Insert<Contract>, Err = Traced<database::Error>>
+        + Database<Insert<Vec<contract::Party>>, Err = Traced<database::Error>>
+        + Database<Insert<listing::Import>, Err = Traced<database::Error>>
+        + Database<Commit, Err = Traced<database::Error>>,
+{
+    /// Imports the provided [`Listing`] pulled from the provided [`Feed`],
+    /// unless it's imported already.
+    ///
+    /// [`Realty`] and its management [`Contract`] are created in a single
+    /// transaction, so a [`Listing`] is either imported completely, or not at
+    /// all. Misconfigured [`Feed`]s reject the [`Listing`] only, so it's
+    /// retried by the next run.
+    #[expect(clippy::too_many_lines, reason = "still readable")]
+    async fn import(
+        &self,
+        feed: &Feed,
+        listing: Listing,
+    ) -> Result<Outcome, Traced<ExecutionError>> {
+        use ExecutionError as E;
+
+        let db = self.service.database();
+
+        let import = db
+            .execute(Select(By::<Option<listing::Import>, _>::new((
+                feed.source.clone(),
+                listing.external_id.clone(),
+            ))))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if import.is_some() {
+            return Ok(Outcome::Unchanged);
+        }
+
+        let is_employed = db
+            .execute(Select(
+                By::<Option<Active<contract::Employment>>, _>::new(
+                    feed.employer_id,
+                ),
+            ))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .is_some_and(|Active(c)| c.agency_id == feed.agency_id);
+        let landlord = db
+            .execute(Select(By::<Option<User>, _>::new(feed.landlord_id)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        if !is_employed || landlord.is_none() {
+            log::warn!(
+                "Failed to import `{}/{}` listing: `User(id: {})` is not an \
+                 employer or `User(id: {})` does not exist",
+                feed.source,
+                listing.external_id,
+                feed.employer_id,
+                feed.landlord_id,
+            );
+            return Ok(Outcome::Rejected);
+        }
+
+        let settings = db
+            .execute(Select(By::<Option<agency::Settings>, _>::new(
+                feed.agency_id,
+            )))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?
+            .unwrap_or_else(|| agency::Settings::new(feed.agency_id));
+
+        let tx = db
+            .execute(Transact)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+        // Avoid concurrent creation of the same `Realty`.
+        let hash = listing.hash();
+        tx.execute(Lock(By::new(hash)))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+
+        let existing = tx
+            .execute(Select(By::<Option<Realty>, _>::new((
+                feed.agency_id,
+                hash,
+            ))))
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))?;
+        let managed_id = match &existing {
+            Some(r) if r.deleted_at.is_none() => {
+                let is_managed = match listing.deal {
+                    listing::Deal::Rent => tx
+                        .execute(Select(By::<
+                            Option<Active<contract::ManagementForRent>>,
+                            _,
+                        >::new(r.id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))?
+                        .is_some(),
+                    listing::Deal::Sale => tx
+                        .execute(Select(By::<
+                            Option<Active<contract::ManagementForSale>>,
+                            _,
+                        >::new(r.id)))
+                        .await
+                        .map_err(tracerr::map_from_and_wrap!(=> E))?
+                        .is_some(),
+                };
+                is_managed.then_some(r.id)
+            }
+            _ => None,
+        };
+
+        // Only a `Realty` being managed for the same deal is deduplicated,
+        // while a deleted or an unmanaged one is restored and managed by the
+        // imported `Listing`.
+        let (realty_id, contract_id, outcome) = if let Some(id) = managed_id {
+            (id, None, Outcome::Deduplicated)
+        } else {
+            let realty = existing.map_or_else(
+                || listing.realty(feed.agency_id),
+                |mut r| {
+                    r.deleted_at = None;
+                    r
+                },
+            );
+            tx.execute(Insert(realty.clone()))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+
+            // Avoid concurrent actions upon the same `Realty`.
+            tx.execute(Lock(By::new(realty.id)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+
+            let now = DateTime::now();
+            let number = tx
+                .execute(Perform(By::new(read::contract::NumberSequence {
+                    agency_id: feed.agency_id,
+                    kind: match listing.deal {
+                        listing::Deal::Rent => {
+                            contract::Kind::ManagementForRent
+                        }
+                        listing::Deal::Sale => {
+                            contract::Kind::ManagementForSale
+                        }
+                    },
+                    year: now.year(),
+                })))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))?;
+
+            // Default commission applies, as no fee is specified by the
+            // `Listing`.
+            let contract = match listing.deal {
+                listing::Deal::Rent => {
+                    Contract::from(contract::ManagementForRent {
+                        id: contract::Id::new(),
+                        agency_id: feed.agency_id,
+                        number,
+                        name: listing.name,
+                        description: listing.description,
+                        realty_id: realty.id,
+                        landlord_id: feed.landlord_id,
+                        employer_id: feed.employer_id,
+                        expected_price: listing.price,
+                        expected_deposit: None,
+                        one_time_fee: None,
+                        monthly_fee: None,
+                        percent_fee: settings.commission,
+                        is_placed: true,
+                        created_at: now.coerce(),
+                        expires_at: settings.expiration(now),
+                        completed_at: None,
+                        terminated_at: None,
+                        archived_at: None,
+                        version: Version::initial(),
+                    })
+                }
+                listing::Deal::Sale => {
+                    Contract::from(contract::ManagementForSale {
+                        id: contract::Id::new(),
+                        agency_id: feed.agency_id,
+                        number,
+                        name: listing.name,
+                        description: listing.description,
+                        realty_id: realty.id,
+                        landlord_id: feed.landlord_id,
+                        employer_id: feed.employer_id,
+                        expected_price: listing.price,
+                        expected_deposit: None,
+                        one_time_fee: None,
+                        monthly_fee: None,
+                        percent_fee: settings.commission,
+                        is_placed: true,
+                        created_at: now.coerce(),
+                        expires_at: settings.expiration(now),
+                        completed_at: None,
+                        terminated_at: None,
+                        archived_at: None,
+                        version: Version::initial(),
+                    })
+                }
+            };
+            tx.execute(Insert(contract.clone()))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+            tx.execute(Insert(contract::Party::primary(&contract)))
+                .await
+                .map_err(tracerr::map_from_and_wrap!(=> E))
+                .map(drop)?;
+
+            (realty.id, Some(contract.id()), Outcome::Imported)
+        };
+
+        tx.execute(Insert(listing::Import {
+            source: feed.source.clone(),
+            external_id: listing.external_id,
+            realty_id,
+            contract_id,
+            imported_at: DateTime::now().coerce(),
+        }))
+        .await
+        .map_err(tracerr::map_from_and_wrap!(=> E))
+        .map(drop)?;
+
+        tx.execute(Commit)
+            .await
+            .map_err(tracerr::map_from_and_wrap!(=> E))
+            .map(drop)?;
+        if contract_id.is_some() {
+            self.service.placements_changed().await;
+        }
+
+        Ok(outcome)
+    }
+}
+
+/// Outcome of a single [`Listing`] import.
+#[derive(Clone, Copy, Debug)]
+enum Outcome {
+    /// [`Listing`] is imported as a new [`Realty`].
+    Imported,
+
+    /// [`Listing`] is linked to an existing [`Realty`].
+    Deduplicated,
+
+    /// [`Listing`] is imported already.
+    Unchanged,
+
+    /// [`Listing`] failed to be imported.
+    Rejected,
+}
+
+/// Error of [`SyncListings`] execution.
+#[derive(Debug, Display, From, StdError)]
+pub enum ExecutionError {
+    /// [`Database`] error.
+    #[display("`Database` operation failed: {_0}")]
+    Db(database::Error),
+}